@@ -374,6 +374,7 @@ enum Command {
     AppendChunk(Chunk),
     AppendRow(EntityPath, PendingRow),
     Flush(Sender<()>),
+    SetCoarsening(u32),
     Shutdown,
 }
 
@@ -462,6 +463,16 @@ impl ChunkBatcher {
         self.inner.flush_blocking();
     }
 
+    /// Multiplies all flush thresholds (time, bytes and rows) by `factor`, resulting in fewer
+    /// but larger chunks.
+    ///
+    /// Useful to lighten the load downstream, e.g. when the viewer can't keep up.
+    /// A `factor` of 1 (or 0) restores the thresholds of the [`ChunkBatcherConfig`].
+    #[inline]
+    pub fn set_coarsening(&self, factor: u32) {
+        self.inner.send_cmd(Command::SetCoarsening(factor.max(1)));
+    }
+
     // --- Subscribe to chunks ---
 
     /// Returns a _shared_ channel in which are sent the batched [`Chunk`]s.
@@ -506,7 +517,12 @@ impl ChunkBatcherInner {
 
 #[allow(clippy::needless_pass_by_value)]
 fn batching_thread(config: ChunkBatcherConfig, rx_cmd: Receiver<Command>, tx_chunk: Sender<Chunk>) {
-    let rx_tick = crossbeam::channel::tick(config.flush_tick);
+    let mut rx_tick = crossbeam::channel::tick(config.flush_tick);
+
+    // See `ChunkBatcher::set_coarsening`.
+    let mut coarsening = 1_u32;
+    let mut flush_num_rows = config.flush_num_rows;
+    let mut flush_num_bytes = config.flush_num_bytes;
 
     struct Accumulator {
         latest: Instant,
@@ -612,10 +628,10 @@ fn batching_thread(config: ChunkBatcherConfig, rx_cmd: Receiver<Command>, tx_chu
                             config(&acc.pending_rows);
                         }
 
-                        if acc.pending_rows.len() as u64 >= config.flush_num_rows {
+                        if acc.pending_rows.len() as u64 >= flush_num_rows {
                             do_flush_all(acc, &tx_chunk, "rows", config.chunk_max_rows_if_unsorted);
                             skip_next_tick = true;
-                        } else if acc.pending_num_bytes >= config.flush_num_bytes {
+                        } else if acc.pending_num_bytes >= flush_num_bytes {
                             do_flush_all(acc, &tx_chunk, "bytes", config.chunk_max_rows_if_unsorted);
                             skip_next_tick = true;
                         }
@@ -629,6 +645,16 @@ fn batching_thread(config: ChunkBatcherConfig, rx_cmd: Receiver<Command>, tx_chu
                         drop(oneshot); // signals the oneshot
                     },
 
+                    Command::SetCoarsening(factor) => {
+                        if factor != coarsening {
+                            re_log::debug!("Coarsening batches by a factor of {factor}");
+                            coarsening = factor;
+                            flush_num_rows = config.flush_num_rows.saturating_mul(factor as _);
+                            flush_num_bytes = config.flush_num_bytes.saturating_mul(factor as _);
+                            rx_tick = crossbeam::channel::tick(config.flush_tick.saturating_mul(factor));
+                        }
+                    },
+
                     Command::Shutdown => break,
                 };
             },
@@ -1072,6 +1098,37 @@ mod tests {
         Ok(())
     }
 
+    /// Coarsening should scale the flush thresholds.
+    #[test]
+    fn coarsening() -> anyhow::Result<()> {
+        let batcher = ChunkBatcher::new(ChunkBatcherConfig {
+            flush_num_rows: 2,
+            ..ChunkBatcherConfig::NEVER
+        })?;
+        batcher.set_coarsening(2);
+
+        let timeline1 = Timeline::new_temporal("log_time");
+        let entity_path1: EntityPath = "a/b/c".into();
+
+        for time in 0..4 {
+            let points = MyPoint::to_arrow([MyPoint::new(1.0, 2.0)])?;
+            let row = PendingRow::new(
+                TimePoint::default().with(timeline1, time),
+                [(MyPoint::name(), points)].into(),
+            );
+            batcher.push_row(entity_path1.clone(), row);
+        }
+
+        let chunks_rx = batcher.chunks();
+        drop(batcher); // flush and close
+
+        let chunks = chunks_rx.iter().collect::<Vec<_>>();
+        assert_eq!(1, chunks.len());
+        assert_eq!(4, chunks[0].num_rows());
+
+        Ok(())
+    }
+
     /// A bunch of rows that don't fit any of the split conditions should end up together.
     #[test]
     fn simple_static() -> anyhow::Result<()> {
@@ -125,4 +125,48 @@ impl Chunk {
             chunk.row_sliced(start_index, end_index.saturating_sub(start_index))
         }
     }
+
+    /// Slices the [`Chunk`] vertically, keeping only the rows whose time on `timeline` falls
+    /// within `time_range` (inclusive).
+    ///
+    /// Unlike [`Self::range`], this doesn't look at any specific component: all columns are kept
+    /// as-is and no extra row is kept from before the start of `time_range`.
+    /// The resulting [`Chunk`] is sorted on `timeline`.
+    ///
+    /// Static chunks are returned as-is, since they are relevant at all times.
+    /// An empty [`Chunk`] is returned if this is a temporal chunk without any data on `timeline`.
+    pub fn time_range_sliced(&self, timeline: &Timeline, time_range: ResolvedTimeRange) -> Self {
+        if self.is_empty() || self.is_static() {
+            return self.clone();
+        }
+
+        re_tracing::profile_function!();
+
+        let Some(is_sorted_by_time) = self
+            .timelines
+            .get(timeline)
+            .map(|time_chunk| time_chunk.is_sorted())
+        else {
+            return self.emptied();
+        };
+
+        let chunk = if is_sorted_by_time {
+            self.clone()
+        } else {
+            self.sorted_by_timeline_if_unsorted(timeline)
+        };
+
+        let Some(times) = chunk
+            .timelines
+            .get(timeline)
+            .map(|time_chunk| time_chunk.times_raw())
+        else {
+            return chunk.emptied();
+        };
+
+        let start_index = times.partition_point(|&time| time < time_range.min().as_i64());
+        let end_index = times.partition_point(|&time| time <= time_range.max().as_i64());
+
+        chunk.row_sliced(start_index, end_index.saturating_sub(start_index))
+    }
 }
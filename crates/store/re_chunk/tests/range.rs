@@ -443,6 +443,96 @@ fn static_unsorted() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn time_range_sliced() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let row_id1 = RowId::new();
+    let row_id2 = RowId::new();
+    let row_id3 = RowId::new();
+
+    let timepoint1 = [
+        (Timeline::log_time(), 1000),
+        (Timeline::new_sequence("frame"), 1),
+    ];
+    let timepoint2 = [
+        (Timeline::log_time(), 1032),
+        (Timeline::new_sequence("frame"), 3),
+    ];
+    let timepoint3 = [
+        (Timeline::log_time(), 1064),
+        (Timeline::new_sequence("frame"), 5),
+    ];
+
+    let points1 = &[MyPoint::new(1.0, 1.0), MyPoint::new(2.0, 2.0)];
+    let points3 = &[MyPoint::new(3.0, 3.0)];
+    let colors2 = &[MyColor::from_rgb(1, 1, 1)];
+
+    // Unsorted on purpose.
+    let chunk = Chunk::builder(ENTITY_PATH.into())
+        .with_component_batches(row_id3, timepoint3, [points3 as _])
+        .with_component_batches(row_id2, timepoint2, [colors2 as _])
+        .with_component_batches(row_id1, timepoint1, [points1 as _])
+        .build()?;
+
+    {
+        let expected = Chunk::builder_with_id(chunk.id(), ENTITY_PATH.into())
+            .with_sparse_component_batches(
+                row_id2,
+                timepoint2,
+                [
+                    (MyPoint::name(), None),
+                    (MyColor::name(), Some(colors2 as _)),
+                ],
+            )
+            .with_sparse_component_batches(
+                row_id3,
+                timepoint3,
+                [
+                    (MyPoint::name(), Some(points3 as _)),
+                    (MyColor::name(), None),
+                ],
+            )
+            .build_with_datatypes(&datatypes())?;
+
+        let results = chunk.time_range_sliced(
+            &Timeline::new_sequence("frame"),
+            ResolvedTimeRange::new(2, 5),
+        );
+        eprintln!("Expected:\n{expected}");
+        eprintln!("Results:\n{results}");
+        assert_eq!(expected, results);
+    }
+
+    {
+        let results = chunk.time_range_sliced(
+            &Timeline::new_sequence("frame"),
+            ResolvedTimeRange::new(10, 20),
+        );
+        assert!(results.is_empty());
+
+        let results = chunk.time_range_sliced(
+            &Timeline::new_sequence("other"),
+            ResolvedTimeRange::EVERYTHING,
+        );
+        assert!(results.is_empty());
+    }
+
+    {
+        let chunk = Chunk::builder(ENTITY_PATH.into())
+            .with_component_batches(row_id1, TimePoint::default(), [points1 as _])
+            .build()?;
+
+        let results = chunk.time_range_sliced(
+            &Timeline::new_sequence("frame"),
+            ResolvedTimeRange::new(2, 5),
+        );
+        assert_eq!(chunk, results);
+    }
+
+    Ok(())
+}
+
 // ---
 
 fn query_and_compare(
@@ -0,0 +1,113 @@
+//! Reading back the chunks whose payload isn't kept in memory, see [`ChunkSource`].
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use re_chunk::{Chunk, ChunkId};
+
+use crate::{disk_cache::ChunkDiskCache, ChunkStoreResult};
+
+// ---
+
+/// Somewhere the chunks of a [`crate::ChunkStore`] can be read back from, e.g. the file they
+/// were loaded from.
+///
+/// Temporal chunks that the source [`ChunkSource::contains`] are only indexed when inserted
+/// into the store: their payload is dropped right away, and read back from the source whenever
+/// a query needs it. They are never compacted.
+///
+/// This means the chunks inserted into the store don't need to hold any actual data: a source
+/// may have the store index placeholders that only have the row ids, times and component
+/// validity of the real chunks, and component columns of [`arrow2::datatypes::DataType::Null`].
+/// Store subscribers see these placeholders rather than the real chunks.
+///
+/// See [`crate::ChunkStore::set_chunk_source`].
+pub trait ChunkSource: std::fmt::Debug + Send + Sync {
+    /// Can this source read back the given chunk?
+    fn contains(&self, chunk_id: &ChunkId) -> bool;
+
+    /// Reads back the given chunk, with all of its data.
+    ///
+    /// It must have the same rows as the chunk that was inserted into the store.
+    fn load(&self, chunk_id: &ChunkId) -> ChunkStoreResult<Chunk>;
+}
+
+/// Reads back a chunk that isn't in memory: from the chunk source if it has it, from the disk
+/// cache otherwise.
+///
+/// Returns `None` if there is nowhere to read it from.
+pub(crate) fn read_evicted_chunk(
+    chunk_source: Option<&Arc<dyn ChunkSource>>,
+    disk_cache: Option<&Arc<ChunkDiskCache>>,
+    chunk_id: &ChunkId,
+) -> Option<ChunkStoreResult<Arc<Chunk>>> {
+    if let Some(chunk_source) = chunk_source.filter(|source| source.contains(chunk_id)) {
+        return Some(chunk_source.load(chunk_id).map(Arc::new));
+    }
+
+    disk_cache.map(|disk_cache| disk_cache.read(*chunk_id))
+}
+
+// ---
+
+/// The chunks that were recently read back from a [`ChunkSource`] or the disk cache, so that
+/// queries touching the same chunks frame after frame don't have to read them every time.
+///
+/// The least recently used chunks are dropped first once [`Self::MAX_BYTES`] is exceeded.
+#[derive(Debug, Default)]
+pub(crate) struct PagedInChunks {
+    chunks: BTreeMap<ChunkId, (Arc<Chunk>, u64)>,
+
+    /// When each chunk was last used, oldest first.
+    last_used: BTreeMap<u64, ChunkId>,
+
+    total_size_bytes: u64,
+    tick: u64,
+}
+
+impl PagedInChunks {
+    pub const MAX_BYTES: u64 = 512 * 1024 * 1024;
+
+    pub fn get(&mut self, chunk_id: &ChunkId) -> Option<Arc<Chunk>> {
+        let (chunk, last_used) = self.chunks.get_mut(chunk_id)?;
+
+        self.tick += 1;
+        self.last_used.remove(last_used);
+        self.last_used.insert(self.tick, *chunk_id);
+        *last_used = self.tick;
+
+        Some(Arc::clone(chunk))
+    }
+
+    pub fn insert(&mut self, chunk: Arc<Chunk>) {
+        self.remove(&chunk.id());
+
+        self.tick += 1;
+        self.total_size_bytes += chunk_size_bytes(&chunk);
+        self.last_used.insert(self.tick, chunk.id());
+        self.chunks.insert(chunk.id(), (chunk, self.tick));
+
+        // Always keep the chunk that was just read, no matter how large.
+        while Self::MAX_BYTES < self.total_size_bytes && 1 < self.chunks.len() {
+            let Some((_, chunk_id)) = self.last_used.pop_first() else {
+                break;
+            };
+            if let Some((chunk, _)) = self.chunks.remove(&chunk_id) {
+                self.total_size_bytes -= chunk_size_bytes(&chunk);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, chunk_id: &ChunkId) {
+        if let Some((chunk, last_used)) = self.chunks.remove(chunk_id) {
+            self.last_used.remove(&last_used);
+            self.total_size_bytes -= chunk_size_bytes(&chunk);
+        }
+    }
+}
+
+fn chunk_size_bytes(chunk: &Arc<Chunk>) -> u64 {
+    // NOTE: Do _NOT_ use `chunk.total_size_bytes` as it is sitting behind an Arc
+    // and would count as amortized (i.e. 0 bytes).
+    <Chunk as re_types_core::SizeBytes>::total_size_bytes(chunk)
+}
@@ -0,0 +1,103 @@
+//! Keeping the chunks that were evicted from memory on disk, see
+//! [`crate::ChunkStoreConfig::disk_cache_dir`].
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use arrow2::io::ipc::{read, write};
+
+use re_chunk::{Chunk, ChunkId, TransportChunk};
+use re_log_types::StoreId;
+
+use crate::{ChunkStoreError, ChunkStoreResult};
+
+// ---
+
+/// The chunks of one [`crate::ChunkStore`] that were evicted from memory, one Arrow IPC file per
+/// chunk.
+///
+/// The directory is private to this cache, and deleted once the cache is dropped: it is only
+/// meant to outlive memory, not the store.
+#[derive(Debug)]
+pub(crate) struct ChunkDiskCache {
+    dir: PathBuf,
+}
+
+impl ChunkDiskCache {
+    /// Creates a new, empty cache for `store_id` somewhere in `root_dir`.
+    pub fn new(root_dir: &Path, store_id: &StoreId) -> ChunkStoreResult<Self> {
+        // Several stores with the same id can coexist, e.g. in different viewers.
+        static NUM_CACHES: AtomicU64 = AtomicU64::new(0);
+
+        let store_id: String = store_id
+            .id
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let dir = root_dir.join(format!(
+            "{store_id}-{}-{}",
+            std::process::id(),
+            NUM_CACHES.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&dir)?;
+
+        re_log::debug!("Evicting chunks of {store_id} to {dir:?}");
+
+        Ok(Self { dir })
+    }
+
+    fn path(&self, chunk_id: ChunkId) -> PathBuf {
+        self.dir.join(format!("{chunk_id}.arrow"))
+    }
+
+    pub fn write(&self, chunk: &Chunk) -> ChunkStoreResult<()> {
+        re_tracing::profile_function!();
+
+        let TransportChunk { schema, data } = chunk.to_transport()?;
+
+        let file = std::fs::File::create(self.path(chunk.id()))?;
+        let mut writer = write::FileWriter::try_new(
+            std::io::BufWriter::new(file),
+            schema,
+            None,
+            write::WriteOptions { compression: None },
+        )?;
+        writer.write(&data, None)?;
+        writer.finish()?;
+
+        Ok(())
+    }
+
+    pub fn read(&self, chunk_id: ChunkId) -> ChunkStoreResult<Arc<Chunk>> {
+        re_tracing::profile_function!();
+
+        let mut file = std::io::BufReader::new(std::fs::File::open(self.path(chunk_id))?);
+        let metadata = read::read_file_metadata(&mut file)?;
+        let schema = metadata.schema.clone();
+        let data = read::FileReader::new(file, metadata, None, None)
+            .next()
+            .ok_or_else(|| {
+                ChunkStoreError::Io(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    format!("no data for chunk {chunk_id} in the disk cache"),
+                ))
+            })??;
+
+        Ok(Arc::new(Chunk::from_transport(&TransportChunk {
+            schema,
+            data,
+        })?))
+    }
+}
+
+impl Drop for ChunkDiskCache {
+    fn drop(&mut self) {
+        if let Err(err) = std::fs::remove_dir_all(&self.dir) {
+            re_log::warn!(
+                "Failed to clean up the chunk disk cache at {:?}: {err}",
+                self.dir
+            );
+        }
+    }
+}
@@ -0,0 +1,497 @@
+//! Export the contents of a [`ChunkStore`] as plain Arrow tables, readable by the rest of the
+//! Arrow ecosystem (pandas, polars, DuckDB, etc).
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use arrow2::{
+    array::{
+        new_null_array, Array as ArrowArray, ListArray as ArrowListArray,
+        PrimitiveArray as ArrowPrimitiveArray, StructArray as ArrowStructArray,
+    },
+    chunk::Chunk as ArrowChunk,
+    compute::{concatenate::concatenate, take::take},
+    datatypes::{DataType as ArrowDatatype, Field as ArrowField, Schema as ArrowSchema},
+};
+
+use re_chunk::{Chunk, RowId, TransportChunk};
+use re_log_types::{EntityPath, Timeline};
+use re_types_core::{ComponentName, Loggable as _};
+
+use crate::{ChunkStore, ChunkStoreResult};
+
+// ---
+
+/// All the data of a single entity, as one flat Arrow table.
+///
+/// Every row of every chunk of the entity ends up as a row in this table, with the following
+/// columns:
+/// * the [`RowId`],
+/// * one column per timeline, which is null for rows that weren't logged on that timeline
+///   (e.g. static data),
+/// * one column per component, which is null for rows that don't hold data for that component.
+///
+/// Obtained by calling [`ChunkStore::entity_tables`].
+/// See [`Self::flattened`] for a version that is easier to consume from dataframe libraries.
+#[derive(Debug)]
+pub struct EntityTable {
+    /// The schema of the table.
+    ///
+    /// Uses the same chunk-level and field-level metadata as [`TransportChunk`].
+    pub schema: ArrowSchema,
+
+    /// All the control, time and component data.
+    pub data: ArrowChunk<Box<dyn ArrowArray>>,
+}
+
+impl std::fmt::Display for EntityTable {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        re_format_arrow::format_dataframe(
+            &self.schema.metadata,
+            &self.schema.fields,
+            self.data.iter().map(|array| &**array),
+        )
+        .fmt(f)
+    }
+}
+
+impl EntityTable {
+    /// Concatenates all the given chunks into a single table.
+    ///
+    /// All chunks must belong to `entity_path`.
+    pub fn from_chunks(entity_path: &EntityPath, chunks: &[Arc<Chunk>]) -> ChunkStoreResult<Self> {
+        re_tracing::profile_function!(entity_path.to_string());
+
+        let timelines: BTreeSet<Timeline> = chunks
+            .iter()
+            .flat_map(|chunk| chunk.timelines().keys().copied())
+            .collect();
+
+        let components: BTreeMap<ComponentName, ArrowDatatype> = chunks
+            .iter()
+            .flat_map(|chunk| {
+                chunk
+                    .components()
+                    .iter()
+                    .map(|(component_name, list_array)| {
+                        (*component_name, list_array.data_type().clone())
+                    })
+            })
+            .collect();
+
+        let mut schema = ArrowSchema::default();
+        schema
+            .metadata
+            .extend(TransportChunk::chunk_metadata_entity_path(entity_path));
+
+        schema.fields.push(
+            ArrowField::new(RowId::name().to_string(), RowId::arrow_datatype(), false)
+                .with_metadata(TransportChunk::field_metadata_control_column()),
+        );
+        schema.fields.extend(timelines.iter().map(|timeline| {
+            // Rows that weren't logged on this timeline (e.g. static data) are null.
+            ArrowField::new(timeline.name().to_string(), timeline.datatype(), true)
+                .with_metadata(TransportChunk::field_metadata_time_column())
+        }));
+        schema
+            .fields
+            .extend(components.iter().map(|(component_name, datatype)| {
+                ArrowField::new(component_name.to_string(), datatype.clone(), true)
+                    .with_metadata(TransportChunk::field_metadata_data_column())
+            }));
+
+        let transports: Vec<TransportChunk> = chunks
+            .iter()
+            .map(|chunk| chunk.to_transport())
+            .collect::<Result<_, _>>()?;
+
+        let columns = schema
+            .fields
+            .iter()
+            .map(|field| {
+                let arrays = transports
+                    .iter()
+                    .map(|transport| {
+                        itertools::izip!(&transport.schema.fields, transport.data.columns())
+                            .find_map(|(chunk_field, array)| {
+                                (chunk_field.name == field.name).then(|| array.clone())
+                            })
+                            .unwrap_or_else(|| {
+                                new_null_array(field.data_type().clone(), transport.num_rows())
+                            })
+                    })
+                    .collect::<Vec<_>>();
+
+                let arrays = arrays.iter().map(|array| &**array).collect::<Vec<_>>();
+                if arrays.is_empty() {
+                    Ok(new_null_array(field.data_type().clone(), 0))
+                } else {
+                    concatenate(&arrays)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            schema,
+            data: ArrowChunk::try_new(columns)?,
+        })
+    }
+
+    /// Returns a copy of the table where the component columns are flattened, which makes them
+    /// much easier to work with in dataframe libraries:
+    /// * Components that hold at most one instance on every row are unwrapped from their list,
+    ///   i.e. a `List<Struct<x, y>>` column becomes a `Struct<x, y>` column.
+    /// * Unwrapped struct components are then split into one column per field, named
+    ///   `<component>.<field>`, e.g. `rerun.components.Position2D.x`.
+    ///
+    /// Components that hold more than one instance on any row stay list columns.
+    /// The `RowId` and time columns are left untouched.
+    pub fn flattened(&self) -> Self {
+        re_tracing::profile_function!();
+
+        let mut fields = Vec::with_capacity(self.schema.fields.len());
+        let mut columns = Vec::with_capacity(self.schema.fields.len());
+
+        for (field, column) in itertools::izip!(&self.schema.fields, self.data.columns()) {
+            let is_component = field
+                .metadata
+                .get(TransportChunk::FIELD_METADATA_KEY_KIND)
+                .map(String::as_str)
+                == Some(TransportChunk::FIELD_METADATA_VALUE_KIND_DATA);
+
+            let unwrapped = if is_component {
+                unwrap_mono_list(&**column)
+            } else {
+                None
+            };
+            let Some(unwrapped) = unwrapped else {
+                fields.push(field.clone());
+                columns.push(column.clone());
+                continue;
+            };
+
+            if let Some(struct_array) = unwrapped.as_any().downcast_ref::<ArrowStructArray>() {
+                for (child_field, child) in
+                    itertools::izip!(struct_array.fields(), struct_array.values())
+                {
+                    // A null struct means that all of its fields are null.
+                    let validity = match (struct_array.validity(), child.validity()) {
+                        (Some(outer), Some(inner)) => Some(outer & inner),
+                        (Some(validity), None) | (None, Some(validity)) => Some(validity.clone()),
+                        (None, None) => None,
+                    };
+
+                    fields.push(
+                        ArrowField::new(
+                            format!("{}.{}", field.name, child_field.name),
+                            child_field.data_type().clone(),
+                            true,
+                        )
+                        .with_metadata(field.metadata.clone()),
+                    );
+                    columns.push(child.with_validity(validity));
+                }
+            } else {
+                fields.push(
+                    ArrowField::new(field.name.clone(), unwrapped.data_type().clone(), true)
+                        .with_metadata(field.metadata.clone()),
+                );
+                columns.push(unwrapped);
+            }
+        }
+
+        Self {
+            schema: ArrowSchema {
+                fields,
+                metadata: self.schema.metadata.clone(),
+            },
+            // Can't fail: all the columns are derived from columns of the same length.
+            data: ArrowChunk::new(columns),
+        }
+    }
+
+    /// Writes the table in the given [`ExportFormat`].
+    pub fn write(&self, format: ExportFormat, writer: impl std::io::Write) -> ChunkStoreResult<()> {
+        match format {
+            ExportFormat::ArrowIpc => self.write_arrow_ipc(writer),
+            ExportFormat::Parquet => self.write_parquet(writer),
+        }
+    }
+
+    /// Writes the table as an [Arrow IPC file](https://arrow.apache.org/docs/format/Columnar.html#ipc-file-format).
+    pub fn write_arrow_ipc(&self, writer: impl std::io::Write) -> ChunkStoreResult<()> {
+        re_tracing::profile_function!();
+
+        use arrow2::io::ipc::write::{FileWriter, WriteOptions};
+
+        let mut writer = FileWriter::try_new(
+            writer,
+            self.schema.clone(),
+            None,
+            WriteOptions { compression: None },
+        )?;
+        writer.write(&self.data, None)?;
+        writer.finish()?;
+
+        Ok(())
+    }
+
+    /// Writes the table as a snappy-compressed [Parquet file](https://parquet.apache.org/).
+    ///
+    /// Parquet has no equivalent for Arrow unions: columns that contain one are skipped.
+    pub fn write_parquet(&self, writer: impl std::io::Write) -> ChunkStoreResult<()> {
+        re_tracing::profile_function!();
+
+        use arrow2::io::parquet::write::{
+            transverse, CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version,
+            WriteOptions,
+        };
+
+        let (fields, columns): (Vec<_>, Vec<_>) =
+            itertools::izip!(&self.schema.fields, self.data.columns())
+                .filter(|(field, _)| {
+                    let has_union = contains_union(field.data_type());
+                    if has_union {
+                        re_log::warn_once!(
+                            "Column {:?} contains a union, which Parquet cannot represent: skipped",
+                            field.name
+                        );
+                    }
+                    !has_union
+                })
+                .map(|(field, column)| (field.clone(), column.clone()))
+                .unzip();
+
+        let schema = ArrowSchema {
+            fields,
+            metadata: self.schema.metadata.clone(),
+        };
+
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: CompressionOptions::Snappy,
+            version: Version::V2,
+            data_pagesize_limit: None,
+        };
+
+        let encodings = schema
+            .fields
+            .iter()
+            .map(|field| transverse(field.data_type(), |_| Encoding::Plain))
+            .collect();
+
+        let row_groups = RowGroupIterator::try_new(
+            std::iter::once(Ok(ArrowChunk::try_new(columns)?)),
+            &schema,
+            options,
+            encodings,
+        )?;
+
+        let mut writer = FileWriter::try_new(writer, schema.clone(), options)?;
+        for row_group in row_groups {
+            writer.write(row_group?)?;
+        }
+        writer.end(None)?;
+
+        Ok(())
+    }
+}
+
+/// If every row of `array` holds at most one instance, returns the instances as a flat array with
+/// one entry per row (null for rows without an instance).
+///
+/// Returns `None` if `array` is not a list array or if any of its rows holds several instances.
+fn unwrap_mono_list(array: &dyn ArrowArray) -> Option<Box<dyn ArrowArray>> {
+    let list_array = array.as_any().downcast_ref::<ArrowListArray<i32>>()?;
+
+    let offsets = list_array.offsets().buffer();
+    if offsets.windows(2).any(|w| w[1] - w[0] > 1) {
+        return None;
+    }
+
+    let indices: ArrowPrimitiveArray<i32> = offsets
+        .windows(2)
+        .enumerate()
+        .map(|(row, w)| (list_array.is_valid(row) && w[1] > w[0]).then_some(w[0]))
+        .collect();
+
+    take(list_array.values().as_ref(), &indices).ok()
+}
+
+/// Whether `datatype` is, or contains, a union.
+fn contains_union(datatype: &ArrowDatatype) -> bool {
+    match datatype.to_logical_type() {
+        ArrowDatatype::Union(..) => true,
+        ArrowDatatype::List(field)
+        | ArrowDatatype::LargeList(field)
+        | ArrowDatatype::FixedSizeList(field, _)
+        | ArrowDatatype::Map(field, _) => contains_union(field.data_type()),
+        ArrowDatatype::Struct(fields) => {
+            fields.iter().any(|field| contains_union(field.data_type()))
+        }
+        _ => false,
+    }
+}
+
+/// The file format used by [`ChunkStore::export`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// [Arrow IPC files](https://arrow.apache.org/docs/format/Columnar.html#ipc-file-format),
+    /// with the `.arrow` extension.
+    #[default]
+    ArrowIpc,
+
+    /// [Parquet files](https://parquet.apache.org/), with the `.parquet` extension.
+    Parquet,
+}
+
+impl ExportFormat {
+    /// The file extension used for this format, without the leading dot.
+    #[inline]
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::ArrowIpc => "arrow",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+/// Options for [`ChunkStore::export`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// The format of the files to write.
+    pub format: ExportFormat,
+
+    /// Whether to flatten the component columns, see [`EntityTable::flattened`].
+    pub flatten: bool,
+}
+
+impl Default for ExportOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            format: ExportFormat::default(),
+            flatten: true,
+        }
+    }
+}
+
+impl ChunkStore {
+    /// Returns the contents of the store as one [`EntityTable`] per entity.
+    pub fn entity_tables(&self) -> ChunkStoreResult<BTreeMap<EntityPath, EntityTable>> {
+        re_tracing::profile_function!();
+
+        let mut chunks_per_entity: BTreeMap<EntityPath, Vec<Arc<Chunk>>> = BTreeMap::new();
+        for chunk in self.iter_chunks_including_evicted() {
+            chunks_per_entity
+                .entry(chunk.entity_path().clone())
+                .or_default()
+                .push(chunk);
+        }
+
+        chunks_per_entity
+            .into_iter()
+            .map(|(entity_path, chunks)| {
+                let table = EntityTable::from_chunks(&entity_path, &chunks)?;
+                Ok((entity_path, table))
+            })
+            .collect()
+    }
+
+    /// Writes the contents of the store to `dir` as one file per entity.
+    ///
+    /// The directory layout mirrors the entity hierarchy, e.g. the data for `/world/points` ends
+    /// up in `<dir>/world/points.arrow`.
+    /// The data for the root entity, if any, ends up in `<dir>/__root.arrow`.
+    ///
+    /// Entity path parts that aren't valid file names get sanitized. If that makes two entities
+    /// end up with the same file name (e.g. `/a b` and `/a_b`), all but the first one get a `~N`
+    /// suffix (e.g. `a_b~1.arrow`), so that no export ever overwrites another.
+    ///
+    /// Returns the path of the file that was written for each entity.
+    pub fn export(
+        &self,
+        dir: &Path,
+        options: &ExportOptions,
+    ) -> ChunkStoreResult<BTreeMap<EntityPath, PathBuf>> {
+        re_tracing::profile_function!();
+
+        let ExportOptions { format, flatten } = *options;
+
+        let mut paths = BTreeMap::new();
+        let mut used_paths = HashSet::new();
+
+        for (entity_path, table) in self.entity_tables()? {
+            let relative_path = entity_path_to_relative_path(&entity_path);
+
+            let mut suffix = 0;
+            let path = loop {
+                // NOTE: Not `with_extension`: entity names can contain dots.
+                let mut path = dir.join(&relative_path).into_os_string();
+                if suffix > 0 {
+                    path.push(format!("~{suffix}"));
+                }
+                path.push(".");
+                path.push(format.file_extension());
+                let path = PathBuf::from(path);
+
+                // NOTE: Compare case-insensitively, as many file systems are.
+                if used_paths.insert(path.to_string_lossy().to_lowercase()) {
+                    break path;
+                }
+                suffix += 1;
+            };
+
+            if suffix > 0 {
+                re_log::warn!(
+                    "{entity_path} collides with another entity once turned into a file name, \
+                     exporting it to {path:?} instead"
+                );
+            }
+
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            let table = if flatten { table.flattened() } else { table };
+
+            let file = std::fs::File::create(&path)?;
+            table.write(format, std::io::BufWriter::new(file))?;
+
+            paths.insert(entity_path, path);
+        }
+
+        Ok(paths)
+    }
+}
+
+/// Turns an entity path into a relative file path (without extension), making sure that every
+/// part is a valid file name on all platforms.
+fn entity_path_to_relative_path(entity_path: &EntityPath) -> PathBuf {
+    if entity_path.is_root() {
+        return PathBuf::from("__root");
+    }
+
+    entity_path
+        .iter()
+        .map(|part| {
+            let part = part.unescaped_str();
+            if part.chars().all(|c| c == '.') {
+                // Don't let `.` and `..` escape the output directory.
+                return "_".repeat(part.len());
+            }
+
+            part.chars()
+                .map(|c| {
+                    if c.is_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect()
+}
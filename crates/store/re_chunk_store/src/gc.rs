@@ -1,5 +1,6 @@
 use std::{
     collections::{btree_map::Entry as BTreeMapEntry, BTreeSet},
+    sync::Arc,
     time::Duration,
 };
 
@@ -8,12 +9,12 @@ use nohash_hasher::IntMap;
 use web_time::Instant;
 
 use re_chunk::{Chunk, ChunkId};
-use re_log_types::{EntityPath, TimeInt, Timeline};
+use re_log_types::{EntityPath, StoreKind, TimeInt, Timeline};
 use re_types_core::{ComponentName, SizeBytes};
 
 use crate::{
-    store::ChunkIdSetPerTime, ChunkStore, ChunkStoreChunkStats, ChunkStoreDiff, ChunkStoreDiffKind,
-    ChunkStoreEvent, ChunkStoreStats,
+    disk_cache::ChunkDiskCache, store::ChunkIdSetPerTime, ChunkStore, ChunkStoreChunkStats,
+    ChunkStoreDiff, ChunkStoreDiffKind, ChunkStoreEvent, ChunkStoreResult, ChunkStoreStats,
 };
 
 // Used all over in docstrings.
@@ -94,6 +95,9 @@ impl ChunkStore {
     /// store's internal references to that data (the `Chunk`s), which will be deallocated once
     /// their reference count reaches 0.
     ///
+    /// If [`crate::ChunkStoreConfig::disk_cache_dir`] is set, temporal chunks are evicted to disk
+    /// instead of being dropped. They stay part of the store, so no events are emitted for them.
+    ///
     /// ## Limitations
     ///
     /// The garbage collector has limited support for latest-at semantics. The configuration option:
@@ -253,6 +257,17 @@ impl ChunkStore {
     ) -> Vec<ChunkStoreDiff> {
         re_tracing::profile_function!(re_format::format_bytes(num_bytes_to_drop));
 
+        if self.config.disk_cache_dir.is_some() && self.id.kind == StoreKind::Recording {
+            match self.evict_at_least_num_bytes(options, num_bytes_to_drop, protected_chunk_ids) {
+                Ok(()) => return Vec::new(),
+                Err(err) => {
+                    re_log::warn_once!(
+                        "Failed to evict chunks to disk, dropping them instead: {err}"
+                    );
+                }
+            }
+        }
+
         let mut chunk_ids_to_be_removed =
             RemovableChunkIdPerTimePerComponentPerTimelinePerEntity::default();
         let mut chunk_ids_dangling = HashSet::default();
@@ -299,7 +314,7 @@ impl ChunkStore {
                             }
                         }
                     }
-                } else {
+                } else if !self.evicted_chunks.contains_key(chunk_id) {
                     chunk_ids_dangling.insert(*chunk_id);
                 }
 
@@ -326,6 +341,11 @@ impl ChunkStore {
                 temporal_chunks_stats: _,
                 static_chunk_ids_per_entity: _, // we don't GC static data
                 static_chunks_stats: _,         // we don't GC static data
+                disk_cache: _,
+                chunk_source: _,
+                evicted_chunks: _, // evicted chunks don't take any memory
+                paged_in_chunks: _,
+                evicted_chunks_stats: _,
                 insert_id: _,
                 query_id: _,
                 gc_id: _,
@@ -429,6 +449,88 @@ impl ChunkStore {
         }
     }
 
+    /// Moves the oldest temporal chunks to the disk cache, until `num_bytes_to_evict` were freed
+    /// or the time budget is exhausted.
+    ///
+    /// The chunks stay in all indices, so that queries can read them back as needed.
+    fn evict_at_least_num_bytes(
+        &mut self,
+        options: &GarbageCollectionOptions,
+        mut num_bytes_to_evict: f64,
+        protected_chunk_ids: &BTreeSet<ChunkId>,
+    ) -> ChunkStoreResult<()> {
+        re_tracing::profile_function!(re_format::format_bytes(num_bytes_to_evict));
+
+        let start_time = Instant::now();
+
+        let disk_cache = if let Some(disk_cache) = &self.disk_cache {
+            Arc::clone(disk_cache)
+        } else {
+            let Some(dir) = &self.config.disk_cache_dir else {
+                return Ok(());
+            };
+            let disk_cache = Arc::new(ChunkDiskCache::new(dir, &self.id)?);
+            self.disk_cache = Some(Arc::clone(&disk_cache));
+            disk_cache
+        };
+
+        let chunks_to_evict = {
+            re_tracing::profile_scope!("mark");
+
+            let mut chunks_to_evict = Vec::new();
+            for chunk_id in self
+                .chunk_ids_per_min_row_id
+                .values()
+                .flatten()
+                .filter(|chunk_id| !protected_chunk_ids.contains(chunk_id))
+            {
+                if num_bytes_to_evict <= 0.0 {
+                    break;
+                }
+
+                // Evicted chunks aren't in there anymore.
+                let Some(chunk) = self.chunks_per_chunk_id.get(chunk_id) else {
+                    continue;
+                };
+                if chunk.is_static() {
+                    continue;
+                }
+
+                // NOTE: Do _NOT_ use `chunk.total_size_bytes` as it is sitting behind an Arc
+                // and would count as amortized (i.e. 0 bytes).
+                num_bytes_to_evict -= <Chunk as SizeBytes>::total_size_bytes(chunk) as f64;
+                chunks_to_evict.push(Arc::clone(chunk));
+            }
+
+            chunks_to_evict
+        };
+
+        re_tracing::profile_scope!("evict");
+
+        for chunk in chunks_to_evict {
+            if start_time.elapsed() >= options.time_budget {
+                break;
+            }
+
+            // No need to write what the source can read back already.
+            let is_in_source = self
+                .chunk_source
+                .as_ref()
+                .is_some_and(|chunk_source| chunk_source.contains(&chunk.id()));
+            if !is_in_source {
+                disk_cache.write(&chunk)?;
+            }
+
+            self.chunks_per_chunk_id.remove(&chunk.id());
+            let chunk_stats = ChunkStoreChunkStats::from_chunk(&chunk);
+            self.evicted_chunks.insert(chunk.id(), chunk_stats);
+            self.temporal_chunks_stats -= chunk_stats;
+            self.evicted_chunks_stats += chunk_stats;
+        }
+
+        Ok(())
+    }
+
     /// Surgically removes a _temporal_ [`ChunkId`] from all indices.
     ///
     /// This is orders of magnitude faster than trying to `retain()` on all our internal indices.
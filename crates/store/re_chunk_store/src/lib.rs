@@ -9,12 +9,20 @@
 //! * See [`ChunkStore::latest_at_relevant_chunks`] and [`ChunkStore::range_relevant_chunks`]
 //!   for the documentation of the public read APIs.
 //! * See [`ChunkStore::insert_chunk`] for the documentation of the public write APIs.
+//! * With the `export` feature, see `ChunkStore::entity_tables` and `ChunkStore::export` for
+//!   exporting the contents of the store as plain Arrow tables, or as Arrow IPC and Parquet files.
+//! * See [`ChunkStoreConfig::disk_cache_dir`] and [`ChunkSource`] for keeping recordings that are
+//!   larger than RAM.
 //!
 //! ## Feature flags
 #![doc = document_features::document_features!()]
 //!
 
+mod chunk_source;
+mod disk_cache;
 mod events;
+#[cfg(feature = "export")]
+mod export;
 mod gc;
 mod query;
 mod stats;
@@ -22,9 +30,14 @@ mod store;
 mod subscribers;
 mod writes;
 
+pub use self::chunk_source::ChunkSource;
 pub use self::events::{ChunkStoreDiff, ChunkStoreDiffKind, ChunkStoreEvent};
+#[cfg(feature = "export")]
+pub use self::export::{EntityTable, ExportFormat, ExportOptions};
 pub use self::gc::{GarbageCollectionOptions, GarbageCollectionTarget};
-pub use self::stats::{ChunkStoreChunkStats, ChunkStoreStats};
+pub use self::stats::{
+    ChunkStoreChunkStats, ChunkStoreEntityStats, ChunkStoreStats, ChunkStoreTimelineStats,
+};
 pub use self::store::{ChunkStore, ChunkStoreConfig, ChunkStoreGeneration};
 pub use self::subscribers::{ChunkStoreSubscriber, ChunkStoreSubscriberHandle};
 
@@ -48,6 +61,19 @@ pub enum ChunkStoreError {
     #[error(transparent)]
     Chunk(#[from] re_chunk::ChunkError),
 
+    #[error(transparent)]
+    Arrow(#[from] arrow2::error::Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// A [`ChunkSource`] failed to read back a chunk.
+    #[error("Failed to read back chunk {chunk_id}: {err}")]
+    ChunkSource {
+        chunk_id: re_chunk::ChunkId,
+        err: Box<dyn std::error::Error + Send + Sync>,
+    },
+
     /// Error when parsing configuration from environment.
     #[error("Failed to parse config: '{name}={value}': {err}")]
     ParseConfig {
@@ -247,7 +247,7 @@ impl ChunkStore {
         Some(
             temporal_chunk_ids
                 .iter()
-                .filter_map(|chunk_id| self.chunks_per_chunk_id.get(chunk_id).cloned())
+                .filter_map(|chunk_id| self.chunk(chunk_id))
                 .collect(),
         )
     }
@@ -408,7 +408,7 @@ impl ChunkStore {
             .flat_map(|temporal_chunk_ids| {
                 temporal_chunk_ids
                     .iter()
-                    .filter_map(|chunk_id| self.chunks_per_chunk_id.get(chunk_id).cloned())
+                    .filter_map(|chunk_id| self.chunk(chunk_id))
             })
             .collect()
     }
@@ -1,6 +1,8 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
-use re_chunk::Chunk;
+use re_chunk::{ArrowArray as _, Chunk, ComponentName};
+use re_log_types::{EntityPath, ResolvedTimeRange, Timeline};
 use re_types_core::SizeBytes;
 
 use crate::ChunkStore;
@@ -164,3 +166,97 @@ impl ChunkStoreChunkStats {
         }
     }
 }
+
+// ---
+
+/// Statistics about the data of a single entity in a [`ChunkStore`].
+///
+/// See [`ChunkStore::stats_per_entity`].
+#[derive(Default, Debug, Clone)]
+pub struct ChunkStoreEntityStats {
+    /// Stats for all the chunks of this entity.
+    pub chunks: ChunkStoreStats,
+
+    /// Stats for each component of this entity.
+    ///
+    /// Sizes only account for the component's own column, and rows are only counted when
+    /// they hold data for that component.
+    pub per_component: BTreeMap<ComponentName, ChunkStoreStats>,
+
+    /// Stats for each timeline this entity has temporal data on.
+    pub per_timeline: BTreeMap<Timeline, ChunkStoreTimelineStats>,
+}
+
+/// Statistics about the temporal chunks of an entity that live on a given [`Timeline`].
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkStoreTimelineStats {
+    pub chunks: ChunkStoreChunkStats,
+
+    /// The time range covered by all these chunks on that timeline.
+    pub time_range: ResolvedTimeRange,
+}
+
+impl ChunkStoreEntityStats {
+    fn add_chunk(&mut self, chunk: &Arc<Chunk>) {
+        let chunk_stats = ChunkStoreChunkStats::from_chunk(chunk);
+        let split = |stats: ChunkStoreChunkStats| {
+            if chunk.is_static() {
+                ChunkStoreStats {
+                    static_chunks: stats,
+                    temporal_chunks: Default::default(),
+                }
+            } else {
+                ChunkStoreStats {
+                    static_chunks: Default::default(),
+                    temporal_chunks: stats,
+                }
+            }
+        };
+
+        self.chunks = self.chunks + split(chunk_stats);
+
+        for (component_name, list_array) in chunk.components() {
+            let component_stats = ChunkStoreChunkStats {
+                num_chunks: 1,
+                total_size_bytes: list_array.total_size_bytes(),
+                total_num_rows: (list_array.len() - list_array.null_count()) as u64,
+            };
+            let entry = self.per_component.entry(*component_name).or_default();
+            *entry = *entry + split(component_stats);
+        }
+
+        for (timeline, time_chunk) in chunk.timelines() {
+            self.per_timeline
+                .entry(*timeline)
+                .and_modify(|stats| {
+                    stats.chunks += chunk_stats;
+                    stats.time_range = stats.time_range.union(time_chunk.time_range());
+                })
+                .or_insert(ChunkStoreTimelineStats {
+                    chunks: chunk_stats,
+                    time_range: time_chunk.time_range(),
+                });
+        }
+    }
+}
+
+impl ChunkStore {
+    /// Computes detailed statistics for every entity in the store, broken down per component
+    /// and per timeline.
+    ///
+    /// This walks over every chunk in the store: it is meant for offline inspection, not for
+    /// being called every frame.
+    pub fn stats_per_entity(&self) -> BTreeMap<EntityPath, ChunkStoreEntityStats> {
+        re_tracing::profile_function!();
+
+        let mut stats_per_entity: BTreeMap<EntityPath, ChunkStoreEntityStats> = BTreeMap::new();
+        for chunk in self.iter_chunks_including_evicted() {
+            stats_per_entity
+                .entry(chunk.entity_path().clone())
+                .or_default()
+                .add_chunk(&chunk);
+        }
+
+        stats_per_entity
+    }
+}
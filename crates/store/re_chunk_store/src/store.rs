@@ -1,4 +1,5 @@
 use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
@@ -9,7 +10,11 @@ use re_chunk::{Chunk, ChunkId, RowId};
 use re_log_types::{EntityPath, StoreId, TimeInt, Timeline};
 use re_types_core::ComponentName;
 
-use crate::{ChunkStoreChunkStats, ChunkStoreError, ChunkStoreResult};
+use crate::{
+    chunk_source::{read_evicted_chunk, PagedInChunks},
+    disk_cache::ChunkDiskCache,
+    ChunkSource, ChunkStoreChunkStats, ChunkStoreError, ChunkStoreResult,
+};
 
 // ---
 
@@ -72,6 +77,17 @@ pub struct ChunkStoreConfig {
     /// The default byte threshold is set to 8MiB, which is a reasonable unit of work when e.g.
     /// sending chunks over the network.
     pub chunk_max_rows_if_unsorted: u64,
+
+    /// If set, garbage collection evicts temporal chunks to a cache in this directory instead of
+    /// dropping them, and queries read them back as needed.
+    ///
+    /// This makes it possible to scrub through recordings that are larger than RAM, at the cost
+    /// of slower queries over the evicted parts.
+    /// Only the chunks are evicted: all indices stay in memory.
+    ///
+    /// Each store gets its own sub-directory, which is deleted when the store is dropped.
+    /// Only applies to recordings, not blueprints.
+    pub disk_cache_dir: Option<PathBuf>,
     //
     // TODO(cmc): It could make sense to have time-range-based thresholds in here, since the time
     // range covered by a chunk has direct effects on A) the complexity of backward walks and
@@ -105,6 +121,8 @@ impl ChunkStoreConfig {
         chunk_max_rows: 1024,
 
         chunk_max_rows_if_unsorted: 256,
+
+        disk_cache_dir: None,
     };
 
     /// [`Self::DEFAULT`], but with compaction entirely disabled.
@@ -129,6 +147,9 @@ impl ChunkStoreConfig {
     // NOTE: Shared with the same env-var on the batcher side, for consistency.
     pub const ENV_CHUNK_MAX_ROWS_IF_UNSORTED: &'static str = "RERUN_CHUNK_MAX_ROWS_IF_UNSORTED";
 
+    /// Environment variable to configure [`Self::disk_cache_dir`].
+    pub const ENV_CHUNK_DISK_CACHE_DIR: &'static str = "RERUN_CHUNK_DISK_CACHE_DIR";
+
     /// Creates a new `ChunkStoreConfig` using the default values, optionally overridden
     /// through the environment.
     ///
@@ -141,8 +162,8 @@ impl ChunkStoreConfig {
     /// Returns a copy of `self`, overriding existing fields with values from the environment if
     /// they are present.
     ///
-    /// See [`Self::ENV_STORE_ENABLE_CHANGELOG`], [`Self::ENV_CHUNK_MAX_BYTES`], [`Self::ENV_CHUNK_MAX_ROWS`]
-    /// and [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`].
+    /// See [`Self::ENV_STORE_ENABLE_CHANGELOG`], [`Self::ENV_CHUNK_MAX_BYTES`], [`Self::ENV_CHUNK_MAX_ROWS`],
+    /// [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`] and [`Self::ENV_CHUNK_DISK_CACHE_DIR`].
     pub fn apply_env(&self) -> ChunkStoreResult<Self> {
         let mut new = self.clone();
 
@@ -179,6 +200,10 @@ impl ChunkStoreConfig {
                 })?;
         }
 
+        if let Ok(s) = std::env::var(Self::ENV_CHUNK_DISK_CACHE_DIR) {
+            new.disk_cache_dir = (!s.is_empty()).then(|| s.into());
+        }
+
         Ok(new)
     }
 }
@@ -190,6 +215,7 @@ fn chunk_store_config() {
     std::env::set_var("RERUN_CHUNK_MAX_BYTES", "42");
     std::env::set_var("RERUN_CHUNK_MAX_ROWS", "666");
     std::env::set_var("RERUN_CHUNK_MAX_ROWS_IF_UNSORTED", "999");
+    std::env::set_var("RERUN_CHUNK_DISK_CACHE_DIR", "/tmp/rerun");
 
     let config = ChunkStoreConfig::from_env().unwrap();
 
@@ -198,6 +224,7 @@ fn chunk_store_config() {
         chunk_max_bytes: 42,
         chunk_max_rows: 666,
         chunk_max_rows_if_unsorted: 999,
+        disk_cache_dir: Some("/tmp/rerun".into()),
     };
 
     assert_eq!(expected, config);
@@ -330,6 +357,32 @@ pub struct ChunkStore {
     /// This is too costly to be computed from scratch every frame, and is required by e.g. the GC.
     pub(crate) static_chunks_stats: ChunkStoreChunkStats,
 
+    /// Where evicted chunks live, see [`ChunkStoreConfig::disk_cache_dir`].
+    ///
+    /// Created on the first eviction.
+    pub(crate) disk_cache: Option<Arc<ChunkDiskCache>>,
+
+    /// Where the payload of lazily inserted chunks lives, see [`ChunkSource`].
+    pub(crate) chunk_source: Option<Arc<dyn ChunkSource>>,
+
+    /// The temporal chunks that are in [`Self::disk_cache`] or [`Self::chunk_source`] rather
+    /// than in [`Self::chunks_per_chunk_id`], with what each of them adds to
+    /// [`Self::evicted_chunks_stats`].
+    ///
+    /// They are still part of all other indices.
+    ///
+    /// The stats are kept because the chunk that is read back isn't necessarily the one that
+    /// was inserted: a [`ChunkSource`] may have the store index placeholders instead.
+    pub(crate) evicted_chunks: BTreeMap<ChunkId, ChunkStoreChunkStats>,
+
+    /// The evicted chunks that were recently read back.
+    pub(crate) paged_in_chunks: parking_lot::Mutex<PagedInChunks>,
+
+    /// Accumulated size statistics for all evicted [`Chunk`]s.
+    ///
+    /// These are not part of [`Self::temporal_chunks_stats`], which only accounts for memory.
+    pub(crate) evicted_chunks_stats: ChunkStoreChunkStats,
+
     // pub(crate) static_tables: BTreeMap<EntityPathHash, StaticTable>,
     /// Monotonically increasing ID for insertions.
     pub(crate) insert_id: u64,
@@ -360,6 +413,11 @@ impl Clone for ChunkStore {
             temporal_chunks_stats: self.temporal_chunks_stats,
             static_chunk_ids_per_entity: self.static_chunk_ids_per_entity.clone(),
             static_chunks_stats: self.static_chunks_stats,
+            disk_cache: self.disk_cache.clone(),
+            chunk_source: self.chunk_source.clone(),
+            evicted_chunks: self.evicted_chunks.clone(),
+            paged_in_chunks: Default::default(),
+            evicted_chunks_stats: self.evicted_chunks_stats,
             insert_id: Default::default(),
             query_id: Default::default(),
             gc_id: Default::default(),
@@ -381,6 +439,11 @@ impl std::fmt::Display for ChunkStore {
             temporal_chunks_stats,
             static_chunk_ids_per_entity: _,
             static_chunks_stats,
+            disk_cache: _,
+            chunk_source: _,
+            evicted_chunks,
+            paged_in_chunks: _,
+            evicted_chunks_stats: _,
             insert_id: _,
             query_id: _,
             gc_id: _,
@@ -403,6 +466,11 @@ impl std::fmt::Display for ChunkStore {
         for chunk_id in chunk_id_per_min_row_id.values().flatten() {
             if let Some(chunk) = chunks_per_chunk_id.get(chunk_id) {
                 f.write_str(&indent::indent_all_by(8, format!("{chunk}\n")))?;
+            } else if evicted_chunks.contains_key(chunk_id) {
+                f.write_str(&indent::indent_all_by(
+                    8,
+                    format!("<evicted: {chunk_id}>\n"),
+                ))?;
             } else {
                 f.write_str(&indent::indent_all_by(8, "<not_found>\n"))?;
             }
@@ -431,6 +499,11 @@ impl ChunkStore {
             temporal_chunks_stats: Default::default(),
             static_chunk_ids_per_entity: Default::default(),
             static_chunks_stats: Default::default(),
+            disk_cache: None,
+            chunk_source: None,
+            evicted_chunks: Default::default(),
+            paged_in_chunks: Default::default(),
+            evicted_chunks_stats: Default::default(),
             insert_id: 0,
             query_id: AtomicU64::new(0),
             gc_id: 0,
@@ -459,12 +532,89 @@ impl ChunkStore {
         &self.config
     }
 
-    /// Iterate over all chunks in the store, in ascending [`ChunkId`] order.
+    /// Reads the payload of chunks back from `chunk_source` instead of keeping it in memory.
+    ///
+    /// Only affects the chunks inserted from now on. See [`ChunkSource`].
+    #[inline]
+    pub fn set_chunk_source(&mut self, chunk_source: Arc<dyn ChunkSource>) {
+        self.chunk_source = Some(chunk_source);
+    }
+
+    /// See [`Self::set_chunk_source`].
+    #[inline]
+    pub fn chunk_source(&self) -> Option<&Arc<dyn ChunkSource>> {
+        self.chunk_source.as_ref()
+    }
+
+    /// Iterate over all chunks in memory, in ascending [`ChunkId`] order.
+    ///
+    /// This leaves out the chunks that were evicted to disk or that live in the
+    /// [`ChunkSource`], see [`Self::iter_chunks_including_evicted`].
     #[inline]
     pub fn iter_chunks(&self) -> impl Iterator<Item = &Arc<Chunk>> + '_ {
         self.chunks_per_chunk_id.values()
     }
 
+    /// Iterate over all chunks in the store, reading the evicted ones back, in ascending
+    /// [`ChunkId`] order.
+    ///
+    /// Evicted chunks that can't be read back are skipped, with a warning.
+    /// See [`ChunkStoreConfig::disk_cache_dir`].
+    pub fn iter_chunks_including_evicted(&self) -> impl Iterator<Item = Arc<Chunk>> + '_ {
+        itertools::merge_join_by(
+            self.chunks_per_chunk_id.iter(),
+            self.evicted_chunks.keys(),
+            |(chunk_id, _), evicted_chunk_id| chunk_id.cmp(evicted_chunk_id),
+        )
+        .filter_map(|either| match either {
+            itertools::EitherOrBoth::Left((_, chunk))
+            | itertools::EitherOrBoth::Both((_, chunk), _) => Some(Arc::clone(chunk)),
+            itertools::EitherOrBoth::Right(chunk_id) => self.chunk(chunk_id),
+        })
+    }
+
+    /// Statistics about the chunks that were evicted to disk (see
+    /// [`ChunkStoreConfig::disk_cache_dir`]) or that live in the [`ChunkSource`].
+    ///
+    /// These are not part of [`Self::stats`], which only accounts for the chunks in memory.
+    #[inline]
+    pub fn evicted_chunks_stats(&self) -> ChunkStoreChunkStats {
+        self.evicted_chunks_stats
+    }
+
+    /// Looks up a chunk, reading it back if it was evicted.
+    ///
+    /// Only the most recently read back chunks are kept in memory, see [`PagedInChunks`].
+    pub(crate) fn chunk(&self, chunk_id: &ChunkId) -> Option<Arc<Chunk>> {
+        if let Some(chunk) = self.chunks_per_chunk_id.get(chunk_id) {
+            return Some(Arc::clone(chunk));
+        }
+
+        if !self.evicted_chunks.contains_key(chunk_id) {
+            return None;
+        }
+
+        let mut paged_in_chunks = self.paged_in_chunks.lock();
+        if let Some(chunk) = paged_in_chunks.get(chunk_id) {
+            return Some(chunk);
+        }
+
+        match read_evicted_chunk(
+            self.chunk_source.as_ref(),
+            self.disk_cache.as_ref(),
+            chunk_id,
+        )? {
+            Ok(chunk) => {
+                paged_in_chunks.insert(Arc::clone(&chunk));
+                Some(chunk)
+            }
+            Err(err) => {
+                re_log::warn_once!("Failed to read chunk {chunk_id} back: {err}");
+                None
+            }
+        }
+    }
+
     /// Lookup the _latest_ arrow [`ArrowDataType`] used by a specific [`re_types_core::Component`].
     #[inline]
     pub fn lookup_datatype(&self, component_name: &ComponentName) -> Option<&ArrowDataType> {
@@ -2,14 +2,15 @@ use std::{collections::BTreeSet, sync::Arc};
 
 use ahash::HashMap;
 use arrow2::array::{Array as _, ListArray as ArrowListArray};
+use arrow2::datatypes::DataType as ArrowDataType;
 use itertools::Itertools as _;
 
 use re_chunk::{Chunk, EntityPath, RowId};
 use re_types_core::SizeBytes;
 
 use crate::{
-    store::ChunkIdSetPerTime, ChunkStore, ChunkStoreChunkStats, ChunkStoreConfig, ChunkStoreDiff,
-    ChunkStoreError, ChunkStoreEvent, ChunkStoreResult,
+    chunk_source::read_evicted_chunk, store::ChunkIdSetPerTime, ChunkStore, ChunkStoreChunkStats,
+    ChunkStoreConfig, ChunkStoreDiff, ChunkStoreError, ChunkStoreEvent, ChunkStoreResult,
 };
 
 // Used all over in docstrings.
@@ -28,7 +29,9 @@ impl ChunkStore {
     /// * Inserting a duplicated [`ChunkId`] will result in a no-op.
     /// * Inserting an empty [`Chunk`] will result in a no-op.
     pub fn insert_chunk(&mut self, chunk: &Arc<Chunk>) -> ChunkStoreResult<Vec<ChunkStoreEvent>> {
-        if self.chunks_per_chunk_id.contains_key(&chunk.id()) {
+        if self.chunks_per_chunk_id.contains_key(&chunk.id())
+            || self.evicted_chunks.contains_key(&chunk.id())
+        {
             // We assume that chunk IDs are unique, and that reinserting a chunk has no effect.
             re_log::warn_once!(
                 "Chunk #{} was inserted more than once (this has no effect)",
@@ -49,6 +52,13 @@ impl ChunkStore {
 
         self.insert_id += 1;
 
+        // The payload of these only gets read back when needed, see `ChunkSource`.
+        let is_lazy = !chunk.is_static()
+            && self
+                .chunk_source
+                .as_ref()
+                .is_some_and(|chunk_source| chunk_source.contains(&chunk.id()));
+
         let (chunk, diffs) = if chunk.is_static() {
             // Static data: make sure to keep the most recent chunk available for each component column.
             re_tracing::profile_scope!("static");
@@ -106,7 +116,12 @@ impl ChunkStore {
             let (elected_chunk, chunk_or_compacted) = {
                 re_tracing::profile_scope!("election");
 
-                let elected_chunk = self.find_and_elect_compaction_candidate(chunk);
+                // Compacting would create a chunk that the source knows nothing about.
+                let elected_chunk = if is_lazy {
+                    None
+                } else {
+                    self.find_and_elect_compaction_candidate(chunk)
+                };
 
                 let chunk_or_compacted = if let Some(elected_chunk) = &elected_chunk {
                     let chunk_rowid_min = chunk.row_id_range().map(|(min, _)| min);
@@ -220,7 +235,14 @@ impl ChunkStore {
                 }
             }
 
-            self.temporal_chunks_stats += ChunkStoreChunkStats::from_chunk(&chunk_or_compacted);
+            let chunk_stats = ChunkStoreChunkStats::from_chunk(&chunk_or_compacted);
+            if is_lazy {
+                self.evicted_chunks_stats += chunk_stats;
+                self.evicted_chunks
+                    .insert(chunk_or_compacted.id(), chunk_stats);
+            } else {
+                self.temporal_chunks_stats += chunk_stats;
+            }
 
             let mut diffs = vec![ChunkStoreDiff::addition(Arc::clone(&chunk_or_compacted))];
             if let Some(elected_chunk) = &elected_chunk {
@@ -230,17 +252,23 @@ impl ChunkStore {
             (chunk_or_compacted, diffs)
         };
 
-        self.chunks_per_chunk_id.insert(chunk.id(), chunk.clone());
+        if !is_lazy {
+            self.chunks_per_chunk_id.insert(chunk.id(), chunk.clone());
+        }
         self.chunk_ids_per_min_row_id
             .entry(row_id_range.0)
             .or_default()
             .push(chunk.id());
 
         for (&component_name, list_array) in chunk.components() {
-            self.type_registry.insert(
-                component_name,
-                ArrowListArray::<i32>::get_child_type(list_array.data_type()).clone(),
-            );
+            let datatype = ArrowListArray::<i32>::get_child_type(list_array.data_type());
+
+            // Placeholders don't know the actual datatype, see `ChunkSource`.
+            if is_lazy && datatype == &ArrowDataType::Null {
+                continue;
+            }
+
+            self.type_registry.insert(component_name, datatype.clone());
         }
 
         let events = if self.config.enable_changelog {
@@ -287,6 +315,7 @@ impl ChunkStore {
                     chunk_max_bytes,
                     chunk_max_rows,
                     chunk_max_rows_if_unsorted,
+                    disk_cache_dir: _,
                 } = store.config;
 
                 *candidates_below_threshold
@@ -411,6 +440,11 @@ impl ChunkStore {
             temporal_chunks_stats,
             static_chunk_ids_per_entity,
             static_chunks_stats,
+            disk_cache,
+            chunk_source,
+            evicted_chunks,
+            paged_in_chunks,
+            evicted_chunks_stats,
             insert_id: _,
             query_id: _,
             gc_id: _,
@@ -468,11 +502,24 @@ impl ChunkStore {
             // NOTE: gotta collect to release the mut ref on `chunks_per_chunk_id`.
             .collect_vec();
 
-        let dropped_temporal_chunks = dropped_temporal_chunks
-            .filter_map(|chunk_id| chunks_per_chunk_id.remove(&chunk_id))
-            .inspect(|chunk| {
-                *temporal_chunks_stats -= ChunkStoreChunkStats::from_chunk(chunk);
-            });
+        let dropped_temporal_chunks = dropped_temporal_chunks.filter_map(|chunk_id| {
+            if let Some(chunk) = chunks_per_chunk_id.remove(&chunk_id) {
+                *temporal_chunks_stats -= ChunkStoreChunkStats::from_chunk(&chunk);
+                return Some(chunk);
+            }
+
+            // Evicted chunks have to be read back, so that subscribers learn about their deletion.
+            let chunk_stats = evicted_chunks.remove(&chunk_id)?;
+            *evicted_chunks_stats -= chunk_stats;
+            paged_in_chunks.get_mut().remove(&chunk_id);
+            match read_evicted_chunk(chunk_source.as_ref(), disk_cache.as_ref(), &chunk_id)? {
+                Ok(chunk) => Some(chunk),
+                Err(err) => {
+                    re_log::warn_once!("Failed to read evicted chunk {chunk_id}: {err}");
+                    None
+                }
+            }
+        });
 
         dropped_static_chunks
             .into_iter()
@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use re_chunk::{Chunk, ChunkId, LatestAtQuery, RangeQuery, RowId, TimePoint};
+use re_chunk_store::{
+    ChunkSource, ChunkStore, ChunkStoreConfig, ChunkStoreError, ChunkStoreResult, ResolvedTimeRange,
+};
+use re_log_types::{
+    build_frame_nr,
+    example_components::{MyColor, MyPoint},
+    EntityPath, Timeline,
+};
+use re_types_core::Loggable as _;
+
+// ---
+
+/// Keeps a copy of every chunk, and counts how often they are read back.
+#[derive(Debug, Default)]
+struct InMemorySource {
+    chunks: BTreeMap<ChunkId, Chunk>,
+    num_loads: AtomicUsize,
+}
+
+impl ChunkSource for InMemorySource {
+    fn contains(&self, chunk_id: &ChunkId) -> bool {
+        self.chunks.contains_key(chunk_id)
+    }
+
+    fn load(&self, chunk_id: &ChunkId) -> ChunkStoreResult<Chunk> {
+        self.num_loads.fetch_add(1, Ordering::Relaxed);
+        self.chunks
+            .get(chunk_id)
+            .cloned()
+            .ok_or_else(|| ChunkStoreError::ChunkSource {
+                chunk_id: *chunk_id,
+                err: "unknown chunk".into(),
+            })
+    }
+}
+
+#[test]
+fn lazy_insertion() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let entity_path = EntityPath::from("this/that");
+    let num_frames = 10;
+
+    let temporal_chunks = (0..num_frames)
+        .map(|frame_nr| {
+            Chunk::builder(entity_path.clone())
+                .with_component_batch(
+                    RowId::new(),
+                    [build_frame_nr(frame_nr)],
+                    &[MyPoint::new(frame_nr as f32, frame_nr as f32)],
+                )
+                .build()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let static_chunk = Chunk::builder(entity_path.clone())
+        .with_component_batch(RowId::new(), TimePoint::default(), &[MyColor(0xFF0000FF)])
+        .build()?;
+
+    let source = Arc::new(InMemorySource {
+        chunks: temporal_chunks
+            .iter()
+            .chain(std::iter::once(&static_chunk))
+            .map(|chunk| (chunk.id(), chunk.clone()))
+            .collect(),
+        num_loads: AtomicUsize::new(0),
+    });
+
+    // Compaction is enabled, but must leave lazily inserted chunks alone.
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        ChunkStoreConfig::DEFAULT,
+    );
+    store.set_chunk_source(source.clone());
+
+    for chunk in temporal_chunks
+        .into_iter()
+        .chain(std::iter::once(static_chunk))
+    {
+        let events = store.insert_chunk(&Arc::new(chunk))?;
+        assert_eq!(1, events.len());
+    }
+
+    // Only the static chunk is kept in memory.
+    assert_eq!(1, store.iter_chunks().count());
+    assert_eq!(0, store.stats().temporal_chunks.num_chunks);
+    assert_eq!(num_frames as u64, store.evicted_chunks_stats().num_chunks);
+    assert_eq!(0, source.num_loads.load(Ordering::Relaxed));
+
+    let timeline_frame_nr = Timeline::new_sequence("frame_nr");
+    let query = RangeQuery::new(timeline_frame_nr, ResolvedTimeRange::EVERYTHING);
+
+    let chunks = store.range_relevant_chunks(&query, &entity_path, MyPoint::name());
+    assert_eq!(num_frames as usize, chunks.len());
+    assert_eq!(
+        num_frames as usize,
+        source.num_loads.load(Ordering::Relaxed)
+    );
+
+    // Recently read chunks are kept around.
+    let chunks = store.range_relevant_chunks(&query, &entity_path, MyPoint::name());
+    assert_eq!(num_frames as usize, chunks.len());
+    let query = LatestAtQuery::new(timeline_frame_nr, 3);
+    let chunks = store.latest_at_relevant_chunks(&query, &entity_path, MyPoint::name());
+    assert_eq!(1, chunks.len());
+    assert_eq!(
+        num_frames as usize,
+        source.num_loads.load(Ordering::Relaxed)
+    );
+
+    // Static data is never read back.
+    let chunks = store.latest_at_relevant_chunks(&query, &entity_path, MyColor::name());
+    assert_eq!(1, chunks.len());
+    assert_eq!(
+        num_frames as usize,
+        source.num_loads.load(Ordering::Relaxed)
+    );
+
+    assert_eq!(
+        1 + num_frames as usize,
+        store.iter_chunks_including_evicted().count()
+    );
+
+    let events = store.drop_entity_path(&entity_path);
+    assert_eq!(1 + num_frames as usize, events.len());
+    assert_eq!(0, store.evicted_chunks_stats().num_chunks);
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use re_chunk::{Chunk, LatestAtQuery, RangeQuery, RowId, TimeInt, TimePoint};
+use re_chunk_store::{
+    ChunkStore, ChunkStoreConfig, GarbageCollectionOptions, GarbageCollectionTarget,
+    ResolvedTimeRange,
+};
+use re_log_types::{
+    build_frame_nr,
+    example_components::{MyColor, MyPoint},
+    EntityPath, Timeline,
+};
+use re_types_core::Loggable as _;
+
+// ---
+
+#[test]
+fn evict_and_read_back() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let cache_root = tempfile::tempdir()?;
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        ChunkStoreConfig {
+            disk_cache_dir: Some(cache_root.path().to_owned()),
+            ..ChunkStoreConfig::COMPACTION_DISABLED
+        },
+    );
+
+    let entity_path = EntityPath::from("this/that");
+    let num_frames = 10;
+
+    for frame_nr in 0..num_frames {
+        let chunk = Chunk::builder(entity_path.clone())
+            .with_component_batch(
+                RowId::new(),
+                [build_frame_nr(frame_nr)],
+                &[MyPoint::new(frame_nr as f32, frame_nr as f32)],
+            )
+            .build()?;
+        store.insert_chunk(&Arc::new(chunk))?;
+    }
+
+    let static_chunk = Chunk::builder(entity_path.clone())
+        .with_component_batch(RowId::new(), TimePoint::default(), &[MyColor(0xFF0000FF)])
+        .build()?;
+    store.insert_chunk(&Arc::new(static_chunk))?;
+
+    let stats_before = store.stats();
+
+    let (store_events, stats_diff) = store.gc(&GarbageCollectionOptions {
+        target: GarbageCollectionTarget::DropAtLeastFraction(1.0),
+        protect_latest: 1,
+        time_budget: std::time::Duration::MAX,
+    });
+
+    // Evicted chunks are still part of the store: nobody must be told otherwise.
+    assert!(store_events.is_empty());
+
+    // Everything but the static and the protected chunks went to disk.
+    let stats_after = store.stats();
+    assert_eq!(
+        stats_before.static_chunks.total_size_bytes,
+        stats_after.static_chunks.total_size_bytes
+    );
+    assert_eq!(1, stats_after.temporal_chunks.num_chunks);
+    assert_eq!(
+        num_frames - 1,
+        store.evicted_chunks_stats().num_chunks as i64
+    );
+    assert_eq!(
+        stats_diff.temporal_chunks.total_size_bytes,
+        store.evicted_chunks_stats().total_size_bytes
+    );
+    assert_eq!(1 + 1, store.iter_chunks().count());
+    assert_eq!(
+        1 + num_frames as usize,
+        store.iter_chunks_including_evicted().count()
+    );
+
+    // Queries read the evicted data back.
+    let timeline_frame_nr = Timeline::new_sequence("frame_nr");
+
+    for frame_nr in 0..num_frames {
+        let query = LatestAtQuery::new(timeline_frame_nr, frame_nr);
+        let chunks = store.latest_at_relevant_chunks(&query, &entity_path, MyPoint::name());
+        assert_eq!(1, chunks.len(), "frame #{frame_nr}");
+
+        let chunk = chunks[0].latest_at(&query, MyPoint::name());
+        assert_eq!(
+            Some(TimeInt::new_temporal(frame_nr)),
+            chunk
+                .timelines()
+                .get(&timeline_frame_nr)
+                .map(|time_column| time_column.time_range().min())
+        );
+    }
+
+    let query = RangeQuery::new(timeline_frame_nr, ResolvedTimeRange::EVERYTHING);
+    let chunks = store.range_relevant_chunks(&query, &entity_path, MyPoint::name());
+    assert_eq!(num_frames as usize, chunks.len());
+
+    // Dropping an entity reads its evicted chunks back, so their deletion can be reported.
+    let store_events = store.drop_entity_path(&entity_path);
+    assert_eq!(1 + num_frames as usize, store_events.len());
+    assert_eq!(0, store.evicted_chunks_stats().num_chunks);
+
+    // The cache doesn't outlive the store.
+    drop(store);
+    assert_eq!(0, std::fs::read_dir(cache_root.path())?.count());
+
+    Ok(())
+}
+
+#[test]
+fn blueprints_are_not_evicted() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let cache_root = tempfile::tempdir()?;
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Blueprint),
+        ChunkStoreConfig {
+            disk_cache_dir: Some(cache_root.path().to_owned()),
+            ..ChunkStoreConfig::COMPACTION_DISABLED
+        },
+    );
+
+    let entity_path = EntityPath::from("this/that");
+    for frame_nr in 0..10 {
+        let chunk = Chunk::builder(entity_path.clone())
+            .with_component_batch(
+                RowId::new(),
+                [build_frame_nr(frame_nr)],
+                &[MyPoint::new(1.0, 1.0)],
+            )
+            .build()?;
+        store.insert_chunk(&Arc::new(chunk))?;
+    }
+
+    let (store_events, _) = store.gc(&GarbageCollectionOptions {
+        target: GarbageCollectionTarget::DropAtLeastFraction(1.0),
+        protect_latest: 0,
+        time_budget: std::time::Duration::MAX,
+    });
+
+    assert_eq!(10, store_events.len());
+    assert_eq!(0, store.evicted_chunks_stats().num_chunks);
+    assert_eq!(0, std::fs::read_dir(cache_root.path())?.count());
+
+    Ok(())
+}
@@ -0,0 +1,313 @@
+use std::sync::Arc;
+
+use itertools::Itertools as _;
+
+use re_chunk::{Chunk, RowId, TimePoint};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig, ExportFormat, ExportOptions};
+use re_log_types::{
+    build_frame_nr,
+    example_components::{MyColor, MyPoint},
+    EntityPath,
+};
+use re_types_core::Loggable as _;
+
+// ---
+
+#[test]
+fn entity_tables() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let entity_path: EntityPath = "this/that".into();
+
+    let points = MyPoint::from_iter(0..3);
+    let colors = MyColor::from_iter(0..1);
+
+    let chunk = Chunk::builder(entity_path.clone())
+        .with_component_batches(RowId::new(), [build_frame_nr(1)], [&points as _])
+        .with_component_batches(RowId::new(), [build_frame_nr(2)], [&points as _])
+        .build()?;
+    store.insert_chunk(&Arc::new(chunk))?;
+
+    let chunk = Chunk::builder(entity_path.clone())
+        .with_component_batches(RowId::new(), TimePoint::default(), [&colors as _])
+        .build()?;
+    store.insert_chunk(&Arc::new(chunk))?;
+
+    let chunk = Chunk::builder("other".into())
+        .with_component_batches(RowId::new(), [build_frame_nr(10)], [&points as _])
+        .build()?;
+    store.insert_chunk(&Arc::new(chunk))?;
+
+    let tables = store.entity_tables()?;
+    assert_eq!(2, tables.len());
+
+    let table = &tables[&entity_path];
+    similar_asserts::assert_eq!(
+        vec![
+            RowId::name().to_string(),
+            "frame_nr".to_owned(),
+            MyColor::name().to_string(),
+            MyPoint::name().to_string(),
+        ],
+        table
+            .schema
+            .fields
+            .iter()
+            .map(|field| field.name.clone())
+            .collect_vec()
+    );
+    assert_eq!(3, table.data.len());
+
+    // The static row has no time, the temporal rows have no color.
+    let columns = table.data.columns();
+    assert_eq!(1, columns[1].null_count());
+    assert_eq!(2, columns[2].null_count());
+    assert_eq!(1, columns[3].null_count());
+
+    // Roundtrip through Arrow IPC.
+    let mut buf = Vec::new();
+    table.write_arrow_ipc(&mut buf)?;
+
+    let mut reader = std::io::Cursor::new(buf);
+    let metadata = arrow2::io::ipc::read::read_file_metadata(&mut reader)?;
+    similar_asserts::assert_eq!(table.schema, metadata.schema);
+
+    let batches: Vec<_> =
+        arrow2::io::ipc::read::FileReader::new(reader, metadata, None, None).try_collect()?;
+    assert_eq!(1, batches.len());
+    // NOTE: The IPC reader doesn't restore extension types on the arrays themselves (only in the
+    // schema), which makes the `RowId` column fail the comparison.
+    assert_eq!(table.data.len(), batches[0].len());
+    assert_eq!(table.data.arrays()[1..], batches[0].arrays()[1..]);
+
+    Ok(())
+}
+
+#[test]
+fn flattened() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let mono: EntityPath = "mono".into();
+    let multi: EntityPath = "multi".into();
+
+    let point = MyPoint::from_iter(1..2);
+    let points = MyPoint::from_iter(0..3);
+    let color = MyColor::from_iter(42..43);
+
+    let chunk = Chunk::builder(mono.clone())
+        .with_component_batches(
+            RowId::new(),
+            [build_frame_nr(1)],
+            [&point as _, &color as _],
+        )
+        .with_component_batches(RowId::new(), [build_frame_nr(2)], [&point as _])
+        .build()?;
+    store.insert_chunk(&Arc::new(chunk))?;
+
+    let chunk = Chunk::builder(multi.clone())
+        .with_component_batches(RowId::new(), [build_frame_nr(1)], [&points as _])
+        .with_component_batches(RowId::new(), [build_frame_nr(2)], [&point as _])
+        .build()?;
+    store.insert_chunk(&Arc::new(chunk))?;
+
+    let tables = store.entity_tables()?;
+
+    // Single-instance components get unwrapped, and their structs split into one column per field.
+    {
+        let table = tables[&mono].flattened();
+        similar_asserts::assert_eq!(
+            vec![
+                RowId::name().to_string(),
+                "frame_nr".to_owned(),
+                MyColor::name().to_string(),
+                format!("{}.x", MyPoint::name()),
+                format!("{}.y", MyPoint::name()),
+            ],
+            table
+                .schema
+                .fields
+                .iter()
+                .map(|field| field.name.clone())
+                .collect_vec()
+        );
+        assert_eq!(2, table.data.len());
+
+        let columns = table.data.columns();
+        let colors = columns[2]
+            .as_any()
+            .downcast_ref::<arrow2::array::UInt32Array>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(42), None],
+            colors.iter().map(|c| c.copied()).collect_vec()
+        );
+
+        let xs = columns[3]
+            .as_any()
+            .downcast_ref::<arrow2::array::Float32Array>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(1.0), Some(1.0)],
+            xs.iter().map(|x| x.copied()).collect_vec()
+        );
+    }
+
+    // Multi-instance components are left as is.
+    {
+        let table = tables[&multi].flattened();
+        similar_asserts::assert_eq!(tables[&multi].schema, table.schema);
+        assert_eq!(tables[&multi].data.arrays(), table.data.arrays());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parquet() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let entity_path: EntityPath = "this/that".into();
+
+    let points = MyPoint::from_iter(0..3);
+    let colors = MyColor::from_iter(0..1);
+
+    let chunk = Chunk::builder(entity_path.clone())
+        .with_component_batches(RowId::new(), [build_frame_nr(1)], [&points as _])
+        .with_component_batches(RowId::new(), TimePoint::default(), [&colors as _])
+        .build()?;
+    store.insert_chunk(&Arc::new(chunk))?;
+
+    for table in [
+        store.entity_tables()?.remove(&entity_path).unwrap(),
+        store
+            .entity_tables()?
+            .remove(&entity_path)
+            .unwrap()
+            .flattened(),
+    ] {
+        let mut buf = Vec::new();
+        table.write_parquet(&mut buf)?;
+
+        let mut reader = std::io::Cursor::new(buf);
+        let metadata = arrow2::io::parquet::read::read_metadata(&mut reader)?;
+        let schema = arrow2::io::parquet::read::infer_schema(&metadata)?;
+        similar_asserts::assert_eq!(
+            table
+                .schema
+                .fields
+                .iter()
+                .map(|field| field.name.clone())
+                .collect_vec(),
+            schema
+                .fields
+                .iter()
+                .map(|field| field.name.clone())
+                .collect_vec()
+        );
+
+        let batches: Vec<_> = arrow2::io::parquet::read::FileReader::new(
+            reader,
+            metadata.row_groups,
+            schema,
+            None,
+            None,
+            None,
+        )
+        .try_collect()?;
+        assert_eq!(1, batches.len());
+        assert_eq!(table.data.len(), batches[0].len());
+        // NOTE: Skip the `RowId`, for the same reason as in the IPC roundtrip above.
+        assert_eq!(table.data.arrays()[1..], batches[0].arrays()[1..]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn export() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let points = MyPoint::from_iter(0..3);
+    let entity_paths = [
+        "/",
+        "world/points",
+        "world/image.png",
+        "world/..",
+        // These all sanitize to `world/a_b`.
+        "world/a b",
+        "world/a_b",
+        "world/a?b",
+        // Same, but only on case-insensitive file systems.
+        "world/A_B",
+    ];
+    for entity_path in entity_paths {
+        let chunk = Chunk::builder(entity_path.into())
+            .with_component_batches(RowId::new(), [build_frame_nr(1)], [&points as _])
+            .build()?;
+        store.insert_chunk(&Arc::new(chunk))?;
+    }
+
+    for format in [ExportFormat::ArrowIpc, ExportFormat::Parquet] {
+        let dir = tempfile::tempdir()?;
+        let paths = store.export(
+            dir.path(),
+            &ExportOptions {
+                format,
+                flatten: true,
+            },
+        )?;
+
+        let ext = format.file_extension();
+        let path = |parts: &[&str]| -> std::path::PathBuf {
+            let mut path: std::path::PathBuf = parts.iter().collect();
+            path.as_mut_os_string().push(format!(".{ext}"));
+            path
+        };
+
+        // Every entity gets its own file, nothing gets overwritten.
+        assert_eq!(entity_paths.len(), paths.len());
+        for path in paths.values() {
+            assert!(path.exists(), "{path:?}");
+        }
+
+        let relative = |entity_path: &str| {
+            paths[&EntityPath::from(entity_path)]
+                .strip_prefix(dir.path())
+                .unwrap()
+                .to_owned()
+        };
+
+        similar_asserts::assert_eq!(path(&["__root"]), relative("/"));
+        similar_asserts::assert_eq!(path(&["world", "points"]), relative("world/points"));
+        similar_asserts::assert_eq!(path(&["world", "image.png"]), relative("world/image.png"));
+        similar_asserts::assert_eq!(path(&["world", "__"]), relative("world/.."));
+
+        // Entities are exported in order, so the suffixes are deterministic.
+        similar_asserts::assert_eq!(path(&["world", "A_B"]), relative("world/A_B"));
+        similar_asserts::assert_eq!(path(&["world", "a_b~1"]), relative("world/a b"));
+        similar_asserts::assert_eq!(path(&["world", "a_b~2"]), relative("world/a?b"));
+        similar_asserts::assert_eq!(path(&["world", "a_b~3"]), relative("world/a_b"));
+    }
+
+    Ok(())
+}
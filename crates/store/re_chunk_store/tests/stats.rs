@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use re_chunk::{Chunk, RowId, TimePoint};
+use re_chunk_store::{ChunkStore, ChunkStoreConfig, ChunkStoreStats, ResolvedTimeRange};
+use re_log_types::{
+    build_frame_nr,
+    example_components::{MyColor, MyPoint},
+    EntityPath, TimeType, Timeline,
+};
+use re_types_core::Loggable as _;
+
+// ---
+
+#[test]
+fn stats_per_entity() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        ChunkStoreConfig::COMPACTION_DISABLED,
+    );
+
+    let entity_path: EntityPath = "this/that".into();
+
+    let points = MyPoint::from_iter(0..3);
+    let colors = MyColor::from_iter(0..1);
+
+    let chunk = Chunk::builder(entity_path.clone())
+        .with_component_batches(RowId::new(), [build_frame_nr(1)], [&points as _])
+        .with_component_batches(
+            RowId::new(),
+            [build_frame_nr(5)],
+            [&points as _, &colors as _],
+        )
+        .build()?;
+    store.insert_chunk(&Arc::new(chunk))?;
+
+    let chunk = Chunk::builder(entity_path.clone())
+        .with_component_batches(RowId::new(), TimePoint::default(), [&colors as _])
+        .build()?;
+    store.insert_chunk(&Arc::new(chunk))?;
+
+    let chunk = Chunk::builder("other".into())
+        .with_component_batches(RowId::new(), [build_frame_nr(10)], [&points as _])
+        .build()?;
+    store.insert_chunk(&Arc::new(chunk))?;
+
+    let stats_per_entity = store.stats_per_entity();
+    assert_eq!(2, stats_per_entity.len());
+
+    let stats = &stats_per_entity[&entity_path];
+    assert_eq!(1, stats.chunks.static_chunks.num_chunks);
+    assert_eq!(1, stats.chunks.temporal_chunks.num_chunks);
+    assert_eq!(2, stats.chunks.temporal_chunks.total_num_rows);
+
+    let point_stats = &stats.per_component[&MyPoint::name()];
+    assert_eq!(0, point_stats.static_chunks.num_chunks);
+    assert_eq!(2, point_stats.temporal_chunks.total_num_rows);
+
+    // The first row has no color.
+    let color_stats = &stats.per_component[&MyColor::name()];
+    assert_eq!(1, color_stats.static_chunks.total_num_rows);
+    assert_eq!(1, color_stats.temporal_chunks.total_num_rows);
+
+    let timeline = Timeline::new("frame_nr", TimeType::Sequence);
+    let timeline_stats = &stats.per_timeline[&timeline];
+    assert_eq!(1, timeline_stats.chunks.num_chunks);
+    assert_eq!(ResolvedTimeRange::new(1, 5), timeline_stats.time_range);
+
+    // Per-entity stats must add up to the store-wide ones.
+    let total = stats_per_entity
+        .values()
+        .fold(ChunkStoreStats::default(), |acc, stats| acc + stats.chunks);
+    assert_eq!(store.stats().total().num_chunks, total.total().num_chunks);
+    assert_eq!(
+        store.stats().total().total_size_bytes,
+        total.total().total_size_bytes
+    );
+
+    Ok(())
+}
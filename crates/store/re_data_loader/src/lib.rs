@@ -11,15 +11,24 @@ use re_log_types::{ArrowMsg, EntityPath, LogMsg, TimePoint};
 
 mod load_file;
 mod loader_archetype;
+mod loader_arrow;
 mod loader_directory;
+mod loader_mcap;
 mod loader_rrd;
+mod loader_urdf;
+mod point_cloud;
 
 #[cfg(not(target_arch = "wasm32"))]
 mod loader_external;
 
 pub use self::{
-    load_file::load_from_file_contents, loader_archetype::ArchetypeLoader,
-    loader_directory::DirectoryLoader, loader_rrd::RrdLoader,
+    load_file::load_from_file_contents,
+    loader_archetype::ArchetypeLoader,
+    loader_arrow::{ArrowColumnMapping, ArrowColumnRule, ArrowLoader, ArrowTimelineKind},
+    loader_directory::DirectoryLoader,
+    loader_mcap::McapLoader,
+    loader_rrd::RrdLoader,
+    loader_urdf::UrdfLoader,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -176,6 +185,9 @@ impl DataLoaderSettings {
 ///     - [Images]
 ///     - [Point clouds]
 ///     - [Text files]
+/// - [`ArrowLoader`] for [Arrow tables].
+/// - [`McapLoader`] for [MCAP files] (e.g. ROS 2 bags).
+/// - [`UrdfLoader`] for [URDF robot descriptions].
 /// - [`DirectoryLoader`] for recursively loading folders.
 /// - [`ExternalLoader`], which looks for user-defined data loaders in $PATH.
 ///
@@ -199,6 +211,9 @@ impl DataLoaderSettings {
 /// On native, [`DataLoader`]s are executed in parallel.
 ///
 /// [Rerun files]: crate::SUPPORTED_RERUN_EXTENSIONS
+/// [Arrow tables]: crate::SUPPORTED_ARROW_EXTENSIONS
+/// [MCAP files]: crate::SUPPORTED_MCAP_EXTENSIONS
+/// [URDF robot descriptions]: crate::SUPPORTED_URDF_EXTENSIONS
 /// [3D models]: crate::SUPPORTED_MESH_EXTENSIONS
 /// [Images]: crate::SUPPORTED_IMAGE_EXTENSIONS
 /// [Point clouds]: crate::SUPPORTED_POINT_CLOUD_EXTENSIONS
@@ -366,6 +381,9 @@ static BUILTIN_LOADERS: Lazy<Vec<Arc<dyn DataLoader>>> = Lazy::new(|| {
     vec![
         Arc::new(RrdLoader) as Arc<dyn DataLoader>,
         Arc::new(ArchetypeLoader),
+        Arc::new(ArrowLoader),
+        Arc::new(McapLoader),
+        Arc::new(UrdfLoader),
         Arc::new(DirectoryLoader),
         #[cfg(not(target_arch = "wasm32"))]
         Arc::new(ExternalLoader),
@@ -418,11 +436,20 @@ pub const SUPPORTED_IMAGE_EXTENSIONS: &[&str] = &[
 
 pub const SUPPORTED_MESH_EXTENSIONS: &[&str] = &["glb", "gltf", "obj", "stl"];
 
-// TODO(#4532): `.ply` data loader should support 2D point cloud & meshes
-pub const SUPPORTED_POINT_CLOUD_EXTENSIONS: &[&str] = &["ply"];
+/// Point clouds. `.ply` files that contain faces are loaded as meshes instead.
+pub const SUPPORTED_POINT_CLOUD_EXTENSIONS: &[&str] = &["las", "pcd", "ply"];
 
 pub const SUPPORTED_RERUN_EXTENSIONS: &[&str] = &["rbl", "rrd"];
 
+/// Arrow IPC and Parquet files, see [`ArrowLoader`].
+pub const SUPPORTED_ARROW_EXTENSIONS: &[&str] = &["arrow", "feather", "ipc", "parquet"];
+
+/// MCAP files, see [`McapLoader`].
+pub const SUPPORTED_MCAP_EXTENSIONS: &[&str] = &["mcap"];
+
+/// URDF robot descriptions, see [`UrdfLoader`].
+pub const SUPPORTED_URDF_EXTENSIONS: &[&str] = &["urdf"];
+
 // TODO(#4555): Add catch-all builtin `DataLoader` for text files
 pub const SUPPORTED_TEXT_EXTENSIONS: &[&str] = &["txt", "md"];
 
@@ -434,6 +461,9 @@ pub fn supported_extensions() -> impl Iterator<Item = &'static str> {
         .chain(SUPPORTED_MESH_EXTENSIONS)
         .chain(SUPPORTED_POINT_CLOUD_EXTENSIONS)
         .chain(SUPPORTED_TEXT_EXTENSIONS)
+        .chain(SUPPORTED_ARROW_EXTENSIONS)
+        .chain(SUPPORTED_MCAP_EXTENSIONS)
+        .chain(SUPPORTED_URDF_EXTENSIONS)
         .copied()
 }
 
@@ -444,4 +474,7 @@ pub fn is_supported_file_extension(extension: &str) -> bool {
         || SUPPORTED_POINT_CLOUD_EXTENSIONS.contains(&extension)
         || SUPPORTED_RERUN_EXTENSIONS.contains(&extension)
         || SUPPORTED_TEXT_EXTENSIONS.contains(&extension)
+        || SUPPORTED_ARROW_EXTENSIONS.contains(&extension)
+        || SUPPORTED_MCAP_EXTENSIONS.contains(&extension)
+        || SUPPORTED_URDF_EXTENSIONS.contains(&extension)
 }
@@ -106,7 +106,12 @@ impl DataLoader for ArchetypeLoader {
             )?);
         } else if crate::SUPPORTED_POINT_CLOUD_EXTENSIONS.contains(&extension.as_str()) {
             re_log::debug!(?filepath, loader = self.name(), "Loading 3D point cloud…",);
-            rows.extend(load_point_cloud(timepoint, entity_path, &contents)?);
+            rows.extend(load_point_cloud(
+                &extension,
+                &timepoint,
+                &entity_path,
+                &contents,
+            )?);
         } else if crate::SUPPORTED_TEXT_EXTENSIONS.contains(&extension.as_str()) {
             re_log::debug!(?filepath, loader = self.name(), "Loading text document…",);
             rows.extend(load_text_document(
@@ -180,24 +185,24 @@ fn load_mesh(
 }
 
 fn load_point_cloud(
-    timepoint: TimePoint,
-    entity_path: EntityPath,
+    extension: &str,
+    timepoint: &TimePoint,
+    entity_path: &EntityPath,
     contents: &[u8],
-) -> Result<impl ExactSizeIterator<Item = Chunk>, DataLoaderError> {
+) -> Result<Vec<Chunk>, DataLoaderError> {
     re_tracing::profile_function!();
 
-    let rows = [
-        {
-            // TODO(#4532): `.ply` data loader should support 2D point cloud & meshes
-            let points3d = re_types::archetypes::Points3D::from_file_contents(contents)?;
-            Chunk::builder(entity_path)
-                .with_archetype(RowId::new(), timepoint, &points3d)
-                .build()?
-        },
-        //
-    ];
+    match extension {
+        "las" => Ok(crate::point_cloud::las::parse(contents)?.into_chunks(entity_path, timepoint)?),
 
-    Ok(rows.into_iter())
+        "pcd" => Ok(crate::point_cloud::pcd::parse(contents)?.into_chunks(entity_path, timepoint)?),
+
+        _ => Ok(crate::point_cloud::ply::load(
+            contents,
+            entity_path,
+            timepoint,
+        )?),
+    }
 }
 
 fn load_text_document(
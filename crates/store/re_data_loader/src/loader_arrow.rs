@@ -0,0 +1,835 @@
+use std::collections::BTreeMap;
+
+use arrow2::{
+    array::{
+        Array as ArrowArray, BooleanArray as ArrowBooleanArray, ListArray as ArrowListArray,
+        PrimitiveArray as ArrowPrimitiveArray, StructArray as ArrowStructArray,
+    },
+    datatypes::{
+        DataType as ArrowDatatype, Field as ArrowField, Schema as ArrowSchema,
+        TimeUnit as ArrowTimeUnit,
+    },
+    offset::Offsets as ArrowOffsets,
+};
+
+use re_chunk::{Chunk, ChunkId, ChunkTimeline, RowId, TransportChunk};
+use re_log_types::{EntityPath, TimeType, Timeline};
+use re_types::{ComponentName, Loggable as _};
+
+use crate::{DataLoader, DataLoaderError, LoadedData};
+
+// ---
+
+/// Loads tabular data from [Arrow IPC files](https://arrow.apache.org/docs/format/Columnar.html#ipc-file-format)
+/// and [Parquet files](https://parquet.apache.org/).
+///
+/// Every row of the table becomes a row in the resulting [`Chunk`]s. Columns are mapped as
+/// follows:
+/// * A column named `rerun.controls.RowId` holds the [`RowId`]s. If missing, new ones are
+///   generated.
+/// * A column whose name starts with `timeline:` is a timeline, e.g. `timeline:frame`.
+///   Integer columns are sequence timelines, timestamp columns are temporal ones.
+/// * Any other timestamp column is a temporal timeline named after the column.
+/// * Every other column is a component named after the column.
+///   List columns are taken as-is (one batch per row), other columns are logged as one
+///   instance per row.
+///
+/// Rows that don't have a value on any timeline are logged as static data.
+///
+/// If a column carries Rerun's own `rerun.kind` field metadata (e.g. files written by
+/// `rerun rrd export`), that takes precedence over the naming convention.
+///
+/// Data is logged to the entity path stored in the file's `rerun.entity_path` schema metadata,
+/// if any, or to an entity path derived from the file path otherwise.
+///
+/// ## Sidecar mapping file
+///
+/// When loading from a path, the mapping can be overridden with a JSON file sitting next to the
+/// table, named after it with an extra `.rerun.json` extension (e.g. `imu.parquet.rerun.json`
+/// for `imu.parquet`). It takes precedence over everything above, and columns it doesn't
+/// mention fall back to the rules above:
+/// ```json
+/// {
+///     "entity_path": "sensors/imu",
+///     "columns": {
+///         "id": "row_id",
+///         "stamp_ns": { "timeline": { "name": "sensor_time", "kind": "time" } },
+///         "seq": { "timeline": {} },
+///         "acc": { "component": { "name": "rerun.components.Position3D" } },
+///         "debug_info": "ignore"
+///     }
+/// }
+/// ```
+///
+/// See [`ArrowColumnMapping`].
+pub struct ArrowLoader;
+
+impl DataLoader for ArrowLoader {
+    #[inline]
+    fn name(&self) -> String {
+        "rerun.data_loaders.Arrow".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), crate::DataLoaderError> {
+        use anyhow::Context as _;
+
+        if filepath.is_dir() {
+            return Err(crate::DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        let extension = crate::extension(&filepath);
+        if !crate::SUPPORTED_ARROW_EXTENSIONS.contains(&extension.as_str()) {
+            return Err(crate::DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let contents = std::fs::read(&filepath)
+            .with_context(|| format!("Failed to read file {filepath:?}"))?;
+
+        let mapping = ArrowColumnMapping::from_sidecar_of(&filepath)?;
+
+        send_chunks(
+            load_table(settings, &filepath, &contents, mapping.as_ref())?,
+            &tx,
+        );
+
+        Ok(())
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), crate::DataLoaderError> {
+        let extension = crate::extension(&filepath);
+        if !crate::SUPPORTED_ARROW_EXTENSIONS.contains(&extension.as_str()) {
+            return Err(crate::DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        // NOTE: There is no sidecar mapping file to look at when all we have is the contents.
+        send_chunks(load_table(settings, &filepath, &contents, None)?, &tx);
+
+        Ok(())
+    }
+}
+
+fn send_chunks(chunks: Vec<Chunk>, tx: &std::sync::mpsc::Sender<LoadedData>) {
+    for chunk in chunks {
+        if tx.send(chunk.into()).is_err() {
+            break; // The other end has decided to hang up, not our problem.
+        }
+    }
+}
+
+// ---
+
+/// The contents of a sidecar mapping file, see [`ArrowLoader`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArrowColumnMapping {
+    /// The entity path to log the table to.
+    #[serde(default)]
+    pub entity_path: Option<String>,
+
+    /// How to interpret the columns, by column name.
+    #[serde(default)]
+    pub columns: BTreeMap<String, ArrowColumnRule>,
+}
+
+/// How to interpret a column of a table, see [`ArrowColumnMapping`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum ArrowColumnRule {
+    /// The column holds the [`RowId`]s.
+    RowId,
+
+    /// The column is a timeline.
+    Timeline {
+        /// The name of the timeline. Defaults to the name of the column.
+        #[serde(default)]
+        name: Option<String>,
+
+        /// `time` (nanoseconds, or a timestamp column) or `sequence`.
+        ///
+        /// Defaults to `time` for timestamp columns, and `sequence` otherwise.
+        #[serde(default)]
+        kind: Option<ArrowTimelineKind>,
+    },
+
+    /// The column is a component.
+    Component {
+        /// The name of the component. Defaults to the name of the column.
+        #[serde(default)]
+        name: Option<String>,
+    },
+
+    /// The column is skipped.
+    Ignore,
+}
+
+/// The type of the timeline in an [`ArrowColumnRule::Timeline`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArrowTimelineKind {
+    /// A temporal timeline, see [`TimeType::Time`].
+    Time,
+
+    /// A sequence timeline, see [`TimeType::Sequence`].
+    Sequence,
+}
+
+impl ArrowColumnMapping {
+    /// The extension appended to the name of a table to get the name of its sidecar mapping file.
+    pub const SIDECAR_EXTENSION: &'static str = "rerun.json";
+
+    /// Parses a mapping from the contents of a sidecar mapping file.
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Reads the sidecar mapping file of the table at `filepath`, if there is one.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_sidecar_of(filepath: &std::path::Path) -> anyhow::Result<Option<Self>> {
+        use anyhow::Context as _;
+
+        let mut sidecar_path = filepath.as_os_str().to_owned();
+        sidecar_path.push(".");
+        sidecar_path.push(Self::SIDECAR_EXTENSION);
+        let sidecar_path = std::path::PathBuf::from(sidecar_path);
+
+        if !sidecar_path.is_file() {
+            return Ok(None);
+        }
+
+        re_log::debug!(?sidecar_path, "Using sidecar mapping file");
+
+        let json = std::fs::read_to_string(&sidecar_path)
+            .with_context(|| format!("Failed to read mapping file {sidecar_path:?}"))?;
+        Self::from_json(&json)
+            .with_context(|| format!("Failed to parse mapping file {sidecar_path:?}"))
+            .map(Some)
+    }
+}
+
+// ---
+
+/// What a column of the table maps to.
+enum Column {
+    RowId,
+    Timeline(Timeline),
+    Component(ComponentName),
+    Ignored,
+}
+
+impl Column {
+    fn from_field(field: &ArrowField, mapping: Option<&ArrowColumnMapping>) -> Self {
+        let timeline = |name: &str| {
+            let typ = match field.data_type().to_logical_type() {
+                ArrowDatatype::Timestamp(_, _) => TimeType::Time,
+                _ => TimeType::Sequence,
+            };
+            Self::Timeline(Timeline::new(name, typ))
+        };
+
+        if let Some(rule) = mapping.and_then(|mapping| mapping.columns.get(&field.name)) {
+            return match rule {
+                ArrowColumnRule::RowId => Self::RowId,
+                ArrowColumnRule::Timeline { name, kind } => {
+                    let name = name.as_deref().unwrap_or(&field.name);
+                    match kind {
+                        Some(ArrowTimelineKind::Time) => {
+                            Self::Timeline(Timeline::new(name, TimeType::Time))
+                        }
+                        Some(ArrowTimelineKind::Sequence) => {
+                            Self::Timeline(Timeline::new(name, TimeType::Sequence))
+                        }
+                        None => timeline(name),
+                    }
+                }
+                ArrowColumnRule::Component { name } => {
+                    Self::Component(name.as_deref().unwrap_or(&field.name).to_owned().into())
+                }
+                ArrowColumnRule::Ignore => Self::Ignored,
+            };
+        }
+
+        match field
+            .metadata
+            .get(TransportChunk::FIELD_METADATA_KEY_KIND)
+            .map(|kind| kind.as_str())
+        {
+            Some(TransportChunk::FIELD_METADATA_VALUE_KIND_CONTROL) => {
+                if field.name == RowId::name().as_str() {
+                    Self::RowId
+                } else {
+                    Self::Ignored
+                }
+            }
+            Some(TransportChunk::FIELD_METADATA_VALUE_KIND_TIME) => timeline(&field.name),
+            Some(TransportChunk::FIELD_METADATA_VALUE_KIND_DATA) => {
+                Self::Component(field.name.clone().into())
+            }
+            _ => {
+                if field.name == RowId::name().as_str() {
+                    Self::RowId
+                } else if let Some(name) = field.name.strip_prefix("timeline:") {
+                    timeline(name)
+                } else if matches!(
+                    field.data_type().to_logical_type(),
+                    ArrowDatatype::Timestamp(_, _)
+                ) {
+                    timeline(&field.name)
+                } else {
+                    Self::Component(field.name.clone().into())
+                }
+            }
+        }
+    }
+}
+
+/// Loads an Arrow IPC or Parquet table, depending on the extension of `filepath`.
+fn load_table(
+    settings: &crate::DataLoaderSettings,
+    filepath: &std::path::Path,
+    contents: &[u8],
+    mapping: Option<&ArrowColumnMapping>,
+) -> Result<Vec<Chunk>, DataLoaderError> {
+    re_tracing::profile_function!();
+
+    use anyhow::Context as _;
+
+    re_log::debug!(?filepath, "Loading Arrow table…");
+
+    let mut reader = std::io::Cursor::new(contents);
+
+    let mut chunks = Vec::new();
+    if crate::extension(filepath) == "parquet" {
+        use arrow2::io::parquet::read::{infer_schema, read_metadata, FileReader};
+
+        let metadata = read_metadata(&mut reader)
+            .with_context(|| format!("Failed to read Parquet file {filepath:?}"))?;
+        let schema = infer_schema(&metadata)
+            .with_context(|| format!("Failed to read Parquet schema {filepath:?}"))?;
+
+        let (entity_path, columns) = entity_path_and_columns(settings, filepath, &schema, mapping);
+
+        let batches = FileReader::new(reader, metadata.row_groups, schema, None, None, None);
+        for batch in batches {
+            let batch =
+                batch.with_context(|| format!("Failed to read Parquet row group {filepath:?}"))?;
+            chunks.extend(batch_to_chunks(&entity_path, &columns, batch.arrays())?);
+        }
+    } else {
+        use arrow2::io::ipc::read::{read_file_metadata, FileReader};
+
+        let metadata = read_file_metadata(&mut reader)
+            .with_context(|| format!("Failed to read Arrow IPC file {filepath:?}"))?;
+
+        let (entity_path, columns) =
+            entity_path_and_columns(settings, filepath, &metadata.schema, mapping);
+
+        for batch in FileReader::new(reader, metadata, None, None) {
+            let batch = batch
+                .with_context(|| format!("Failed to read Arrow IPC record batch {filepath:?}"))?;
+            chunks.extend(batch_to_chunks(&entity_path, &columns, batch.arrays())?);
+        }
+    }
+
+    Ok(chunks)
+}
+
+fn entity_path_and_columns(
+    settings: &crate::DataLoaderSettings,
+    filepath: &std::path::Path,
+    schema: &ArrowSchema,
+    mapping: Option<&ArrowColumnMapping>,
+) -> (EntityPath, Vec<Column>) {
+    let mut entity_path = mapping
+        .and_then(|mapping| mapping.entity_path.as_deref())
+        .or_else(|| {
+            schema
+                .metadata
+                .get(TransportChunk::CHUNK_METADATA_KEY_ENTITY_PATH)
+                .map(String::as_str)
+        })
+        .map_or_else(
+            || EntityPath::from_file_path(filepath),
+            EntityPath::parse_forgiving,
+        );
+    if let Some(entity_path_prefix) = &settings.entity_path_prefix {
+        entity_path = entity_path_prefix.join(&entity_path);
+    }
+
+    let columns = schema
+        .fields
+        .iter()
+        .map(|field| Column::from_field(field, mapping))
+        .collect();
+
+    (entity_path, columns)
+}
+
+/// Converts a single record batch into [`Chunk`]s.
+///
+/// Chunks require dense timelines, so rows are split into one chunk per set of timelines they
+/// have values for.
+fn batch_to_chunks(
+    entity_path: &EntityPath,
+    columns: &[Column],
+    arrays: &[Box<dyn ArrowArray>],
+) -> Result<Vec<Chunk>, DataLoaderError> {
+    re_tracing::profile_function!();
+
+    let num_rows = arrays.first().map_or(0, |array| array.len());
+    if num_rows == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut row_ids = None;
+    let mut timelines = Vec::new();
+    let mut components = Vec::new();
+
+    for (column, array) in itertools::izip!(columns, arrays) {
+        match column {
+            Column::RowId => row_ids = Some(row_ids_from_array(&**array)?),
+            Column::Timeline(timeline) => {
+                timelines.push((*timeline, times_from_array(timeline, &**array)?));
+            }
+            Column::Component(component_name) => {
+                components.push((*component_name, list_array_from_array(&**array)));
+            }
+            Column::Ignored => {}
+        }
+    }
+
+    let row_ids = row_ids.unwrap_or_else(|| {
+        std::iter::successors(Some(RowId::new()), |row_id| Some(row_id.next()))
+            .take(num_rows)
+            .collect()
+    });
+
+    // Group rows by the set of timelines they have values for.
+    let mut rows_per_timeline_set: BTreeMap<Vec<bool>, Vec<bool>> = BTreeMap::new();
+    for row in 0..num_rows {
+        let timeline_set = timelines
+            .iter()
+            .map(|(_, times)| times.is_valid(row))
+            .collect::<Vec<_>>();
+        rows_per_timeline_set
+            .entry(timeline_set)
+            .or_insert_with(|| vec![false; num_rows])[row] = true;
+    }
+
+    let mut chunks = Vec::with_capacity(rows_per_timeline_set.len());
+    for (timeline_set, rows) in rows_per_timeline_set {
+        let filter = ArrowBooleanArray::from_slice(&rows);
+        let is_everything = rows.iter().all(|keep| *keep);
+
+        let row_ids = itertools::izip!(&row_ids, &rows)
+            .filter_map(|(row_id, keep)| keep.then_some(*row_id))
+            .collect::<Vec<_>>();
+
+        let chunk_timelines = itertools::izip!(&timelines, timeline_set)
+            .filter(|(_, has_values)| *has_values)
+            .map(|((timeline, times), _)| {
+                let times = if is_everything {
+                    times.clone()
+                } else {
+                    re_chunk::util::filter_array(times, &filter)
+                };
+                // All these rows have values on this timeline.
+                let times = times.with_validity(None);
+                (*timeline, ChunkTimeline::new(None, *timeline, times))
+            })
+            .collect();
+
+        let chunk_components = components
+            .iter()
+            .map(|(component_name, list_array)| {
+                let list_array = if is_everything {
+                    list_array.clone()
+                } else {
+                    re_chunk::util::filter_array(list_array, &filter)
+                };
+                (*component_name, list_array)
+            })
+            // Columns that have no data at all for these rows are just noise.
+            .filter(|(_, list_array)| list_array.null_count() < list_array.len())
+            .collect();
+
+        chunks.push(Chunk::from_native_row_ids(
+            ChunkId::new(),
+            entity_path.clone(),
+            None,
+            &row_ids,
+            chunk_timelines,
+            chunk_components,
+        )?);
+    }
+
+    Ok(chunks)
+}
+
+fn row_ids_from_array(array: &dyn ArrowArray) -> anyhow::Result<Vec<RowId>> {
+    let struct_array = array
+        .as_any()
+        .downcast_ref::<ArrowStructArray>()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "{} column has the wrong datatype: {:?}",
+                RowId::name(),
+                array.data_type()
+            )
+        })?;
+
+    let [times, counters] = struct_array.values() else {
+        anyhow::bail!("{} column is malformed", RowId::name());
+    };
+    let (Some(times), Some(counters)) = (
+        times.as_any().downcast_ref::<ArrowPrimitiveArray<u64>>(),
+        counters.as_any().downcast_ref::<ArrowPrimitiveArray<u64>>(),
+    ) else {
+        anyhow::bail!("{} column is malformed", RowId::name());
+    };
+
+    Ok(
+        itertools::izip!(times.values().iter(), counters.values().iter())
+            .map(|(&time, &counter)| RowId::from_u128((time as u128) << 64 | (counter as u128)))
+            .collect(),
+    )
+}
+
+/// Returns the times as nanoseconds (temporal timelines) or sequence numbers.
+fn times_from_array(
+    timeline: &Timeline,
+    array: &dyn ArrowArray,
+) -> anyhow::Result<ArrowPrimitiveArray<i64>> {
+    let times = array
+        .as_any()
+        .downcast_ref::<ArrowPrimitiveArray<i64>>()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Timeline column {:?} must be Int64 or Timestamp, got {:?}",
+                timeline.name(),
+                array.data_type()
+            )
+        })?;
+
+    let nanos_per_unit = match times.data_type().to_logical_type() {
+        ArrowDatatype::Timestamp(ArrowTimeUnit::Second, _) => 1_000_000_000,
+        ArrowDatatype::Timestamp(ArrowTimeUnit::Millisecond, _) => 1_000_000,
+        ArrowDatatype::Timestamp(ArrowTimeUnit::Microsecond, _) => 1_000,
+        _ => 1,
+    };
+
+    let values = times
+        .values()
+        .iter()
+        .map(|time| time.saturating_mul(nanos_per_unit))
+        .collect::<Vec<_>>();
+
+    Ok(ArrowPrimitiveArray::new(
+        ArrowDatatype::Int64,
+        values.into(),
+        times.validity().cloned(),
+    ))
+}
+
+/// Components are stored as lists: list columns are kept as-is, anything else becomes a list
+/// of exactly one instance per row.
+fn list_array_from_array(array: &dyn ArrowArray) -> ArrowListArray<i32> {
+    if let Some(list_array) = array.as_any().downcast_ref::<ArrowListArray<i32>>() {
+        return list_array.clone();
+    }
+
+    // NOTE: Can only fail on `i32` overflow, which would require a ~2GiB column of
+    // single-instance components to begin with.
+    let offsets = ArrowOffsets::<i32>::try_from_lengths(std::iter::repeat(1).take(array.len()))
+        .unwrap_or_default();
+
+    ArrowListArray::new(
+        ArrowListArray::<i32>::default_datatype(array.data_type().clone()),
+        offsets.into(),
+        array.to_boxed(),
+        array.validity().cloned(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow2::{
+        array::{Int64Array, UInt32Array, Utf8Array},
+        chunk::Chunk as ArrowChunk,
+        datatypes::Schema as ArrowSchema,
+    };
+    use re_log_types::{StoreId, StoreKind};
+    use re_types::Loggable as _;
+
+    use super::*;
+
+    fn settings() -> crate::DataLoaderSettings {
+        crate::DataLoaderSettings::recommended(StoreId::random(StoreKind::Recording))
+    }
+
+    /// A small table: two rows on two timelines, and a third one without any time.
+    fn table() -> (ArrowSchema, ArrowChunk<Box<dyn ArrowArray>>) {
+        let frames = Int64Array::from([Some(1), Some(2), None]);
+        let log_times = Int64Array::from([Some(10), Some(20), None])
+            .to(ArrowDatatype::Timestamp(ArrowTimeUnit::Millisecond, None));
+        let colors = UInt32Array::from_slice([0xFF0000FF, 0x00FF00FF, 0x0000FFFF]);
+        let labels = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+
+        let schema = ArrowSchema::from(vec![
+            ArrowField::new("timeline:frame", frames.data_type().clone(), true),
+            ArrowField::new("log_time", log_times.data_type().clone(), true),
+            ArrowField::new("rerun.components.Color", colors.data_type().clone(), false),
+            ArrowField::new("label", labels.data_type().clone(), false),
+        ]);
+        let chunk = ArrowChunk::new(vec![
+            frames.boxed(),
+            log_times.boxed(),
+            colors.boxed(),
+            labels.boxed(),
+        ]);
+
+        (schema, chunk)
+    }
+
+    fn to_arrow_ipc(schema: &ArrowSchema, chunk: &ArrowChunk<Box<dyn ArrowArray>>) -> Vec<u8> {
+        use arrow2::io::ipc::write::{FileWriter, WriteOptions};
+
+        let mut buf = Vec::new();
+        let mut writer = FileWriter::try_new(
+            &mut buf,
+            schema.clone(),
+            None,
+            WriteOptions { compression: None },
+        )
+        .unwrap();
+        writer.write(chunk, None).unwrap();
+        writer.finish().unwrap();
+
+        buf
+    }
+
+    fn to_parquet(schema: &ArrowSchema, chunk: &ArrowChunk<Box<dyn ArrowArray>>) -> Vec<u8> {
+        use arrow2::io::parquet::write::{
+            transverse, CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version,
+            WriteOptions,
+        };
+
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: CompressionOptions::Snappy,
+            version: Version::V2,
+            data_pagesize_limit: None,
+        };
+        let encodings = schema
+            .fields
+            .iter()
+            .map(|field| transverse(field.data_type(), |_| Encoding::Plain))
+            .collect();
+        let row_groups = RowGroupIterator::try_new(
+            std::iter::once(Ok(chunk.clone())),
+            schema,
+            options,
+            encodings,
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = FileWriter::try_new(&mut buf, schema.clone(), options).unwrap();
+        for row_group in row_groups {
+            writer.write(row_group.unwrap()).unwrap();
+        }
+        writer.end(None).unwrap();
+
+        buf
+    }
+
+    #[test]
+    fn naming_convention() {
+        let (schema, chunk) = table();
+
+        for (filename, contents) in [
+            ("table.arrow", to_arrow_ipc(&schema, &chunk)),
+            ("table.parquet", to_parquet(&schema, &chunk)),
+        ] {
+            let chunks = load_table(&settings(), filename.as_ref(), &contents, None).unwrap();
+
+            // One chunk for the row without time, one for the rest.
+            assert_eq!(2, chunks.len(), "{filename}");
+            let (static_chunk, temporal_chunk) = (&chunks[0], &chunks[1]);
+
+            assert_eq!(
+                &EntityPath::from_file_path(filename.as_ref()),
+                temporal_chunk.entity_path()
+            );
+
+            assert!(static_chunk.is_static());
+            assert_eq!(1, static_chunk.num_rows());
+
+            assert_eq!(2, temporal_chunk.num_rows());
+            let frame = Timeline::new_sequence("frame");
+            let log_time = Timeline::new_temporal("log_time");
+            assert_eq!(
+                vec![frame, log_time],
+                temporal_chunk
+                    .timelines()
+                    .keys()
+                    .copied()
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(&[1_i64, 2], temporal_chunk.timelines()[&frame].times_raw());
+            // Milliseconds are converted to nanoseconds.
+            assert_eq!(
+                &[10_000_000_i64, 20_000_000],
+                temporal_chunk.timelines()[&log_time].times_raw()
+            );
+
+            let components = temporal_chunk.components();
+            assert_eq!(2, components.len());
+            // Non-list columns hold exactly one instance per row.
+            let colors = &components[&ComponentName::from("rerun.components.Color")];
+            assert_eq!(&[0, 1, 2], colors.offsets().as_slice());
+            assert!(components.contains_key(&ComponentName::from("label")));
+        }
+    }
+
+    #[test]
+    fn row_ids_roundtrip() {
+        let (mut schema, chunk) = table();
+
+        let row_ids = [RowId::new(), RowId::new(), RowId::new()];
+        let mut arrays = chunk.into_arrays();
+        arrays.push(RowId::to_arrow(row_ids).unwrap());
+        schema.fields.push(ArrowField::new(
+            RowId::name().to_string(),
+            RowId::arrow_datatype(),
+            false,
+        ));
+        let chunk = ArrowChunk::new(arrays);
+
+        let chunks = load_table(
+            &settings(),
+            "table.arrow".as_ref(),
+            &to_arrow_ipc(&schema, &chunk),
+            None,
+        )
+        .unwrap();
+
+        let mut loaded_row_ids = chunks
+            .iter()
+            .flat_map(|chunk| chunk.row_ids())
+            .collect::<Vec<_>>();
+        loaded_row_ids.sort();
+        assert_eq!(row_ids.to_vec(), loaded_row_ids);
+    }
+
+    #[test]
+    fn mapping() {
+        let (schema, chunk) = table();
+
+        let mapping = ArrowColumnMapping::from_json(
+            r#"{
+                "entity_path": "sensors/imu",
+                "columns": {
+                    "timeline:frame": { "timeline": { "name": "sample", "kind": "time" } },
+                    "log_time": "ignore",
+                    "label": { "component": { "name": "rerun.components.Text" } }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let chunks = load_table(
+            &settings(),
+            "table.parquet".as_ref(),
+            &to_parquet(&schema, &chunk),
+            Some(&mapping),
+        )
+        .unwrap();
+        assert_eq!(2, chunks.len());
+        let temporal_chunk = &chunks[1];
+
+        assert_eq!(
+            &EntityPath::from("sensors/imu"),
+            temporal_chunk.entity_path()
+        );
+        assert_eq!(
+            vec![Timeline::new_temporal("sample")],
+            temporal_chunk
+                .timelines()
+                .keys()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                ComponentName::from("rerun.components.Color"),
+                ComponentName::from("rerun.components.Text"),
+            ],
+            temporal_chunk
+                .components()
+                .keys()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn mapping_parse_errors() {
+        assert!(ArrowColumnMapping::from_json("{}").is_ok());
+        assert!(ArrowColumnMapping::from_json(r#"{ "entity": "typo" }"#).is_err());
+        assert!(ArrowColumnMapping::from_json(r#"{ "columns": { "a": "nope" } }"#).is_err());
+        assert!(ArrowColumnMapping::from_json(
+            r#"{ "columns": { "a": { "timeline": { "kind": "nope" } } } }"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn sidecar() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let (schema, chunk) = table();
+        let filepath = dir.path().join("imu.arrow");
+        std::fs::write(&filepath, to_arrow_ipc(&schema, &chunk)).unwrap();
+
+        assert_eq!(
+            None,
+            ArrowColumnMapping::from_sidecar_of(&filepath).unwrap()
+        );
+
+        std::fs::write(
+            dir.path().join("imu.arrow.rerun.json"),
+            r#"{ "entity_path": "sensors/imu" }"#,
+        )
+        .unwrap();
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        ArrowLoader
+            .load_from_path(&settings(), filepath, tx)
+            .unwrap();
+
+        let chunks = rx.try_iter().collect::<Vec<_>>();
+        assert_eq!(2, chunks.len());
+        for chunk in chunks {
+            let LoadedData::Chunk(chunk) = chunk else {
+                panic!("expected a chunk");
+            };
+            assert_eq!(&EntityPath::from("sensors/imu"), chunk.entity_path());
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Batches decoded messages into [`Chunk`]s, rather than logging one tiny chunk per message.
+
+use std::collections::BTreeMap;
+
+use arrow2::array::Array as ArrowArray;
+
+use re_chunk::{Chunk, RowId};
+use re_log_types::{EntityPath, TimePoint, Timeline};
+use re_types::{AsComponents, ComponentBatch, ComponentName, SizeBytes as _};
+
+/// A single row's worth of decoded data, waiting to be batched.
+pub struct Row {
+    pub entity_path: EntityPath,
+    pub timepoint: TimePoint,
+    pub components: Vec<(ComponentName, Box<dyn ArrowArray>)>,
+}
+
+impl Row {
+    /// Destructures an archetype into component columns.
+    pub fn new(
+        entity_path: EntityPath,
+        timepoint: TimePoint,
+        as_components: &dyn AsComponents,
+    ) -> Self {
+        let batches = as_components.as_component_batches();
+        Self::from_component_batches(
+            entity_path,
+            timepoint,
+            batches.iter().map(|batch| batch.as_ref()),
+        )
+    }
+
+    /// Serializes the given [`ComponentBatch`]es.
+    pub fn from_component_batches<'a>(
+        entity_path: EntityPath,
+        timepoint: TimePoint,
+        component_batches: impl IntoIterator<Item = &'a dyn ComponentBatch>,
+    ) -> Self {
+        let components = component_batches
+            .into_iter()
+            .filter_map(|component_batch| {
+                component_batch
+                    .to_arrow()
+                    .ok()
+                    .map(|array| (component_batch.name(), array))
+            })
+            .collect();
+
+        Self {
+            entity_path,
+            timepoint,
+            components,
+        }
+    }
+}
+
+/// Rows that will end up in the same [`Chunk`].
+#[derive(Default)]
+struct PendingChunk {
+    rows: Vec<Row>,
+    num_bytes: u64,
+}
+
+/// Groups [`Row`]s into [`Chunk`]s, per entity and set of timelines.
+///
+/// A chunk is emitted as soon as it reaches either [`Self::MAX_ROWS`] or [`Self::MAX_BYTES`],
+/// the rest when calling [`Self::flush`].
+#[derive(Default)]
+pub struct ChunkBatcher {
+    pending: BTreeMap<(EntityPath, Vec<Timeline>), PendingChunk>,
+}
+
+impl ChunkBatcher {
+    pub const MAX_ROWS: usize = 1024;
+    pub const MAX_BYTES: u64 = 4 * 1024 * 1024;
+
+    /// Adds a row to its batch, returning that batch as a [`Chunk`] if it is now full.
+    pub fn push(&mut self, row: Row) -> Option<Chunk> {
+        // Chunks must have dense timelines.
+        let key = (
+            row.entity_path.clone(),
+            row.timepoint.timelines().copied().collect(),
+        );
+
+        let pending = self.pending.entry(key.clone()).or_default();
+        pending.num_bytes += row
+            .components
+            .iter()
+            .map(|(_, array)| array.total_size_bytes())
+            .sum::<u64>();
+        pending.rows.push(row);
+
+        if pending.rows.len() >= Self::MAX_ROWS || pending.num_bytes >= Self::MAX_BYTES {
+            let pending = self.pending.remove(&key)?;
+            build_chunk(key.0, pending.rows)
+        } else {
+            None
+        }
+    }
+
+    /// Returns all the remaining batches as [`Chunk`]s.
+    pub fn flush(&mut self) -> Vec<Chunk> {
+        std::mem::take(&mut self.pending)
+            .into_iter()
+            .filter_map(|((entity_path, _), pending)| build_chunk(entity_path, pending.rows))
+            .collect()
+    }
+}
+
+fn build_chunk(entity_path: EntityPath, rows: Vec<Row>) -> Option<Chunk> {
+    re_tracing::profile_function!();
+
+    let mut builder = Chunk::builder(entity_path.clone());
+    for row in rows {
+        builder = builder.with_row(RowId::new(), row.timepoint, row.components);
+    }
+
+    match builder.build() {
+        Ok(chunk) => Some(chunk),
+        Err(err) => {
+            re_log::warn_once!("Failed to batch messages logged to {entity_path}: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::TimeInt;
+    use re_types::archetypes::Scalar;
+
+    use super::*;
+
+    fn row(entity_path: &str, frame: Option<i64>, value: f64) -> Row {
+        let timepoint = frame.map_or_else(TimePoint::default, |frame| {
+            TimePoint::default().with(
+                Timeline::new_sequence("frame"),
+                TimeInt::new_temporal(frame),
+            )
+        });
+        Row::new(entity_path.into(), timepoint, &Scalar::new(value))
+    }
+
+    #[test]
+    fn batches_per_entity_and_timelines() {
+        let mut batcher = ChunkBatcher::default();
+
+        for frame in 0..10 {
+            assert!(batcher.push(row("a", Some(frame), 1.0)).is_none());
+            assert!(batcher.push(row("b", Some(frame), 2.0)).is_none());
+        }
+        assert!(batcher.push(row("a", None, 3.0)).is_none());
+
+        let chunks = batcher.flush();
+        assert_eq!(3, chunks.len());
+
+        let num_rows = chunks
+            .iter()
+            .map(|chunk| {
+                (
+                    chunk.entity_path().to_string(),
+                    chunk.is_static(),
+                    chunk.num_rows(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("/a".to_owned(), true, 1),
+                ("/a".to_owned(), false, 10),
+                ("/b".to_owned(), false, 10),
+            ],
+            num_rows
+        );
+
+        assert!(batcher.flush().is_empty());
+    }
+
+    #[test]
+    fn emits_full_batches() {
+        let mut batcher = ChunkBatcher::default();
+
+        let mut chunks = Vec::new();
+        for frame in 0..ChunkBatcher::MAX_ROWS as i64 + 1 {
+            chunks.extend(batcher.push(row("a", Some(frame), 1.0)));
+        }
+        assert_eq!(1, chunks.len());
+        assert_eq!(ChunkBatcher::MAX_ROWS, chunks[0].num_rows());
+
+        let rest = batcher.flush();
+        assert_eq!(1, rest.len());
+        assert_eq!(1, rest[0].num_rows());
+    }
+}
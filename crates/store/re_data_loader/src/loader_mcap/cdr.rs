@@ -0,0 +1,298 @@
+//! A minimal reader for [CDR](https://www.omg.org/spec/DDSI-RTPS/2.3/PDF)-encoded data, which is
+//! how ROS 2 serializes its messages.
+
+use anyhow::Context as _;
+
+/// Reads primitives out of a CDR-encoded buffer, taking care of endianness and alignment.
+pub struct CdrReader<'a> {
+    /// The serialized data, without the encapsulation header.
+    data: &'a [u8],
+
+    /// Current read position in `data`.
+    ///
+    /// Alignment is relative to the start of `data`.
+    pos: usize,
+
+    little_endian: bool,
+}
+
+macro_rules! read_primitive {
+    ($name:ident, $ty:ty) => {
+        pub fn $name(&mut self) -> anyhow::Result<$ty> {
+            const SIZE: usize = std::mem::size_of::<$ty>();
+            self.align(SIZE);
+            let bytes: [u8; SIZE] = self.bytes(SIZE)?.try_into()?;
+            Ok(if self.little_endian {
+                <$ty>::from_le_bytes(bytes)
+            } else {
+                <$ty>::from_be_bytes(bytes)
+            })
+        }
+    };
+}
+
+impl<'a> CdrReader<'a> {
+    /// Parses the 4-byte encapsulation header.
+    pub fn new(data: &'a [u8]) -> anyhow::Result<Self> {
+        anyhow::ensure!(data.len() >= 4, "CDR data is missing its header");
+
+        // See the `Encapsulation identifier` table of the DDS-XTYPES spec: odd identifiers are
+        // little-endian, even ones are big-endian.
+        let little_endian = match [data[0], data[1]] {
+            [0x00, 0x00 | 0x02] => false,
+            [0x00, 0x01 | 0x03] => true,
+            [a, b] => anyhow::bail!("unsupported CDR encapsulation: {a:#04x}{b:02x}"),
+        };
+
+        Ok(Self {
+            data: &data[4..],
+            pos: 0,
+            little_endian,
+        })
+    }
+
+    fn align(&mut self, alignment: usize) {
+        self.pos = self.pos.next_multiple_of(alignment);
+    }
+
+    fn bytes(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        let bytes = self
+            .pos
+            .checked_add(len)
+            .and_then(|end| self.data.get(self.pos..end))
+            .with_context(|| {
+                format!(
+                    "unexpected end of CDR data: needed {len} bytes at offset {}, got {}",
+                    self.pos,
+                    self.data.len()
+                )
+            })?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    read_primitive!(u8, u8);
+    read_primitive!(i8, i8);
+    read_primitive!(u16, u16);
+    read_primitive!(i16, i16);
+    read_primitive!(u32, u32);
+    read_primitive!(i32, i32);
+    read_primitive!(u64, u64);
+    read_primitive!(i64, i64);
+    read_primitive!(f32, f32);
+    read_primitive!(f64, f64);
+
+    pub fn bool(&mut self) -> anyhow::Result<bool> {
+        Ok(self.u8()? != 0)
+    }
+
+    /// The length of a sequence, i.e. a variable-sized array.
+    ///
+    /// Every element takes at least one byte, so lengths beyond the remaining data are rejected
+    /// before anyone allocates for them.
+    pub fn sequence_len(&mut self) -> anyhow::Result<usize> {
+        let len = self.u32()? as usize;
+        let remaining = self.data.len().saturating_sub(self.pos);
+        anyhow::ensure!(
+            len <= remaining,
+            "CDR sequence of length {len} at offset {} exceeds the remaining {remaining} bytes",
+            self.pos
+        );
+        Ok(len)
+    }
+
+    pub fn string(&mut self) -> anyhow::Result<String> {
+        // The length includes the null terminator.
+        let len = self.sequence_len()?;
+        let bytes = self.bytes(len)?;
+        let bytes = bytes.strip_suffix(&[0]).unwrap_or(bytes);
+        String::from_utf8(bytes.to_vec()).context("invalid CDR string")
+    }
+
+    /// A `uint8[]` sequence, e.g. image data.
+    pub fn byte_sequence(&mut self) -> anyhow::Result<&'a [u8]> {
+        let len = self.sequence_len()?;
+        self.bytes(len)
+    }
+
+    /// A `float64[N]` fixed-size array.
+    pub fn f64_array<const N: usize>(&mut self) -> anyhow::Result<[f64; N]> {
+        let mut array = [0.0; N];
+        for value in &mut array {
+            *value = self.f64()?;
+        }
+        Ok(array)
+    }
+
+    /// A `float64[]` sequence.
+    pub fn f64_sequence(&mut self) -> anyhow::Result<Vec<f64>> {
+        let len = self.sequence_len()?;
+        (0..len).map(|_| self.f64()).collect()
+    }
+}
+
+/// Writes CDR-encoded data, to build test fixtures.
+#[cfg(test)]
+pub struct CdrWriter {
+    data: Vec<u8>,
+    little_endian: bool,
+}
+
+#[cfg(test)]
+macro_rules! write_primitive {
+    ($name:ident, $ty:ty) => {
+        pub fn $name(&mut self, value: $ty) -> &mut Self {
+            self.align(std::mem::size_of::<$ty>());
+            if self.little_endian {
+                self.data.extend_from_slice(&value.to_le_bytes());
+            } else {
+                self.data.extend_from_slice(&value.to_be_bytes());
+            }
+            self
+        }
+    };
+}
+
+#[cfg(test)]
+impl CdrWriter {
+    pub fn new(little_endian: bool) -> Self {
+        Self {
+            data: vec![0x00, u8::from(little_endian), 0x00, 0x00],
+            little_endian,
+        }
+    }
+
+    fn align(&mut self, alignment: usize) {
+        // Alignment is relative to the end of the encapsulation header.
+        while (self.data.len() - 4) % alignment != 0 {
+            self.data.push(0);
+        }
+    }
+
+    write_primitive!(u8, u8);
+    write_primitive!(i8, i8);
+    write_primitive!(u16, u16);
+    write_primitive!(i16, i16);
+    write_primitive!(u32, u32);
+    write_primitive!(i32, i32);
+    write_primitive!(u64, u64);
+    write_primitive!(i64, i64);
+    write_primitive!(f32, f32);
+    write_primitive!(f64, f64);
+
+    pub fn bool(&mut self, value: bool) -> &mut Self {
+        self.u8(u8::from(value))
+    }
+
+    pub fn string(&mut self, value: &str) -> &mut Self {
+        self.u32(value.len() as u32 + 1);
+        self.data.extend_from_slice(value.as_bytes());
+        self.data.push(0);
+        self
+    }
+
+    pub fn byte_sequence(&mut self, value: &[u8]) -> &mut Self {
+        self.u32(value.len() as u32);
+        self.data.extend_from_slice(value);
+        self
+    }
+
+    pub fn f64_array(&mut self, values: &[f64]) -> &mut Self {
+        for value in values {
+            self.f64(*value);
+        }
+        self
+    }
+
+    pub fn finish(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for little_endian in [true, false] {
+            let data = CdrWriter::new(little_endian)
+                .u8(1)
+                // Aligned to 4 bytes, after 3 bytes of padding.
+                .u32(0xDEAD_BEEF)
+                .i8(-2)
+                // Aligned to 8 bytes.
+                .f64(std::f64::consts::PI)
+                .i16(-3)
+                .u16(4)
+                .i32(-5)
+                .u64(6)
+                .i64(-7)
+                .f32(0.5)
+                .bool(true)
+                .string("hello")
+                .byte_sequence(&[1, 2, 3])
+                .f64_array(&[1.0, 2.0, 3.0])
+                .finish();
+
+            let mut cdr = CdrReader::new(&data).unwrap();
+            assert_eq!(1, cdr.u8().unwrap());
+            assert_eq!(0xDEAD_BEEF, cdr.u32().unwrap());
+            assert_eq!(-2, cdr.i8().unwrap());
+            assert_eq!(std::f64::consts::PI, cdr.f64().unwrap());
+            assert_eq!(-3, cdr.i16().unwrap());
+            assert_eq!(4, cdr.u16().unwrap());
+            assert_eq!(-5, cdr.i32().unwrap());
+            assert_eq!(6, cdr.u64().unwrap());
+            assert_eq!(-7, cdr.i64().unwrap());
+            assert_eq!(0.5, cdr.f32().unwrap());
+            assert!(cdr.bool().unwrap());
+            assert_eq!("hello", cdr.string().unwrap());
+            assert_eq!(&[1_u8, 2, 3], cdr.byte_sequence().unwrap());
+            assert_eq!([1.0, 2.0, 3.0], cdr.f64_array::<3>().unwrap());
+
+            // Nothing left.
+            assert!(cdr.u8().is_err());
+        }
+    }
+
+    #[test]
+    fn endianness() {
+        // 0x00 0x01 is little-endian CDR, 0x00 0x00 big-endian.
+        let mut cdr = CdrReader::new(&[0x00, 0x01, 0, 0, 0x01, 0x02]).unwrap();
+        assert_eq!(0x0201, cdr.u16().unwrap());
+
+        let mut cdr = CdrReader::new(&[0x00, 0x00, 0, 0, 0x01, 0x02]).unwrap();
+        assert_eq!(0x0102, cdr.u16().unwrap());
+    }
+
+    #[test]
+    fn malformed() {
+        // Missing header.
+        assert!(CdrReader::new(&[0x00, 0x01]).is_err());
+
+        // Unknown encapsulation.
+        assert!(CdrReader::new(&[0x00, 0x10, 0, 0]).is_err());
+
+        // Truncated data.
+        let data = CdrWriter::new(true).u32(42).finish();
+        let mut cdr = CdrReader::new(&data[..data.len() - 1]).unwrap();
+        assert!(cdr.u32().is_err());
+
+        // Sequence longer than the data.
+        let data = CdrWriter::new(true).u32(1_000).u8(1).finish();
+        let mut cdr = CdrReader::new(&data).unwrap();
+        assert!(cdr.byte_sequence().is_err());
+
+        // Huge lengths don't overflow.
+        let data = CdrWriter::new(true).u32(u32::MAX).finish();
+        let mut cdr = CdrReader::new(&data).unwrap();
+        assert!(cdr.string().is_err());
+
+        // Lengths are checked before any elements are read.
+        let data = CdrWriter::new(true).u32(2).u8(1).finish();
+        assert!(CdrReader::new(&data).unwrap().sequence_len().is_err());
+        let data = CdrWriter::new(true).u32(1).u8(1).finish();
+        assert_eq!(1, CdrReader::new(&data).unwrap().sequence_len().unwrap());
+    }
+}
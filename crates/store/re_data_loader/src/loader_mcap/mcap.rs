@@ -0,0 +1,486 @@
+//! A minimal reader for the [MCAP](https://mcap.dev/spec) container format.
+//!
+//! Only the records needed to extract messages are decoded: schemas, channels, messages and
+//! (possibly compressed) chunks. Everything else, including the summary section, is skipped.
+
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+use anyhow::Context as _;
+
+/// Every MCAP file starts (and ends) with these bytes.
+pub const MAGIC: &[u8; 8] = b"\x89MCAP0\r\n";
+
+mod opcode {
+    pub const FOOTER: u8 = 0x02;
+    pub const SCHEMA: u8 = 0x03;
+    pub const CHANNEL: u8 = 0x04;
+    pub const MESSAGE: u8 = 0x05;
+    pub const CHUNK: u8 = 0x06;
+    pub const DATA_END: u8 = 0x0F;
+}
+
+/// Describes the layout of the messages of one or more [`Channel`]s.
+#[derive(Debug)]
+pub struct Schema {
+    /// E.g. `sensor_msgs/msg/Image`.
+    pub name: String,
+
+    /// E.g. `ros2msg`.
+    pub encoding: String,
+}
+
+/// A stream of messages, e.g. a ROS topic.
+#[derive(Debug)]
+pub struct Channel {
+    pub topic: String,
+
+    /// E.g. `cdr` or `json`.
+    pub message_encoding: String,
+
+    /// `None` for schemaless channels.
+    pub schema: Option<Arc<Schema>>,
+}
+
+/// A single message, as stored in the file.
+pub struct Message<'a> {
+    pub channel: Arc<Channel>,
+
+    /// Time at which the message was recorded, in nanoseconds.
+    pub log_time: u64,
+
+    /// The raw, encoded message.
+    pub data: &'a [u8],
+}
+
+/// Calls `on_message` for every message in the MCAP file, in file order.
+///
+/// Stops early if `on_message` returns [`ControlFlow::Break`].
+///
+/// Truncated files (e.g. recordings that are still being written) are read up to the last
+/// complete record.
+pub fn read_messages(
+    contents: &[u8],
+    mut on_message: impl FnMut(Message<'_>) -> ControlFlow<()>,
+) -> anyhow::Result<()> {
+    re_tracing::profile_function!();
+
+    let records = contents
+        .strip_prefix(MAGIC)
+        .context("not an MCAP file: bad magic")?;
+
+    let mut state = ReaderState::default();
+    state.read_records(records, &mut on_message)?;
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct ReaderState {
+    schemas: HashMap<u16, Arc<Schema>>,
+    channels: HashMap<u16, Arc<Channel>>,
+}
+
+impl ReaderState {
+    fn read_records(
+        &mut self,
+        mut records: &[u8],
+        on_message: &mut impl FnMut(Message<'_>) -> ControlFlow<()>,
+    ) -> anyhow::Result<ControlFlow<()>> {
+        while !records.is_empty() {
+            let Some((opcode, body, rest)) = split_record(records) else {
+                re_log::warn!(
+                    "MCAP file is truncated, ignoring its last {} bytes",
+                    records.len()
+                );
+                break;
+            };
+            records = rest;
+
+            match opcode {
+                opcode::FOOTER | opcode::DATA_END => break,
+
+                opcode::SCHEMA => {
+                    let mut cursor = Cursor::new(body, "schema");
+                    let id = cursor.u16()?;
+                    let name = cursor.string()?;
+                    let encoding = cursor.string()?;
+                    self.schemas.insert(id, Arc::new(Schema { name, encoding }));
+                }
+
+                opcode::CHANNEL => {
+                    let mut cursor = Cursor::new(body, "channel");
+                    let id = cursor.u16()?;
+                    let schema_id = cursor.u16()?;
+                    let topic = cursor.string()?;
+                    let message_encoding = cursor.string()?;
+                    let schema = self.schemas.get(&schema_id).cloned();
+                    self.channels.insert(
+                        id,
+                        Arc::new(Channel {
+                            topic,
+                            message_encoding,
+                            schema,
+                        }),
+                    );
+                }
+
+                opcode::MESSAGE => {
+                    let mut cursor = Cursor::new(body, "message");
+                    let channel_id = cursor.u16()?;
+                    let _sequence = cursor.u32()?;
+                    let log_time = cursor.u64()?;
+                    let _publish_time = cursor.u64()?;
+
+                    let Some(channel) = self.channels.get(&channel_id) else {
+                        re_log::warn_once!("MCAP message refers to unknown channel {channel_id}");
+                        continue;
+                    };
+
+                    let message = Message {
+                        channel: Arc::clone(channel),
+                        log_time,
+                        data: cursor.rest(),
+                    };
+                    if on_message(message).is_break() {
+                        return Ok(ControlFlow::Break(()));
+                    }
+                }
+
+                opcode::CHUNK => {
+                    let mut cursor = Cursor::new(body, "chunk");
+                    let _message_start_time = cursor.u64()?;
+                    let _message_end_time = cursor.u64()?;
+                    let _uncompressed_size = cursor.u64()?;
+                    let _uncompressed_crc = cursor.u32()?;
+                    let compression = cursor.string()?;
+                    let compressed_size = usize::try_from(cursor.u64()?)?;
+                    let compressed = cursor.bytes(compressed_size)?;
+
+                    let uncompressed = decompress(&compression, compressed)?;
+                    if self.read_records(&uncompressed, on_message)?.is_break() {
+                        return Ok(ControlFlow::Break(()));
+                    }
+                }
+
+                _ => {} // Indices, attachments, metadata, statistics…: not needed.
+            }
+        }
+
+        Ok(ControlFlow::Continue(()))
+    }
+}
+
+/// Splits the next record off `records`, returning its opcode, its body and the remaining records.
+///
+/// Returns `None` if the record is incomplete.
+fn split_record(records: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&opcode, rest) = records.split_first()?;
+    let len = u64::from_le_bytes(rest.get(..8)?.try_into().ok()?);
+    let rest = &rest[8..];
+    let len = usize::try_from(len).ok().filter(|&len| len <= rest.len())?;
+    let (body, rest) = rest.split_at(len);
+    Some((opcode, body, rest))
+}
+
+fn decompress<'a>(
+    compression: &str,
+    compressed: &'a [u8],
+) -> anyhow::Result<std::borrow::Cow<'a, [u8]>> {
+    use std::io::Read as _;
+
+    if compression.is_empty() {
+        return Ok(std::borrow::Cow::Borrowed(compressed));
+    }
+
+    re_tracing::profile_function!(compression);
+
+    // NOTE: We don't trust the uncompressed size stored in the chunk for preallocating.
+    let mut uncompressed = Vec::new();
+    match compression {
+        "zstd" => {
+            ruzstd::StreamingDecoder::new(compressed)
+                .map_err(|err| anyhow::anyhow!("{err}"))?
+                .read_to_end(&mut uncompressed)
+                .context("failed to decompress zstd chunk")?;
+        }
+
+        "lz4" => {
+            lz4_flex::frame::FrameDecoder::new(compressed)
+                .read_to_end(&mut uncompressed)
+                .context("failed to decompress lz4 chunk")?;
+        }
+
+        _ => anyhow::bail!("unsupported MCAP chunk compression: {compression:?}"),
+    }
+
+    Ok(std::borrow::Cow::Owned(uncompressed))
+}
+
+// ---
+
+/// Reads the little-endian primitives MCAP records are made of.
+struct Cursor<'a> {
+    data: &'a [u8],
+
+    /// What we're reading, for error messages.
+    what: &'static str,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8], what: &'static str) -> Self {
+        Self { data, what }
+    }
+
+    fn bytes(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        anyhow::ensure!(
+            len <= self.data.len(),
+            "unexpected end of MCAP {}: needed {len} bytes, got {}",
+            self.what,
+            self.data.len()
+        );
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> anyhow::Result<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.bytes(N)?);
+        Ok(array)
+    }
+
+    fn u16(&mut self) -> anyhow::Result<u16> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> anyhow::Result<u32> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> anyhow::Result<u64> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    fn string(&mut self) -> anyhow::Result<String> {
+        let len = self.u32()? as usize;
+        let bytes = self.bytes(len)?;
+        String::from_utf8(bytes.to_vec())
+            .with_context(|| format!("invalid string in MCAP {}", self.what))
+    }
+
+    fn rest(&mut self) -> &'a [u8] {
+        std::mem::take(&mut self.data)
+    }
+}
+
+/// Writes MCAP files, to build test fixtures.
+#[cfg(test)]
+#[derive(Default)]
+pub struct McapWriter {
+    records: Vec<u8>,
+}
+
+#[cfg(test)]
+impl McapWriter {
+    fn record(&mut self, opcode: u8, body: &[u8]) -> &mut Self {
+        self.records.push(opcode);
+        self.records
+            .extend_from_slice(&(body.len() as u64).to_le_bytes());
+        self.records.extend_from_slice(body);
+        self
+    }
+
+    fn string(body: &mut Vec<u8>, value: &str) {
+        body.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        body.extend_from_slice(value.as_bytes());
+    }
+
+    pub fn schema(&mut self, id: u16, name: &str, encoding: &str) -> &mut Self {
+        let mut body = id.to_le_bytes().to_vec();
+        Self::string(&mut body, name);
+        Self::string(&mut body, encoding);
+        body.extend_from_slice(&0_u32.to_le_bytes()); // No schema data.
+        self.record(opcode::SCHEMA, &body)
+    }
+
+    pub fn channel(
+        &mut self,
+        id: u16,
+        schema_id: u16,
+        topic: &str,
+        message_encoding: &str,
+    ) -> &mut Self {
+        let mut body = id.to_le_bytes().to_vec();
+        body.extend_from_slice(&schema_id.to_le_bytes());
+        Self::string(&mut body, topic);
+        Self::string(&mut body, message_encoding);
+        body.extend_from_slice(&0_u32.to_le_bytes()); // No metadata.
+        self.record(opcode::CHANNEL, &body)
+    }
+
+    pub fn message(&mut self, channel_id: u16, log_time: u64, data: &[u8]) -> &mut Self {
+        let mut body = channel_id.to_le_bytes().to_vec();
+        body.extend_from_slice(&0_u32.to_le_bytes()); // Sequence.
+        body.extend_from_slice(&log_time.to_le_bytes());
+        body.extend_from_slice(&log_time.to_le_bytes()); // Publish time.
+        body.extend_from_slice(data);
+        self.record(opcode::MESSAGE, &body)
+    }
+
+    /// Wraps all the records of `inner` in a chunk.
+    ///
+    /// `compression` is either empty or `lz4`.
+    pub fn chunk(&mut self, compression: &str, inner: &Self) -> &mut Self {
+        use std::io::Write as _;
+
+        let compressed = match compression {
+            "" => inner.records.clone(),
+            "lz4" => {
+                let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+                encoder.write_all(&inner.records).unwrap();
+                encoder.finish().unwrap()
+            }
+            _ => unimplemented!("{compression}"),
+        };
+
+        let mut body = Vec::new();
+        body.extend_from_slice(&0_u64.to_le_bytes()); // Message start time.
+        body.extend_from_slice(&0_u64.to_le_bytes()); // Message end time.
+        body.extend_from_slice(&(inner.records.len() as u64).to_le_bytes());
+        body.extend_from_slice(&0_u32.to_le_bytes()); // CRC: not checked.
+        Self::string(&mut body, compression);
+        body.extend_from_slice(&(compressed.len() as u64).to_le_bytes());
+        body.extend_from_slice(&compressed);
+        self.record(opcode::CHUNK, &body)
+    }
+
+    pub fn finish(&mut self) -> Vec<u8> {
+        self.record(opcode::DATA_END, &0_u32.to_le_bytes());
+        self.record(opcode::FOOTER, &[0; 20]);
+
+        let mut contents = MAGIC.to_vec();
+        contents.extend_from_slice(&self.records);
+        contents.extend_from_slice(MAGIC);
+        contents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the topic, log time and data of every message.
+    fn read_all(contents: &[u8]) -> anyhow::Result<Vec<(String, u64, Vec<u8>)>> {
+        let mut messages = Vec::new();
+        read_messages(contents, |message| {
+            messages.push((
+                message.channel.topic.clone(),
+                message.log_time,
+                message.data.to_vec(),
+            ));
+            ControlFlow::Continue(())
+        })?;
+        Ok(messages)
+    }
+
+    fn expected() -> Vec<(String, u64, Vec<u8>)> {
+        vec![
+            ("/a".to_owned(), 1, vec![1]),
+            ("/b".to_owned(), 2, vec![2, 2]),
+            ("/a".to_owned(), 3, vec![3, 3, 3]),
+        ]
+    }
+
+    fn messages(writer: &mut McapWriter) -> &mut McapWriter {
+        writer
+            .schema(1, "std_msgs/msg/UInt8", "ros2msg")
+            .channel(1, 1, "/a", "cdr")
+            .channel(2, 0, "/b", "json")
+            .message(1, 1, &[1])
+            .message(2, 2, &[2, 2])
+            .message(1, 3, &[3, 3, 3])
+    }
+
+    #[test]
+    fn plain() {
+        let contents = messages(&mut McapWriter::default()).finish();
+        assert_eq!(expected(), read_all(&contents).unwrap());
+    }
+
+    #[test]
+    fn schemas_and_channels() {
+        let contents = messages(&mut McapWriter::default()).finish();
+
+        let mut channels = Vec::new();
+        read_messages(&contents, |message| {
+            let channel = &message.channel;
+            channels.push((
+                channel.message_encoding.clone(),
+                channel.schema.as_ref().map(|schema| schema.name.clone()),
+            ));
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                ("cdr".to_owned(), Some("std_msgs/msg/UInt8".to_owned())),
+                ("json".to_owned(), None),
+                ("cdr".to_owned(), Some("std_msgs/msg/UInt8".to_owned())),
+            ],
+            channels
+        );
+    }
+
+    #[test]
+    fn chunks() {
+        for compression in ["", "lz4"] {
+            let inner = messages(&mut McapWriter::default()).records.clone();
+            let contents = McapWriter::default()
+                .chunk(compression, &McapWriter { records: inner })
+                .finish();
+            assert_eq!(expected(), read_all(&contents).unwrap(), "{compression:?}");
+        }
+
+        let contents = McapWriter::default()
+            .chunk("brotli", &McapWriter::default())
+            .finish();
+        assert!(read_all(&contents).is_err());
+    }
+
+    #[test]
+    fn truncated() {
+        let contents = messages(&mut McapWriter::default()).finish();
+
+        // Cut in the middle of the last message (i.e. before the 8 bytes of magic, the 29 bytes
+        // of the footer record, and the 13 bytes of the data end record): the first two are
+        // still there.
+        let end = contents.len() - 8 - 29 - 13 - 5;
+        let messages = read_all(&contents[..end]).unwrap();
+        assert_eq!(expected()[..2], messages[..]);
+    }
+
+    #[test]
+    fn early_stop() {
+        let contents = messages(&mut McapWriter::default()).finish();
+
+        let mut num_messages = 0;
+        read_messages(&contents, |_| {
+            num_messages += 1;
+            ControlFlow::Break(())
+        })
+        .unwrap();
+        assert_eq!(1, num_messages);
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(read_all(b"not an mcap file").is_err());
+
+        // Messages on unknown channels are skipped.
+        let contents = McapWriter::default().message(42, 1, &[1]).finish();
+        assert!(read_all(&contents).unwrap().is_empty());
+    }
+}
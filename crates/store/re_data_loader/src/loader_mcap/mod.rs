@@ -0,0 +1,293 @@
+use std::ops::ControlFlow;
+
+use re_log_types::{EntityPath, TimeInt, TimePoint, Timeline};
+use re_types::{archetypes::TextLog, components::Blob};
+
+use crate::{DataLoader, LoadedData};
+
+use self::batcher::{ChunkBatcher, Row};
+
+mod batcher;
+mod cdr;
+mod mcap;
+mod ros2;
+
+// ---
+
+/// Loads messages from [MCAP files](https://mcap.dev), such as ROS 2 bags.
+///
+/// Every topic is logged to the entity path of the same name, e.g. `/camera/image_raw` ends up
+/// on `camera/image_raw`.
+/// Messages are logged on the `log_time` timeline, using the time at which they were recorded.
+/// Messages that have a `std_msgs/Header` are also logged on the `ros_time` timeline, using the
+/// stamp of that header.
+///
+/// The following CDR-encoded ROS 2 messages are converted to their matching archetypes:
+/// * `sensor_msgs/msg/Image` and `sensor_msgs/msg/CompressedImage`
+/// * `sensor_msgs/msg/PointCloud2`
+/// * `sensor_msgs/msg/Imu`
+/// * `sensor_msgs/msg/CameraInfo`
+/// * `tf2_msgs/msg/TFMessage`, logged to the `tf` entity, following the frame hierarchy
+///   (e.g. `tf/map/odom/base_link`). Transforms published on `/tf_static` are logged as static.
+///   The hierarchy is read from the whole file before anything gets logged, so that a frame's
+///   entity path never changes.
+/// * the numeric and string messages of `std_msgs`.
+///
+/// Messages of any other type (or encoding) fall back to a [`TextLog`] if they are text-based
+/// (e.g. JSON), or to a raw [`Blob`] otherwise.
+///
+/// Messages are batched into chunks per entity, rather than logged one at a time.
+pub struct McapLoader;
+
+impl DataLoader for McapLoader {
+    #[inline]
+    fn name(&self) -> String {
+        "rerun.data_loaders.Mcap".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), crate::DataLoaderError> {
+        use anyhow::Context as _;
+
+        if filepath.is_dir() {
+            return Err(crate::DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        let extension = crate::extension(&filepath);
+        if !crate::SUPPORTED_MCAP_EXTENSIONS.contains(&extension.as_str()) {
+            return Err(crate::DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let contents = std::fs::read(&filepath)
+            .with_context(|| format!("Failed to read file {filepath:?}"))?;
+        let contents = std::borrow::Cow::Owned(contents);
+
+        self.load_from_file_contents(settings, filepath, contents, tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), crate::DataLoaderError> {
+        let extension = crate::extension(&filepath);
+        if !crate::SUPPORTED_MCAP_EXTENSIONS.contains(&extension.as_str()) {
+            return Err(crate::DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        re_log::debug!(?filepath, loader = self.name(), "Loading MCAP file…",);
+
+        let root = settings
+            .entity_path_prefix
+            .clone()
+            .unwrap_or_else(EntityPath::root);
+        let mut decoder = ros2::Ros2Decoder::new(root.join(&EntityPath::from_single_string("tf")));
+
+        // First pass: learn the whole `tf` hierarchy, so that frames get stable entity paths.
+        mcap::read_messages(&contents, |message| {
+            if is_ros2_message(&message, ros2::TF_MESSAGE_TYPE) {
+                if let Err(err) = decoder.learn_frame_hierarchy(message.data) {
+                    re_log::warn_once!(
+                        "Failed to decode tf message on topic {:?}: {err}",
+                        message.channel.topic
+                    );
+                }
+            }
+            ControlFlow::Continue(())
+        })?;
+
+        // Second pass: decode everything.
+        let mut batcher = ChunkBatcher::default();
+        let mut is_hung_up = false;
+        mcap::read_messages(&contents, |message| {
+            for row in decode_message(&mut decoder, &root, &message) {
+                let Some(chunk) = batcher.push(row) else {
+                    continue;
+                };
+                if tx.send(chunk.into()).is_err() {
+                    // The other end has decided to hang up, not our problem.
+                    is_hung_up = true;
+                    return ControlFlow::Break(());
+                }
+            }
+            ControlFlow::Continue(())
+        })?;
+
+        if !is_hung_up {
+            for chunk in batcher.flush() {
+                if tx.send(chunk.into()).is_err() {
+                    break; // The other end has decided to hang up, not our problem.
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// Topics whose messages are logged as static data.
+const STATIC_TOPICS: &[&str] = &["/tf_static"];
+
+/// Whether `message` is a CDR-encoded ROS 2 message of type `message_type`.
+fn is_ros2_message(message: &mcap::Message<'_>, message_type: &str) -> bool {
+    let channel = &message.channel;
+    channel.message_encoding == "cdr"
+        && channel.schema.as_ref().map_or(false, |schema| {
+            schema.encoding.starts_with("ros2") && schema.name == message_type
+        })
+}
+
+fn decode_message(
+    decoder: &mut ros2::Ros2Decoder,
+    root: &EntityPath,
+    message: &mcap::Message<'_>,
+) -> Vec<Row> {
+    let channel = &message.channel;
+    let entity_path = root.join(&EntityPath::parse_forgiving(&channel.topic));
+
+    let timepoint = if STATIC_TOPICS.contains(&channel.topic.as_str()) {
+        TimePoint::default()
+    } else {
+        let log_time = i64::try_from(message.log_time).unwrap_or(i64::MAX);
+        TimePoint::default().with(Timeline::log_time(), TimeInt::new_temporal(log_time))
+    };
+
+    if let Some(schema) = &channel.schema {
+        if is_ros2_message(message, &schema.name) {
+            match decoder.decode(&schema.name, &entity_path, timepoint.clone(), message.data) {
+                Ok(Some(rows)) => return rows,
+                Ok(None) => {}
+                Err(err) => {
+                    re_log::warn_once!(
+                        "Failed to decode {} message on topic {:?}: {err}",
+                        schema.name,
+                        channel.topic
+                    );
+                }
+            }
+        }
+    }
+
+    // Fallback: log the message as-is.
+    let row = match std::str::from_utf8(message.data) {
+        Ok(text) if channel.message_encoding == "json" => {
+            Row::new(entity_path, timepoint, &TextLog::new(text))
+        }
+        _ => Row::from_component_batches(
+            entity_path,
+            timepoint,
+            [&Blob::from(message.data.to_vec()) as _],
+        ),
+    };
+
+    vec![row]
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::Chunk;
+    use re_log_types::{StoreId, StoreKind};
+
+    use super::{cdr::CdrWriter, mcap::McapWriter, *};
+
+    fn load(contents: Vec<u8>) -> Vec<Chunk> {
+        let settings =
+            crate::DataLoaderSettings::recommended(StoreId::random(StoreKind::Recording));
+        let (tx, rx) = std::sync::mpsc::channel();
+        McapLoader
+            .load_from_file_contents(&settings, "test.mcap".into(), contents.into(), tx)
+            .unwrap();
+
+        rx.try_iter()
+            .map(|data| match data {
+                LoadedData::Chunk(chunk) => chunk,
+                _ => panic!("expected chunks"),
+            })
+            .collect()
+    }
+
+    fn tf_message(parent: &str, child: &str) -> Vec<u8> {
+        let mut cdr = CdrWriter::new(true);
+        cdr.u32(1)
+            .i32(0)
+            .u32(0)
+            .string(parent)
+            .string(child)
+            .f64_array(&[0.0; 3])
+            .f64_array(&[0.0, 0.0, 0.0, 1.0]);
+        cdr.finish()
+    }
+
+    #[test]
+    fn load_mcap() {
+        let mut writer = McapWriter::default();
+        writer
+            .schema(1, "std_msgs/msg/Float64", "ros2msg")
+            .schema(2, ros2::TF_MESSAGE_TYPE, "ros2msg")
+            .channel(1, 1, "/speed", "cdr")
+            .channel(2, 2, "/tf", "cdr")
+            .channel(3, 2, "/tf_static", "cdr")
+            .channel(4, 0, "/events", "json")
+            .channel(5, 0, "/raw", "protobuf");
+
+        // `base_link` gets published before `odom`'s parent is known.
+        writer.message(2, 1, &tf_message("odom", "base_link"));
+        writer.message(3, 1, &tf_message("map", "odom"));
+        for (i, speed) in [1.0, 2.0, 3.0].into_iter().enumerate() {
+            let log_time = 10 + i as u64;
+            writer.message(1, log_time, &CdrWriter::new(true).f64(speed).finish());
+            writer.message(2, log_time, &tf_message("odom", "base_link"));
+        }
+        writer.message(4, 20, br#"{"hello": "world"}"#);
+        writer.message(5, 20, &[0xFF, 0x00]);
+
+        let chunks = load(writer.finish());
+
+        let mut summary = chunks
+            .iter()
+            .map(|chunk| {
+                (
+                    chunk.entity_path().to_string(),
+                    chunk.is_static(),
+                    chunk.num_rows(),
+                )
+            })
+            .collect::<Vec<_>>();
+        summary.sort();
+
+        // Messages are batched per entity, and `base_link` always ends up on the same entity.
+        assert_eq!(
+            vec![
+                ("/events".to_owned(), false, 1),
+                ("/raw".to_owned(), false, 1),
+                ("/speed".to_owned(), false, 3),
+                ("/tf/map/odom".to_owned(), true, 1),
+                ("/tf/map/odom/base_link".to_owned(), false, 4),
+            ],
+            summary
+        );
+
+        let components = |entity_path: &str| {
+            let chunk = chunks
+                .iter()
+                .find(|chunk| chunk.entity_path() == &EntityPath::from(entity_path))
+                .unwrap();
+            chunk.components().keys().copied().collect::<Vec<_>>()
+        };
+        assert!(components("events").contains(&"rerun.components.Text".into()));
+        assert!(components("raw").contains(&"rerun.components.Blob".into()));
+    }
+}
@@ -0,0 +1,1003 @@
+//! Decoding of common ROS 2 messages into Rerun archetypes.
+
+use std::collections::HashMap;
+
+use anyhow::Context as _;
+use re_log_types::{EntityPath, EntityPathPart, TimeInt, TimePoint, Timeline};
+use re_types::{
+    archetypes::{
+        DepthImage, Image, ImageEncoded, Pinhole, Points3D, Scalar, TextLog, Transform3D,
+    },
+    components::{ChannelDataType, Color, PinholeProjection},
+    datatypes::{Quaternion, TensorBuffer, TensorData, TensorDimension},
+};
+
+use super::{batcher::Row, cdr::CdrReader};
+
+/// The timeline on which the stamps found in message headers are logged.
+pub const ROS_TIME_TIMELINE: &str = "ros_time";
+
+/// The ROS 2 message types we know how to turn into archetypes.
+enum MessageKind {
+    Image,
+    CompressedImage,
+    PointCloud2,
+    Imu,
+    CameraInfo,
+    TfMessage,
+
+    /// A `std_msgs` message holding a single number.
+    Scalar(fn(&mut CdrReader<'_>) -> anyhow::Result<f64>),
+
+    /// `std_msgs/msg/String`.
+    String,
+}
+
+impl MessageKind {
+    /// `message_type` is e.g. `sensor_msgs/msg/Image`.
+    fn from_message_type(message_type: &str) -> Option<Self> {
+        let kind = match message_type {
+            "sensor_msgs/msg/Image" => Self::Image,
+            "sensor_msgs/msg/CompressedImage" => Self::CompressedImage,
+            "sensor_msgs/msg/PointCloud2" => Self::PointCloud2,
+            "sensor_msgs/msg/Imu" => Self::Imu,
+            "sensor_msgs/msg/CameraInfo" => Self::CameraInfo,
+            TF_MESSAGE_TYPE => Self::TfMessage,
+
+            "std_msgs/msg/Bool" => Self::Scalar(|cdr| Ok(f64::from(u8::from(cdr.bool()?)))),
+            "std_msgs/msg/Byte" | "std_msgs/msg/Char" | "std_msgs/msg/UInt8" => {
+                Self::Scalar(|cdr| Ok(f64::from(cdr.u8()?)))
+            }
+            "std_msgs/msg/Int8" => Self::Scalar(|cdr| Ok(f64::from(cdr.i8()?))),
+            "std_msgs/msg/Int16" => Self::Scalar(|cdr| Ok(f64::from(cdr.i16()?))),
+            "std_msgs/msg/UInt16" => Self::Scalar(|cdr| Ok(f64::from(cdr.u16()?))),
+            "std_msgs/msg/Int32" => Self::Scalar(|cdr| Ok(f64::from(cdr.i32()?))),
+            "std_msgs/msg/UInt32" => Self::Scalar(|cdr| Ok(f64::from(cdr.u32()?))),
+            "std_msgs/msg/Int64" => Self::Scalar(|cdr| Ok(cdr.i64()? as f64)),
+            "std_msgs/msg/UInt64" => Self::Scalar(|cdr| Ok(cdr.u64()? as f64)),
+            "std_msgs/msg/Float32" => Self::Scalar(|cdr| Ok(f64::from(cdr.f32()?))),
+            "std_msgs/msg/Float64" => Self::Scalar(|cdr| cdr.f64()),
+            "std_msgs/msg/String" => Self::String,
+
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
+/// The message type of `tf` messages.
+pub const TF_MESSAGE_TYPE: &str = "tf2_msgs/msg/TFMessage";
+
+/// Decodes CDR-encoded ROS 2 messages into [`Row`]s.
+///
+/// Keeps track of the `tf` frame hierarchy, so that every frame can be logged to an entity path
+/// that mirrors its position in the hierarchy.
+///
+/// The hierarchy should be learned from all the `tf` messages up-front (see
+/// [`Self::learn_frame_hierarchy`]): that way, each frame is always logged to the same entity
+/// path, no matter whether its parent's transform was already published or not.
+pub struct Ros2Decoder {
+    /// Where the `tf` frame hierarchy gets logged.
+    tf_root: EntityPath,
+
+    /// Maps each `tf` frame to its parent frame.
+    frame_parents: HashMap<String, String>,
+}
+
+impl Ros2Decoder {
+    pub fn new(tf_root: EntityPath) -> Self {
+        Self {
+            tf_root,
+            frame_parents: HashMap::default(),
+        }
+    }
+
+    /// Records the parent of every frame found in a [`TF_MESSAGE_TYPE`] message, without
+    /// decoding anything else.
+    ///
+    /// A frame keeps the first parent it was seen with: ROS doesn't allow re-parenting frames.
+    pub fn learn_frame_hierarchy(&mut self, data: &[u8]) -> anyhow::Result<()> {
+        let mut cdr = CdrReader::new(data)?;
+
+        for _ in 0..cdr.sequence_len()? {
+            let (header, child_frame_id, _, _) = read_transform_stamped(&mut cdr)?;
+            self.add_frame(&child_frame_id, &header.frame_id);
+        }
+
+        Ok(())
+    }
+
+    fn add_frame(&mut self, child_frame_id: &str, parent_frame_id: &str) {
+        let child_frame_id = normalize_frame_id(child_frame_id);
+        let parent_frame_id = normalize_frame_id(parent_frame_id);
+
+        let parent = self
+            .frame_parents
+            .entry(child_frame_id.to_owned())
+            .or_insert_with(|| parent_frame_id.to_owned());
+        if parent != parent_frame_id {
+            re_log::warn_once!(
+                "tf frame {child_frame_id:?} has several parents ({parent:?} and \
+                 {parent_frame_id:?}), only the first one is used"
+            );
+        }
+    }
+
+    /// Decodes a message of type `message_type` (e.g. `sensor_msgs/msg/Image`) that was
+    /// published on the topic that maps to `entity_path`.
+    ///
+    /// Returns `Ok(None)` if the message type isn't supported.
+    pub fn decode(
+        &mut self,
+        message_type: &str,
+        entity_path: &EntityPath,
+        timepoint: TimePoint,
+        data: &[u8],
+    ) -> anyhow::Result<Option<Vec<Row>>> {
+        let Some(kind) = MessageKind::from_message_type(message_type) else {
+            return Ok(None);
+        };
+
+        let mut cdr = CdrReader::new(data)?;
+
+        let rows = match kind {
+            MessageKind::Image => vec![decode_image(&mut cdr, entity_path, timepoint)?],
+            MessageKind::CompressedImage => {
+                vec![decode_compressed_image(&mut cdr, entity_path, timepoint)?]
+            }
+            MessageKind::PointCloud2 => {
+                vec![decode_point_cloud(&mut cdr, entity_path, timepoint)?]
+            }
+            MessageKind::Imu => decode_imu(&mut cdr, entity_path, timepoint)?,
+            MessageKind::CameraInfo => {
+                vec![decode_camera_info(&mut cdr, entity_path, timepoint)?]
+            }
+            MessageKind::TfMessage => self.decode_tf(&mut cdr, &timepoint)?,
+            MessageKind::Scalar(read) => {
+                let scalar = Scalar::new(read(&mut cdr)?);
+                vec![Row::new(entity_path.clone(), timepoint, &scalar)]
+            }
+            MessageKind::String => {
+                let text_log = TextLog::new(cdr.string()?);
+                vec![Row::new(entity_path.clone(), timepoint, &text_log)]
+            }
+        };
+
+        Ok(Some(rows))
+    }
+
+    /// `tf2_msgs/msg/TFMessage`
+    fn decode_tf(
+        &mut self,
+        cdr: &mut CdrReader<'_>,
+        timepoint: &TimePoint,
+    ) -> anyhow::Result<Vec<Row>> {
+        let num_transforms = cdr.sequence_len()?;
+
+        let mut rows = Vec::with_capacity(num_transforms);
+        for _ in 0..num_transforms {
+            let (header, child_frame_id, translation, rotation) = read_transform_stamped(cdr)?;
+
+            // In case the hierarchy wasn't learned up-front.
+            self.add_frame(&child_frame_id, &header.frame_id);
+
+            let mut timepoint = timepoint.clone();
+            header.insert_stamp(&mut timepoint);
+
+            // A TF transform maps points from the child frame to the parent frame, which is the
+            // default direction for `Transform3D`.
+            let transform = Transform3D::from_translation_rotation(
+                to_f32(translation),
+                Quaternion::from_xyzw(to_f32(rotation)),
+            );
+            rows.push(Row::new(
+                self.frame_entity_path(normalize_frame_id(&child_frame_id)),
+                timepoint,
+                &transform,
+            ));
+        }
+
+        Ok(rows)
+    }
+
+    /// The entity path of a `tf` frame, e.g. `tf/map/odom/base_link` for `base_link`.
+    fn frame_entity_path(&self, frame_id: &str) -> EntityPath {
+        let mut frames = vec![frame_id];
+        while let Some(parent) = self.frame_parents.get(frames[frames.len() - 1]) {
+            if frames.contains(&parent.as_str()) {
+                re_log::warn_once!("Cycle in tf frame hierarchy involving {parent:?}");
+                break;
+            }
+            frames.push(parent);
+        }
+
+        self.tf_root.join(
+            &frames
+                .into_iter()
+                .rev()
+                .map(EntityPathPart::new)
+                .collect::<EntityPath>(),
+        )
+    }
+}
+
+/// `geometry_msgs/msg/TransformStamped`: header, child frame id, translation and rotation.
+fn read_transform_stamped(
+    cdr: &mut CdrReader<'_>,
+) -> anyhow::Result<(Header, String, [f64; 3], [f64; 4])> {
+    let header = Header::read(cdr)?;
+    let child_frame_id = cdr.string()?;
+    let translation = cdr.f64_array::<3>()?;
+    let rotation = cdr.f64_array::<4>()?;
+    Ok((header, child_frame_id, translation, rotation))
+}
+
+// ---
+
+/// `std_msgs/msg/Header`
+struct Header {
+    /// `None` if the stamp was never set, as is common for e.g. static transforms.
+    stamp: Option<TimeInt>,
+    frame_id: String,
+}
+
+impl Header {
+    fn read(cdr: &mut CdrReader<'_>) -> anyhow::Result<Self> {
+        let sec = cdr.i32()?;
+        let nanosec = cdr.u32()?;
+        let frame_id = cdr.string()?;
+
+        let stamp = (sec != 0 || nanosec != 0)
+            .then(|| TimeInt::new_temporal(i64::from(sec) * 1_000_000_000 + i64::from(nanosec)));
+
+        Ok(Self { stamp, frame_id })
+    }
+
+    /// Adds the stamp to the [`ROS_TIME_TIMELINE`], unless the data is static.
+    fn insert_stamp(&self, timepoint: &mut TimePoint) {
+        if let Some(stamp) = self.stamp {
+            if !timepoint.is_static() {
+                timepoint.insert(Timeline::new_temporal(ROS_TIME_TIMELINE), stamp);
+            }
+        }
+    }
+}
+
+/// `sensor_msgs/msg/Image`
+///
+/// Depth images follow [REP 118](https://www.ros.org/reps/rep-0118.html): `16UC1` is in
+/// millimeters, `32FC1` is in meters.
+fn decode_image(
+    cdr: &mut CdrReader<'_>,
+    entity_path: &EntityPath,
+    mut timepoint: TimePoint,
+) -> anyhow::Result<Row> {
+    let header = Header::read(cdr)?;
+    let height = cdr.u32()?;
+    let width = cdr.u32()?;
+    let encoding = cdr.string()?;
+    let is_bigendian = cdr.bool()?;
+    let step = cdr.u32()? as usize;
+    let data = cdr.byte_sequence()?;
+
+    header.insert_stamp(&mut timepoint);
+
+    let (num_channels, bytes_per_channel) = match encoding.as_str() {
+        "mono8" | "8UC1" => (1, 1),
+        "mono16" | "16UC1" => (1, 2),
+        "32FC1" => (1, 4),
+        "rgb8" | "bgr8" | "8UC3" => (3, 1),
+        "rgba8" | "bgra8" | "8UC4" => (4, 1),
+        _ => anyhow::bail!("unsupported image encoding {encoding:?}"),
+    };
+
+    // Get rid of the padding at the end of each row, if any.
+    let row_len = width as usize * num_channels * bytes_per_channel;
+    anyhow::ensure!(
+        step >= row_len
+            && step
+                .checked_mul(height as usize)
+                .map_or(false, |len| data.len() >= len),
+        "not enough data for a {width}x{height} {encoding} image"
+    );
+    let mut pixels = Vec::with_capacity(row_len * height as usize);
+    for row in data.chunks(step.max(1)).take(height as usize) {
+        pixels.extend_from_slice(&row[..row_len]);
+    }
+
+    // Rerun expects little-endian data.
+    if is_bigendian && bytes_per_channel > 1 {
+        for value in pixels.chunks_exact_mut(bytes_per_channel) {
+            value.reverse();
+        }
+    }
+
+    match encoding.as_str() {
+        "bgr8" | "bgra8" => {
+            for pixel in pixels.chunks_exact_mut(num_channels) {
+                pixel.swap(0, 2);
+            }
+        }
+        "16UC1" => {
+            let depth_image =
+                DepthImage::new(pixels, [width, height], ChannelDataType::U16).with_meter(1000.0);
+            return Ok(Row::new(entity_path.clone(), timepoint, &depth_image));
+        }
+        "32FC1" => {
+            let depth_image =
+                DepthImage::new(pixels, [width, height], ChannelDataType::F32).with_meter(1.0);
+            return Ok(Row::new(entity_path.clone(), timepoint, &depth_image));
+        }
+        _ => {}
+    }
+
+    let mut shape = vec![
+        TensorDimension::height(height.into()),
+        TensorDimension::width(width.into()),
+    ];
+    if num_channels > 1 {
+        shape.push(TensorDimension::depth(num_channels as u64));
+    }
+
+    let buffer = if bytes_per_channel == 2 {
+        TensorBuffer::U16(
+            pixels
+                .chunks_exact(2)
+                .map(|value| u16::from_le_bytes([value[0], value[1]]))
+                .collect(),
+        )
+    } else {
+        TensorBuffer::U8(pixels.into())
+    };
+
+    let image = Image::new(TensorData::new(shape, buffer));
+    Ok(Row::new(entity_path.clone(), timepoint, &image))
+}
+
+/// `sensor_msgs/msg/CompressedImage`
+fn decode_compressed_image(
+    cdr: &mut CdrReader<'_>,
+    entity_path: &EntityPath,
+    mut timepoint: TimePoint,
+) -> anyhow::Result<Row> {
+    let header = Header::read(cdr)?;
+    let _format = cdr.string()?; // The actual format is sniffed from the data.
+    let data = cdr.byte_sequence()?;
+
+    header.insert_stamp(&mut timepoint);
+
+    let image = ImageEncoded::from_file_contents(data.to_vec());
+    Ok(Row::new(entity_path.clone(), timepoint, &image))
+}
+
+/// `sensor_msgs/msg/PointField`
+struct PointField {
+    name: String,
+    offset: usize,
+    datatype: u8,
+}
+
+impl PointField {
+    const INT8: u8 = 1;
+    const UINT8: u8 = 2;
+    const INT16: u8 = 3;
+    const UINT16: u8 = 4;
+    const INT32: u8 = 5;
+    const UINT32: u8 = 6;
+    const FLOAT32: u8 = 7;
+    const FLOAT64: u8 = 8;
+
+    fn read(cdr: &mut CdrReader<'_>) -> anyhow::Result<Self> {
+        let name = cdr.string()?;
+        let offset = cdr.u32()? as usize;
+        let datatype = cdr.u8()?;
+        let _count = cdr.u32()?;
+        Ok(Self {
+            name,
+            offset,
+            datatype,
+        })
+    }
+
+    /// Reads the little-endian bytes of this field in `point`.
+    fn bytes<const N: usize>(&self, point: &[u8], is_bigendian: bool) -> Option<[u8; N]> {
+        let mut bytes: [u8; N] = point
+            .get(self.offset..self.offset.checked_add(N)?)?
+            .try_into()
+            .ok()?;
+        if is_bigendian {
+            bytes.reverse();
+        }
+        Some(bytes)
+    }
+
+    fn read_f32(&self, point: &[u8], is_bigendian: bool) -> Option<f32> {
+        let value = match self.datatype {
+            Self::INT8 => f32::from(i8::from_le_bytes(self.bytes(point, is_bigendian)?)),
+            Self::UINT8 => f32::from(u8::from_le_bytes(self.bytes(point, is_bigendian)?)),
+            Self::INT16 => f32::from(i16::from_le_bytes(self.bytes(point, is_bigendian)?)),
+            Self::UINT16 => f32::from(u16::from_le_bytes(self.bytes(point, is_bigendian)?)),
+            Self::INT32 => i32::from_le_bytes(self.bytes(point, is_bigendian)?) as f32,
+            Self::UINT32 => u32::from_le_bytes(self.bytes(point, is_bigendian)?) as f32,
+            Self::FLOAT32 => f32::from_le_bytes(self.bytes(point, is_bigendian)?),
+            Self::FLOAT64 => f64::from_le_bytes(self.bytes(point, is_bigendian)?) as f32,
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// Colors are packed as `0xAARRGGBB` in a 32-bit field, like PCL does.
+    fn read_color(&self, point: &[u8], is_bigendian: bool) -> Option<Color> {
+        let [b, g, r, a] = self.bytes::<4>(point, is_bigendian)?;
+        Some(if self.name == "rgba" {
+            Color::from_unmultiplied_rgba(r, g, b, a)
+        } else {
+            Color::from_rgb(r, g, b)
+        })
+    }
+}
+
+/// `sensor_msgs/msg/PointCloud2`
+///
+/// Points with non-finite coordinates are skipped.
+fn decode_point_cloud(
+    cdr: &mut CdrReader<'_>,
+    entity_path: &EntityPath,
+    mut timepoint: TimePoint,
+) -> anyhow::Result<Row> {
+    let header = Header::read(cdr)?;
+    let height = cdr.u32()? as usize;
+    let width = cdr.u32()? as usize;
+    let num_fields = cdr.sequence_len()?;
+    let fields = (0..num_fields)
+        .map(|_| PointField::read(cdr))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let is_bigendian = cdr.bool()?;
+    let point_step = cdr.u32()? as usize;
+    let row_step = cdr.u32()? as usize;
+    let data = cdr.byte_sequence()?;
+
+    header.insert_stamp(&mut timepoint);
+
+    let field = |name: &str| fields.iter().find(|field| field.name == name);
+    let (Some(x), Some(y), Some(z)) = (field("x"), field("y"), field("z")) else {
+        anyhow::bail!("point cloud has no x, y and z fields");
+    };
+    let color = field("rgb").or_else(|| field("rgba")).filter(|field| {
+        matches!(
+            field.datatype,
+            PointField::INT32 | PointField::UINT32 | PointField::FLOAT32
+        )
+    });
+
+    // Every point takes `point_step` bytes of `data`, which bounds how many there can be.
+    let num_points = height
+        .checked_mul(width)
+        .filter(|&num_points| num_points <= data.len() / point_step.max(1))
+        .with_context(|| format!("not enough data for a {width}x{height} point cloud"))?;
+
+    let mut positions = Vec::with_capacity(num_points);
+    let mut colors = Vec::with_capacity(if color.is_some() { num_points } else { 0 });
+
+    for row in 0..height {
+        for col in 0..width {
+            let point = row
+                .checked_mul(row_step)
+                .zip(col.checked_mul(point_step))
+                .and_then(|(row_start, col_offset)| row_start.checked_add(col_offset))
+                .and_then(|start| data.get(start..start.checked_add(point_step)?));
+            let Some(point) = point else {
+                anyhow::bail!("not enough data for a {width}x{height} point cloud");
+            };
+
+            let (Some(px), Some(py), Some(pz)) = (
+                x.read_f32(point, is_bigendian),
+                y.read_f32(point, is_bigendian),
+                z.read_f32(point, is_bigendian),
+            ) else {
+                continue;
+            };
+            if !(px.is_finite() && py.is_finite() && pz.is_finite()) {
+                continue;
+            }
+
+            if let Some(color) = color {
+                let Some(color) = color.read_color(point, is_bigendian) else {
+                    continue;
+                };
+                colors.push(color);
+            }
+            positions.push([px, py, pz]);
+        }
+    }
+
+    let mut points = Points3D::new(positions);
+    if color.is_some() {
+        points = points.with_colors(colors);
+    }
+
+    Ok(Row::new(entity_path.clone(), timepoint, &points))
+}
+
+/// `sensor_msgs/msg/Imu`
+///
+/// The orientation is logged as a [`Transform3D`] on the entity itself, while angular velocity and
+/// linear acceleration are logged as one [`Scalar`] per axis, e.g. `imu/angular_velocity/x`.
+fn decode_imu(
+    cdr: &mut CdrReader<'_>,
+    entity_path: &EntityPath,
+    mut timepoint: TimePoint,
+) -> anyhow::Result<Vec<Row>> {
+    let header = Header::read(cdr)?;
+    let orientation = cdr.f64_array::<4>()?;
+    let orientation_covariance = cdr.f64_array::<9>()?;
+    let angular_velocity = cdr.f64_array::<3>()?;
+    let _angular_velocity_covariance = cdr.f64_array::<9>()?;
+    let linear_acceleration = cdr.f64_array::<3>()?;
+
+    header.insert_stamp(&mut timepoint);
+
+    let mut rows = Vec::with_capacity(7);
+
+    // By convention, a covariance starting with -1 means that the orientation isn't available.
+    if orientation_covariance[0] >= 0.0 {
+        let transform = Transform3D::from_rotation(Quaternion::from_xyzw(to_f32(orientation)));
+        rows.push(Row::new(entity_path.clone(), timepoint.clone(), &transform));
+    }
+
+    for (name, values) in [
+        ("angular_velocity", angular_velocity),
+        ("linear_acceleration", linear_acceleration),
+    ] {
+        for (axis, value) in ["x", "y", "z"].into_iter().zip(values) {
+            let entity_path = entity_path.join(&EntityPath::new(vec![
+                EntityPathPart::new(name),
+                EntityPathPart::new(axis),
+            ]));
+            rows.push(Row::new(
+                entity_path,
+                timepoint.clone(),
+                &Scalar::new(value),
+            ));
+        }
+    }
+
+    Ok(rows)
+}
+
+/// `sensor_msgs/msg/CameraInfo`
+///
+/// The [`Pinhole`] is logged to the parent of the topic's entity, e.g. `/camera/camera_info` ends
+/// up on `camera`, so that it applies to the images of the same camera (e.g. `/camera/image_raw`).
+fn decode_camera_info(
+    cdr: &mut CdrReader<'_>,
+    entity_path: &EntityPath,
+    mut timepoint: TimePoint,
+) -> anyhow::Result<Row> {
+    let header = Header::read(cdr)?;
+    let height = cdr.u32()?;
+    let width = cdr.u32()?;
+    let _distortion_model = cdr.string()?;
+    let _distortion = cdr.f64_sequence()?;
+    let k = to_f32(cdr.f64_array::<9>()?);
+
+    header.insert_stamp(&mut timepoint);
+
+    // `k` is the row-major intrinsic matrix.
+    let pinhole = Pinhole::new(PinholeProjection::from_focal_length_and_principal_point(
+        [k[0], k[4]],
+        [k[2], k[5]],
+    ))
+    .with_resolution([width as f32, height as f32]);
+
+    let entity_path = entity_path
+        .parent()
+        .filter(|parent| !parent.is_root())
+        .unwrap_or_else(|| entity_path.clone());
+
+    Ok(Row::new(entity_path, timepoint, &pinhole))
+}
+
+// ---
+
+fn to_f32<const N: usize>(values: [f64; N]) -> [f32; N] {
+    values.map(|value| value as f32)
+}
+
+/// Frame ids sometimes have a leading slash, a leftover from ROS 1.
+fn normalize_frame_id(frame_id: &str) -> &str {
+    frame_id.trim_start_matches('/')
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::{components, Component};
+
+    use super::super::cdr::CdrWriter;
+    use super::*;
+
+    fn decoder() -> Ros2Decoder {
+        Ros2Decoder::new("tf".into())
+    }
+
+    fn timepoint() -> TimePoint {
+        TimePoint::default().with(Timeline::log_time(), TimeInt::new_temporal(42))
+    }
+
+    fn component<C: Component>(row: &Row) -> Vec<C> {
+        let (_, array) = row
+            .components
+            .iter()
+            .find(|(component_name, _)| *component_name == C::name())
+            .unwrap_or_else(|| panic!("missing {}", C::name()));
+        C::from_arrow(&**array).unwrap()
+    }
+
+    fn header(cdr: &mut CdrWriter, sec: i32, frame_id: &str) {
+        cdr.i32(sec).u32(0).string(frame_id);
+    }
+
+    /// A `tf2_msgs/msg/TFMessage` with the given `(parent, child)` transforms.
+    fn tf_message(transforms: &[(&str, &str)]) -> Vec<u8> {
+        let mut cdr = CdrWriter::new(true);
+        cdr.u32(transforms.len() as u32);
+        for (parent, child) in transforms {
+            header(&mut cdr, 1, parent);
+            cdr.string(child)
+                .f64_array(&[1.0, 2.0, 3.0])
+                .f64_array(&[0.0, 0.0, 0.0, 1.0]);
+        }
+        cdr.finish()
+    }
+
+    #[test]
+    fn unsupported() {
+        let rows = decoder()
+            .decode("my_msgs/msg/Custom", &"topic".into(), timepoint(), &[])
+            .unwrap();
+        assert!(rows.is_none());
+    }
+
+    #[test]
+    fn scalars_and_strings() {
+        let data = CdrWriter::new(true).f64(1.5).finish();
+        let rows = decoder()
+            .decode("std_msgs/msg/Float64", &"value".into(), timepoint(), &data)
+            .unwrap()
+            .unwrap();
+        assert_eq!(1, rows.len());
+        assert_eq!(EntityPath::from("value"), rows[0].entity_path);
+        assert_eq!(timepoint(), rows[0].timepoint);
+        assert_eq!(vec![components::Scalar::from(1.5)], component(&rows[0]));
+
+        let data = CdrWriter::new(false).i16(-3).finish();
+        let rows = decoder()
+            .decode("std_msgs/msg/Int16", &"value".into(), timepoint(), &data)
+            .unwrap()
+            .unwrap();
+        assert_eq!(vec![components::Scalar::from(-3.0)], component(&rows[0]));
+
+        let data = CdrWriter::new(true).string("hello").finish();
+        let rows = decoder()
+            .decode("std_msgs/msg/String", &"log".into(), timepoint(), &data)
+            .unwrap()
+            .unwrap();
+        assert_eq!(vec![components::Text::from("hello")], component(&rows[0]));
+
+        // Truncated message.
+        let data = CdrWriter::new(true).u8(1).finish();
+        assert!(decoder()
+            .decode("std_msgs/msg/Float64", &"value".into(), timepoint(), &data)
+            .is_err());
+    }
+
+    #[test]
+    fn tf_frame_paths() {
+        // `base_link`'s transform comes before its parent's.
+        let messages = [
+            tf_message(&[("odom", "base_link")]),
+            tf_message(&[("/map", "odom")]),
+            tf_message(&[("odom", "base_link"), ("base_link", "camera")]),
+        ];
+
+        let mut decoder = decoder();
+        for message in &messages {
+            decoder.learn_frame_hierarchy(message).unwrap();
+        }
+
+        let entity_paths = messages
+            .iter()
+            .flat_map(|message| {
+                decoder
+                    .decode(TF_MESSAGE_TYPE, &"tf".into(), timepoint(), message)
+                    .unwrap()
+                    .unwrap()
+            })
+            .map(|row| row.entity_path.to_string())
+            .collect::<Vec<_>>();
+
+        // Every frame always ends up on the same entity.
+        assert_eq!(
+            vec![
+                "/tf/map/odom/base_link",
+                "/tf/map/odom",
+                "/tf/map/odom/base_link",
+                "/tf/map/odom/base_link/camera",
+            ],
+            entity_paths
+        );
+    }
+
+    #[test]
+    fn tf_transform() {
+        let rows = decoder()
+            .decode(
+                TF_MESSAGE_TYPE,
+                &"tf".into(),
+                timepoint(),
+                &tf_message(&[("map", "odom")]),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(1, rows.len());
+
+        // The header stamp ends up on its own timeline.
+        assert_eq!(
+            timepoint().with(
+                Timeline::new_temporal(ROS_TIME_TIMELINE),
+                TimeInt::new_temporal(1_000_000_000)
+            ),
+            rows[0].timepoint
+        );
+        assert_eq!(
+            vec![components::Translation3D::from([1.0, 2.0, 3.0])],
+            component(&rows[0])
+        );
+
+        // Static transforms don't get a stamp.
+        let rows = decoder()
+            .decode(
+                TF_MESSAGE_TYPE,
+                &"tf".into(),
+                TimePoint::default(),
+                &tf_message(&[("map", "odom")]),
+            )
+            .unwrap()
+            .unwrap();
+        assert!(rows[0].timepoint.is_static());
+    }
+
+    #[test]
+    fn tf_cycle() {
+        let mut decoder = decoder();
+        decoder
+            .learn_frame_hierarchy(&tf_message(&[("a", "b"), ("b", "a")]))
+            .unwrap();
+
+        // Doesn't loop forever.
+        assert_eq!(EntityPath::from("tf/a/b"), decoder.frame_entity_path("b"));
+    }
+
+    fn image_message(encoding: &str, width: u32, height: u32, step: u32, data: &[u8]) -> Vec<u8> {
+        let mut cdr = CdrWriter::new(true);
+        header(&mut cdr, 0, "camera");
+        cdr.u32(height)
+            .u32(width)
+            .string(encoding)
+            .bool(false)
+            .u32(step)
+            .byte_sequence(data);
+        cdr.finish()
+    }
+
+    #[test]
+    fn image() {
+        // 2x2 `bgr8` image, with 2 bytes of padding per row.
+        let data = [
+            1, 2, 3, 4, 5, 6, 0, 0, //
+            7, 8, 9, 10, 11, 12, 0, 0,
+        ];
+        let rows = decoder()
+            .decode(
+                "sensor_msgs/msg/Image",
+                &"camera/image".into(),
+                timepoint(),
+                &image_message("bgr8", 2, 2, 8, &data),
+            )
+            .unwrap()
+            .unwrap();
+
+        let [tensor] = &component::<components::TensorData>(&rows[0])[..] else {
+            panic!("expected a single tensor");
+        };
+        assert_eq!(
+            vec![2, 2, 3],
+            tensor
+                .0
+                .shape
+                .iter()
+                .map(|dim| dim.size)
+                .collect::<Vec<_>>()
+        );
+        let TensorBuffer::U8(pixels) = &tensor.0.buffer else {
+            panic!("expected u8 pixels");
+        };
+        // Padding is gone, and channels are swapped to RGB.
+        assert_eq!(
+            &[3_u8, 2, 1, 6, 5, 4, 9, 8, 7, 12, 11, 10],
+            pixels.as_slice()
+        );
+
+        // Not enough data.
+        assert!(decoder()
+            .decode(
+                "sensor_msgs/msg/Image",
+                &"camera/image".into(),
+                timepoint(),
+                &image_message("rgb8", 2, 2, 6, &data[..8]),
+            )
+            .is_err());
+
+        // Unknown encoding.
+        assert!(decoder()
+            .decode(
+                "sensor_msgs/msg/Image",
+                &"camera/image".into(),
+                timepoint(),
+                &image_message("yuv422", 2, 2, 8, &data),
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn point_cloud() {
+        let mut cdr = CdrWriter::new(true);
+        header(&mut cdr, 0, "lidar");
+        cdr.u32(1).u32(3); // height, width
+        cdr.u32(4);
+        for (name, offset, datatype) in [
+            ("x", 0, PointField::FLOAT32),
+            ("y", 4, PointField::FLOAT32),
+            ("z", 8, PointField::FLOAT32),
+            ("rgb", 12, PointField::UINT32),
+        ] {
+            cdr.string(name).u32(offset).u8(datatype).u32(1);
+        }
+
+        let mut data = Vec::new();
+        for (position, color) in [
+            ([1.0_f32, 2.0, 3.0], [0x30, 0x20, 0x10, 0x00]),
+            ([f32::NAN, 0.0, 0.0], [0, 0, 0, 0]),
+            ([4.0_f32, 5.0, 6.0], [0x60, 0x50, 0x40, 0x00]),
+        ] {
+            for value in position {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+            data.extend_from_slice(&color);
+        }
+        cdr.bool(false).u32(16).u32(48).byte_sequence(&data);
+
+        let rows = decoder()
+            .decode(
+                "sensor_msgs/msg/PointCloud2",
+                &"lidar".into(),
+                timepoint(),
+                &cdr.finish(),
+            )
+            .unwrap()
+            .unwrap();
+
+        // The point with a NaN coordinate is skipped.
+        assert_eq!(
+            vec![
+                components::Position3D::new(1.0, 2.0, 3.0),
+                components::Position3D::new(4.0, 5.0, 6.0),
+            ],
+            component(&rows[0])
+        );
+        assert_eq!(
+            vec![
+                components::Color::from_rgb(0x10, 0x20, 0x30),
+                components::Color::from_rgb(0x40, 0x50, 0x60),
+            ],
+            component(&rows[0])
+        );
+    }
+
+    #[test]
+    fn point_cloud_larger_than_its_data() {
+        for (height, width, point_step, row_step) in [
+            // More points than fit in the data.
+            (u32::MAX, u32::MAX, 4, 4),
+            // Rows that start beyond the data.
+            (2, 1, 4, u32::MAX),
+        ] {
+            let mut cdr = CdrWriter::new(true);
+            header(&mut cdr, 0, "lidar");
+            cdr.u32(height).u32(width);
+            cdr.u32(3);
+            for (name, offset) in [("x", 0), ("y", 0), ("z", 0)] {
+                cdr.string(name).u32(offset).u8(PointField::FLOAT32).u32(1);
+            }
+            cdr.bool(false)
+                .u32(point_step)
+                .u32(row_step)
+                .byte_sequence(&[0; 8]);
+
+            assert!(decoder()
+                .decode(
+                    "sensor_msgs/msg/PointCloud2",
+                    &"lidar".into(),
+                    timepoint(),
+                    &cdr.finish(),
+                )
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn imu() {
+        let mut cdr = CdrWriter::new(true);
+        header(&mut cdr, 0, "imu");
+        cdr.f64_array(&[0.0, 0.0, 0.0, 1.0])
+            .f64_array(&[-1.0; 9]) // No orientation.
+            .f64_array(&[1.0, 2.0, 3.0])
+            .f64_array(&[0.0; 9])
+            .f64_array(&[4.0, 5.0, 6.0])
+            .f64_array(&[0.0; 9]);
+
+        let rows = decoder()
+            .decode(
+                "sensor_msgs/msg/Imu",
+                &"imu".into(),
+                timepoint(),
+                &cdr.finish(),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "/imu/angular_velocity/x",
+                "/imu/angular_velocity/y",
+                "/imu/angular_velocity/z",
+                "/imu/linear_acceleration/x",
+                "/imu/linear_acceleration/y",
+                "/imu/linear_acceleration/z",
+            ],
+            rows.iter()
+                .map(|row| row.entity_path.to_string())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(vec![components::Scalar::from(6.0)], component(&rows[5]));
+    }
+
+    #[test]
+    fn camera_info() {
+        let mut cdr = CdrWriter::new(true);
+        header(&mut cdr, 0, "camera");
+        cdr.u32(480)
+            .u32(640)
+            .string("plumb_bob")
+            .u32(5)
+            .f64_array(&[0.0; 5])
+            .f64_array(&[500.0, 0.0, 320.0, 0.0, 500.0, 240.0, 0.0, 0.0, 1.0]);
+
+        let rows = decoder()
+            .decode(
+                "sensor_msgs/msg/CameraInfo",
+                &"camera/camera_info".into(),
+                timepoint(),
+                &cdr.finish(),
+            )
+            .unwrap()
+            .unwrap();
+
+        // Logged to the camera itself, so that it applies to its images.
+        assert_eq!(EntityPath::from("camera"), rows[0].entity_path);
+        assert_eq!(
+            vec![components::Resolution::from([640.0, 480.0])],
+            component(&rows[0])
+        );
+    }
+}
@@ -0,0 +1,866 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context as _;
+
+use re_chunk::{Chunk, RowId};
+use re_log_types::{EntityPath, EntityPathPart, TimePoint};
+use re_types::{
+    archetypes::{Asset3D, Mesh3D, Transform3D},
+    components::MediaType,
+    datatypes::{Quaternion, Rgba32},
+    AsComponents,
+};
+
+use crate::{DataLoader, LoadedData};
+
+// ---
+
+/// Loads robot descriptions from [URDF files](https://wiki.ros.org/urdf/XML).
+///
+/// The kinematic tree is mirrored in the entity hierarchy, alternating links and joints:
+/// `<robot>/<root link>/<joint>/<child link>/…`.
+/// * Every joint entity holds the (static) [`Transform3D`] of the joint's origin, relative to
+///   its parent link.
+/// * Every link entity is left untouched, so that joint states can be logged to it as
+///   [`Transform3D`]s later on (e.g. a rotation around the joint's axis for a revolute joint)
+///   to animate the robot.
+/// * The visual geometries of a link are logged to `<link>/visual_<index>` (or their name, if
+///   any): meshes as [`Asset3D`]s, boxes, cylinders and spheres as [`Mesh3D`]s.
+///
+/// Mesh references can be `package://` URIs, `file://` URIs or paths relative to the URDF file.
+/// Packages are looked up in `ROS_PACKAGE_PATH`, `AMENT_PREFIX_PATH`, and in the folders
+/// surrounding the URDF file.
+pub struct UrdfLoader;
+
+impl DataLoader for UrdfLoader {
+    #[inline]
+    fn name(&self) -> String {
+        "rerun.data_loaders.Urdf".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), crate::DataLoaderError> {
+        if filepath.is_dir() {
+            return Err(crate::DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        let extension = crate::extension(&filepath);
+        if !crate::SUPPORTED_URDF_EXTENSIONS.contains(&extension.as_str()) {
+            return Err(crate::DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let contents = std::fs::read(&filepath)
+            .with_context(|| format!("Failed to read file {filepath:?}"))?;
+        let contents = std::borrow::Cow::Owned(contents);
+
+        self.load_from_file_contents(settings, filepath, contents, tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), crate::DataLoaderError> {
+        let extension = crate::extension(&filepath);
+        if !crate::SUPPORTED_URDF_EXTENSIONS.contains(&extension.as_str()) {
+            return Err(crate::DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        re_log::debug!(?filepath, loader = self.name(), "Loading URDF…",);
+
+        let contents = std::str::from_utf8(&contents).context("URDF file is not valid UTF-8")?;
+        let robot = Robot::parse(contents)?;
+
+        let root = settings
+            .entity_path_prefix
+            .clone()
+            .unwrap_or_else(EntityPath::root)
+            .join(&entity_path_part(&robot.name));
+        let urdf_dir = filepath.parent().unwrap_or_else(|| Path::new("."));
+
+        for chunk in robot.to_chunks(&root, urdf_dir)? {
+            if tx.send(chunk.into()).is_err() {
+                break; // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// The parts of a URDF robot description that we know how to log.
+struct Robot {
+    name: String,
+    links: BTreeMap<String, Link>,
+    joints: Vec<Joint>,
+}
+
+struct Link {
+    visuals: Vec<Visual>,
+}
+
+struct Visual {
+    name: Option<String>,
+    origin: Origin,
+    geometry: Geometry,
+    color: Option<Rgba32>,
+}
+
+enum Geometry {
+    Mesh { filename: String, scale: [f32; 3] },
+    Box { size: [f32; 3] },
+    Cylinder { radius: f32, length: f32 },
+    Sphere { radius: f32 },
+}
+
+struct Joint {
+    name: String,
+    parent: String,
+    child: String,
+    origin: Origin,
+}
+
+/// A URDF `<origin>`: a translation followed by a rotation given as fixed-axis roll, pitch and
+/// yaw angles.
+#[derive(Default, Clone, Copy)]
+struct Origin {
+    xyz: [f32; 3],
+    rpy: [f32; 3],
+}
+
+impl Origin {
+    fn parse(node: Option<roxmltree::Node<'_, '_>>) -> anyhow::Result<Self> {
+        let Some(node) = node else {
+            return Ok(Self::default());
+        };
+        Ok(Self {
+            xyz: node.attribute("xyz").map_or(Ok([0.0; 3]), parse_floats)?,
+            rpy: node.attribute("rpy").map_or(Ok([0.0; 3]), parse_floats)?,
+        })
+    }
+
+    fn rotation(&self) -> Quaternion {
+        let [roll, pitch, yaw] = self.rpy;
+        let (sr, cr) = (roll * 0.5).sin_cos();
+        let (sp, cp) = (pitch * 0.5).sin_cos();
+        let (sy, cy) = (yaw * 0.5).sin_cos();
+        Quaternion::from_xyzw([
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+            cr * cp * cy + sr * sp * sy,
+        ])
+    }
+}
+
+impl Robot {
+    fn parse(contents: &str) -> anyhow::Result<Self> {
+        re_tracing::profile_function!();
+
+        let doc = roxmltree::Document::parse(contents).context("invalid XML")?;
+        let robot = doc.root_element();
+        anyhow::ensure!(
+            robot.has_tag_name("robot"),
+            "expected a <robot> root element, got <{}>",
+            robot.tag_name().name()
+        );
+
+        // Materials can be defined at the top-level and then referred to by name.
+        let mut materials = HashMap::new();
+        for material in robot
+            .children()
+            .filter(|node| node.has_tag_name("material"))
+        {
+            if let (Some(name), Some(color)) = (material.attribute("name"), parse_color(material)?)
+            {
+                materials.insert(name.to_owned(), color);
+            }
+        }
+
+        let mut links = BTreeMap::new();
+        for link in robot.children().filter(|node| node.has_tag_name("link")) {
+            let name = required_attribute(link, "name")?;
+            let visuals = link
+                .children()
+                .filter(|node| node.has_tag_name("visual"))
+                .filter_map(|visual| parse_visual(visual, &materials).transpose())
+                .collect::<anyhow::Result<_>>()
+                .with_context(|| format!("invalid link {name:?}"))?;
+            links.insert(name.to_owned(), Link { visuals });
+        }
+
+        let mut joints = Vec::new();
+        for joint in robot.children().filter(|node| node.has_tag_name("joint")) {
+            let name = required_attribute(joint, "name")?;
+            let link = |tag: &str| -> anyhow::Result<String> {
+                let node =
+                    child(joint, tag).with_context(|| format!("joint {name:?} has no <{tag}>"))?;
+                Ok(required_attribute(node, "link")?.to_owned())
+            };
+            joints.push(Joint {
+                name: name.to_owned(),
+                parent: link("parent")?,
+                child: link("child")?,
+                origin: Origin::parse(child(joint, "origin"))
+                    .with_context(|| format!("invalid joint {name:?}"))?,
+            });
+        }
+
+        Ok(Self {
+            name: robot.attribute("name").unwrap_or("robot").to_owned(),
+            links,
+            joints,
+        })
+    }
+
+    fn to_chunks(&self, root: &EntityPath, urdf_dir: &Path) -> anyhow::Result<Vec<Chunk>> {
+        re_tracing::profile_function!();
+
+        let mut joints_per_parent: HashMap<&str, Vec<&Joint>> = HashMap::new();
+        for joint in &self.joints {
+            joints_per_parent
+                .entry(joint.parent.as_str())
+                .or_default()
+                .push(joint);
+        }
+
+        let children: HashSet<&str> = self
+            .joints
+            .iter()
+            .map(|joint| joint.child.as_str())
+            .collect();
+
+        let mut chunks = Vec::new();
+        let mut visited = HashSet::new();
+
+        // Walk the kinematic tree, starting from the links that aren't the child of any joint.
+        let mut stack: Vec<(&str, EntityPath)> = self
+            .links
+            .keys()
+            .filter(|link| !children.contains(link.as_str()))
+            .map(|link| (link.as_str(), root.join(&entity_path_part(link))))
+            .collect();
+
+        while let Some((link_name, link_path)) = stack.pop() {
+            if !visited.insert(link_name) {
+                re_log::warn_once!("Cycle in URDF kinematic tree involving link {link_name:?}");
+                continue;
+            }
+
+            if let Some(link) = self.links.get(link_name) {
+                for (index, visual) in link.visuals.iter().enumerate() {
+                    let name = visual
+                        .name
+                        .clone()
+                        .unwrap_or_else(|| format!("visual_{index}"));
+                    let visual_path = link_path.join(&entity_path_part(&name));
+                    chunks.extend(visual.to_chunks(visual_path, urdf_dir)?);
+                }
+            } else {
+                re_log::warn_once!("URDF joint refers to unknown link {link_name:?}");
+            }
+
+            for joint in joints_per_parent.get(link_name).into_iter().flatten() {
+                let joint_path = link_path.join(&entity_path_part(&joint.name));
+                let transform = Transform3D::from_translation_rotation(
+                    joint.origin.xyz,
+                    joint.origin.rotation(),
+                );
+                chunks.push(static_chunk(joint_path.clone(), &transform)?);
+
+                stack.push((
+                    joint.child.as_str(),
+                    joint_path.join(&entity_path_part(&joint.child)),
+                ));
+            }
+        }
+
+        Ok(chunks)
+    }
+}
+
+impl Visual {
+    fn to_chunks(&self, entity_path: EntityPath, urdf_dir: &Path) -> anyhow::Result<Vec<Chunk>> {
+        let translation = self.origin.xyz;
+        let rotation = self.origin.rotation();
+
+        let mesh = match &self.geometry {
+            Geometry::Mesh { filename, scale } => {
+                let transform =
+                    Transform3D::from_translation_rotation_scale(translation, rotation, *scale);
+
+                let Some(asset) = load_mesh(filename, urdf_dir) else {
+                    return Ok(vec![static_chunk(entity_path, &transform)?]);
+                };
+
+                return Ok(vec![
+                    static_chunk(entity_path.clone(), &transform)?,
+                    static_chunk(entity_path, &asset)?,
+                ]);
+            }
+            Geometry::Box { size } => box_mesh(*size),
+            Geometry::Cylinder { radius, length } => cylinder_mesh(*radius, *length),
+            Geometry::Sphere { radius } => sphere_mesh(*radius),
+        };
+
+        let mesh = match self.color {
+            Some(color) => mesh.with_albedo_factor(color),
+            None => mesh,
+        };
+
+        let transform = Transform3D::from_translation_rotation(translation, rotation);
+        Ok(vec![
+            static_chunk(entity_path.clone(), &transform)?,
+            static_chunk(entity_path, &mesh)?,
+        ])
+    }
+}
+
+// --- Parsing ---
+
+fn child<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    tag_name: &str,
+) -> Option<roxmltree::Node<'a, 'input>> {
+    node.children().find(|child| child.has_tag_name(tag_name))
+}
+
+fn required_attribute<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> anyhow::Result<&'a str> {
+    node.attribute(name).with_context(|| {
+        format!(
+            "<{}> is missing its {name:?} attribute",
+            node.tag_name().name()
+        )
+    })
+}
+
+fn parse_floats<const N: usize>(value: &str) -> anyhow::Result<[f32; N]> {
+    let mut floats = [0.0; N];
+    let mut values = value.split_whitespace();
+    for float in &mut floats {
+        let value = values
+            .next()
+            .with_context(|| format!("expected {N} numbers, got {value:?}"))?;
+        *float = value
+            .parse()
+            .with_context(|| format!("invalid number {value:?}"))?;
+    }
+    anyhow::ensure!(
+        values.next().is_none(),
+        "expected {N} numbers, got {value:?}"
+    );
+    Ok(floats)
+}
+
+fn parse_float(node: roxmltree::Node<'_, '_>, name: &str) -> anyhow::Result<f32> {
+    let [value] = parse_floats(required_attribute(node, name)?)?;
+    Ok(value)
+}
+
+/// Parses the `<color rgba="…">` of a `<material>`, if any.
+fn parse_color(material: roxmltree::Node<'_, '_>) -> anyhow::Result<Option<Rgba32>> {
+    let Some(color) = child(material, "color") else {
+        return Ok(None);
+    };
+    let rgba: [f32; 4] = parse_floats(required_attribute(color, "rgba")?)?;
+    let [r, g, b, a] = rgba.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+    Ok(Some(Rgba32::from_unmultiplied_rgba(r, g, b, a)))
+}
+
+/// Returns `None` for unsupported geometries.
+fn parse_visual(
+    visual: roxmltree::Node<'_, '_>,
+    materials: &HashMap<String, Rgba32>,
+) -> anyhow::Result<Option<Visual>> {
+    let geometry = child(visual, "geometry")
+        .and_then(|geometry| geometry.children().find(|node| node.is_element()))
+        .context("<visual> has no geometry")?;
+
+    let geometry = match geometry.tag_name().name() {
+        "mesh" => Geometry::Mesh {
+            filename: required_attribute(geometry, "filename")?.to_owned(),
+            scale: geometry
+                .attribute("scale")
+                .map_or(Ok([1.0; 3]), parse_floats)?,
+        },
+        "box" => Geometry::Box {
+            size: parse_floats(required_attribute(geometry, "size")?)?,
+        },
+        "cylinder" => Geometry::Cylinder {
+            radius: parse_float(geometry, "radius")?,
+            length: parse_float(geometry, "length")?,
+        },
+        "sphere" => Geometry::Sphere {
+            radius: parse_float(geometry, "radius")?,
+        },
+        name => {
+            re_log::warn_once!("Unsupported URDF geometry <{name}>");
+            return Ok(None);
+        }
+    };
+
+    // Inline colors take precedence over named materials.
+    let color = match child(visual, "material") {
+        Some(material) => match parse_color(material)? {
+            Some(color) => Some(color),
+            None => material
+                .attribute("name")
+                .and_then(|name| materials.get(name))
+                .copied(),
+        },
+        None => None,
+    };
+
+    Ok(Some(Visual {
+        name: visual.attribute("name").map(ToOwned::to_owned),
+        origin: Origin::parse(child(visual, "origin"))?,
+        geometry,
+        color,
+    }))
+}
+
+// --- Meshes ---
+
+/// Loads the mesh file referred to by a `<mesh filename="…">`.
+///
+/// Failures are logged, as a missing mesh shouldn't prevent the rest of the robot from loading.
+fn load_mesh(filename: &str, urdf_dir: &Path) -> Option<Asset3D> {
+    let Some(path) = resolve_mesh_path(filename, urdf_dir) else {
+        re_log::warn!("Couldn't find URDF mesh {filename:?}");
+        return None;
+    };
+
+    if !crate::SUPPORTED_MESH_EXTENSIONS.contains(&crate::extension(&path).as_str()) {
+        re_log::warn!("Unsupported URDF mesh format: {path:?}");
+        return None;
+    }
+
+    match std::fs::read(&path) {
+        Ok(contents) => Some(Asset3D::from_file_contents(
+            contents,
+            MediaType::guess_from_path(&path),
+        )),
+        Err(err) => {
+            re_log::warn!("Failed to read URDF mesh {path:?}: {err}");
+            None
+        }
+    }
+}
+
+/// Resolves `package://`, `file://` and relative mesh references to a path on disk.
+fn resolve_mesh_path(filename: &str, urdf_dir: &Path) -> Option<PathBuf> {
+    if let Some(path) = filename.strip_prefix("file://") {
+        return Some(PathBuf::from(path));
+    }
+
+    let Some(package_path) = filename.strip_prefix("package://") else {
+        return Some(urdf_dir.join(filename));
+    };
+    let (package, relative_path) = package_path.split_once('/')?;
+
+    let mut package_dirs = Vec::new();
+
+    // ROS 1
+    if let Some(paths) = std::env::var_os("ROS_PACKAGE_PATH") {
+        package_dirs.extend(std::env::split_paths(&paths).map(|path| path.join(package)));
+    }
+    // ROS 2
+    if let Some(paths) = std::env::var_os("AMENT_PREFIX_PATH") {
+        package_dirs
+            .extend(std::env::split_paths(&paths).map(|path| path.join("share").join(package)));
+    }
+    // The URDF file is usually somewhere within its package, or next to it.
+    for dir in urdf_dir.ancestors() {
+        if dir.file_name().is_some_and(|name| name == package) {
+            package_dirs.push(dir.to_owned());
+        }
+        package_dirs.push(dir.join(package));
+    }
+
+    package_dirs
+        .into_iter()
+        .map(|dir| dir.join(relative_path))
+        .find(|path| path.is_file())
+}
+
+/// An axis-aligned box centered on the origin, with flat normals.
+fn box_mesh(size: [f32; 3]) -> Mesh3D {
+    let [hx, hy, hz] = size.map(|s| s * 0.5);
+
+    let mut positions = Vec::with_capacity(24);
+    let mut normals = Vec::with_capacity(24);
+    let mut indices = Vec::with_capacity(12);
+
+    // For each face: its normal axis, the sign of the normal, and the two in-plane axes in
+    // counter-clockwise order when seen from outside.
+    for (axis, sign) in [
+        (0, 1.0),
+        (0, -1.0),
+        (1, 1.0),
+        (1, -1.0),
+        (2, 1.0),
+        (2, -1.0),
+    ] {
+        let (u, v) = if sign > 0.0 {
+            ((axis + 1) % 3, (axis + 2) % 3)
+        } else {
+            ((axis + 2) % 3, (axis + 1) % 3)
+        };
+
+        let first = positions.len() as u32;
+        for (su, sv) in [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)] {
+            let mut position = [0.0; 3];
+            position[axis] = sign;
+            position[u] = su;
+            position[v] = sv;
+            positions.push([position[0] * hx, position[1] * hy, position[2] * hz]);
+
+            let mut normal = [0.0; 3];
+            normal[axis] = sign;
+            normals.push(normal);
+        }
+        indices.push([first, first + 1, first + 2]);
+        indices.push([first, first + 2, first + 3]);
+    }
+
+    Mesh3D::new(positions)
+        .with_vertex_normals(normals)
+        .with_triangle_indices(indices)
+}
+
+const NUM_SEGMENTS: u32 = 32;
+
+/// A cylinder centered on the origin, along the Z axis.
+fn cylinder_mesh(radius: f32, length: f32) -> Mesh3D {
+    let half_length = length * 0.5;
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut indices = Vec::new();
+
+    let ring = |i: u32| {
+        let angle = std::f32::consts::TAU * i as f32 / NUM_SEGMENTS as f32;
+        let (sin, cos) = angle.sin_cos();
+        (cos, sin)
+    };
+
+    // Side
+    for i in 0..=NUM_SEGMENTS {
+        let (cos, sin) = ring(i);
+        for z in [-half_length, half_length] {
+            positions.push([radius * cos, radius * sin, z]);
+            normals.push([cos, sin, 0.0]);
+        }
+    }
+    for i in 0..NUM_SEGMENTS {
+        let bottom = 2 * i;
+        indices.push([bottom, bottom + 2, bottom + 3]);
+        indices.push([bottom, bottom + 3, bottom + 1]);
+    }
+
+    // Caps
+    for (z, normal_z) in [(-half_length, -1.0), (half_length, 1.0)] {
+        let center = positions.len() as u32;
+        positions.push([0.0, 0.0, z]);
+        normals.push([0.0, 0.0, normal_z]);
+        for i in 0..NUM_SEGMENTS {
+            let (cos, sin) = ring(i);
+            positions.push([radius * cos, radius * sin, z]);
+            normals.push([0.0, 0.0, normal_z]);
+        }
+        for i in 0..NUM_SEGMENTS {
+            let (a, b) = (center + 1 + i, center + 1 + (i + 1) % NUM_SEGMENTS);
+            if normal_z > 0.0 {
+                indices.push([center, a, b]);
+            } else {
+                indices.push([center, b, a]);
+            }
+        }
+    }
+
+    Mesh3D::new(positions)
+        .with_vertex_normals(normals)
+        .with_triangle_indices(indices)
+}
+
+/// A UV sphere centered on the origin.
+fn sphere_mesh(radius: f32) -> Mesh3D {
+    let num_rings = NUM_SEGMENTS / 2;
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut indices = Vec::new();
+
+    for ring in 0..=num_rings {
+        let theta = std::f32::consts::PI * ring as f32 / num_rings as f32;
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        for segment in 0..=NUM_SEGMENTS {
+            let phi = std::f32::consts::TAU * segment as f32 / NUM_SEGMENTS as f32;
+            let (sin_phi, cos_phi) = phi.sin_cos();
+            let normal = [sin_theta * cos_phi, sin_theta * sin_phi, cos_theta];
+            positions.push(normal.map(|n| n * radius));
+            normals.push(normal);
+        }
+    }
+
+    let stride = NUM_SEGMENTS + 1;
+    for ring in 0..num_rings {
+        for segment in 0..NUM_SEGMENTS {
+            let a = ring * stride + segment;
+            let b = a + stride;
+            indices.push([a, b, b + 1]);
+            indices.push([a, b + 1, a + 1]);
+        }
+    }
+
+    Mesh3D::new(positions)
+        .with_vertex_normals(normals)
+        .with_triangle_indices(indices)
+}
+
+// --- Helpers ---
+
+fn entity_path_part(name: &str) -> EntityPath {
+    EntityPath::new(vec![EntityPathPart::new(name)])
+}
+
+fn static_chunk(entity_path: EntityPath, archetype: &dyn AsComponents) -> anyhow::Result<Chunk> {
+    Ok(Chunk::builder(entity_path)
+        .with_archetype(RowId::new(), TimePoint::default(), archetype)
+        .build()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{StoreId, StoreKind};
+    use re_types::{components, Component, Loggable as _};
+
+    use super::*;
+
+    const URDF: &str = r#"<?xml version="1.0"?>
+<robot name="arm">
+  <material name="red">
+    <color rgba="1 0 0 1"/>
+  </material>
+
+  <link name="base_link">
+    <visual>
+      <geometry><box size="1 2 3"/></geometry>
+      <material name="red"/>
+    </visual>
+  </link>
+  <link name="upper_arm">
+    <visual name="shell">
+      <origin xyz="0 0 0.5"/>
+      <geometry><cylinder radius="0.1" length="1"/></geometry>
+    </visual>
+    <visual>
+      <geometry><mesh filename="package://arm_description/meshes/missing.stl"/></geometry>
+    </visual>
+  </link>
+  <link name="forearm"/>
+
+  <joint name="shoulder" type="revolute">
+    <parent link="base_link"/>
+    <child link="upper_arm"/>
+    <origin xyz="0 0 1.5" rpy="0 0 1.5707963"/>
+  </joint>
+  <joint name="elbow" type="fixed">
+    <parent link="upper_arm"/>
+    <child link="forearm"/>
+    <origin xyz="1 0 0"/>
+  </joint>
+</robot>
+"#;
+
+    fn load(contents: &str) -> Vec<Chunk> {
+        let settings =
+            crate::DataLoaderSettings::recommended(StoreId::random(StoreKind::Recording));
+        let (tx, rx) = std::sync::mpsc::channel();
+        UrdfLoader
+            .load_from_file_contents(&settings, "arm.urdf".into(), contents.as_bytes().into(), tx)
+            .unwrap();
+
+        rx.try_iter()
+            .map(|data| match data {
+                LoadedData::Chunk(chunk) => chunk,
+                _ => panic!("expected chunks"),
+            })
+            .collect()
+    }
+
+    fn component<C: Component>(chunks: &[Chunk], entity_path: &str) -> Option<C> {
+        chunks
+            .iter()
+            .filter(|chunk| chunk.entity_path() == &EntityPath::from(entity_path))
+            .find_map(|chunk| chunk.components().get(&C::name()))
+            .map(|list_array| C::from_arrow(&*list_array.value(0)).unwrap().remove(0))
+    }
+
+    #[test]
+    fn entity_paths() {
+        let chunks = load(URDF);
+        assert!(chunks.iter().all(|chunk| chunk.is_static()));
+
+        let mut entity_paths = chunks
+            .iter()
+            .map(|chunk| chunk.entity_path().to_string())
+            .collect::<Vec<_>>();
+        entity_paths.sort();
+        entity_paths.dedup();
+
+        // The missing mesh still gets its transform logged.
+        assert_eq!(
+            vec![
+                "/arm/base_link/shoulder",
+                "/arm/base_link/shoulder/upper_arm/elbow",
+                "/arm/base_link/shoulder/upper_arm/shell",
+                "/arm/base_link/shoulder/upper_arm/visual_1",
+                "/arm/base_link/visual_0",
+            ],
+            entity_paths
+        );
+
+        // Links are left free for joint states.
+        assert!(component::<components::Translation3D>(
+            &chunks,
+            "arm/base_link/shoulder/upper_arm"
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn transforms() {
+        let chunks = load(URDF);
+
+        let translation = |entity_path: &str| {
+            component::<components::Translation3D>(&chunks, entity_path)
+                .unwrap()
+                .0
+                 .0
+        };
+        assert_eq!([0.0, 0.0, 1.5], translation("arm/base_link/shoulder"));
+        assert_eq!(
+            [1.0, 0.0, 0.0],
+            translation("arm/base_link/shoulder/upper_arm/elbow")
+        );
+        assert_eq!(
+            [0.0, 0.0, 0.5],
+            translation("arm/base_link/shoulder/upper_arm/shell")
+        );
+        assert_eq!([0.0; 3], translation("arm/base_link/visual_0"));
+    }
+
+    #[test]
+    fn visuals() {
+        let chunks = load(URDF);
+
+        let albedo = component::<components::AlbedoFactor>(&chunks, "arm/base_link/visual_0");
+        assert_eq!(
+            Some(Rgba32::from_unmultiplied_rgba(255, 0, 0, 255)),
+            albedo.map(|albedo| albedo.0)
+        );
+
+        let positions = chunks
+            .iter()
+            .find(|chunk| {
+                chunk.entity_path() == &EntityPath::from("arm/base_link/visual_0")
+                    && chunk
+                        .components()
+                        .contains_key(&components::Position3D::name())
+            })
+            .unwrap()
+            .components()[&components::Position3D::name()]
+            .value(0);
+        let positions = components::Position3D::from_arrow(&*positions).unwrap();
+        assert_eq!(24, positions.len());
+        assert!(positions
+            .iter()
+            .all(|p| p.x().abs() == 0.5 && p.y().abs() == 1.0 && p.z().abs() == 1.5));
+
+        assert!(component::<components::Blob>(
+            &chunks,
+            "arm/base_link/shoulder/upper_arm/visual_1"
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn rotation() {
+        let origin = Origin {
+            xyz: [0.0; 3],
+            rpy: [0.0, 0.0, std::f32::consts::FRAC_PI_2],
+        };
+        let [x, y, z, w] = origin.rotation().0;
+        let half_sqrt2 = std::f32::consts::FRAC_1_SQRT_2;
+        for (actual, expected) in [(x, 0.0), (y, 0.0), (z, half_sqrt2), (w, half_sqrt2)] {
+            assert!((actual - expected).abs() < 1e-6, "{actual} != {expected}");
+        }
+
+        let origin = Origin {
+            xyz: [0.0; 3],
+            rpy: [std::f32::consts::PI, 0.0, 0.0],
+        };
+        let [x, y, z, w] = origin.rotation().0;
+        for (actual, expected) in [(x, 1.0), (y, 0.0), (z, 0.0), (w, 0.0)] {
+            assert!((actual - expected).abs() < 1e-6, "{actual} != {expected}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Robot::parse("<not_a_robot/>").is_err());
+        assert!(Robot::parse(
+            r#"<robot name="r"><joint name="j"><child link="a"/></joint></robot>"#
+        )
+        .is_err());
+        assert!(Robot::parse(
+            r#"<robot name="r"><link name="a"><visual><origin xyz="1 2"/><geometry><sphere radius="1"/></geometry></visual></link></robot>"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn mesh_relative_to_urdf() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("part.obj"), "v 0 0 0\n").unwrap();
+        let urdf = dir.path().join("part.urdf");
+        std::fs::write(
+            &urdf,
+            r#"<robot name="part"><link name="body"><visual><geometry><mesh filename="part.obj" scale="2 2 2"/></geometry></visual></link></robot>"#,
+        )
+        .unwrap();
+
+        let settings =
+            crate::DataLoaderSettings::recommended(StoreId::random(StoreKind::Recording));
+        let (tx, rx) = std::sync::mpsc::channel();
+        UrdfLoader.load_from_path(&settings, urdf, tx).unwrap();
+        let chunks = rx
+            .try_iter()
+            .map(|data| match data {
+                LoadedData::Chunk(chunk) => chunk,
+                _ => panic!("expected chunks"),
+            })
+            .collect::<Vec<_>>();
+
+        assert!(component::<components::Blob>(&chunks, "part/body/visual_0").is_some());
+        assert_eq!(
+            Some([2.0; 3]),
+            component::<components::Scale3D>(&chunks, "part/body/visual_0").map(|scale| scale.0 .0)
+        );
+    }
+}
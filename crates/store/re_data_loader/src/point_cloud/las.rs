@@ -0,0 +1,411 @@
+//! [LAS](https://www.asprs.org/divisions-committees/lidar-division/laser-las-file-format-exchange-activities)
+//! files, the standard format for lidar data.
+//!
+//! All point data record formats (0 to 10) of LAS 1.0 to 1.4 are supported.
+//! Compressed LAZ files are not.
+
+use anyhow::Context as _;
+
+use re_types::components::Color;
+
+use super::{Column, PointCloud};
+
+// ---
+
+/// Reads little-endian values at fixed offsets.
+struct Reader<'a>(&'a [u8]);
+
+macro_rules! read_primitive {
+    ($name:ident, $ty:ty) => {
+        fn $name(&self, offset: usize) -> anyhow::Result<$ty> {
+            const SIZE: usize = std::mem::size_of::<$ty>();
+            let bytes = self
+                .0
+                .get(offset..offset + SIZE)
+                .with_context(|| format!("unexpected end of LAS data at offset {offset}"))?;
+            Ok(<$ty>::from_le_bytes(bytes.try_into()?))
+        }
+    };
+}
+
+impl Reader<'_> {
+    read_primitive!(u8, u8);
+    read_primitive!(i8, i8);
+    read_primitive!(u16, u16);
+    read_primitive!(i16, i16);
+    read_primitive!(u32, u32);
+    read_primitive!(i32, i32);
+    read_primitive!(u64, u64);
+    read_primitive!(f64, f64);
+}
+
+/// Parses the contents of a `.las` file.
+///
+/// Positions are logged relative to the minimum corner of the bounding box of the points.
+pub fn parse(contents: &[u8]) -> anyhow::Result<PointCloud> {
+    re_tracing::profile_function!();
+
+    let header = Reader(contents);
+
+    anyhow::ensure!(
+        contents.starts_with(b"LASF"),
+        "not a LAS file: missing LASF signature"
+    );
+
+    let (version_major, version_minor) = (header.u8(24)?, header.u8(25)?);
+    let offset_to_points = header.u32(96)? as usize;
+    let format = header.u8(104)?;
+    let record_len = header.u16(105)? as usize;
+
+    // LAZ files flag their compressed records by setting the high bits of the format.
+    anyhow::ensure!(
+        format & 0x80 == 0,
+        "compressed LAZ files are not supported, decompress them to LAS first (e.g. with `laszip`)"
+    );
+    let format = format & 0x3f;
+    anyhow::ensure!(format <= 10, "unsupported LAS point data format: {format}");
+
+    let mut num_points = header.u32(107)? as usize;
+    if num_points == 0 && (version_major, version_minor) >= (1, 4) {
+        num_points = usize::try_from(header.u64(247)?)?;
+    }
+
+    let scale = [header.f64(131)?, header.f64(139)?, header.f64(147)?];
+    let offset = [header.f64(155)?, header.f64(163)?, header.f64(171)?];
+    let min = [header.f64(187)?, header.f64(203)?, header.f64(219)?];
+
+    let layout = Layout::new(format);
+    anyhow::ensure!(
+        record_len >= layout.min_record_len(),
+        "LAS point data format {format} needs records of at least {} bytes, got {record_len}",
+        layout.min_record_len()
+    );
+
+    let data = contents
+        .get(offset_to_points..)
+        .context("LAS file is truncated before its point data")?;
+    let num_available = data.len() / record_len;
+    if num_available < num_points {
+        re_log::warn!("LAS file is truncated: expected {num_points} points, found {num_available}");
+        num_points = num_available;
+    }
+
+    let mut positions = Vec::with_capacity(num_points);
+    let mut rgb = layout.rgb.map(|_| Vec::with_capacity(num_points));
+    let mut intensity = Vec::with_capacity(num_points);
+    let mut return_number = Vec::with_capacity(num_points);
+    let mut number_of_returns = Vec::with_capacity(num_points);
+    let mut classification = Vec::with_capacity(num_points);
+    let mut scan_angle = Vec::with_capacity(num_points);
+    let mut user_data = Vec::with_capacity(num_points);
+    let mut point_source_id = Vec::with_capacity(num_points);
+    let mut gps_time = layout.gps_time.map(|_| Vec::with_capacity(num_points));
+    let mut nir = layout.nir.map(|_| Vec::with_capacity(num_points));
+
+    for record in data.chunks_exact(record_len).take(num_points) {
+        let record = Reader(record);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let position = |axis: usize| -> anyhow::Result<f32> {
+            let value = f64::from(record.i32(axis * 4)?) * scale[axis] + offset[axis];
+            Ok((value - min[axis]) as f32)
+        };
+        positions.push([position(0)?, position(1)?, position(2)?]);
+
+        intensity.push(record.u16(12)?);
+
+        let returns = record.u8(14)?;
+        if layout.extended {
+            return_number.push(returns & 0x0f);
+            number_of_returns.push(returns >> 4);
+            classification.push(record.u8(16)?);
+            user_data.push(record.u8(17)?);
+            scan_angle.push(f32::from(record.i16(18)?) * 0.006);
+            point_source_id.push(record.u16(20)?);
+        } else {
+            return_number.push(returns & 0x07);
+            number_of_returns.push((returns >> 3) & 0x07);
+            classification.push(record.u8(15)? & 0x1f);
+            scan_angle.push(f32::from(record.i8(16)?));
+            user_data.push(record.u8(17)?);
+            point_source_id.push(record.u16(18)?);
+        }
+
+        if let (Some(offset), Some(gps_time)) = (layout.gps_time, &mut gps_time) {
+            gps_time.push(record.f64(offset)?);
+        }
+        if let (Some(offset), Some(rgb)) = (layout.rgb, &mut rgb) {
+            rgb.push([
+                record.u16(offset)?,
+                record.u16(offset + 2)?,
+                record.u16(offset + 4)?,
+            ]);
+        }
+        if let (Some(offset), Some(nir)) = (layout.nir, &mut nir) {
+            nir.push(record.u16(offset)?);
+        }
+    }
+
+    let mut fields = vec![
+        ("intensity".to_owned(), Column::U16(intensity)),
+        ("return_number".to_owned(), Column::U8(return_number)),
+        (
+            "number_of_returns".to_owned(),
+            Column::U8(number_of_returns),
+        ),
+        ("classification".to_owned(), Column::U8(classification)),
+        ("scan_angle".to_owned(), Column::F32(scan_angle)),
+        ("user_data".to_owned(), Column::U8(user_data)),
+        ("point_source_id".to_owned(), Column::U16(point_source_id)),
+    ];
+    if let Some(gps_time) = gps_time {
+        fields.push(("gps_time".to_owned(), Column::F64(gps_time)));
+    }
+    if let Some(nir) = nir {
+        fields.push(("nir".to_owned(), Column::U16(nir)));
+    }
+
+    Ok(PointCloud {
+        format: "las",
+        positions,
+        colors: rgb.map(rgb_colors),
+        fields,
+        origin: (min != [0.0; 3]).then_some(min),
+    })
+}
+
+/// Where the optional values are stored in a point record, for a given point data format.
+struct Layout {
+    /// Formats 6 and above use a different layout for the common values.
+    extended: bool,
+    gps_time: Option<usize>,
+    rgb: Option<usize>,
+    nir: Option<usize>,
+}
+
+impl Layout {
+    fn new(format: u8) -> Self {
+        match format {
+            0 => Self::legacy(None, None),
+            1 | 4 => Self::legacy(Some(20), None),
+            2 => Self::legacy(None, Some(20)),
+            3 | 5 => Self::legacy(Some(20), Some(28)),
+            6 | 9 => Self::extended(None, None),
+            7 => Self::extended(Some(30), None),
+            _ => Self::extended(Some(30), Some(36)),
+        }
+    }
+
+    fn legacy(gps_time: Option<usize>, rgb: Option<usize>) -> Self {
+        Self {
+            extended: false,
+            gps_time,
+            rgb,
+            nir: None,
+        }
+    }
+
+    fn extended(rgb: Option<usize>, nir: Option<usize>) -> Self {
+        Self {
+            extended: true,
+            gps_time: Some(22),
+            rgb,
+            nir,
+        }
+    }
+
+    /// The size of the values we read from each record.
+    fn min_record_len(&self) -> usize {
+        let mut len = if self.extended { 30 } else { 20 };
+        if let Some(gps_time) = self.gps_time {
+            len = len.max(gps_time + 8);
+        }
+        if let Some(rgb) = self.rgb {
+            len = len.max(rgb + 6);
+        }
+        if let Some(nir) = self.nir {
+            len = len.max(nir + 2);
+        }
+        len
+    }
+}
+
+/// LAS colors are 16-bit, but many writers store 8-bit values in them.
+#[allow(clippy::cast_possible_truncation)]
+fn rgb_colors(rgb: Vec<[u16; 3]>) -> Vec<Color> {
+    let is_16_bit = rgb.iter().flatten().any(|&value| value > 255);
+    let shift = if is_16_bit { 8 } else { 0 };
+
+    rgb.into_iter()
+        .map(|[r, g, b]| {
+            Color::from_rgb((r >> shift) as u8, (g >> shift) as u8, (b >> shift) as u8)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{EntityPath, TimePoint};
+
+    use super::*;
+
+    /// A LAS header, without any point.
+    fn header(version_minor: u8, format: u8, record_len: u16, num_points: u64) -> Vec<u8> {
+        let header_len: u16 = if version_minor >= 4 { 375 } else { 227 };
+        let mut header = vec![0; usize::from(header_len)];
+
+        let mut write = |offset: usize, bytes: &[u8]| {
+            header[offset..offset + bytes.len()].copy_from_slice(bytes);
+        };
+        write(0, b"LASF");
+        write(24, &[1, version_minor]);
+        write(94, &header_len.to_le_bytes());
+        write(96, &u32::from(header_len).to_le_bytes());
+        write(104, &[format]);
+        write(105, &record_len.to_le_bytes());
+        if version_minor >= 4 {
+            write(247, &num_points.to_le_bytes());
+        } else {
+            write(107, &u32::try_from(num_points).unwrap().to_le_bytes());
+        }
+        // Scale, offset and minimum corner.
+        for axis in 0..3 {
+            write(131 + axis * 8, &0.01_f64.to_le_bytes());
+            write(155 + axis * 8, &1000.0_f64.to_le_bytes());
+            write(187 + axis * 16, &1000.0_f64.to_le_bytes());
+        }
+
+        header
+    }
+
+    fn values(cloud: &PointCloud, name: &str) -> Vec<f64> {
+        let (_, column) = cloud
+            .fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .unwrap_or_else(|| panic!("missing field {name:?}"));
+        (0..column.len())
+            .map(|index| column.get_f64(index).unwrap())
+            .collect()
+    }
+
+    fn legacy_record(xyz: [i32; 3], intensity: u16, rgb: [u16; 3]) -> Vec<u8> {
+        let mut record = Vec::new();
+        for value in xyz {
+            record.extend(value.to_le_bytes());
+        }
+        record.extend(intensity.to_le_bytes());
+        record.push(2 | (3 << 3)); // return 2 of 3
+        record.push(0x80 | 6); // withheld flag, building
+        record.extend((-15_i8).to_le_bytes()); // scan angle
+        record.push(7); // user data
+        record.extend(42_u16.to_le_bytes()); // point source id
+        record.extend(123.5_f64.to_le_bytes()); // gps time
+        for value in rgb {
+            record.extend(value.to_le_bytes());
+        }
+        record
+    }
+
+    #[test]
+    fn legacy_format() {
+        let mut contents = header(2, 3, 34, 2);
+        contents.extend(legacy_record([0, 100, 250], 10, [255, 128, 0]));
+        contents.extend(legacy_record([-100, 0, 0], 20, [0, 0, 255]));
+
+        let cloud = parse(&contents).unwrap();
+
+        assert_eq!("las", cloud.format);
+        assert_eq!(Some([1000.0; 3]), cloud.origin);
+        assert_eq!(vec![[0.0, 1.0, 2.5], [-1.0, 0.0, 0.0]], cloud.positions);
+        // 8-bit colors stored in 16-bit values are used as-is.
+        assert_eq!(
+            Some(vec![
+                Color::from_rgb(255, 128, 0),
+                Color::from_rgb(0, 0, 255)
+            ]),
+            cloud.colors
+        );
+
+        assert_eq!(vec![10.0, 20.0], values(&cloud, "intensity"));
+        assert_eq!(vec![2.0; 2], values(&cloud, "return_number"));
+        assert_eq!(vec![3.0; 2], values(&cloud, "number_of_returns"));
+        assert_eq!(vec![6.0; 2], values(&cloud, "classification"));
+        assert_eq!(vec![-15.0; 2], values(&cloud, "scan_angle"));
+        assert_eq!(vec![7.0; 2], values(&cloud, "user_data"));
+        assert_eq!(vec![42.0; 2], values(&cloud, "point_source_id"));
+        assert_eq!(vec![123.5; 2], values(&cloud, "gps_time"));
+
+        let chunks = cloud
+            .into_chunks(&EntityPath::from("lidar"), &TimePoint::default())
+            .unwrap();
+        assert_eq!(2, chunks.len(), "origin transform, then points");
+        let components = chunks[1].components();
+        for name in [
+            "rerun.las.Intensity",
+            "rerun.las.GpsTime",
+            "rerun.las.PointSourceId",
+        ] {
+            assert!(components.contains_key(&name.into()), "missing {name}");
+        }
+    }
+
+    #[test]
+    fn extended_format() {
+        let mut contents = header(4, 6, 30, 1);
+        for value in [100_i32, 200, 300] {
+            contents.extend(value.to_le_bytes());
+        }
+        contents.extend(1000_u16.to_le_bytes());
+        contents.push(3 | (5 << 4)); // return 3 of 5
+        contents.push(0); // flags
+        contents.push(2); // ground
+        contents.push(9); // user data
+        contents.extend(500_i16.to_le_bytes()); // scan angle, in 0.006° increments
+        contents.extend(1_u16.to_le_bytes());
+        contents.extend(7.0_f64.to_le_bytes());
+
+        let cloud = parse(&contents).unwrap();
+
+        assert_eq!(vec![[1.0, 2.0, 3.0]], cloud.positions);
+        assert!(cloud.colors.is_none());
+        assert_eq!(vec![3.0], values(&cloud, "return_number"));
+        assert_eq!(vec![5.0], values(&cloud, "number_of_returns"));
+        assert_eq!(vec![2.0], values(&cloud, "classification"));
+        assert_eq!(vec![9.0], values(&cloud, "user_data"));
+        assert!((values(&cloud, "scan_angle")[0] - 3.0).abs() < 1e-5);
+        assert_eq!(vec![7.0], values(&cloud, "gps_time"));
+    }
+
+    #[test]
+    fn sixteen_bit_colors() {
+        let mut contents = header(2, 2, 26, 1);
+        let record = legacy_record([0; 3], 0, [0xFFFF, 0x8000, 0x0100]);
+        contents.extend(&record[..20]);
+        contents.extend(&record[28..]);
+
+        let cloud = parse(&contents).unwrap();
+        assert_eq!(Some(vec![Color::from_rgb(255, 128, 1)]), cloud.colors);
+        assert!(cloud.fields.iter().all(|(name, _)| name != "gps_time"));
+    }
+
+    #[test]
+    fn truncated() {
+        let mut contents = header(2, 0, 20, 3);
+        contents.extend(&legacy_record([0; 3], 0, [0; 3])[..20]);
+        contents.extend([0; 10]);
+
+        let cloud = parse(&contents).unwrap();
+        assert_eq!(1, cloud.positions.len());
+        assert_eq!(vec![0.0], values(&cloud, "intensity"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(parse(b"PK\x03\x04").is_err());
+        assert!(parse(&header(2, 0, 20, 0)[..100]).is_err());
+        assert!(parse(&header(2, 0x83, 34, 0)).is_err(), "LAZ");
+        assert!(parse(&header(2, 11, 34, 0)).is_err(), "unknown format");
+        assert!(parse(&header(2, 3, 20, 0)).is_err(), "records too short");
+    }
+}
@@ -0,0 +1,337 @@
+//! Point cloud formats, see [`crate::SUPPORTED_POINT_CLOUD_EXTENSIONS`].
+
+use arrow2::array::{Array as ArrowArray, PrimitiveArray as ArrowPrimitiveArray};
+
+use re_chunk::{Chunk, RowId};
+use re_log_types::{EntityPath, EntityPathPart, TimePoint};
+use re_types::{
+    archetypes::{Points3D, Transform3D},
+    components::Color,
+    AsComponents, ComponentName,
+};
+
+pub mod las;
+pub mod pcd;
+pub mod ply;
+
+// ---
+
+/// Point clouds with more points than this are split over several child entities
+/// (`part_0`, `part_1`, …), one chunk each.
+pub const MAX_POINTS_PER_CHUNK: usize = 256 * 1024;
+
+/// A point cloud, as read from a file.
+pub struct PointCloud {
+    /// The file format, used to namespace the extra fields, e.g. `las`.
+    pub format: &'static str,
+
+    pub positions: Vec<[f32; 3]>,
+
+    pub colors: Option<Vec<Color>>,
+
+    /// Any other per-point field (e.g. intensity), each logged as an extra component.
+    ///
+    /// See [`field_component_name`].
+    pub fields: Vec<(String, Column)>,
+
+    /// Translation to apply to all the positions.
+    ///
+    /// Georeferenced data is usually far away from the origin: storing positions relative to
+    /// a nearby origin keeps them precise in single precision.
+    pub origin: Option<[f64; 3]>,
+}
+
+/// The values of a per-point field.
+pub enum Column {
+    I8(Vec<i8>),
+    U8(Vec<u8>),
+    I16(Vec<i16>),
+    U16(Vec<u16>),
+    I32(Vec<i32>),
+    U32(Vec<u32>),
+    F32(Vec<f32>),
+    F64(Vec<f64>),
+}
+
+/// Runs `$body` with `$values` bound to the inner `Vec` of `$column`, whatever its type.
+macro_rules! with_values {
+    ($column:expr, $values:ident => $body:expr) => {
+        match $column {
+            Column::I8($values) => $body,
+            Column::U8($values) => $body,
+            Column::I16($values) => $body,
+            Column::U16($values) => $body,
+            Column::I32($values) => $body,
+            Column::U32($values) => $body,
+            Column::F32($values) => $body,
+            Column::F64($values) => $body,
+        }
+    };
+}
+
+impl Column {
+    #[inline]
+    pub fn len(&self) -> usize {
+        with_values!(self, values => values.len())
+    }
+
+    /// The value at `index`, converted to `f64`.
+    #[allow(trivial_numeric_casts, clippy::cast_lossless, clippy::unnecessary_cast)]
+    pub fn get_f64(&self, index: usize) -> Option<f64> {
+        with_values!(self, values => values.get(index).map(|&value| value as f64))
+    }
+
+    /// Appends `value`, converted to the type of this column.
+    #[allow(
+        trivial_numeric_casts,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::unnecessary_cast
+    )]
+    pub fn push_f64(&mut self, value: f64) {
+        match self {
+            Self::I8(values) => values.push(value as i8),
+            Self::U8(values) => values.push(value as u8),
+            Self::I16(values) => values.push(value as i16),
+            Self::U16(values) => values.push(value as u16),
+            Self::I32(values) => values.push(value as i32),
+            Self::U32(values) => values.push(value as u32),
+            Self::F32(values) => values.push(value as f32),
+            Self::F64(values) => values.push(value as f64),
+        }
+    }
+
+    fn retain(&mut self, keep: &[bool]) {
+        with_values!(self, values => {
+            let mut keep = keep.iter();
+            values.retain(|_| keep.next().copied().unwrap_or(false));
+        });
+    }
+
+    fn to_arrow(&self, range: std::ops::Range<usize>) -> Box<dyn ArrowArray> {
+        with_values!(self, values => ArrowPrimitiveArray::from_slice(&values[range]).boxed())
+    }
+}
+
+impl PointCloud {
+    /// Creates the chunks for this point cloud, splitting it over several child entities if it's
+    /// larger than [`MAX_POINTS_PER_CHUNK`].
+    ///
+    /// Points with non-finite positions are dropped.
+    /// If there are no colors, they are derived from the `intensity` field, if any.
+    pub fn into_chunks(
+        mut self,
+        entity_path: &EntityPath,
+        timepoint: &TimePoint,
+    ) -> anyhow::Result<Vec<Chunk>> {
+        re_tracing::profile_function!();
+
+        self.retain_finite();
+
+        if self.colors.is_none() {
+            self.colors = self.colors_from_intensity();
+        }
+
+        let mut chunks = Vec::new();
+
+        #[allow(clippy::cast_possible_truncation)]
+        if let Some(origin) = self.origin {
+            let transform = Transform3D::from_translation(origin.map(|v| v as f32));
+            chunks.push(
+                Chunk::builder(entity_path.clone())
+                    .with_archetype(RowId::new(), timepoint.clone(), &transform)
+                    .build()?,
+            );
+        }
+
+        chunks.extend(chunks_per_part(
+            entity_path,
+            timepoint,
+            self.positions.len(),
+            |range| {
+                let mut points = Points3D::new(self.positions[range.clone()].iter().copied());
+                if let Some(colors) = &self.colors {
+                    points = points.with_colors(colors[range.clone()].iter().copied());
+                }
+                component_arrays(&points, self.format, &self.fields, range)
+            },
+        )?);
+
+        Ok(chunks)
+    }
+
+    fn retain_finite(&mut self) {
+        let keep = self
+            .positions
+            .iter()
+            .map(|position| position.iter().all(|v| v.is_finite()))
+            .collect::<Vec<_>>();
+        if keep.iter().all(|&keep| keep) {
+            return;
+        }
+
+        let mut keep_iter = keep.iter();
+        self.positions
+            .retain(|_| keep_iter.next().copied().unwrap_or(false));
+        if let Some(colors) = &mut self.colors {
+            let mut keep_iter = keep.iter();
+            colors.retain(|_| keep_iter.next().copied().unwrap_or(false));
+        }
+        for (_, column) in &mut self.fields {
+            column.retain(&keep);
+        }
+    }
+
+    /// Grayscale colors, normalized by the maximum intensity.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn colors_from_intensity(&self) -> Option<Vec<Color>> {
+        let (_, intensity) = self.fields.iter().find(|(name, _)| name == "intensity")?;
+
+        let values = (0..intensity.len()).filter_map(|index| intensity.get_f64(index));
+        let max = values.clone().fold(0.0, f64::max);
+        if max <= 0.0 {
+            return None;
+        }
+
+        Some(
+            values
+                .map(|value| {
+                    let gray = (value / max * 255.0).clamp(0.0, 255.0) as u8;
+                    Color::from_rgb(gray, gray, gray)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The name of the component an extra per-point field is logged as, e.g. `rerun.las.Intensity`
+/// for the `intensity` field of a `.las` file.
+///
+/// Namespacing the fields per format keeps them from clashing with Rerun's own components, or
+/// from being mistaken for indicator components.
+pub fn field_component_name(format: &str, field: &str) -> ComponentName {
+    let name = field
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+                .collect::<String>()
+        })
+        .collect::<String>();
+    let name = if name.is_empty() { field } else { &name };
+    format!("rerun.{format}.{name}").into()
+}
+
+/// The components of `archetype`, followed by the values of `fields` within `range`.
+fn component_arrays(
+    archetype: &dyn AsComponents,
+    format: &str,
+    fields: &[(String, Column)],
+    range: std::ops::Range<usize>,
+) -> anyhow::Result<Vec<(ComponentName, Box<dyn ArrowArray>)>> {
+    let mut components = archetype
+        .as_component_batches()
+        .iter()
+        .map(|batch| Ok((batch.name(), batch.to_arrow()?)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    components.extend(fields.iter().map(|(name, column)| {
+        (
+            field_component_name(format, name),
+            column.to_arrow(range.clone()),
+        )
+    }));
+    Ok(components)
+}
+
+/// Logs `num_points` points as a single row, split over several child entities if there are more
+/// than [`MAX_POINTS_PER_CHUNK`] of them.
+///
+/// `components` returns the components of the points within the given range.
+fn chunks_per_part(
+    entity_path: &EntityPath,
+    timepoint: &TimePoint,
+    num_points: usize,
+    mut components: impl FnMut(
+        std::ops::Range<usize>,
+    ) -> anyhow::Result<Vec<(ComponentName, Box<dyn ArrowArray>)>>,
+) -> anyhow::Result<Vec<Chunk>> {
+    let num_parts = num_points.div_ceil(MAX_POINTS_PER_CHUNK).max(1);
+
+    (0..num_parts)
+        .map(|part| {
+            let range = part * MAX_POINTS_PER_CHUNK
+                ..usize::min(num_points, (part + 1) * MAX_POINTS_PER_CHUNK);
+
+            let entity_path = if num_parts == 1 {
+                entity_path.clone()
+            } else {
+                entity_path.join(&EntityPath::new(vec![EntityPathPart::new(format!(
+                    "part_{part}"
+                ))]))
+            };
+
+            Ok(Chunk::builder(entity_path)
+                .with_row(RowId::new(), timepoint.clone(), components(range)?)
+                .build()?)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_component_names() {
+        assert_eq!(
+            ComponentName::from("rerun.las.Intensity"),
+            field_component_name("las", "intensity")
+        );
+        assert_eq!(
+            ComponentName::from("rerun.las.GpsTime"),
+            field_component_name("las", "gps_time")
+        );
+        assert_eq!(
+            ComponentName::from("rerun.pcd.NormalX"),
+            field_component_name("pcd", "normal_x")
+        );
+        assert_eq!(
+            ComponentName::from("rerun.ply.ScalarIntensity"),
+            field_component_name("ply", "scalar-Intensity")
+        );
+
+        // Never mistaken for an indicator.
+        assert!(!field_component_name("ply", "indicator").is_indicator_component());
+    }
+
+    #[test]
+    fn colors_from_intensity() {
+        let cloud = PointCloud {
+            format: "test",
+            positions: vec![[0.0; 3], [f32::NAN, 0.0, 0.0], [1.0; 3]],
+            colors: None,
+            fields: vec![("intensity".to_owned(), Column::U16(vec![100, 50, 200]))],
+            origin: None,
+        };
+
+        let chunks = cloud
+            .into_chunks(&EntityPath::from("points"), &TimePoint::default())
+            .unwrap();
+        assert_eq!(1, chunks.len());
+
+        let colors = &chunks[0].components()[&"rerun.components.Color".into()];
+        let colors = <Color as re_types::Loggable>::from_arrow(&*colors.value(0)).unwrap();
+        assert_eq!(
+            vec![
+                Color::from_rgb(127, 127, 127),
+                Color::from_rgb(255, 255, 255)
+            ],
+            colors
+        );
+    }
+}
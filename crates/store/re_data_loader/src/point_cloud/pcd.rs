@@ -0,0 +1,663 @@
+//! [PCD](https://pointclouds.org/documentation/tutorials/pcd_file_format.html) files, as written
+//! by the Point Cloud Library.
+//!
+//! All three data encodings are supported: `ascii`, `binary` and `binary_compressed`.
+
+use anyhow::Context as _;
+
+use re_types::components::Color;
+
+use super::{Column, PointCloud};
+
+// ---
+
+/// Field names that are consumed as positions or colors, rather than logged as-is.
+const SPECIAL_FIELDS: &[&str] = &["x", "y", "z", "rgb", "rgba"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DataEncoding {
+    Ascii,
+    Binary,
+    BinaryCompressed,
+}
+
+struct Field {
+    name: String,
+
+    /// Size of a single value, in bytes.
+    size: usize,
+
+    /// `I`, `U` or `F`.
+    ty: char,
+
+    /// Number of values per point.
+    count: usize,
+}
+
+impl Field {
+    /// Size of the field for a single point, in bytes.
+    fn stride(&self) -> usize {
+        self.size * self.count
+    }
+
+    /// An empty column that can hold the values of this field.
+    fn new_column(&self, capacity: usize) -> Option<Column> {
+        Some(match (self.ty, self.size) {
+            ('I', 1) => Column::I8(Vec::with_capacity(capacity)),
+            ('U', 1) => Column::U8(Vec::with_capacity(capacity)),
+            ('I', 2) => Column::I16(Vec::with_capacity(capacity)),
+            ('U', 2) => Column::U16(Vec::with_capacity(capacity)),
+            ('I', 4) => Column::I32(Vec::with_capacity(capacity)),
+            ('U', 4) => Column::U32(Vec::with_capacity(capacity)),
+            ('F', 4) => Column::F32(Vec::with_capacity(capacity)),
+            ('F', 8) => Column::F64(Vec::with_capacity(capacity)),
+            _ => return None,
+        })
+    }
+}
+
+struct Header {
+    fields: Vec<Field>,
+    num_points: usize,
+    encoding: DataEncoding,
+}
+
+/// Parses the contents of a `.pcd` file.
+pub fn parse(contents: &[u8]) -> anyhow::Result<PointCloud> {
+    re_tracing::profile_function!();
+
+    let (header, data) = parse_header(contents)?;
+
+    let mut columns = header
+        .fields
+        .iter()
+        .map(|field| {
+            if field.count != 1 || field.name == "_" {
+                // Padding, or a multi-valued field (e.g. a feature histogram): we can't map
+                // those to a single component.
+                return None;
+            }
+            let column = field.new_column(header.num_points);
+            if column.is_none() {
+                re_log::warn_once!(
+                    "Ignoring PCD field {:?} of unsupported type {}{}",
+                    field.name,
+                    field.ty,
+                    field.size
+                );
+            }
+            column
+        })
+        .collect::<Vec<_>>();
+
+    match header.encoding {
+        DataEncoding::Ascii => read_ascii(&header, data, &mut columns)?,
+        DataEncoding::Binary => read_binary(&header, data, &mut columns)?,
+        DataEncoding::BinaryCompressed => {
+            let data = decompress(data)?;
+            read_binary_compressed(&header, &data, &mut columns)?;
+        }
+    }
+
+    let mut columns = header
+        .fields
+        .iter()
+        .zip(columns)
+        .filter_map(|(field, column)| Some((field.name.clone(), column?)))
+        .collect::<Vec<_>>();
+
+    // Truncated files hold fewer points than announced.
+    let num_points = columns
+        .iter()
+        .map(|(_, column)| column.len())
+        .min()
+        .unwrap_or_default();
+
+    let position_column = |name: &str| {
+        columns
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, column)| column)
+            .with_context(|| format!("PCD file has no {name:?} field"))
+    };
+    #[allow(clippy::cast_possible_truncation)]
+    let positions = {
+        let (x, y, z) = (
+            position_column("x")?,
+            position_column("y")?,
+            position_column("z")?,
+        );
+        (0..num_points)
+            .map(|i| {
+                let value = |column: &Column| column.get_f64(i).unwrap_or(f64::NAN) as f32;
+                [value(x), value(y), value(z)]
+            })
+            .collect()
+    };
+
+    let colors = columns
+        .iter()
+        .find(|(name, _)| name == "rgb" || name == "rgba")
+        .map(|(name, column)| packed_colors(column, name == "rgba"))
+        .transpose()?;
+
+    columns.retain(|(name, _)| !SPECIAL_FIELDS.contains(&name.as_str()));
+
+    Ok(PointCloud {
+        format: "pcd",
+        positions,
+        colors,
+        fields: columns,
+        origin: None,
+    })
+}
+
+fn parse_header(contents: &[u8]) -> anyhow::Result<(Header, &[u8])> {
+    let mut names = Vec::new();
+    let mut sizes = Vec::new();
+    let mut types = Vec::new();
+    let mut counts = Vec::new();
+    let mut width = None;
+    let mut height = 1;
+    let mut num_points = None;
+
+    let mut rest = contents;
+    let encoding = loop {
+        let (line, remainder) = match rest.iter().position(|&b| b == b'\n') {
+            Some(end) => (&rest[..end], &rest[end + 1..]),
+            None => (rest, &rest[rest.len()..]),
+        };
+        rest = remainder;
+
+        anyhow::ensure!(
+            !line.is_empty() || !rest.is_empty(),
+            "PCD header is missing its DATA entry"
+        );
+
+        let line = std::str::from_utf8(line).context("PCD header is not valid text")?;
+        let mut tokens = line.split_whitespace();
+        let Some(keyword) = tokens.next() else {
+            continue;
+        };
+        let values = tokens.collect::<Vec<_>>();
+
+        let parse_all = |values: &[&str]| -> anyhow::Result<Vec<usize>> {
+            values
+                .iter()
+                .map(|value| {
+                    value
+                        .parse()
+                        .with_context(|| format!("invalid {keyword} in PCD header: {value:?}"))
+                })
+                .collect()
+        };
+        let parse_one = |values: &[&str]| -> anyhow::Result<usize> {
+            parse_all(values)?
+                .first()
+                .copied()
+                .with_context(|| format!("missing {keyword} in PCD header"))
+        };
+
+        match keyword.to_uppercase().as_str() {
+            _ if keyword.starts_with('#') => {}
+            "FIELDS" => names = values.iter().map(|name| (*name).to_owned()).collect(),
+            "SIZE" => sizes = parse_all(&values)?,
+            "TYPE" => {
+                types = values
+                    .iter()
+                    .map(|ty| ty.chars().next().unwrap_or_default().to_ascii_uppercase())
+                    .collect();
+            }
+            "COUNT" => counts = parse_all(&values)?,
+            "WIDTH" => width = Some(parse_one(&values)?),
+            "HEIGHT" => height = parse_one(&values)?,
+            "POINTS" => num_points = Some(parse_one(&values)?),
+            "DATA" => {
+                break match values
+                    .first()
+                    .map(|encoding| encoding.to_lowercase())
+                    .as_deref()
+                {
+                    Some("ascii") => DataEncoding::Ascii,
+                    Some("binary") => DataEncoding::Binary,
+                    Some("binary_compressed") => DataEncoding::BinaryCompressed,
+                    encoding => anyhow::bail!("unsupported PCD data encoding: {encoding:?}"),
+                };
+            }
+            // `VERSION`, `VIEWPOINT`…
+            _ => {}
+        }
+    };
+
+    anyhow::ensure!(!names.is_empty(), "PCD header has no FIELDS");
+    if counts.is_empty() {
+        counts = vec![1; names.len()];
+    }
+    anyhow::ensure!(
+        sizes.len() == names.len() && types.len() == names.len() && counts.len() == names.len(),
+        "PCD header has mismatched FIELDS, SIZE, TYPE and COUNT entries"
+    );
+
+    let num_points = num_points
+        .or_else(|| width.map(|width| width * height))
+        .context("PCD header has neither POINTS nor WIDTH")?;
+
+    let fields = itertools::izip!(names, sizes, types, counts)
+        .map(|(name, size, ty, count)| Field {
+            name,
+            size,
+            ty,
+            count,
+        })
+        .collect();
+
+    Ok((
+        Header {
+            fields,
+            num_points,
+            encoding,
+        },
+        rest,
+    ))
+}
+
+fn read_ascii(header: &Header, data: &[u8], columns: &mut [Option<Column>]) -> anyhow::Result<()> {
+    let data = std::str::from_utf8(data).context("PCD ascii data is not valid text")?;
+
+    let lines = data
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .take(header.num_points);
+
+    for line in lines {
+        let mut tokens = line.split_whitespace();
+        for (field, column) in header.fields.iter().zip(columns.iter_mut()) {
+            let Some(column) = column else {
+                // Skip all the values of this field.
+                tokens.by_ref().take(field.count).for_each(drop);
+                continue;
+            };
+
+            let token = tokens.next().unwrap_or("nan");
+            push_ascii(column, token)
+                .with_context(|| format!("invalid value for PCD field {:?}", field.name))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn push_ascii(column: &mut Column, token: &str) -> anyhow::Result<()> {
+    match column {
+        Column::I8(values) => values.push(token.parse()?),
+        Column::U8(values) => values.push(token.parse()?),
+        Column::I16(values) => values.push(token.parse()?),
+        Column::U16(values) => values.push(token.parse()?),
+        Column::I32(values) => values.push(token.parse()?),
+        // Packed colors are sometimes written as floats, reinterpret their bits.
+        Column::U32(values) => values.push(match token.parse() {
+            Ok(value) => value,
+            Err(_) => token.parse::<f32>()?.to_bits(),
+        }),
+        Column::F32(values) => values.push(token.parse()?),
+        Column::F64(values) => values.push(token.parse()?),
+    }
+    Ok(())
+}
+
+fn push_le_bytes(column: &mut Column, bytes: &[u8]) {
+    macro_rules! push {
+        ($values:expr, $ty:ty) => {{
+            let mut array = [0; std::mem::size_of::<$ty>()];
+            array.copy_from_slice(bytes);
+            $values.push(<$ty>::from_le_bytes(array));
+        }};
+    }
+
+    match column {
+        Column::I8(values) => push!(values, i8),
+        Column::U8(values) => push!(values, u8),
+        Column::I16(values) => push!(values, i16),
+        Column::U16(values) => push!(values, u16),
+        Column::I32(values) => push!(values, i32),
+        Column::U32(values) => push!(values, u32),
+        Column::F32(values) => push!(values, f32),
+        Column::F64(values) => push!(values, f64),
+    }
+}
+
+/// Binary data is laid out point after point.
+fn read_binary(header: &Header, data: &[u8], columns: &mut [Option<Column>]) -> anyhow::Result<()> {
+    let point_size = header.fields.iter().map(Field::stride).sum::<usize>();
+    anyhow::ensure!(point_size > 0, "PCD points have a size of zero");
+
+    let num_points = data.len() / point_size;
+    if num_points < header.num_points {
+        re_log::warn!(
+            "PCD file is truncated: expected {} points, found {num_points}",
+            header.num_points
+        );
+    }
+
+    for point in data.chunks_exact(point_size).take(header.num_points) {
+        let mut offset = 0;
+        for (field, column) in header.fields.iter().zip(columns.iter_mut()) {
+            if let Some(column) = column {
+                push_le_bytes(column, &point[offset..offset + field.size]);
+            }
+            offset += field.stride();
+        }
+    }
+
+    Ok(())
+}
+
+/// Compressed binary data is laid out field after field.
+fn read_binary_compressed(
+    header: &Header,
+    data: &[u8],
+    columns: &mut [Option<Column>],
+) -> anyhow::Result<()> {
+    let mut offset = 0;
+    for (field, column) in header.fields.iter().zip(columns.iter_mut()) {
+        let len = field.stride() * header.num_points;
+        let values = data
+            .get(offset..offset + len)
+            .with_context(|| format!("PCD data is too short for field {:?}", field.name))?;
+        offset += len;
+
+        if let Some(column) = column {
+            for value in values.chunks_exact(field.stride()) {
+                push_le_bytes(column, &value[..field.size]);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Compressed data starts with its compressed and uncompressed sizes, followed by LZF data.
+fn decompress(data: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let size = |offset: usize| -> anyhow::Result<usize> {
+        let bytes = data
+            .get(offset..offset + 4)
+            .context("PCD compressed data is missing its header")?;
+        Ok(u32::from_le_bytes(bytes.try_into()?) as usize)
+    };
+    let compressed_size = size(0)?;
+    let uncompressed_size = size(4)?;
+
+    let compressed = data
+        .get(8..8 + compressed_size)
+        .context("PCD compressed data is truncated")?;
+
+    let decompressed = lzf_decompress(compressed)?;
+    anyhow::ensure!(
+        decompressed.len() == uncompressed_size,
+        "PCD compressed data has the wrong size: expected {uncompressed_size} bytes, got {}",
+        decompressed.len()
+    );
+
+    Ok(decompressed)
+}
+
+/// Decompresses [LZF](http://oldhome.schmorp.de/marc/liblzf.html) data.
+fn lzf_decompress(input: &[u8]) -> anyhow::Result<Vec<u8>> {
+    const ERR_TRUNCATED: &str = "LZF data is truncated";
+
+    let mut output = Vec::with_capacity(input.len() * 2);
+    let mut pos = 0;
+
+    while let Some(&ctrl) = input.get(pos) {
+        pos += 1;
+        let ctrl = ctrl as usize;
+
+        if ctrl < 1 << 5 {
+            // Literal run.
+            let len = ctrl + 1;
+            let literal = input.get(pos..pos + len).context(ERR_TRUNCATED)?;
+            output.extend_from_slice(literal);
+            pos += len;
+        } else {
+            // Back reference.
+            let mut len = ctrl >> 5;
+            if len == 7 {
+                len += *input.get(pos).context(ERR_TRUNCATED)? as usize;
+                pos += 1;
+            }
+            len += 2;
+
+            let low = *input.get(pos).context(ERR_TRUNCATED)? as usize;
+            pos += 1;
+            let distance = ((ctrl & 0x1f) << 8) + low + 1;
+
+            let start = output
+                .len()
+                .checked_sub(distance)
+                .context("LZF back reference points before the start of the data")?;
+            // The source and destination may overlap, so copy byte per byte.
+            for i in start..start + len {
+                output.push(output[i]);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Colors are packed in a single 32-bit value, as `0x00RRGGBB` (or `0xAARRGGBB` for `rgba`),
+/// and usually stored in a float field.
+fn packed_colors(column: &Column, has_alpha: bool) -> anyhow::Result<Vec<Color>> {
+    #[allow(clippy::cast_sign_loss)]
+    let packed: Vec<u32> = match column {
+        Column::F32(values) => values.iter().map(|value| value.to_bits()).collect(),
+        Column::U32(values) => values.clone(),
+        Column::I32(values) => values.iter().map(|&value| value as u32).collect(),
+        _ => anyhow::bail!("unsupported type for PCD color field"),
+    };
+
+    Ok(packed
+        .into_iter()
+        .map(|rgba| {
+            let [b, g, r, a] = rgba.to_le_bytes();
+            if has_alpha {
+                Color::from_unmultiplied_rgba(r, g, b, a)
+            } else {
+                Color::from_rgb(r, g, b)
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{EntityPath, TimePoint};
+    use re_types::Loggable as _;
+
+    use super::*;
+
+    fn values(cloud: &PointCloud, name: &str) -> Vec<f64> {
+        let (_, column) = cloud
+            .fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .unwrap_or_else(|| panic!("missing field {name:?}"));
+        (0..column.len())
+            .map(|index| column.get_f64(index).unwrap())
+            .collect()
+    }
+
+    fn field_names(cloud: &PointCloud) -> Vec<&str> {
+        cloud.fields.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    #[test]
+    fn ascii() {
+        let contents = b"# .PCD v0.7 - Point Cloud Data file format
+VERSION 0.7
+FIELDS x y z rgb intensity
+SIZE 4 4 4 4 2
+TYPE F F F U U
+COUNT 1 1 1 1 1
+WIDTH 3
+HEIGHT 1
+VIEWPOINT 0 0 0 1 0 0 0
+POINTS 3
+DATA ascii
+1 2 3 16711680 10
+4.5 5.5 6.5 65280 20
+
+nan 0 0 255 30
+";
+        let cloud = parse(contents).unwrap();
+
+        assert_eq!("pcd", cloud.format);
+        assert_eq!([1.0, 2.0, 3.0], cloud.positions[0]);
+        assert_eq!([4.5, 5.5, 6.5], cloud.positions[1]);
+        assert!(cloud.positions[2][0].is_nan());
+        assert_eq!(
+            Some(vec![
+                Color::from_rgb(255, 0, 0),
+                Color::from_rgb(0, 255, 0),
+                Color::from_rgb(0, 0, 255),
+            ]),
+            cloud.colors
+        );
+        assert_eq!(vec!["intensity"], field_names(&cloud));
+        assert_eq!(vec![10.0, 20.0, 30.0], values(&cloud, "intensity"));
+
+        // Non-finite points are dropped, extra fields are namespaced.
+        let chunks = cloud
+            .into_chunks(&EntityPath::from("cloud"), &TimePoint::default())
+            .unwrap();
+        assert_eq!(1, chunks.len());
+        let components = chunks[0].components();
+        assert_eq!(
+            2,
+            components[&re_types::components::Position3D::name()]
+                .value(0)
+                .len()
+        );
+        assert!(components.contains_key(&"rerun.pcd.Intensity".into()));
+        assert!(!components.contains_key(&"intensity".into()));
+    }
+
+    /// Packed colors written as floats, as PCL does.
+    #[test]
+    fn ascii_float_colors() {
+        let rgb = f32::from_bits(0x00_12_34_56);
+        let contents = format!(
+            "FIELDS x y z rgb\nSIZE 4 4 4 4\nTYPE F F F F\nWIDTH 1\nDATA ascii\n0 0 0 {rgb:e}\n"
+        );
+        let cloud = parse(contents.as_bytes()).unwrap();
+        assert_eq!(Some(vec![Color::from_rgb(0x12, 0x34, 0x56)]), cloud.colors);
+    }
+
+    fn binary_points() -> Vec<u8> {
+        let mut data = Vec::new();
+        for (curvature, [x, y, z]) in [(0.0_f64, [1.0_f32, 2.0, 3.0]), (1.0, [-1.0, -2.0, -3.0])] {
+            for value in [x, y, z] {
+                data.extend(value.to_le_bytes());
+            }
+            data.extend(0x80_40_20_10_u32.to_le_bytes()); // rgba
+            data.extend([0xAA; 3]); // padding
+            data.extend(curvature.to_le_bytes());
+            data.extend([1_u8, 2]); // histogram
+        }
+        data
+    }
+
+    const BINARY_HEADER: &str = "VERSION .7
+FIELDS x y z rgba _ curvature histogram
+SIZE 4 4 4 4 1 8 1
+TYPE F F F U U F U
+COUNT 1 1 1 1 3 1 2
+WIDTH 2
+HEIGHT 1
+POINTS 2
+";
+
+    #[test]
+    fn binary() {
+        let mut contents = format!("{BINARY_HEADER}DATA binary\n").into_bytes();
+        contents.extend(binary_points());
+
+        let cloud = parse(&contents).unwrap();
+
+        assert_eq!(vec![[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]], cloud.positions);
+        assert_eq!(
+            Some(vec![
+                Color::from_unmultiplied_rgba(0x40, 0x20, 0x10, 0x80);
+                2
+            ]),
+            cloud.colors
+        );
+        // Padding and multi-valued fields are skipped.
+        assert_eq!(vec!["curvature"], field_names(&cloud));
+        assert_eq!(vec![0.0, 1.0], values(&cloud, "curvature"));
+    }
+
+    #[test]
+    fn binary_truncated() {
+        let mut contents = format!("{BINARY_HEADER}DATA binary\n").into_bytes();
+        let points = binary_points();
+        contents.extend(&points[..points.len() - 1]);
+
+        let cloud = parse(&contents).unwrap();
+        assert_eq!(vec![[1.0, 2.0, 3.0]], cloud.positions);
+        assert_eq!(vec![0.0], values(&cloud, "curvature"));
+    }
+
+    /// LZF data made of literal runs only.
+    fn lzf_literals(data: &[u8]) -> Vec<u8> {
+        let mut compressed = Vec::new();
+        for run in data.chunks(32) {
+            compressed.push(u8::try_from(run.len() - 1).unwrap());
+            compressed.extend(run);
+        }
+        compressed
+    }
+
+    #[test]
+    fn binary_compressed() {
+        // Field after field.
+        let mut data = Vec::new();
+        for value in [1.0_f32, 4.0, 2.0, 5.0, 3.0, 6.0] {
+            data.extend(value.to_le_bytes());
+        }
+        for value in [7_u16, 8] {
+            data.extend(value.to_le_bytes());
+        }
+        let compressed = lzf_literals(&data);
+
+        let mut contents =
+            b"FIELDS x y z intensity\nSIZE 4 4 4 2\nTYPE F F F U\nPOINTS 2\nDATA binary_compressed\n"
+                .to_vec();
+        contents.extend(u32::try_from(compressed.len()).unwrap().to_le_bytes());
+        contents.extend(u32::try_from(data.len()).unwrap().to_le_bytes());
+        contents.extend(compressed);
+
+        let cloud = parse(&contents).unwrap();
+        assert_eq!(vec![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], cloud.positions);
+        assert_eq!(vec![7.0, 8.0], values(&cloud, "intensity"));
+    }
+
+    #[test]
+    fn lzf_back_reference() {
+        // "abc", then 6 bytes copied from 3 bytes back.
+        let compressed = [2, b'a', b'b', b'c', 4 << 5, 2];
+        assert_eq!(b"abcabcabc".to_vec(), lzf_decompress(&compressed).unwrap());
+
+        assert!(lzf_decompress(&[5, b'a']).is_err());
+        assert!(lzf_decompress(&[1 << 5, 10]).is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(parse(b"FIELDS x y z\nSIZE 4 4 4\nTYPE F F F\nWIDTH 1\n").is_err());
+        assert!(parse(b"FIELDS x y\nSIZE 4 4\nTYPE F F\nWIDTH 1\nDATA ascii\n0 0\n").is_err());
+        assert!(parse(b"FIELDS x y z\nSIZE 4 4\nTYPE F F F\nWIDTH 1\nDATA ascii\n").is_err());
+        assert!(parse(b"FIELDS x y z\nSIZE 4 4 4\nTYPE F F F\nWIDTH 1\nDATA lzma\n").is_err());
+    }
+}
@@ -0,0 +1,573 @@
+//! [PLY](https://paulbourke.net/dataformats/ply/) files.
+//!
+//! Files with a `face` element are loaded as a [`Mesh3D`], others as [`Points3D`], or as
+//! [`Points2D`] if their vertices have no `z` property.
+//! Vertex properties that don't map to any component of these archetypes are logged as extra
+//! components, e.g. `rerun.ply.Confidence` for a `confidence` property.
+
+use anyhow::Context as _;
+use ply_rs::ply::{DefaultElement, Property};
+
+use re_chunk::{Chunk, RowId};
+use re_log_types::{EntityPath, TimePoint};
+use re_types::{
+    archetypes::{Mesh3D, Points2D, Points3D},
+    components::{Color, Radius, Text},
+};
+
+use super::{chunks_per_part, component_arrays, Column};
+
+// ---
+
+/// Namespace of the extra vertex components, see [`super::field_component_name`].
+const FORMAT: &str = "ply";
+
+/// Property names of the texture coordinates, in order of preference.
+///
+/// There is no standard for these, so we accept the most common ones.
+const TEXCOORD_PROPERTIES: &[[&str; 2]] = &[
+    ["u", "v"],
+    ["s", "t"],
+    ["texture_u", "texture_v"],
+    ["texture_s", "texture_t"],
+];
+
+/// Property names of the vertex indices of a face.
+const FACE_PROPERTIES: &[&str] = &["vertex_indices", "vertex_index"];
+
+/// Loads the contents of a `.ply` file.
+pub fn load(
+    contents: &[u8],
+    entity_path: &EntityPath,
+    timepoint: &TimePoint,
+) -> anyhow::Result<Vec<Chunk>> {
+    re_tracing::profile_function!();
+
+    let ply = {
+        re_tracing::profile_scope!("read_ply");
+        let parser = ply_rs::parser::Parser::<DefaultElement>::new();
+        parser.read_ply(&mut std::io::Cursor::new(contents))?
+    };
+
+    let mut vertices = None;
+    let mut faces = None;
+    for (key, elements) in ply.payload {
+        match key.as_str() {
+            "vertex" => vertices = Some(elements),
+            "face" => faces = Some(elements),
+            _ => re_log::warn!("Ignoring {key:?} in .ply file"),
+        }
+    }
+
+    let vertices = Vertices::new(&vertices.context(".ply file has no vertex element")?);
+
+    match faces {
+        Some(faces) => {
+            let triangles = triangles(&faces, vertices.len)?;
+            vertices.into_mesh(&triangles, entity_path, timepoint)
+        }
+        None => vertices.into_points(entity_path, timepoint),
+    }
+}
+
+/// The scalar properties of all vertices, stored column by column.
+struct Vertices {
+    len: usize,
+
+    /// In the order they are declared in the file.
+    columns: Vec<(String, Column)>,
+
+    labels: Option<Vec<String>>,
+}
+
+impl Vertices {
+    fn new(elements: &[DefaultElement]) -> Self {
+        re_tracing::profile_function!();
+
+        let mut columns = Vec::new();
+        let mut has_labels = false;
+        if let Some(first) = elements.first() {
+            for (name, property) in first {
+                if let Some(column) = new_column(property, elements.len()) {
+                    columns.push((name.clone(), column));
+                } else if name == "label" && matches!(property, Property::ListUChar(_)) {
+                    has_labels = true;
+                } else {
+                    re_log::warn!("Ignoring list property {name:?} of .ply vertices");
+                }
+            }
+        }
+
+        let mut labels = has_labels.then(|| Vec::with_capacity(elements.len()));
+
+        for element in elements {
+            for (name, column) in &mut columns {
+                column.push_f64(element.get(name).and_then(scalar).unwrap_or(f64::NAN));
+            }
+            if let Some(labels) = &mut labels {
+                let label = match element.get("label") {
+                    Some(Property::ListUChar(chars)) => String::from_utf8_lossy(chars).into_owned(),
+                    _ => String::new(),
+                };
+                labels.push(label);
+            }
+        }
+
+        Self {
+            len: elements.len(),
+            columns,
+            labels,
+        }
+    }
+
+    /// Removes the column of the given property, if any.
+    fn take(&mut self, name: &str) -> Option<Column> {
+        let index = self.columns.iter().position(|(n, _)| n == name)?;
+        Some(self.columns.remove(index).1)
+    }
+
+    /// Removes the columns of all the given properties, if they are all present.
+    fn take_all<const N: usize>(&mut self, names: [&str; N]) -> Option<[Column; N]> {
+        if !names
+            .iter()
+            .all(|name| self.columns.iter().any(|(n, _)| n == name))
+        {
+            return None;
+        }
+        Some(names.map(|name| self.take(name).expect("checked above")))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn take_f32<const N: usize>(&mut self, names: [&str; N]) -> Option<Vec<[f32; N]>> {
+        let columns = self.take_all(names)?;
+        Some(
+            (0..self.len)
+                .map(|i| {
+                    std::array::from_fn(|axis| columns[axis].get_f64(i).unwrap_or(f64::NAN) as f32)
+                })
+                .collect(),
+        )
+    }
+
+    fn take_colors(&mut self) -> Option<Vec<Color>> {
+        let [r, g, b] = self.take_all(["red", "green", "blue"])?;
+        let a = self.take("alpha");
+        Some(
+            (0..self.len)
+                .map(|i| {
+                    let a = a.as_ref().map_or(255, |a| color_channel(a, i));
+                    Color::from_unmultiplied_rgba(
+                        color_channel(&r, i),
+                        color_channel(&g, i),
+                        color_channel(&b, i),
+                        a,
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn into_points(
+        mut self,
+        entity_path: &EntityPath,
+        timepoint: &TimePoint,
+    ) -> anyhow::Result<Vec<Chunk>> {
+        let colors = self.take_colors();
+        let radii = self.take_f32(["radius"]).map(|radii| {
+            radii
+                .into_iter()
+                .map(|[r]| Radius::from(r))
+                .collect::<Vec<_>>()
+        });
+        let labels = self.labels.take().map(|labels| {
+            labels
+                .into_iter()
+                .map(|label| Text(label.into()))
+                .collect::<Vec<_>>()
+        });
+
+        if let Some(positions) = self.take_f32(["x", "y", "z"]) {
+            chunks_per_part(entity_path, timepoint, self.len, |range| {
+                let mut points = Points3D::new(positions[range.clone()].iter().copied());
+                if let Some(colors) = &colors {
+                    points = points.with_colors(colors[range.clone()].iter().copied());
+                }
+                if let Some(radii) = &radii {
+                    points = points.with_radii(radii[range.clone()].iter().copied());
+                }
+                if let Some(labels) = &labels {
+                    points = points.with_labels(labels[range.clone()].iter().cloned());
+                }
+                component_arrays(&points, FORMAT, &self.columns, range)
+            })
+        } else {
+            let positions = self
+                .take_f32(["x", "y"])
+                .context(".ply vertices have no x & y properties")?;
+            chunks_per_part(entity_path, timepoint, self.len, |range| {
+                let mut points = Points2D::new(positions[range.clone()].iter().copied());
+                if let Some(colors) = &colors {
+                    points = points.with_colors(colors[range.clone()].iter().copied());
+                }
+                if let Some(radii) = &radii {
+                    points = points.with_radii(radii[range.clone()].iter().copied());
+                }
+                if let Some(labels) = &labels {
+                    points = points.with_labels(labels[range.clone()].iter().cloned());
+                }
+                component_arrays(&points, FORMAT, &self.columns, range)
+            })
+        }
+    }
+
+    /// Meshes are always logged as a single chunk, since faces can refer to any vertex.
+    fn into_mesh(
+        mut self,
+        triangles: &[[u32; 3]],
+        entity_path: &EntityPath,
+        timepoint: &TimePoint,
+    ) -> anyhow::Result<Vec<Chunk>> {
+        let positions = match self.take_f32(["x", "y", "z"]) {
+            Some(positions) => positions,
+            None => self
+                .take_f32(["x", "y"])
+                .context(".ply vertices have no x, y & z properties")?
+                .into_iter()
+                .map(|[x, y]| [x, y, 0.0])
+                .collect(),
+        };
+
+        let mut mesh = Mesh3D::new(positions).with_triangle_indices(triangles.iter().copied());
+        if let Some(normals) = self.take_f32(["nx", "ny", "nz"]) {
+            mesh = mesh.with_vertex_normals(normals);
+        }
+        if let Some(colors) = self.take_colors() {
+            mesh = mesh.with_vertex_colors(colors);
+        }
+        if let Some(texcoords) = TEXCOORD_PROPERTIES
+            .iter()
+            .find_map(|&names| self.take_f32(names))
+        {
+            mesh = mesh.with_vertex_texcoords(texcoords);
+        }
+        if self.labels.is_some() {
+            re_log::warn!("Ignoring labels of .ply mesh vertices");
+        }
+
+        mesh.sanity_check()?;
+
+        let components = component_arrays(&mesh, FORMAT, &self.columns, 0..self.len)?;
+        Ok(vec![Chunk::builder(entity_path.clone())
+            .with_row(RowId::new(), timepoint.clone(), components)
+            .build()?])
+    }
+}
+
+/// Triangulates the faces, assuming they are convex polygons.
+fn triangles(faces: &[DefaultElement], num_vertices: usize) -> anyhow::Result<Vec<[u32; 3]>> {
+    re_tracing::profile_function!();
+
+    let mut triangles = Vec::with_capacity(faces.len());
+
+    for face in faces {
+        let indices = FACE_PROPERTIES
+            .iter()
+            .find_map(|name| face.get(*name))
+            .and_then(indices)
+            .context(".ply faces have no vertex_indices property")?;
+
+        if let Some(&index) = indices.iter().find(|&&i| i as usize >= num_vertices) {
+            anyhow::bail!(".ply face refers to vertex {index}, but there are only {num_vertices}");
+        }
+
+        if let Some((&first, rest)) = indices.split_first() {
+            triangles.extend(rest.windows(2).map(|pair| [first, pair[0], pair[1]]));
+        }
+    }
+
+    Ok(triangles)
+}
+
+// ---
+
+/// An empty column that can hold the values of `property`, if it's a scalar.
+fn new_column(property: &Property, capacity: usize) -> Option<Column> {
+    Some(match property {
+        Property::Char(_) => Column::I8(Vec::with_capacity(capacity)),
+        Property::UChar(_) => Column::U8(Vec::with_capacity(capacity)),
+        Property::Short(_) => Column::I16(Vec::with_capacity(capacity)),
+        Property::UShort(_) => Column::U16(Vec::with_capacity(capacity)),
+        Property::Int(_) => Column::I32(Vec::with_capacity(capacity)),
+        Property::UInt(_) => Column::U32(Vec::with_capacity(capacity)),
+        Property::Float(_) => Column::F32(Vec::with_capacity(capacity)),
+        Property::Double(_) => Column::F64(Vec::with_capacity(capacity)),
+        Property::ListChar(_)
+        | Property::ListUChar(_)
+        | Property::ListShort(_)
+        | Property::ListUShort(_)
+        | Property::ListInt(_)
+        | Property::ListUInt(_)
+        | Property::ListFloat(_)
+        | Property::ListDouble(_) => return None,
+    })
+}
+
+fn scalar(property: &Property) -> Option<f64> {
+    match *property {
+        Property::Char(v) => Some(v.into()),
+        Property::UChar(v) => Some(v.into()),
+        Property::Short(v) => Some(v.into()),
+        Property::UShort(v) => Some(v.into()),
+        Property::Int(v) => Some(v.into()),
+        Property::UInt(v) => Some(v.into()),
+        Property::Float(v) => Some(v.into()),
+        Property::Double(v) => Some(v),
+        Property::ListChar(_)
+        | Property::ListUChar(_)
+        | Property::ListShort(_)
+        | Property::ListUShort(_)
+        | Property::ListInt(_)
+        | Property::ListUInt(_)
+        | Property::ListFloat(_)
+        | Property::ListDouble(_) => None,
+    }
+}
+
+#[allow(clippy::cast_sign_loss)]
+fn indices(property: &Property) -> Option<Vec<u32>> {
+    match property {
+        Property::ListChar(v) => Some(v.iter().map(|&i| i as u32).collect()),
+        Property::ListUChar(v) => Some(v.iter().map(|&i| i.into()).collect()),
+        Property::ListShort(v) => Some(v.iter().map(|&i| i as u32).collect()),
+        Property::ListUShort(v) => Some(v.iter().map(|&i| i.into()).collect()),
+        Property::ListInt(v) => Some(v.iter().map(|&i| i as u32).collect()),
+        Property::ListUInt(v) => Some(v.clone()),
+        Property::ListFloat(_)
+        | Property::ListDouble(_)
+        | Property::Char(_)
+        | Property::UChar(_)
+        | Property::Short(_)
+        | Property::UShort(_)
+        | Property::Int(_)
+        | Property::UInt(_)
+        | Property::Float(_)
+        | Property::Double(_) => None,
+    }
+}
+
+/// Floating point colors are in the `[0, 1]` range, integer ones in `[0, 255]`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn color_channel(column: &Column, index: usize) -> u8 {
+    let value = column.get_f64(index).unwrap_or_default();
+    let value = match column {
+        Column::F32(_) | Column::F64(_) => value * 255.0,
+        _ => value,
+    };
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow2::array::{Array as ArrowArray, PrimitiveArray as ArrowPrimitiveArray};
+    use re_types::{
+        components::{Position2D, Position3D, TriangleIndices},
+        Component, ComponentName, Loggable as _,
+    };
+
+    use super::*;
+
+    fn load_chunk(ply: &str) -> Chunk {
+        let mut chunks = load(
+            ply.as_bytes(),
+            &EntityPath::from("ply"),
+            &TimePoint::default(),
+        )
+        .unwrap();
+        assert_eq!(1, chunks.len());
+        chunks.remove(0)
+    }
+
+    fn cell(chunk: &Chunk, component_name: impl Into<ComponentName>) -> Box<dyn ArrowArray> {
+        let component_name = component_name.into();
+        chunk
+            .components()
+            .get(&component_name)
+            .unwrap_or_else(|| panic!("missing {component_name}"))
+            .value(0)
+    }
+
+    fn component<C: Component>(chunk: &Chunk) -> Vec<C> {
+        C::from_arrow(&*cell(chunk, C::name())).unwrap()
+    }
+
+    #[test]
+    fn points_2d() {
+        let chunk = load_chunk(
+            "ply
+format ascii 1.0
+element vertex 2
+property float x
+property float y
+property uchar red
+property uchar green
+property uchar blue
+property float confidence
+end_header
+1 2 255 0 0 0.5
+3 4 0 255 0 1
+",
+        );
+
+        assert!(!chunk.components().contains_key(&Position3D::name()));
+        assert_eq!(
+            vec![Position2D::new(1.0, 2.0), Position2D::new(3.0, 4.0)],
+            component::<Position2D>(&chunk)
+        );
+        assert_eq!(
+            vec![Color::from_rgb(255, 0, 0), Color::from_rgb(0, 255, 0)],
+            component::<Color>(&chunk)
+        );
+
+        let confidence = cell(&chunk, "rerun.ply.Confidence");
+        let confidence = confidence
+            .as_any()
+            .downcast_ref::<ArrowPrimitiveArray<f32>>()
+            .unwrap();
+        assert_eq!(&[0.5, 1.0], confidence.values().as_slice());
+    }
+
+    #[test]
+    fn points_3d() {
+        let chunk = load_chunk(
+            "ply
+format ascii 1.0
+comment extra properties keep their type
+element vertex 2
+property double x
+property double y
+property double z
+property float radius
+property ushort intensity
+property list uchar uchar label
+end_header
+1 2 3 0.5 100 2 104 105
+4 5 6 0.25 200 0
+",
+        );
+
+        assert_eq!(
+            vec![
+                Position3D::new(1.0, 2.0, 3.0),
+                Position3D::new(4.0, 5.0, 6.0)
+            ],
+            component::<Position3D>(&chunk)
+        );
+        assert_eq!(
+            vec![Radius::from(0.5), Radius::from(0.25)],
+            component::<Radius>(&chunk)
+        );
+        assert_eq!(
+            vec![Text("hi".into()), Text(String::new().into())],
+            component::<Text>(&chunk)
+        );
+
+        let intensity = cell(&chunk, "rerun.ply.Intensity");
+        let intensity = intensity
+            .as_any()
+            .downcast_ref::<ArrowPrimitiveArray<u16>>()
+            .unwrap();
+        assert_eq!(&[100, 200], intensity.values().as_slice());
+
+        // Properties consumed by the archetype aren't logged twice.
+        assert!(!chunk.components().contains_key(&"rerun.ply.Radius".into()));
+        assert!(!chunk.components().contains_key(&"rerun.ply.X".into()));
+    }
+
+    #[test]
+    fn mesh() {
+        let chunk = load_chunk(
+            "ply
+format ascii 1.0
+element vertex 6
+property float x
+property float y
+property float z
+property float u
+property float v
+property float quality
+element face 3
+property list uchar int vertex_indices
+end_header
+0 0 0 0 0 1
+1 0 0 1 0 1
+1 1 0 1 1 1
+0 1 0 0 1 1
+0.5 2 0 0.5 1 1
+0 0 1 0 0 1
+3 0 1 5
+4 0 1 2 3
+5 0 1 2 4 3
+",
+        );
+
+        // Faces are fan-triangulated around their first vertex.
+        assert_eq!(
+            vec![
+                [0, 1, 5],
+                [0, 1, 2],
+                [0, 2, 3],
+                [0, 1, 2],
+                [0, 2, 4],
+                [0, 4, 3],
+            ],
+            component::<TriangleIndices>(&chunk)
+                .into_iter()
+                .map(|indices| indices.0 .0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(6, component::<Position3D>(&chunk).len());
+        assert!(chunk
+            .components()
+            .contains_key(&"rerun.components.Texcoord2D".into()));
+        assert!(chunk.components().contains_key(&"rerun.ply.Quality".into()));
+    }
+
+    #[test]
+    fn invalid_faces() {
+        let ply = "ply
+format ascii 1.0
+element vertex 3
+property float x
+property float y
+property float z
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0
+1 0 0
+0 1 0
+3 0 1 3
+";
+        let err = load(
+            ply.as_bytes(),
+            &EntityPath::from("ply"),
+            &TimePoint::default(),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("refers to vertex 3"), "{err}");
+
+        let ply = "ply
+format ascii 1.0
+element face 0
+property list uchar int vertex_indices
+end_header
+";
+        assert!(load(
+            ply.as_bytes(),
+            &EntityPath::from("ply"),
+            &TimePoint::default()
+        )
+        .is_err());
+    }
+}
@@ -2,10 +2,16 @@ use re_log_types::LogMsg;
 use re_smart_channel::Receiver;
 
 /// `on_msg` can be used to wake up the UI thread on Wasm.
+///
+/// If the url holds an auth token (e.g. `ws://localhost:9877/?token=…`), it is presented to the
+/// server, but kept out of the logs and of the source of the returned channel.
 pub fn connect_to_ws_url(
     url: &str,
     on_msg: Option<Box<dyn Fn() + Send + Sync>>,
 ) -> anyhow::Result<Receiver<LogMsg>> {
+    let (url, auth_token) = re_ws_comms::split_auth_token(url);
+    let url = url.as_str();
+
     let (tx, rx) = re_smart_channel::smart_channel(
         re_smart_channel::SmartMessageSource::WsClient {
             ws_server_url: url.to_owned(),
@@ -38,6 +44,10 @@ pub fn connect_to_ws_url(
         }
     };
 
-    re_ws_comms::viewer_to_server(url.to_owned(), callback)?;
+    let options = re_ws_comms::ClientOptions {
+        auth_token,
+        ..Default::default()
+    };
+    re_ws_comms::viewer_to_server(url.to_owned(), &options, callback)?;
     Ok(rx)
 }
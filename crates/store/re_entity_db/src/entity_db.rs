@@ -5,8 +5,8 @@ use parking_lot::Mutex;
 
 use re_chunk::{Chunk, ChunkResult, RowId};
 use re_chunk_store::{
-    ChunkStore, ChunkStoreConfig, ChunkStoreEvent, ChunkStoreSubscriber, GarbageCollectionOptions,
-    GarbageCollectionTarget,
+    ChunkSource, ChunkStore, ChunkStoreConfig, ChunkStoreEvent, ChunkStoreSubscriber,
+    GarbageCollectionOptions, GarbageCollectionTarget,
 };
 use re_log_types::{
     ApplicationId, ComponentPath, EntityPath, EntityPathHash, LogMsg, ResolvedTimeRange,
@@ -102,6 +102,13 @@ impl EntityDb {
         &self.data_store
     }
 
+    /// Only keep the metadata of the chunks that `chunk_source` can read back in memory.
+    ///
+    /// Must be set before adding the chunks, see [`ChunkStore::set_chunk_source`].
+    pub fn set_chunk_source(&mut self, chunk_source: Arc<dyn ChunkSource>) {
+        self.data_store.set_chunk_source(chunk_source);
+    }
+
     pub fn store_info_msg(&self) -> Option<&SetStoreInfo> {
         self.set_store_info.as_ref()
     }
@@ -290,7 +297,9 @@ impl EntityDb {
 
     #[inline]
     pub fn num_rows(&self) -> u64 {
+        // Rows that aren't in memory are still part of the recording.
         self.data_store.stats().total().total_num_rows
+            + self.data_store.evicted_chunks_stats().total_num_rows
     }
 
     /// Return the current `ChunkStoreGeneration`. This can be used to determine whether the
@@ -363,7 +372,7 @@ impl EntityDb {
                 self.add_chunk(&Arc::new(chunk))?;
             }
 
-            LogMsg::BlueprintActivationCommand(_) => {
+            LogMsg::BlueprintActivationCommand(_) | LogMsg::ViewerCommand(_, _) => {
                 // Not for us to handle
             }
         }
@@ -429,8 +438,15 @@ impl EntityDb {
     pub fn gc(&mut self, gc_options: &GarbageCollectionOptions) {
         re_tracing::profile_function!();
 
+        let num_evicted_chunks_before = self.data_store.evicted_chunks_stats().num_chunks;
         let (store_events, stats_diff) = self.data_store.gc(gc_options);
 
+        // The query caches hold on to the chunks that were just evicted to disk: let them go,
+        // otherwise no memory would be freed.
+        if self.data_store.evicted_chunks_stats().num_chunks != num_evicted_chunks_before {
+            self.query_caches.clear();
+        }
+
         re_log::trace!(
             num_row_ids_dropped = store_events.len(),
             size_bytes_dropped = re_format::format_bytes(stats_diff.total().total_size_bytes as _),
@@ -492,7 +508,7 @@ impl EntityDb {
 
         let data_messages = self
             .store()
-            .iter_chunks()
+            .iter_chunks_including_evicted()
             .filter(|chunk| {
                 let Some((timeline, time_range)) = time_filter else {
                     return true;
@@ -565,8 +581,8 @@ impl EntityDb {
             });
         }
 
-        for chunk in self.store().iter_chunks() {
-            new_db.add_chunk(&Arc::clone(chunk))?;
+        for chunk in self.store().iter_chunks_including_evicted() {
+            new_db.add_chunk(&chunk)?;
         }
 
         Ok(new_db)
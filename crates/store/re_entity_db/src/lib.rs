@@ -7,6 +7,7 @@
 pub mod entity_db;
 pub mod entity_tree;
 mod instance_path;
+pub mod rrd_chunk_source;
 mod store_bundle;
 mod time_histogram_per_timeline;
 mod times_per_timeline;
@@ -16,6 +17,7 @@ pub use self::{
     entity_db::EntityDb,
     entity_tree::EntityTree,
     instance_path::{InstancePath, InstancePathHash},
+    rrd_chunk_source::RrdChunkSource,
     store_bundle::{StoreBundle, StoreLoadError},
     time_histogram_per_timeline::{TimeHistogram, TimeHistogramPerTimeline},
     times_per_timeline::{TimeCounts, TimesPerTimeline},
@@ -0,0 +1,301 @@
+//! Loading large `.rrd` files lazily, see [`stream_rrd_lazily`].
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use parking_lot::{Mutex, RwLock};
+
+use re_chunk::external::arrow2::array::{new_empty_array, PrimitiveArray as ArrowPrimitiveArray};
+use re_chunk::external::arrow2::datatypes::DataType as ArrowDataType;
+use re_chunk::{Chunk, ChunkError, ChunkId, ChunkResult, ChunkTimeline};
+use re_chunk_store::{ChunkSource, ChunkStoreError, ChunkStoreResult};
+use re_log_encoding::decoder::{DecodeError, Decoder, VersionPolicy};
+use re_log_encoding::{RrdIndex, RrdIndexEntry};
+use re_log_types::LogMsg;
+use re_smart_channel::{Receiver, Sender, SmartChannelSource, SmartMessageSource};
+
+/// `.rrd` files at least this large are loaded lazily by the viewer, see [`should_load_lazily`].
+pub const LAZY_LOADING_MIN_FILE_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// Is this an `.rrd` file that is worth loading lazily, see [`stream_rrd_lazily`]?
+pub fn should_load_lazily(path: &Path) -> bool {
+    let is_rrd = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("rrd"));
+    is_rrd
+        && std::fs::metadata(path)
+            .is_ok_and(|metadata| LAZY_LOADING_MIN_FILE_SIZE <= metadata.len())
+}
+
+/// Streams all messages of the `.rrd` file at `path`, while recording where each chunk is in
+/// the file.
+///
+/// Hand the returned source to the [`crate::EntityDb`]s of the recordings in the file (see
+/// [`crate::EntityDb::set_chunk_source`]): they will then only keep the metadata of the chunks
+/// in memory, i.e. enough for the entity tree and the time histograms, and read the rest back
+/// from the file as queries need it.
+///
+/// If the file has a footer index (see [`Decoder::read_index`]), its temporal chunks aren't
+/// decoded at all: placeholders built from the index are streamed in their stead, see
+/// [`ChunkSource`]. Otherwise the whole file is decoded once.
+pub fn stream_rrd_lazily(
+    path: &Path,
+) -> Result<(Arc<RrdChunkSource>, Receiver<LogMsg>), DecodeError> {
+    re_tracing::profile_function!(path.display().to_string());
+
+    let source = Arc::new(RrdChunkSource::open(path)?);
+
+    let file = File::open(path).map_err(DecodeError::Read)?;
+    let decoder = Decoder::new(VersionPolicy::Warn, BufReader::new(file))?;
+
+    let (tx, rx) = re_smart_channel::smart_channel(
+        SmartMessageSource::File(path.to_owned()),
+        SmartChannelSource::File(path.to_owned()),
+    );
+
+    // NOTE: This is IO bound, it must run on a dedicated thread, not the shared rayon thread pool.
+    std::thread::Builder::new()
+        .name(format!("stream_rrd_lazily({path:?})"))
+        .spawn({
+            let source = Arc::clone(&source);
+            move || source.index_and_stream(decoder, &tx)
+        })
+        .map_err(DecodeError::Read)?;
+
+    Ok((source, rx))
+}
+
+/// A [`ChunkSource`] that reads chunks back from the `.rrd` file they were loaded from.
+///
+/// See [`stream_rrd_lazily`].
+pub struct RrdChunkSource {
+    path: PathBuf,
+
+    /// Where each chunk is in the file.
+    entries: RwLock<ahash::HashMap<ChunkId, RrdIndexEntry>>,
+
+    /// Used for reading chunks back, independently of the streaming.
+    decoder: Mutex<Decoder<BufReader<File>>>,
+}
+
+impl std::fmt::Debug for RrdChunkSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RrdChunkSource")
+            .field("path", &self.path)
+            .field("num_chunks", &self.entries.read().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl RrdChunkSource {
+    fn open(path: &Path) -> Result<Self, DecodeError> {
+        let file = File::open(path).map_err(DecodeError::Read)?;
+        let decoder = Decoder::new(VersionPolicy::Warn, BufReader::new(file))?;
+
+        Ok(Self {
+            path: path.to_owned(),
+            entries: Default::default(),
+            decoder: Mutex::new(decoder),
+        })
+    }
+
+    /// The file the chunks are read back from.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn index_and_stream(&self, mut decoder: Decoder<BufReader<File>>, tx: &Sender<LogMsg>) {
+        re_tracing::profile_function!(self.path.display().to_string());
+
+        let index = decoder.read_index().unwrap_or_else(|err| {
+            re_log::warn_once!("Failed to read the index of {:?}: {err}", self.path);
+            None
+        });
+
+        // Indices written by older versions don't describe the rows of the chunks.
+        match index.filter(|index| index.entries.iter().all(|entry| entry.rows.is_some())) {
+            Some(index) => self.stream_from_index(decoder, index, tx),
+            None => self.scan_and_stream(decoder, tx),
+        }
+
+        tx.quit(None).ok();
+    }
+
+    /// Streams the temporal chunks of the file as placeholders built from its `index`, and
+    /// everything else as is.
+    fn stream_from_index(
+        &self,
+        mut decoder: Decoder<BufReader<File>>,
+        index: RrdIndex,
+        tx: &Sender<LogMsg>,
+    ) {
+        re_tracing::profile_function!(format!("{} chunks", index.entries.len()));
+
+        self.entries.write().extend(
+            index
+                .entries
+                .iter()
+                .map(|entry| (entry.chunk_id, entry.clone())),
+        );
+
+        let temporal_entries: ahash::HashMap<u64, RrdIndexEntry> = index
+            .entries
+            .into_iter()
+            .filter(|entry| !entry.is_static())
+            .map(|entry| (entry.byte_offset, entry))
+            .collect();
+
+        loop {
+            let byte_offset = decoder.num_bytes_read();
+
+            let msg = if let Some(entry) = temporal_entries.get(&byte_offset) {
+                if let Err(err) = decoder.seek_to(byte_offset + entry.byte_len) {
+                    re_log::warn_once!("Failed to read {:?}: {err}", self.path);
+                    return;
+                }
+
+                let arrow_msg =
+                    match placeholder_chunk(entry).and_then(|chunk| chunk.to_arrow_msg()) {
+                        Ok(arrow_msg) => arrow_msg,
+                        Err(err) => {
+                            re_log::warn_once!(
+                                "Bad index entry for chunk {} in {:?}: {err}",
+                                entry.chunk_id,
+                                self.path
+                            );
+                            continue;
+                        }
+                    };
+
+                LogMsg::ArrowMsg(entry.store_id.clone(), arrow_msg)
+            } else {
+                match decoder.next() {
+                    Some(Ok(msg)) => msg,
+                    Some(Err(err)) => {
+                        re_log::warn_once!("Failed to decode message in {:?}: {err}", self.path);
+                        continue;
+                    }
+                    None => return,
+                }
+            };
+
+            if tx.send(msg).is_err() {
+                return; // The other end has decided to hang up, not our problem.
+            }
+        }
+    }
+
+    /// Decodes and streams every message of the file, indexing its chunks along the way.
+    fn scan_and_stream(&self, mut decoder: Decoder<BufReader<File>>, tx: &Sender<LogMsg>) {
+        re_tracing::profile_function!();
+
+        loop {
+            let byte_offset = decoder.num_bytes_read();
+            let msg = match decoder.next() {
+                Some(Ok(msg)) => msg,
+                Some(Err(err)) => {
+                    re_log::warn_once!("Failed to decode message in {:?}: {err}", self.path);
+                    continue;
+                }
+                None => return,
+            };
+
+            if let LogMsg::ArrowMsg(store_id, arrow_msg) = &msg {
+                // Chunks that can't be decoded won't make it into the store anyway.
+                if let Ok(chunk) = Chunk::from_arrow_msg(arrow_msg) {
+                    let byte_len = decoder.num_bytes_read() - byte_offset;
+                    let entry = RrdIndexEntry::new(byte_offset, byte_len, store_id.clone(), &chunk);
+                    self.entries.write().insert(chunk.id(), entry);
+                }
+            }
+
+            if tx.send(msg).is_err() {
+                return; // The other end has decided to hang up, not our problem.
+            }
+        }
+    }
+}
+
+/// A chunk with the same id and rows as the one described by `entry`, but none of its data.
+///
+/// Each component column is a list of empty [`ArrowDataType::Null`] arrays, which keeps track
+/// of which rows have data for it.
+fn placeholder_chunk(entry: &RrdIndexEntry) -> ChunkResult<Chunk> {
+    let Some(rows) = &entry.rows else {
+        return Err(ChunkError::Malformed {
+            reason: "the index doesn't describe the rows of the chunk".to_owned(),
+        });
+    };
+
+    let timelines = rows
+        .times
+        .iter()
+        .map(|(timeline, times)| {
+            let times = ArrowPrimitiveArray::<i64>::from_vec(times.clone());
+            (*timeline, ChunkTimeline::new(None, *timeline, times))
+        })
+        .collect();
+
+    let empty = new_empty_array(ArrowDataType::Null);
+    let components = rows
+        .components
+        .iter()
+        .filter_map(|(component_name, is_valid)| {
+            let arrays = is_valid
+                .iter()
+                .map(|is_valid| is_valid.then_some(empty.as_ref()))
+                .collect::<Vec<_>>();
+            let list_array = re_chunk::util::arrays_to_list_array(ArrowDataType::Null, &arrays)?;
+            Some((*component_name, list_array))
+        })
+        .collect();
+
+    Chunk::from_native_row_ids(
+        entry.chunk_id,
+        entry.entity_path.clone(),
+        None,
+        &rows.row_ids,
+        timelines,
+        components,
+    )
+}
+
+impl ChunkSource for RrdChunkSource {
+    fn contains(&self, chunk_id: &ChunkId) -> bool {
+        self.entries.read().contains_key(chunk_id)
+    }
+
+    fn load(&self, chunk_id: &ChunkId) -> ChunkStoreResult<Chunk> {
+        re_tracing::profile_function!();
+
+        let err = |err: Box<dyn std::error::Error + Send + Sync>| ChunkStoreError::ChunkSource {
+            chunk_id: *chunk_id,
+            err,
+        };
+
+        let entry = self
+            .entries
+            .read()
+            .get(chunk_id)
+            .cloned()
+            .ok_or_else(|| err(format!("not in {:?}", self.path).into()))?;
+
+        let msg = self
+            .decoder
+            .lock()
+            .read_at(&entry)
+            .map_err(|decode_err| err(decode_err.into()))?;
+        let LogMsg::ArrowMsg(_, arrow_msg) = msg else {
+            return Err(err(format!("no chunk at byte {}", entry.byte_offset).into()));
+        };
+
+        // Same as when it was first inserted, see `EntityDb::add`.
+        let mut chunk = Chunk::from_arrow_msg(&arrow_msg)?;
+        chunk.sort_if_unsorted();
+
+        Ok(chunk)
+    }
+}
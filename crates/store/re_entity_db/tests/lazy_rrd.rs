@@ -0,0 +1,138 @@
+// https://github.com/rust-lang/rust-clippy/issues/10011
+#![cfg(test)]
+
+use re_chunk::{Chunk, LatestAtQuery, RowId};
+use re_entity_db::{rrd_chunk_source::stream_rrd_lazily, EntityDb};
+use re_log_encoding::{
+    decoder::{Decoder, VersionPolicy},
+    encoder::Encoder,
+    EncodingOptions,
+};
+use re_log_types::{
+    build_frame_nr, example_components::MyPoint, ApplicationId, EntityPath, LogMsg, SetStoreInfo,
+    StoreId, StoreInfo, StoreKind, StoreSource, Time, TimeInt, Timeline,
+};
+use re_smart_channel::SmartMessagePayload;
+use re_types_core::Loggable as _;
+
+// ---
+
+#[test]
+fn lazy_rrd() -> anyhow::Result<()> {
+    lazy_rrd_impl(false)
+}
+
+#[test]
+fn lazy_rrd_indexed() -> anyhow::Result<()> {
+    lazy_rrd_impl(true)
+}
+
+fn lazy_rrd_impl(with_index: bool) -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let store_id = StoreId::random(StoreKind::Recording);
+    let num_frames = 10;
+
+    let mut messages = vec![LogMsg::SetStoreInfo(SetStoreInfo {
+        row_id: *RowId::new(),
+        info: StoreInfo {
+            application_id: ApplicationId("test".to_owned()),
+            store_id: store_id.clone(),
+            cloned_from: None,
+            is_official_example: false,
+            started: Time::now(),
+            store_source: StoreSource::Unknown,
+            store_version: None,
+        },
+    })];
+    for frame_nr in 0..num_frames {
+        let chunk = Chunk::builder("points".into())
+            .with_component_batch(
+                RowId::new(),
+                [build_frame_nr(frame_nr)],
+                &[MyPoint::new(frame_nr as f32, frame_nr as f32)],
+            )
+            .build()?;
+        messages.push(LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg()?));
+    }
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("lazy.rrd");
+    if with_index {
+        let mut encoder = Encoder::new(
+            re_build_info::CrateVersion::LOCAL,
+            EncodingOptions::COMPRESSED,
+            std::fs::File::create(&path)?,
+        )?
+        .with_index();
+        for msg in &messages {
+            encoder.append(msg)?;
+        }
+        encoder.finish()?;
+
+        // Corrupt a chunk that no query below touches: with an index, it is never decoded.
+        let mut bytes = std::fs::read(&path)?;
+        let index = Decoder::new(VersionPolicy::Error, std::io::Cursor::new(&bytes))?
+            .read_index()?
+            .unwrap();
+        let entry = &index.entries[7];
+        let start = (entry.byte_offset + entry.byte_len / 2) as usize;
+        let end = (entry.byte_offset + entry.byte_len) as usize;
+        bytes[start..end].fill(0xff);
+        std::fs::write(&path, bytes)?;
+    } else {
+        re_log_encoding::encoder::encode(
+            re_build_info::CrateVersion::LOCAL,
+            EncodingOptions::COMPRESSED,
+            messages.iter(),
+            &mut std::fs::File::create(&path)?,
+        )?;
+    }
+
+    let (source, rx) = stream_rrd_lazily(&path)?;
+
+    let mut db = EntityDb::new(store_id.clone());
+    db.set_chunk_source(source);
+    while let Ok(msg) = rx.recv() {
+        match msg.payload {
+            SmartMessagePayload::Msg(msg) => db.add(&msg)?,
+            SmartMessagePayload::Flush { .. } => {}
+            SmartMessagePayload::Quit(err) => {
+                assert!(err.is_none());
+                break;
+            }
+        }
+    }
+
+    // Only the metadata is in memory…
+    let store = db.store();
+    assert_eq!(0, store.stats().temporal_chunks.num_chunks);
+    assert_eq!(num_frames as u64, store.evicted_chunks_stats().num_chunks);
+    assert_eq!(num_frames as u64, db.num_rows());
+
+    // …which is enough for the entity tree and the time histograms…
+    let timeline_frame_nr = Timeline::new_sequence("frame_nr");
+    assert_eq!(vec![&EntityPath::from("points")], db.entity_paths());
+    assert_eq!(
+        Some(num_frames as u64),
+        db.time_histogram(&timeline_frame_nr)
+            .map(|histogram| histogram.total_count())
+    );
+
+    // …while the data itself is read back from the file.
+    let query = LatestAtQuery::new(timeline_frame_nr, 4);
+    let chunks = store.latest_at_relevant_chunks(&query, &"points".into(), MyPoint::name());
+    assert_eq!(1, chunks.len());
+    let (data_time, _row_id, array) = chunks[0]
+        .latest_at(&query, MyPoint::name())
+        .iter_rows(&timeline_frame_nr, &MyPoint::name())
+        .next()
+        .unwrap();
+    assert_eq!(TimeInt::new_temporal(4), data_time);
+    assert_eq!(
+        vec![MyPoint::new(4.0, 4.0)],
+        MyPoint::from_arrow(&*array.unwrap())?
+    );
+
+    Ok(())
+}
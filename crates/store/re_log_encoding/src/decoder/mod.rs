@@ -5,6 +5,7 @@ pub mod stream;
 use re_build_info::CrateVersion;
 use re_log_types::LogMsg;
 
+use crate::index::{RrdIndex, RrdIndexEntry, RRD_INDEX_MAGIC, RRD_INDEX_TRAILER_SIZE};
 use crate::FileHeader;
 use crate::MessageHeader;
 use crate::OLD_RRD_HEADERS;
@@ -84,6 +85,9 @@ pub enum DecodeError {
     #[error("lz4 error: {0}")]
     Lz4(lz4_flex::block::DecompressError),
 
+    #[error("zstd error: {0}")]
+    Zstd(std::io::Error),
+
     #[error("MsgPack error: {0}")]
     MsgPack(#[from] rmp_serde::decode::Error),
 }
@@ -132,12 +136,35 @@ pub fn read_options(
     Ok((CrateVersion::from_bytes(version), options))
 }
 
+/// Decompresses a single zstd frame from `compressed`, filling all of `uncompressed`.
+///
+/// We use a pure Rust decoder here so that zstd-compressed recordings can be loaded on the web too.
+pub(crate) fn decompress_zstd(
+    compressed: &[u8],
+    uncompressed: &mut [u8],
+) -> Result<(), DecodeError> {
+    use std::io::Read as _;
+
+    re_tracing::profile_function!();
+
+    let mut decoder = ruzstd::StreamingDecoder::new(compressed).map_err(|err| {
+        DecodeError::Zstd(std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    })?;
+    decoder.read_exact(uncompressed).map_err(DecodeError::Zstd)
+}
+
 pub struct Decoder<R: std::io::Read> {
     version: CrateVersion,
     compression: Compression,
     read: R,
     uncompressed: Vec<u8>, // scratch space
     compressed: Vec<u8>,   // scratch space
+
+    /// Set once we've reached the end-of-stream marker, see [`crate::index`].
+    end_of_stream: bool,
+
+    /// How far into the stream we are, see [`Self::num_bytes_read`].
+    num_bytes_read: u64,
 }
 
 impl<R: std::io::Read> Decoder<R> {
@@ -156,6 +183,8 @@ impl<R: std::io::Read> Decoder<R> {
             read,
             uncompressed: vec![],
             compressed: vec![],
+            end_of_stream: false,
+            num_bytes_read: FileHeader::SIZE as u64,
         })
     }
 
@@ -164,6 +193,106 @@ impl<R: std::io::Read> Decoder<R> {
     pub fn version(&self) -> CrateVersion {
         self.version
     }
+
+    /// How many bytes of the stream were decoded so far, headers included.
+    ///
+    /// When decoding a file from its start, this is the byte offset of the next message.
+    #[inline]
+    pub fn num_bytes_read(&self) -> u64 {
+        self.num_bytes_read
+    }
+}
+
+impl<R: std::io::Read + std::io::Seek> Decoder<R> {
+    /// Reads the footer index of the file, if it has one.
+    ///
+    /// Returns `Ok(None)` for files that were written without an index
+    /// (see [`crate::encoder::Encoder::with_index`]).
+    ///
+    /// This doesn't change the position of the decoder within the file.
+    /// Assumes the `.rrd` data starts at the beginning of the underlying reader.
+    pub fn read_index(&mut self) -> Result<Option<RrdIndex>, DecodeError> {
+        use std::io::SeekFrom;
+
+        re_tracing::profile_function!();
+
+        let position = self.read.stream_position().map_err(DecodeError::Read)?;
+        let file_len = self
+            .read
+            .seek(SeekFrom::End(0))
+            .map_err(DecodeError::Read)?;
+
+        let index = (|| -> Result<Option<RrdIndex>, DecodeError> {
+            let min_len = (FileHeader::SIZE + MessageHeader::SIZE + RRD_INDEX_TRAILER_SIZE) as u64;
+            if file_len < min_len {
+                return Ok(None);
+            }
+
+            let mut trailer = [0_u8; RRD_INDEX_TRAILER_SIZE];
+            self.read
+                .seek(SeekFrom::End(-(RRD_INDEX_TRAILER_SIZE as i64)))
+                .map_err(DecodeError::Read)?;
+            self.read
+                .read_exact(&mut trailer)
+                .map_err(DecodeError::Read)?;
+
+            if &trailer[8..] != RRD_INDEX_MAGIC {
+                return Ok(None);
+            }
+
+            let index_len = u64::from_le_bytes(trailer[..8].try_into().expect("8 bytes"));
+            if index_len > file_len - min_len {
+                return Err(DecodeError::Read(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "corrupt .rrd footer index",
+                )));
+            }
+
+            self.read
+                .seek(SeekFrom::End(
+                    -(RRD_INDEX_TRAILER_SIZE as i64) - index_len as i64,
+                ))
+                .map_err(DecodeError::Read)?;
+            let mut bytes = vec![0_u8; index_len as usize];
+            self.read
+                .read_exact(&mut bytes)
+                .map_err(DecodeError::Read)?;
+
+            Ok(Some(rmp_serde::from_slice(&bytes)?))
+        })();
+
+        self.read
+            .seek(SeekFrom::Start(position))
+            .map_err(DecodeError::Read)?;
+
+        index
+    }
+
+    /// Moves the decoder to the message header at `byte_offset`, e.g. to skip over a message
+    /// whose [`RrdIndexEntry`] is all that is needed.
+    ///
+    /// Iterating the decoder afterwards continues with the message at `byte_offset`.
+    pub fn seek_to(&mut self, byte_offset: u64) -> Result<(), DecodeError> {
+        self.read
+            .seek(std::io::SeekFrom::Start(byte_offset))
+            .map_err(DecodeError::Read)?;
+        self.end_of_stream = false;
+        self.num_bytes_read = byte_offset;
+
+        Ok(())
+    }
+
+    /// Decodes the single message described by `entry`, without decoding anything else.
+    ///
+    /// Iterating the decoder afterwards continues with the message following this one.
+    pub fn read_at(&mut self, entry: &RrdIndexEntry) -> Result<LogMsg, DecodeError> {
+        re_tracing::profile_function!();
+
+        self.seek_to(entry.byte_offset)?;
+
+        self.next()
+            .unwrap_or_else(|| Err(DecodeError::Read(std::io::ErrorKind::UnexpectedEof.into())))
+    }
 }
 
 impl<R: std::io::Read> Iterator for Decoder<R> {
@@ -172,6 +301,10 @@ impl<R: std::io::Read> Iterator for Decoder<R> {
     fn next(&mut self) -> Option<Self::Item> {
         re_tracing::profile_function!();
 
+        if self.end_of_stream {
+            return None;
+        }
+
         let header = match MessageHeader::decode(&mut self.read) {
             Ok(header) => header,
             Err(err) => match err {
@@ -182,6 +315,14 @@ impl<R: std::io::Read> Iterator for Decoder<R> {
             },
         };
 
+        self.num_bytes_read += MessageHeader::SIZE as u64;
+
+        if header.is_end_of_stream() {
+            // Whatever comes next is the footer index, which isn't part of the message stream.
+            self.end_of_stream = true;
+            return None;
+        }
+
         let uncompressed_len = header.uncompressed_len as usize;
         self.uncompressed
             .resize(self.uncompressed.len().max(uncompressed_len), 0);
@@ -195,6 +336,7 @@ impl<R: std::io::Read> Iterator for Decoder<R> {
                 {
                     return Some(Err(DecodeError::Read(err)));
                 }
+                self.num_bytes_read += uncompressed_len as u64;
             }
             Compression::LZ4 => {
                 let compressed_len = header.compressed_len as usize;
@@ -206,6 +348,7 @@ impl<R: std::io::Read> Iterator for Decoder<R> {
                     if let Err(err) = self.read.read_exact(&mut self.compressed[..compressed_len]) {
                         return Some(Err(DecodeError::Read(err)));
                     }
+                    self.num_bytes_read += compressed_len as u64;
                 }
 
                 re_tracing::profile_scope!("lz4");
@@ -216,6 +359,26 @@ impl<R: std::io::Read> Iterator for Decoder<R> {
                     return Some(Err(DecodeError::Lz4(err)));
                 }
             }
+            Compression::Zstd { .. } => {
+                let compressed_len = header.compressed_len as usize;
+                self.compressed
+                    .resize(self.compressed.len().max(compressed_len), 0);
+
+                {
+                    re_tracing::profile_scope!("read compressed");
+                    if let Err(err) = self.read.read_exact(&mut self.compressed[..compressed_len]) {
+                        return Some(Err(DecodeError::Read(err)));
+                    }
+                    self.num_bytes_read += compressed_len as u64;
+                }
+
+                if let Err(err) = decompress_zstd(
+                    &self.compressed[..compressed_len],
+                    &mut self.uncompressed[..uncompressed_len],
+                ) {
+                    return Some(Err(err));
+                }
+            }
         }
 
         re_tracing::profile_scope!("MsgPack deser");
@@ -269,6 +432,10 @@ fn test_encode_decode() {
             compression: Compression::LZ4,
             serializer: Serializer::MsgPack,
         },
+        EncodingOptions {
+            compression: Compression::Zstd { level: 19 },
+            serializer: Serializer::MsgPack,
+        },
     ];
 
     for options in options {
@@ -283,3 +450,159 @@ fn test_encode_decode() {
         assert_eq!(messages, decoded_messages);
     }
 }
+
+#[cfg(all(feature = "decoder", feature = "encoder"))]
+#[test]
+fn test_encode_decode_with_index() {
+    use re_chunk::{Chunk, RowId};
+    use re_log_types::{
+        example_components::MyPoint, EntityPath, ResolvedTimeRange, StoreId, StoreKind, TimeInt,
+        Timeline,
+    };
+    use re_types::Loggable as _;
+
+    let rrd_version = CrateVersion::LOCAL;
+    let store_id = StoreId::random(StoreKind::Recording);
+    let frame = Timeline::new_sequence("frame");
+
+    let chunks = (0..4)
+        .map(|i| {
+            let entity_path: EntityPath = if i % 2 == 0 {
+                "robot/camera".into()
+            } else {
+                "robot/lidar".into()
+            };
+            Chunk::builder(entity_path)
+                .with_component_batches(
+                    RowId::new(),
+                    [(frame, i * 10)],
+                    [&[MyPoint::new(1.0, 1.0)] as _],
+                )
+                .with_component_batches(
+                    RowId::new(),
+                    [(frame, i * 10 + 5)],
+                    [&[MyPoint::new(2.0, 2.0)] as _],
+                )
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let messages = chunks
+        .iter()
+        .map(|chunk| LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap()))
+        .collect::<Vec<_>>();
+
+    for options in [EncodingOptions::UNCOMPRESSED, EncodingOptions::COMPRESSED] {
+        let mut file = vec![];
+        {
+            let mut encoder =
+                crate::encoder::Encoder::new(rrd_version, options, std::io::Cursor::new(&mut file))
+                    .unwrap()
+                    .with_index();
+            for msg in &messages {
+                encoder.append(msg).unwrap();
+            }
+            encoder.finish().unwrap();
+            assert!(matches!(
+                encoder.append(&messages[0]),
+                Err(crate::encoder::EncodeError::AlreadyFinished)
+            ));
+        }
+
+        // Reading front to back stops at the footer.
+        let decoded_messages = Decoder::new(VersionPolicy::Error, file.as_slice())
+            .unwrap()
+            .collect::<Result<Vec<LogMsg>, DecodeError>>()
+            .unwrap();
+        assert_eq!(messages.len(), decoded_messages.len());
+
+        let mut decoder =
+            Decoder::new(VersionPolicy::Error, std::io::Cursor::new(file.as_slice())).unwrap();
+        let index = decoder.read_index().unwrap().unwrap();
+        assert_eq!(chunks.len(), index.entries.len());
+
+        // The decoder knows where each message is, even without an index.
+        for entry in &index.entries {
+            assert_eq!(entry.byte_offset, decoder.num_bytes_read());
+            let _msg = decoder.next().unwrap().unwrap();
+            assert_eq!(entry.byte_offset + entry.byte_len, decoder.num_bytes_read());
+        }
+        assert!(decoder.next().is_none());
+
+        for (chunk, entry) in chunks.iter().zip(&index.entries) {
+            assert_eq!(chunk.id(), entry.chunk_id);
+            assert_eq!(store_id, entry.store_id);
+            assert_eq!(chunk.entity_path(), &entry.entity_path);
+            assert_eq!(
+                Some(&chunk.timelines()[&frame].time_range()),
+                entry.time_ranges.get(&frame)
+            );
+
+            let rows = entry.rows.as_ref().unwrap();
+            assert_eq!(chunk.row_ids().collect::<Vec<_>>(), rows.row_ids);
+            assert_eq!(
+                Some(&chunk.timelines()[&frame].times_raw().to_vec()),
+                rows.times.get(&frame)
+            );
+            assert_eq!(
+                Some(&vec![true, true]),
+                rows.components.get(&MyPoint::name())
+            );
+        }
+
+        let in_range = index
+            .entries_in_time_range(&frame, ResolvedTimeRange::new(12, 22))
+            .map(|entry| entry.chunk_id)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![chunks[1].id(), chunks[2].id()], in_range);
+
+        let lidar = index.entries_in_subtree(&"robot/lidar".into()).count();
+        assert_eq!(2, lidar);
+
+        // Jump straight to the last chunk.
+        let entry = index.entries.last().unwrap();
+        let LogMsg::ArrowMsg(decoded_store_id, arrow_msg) = decoder.read_at(entry).unwrap() else {
+            panic!("expected an ArrowMsg");
+        };
+        let chunk = Chunk::from_arrow_msg(&arrow_msg).unwrap();
+        assert_eq!(store_id, decoded_store_id);
+        assert_eq!(chunks[3].id(), chunk.id());
+        assert_eq!(
+            Some(ResolvedTimeRange::new(
+                TimeInt::new_temporal(30),
+                TimeInt::new_temporal(35)
+            )),
+            chunk
+                .timelines()
+                .get(&frame)
+                .map(|time_chunk| time_chunk.time_range())
+        );
+        assert!(decoder.next().is_none());
+
+        // Skip over the first chunk.
+        decoder.seek_to(index.entries[1].byte_offset).unwrap();
+        let LogMsg::ArrowMsg(_, arrow_msg) = decoder.next().unwrap().unwrap() else {
+            panic!("expected an ArrowMsg");
+        };
+        assert_eq!(
+            chunks[1].id(),
+            Chunk::from_arrow_msg(&arrow_msg).unwrap().id()
+        );
+        assert_eq!(index.entries[2].byte_offset, decoder.num_bytes_read());
+    }
+
+    // Files without a footer don't have an index.
+    let mut file = vec![];
+    crate::encoder::encode(
+        rrd_version,
+        EncodingOptions::COMPRESSED,
+        messages.iter(),
+        &mut file,
+    )
+    .unwrap();
+    let mut decoder =
+        Decoder::new(VersionPolicy::Error, std::io::Cursor::new(file.as_slice())).unwrap();
+    assert!(decoder.read_index().unwrap().is_none());
+    assert_eq!(messages.len(), decoder.count());
+}
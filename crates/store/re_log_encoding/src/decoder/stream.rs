@@ -48,6 +48,9 @@ pub struct StreamDecoder {
 /// ^           |
 /// |           |
 /// ---Message<--
+///      |
+///      v
+///   Footer
 /// ```
 #[derive(Clone, Copy)]
 enum State {
@@ -73,6 +76,12 @@ enum State {
     /// to read it, otherwise the call to `decompress_into` or the
     /// MessagePack deserialization may block or even fail.
     Message(MessageHeader),
+
+    /// We've reached the end-of-stream marker.
+    ///
+    /// Everything that follows is the optional footer index (see [`crate::index`]),
+    /// which is of no use to a streaming decoder, so it is discarded.
+    Footer,
 }
 
 impl StreamDecoder {
@@ -109,6 +118,10 @@ impl StreamDecoder {
             State::MessageHeader => {
                 if let Some(mut len) = self.chunks.try_read(MessageHeader::SIZE) {
                     let header = MessageHeader::decode(&mut len)?;
+                    if header.is_end_of_stream() {
+                        self.state = State::Footer;
+                        return self.try_read();
+                    }
                     self.state = State::Message(header);
                     // we might have data left in the current chunk,
                     // immediately try to read the message content
@@ -126,6 +139,12 @@ impl StreamDecoder {
                                 .map_err(DecodeError::Lz4)?;
                             &self.uncompressed
                         }
+                        Compression::Zstd { .. } => {
+                            self.uncompressed
+                                .resize(header.uncompressed_len as usize, 0);
+                            super::decompress_zstd(bytes, &mut self.uncompressed)?;
+                            &self.uncompressed
+                        }
                     };
 
                     // read the message from the uncompressed bytes
@@ -143,6 +162,9 @@ impl StreamDecoder {
                     };
                 }
             }
+            State::Footer => {
+                self.chunks.clear();
+            }
         }
 
         Ok(None)
@@ -172,6 +194,12 @@ impl ChunkBuffer {
         }
     }
 
+    /// Discard all queued chunks.
+    fn clear(&mut self) {
+        self.queue.clear();
+        self.buffer_fill = 0;
+    }
+
     fn push(&mut self, chunk: Vec<u8>) {
         if chunk.is_empty() {
             return;
@@ -372,6 +400,55 @@ mod tests {
         assert_eq!(input, decoded_messages);
     }
 
+    #[test]
+    fn stream_byte_chunks_zstd_compressed() {
+        let (input, data) = test_data(EncodingOptions::ZSTD_COMPRESSED, 16);
+
+        let mut decoder = StreamDecoder::new(VersionPolicy::Error);
+
+        assert_message_incomplete!(decoder.try_read());
+
+        for chunk in data.chunks(1) {
+            decoder.push_chunk(chunk.to_vec());
+        }
+
+        let decoded_messages: Vec<_> = (0..16)
+            .map(|_| assert_message_ok!(decoder.try_read()))
+            .collect();
+
+        assert_eq!(input, decoded_messages);
+    }
+
+    #[test]
+    fn stream_ignores_footer_index() {
+        let messages: Vec<_> = (0..16).map(|_| fake_log_msg()).collect();
+
+        let mut data = Vec::new();
+        {
+            let mut encoder =
+                Encoder::new(CrateVersion::LOCAL, EncodingOptions::COMPRESSED, &mut data)
+                    .unwrap()
+                    .with_index();
+            for message in &messages {
+                encoder.append(message).unwrap();
+            }
+            encoder.finish().unwrap();
+        }
+
+        let mut decoder = StreamDecoder::new(VersionPolicy::Error);
+
+        for chunk in data.chunks(7) {
+            decoder.push_chunk(chunk.to_vec());
+        }
+
+        let decoded_messages: Vec<_> = (0..16)
+            .map(|_| assert_message_ok!(decoder.try_read()))
+            .collect();
+        assert_eq!(messages, decoded_messages);
+
+        assert_message_incomplete!(decoder.try_read());
+    }
+
     #[test]
     fn stream_3x16_chunks() {
         let (input, data) = test_data(EncodingOptions::COMPRESSED, 16);
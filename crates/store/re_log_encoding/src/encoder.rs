@@ -3,6 +3,7 @@
 use re_build_info::CrateVersion;
 use re_log_types::LogMsg;
 
+use crate::index::{RrdIndex, RrdIndexEntry, RRD_INDEX_MAGIC};
 use crate::FileHeader;
 use crate::MessageHeader;
 use crate::{Compression, EncodingOptions};
@@ -18,9 +19,18 @@ pub enum EncodeError {
     #[error("lz4 error: {0}")]
     Lz4(lz4_flex::block::CompressError),
 
+    #[error("zstd error: {0}")]
+    Zstd(std::io::Error),
+
+    #[error("zstd compression is not supported on this platform")]
+    ZstdUnsupported,
+
     #[error("MsgPack error: {0}")]
     MsgPack(#[from] rmp_serde::encode::Error),
 
+    #[error("Chunk error: {0}")]
+    Chunk(#[from] re_chunk::ChunkError),
+
     #[error("Called append on already finished encoder")]
     AlreadyFinished,
 }
@@ -42,6 +52,24 @@ pub fn encode_to_bytes<'a>(
     Ok(bytes)
 }
 
+/// Like [`encode_to_bytes`], but keeps any [`LogMsg::ViewerCommand`]s, for sending straight to a
+/// live viewer, see [`Encoder::with_viewer_commands`].
+pub fn encode_to_bytes_for_viewer<'a>(
+    version: CrateVersion,
+    options: EncodingOptions,
+    msgs: impl IntoIterator<Item = &'a LogMsg>,
+) -> Result<Vec<u8>, EncodeError> {
+    let mut bytes: Vec<u8> = vec![];
+    {
+        let mut encoder = Encoder::new(version, options, std::io::Cursor::new(&mut bytes))?
+            .with_viewer_commands();
+        for msg in msgs {
+            encoder.append(msg)?;
+        }
+    }
+    Ok(bytes)
+}
+
 // ----------------------------------------------------------------------------
 
 /// Encode a stream of [`LogMsg`] into an `.rrd` file.
@@ -50,6 +78,18 @@ pub struct Encoder<W: std::io::Write> {
     write: W,
     uncompressed: Vec<u8>,
     compressed: Vec<u8>,
+
+    /// Total number of bytes written so far, including the file header.
+    num_bytes_written: u64,
+
+    /// `Some` if we're building a footer index, see [`Self::with_index`].
+    index: Option<RrdIndex>,
+
+    /// Set by [`Self::finish`].
+    finished: bool,
+
+    /// See [`Self::with_viewer_commands`].
+    keep_viewer_commands: bool,
 }
 
 impl<W: std::io::Write> Encoder<W> {
@@ -74,16 +114,53 @@ impl<W: std::io::Write> Encoder<W> {
             write,
             uncompressed: vec![],
             compressed: vec![],
+            num_bytes_written: FileHeader::SIZE as u64,
+            index: None,
+            finished: false,
+            keep_viewer_commands: false,
         })
     }
 
+    /// Record the byte offset and contents of every chunk that gets appended, and write them out
+    /// as a footer index when calling [`Self::finish`].
+    ///
+    /// This allows readers to jump straight to the chunks they're interested in, see
+    /// [`crate::decoder::Decoder::read_index`].
+    /// Files written this way can still be read front to back as usual.
+    ///
+    /// All whole `.rrd` files get one, e.g. those written by [`crate::FileSink`],
+    /// saved from the viewer, or by `rerun rrd compact`.
+    #[must_use]
+    pub fn with_index(mut self) -> Self {
+        self.index = Some(RrdIndex::default());
+        self
+    }
+
+    /// Also encode [`LogMsg::ViewerCommand`]s, which are dropped otherwise.
+    ///
+    /// Only for streams that go straight to a live viewer: commands don't belong in `.rrd` files,
+    /// which older viewers would then fail to decode, and which would re-run them whenever opened.
+    #[must_use]
+    pub fn with_viewer_commands(mut self) -> Self {
+        self.keep_viewer_commands = true;
+        self
+    }
+
     pub fn append(&mut self, message: &LogMsg) -> Result<(), EncodeError> {
         re_tracing::profile_function!();
 
+        if self.finished {
+            return Err(EncodeError::AlreadyFinished);
+        }
+
+        if matches!(message, LogMsg::ViewerCommand(..)) && !self.keep_viewer_commands {
+            return Ok(());
+        }
+
         self.uncompressed.clear();
         rmp_serde::encode::write_named(&mut self.uncompressed, message)?;
 
-        match self.compression {
+        let compressed_len = match self.compression {
             Compression::Off => {
                 MessageHeader {
                     uncompressed_len: self.uncompressed.len() as u32,
@@ -93,6 +170,7 @@ impl<W: std::io::Write> Encoder<W> {
                 self.write
                     .write_all(&self.uncompressed)
                     .map_err(EncodeError::Write)?;
+                self.uncompressed.len()
             }
             Compression::LZ4 => {
                 let max_len = lz4_flex::block::get_maximum_output_size(self.uncompressed.len());
@@ -108,8 +186,69 @@ impl<W: std::io::Write> Encoder<W> {
                 self.write
                     .write_all(&self.compressed[..compressed_len])
                     .map_err(EncodeError::Write)?;
+                compressed_len
+            }
+            Compression::Zstd { level } => {
+                let compressed_len =
+                    compress_zstd(&self.uncompressed, &mut self.compressed, level)?;
+                MessageHeader {
+                    uncompressed_len: self.uncompressed.len() as u32,
+                    compressed_len: compressed_len as u32,
+                }
+                .encode(&mut self.write)?;
+                self.write
+                    .write_all(&self.compressed[..compressed_len])
+                    .map_err(EncodeError::Write)?;
+                compressed_len
             }
+        };
+
+        let byte_offset = self.num_bytes_written;
+        let byte_len = (MessageHeader::SIZE + compressed_len) as u64;
+        self.num_bytes_written += byte_len;
+
+        if let (Some(index), LogMsg::ArrowMsg(store_id, arrow_msg)) = (&mut self.index, message) {
+            let chunk = re_chunk::Chunk::from_arrow_msg(arrow_msg)?;
+            index.entries.push(RrdIndexEntry::new(
+                byte_offset,
+                byte_len,
+                store_id.clone(),
+                &chunk,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Ends the stream, writing out the footer index if [`Self::with_index`] was used.
+    ///
+    /// Calling [`Self::append`] afterwards is an error.
+    /// Calling this is optional if no index is being built.
+    pub fn finish(&mut self) -> Result<(), EncodeError> {
+        re_tracing::profile_function!();
+
+        if self.finished {
+            return Ok(());
         }
+        self.finished = true;
+
+        let Some(index) = self.index.take() else {
+            return Ok(());
+        };
+
+        MessageHeader::END_OF_STREAM.encode(&mut self.write)?;
+
+        self.uncompressed.clear();
+        rmp_serde::encode::write_named(&mut self.uncompressed, &index)?;
+        self.write
+            .write_all(&self.uncompressed)
+            .map_err(EncodeError::Write)?;
+        self.write
+            .write_all(&(self.uncompressed.len() as u64).to_le_bytes())
+            .map_err(EncodeError::Write)?;
+        self.write
+            .write_all(RRD_INDEX_MAGIC)
+            .map_err(EncodeError::Write)?;
 
         Ok(())
     }
@@ -123,6 +262,30 @@ impl<W: std::io::Write> Encoder<W> {
     }
 }
 
+/// Compresses `uncompressed` into `compressed`, returning the number of compressed bytes.
+#[cfg(not(target_arch = "wasm32"))]
+fn compress_zstd(
+    uncompressed: &[u8],
+    compressed: &mut Vec<u8>,
+    level: i32,
+) -> Result<usize, EncodeError> {
+    re_tracing::profile_function!();
+    let max_len = zstd::zstd_safe::compress_bound(uncompressed.len());
+    compressed.resize(max_len, 0);
+    zstd::bulk::compress_to_buffer(uncompressed, compressed.as_mut_slice(), level)
+        .map_err(EncodeError::Zstd)
+}
+
+// The `zstd` crate wraps the reference C implementation, which we don't build for the web.
+#[cfg(target_arch = "wasm32")]
+fn compress_zstd(
+    _uncompressed: &[u8],
+    _compressed: &mut Vec<u8>,
+    _level: i32,
+) -> Result<usize, EncodeError> {
+    Err(EncodeError::ZstdUnsupported)
+}
+
 pub fn encode<'a>(
     version: CrateVersion,
     options: EncodingOptions,
@@ -137,6 +300,23 @@ pub fn encode<'a>(
     Ok(())
 }
 
+/// Like [`encode`], but also writes a footer index, see [`Encoder::with_index`].
+///
+/// Use this when writing whole `.rrd` files.
+pub fn encode_with_index<'a>(
+    version: CrateVersion,
+    options: EncodingOptions,
+    messages: impl Iterator<Item = &'a LogMsg>,
+    write: &mut impl std::io::Write,
+) -> Result<(), EncodeError> {
+    re_tracing::profile_function!();
+    let mut encoder = Encoder::new(version, options, write)?.with_index();
+    for message in messages {
+        encoder.append(message)?;
+    }
+    encoder.finish()
+}
+
 pub fn encode_as_bytes<'a>(
     version: CrateVersion,
     options: EncodingOptions,
@@ -166,3 +346,78 @@ pub fn encode_as_bytes_local<'a>(
     }
     Ok(encoder.into_inner())
 }
+
+#[cfg(all(test, feature = "decoder"))]
+pub(crate) mod tests {
+    use re_log_types::{
+        external::re_tuid::Tuid, ApplicationId, SetStoreInfo, StoreId, StoreInfo, StoreKind,
+        StoreSource, Time, ViewerCommand,
+    };
+
+    use crate::decoder::{decode_bytes, VersionPolicy};
+
+    use super::*;
+
+    pub(crate) fn messages() -> Vec<LogMsg> {
+        let store_id = StoreId::random(StoreKind::Recording);
+        vec![
+            LogMsg::SetStoreInfo(SetStoreInfo {
+                row_id: Tuid::new(),
+                info: StoreInfo {
+                    application_id: ApplicationId("test".to_owned()),
+                    store_id: store_id.clone(),
+                    cloned_from: None,
+                    is_official_example: false,
+                    started: Time::now(),
+                    store_source: StoreSource::Unknown,
+                    store_version: Some(CrateVersion::LOCAL),
+                },
+            }),
+            LogMsg::ViewerCommand(store_id, ViewerCommand::SetPlaying(true)),
+        ]
+    }
+
+    #[test]
+    fn viewer_commands_are_dropped() {
+        let messages = messages();
+
+        let mut bytes = Vec::new();
+        encode(
+            CrateVersion::LOCAL,
+            EncodingOptions::COMPRESSED,
+            messages.iter(),
+            &mut bytes,
+        )
+        .unwrap();
+        assert_eq!(
+            messages[..1],
+            decode_bytes(VersionPolicy::Error, &bytes).unwrap()
+        );
+
+        let bytes = encode_to_bytes(
+            CrateVersion::LOCAL,
+            EncodingOptions::UNCOMPRESSED,
+            messages.iter(),
+        )
+        .unwrap();
+        assert_eq!(
+            messages[..1],
+            decode_bytes(VersionPolicy::Error, &bytes).unwrap()
+        );
+    }
+
+    #[test]
+    fn viewer_commands_for_viewer() {
+        let messages = messages();
+        let bytes = encode_to_bytes_for_viewer(
+            CrateVersion::LOCAL,
+            EncodingOptions::UNCOMPRESSED,
+            messages.iter(),
+        )
+        .unwrap();
+        assert_eq!(
+            messages,
+            decode_bytes(VersionPolicy::Error, &bytes).unwrap()
+        );
+    }
+}
@@ -37,6 +37,9 @@ impl Command {
 }
 
 /// Stream log messages to an `.rrd` file.
+///
+/// Files get a footer index once the sink is dropped, so that they can be loaded lazily,
+/// see [`crate::encoder::Encoder::with_index`].
 pub struct FileSink {
     // None = quit
     tx: Mutex<Sender<Option<Command>>>,
@@ -61,8 +64,16 @@ impl FileSink {
     /// Start writing log messages to a file at the given path.
     pub fn new(path: impl Into<std::path::PathBuf>) -> Result<Self, FileSinkError> {
         // We always compress on disk
-        let encoding_options = crate::EncodingOptions::COMPRESSED;
+        Self::with_options(path, crate::EncodingOptions::COMPRESSED)
+    }
 
+    /// Start writing log messages to a file at the given path, using the given encoding options.
+    ///
+    /// Use e.g. [`crate::EncodingOptions::ZSTD_COMPRESSED`] for smaller files.
+    pub fn with_options(
+        path: impl Into<std::path::PathBuf>,
+        encoding_options: crate::EncodingOptions,
+    ) -> Result<Self, FileSinkError> {
         let (tx, rx) = std::sync::mpsc::channel();
 
         let path = path.into();
@@ -79,7 +90,8 @@ impl FileSink {
             re_build_info::CrateVersion::LOCAL,
             encoding_options,
             file,
-        )?;
+        )?
+        .with_index();
         let join_handle = spawn_and_stream(Some(&path), encoder, rx)?;
 
         Ok(Self {
@@ -91,8 +103,13 @@ impl FileSink {
 
     /// Start writing log messages to standard output.
     pub fn stdout() -> Result<Self, FileSinkError> {
-        let encoding_options = crate::EncodingOptions::COMPRESSED;
+        Self::stdout_with_options(crate::EncodingOptions::COMPRESSED)
+    }
 
+    /// Start writing log messages to standard output, using the given encoding options.
+    pub fn stdout_with_options(
+        encoding_options: crate::EncodingOptions,
+    ) -> Result<Self, FileSinkError> {
         let (tx, rx) = std::sync::mpsc::channel();
 
         re_log::debug!("Writing to stdout…");
@@ -157,6 +174,10 @@ fn spawn_and_stream<W: std::io::Write + Send + 'static>(
                         }
                     }
                 }
+                if let Err(err) = encoder.finish() {
+                    re_log::error!("Failed to finish log stream to {target}: {err}");
+                    return;
+                }
                 re_log::debug!("Log stream written to {target}");
             }
         })
@@ -173,3 +194,53 @@ impl fmt::Debug for FileSink {
             .finish_non_exhaustive()
     }
 }
+
+#[cfg(all(test, feature = "decoder"))]
+mod tests {
+    use super::*;
+
+    /// Viewer commands are for live viewers, and must never end up in a saved recording.
+    #[test]
+    fn no_viewer_commands_in_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("recording.rrd");
+
+        let messages = crate::encoder::tests::messages();
+        let sink = FileSink::new(&path).unwrap();
+        for msg in &messages {
+            sink.send(msg.clone());
+        }
+        drop(sink);
+
+        let decoded = crate::decoder::Decoder::new(
+            crate::decoder::VersionPolicy::Error,
+            std::io::BufReader::new(std::fs::File::open(&path).unwrap()),
+        )
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+        assert_eq!(messages[..1], decoded);
+        assert!(!decoded
+            .iter()
+            .any(|msg| matches!(msg, LogMsg::ViewerCommand(..))));
+    }
+
+    #[test]
+    fn files_are_indexed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("recording.rrd");
+
+        let sink = FileSink::new(&path).unwrap();
+        for msg in crate::encoder::tests::messages() {
+            sink.send(msg);
+        }
+        drop(sink);
+
+        let mut decoder = crate::decoder::Decoder::new(
+            crate::decoder::VersionPolicy::Error,
+            std::io::BufReader::new(std::fs::File::open(&path).unwrap()),
+        )
+        .unwrap();
+        assert!(decoder.read_index().unwrap().is_some());
+    }
+}
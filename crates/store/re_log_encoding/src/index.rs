@@ -0,0 +1,171 @@
+//! Optional footer index of an `.rrd` file, for random access to its chunks.
+//!
+//! An indexed `.rrd` file looks like this:
+//!
+//! ```text,ignore
+//! FileHeader
+//! MessageHeader, Message
+//! …
+//! MessageHeader, Message
+//! MessageHeader { compressed_len: 0, uncompressed_len: 0 }   <- end-of-stream marker
+//! RrdIndex                                                   <- MsgPack, uncompressed
+//! u64 (little-endian)                                        <- byte length of the `RrdIndex`
+//! RRD_INDEX_MAGIC
+//! ```
+//!
+//! Decoders stop at the end-of-stream marker, so the footer never shows up as a message.
+//! Files without a footer keep loading exactly as before.
+
+use std::collections::BTreeMap;
+
+use re_chunk::external::arrow2::array::Array as _;
+use re_chunk::{Chunk, ChunkId, ComponentName, RowId};
+use re_log_types::{EntityPath, ResolvedTimeRange, StoreId, Timeline};
+
+// ----------------------------------------------------------------------------
+
+/// Magic bytes at the very end of an `.rrd` file that has a footer index.
+pub(crate) const RRD_INDEX_MAGIC: &[u8; 4] = b"RRIX";
+
+/// Size of the trailer at the very end of the file: index length + [`RRD_INDEX_MAGIC`].
+#[cfg(feature = "decoder")]
+pub(crate) const RRD_INDEX_TRAILER_SIZE: usize = 8 + RRD_INDEX_MAGIC.len();
+
+/// Where to find a single `ArrowMsg` in an `.rrd` file, and what it contains.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct RrdIndexEntry {
+    /// Offset of the message, in bytes, from the start of the file.
+    ///
+    /// Points at the message header preceding the message itself.
+    pub byte_offset: u64,
+
+    /// Size of the message in bytes, including its header.
+    pub byte_len: u64,
+
+    /// The recording the chunk belongs to.
+    pub store_id: StoreId,
+
+    /// The entity the chunk belongs to.
+    pub entity_path: EntityPath,
+
+    pub chunk_id: ChunkId,
+
+    /// The time range covered by the chunk on each of its timelines.
+    ///
+    /// Empty for static chunks.
+    pub time_ranges: BTreeMap<Timeline, ResolvedTimeRange>,
+
+    /// What each row of the chunk contains, without the data itself.
+    ///
+    /// `None` for files written before this was part of the index.
+    #[serde(default)]
+    pub rows: Option<RrdChunkRows>,
+}
+
+/// The rows of a chunk, minus their data: enough to build the entity tree and the time
+/// histograms of a recording without decoding any of its chunks.
+///
+/// All columns are in the order the rows were written in.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct RrdChunkRows {
+    pub row_ids: Vec<RowId>,
+
+    /// The time of each row, on each timeline of the chunk.
+    pub times: BTreeMap<Timeline, Vec<i64>>,
+
+    /// Whether each row has data for the component.
+    pub components: BTreeMap<ComponentName, Vec<bool>>,
+}
+
+impl RrdChunkRows {
+    /// Describes the rows of `chunk`.
+    pub fn new(chunk: &Chunk) -> Self {
+        Self {
+            row_ids: chunk.row_ids().collect(),
+            times: chunk
+                .timelines()
+                .iter()
+                .map(|(timeline, time_chunk)| (*timeline, time_chunk.times_raw().to_vec()))
+                .collect(),
+            components: chunk
+                .components()
+                .iter()
+                .map(|(component_name, list_array)| {
+                    let is_valid = (0..list_array.len())
+                        .map(|index| list_array.is_valid(index))
+                        .collect();
+                    (*component_name, is_valid)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl RrdIndexEntry {
+    /// Describes `chunk`, which was written to `byte_len` bytes at `byte_offset`.
+    pub fn new(byte_offset: u64, byte_len: u64, store_id: StoreId, chunk: &Chunk) -> Self {
+        Self {
+            byte_offset,
+            byte_len,
+            store_id,
+            entity_path: chunk.entity_path().clone(),
+            chunk_id: chunk.id(),
+            time_ranges: chunk
+                .timelines()
+                .iter()
+                .map(|(timeline, time_chunk)| (*timeline, time_chunk.time_range()))
+                .collect(),
+            rows: Some(RrdChunkRows::new(chunk)),
+        }
+    }
+
+    /// Is this chunk static, i.e. relevant at all times?
+    #[inline]
+    pub fn is_static(&self) -> bool {
+        self.time_ranges.is_empty()
+    }
+
+    /// Could this chunk hold data relevant to the given time range?
+    ///
+    /// Static chunks are relevant to every time range. Temporal chunks that don't have data
+    /// on `timeline` at all are not.
+    #[inline]
+    pub fn intersects(&self, timeline: &Timeline, time_range: ResolvedTimeRange) -> bool {
+        self.is_static()
+            || self
+                .time_ranges
+                .get(timeline)
+                .map_or(false, |chunk_range| chunk_range.intersects(time_range))
+    }
+}
+
+/// Index of all the `ArrowMsg`s in an `.rrd` file, in the order they were written.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct RrdIndex {
+    pub entries: Vec<RrdIndexEntry>,
+}
+
+impl RrdIndex {
+    /// All chunks that could hold data relevant to the given time range.
+    ///
+    /// See [`RrdIndexEntry::intersects`].
+    pub fn entries_in_time_range<'a>(
+        &'a self,
+        timeline: &'a Timeline,
+        time_range: ResolvedTimeRange,
+    ) -> impl Iterator<Item = &'a RrdIndexEntry> + 'a {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.intersects(timeline, time_range))
+    }
+
+    /// All chunks belonging to `entity_path` or any of its descendants.
+    pub fn entries_in_subtree<'a>(
+        &'a self,
+        entity_path: &'a EntityPath,
+    ) -> impl Iterator<Item = &'a RrdIndexEntry> + 'a {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.entity_path.starts_with(entity_path))
+    }
+}
@@ -5,6 +5,9 @@ pub mod decoder;
 #[cfg(feature = "encoder")]
 pub mod encoder;
 
+#[cfg(any(feature = "encoder", feature = "decoder"))]
+pub mod index;
+
 #[cfg(feature = "encoder")]
 #[cfg(not(target_arch = "wasm32"))]
 mod file_sink;
@@ -18,6 +21,9 @@ pub mod stream_rrd_from_http;
 #[cfg(not(target_arch = "wasm32"))]
 pub use file_sink::{FileSink, FileSinkError};
 
+#[cfg(any(feature = "encoder", feature = "decoder"))]
+pub use index::{RrdChunkRows, RrdIndex, RrdIndexEntry};
+
 // ----------------------------------------------------------------------------
 
 #[cfg(any(feature = "encoder", feature = "decoder"))]
@@ -36,6 +42,38 @@ pub enum Compression {
 
     /// Very fast compression and decompression, but not very good compression ratio.
     LZ4 = 1,
+
+    /// Much better compression ratio than [`Self::LZ4`], at the cost of slower compression.
+    ///
+    /// Decompression remains fast regardless of the level.
+    /// Well suited for archiving recordings.
+    Zstd {
+        /// The zstd compression level, from 1 (fastest) to 22 (smallest).
+        ///
+        /// The level only affects encoding: it is not stored in the file, and decoded
+        /// [`EncodingOptions`] will always report [`Compression::ZSTD_DEFAULT_LEVEL`].
+        level: i32,
+    } = 2,
+}
+
+impl Compression {
+    /// The zstd compression level used when none is specified.
+    pub const ZSTD_DEFAULT_LEVEL: i32 = 3;
+
+    /// [`Self::Zstd`] with [`Self::ZSTD_DEFAULT_LEVEL`].
+    pub const ZSTD: Self = Self::Zstd {
+        level: Self::ZSTD_DEFAULT_LEVEL,
+    };
+
+    /// The tag written to the file header.
+    #[inline]
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::Off => 0,
+            Self::LZ4 => 1,
+            Self::Zstd { .. } => 2,
+        }
+    }
 }
 
 /// How we serialize the data
@@ -60,6 +98,10 @@ impl EncodingOptions {
         compression: Compression::LZ4,
         serializer: Serializer::MsgPack,
     };
+    pub const ZSTD_COMPRESSED: Self = Self {
+        compression: Compression::ZSTD,
+        serializer: Serializer::MsgPack,
+    };
 
     pub fn from_bytes(bytes: [u8; 4]) -> Result<Self, OptionsError> {
         match bytes {
@@ -67,6 +109,7 @@ impl EncodingOptions {
                 let compression = match compression {
                     0 => Compression::Off,
                     1 => Compression::LZ4,
+                    2 => Compression::ZSTD,
                     _ => return Err(OptionsError::UnknownCompression(compression)),
                 };
                 let serializer = match serializer {
@@ -84,7 +127,7 @@ impl EncodingOptions {
 
     pub fn to_bytes(self) -> [u8; 4] {
         [
-            self.compression as u8,
+            self.compression.to_byte(),
             self.serializer as u8,
             0, // reserved
             0, // reserved
@@ -116,7 +159,6 @@ pub(crate) struct FileHeader {
 
 #[cfg(any(feature = "encoder", feature = "decoder"))]
 impl FileHeader {
-    #[cfg(feature = "decoder")]
     pub const SIZE: usize = 12;
 
     #[cfg(feature = "encoder")]
@@ -161,9 +203,23 @@ pub(crate) struct MessageHeader {
 
 #[cfg(any(feature = "encoder", feature = "decoder"))]
 impl MessageHeader {
-    #[cfg(feature = "decoder")]
     pub const SIZE: usize = 8;
 
+    /// Marks the end of the message stream, see [`crate::index`].
+    ///
+    /// No actual message can ever be empty, so this is unambiguous.
+    #[cfg(feature = "encoder")]
+    pub const END_OF_STREAM: Self = Self {
+        compressed_len: 0,
+        uncompressed_len: 0,
+    };
+
+    #[cfg(feature = "decoder")]
+    #[inline]
+    pub fn is_end_of_stream(&self) -> bool {
+        self.compressed_len == 0 && self.uncompressed_len == 0
+    }
+
     #[cfg(feature = "encoder")]
     pub fn encode(&self, write: &mut impl std::io::Write) -> Result<(), encoder::EncodeError> {
         write
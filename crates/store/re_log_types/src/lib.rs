@@ -22,6 +22,7 @@ pub mod example_components;
 pub mod hash;
 pub mod path;
 pub mod time_point;
+pub mod viewer_control;
 
 // mod data_cell;
 // mod data_row;
@@ -46,6 +47,7 @@ pub use self::time_point::{
 };
 pub use self::time_real::TimeReal;
 pub use self::vec_deque_ext::{VecDequeInsertionExt, VecDequeRemovalExt, VecDequeSortingExt};
+pub use self::viewer_control::{ViewerCommand, ViewerEvent, ViewerMessage};
 
 pub mod external {
     pub use arrow2;
@@ -275,13 +277,19 @@ pub enum LogMsg {
     /// fully transmitted. Showing a half-transmitted blueprint can cause confusion,
     /// and also lead to problems with space-view heuristics.
     BlueprintActivationCommand(BlueprintActivationCommand),
+
+    /// Remote control of the viewer, applied to the given recording.
+    ///
+    /// Not part of the recording's data: commands are only forwarded over live connections to a
+    /// viewer, and are dropped when the stream is written to an `.rrd` file.
+    ViewerCommand(StoreId, ViewerCommand),
 }
 
 impl LogMsg {
     pub fn store_id(&self) -> &StoreId {
         match self {
             Self::SetStoreInfo(msg) => &msg.info.store_id,
-            Self::ArrowMsg(store_id, _) => store_id,
+            Self::ArrowMsg(store_id, _) | Self::ViewerCommand(store_id, _) => store_id,
             Self::BlueprintActivationCommand(cmd) => &cmd.blueprint_id,
         }
     }
@@ -291,7 +299,7 @@ impl LogMsg {
             Self::SetStoreInfo(store_info) => {
                 store_info.info.store_id = new_store_id;
             }
-            Self::ArrowMsg(store_id, _) => {
+            Self::ArrowMsg(store_id, _) | Self::ViewerCommand(store_id, _) => {
                 *store_id = new_store_id;
             }
             Self::BlueprintActivationCommand(cmd) => {
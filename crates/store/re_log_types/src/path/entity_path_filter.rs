@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use ahash::HashMap;
 use itertools::Itertools as _;
 
-use crate::EntityPath;
+use crate::{EntityPath, EntityPathPart};
 
 /// A set of substitutions for entity paths.
 #[derive(Default)]
@@ -32,9 +32,19 @@ impl EntityPathSubs {
 ///
 /// The `/**` suffix matches the whole subtree, i.e. self and any child, recursively
 /// (`/world/**` matches both `/world` and `/world/car/driver`).
-/// Other uses of `*` are not (yet) supported.
+///
+/// Rules can also contain wildcards anywhere in the path:
+/// * `*` matches exactly one part (`/fleet/*/lidar` matches `/fleet/truck/lidar`).
+/// * `*` within a part matches any sequence of characters in it
+///   (`/robot_*/camera` matches `/robot_1/camera`).
+/// * `**` in the middle of a path matches any number of parts, including none
+///   (`/world/**/wheel` matches `/world/wheel` and `/world/car/front/wheel`).
+///
+/// Use `\*` to match a literal `*`.
 ///
 /// `EntityPathFilter` sorts the rule by entity path, with recursive coming before non-recursive.
+/// Rules with wildcards are sorted by the part of their path that comes before the first wildcard,
+/// in between the recursive and the non-recursive rules of that path.
 /// This means the last matching rule is also the most specific one.
 /// For instance:
 ///
@@ -72,10 +82,36 @@ pub struct EntityPathRule {
     // when it contains substitutions.
     pub raw_expression: String,
 
+    /// The path to match.
+    ///
+    /// For rules with wildcards, this is only the part of the path before the first wildcard.
     pub path: EntityPath,
 
     /// If true, ALSO include children and grandchildren of this path (recursive rule).
     pub include_subtree: bool,
+
+    /// The rest of the path, starting at the first wildcard.
+    ///
+    /// Empty for rules without wildcards, other than a trailing `/**`.
+    pub wildcards: Vec<PathPattern>,
+}
+
+/// A part of an [`EntityPathRule`] with wildcards.
+///
+/// The variants are ordered from least to most specific.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PathPattern {
+    /// `**`: matches any number of parts, including none.
+    AnyParts,
+
+    /// A part with `*` wildcards in it, e.g. `robot_*`.
+    ///
+    /// Holds the (unescaped) text in between the wildcards: `*` is `["", ""]`,
+    /// `robot_*` is `["robot_", ""]`.
+    Glob(Vec<String>),
+
+    /// A part without wildcards.
+    Exact(EntityPathPart),
 }
 
 impl PartialEq for EntityPathRule {
@@ -225,7 +261,9 @@ impl EntityPathFilter {
     /// Is there a rule for this exact entity path (ignoring subtree)?
     pub fn is_exact_included(&self, entity_path: &EntityPath) -> bool {
         self.rules.iter().any(|(rule, effect)| {
-            effect == &RuleEffect::Include && !rule.include_subtree && rule.path == *entity_path
+            effect == &RuleEffect::Include
+                && !rule.include_subtree
+                && rule.is_for_exactly(entity_path)
         })
     }
 
@@ -261,39 +299,60 @@ impl EntityPathFilter {
 
     /// Remove any rule for the given entity path (ignoring whether or not that rule includes the subtree).
     pub fn remove_rule_for(&mut self, entity_path: &EntityPath) {
-        self.rules.retain(|rule, _| rule.path != *entity_path);
+        self.rules
+            .retain(|rule, _| !rule.is_for_exactly(entity_path));
     }
 
     /// Is there any rule for this entity path?
     ///
     /// Whether or not the subtree is included is NOT important.
     pub fn contains_rule_for_exactly(&self, entity_path: &EntityPath) -> bool {
-        self.rules.iter().any(|(rule, _)| rule.path == *entity_path)
+        self.rules
+            .iter()
+            .any(|(rule, _)| rule.is_for_exactly(entity_path))
     }
 
     /// Is this entity path explicitly included?
     ///
     /// Whether or not the subtree is included is NOT important.
     pub fn is_explicitly_included(&self, entity_path: &EntityPath) -> bool {
-        self.rules
-            .iter()
-            .any(|(rule, effect)| rule.path == *entity_path && effect == &RuleEffect::Include)
+        self.rules.iter().any(|(rule, effect)| {
+            rule.is_for_exactly(entity_path) && effect == &RuleEffect::Include
+        })
     }
 
     /// Is this entity path explicitly excluded?
     ///
     /// Whether or not the subtree is included is NOT important.
     pub fn is_explicitly_excluded(&self, entity_path: &EntityPath) -> bool {
-        self.rules
-            .iter()
-            .any(|(rule, effect)| rule.path == *entity_path && effect == &RuleEffect::Exclude)
+        self.rules.iter().any(|(rule, effect)| {
+            rule.is_for_exactly(entity_path) && effect == &RuleEffect::Exclude
+        })
     }
 
     /// Is anything under this path included (including self)?
+    ///
+    /// For rules with wildcards, this may return `true` for a subtree in which everything the
+    /// wildcard rule could match is excluded by more specific rules.
     pub fn is_anything_in_subtree_included(&self, path: &EntityPath) -> bool {
-        for (rule, effect) in &self.rules {
-            if effect == &RuleEffect::Include && rule.path.starts_with(path) {
-                return true; // something in this subtree is explicitly included
+        for (i, (rule, effect)) in self.rules.iter().enumerate() {
+            if effect != &RuleEffect::Include {
+                continue;
+            }
+
+            if rule.wildcards.is_empty() {
+                if rule.path.starts_with(path) {
+                    return true; // something in this subtree is explicitly included
+                }
+            } else if rule.may_match_in_subtree(path) {
+                // Unless the whole subtree is excluded by a more specific rule,
+                // something in it is included.
+                let is_shadowed = self.rules.iter().skip(i + 1).any(|(rule, effect)| {
+                    effect == &RuleEffect::Exclude && rule.include_subtree && rule.matches(path)
+                });
+                if !is_shadowed {
+                    return true;
+                }
             }
         }
 
@@ -339,6 +398,20 @@ impl EntityPathFilter {
         for (other_rule, other_effect) in &other.rules {
             match other_effect {
                 RuleEffect::Include => {
+                    // Exclusions are checked below.
+                    if !other_rule.wildcards.is_empty()
+                        && self.rules.iter().any(|(self_rule, self_effect)| {
+                            self_effect == &RuleEffect::Include && self_rule.covers(other_rule)
+                        })
+                    {
+                        continue;
+                    }
+
+                    // A rule with wildcards only matches things in the subtree of its path,
+                    // so we're conservative and check that we include all of that subtree.
+                    let other_includes_subtree =
+                        other_rule.include_subtree || !other_rule.wildcards.is_empty();
+
                     if let Some((self_rule, self_effect)) = self
                         .rules
                         .iter()
@@ -349,7 +422,7 @@ impl EntityPathFilter {
                             RuleEffect::Include => {
                                 // If the other rule includes the subtree, but the matching
                                 // rule doesn't, then we don't fully contain the other rule.
-                                if other_rule.include_subtree && !self_rule.include_subtree {
+                                if other_includes_subtree && !self_rule.include_subtree {
                                     return false;
                                 }
                             }
@@ -368,7 +441,15 @@ impl EntityPathFilter {
             match self_effect {
                 RuleEffect::Include => {}
                 RuleEffect::Exclude => {
-                    if let Some((_, other_effect)) = other
+                    if !self_rule.wildcards.is_empty() {
+                        // We can't tell exactly what the other filter includes among what
+                        // this rule matches, so be conservative.
+                        if other.rules.iter().any(|(other_rule, other_effect)| {
+                            other_effect == &RuleEffect::Include && other_rule.overlaps(self_rule)
+                        }) {
+                            return false;
+                        }
+                    } else if let Some((_, other_effect)) = other
                         .rules
                         .iter()
                         .rev()
@@ -398,6 +479,7 @@ impl EntityPathRule {
             raw_expression: path.to_string(),
             path,
             include_subtree: false,
+            wildcards: Vec::new(),
         }
     }
 
@@ -408,6 +490,7 @@ impl EntityPathRule {
             raw_expression: format!("{path}/**",),
             path,
             include_subtree: true,
+            wildcards: Vec::new(),
         }
     }
 
@@ -423,42 +506,283 @@ impl EntityPathRule {
             expression_sub = expression_sub.replace(format!("${{{key}}}").as_str(), value);
         }
 
-        if expression == "/**" {
-            Self {
-                raw_expression,
-                path: EntityPath::root(),
-                include_subtree: true,
+        let mut path = Vec::new();
+        let mut wildcards = Vec::new();
+        for token in super::parse_path::tokenize_entity_path(&expression_sub) {
+            if token == "/" {
+                continue; // ignore duplicate slashes
             }
-        } else if let Some(path) = expression_sub.strip_suffix("/**") {
-            Self {
-                raw_expression,
-                path: EntityPath::parse_forgiving(path),
-                include_subtree: true,
-            }
-        } else {
-            Self {
-                raw_expression,
-                path: EntityPath::parse_forgiving(&expression_sub),
-                include_subtree: false,
+            let pattern = PathPattern::parse_forgiving(token);
+            match pattern {
+                PathPattern::Exact(part) if wildcards.is_empty() => path.push(part),
+                pattern => wildcards.push(pattern),
             }
         }
+
+        // A trailing `/**` is a recursive rule.
+        let include_subtree = wildcards.last() == Some(&PathPattern::AnyParts);
+        if include_subtree {
+            wildcards.pop();
+        }
+
+        Self {
+            raw_expression,
+            path: EntityPath::from(path),
+            include_subtree,
+            wildcards,
+        }
     }
 
     #[inline]
     pub fn matches(&self, path: &EntityPath) -> bool {
-        if self.include_subtree {
+        if !self.wildcards.is_empty() {
+            path.starts_with(&self.path)
+                && matches_parts(
+                    &self.wildcards,
+                    &path.as_slice()[self.path.len()..],
+                    self.include_subtree,
+                )
+        } else if self.include_subtree {
             path.starts_with(&self.path)
         } else {
             path == &self.path
         }
     }
+
+    /// Does this rule match everything that `other` matches?
+    ///
+    /// This is conservative: it may return `false` even though it does.
+    fn covers(&self, other: &Self) -> bool {
+        covers_pattern(&self.full_pattern(), &other.full_pattern())
+    }
+
+    /// Could this rule and `other` match the same path?
+    ///
+    /// This is conservative: it may return `true` even though they can't.
+    fn overlaps(&self, other: &Self) -> bool {
+        overlaps_pattern(&self.full_pattern(), &other.full_pattern())
+    }
+
+    /// The whole rule as a pattern, with a trailing [`PathPattern::AnyParts`] for recursive rules.
+    fn full_pattern(&self) -> Vec<PathPattern> {
+        self.path
+            .iter()
+            .cloned()
+            .map(PathPattern::Exact)
+            .chain(self.wildcards.iter().cloned())
+            .chain(self.include_subtree.then_some(PathPattern::AnyParts))
+            .collect()
+    }
+
+    /// Is this a rule without wildcards for exactly this path (ignoring subtree)?
+    fn is_for_exactly(&self, path: &EntityPath) -> bool {
+        self.wildcards.is_empty() && self.path == *path
+    }
+
+    /// Could this rule match `path`, or anything in its subtree?
+    fn may_match_in_subtree(&self, path: &EntityPath) -> bool {
+        if self.path.starts_with(path) {
+            true
+        } else if path.starts_with(&self.path) {
+            may_match_parts(
+                &self.wildcards,
+                &path.as_slice()[self.path.len()..],
+                self.include_subtree,
+            )
+        } else {
+            false
+        }
+    }
+}
+
+/// Do these `parts` match the `pattern`?
+///
+/// If `include_subtree` is set, `parts` may have more parts than needed to match `pattern`.
+fn matches_parts(pattern: &[PathPattern], parts: &[EntityPathPart], include_subtree: bool) -> bool {
+    match pattern.split_first() {
+        None => include_subtree || parts.is_empty(),
+        Some((PathPattern::AnyParts, rest)) => {
+            (0..=parts.len()).any(|skip| matches_parts(rest, &parts[skip..], include_subtree))
+        }
+        Some((pattern_part, rest)) => parts.split_first().is_some_and(|(part, parts)| {
+            pattern_part.matches(part) && matches_parts(rest, parts, include_subtree)
+        }),
+    }
+}
+
+/// Could these `parts`, or any of their descendants, match the `pattern`?
+fn may_match_parts(
+    pattern: &[PathPattern],
+    parts: &[EntityPathPart],
+    include_subtree: bool,
+) -> bool {
+    let Some((part, rest_of_parts)) = parts.split_first() else {
+        // We can always add more parts to match the rest of the pattern.
+        return true;
+    };
+
+    match pattern.split_first() {
+        None => include_subtree,
+        Some((PathPattern::AnyParts, rest)) => {
+            may_match_parts(rest, parts, include_subtree)
+                || may_match_parts(pattern, rest_of_parts, include_subtree)
+        }
+        Some((pattern_part, rest)) => {
+            pattern_part.matches(part) && may_match_parts(rest, rest_of_parts, include_subtree)
+        }
+    }
+}
+
+/// Does `pattern` match everything that `other` matches?
+///
+/// This is conservative: it may return `false` even though it does.
+fn covers_pattern(pattern: &[PathPattern], other: &[PathPattern]) -> bool {
+    match (pattern.split_first(), other.split_first()) {
+        (None, None) => true,
+        (Some((PathPattern::AnyParts, rest)), _) => {
+            covers_pattern(rest, other)
+                || other
+                    .split_first()
+                    .is_some_and(|(_, other_rest)| covers_pattern(pattern, other_rest))
+        }
+        (Some((part, rest)), Some((other_part, other_rest))) => {
+            part.covers(other_part) && covers_pattern(rest, other_rest)
+        }
+        (None, Some(_)) | (Some(_), None) => false,
+    }
+}
+
+/// Could `pattern` and `other` match the same path?
+///
+/// This is conservative: it may return `true` even though they can't.
+fn overlaps_pattern(pattern: &[PathPattern], other: &[PathPattern]) -> bool {
+    match (pattern.split_first(), other.split_first()) {
+        (None, None) => true,
+        (Some((PathPattern::AnyParts, rest)), _) => {
+            overlaps_pattern(rest, other)
+                || other
+                    .split_first()
+                    .is_some_and(|(_, other_rest)| overlaps_pattern(pattern, other_rest))
+        }
+        (_, Some((PathPattern::AnyParts, _))) => overlaps_pattern(other, pattern),
+        (Some((part, rest)), Some((other_part, other_rest))) => {
+            part.overlaps(other_part) && overlaps_pattern(rest, other_rest)
+        }
+        (None, Some(_)) | (Some(_), None) => false,
+    }
+}
+
+impl PathPattern {
+    /// Parses a single (escaped) part of a rule.
+    fn parse_forgiving(token: &str) -> Self {
+        if token == "**" {
+            return Self::AnyParts;
+        }
+
+        // Split on the `*` that aren't escaped.
+        let mut pieces = vec![String::new()];
+        let mut chars = token.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '*' => pieces.push(String::new()),
+                '\\' => {
+                    let last = pieces.last_mut().expect("never empty");
+                    last.push(c);
+                    last.extend(chars.next());
+                }
+                c => pieces.last_mut().expect("never empty").push(c),
+            }
+        }
+
+        let mut pieces = pieces.iter().map(|piece| {
+            EntityPathPart::parse_forgiving(piece)
+                .unescaped_str()
+                .to_owned()
+        });
+        if pieces.len() == 1 {
+            Self::Exact(EntityPathPart::new(pieces.next().unwrap_or_default()))
+        } else {
+            Self::Glob(pieces.collect())
+        }
+    }
+
+    /// Does this match all the parts that `other` matches?
+    ///
+    /// Only meant for single parts, i.e. anything but [`Self::AnyParts`].
+    fn covers(&self, other: &Self) -> bool {
+        match (self, other) {
+            (_, Self::Exact(part)) => self.matches(part),
+            (Self::Glob(pieces), Self::Glob(other_pieces)) => {
+                pieces.iter().all(String::is_empty) || pieces == other_pieces
+            }
+            (Self::AnyParts, Self::Glob(_)) => true,
+            (_, Self::AnyParts) | (Self::Exact(_), Self::Glob(_)) => false,
+        }
+    }
+
+    /// Could this and `other` match the same part?
+    fn overlaps(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Exact(part), other) | (other, Self::Exact(part)) => other.matches(part),
+            (Self::AnyParts | Self::Glob(_), Self::AnyParts | Self::Glob(_)) => true,
+        }
+    }
+
+    /// Does this match the given part? Always `true` for [`Self::AnyParts`].
+    fn matches(&self, part: &EntityPathPart) -> bool {
+        match self {
+            Self::AnyParts => true,
+            Self::Exact(exact) => exact == part,
+            Self::Glob(pieces) => {
+                let mut name = part.unescaped_str();
+
+                let (Some(first), Some(last)) = (pieces.first(), pieces.last()) else {
+                    return true;
+                };
+                let Some(rest) = name.strip_prefix(first.as_str()) else {
+                    return false;
+                };
+                name = rest;
+
+                // The pieces in between are matched greedily, leftmost first.
+                for piece in &pieces[1..pieces.len() - 1] {
+                    let Some(index) = name.find(piece.as_str()) else {
+                        return false;
+                    };
+                    name = &name[index + piece.len()..];
+                }
+
+                name.ends_with(last.as_str())
+            }
+        }
+    }
 }
 
 impl std::cmp::Ord for EntityPathRule {
     /// Most specific last, which means recursive first.
     #[inline]
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        (&self.path, !self.include_subtree).cmp(&(&other.path, !other.include_subtree))
+        // Rules with wildcards come in between the recursive and non-recursive rules of their path.
+        fn rank(rule: &EntityPathRule) -> u8 {
+            match (rule.wildcards.is_empty(), rule.include_subtree) {
+                (true, true) => 0,
+                (false, _) => 1,
+                (true, false) => 2,
+            }
+        }
+
+        (
+            &self.path,
+            rank(self),
+            &self.wildcards,
+            !self.include_subtree,
+        )
+            .cmp(&(
+                &other.path,
+                rank(other),
+                &other.wildcards,
+                !other.include_subtree,
+            ))
     }
 }
 
@@ -513,6 +837,19 @@ mod tests {
         ];
         let rules = rules.map(|rule| EntityPathRule::parse_forgiving(rule, &subst_env));
         check_total_order(&rules);
+
+        let rules = [
+            "/world/**",
+            "/world/**/wheel",
+            "/world/*/**",
+            "/world/*",
+            "/world/car_*",
+            "/world/car_*/wheel",
+            "/world",
+            "/world/car",
+        ];
+        let rules = rules.map(|rule| EntityPathRule::parse_forgiving(rule, &subst_env));
+        check_total_order(&rules);
     }
 
     #[test]
@@ -633,6 +970,100 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_entity_path_filter_wildcards() {
+        let subst_env = Default::default();
+
+        for (rule, path, expected) in [
+            ("/fleet/*/lidar", "/fleet/truck/lidar", true),
+            ("/fleet/*/lidar", "/fleet/lidar", false),
+            ("/fleet/*/lidar", "/fleet/truck/trailer/lidar", false),
+            ("/fleet/*/lidar", "/fleet/truck/lidar/points", false),
+            ("/robot_*/camera/**", "/robot_1/camera", true),
+            ("/robot_*/camera/**", "/robot_1/camera/image", true),
+            ("/robot_*/camera/**", "/robot_/camera", true),
+            ("/robot_*/camera/**", "/robot/camera", false),
+            ("/robot_*/camera/**", "/robot_1/lidar", false),
+            ("/*_arm_*/gripper", "/left_arm_2/gripper", true),
+            ("/*_arm_*/gripper", "/leftarm/gripper", false),
+            ("/world/**/wheel", "/world/wheel", true),
+            ("/world/**/wheel", "/world/car/front/wheel", true),
+            ("/world/**/wheel", "/world/car/front/wheel/nut", false),
+            ("/world/**/wheel", "/wheel", false),
+            ("/**/wheel/**", "/car/wheel/nut", true),
+            (r"/world/\*", "/world/car", false),
+            (r"/world/\*", r"/world/\*", true),
+        ] {
+            let rule = EntityPathRule::parse_forgiving(rule, &subst_env);
+            assert_eq!(
+                rule.matches(&EntityPath::parse_forgiving(path)),
+                expected,
+                "rule: {:?}, path: {path:?}",
+                rule.raw_expression,
+            );
+        }
+
+        let filter = EntityPathFilter::parse_forgiving(
+            r#"
+        + /fleet/**
+        - /fleet/*/lidar/**
+        + /fleet/truck/lidar
+        "#,
+            &subst_env,
+        );
+
+        for (path, expected_effect) in [
+            ("/fleet", Some(RuleEffect::Include)),
+            ("/fleet/car/lidar", Some(RuleEffect::Exclude)),
+            ("/fleet/car/lidar/points", Some(RuleEffect::Exclude)),
+            ("/fleet/truck/lidar", Some(RuleEffect::Include)),
+            ("/fleet/truck/lidar/points", Some(RuleEffect::Exclude)),
+            ("/fleet/truck/camera", Some(RuleEffect::Include)),
+        ] {
+            assert_eq!(
+                filter.most_specific_match(&EntityPath::from(path)),
+                expected_effect,
+                "path: {path:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_entity_path_filter_wildcards_subtree() {
+        let subst_env = Default::default();
+
+        let filter = EntityPathFilter::parse_forgiving(
+            r#"
+        + /robot_*/camera/**
+        + /world/**/wheel
+        - /robot_2/**
+        "#,
+            &subst_env,
+        );
+
+        for (path, expected) in [
+            ("/", true),
+            ("/robot_1", true),
+            ("/robot_1/camera", true),
+            ("/robot_1/camera/image", true),
+            ("/robot_1/lidar", false),
+            ("/robot_2", false),
+            ("/robot_2/camera", false),
+            ("/robot", false),
+            ("/world", true),
+            ("/world/car/front", true),
+            ("/world/car/front/wheel", true),
+            ("/world/car/front/wheel/nut", true), // e.g. `/world/car/front/wheel/nut/wheel`
+            ("/elsewhere", false),
+        ] {
+            assert_eq!(
+                filter.is_anything_in_subtree_included(&EntityPath::from(path)),
+                expected,
+                "path: {path:?}",
+            );
+        }
+    }
+
     #[test]
     fn test_is_superset_of() {
         let subst_env = Default::default();
@@ -695,6 +1126,25 @@ mod tests {
                 contains: ["+ /a", "+ /a/**", "+ /b"].into(),
                 not_contains: ["+ /b/**", "+ /b/c", "+ /b/c/d"].into(),
             },
+            TestCase {
+                filter: "+ /robot_*/camera/**",
+                contains: [
+                    "+ /robot_*/camera/**",
+                    "+ /robot_1/camera",
+                    "+ /robot_1/camera/**",
+                    "+ /robot_1/camera/*/depth",
+                ]
+                .into(),
+                not_contains: ["+ /robot_1/**", "+ /*/camera", "+ /robot/camera"].into(),
+            },
+            TestCase {
+                filter: r#"
+                + /**
+                - /*/lidar
+                "#,
+                contains: ["+ /a", "+ /a/camera", "+ /a/lidar/points"].into(),
+                not_contains: ["+ /a/**", "+ /a/lidar", "+ /**/lidar"].into(),
+            },
         ];
 
         for case in &cases {
@@ -14,7 +14,9 @@ mod parse_path;
 pub use component_path::ComponentPath;
 pub use data_path::DataPath;
 pub use entity_path::{EntityPath, EntityPathHash};
-pub use entity_path_filter::{EntityPathFilter, EntityPathRule, EntityPathSubs, RuleEffect};
+pub use entity_path_filter::{
+    EntityPathFilter, EntityPathRule, EntityPathSubs, PathPattern, RuleEffect,
+};
 pub use entity_path_part::EntityPathPart;
 pub use parse_path::PathParseError;
 
@@ -263,7 +263,7 @@ fn join(tokens: &[&str]) -> String {
 }
 
 /// `"/foo/bar"` -> `["/", "foo", "/", "bar"]`
-fn tokenize_entity_path(path: &str) -> Vec<&str> {
+pub(crate) fn tokenize_entity_path(path: &str) -> Vec<&str> {
     tokenize_by(path, &[b'/'])
 }
 
@@ -0,0 +1,94 @@
+//! Remote control of the viewer: commands sent to the viewer, and messages it sends back.
+
+use crate::{EntityPath, Instance, StoreId, TimeInt, Timeline};
+
+/// Tells the viewer what to show, e.g. for scripted reviews of a recording.
+///
+/// Sent as part of [`crate::LogMsg::ViewerCommand`], and applied to the recording it refers to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ViewerCommand {
+    /// Make this the active timeline, and optionally move the time cursor.
+    SetTime {
+        timeline: Timeline,
+        time: Option<TimeInt>,
+    },
+
+    /// Start or stop playback.
+    SetPlaying(bool),
+
+    /// Select an entity, or one instance of it, or clear the selection with `None`.
+    Select(Option<(EntityPath, Instance)>),
+
+    /// Select and focus the space view with this id.
+    FocusSpaceView(uuid::Uuid),
+
+    /// Start or stop sending [`ViewerEvent`]s about this recording to the SDK that sent this.
+    ///
+    /// Only viewers connected over TCP can do this.
+    SetEventsEnabled(bool),
+
+    /// Take a screenshot of the viewer, and send it back as a [`ViewerMessage::Screenshot`].
+    ///
+    /// Only native viewers connected over TCP can do this.
+    Screenshot {
+        /// Echoed back in the [`ViewerMessage::Screenshot`], to match it with this request.
+        request_id: u64,
+    },
+}
+
+/// A message from the viewer to a connected SDK.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ViewerMessage {
+    /// The screenshot requested with [`ViewerCommand::Screenshot`].
+    Screenshot {
+        request_id: u64,
+
+        /// PNG-encoded image of the whole viewer window.
+        #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
+        png: Vec<u8>,
+    },
+
+    /// Something happened in the viewer, see [`ViewerCommand::SetEventsEnabled`].
+    Event {
+        /// The recording this is about.
+        store_id: StoreId,
+        event: ViewerEvent,
+    },
+}
+
+/// Something the user did in the viewer.
+///
+/// Only sent while the recording is the one shown in the viewer.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ViewerEvent {
+    /// The selected entities and instances changed.
+    ///
+    /// Anything else that is selected, e.g. a space view, is left out.
+    SelectionChanged(Vec<(EntityPath, Instance)>),
+
+    /// The mouse moved onto another entity or instance, or away from all of them.
+    HoverChanged(Option<(EntityPath, Instance)>),
+
+    /// The time cursor moved, or another timeline became active.
+    TimeChanged {
+        timeline: Timeline,
+        time: Option<TimeInt>,
+    },
+
+    /// An entity was selected by clicking on it in a spatial view.
+    Picked {
+        entity_path: EntityPath,
+        instance: Instance,
+
+        /// The space that was clicked in.
+        space: EntityPath,
+
+        /// Where it was clicked, in the coordinates of `space`.
+        ///
+        /// In 2D spaces, the third coordinate is the depth.
+        position: [f32; 3],
+    },
+}
@@ -1,9 +1,19 @@
-use std::{fmt, net::SocketAddr, thread::JoinHandle};
+use std::{
+    fmt,
+    net::SocketAddr,
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
 
 use crossbeam::channel::{select, Receiver, Sender};
 
 use re_log_types::LogMsg;
 
+use crate::{
+    spool::Spool, tcp_client::TcpClient, ClientOptions, CongestionMonitor, CongestionReport,
+    SpoolOptions, ViewerMessages,
+};
+
 #[derive(Debug, PartialEq, Eq)]
 struct FlushedMsg;
 
@@ -45,6 +55,8 @@ pub struct Client {
     send_quit_tx: Sender<InterruptMsg>,
     encode_join: Option<JoinHandle<()>>,
     send_join: Option<JoinHandle<()>>,
+    congestion: CongestionMonitor,
+    viewer_messages: Option<ViewerMessages>,
 
     /// Only used for diagnostics, not for communication after `new()`.
     addr: SocketAddr,
@@ -58,6 +70,17 @@ impl Client {
     /// cause a call to `flush` to block indefinitely if a connection cannot be
     /// established.
     pub fn new(addr: SocketAddr, flush_timeout: Option<std::time::Duration>) -> Self {
+        Self::with_options(addr, flush_timeout, ClientOptions::default())
+    }
+
+    /// Connect via TCP to this log server, e.g. with an auth token or over TLS.
+    ///
+    /// See [`Self::new`] for `flush_timeout`.
+    pub fn with_options(
+        addr: SocketAddr,
+        flush_timeout: Option<std::time::Duration>,
+        options: ClientOptions,
+    ) -> Self {
         re_log::debug!("Connecting to remote {addr}…");
 
         // TODO(emilk): keep track of how much memory is in each pipe
@@ -73,6 +96,10 @@ impl Client {
         // can be expensive, see https://github.com/rerun-io/rerun/issues/2216
         let encoding_options = re_log_encoding::EncodingOptions::UNCOMPRESSED;
 
+        let congestion = CongestionMonitor::default();
+        let viewer_messages = ViewerMessages::default();
+        let accepts_viewer_messages = options.viewer_messages;
+
         let encode_join = std::thread::Builder::new()
             .name("msg_encoder".into())
             .spawn(move || {
@@ -82,8 +109,25 @@ impl Client {
 
         let send_join = std::thread::Builder::new()
             .name("tcp_sender".into())
-            .spawn(move || {
-                tcp_sender(addr, flush_timeout, &packet_rx, &send_quit_rx, &flushed_tx);
+            .spawn({
+                let spool_options = options.spool.clone();
+                let mut tcp_client = TcpClient::new(
+                    addr,
+                    flush_timeout,
+                    options,
+                    congestion.clone(),
+                    viewer_messages.clone(),
+                );
+                move || {
+                    tcp_sender(
+                        &mut tcp_client,
+                        spool_options,
+                        &packet_rx,
+                        &send_quit_rx,
+                        &flushed_tx,
+                    );
+                    tcp_client.close();
+                }
             })
             .expect("Failed to spawn thread");
 
@@ -94,6 +138,8 @@ impl Client {
             send_quit_tx,
             encode_join: Some(encode_join),
             send_join: Some(send_join),
+            congestion,
+            viewer_messages: accepts_viewer_messages.then_some(viewer_messages),
             addr,
         }
     }
@@ -121,6 +167,26 @@ impl Client {
         }
     }
 
+    /// How well the server keeps up with our messages, according to its latest report.
+    ///
+    /// Only available if [`ClientOptions::congestion_reports`] was set, and we've recently sent
+    /// messages to a server that supports it.
+    pub fn congestion(&self) -> Option<CongestionReport> {
+        self.congestion.report()
+    }
+
+    /// Gives access to [`Self::congestion`] from elsewhere.
+    pub fn congestion_monitor(&self) -> &CongestionMonitor {
+        &self.congestion
+    }
+
+    /// The messages the viewer sends us, e.g. screenshots.
+    ///
+    /// `None` unless [`ClientOptions::viewer_messages`] was set.
+    pub fn viewer_messages(&self) -> Option<&ViewerMessages> {
+        self.viewer_messages.as_ref()
+    }
+
     /// Switch to a mode where we drop messages if disconnected.
     ///
     /// Calling this before a flush (or drop) ensures we won't get stuck trying to send
@@ -177,7 +243,9 @@ fn msg_encode(
 
                 let packet_msg = match &msg_msg {
                     MsgMsg::LogMsg(log_msg) => {
-                        match re_log_encoding::encoder::encode_to_bytes(
+                        // The viewer we're connected to applies the commands, but they're dropped
+                        // from the spool, like from any `.rrd` file.
+                        match re_log_encoding::encoder::encode_to_bytes_for_viewer(
                             re_build_info::CrateVersion::LOCAL,
                             encoding_options, std::iter::once(log_msg),
                         ) {
@@ -210,13 +278,27 @@ fn msg_encode(
 }
 
 fn tcp_sender(
-    addr: SocketAddr,
-    flush_timeout: Option<std::time::Duration>,
+    tcp_client: &mut TcpClient,
+    spool_options: Option<SpoolOptions>,
     packet_rx: &Receiver<PacketMsg>,
     quit_rx: &Receiver<InterruptMsg>,
     flushed_tx: &Sender<FlushedMsg>,
 ) {
-    let mut tcp_client = crate::tcp_client::TcpClient::new(addr, flush_timeout);
+    if let Some(spool_options) = spool_options {
+        let dir = spool_options.dir.clone();
+        match Spool::new(spool_options) {
+            Ok(spool) => {
+                tcp_sender_with_spool(tcp_client, spool, packet_rx, quit_rx, flushed_tx);
+                return;
+            }
+            Err(err) => {
+                re_log::warn!(
+                    "Failed to open spool at {dir:?}: {err}. Messages will not be spooled."
+                );
+            }
+        }
+    }
+
     // Once this flag has been set, we will drop all messages if the tcp_client is
     // no longer connected.
     let mut drop_if_disconnected = false;
@@ -227,7 +309,7 @@ fn tcp_sender(
                 if let Ok(packet_msg) = packet_msg {
                     match packet_msg {
                         PacketMsg::Packet(packet) => {
-                            match send_until_success(&mut tcp_client, drop_if_disconnected, &packet, quit_rx) {
+                            match send_until_success(tcp_client, drop_if_disconnected, &packet, quit_rx) {
                                 Some(InterruptMsg::Quit) => {return;}
                                 Some(InterruptMsg::DropIfDisconnected) => {
                                     drop_if_disconnected = true;
@@ -261,13 +343,192 @@ fn tcp_sender(
                     re_log::debug!("Shutting down tcp_sender thread: quit_rx channel has closed");
                     return;
                 }
-            }}
+            }},
+            default(IDLE_POLL_INTERVAL) => {
+                tcp_client.poll();
+            }
+        }
+    }
+}
+
+/// How often we check for messages from the server while we have nothing to send.
+const IDLE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How often we try to reconnect while there are spooled messages.
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Like [`tcp_sender`], but instead of blocking while disconnected, the packets go to `spool`.
+fn tcp_sender_with_spool(
+    tcp_client: &mut TcpClient,
+    mut spool: Spool,
+    packet_rx: &Receiver<PacketMsg>,
+    quit_rx: &Receiver<InterruptMsg>,
+    flushed_tx: &Sender<FlushedMsg>,
+) {
+    let mut last_attempt = None;
+
+    // Once this flag has been set, flushing gives up on the spool while disconnected.
+    let mut drop_if_disconnected = false;
+
+    loop {
+        select! {
+            recv(packet_rx) -> packet_msg => {
+                let Ok(packet_msg) = packet_msg else {
+                    re_log::debug!("Shutting down tcp_sender thread: packet_rx channel has closed");
+                    break;
+                };
+                match packet_msg {
+                    PacketMsg::Packet(packet) => {
+                        if spool.is_empty() {
+                            if let Err(err) = tcp_client.send(&packet) {
+                                re_log::warn!("{err}. Spooling messages to {:?} until we reconnect.", spool.dir());
+                                last_attempt = Some(Instant::now());
+                                spool.push(&packet);
+                            }
+                        } else {
+                            // Keep the order: everything goes through the spool until it's drained.
+                            spool.push(&packet);
+                            replay_spool(tcp_client, &mut spool, &mut last_attempt);
+                        }
+                    }
+                    PacketMsg::Flush => {
+                        match flush_spool(
+                            tcp_client,
+                            &mut spool,
+                            &mut last_attempt,
+                            drop_if_disconnected,
+                            quit_rx,
+                        ) {
+                            Some(InterruptMsg::Quit) => break,
+                            Some(InterruptMsg::DropIfDisconnected) => {
+                                drop_if_disconnected = true;
+                            }
+                            None => {}
+                        }
+                        if spool.is_empty() {
+                            tcp_client.flush();
+                        }
+                        flushed_tx
+                            .send(FlushedMsg)
+                            .expect("Main thread should still be alive");
+                    }
+                }
+            },
+            recv(quit_rx) -> quit_msg => { match quit_msg {
+                // We never block on a disconnected server, except while flushing.
+                Ok(InterruptMsg::DropIfDisconnected) => {
+                    drop_if_disconnected = true;
+                }
+                Ok(InterruptMsg::Quit) => {
+                    re_log::debug!("Shutting down tcp_sender thread: received Quit message");
+                    break;
+                }
+                Err(_) => {
+                    re_log::debug!("Shutting down tcp_sender thread: quit_rx channel has closed");
+                    break;
+                }
+            }},
+            default(IDLE_POLL_INTERVAL) => {
+                replay_spool(tcp_client, &mut spool, &mut last_attempt);
+                tcp_client.poll();
+            }
+        }
+    }
+
+    if !spool.is_empty() {
+        re_log::warn!(
+            "{} of messages could not be sent. They are kept in {:?} and will be sent by the next client using that spool.",
+            re_format::format_bytes(spool.num_bytes() as _),
+            spool.dir()
+        );
+    }
+}
+
+/// Sends as much of the spool as we can, trying to reconnect at most once per [`RETRY_INTERVAL`].
+///
+/// Returns `true` if the spool is empty.
+fn replay_spool(
+    tcp_client: &mut TcpClient,
+    spool: &mut Spool,
+    last_attempt: &mut Option<Instant>,
+) -> bool {
+    if spool.is_empty() {
+        return true;
+    }
+
+    if !tcp_client.is_connected() {
+        if last_attempt.is_some_and(|time: Instant| time.elapsed() < RETRY_INTERVAL) {
+            return false;
+        }
+        *last_attempt = Some(Instant::now());
+        if let Err(err) = tcp_client.connect() {
+            re_log::debug!("Failed to reconnect: {err}");
+            return false;
+        }
+        re_log::info!(
+            "Reconnected. Sending {} of spooled messages…",
+            re_format::format_bytes(spool.num_bytes() as _)
+        );
+    }
+
+    let drained = spool.replay(|packet| tcp_client.send(packet).is_ok());
+    if drained {
+        re_log::info!("All spooled messages have been sent.");
+    } else {
+        *last_attempt = Some(Instant::now());
+    }
+    drained
+}
+
+/// Keeps sending the spool until it is drained, or the flush times out.
+///
+/// What's still spooled after that is safe on disk, and will be sent once we reconnect.
+fn flush_spool(
+    tcp_client: &mut TcpClient,
+    spool: &mut Spool,
+    last_attempt: &mut Option<Instant>,
+    mut drop_if_disconnected: bool,
+    quit_rx: &Receiver<InterruptMsg>,
+) -> Option<InterruptMsg> {
+    let mut interrupt = None;
+
+    // Try right away, even if we've only just failed to reconnect.
+    *last_attempt = None;
+
+    loop {
+        if replay_spool(tcp_client, spool, last_attempt) {
+            return interrupt;
+        }
+
+        if tcp_client.has_timed_out_for_flush()
+            || (drop_if_disconnected && !tcp_client.is_connected())
+        {
+            re_log::warn!(
+                "Flush timed out - {} of messages are still spooled in {:?}.",
+                re_format::format_bytes(spool.num_bytes() as _),
+                spool.dir()
+            );
+            return interrupt;
+        }
+
+        select! {
+            recv(quit_rx) -> quit_msg => match quit_msg {
+                Ok(InterruptMsg::DropIfDisconnected) => {
+                    drop_if_disconnected = true;
+                    interrupt = Some(InterruptMsg::DropIfDisconnected);
+                }
+                Ok(InterruptMsg::Quit) | Err(_) => {
+                    re_log::warn_once!("Quitting before the spool could be flushed.");
+                    return Some(InterruptMsg::Quit);
+                }
+            },
+            default(RETRY_INTERVAL) => {}
         }
     }
 }
 
 fn send_until_success(
-    tcp_client: &mut crate::tcp_client::TcpClient,
+    tcp_client: &mut TcpClient,
     drop_if_disconnected: bool,
     packet: &[u8],
     quit_rx: &Receiver<InterruptMsg>,
@@ -325,3 +586,80 @@ fn send_until_success(
         None
     }
 }
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use re_log_types::{
+        external::re_tuid::Tuid, ApplicationId, SetStoreInfo, StoreId, StoreInfo, StoreKind,
+        StoreSource, Time,
+    };
+
+    use super::*;
+
+    fn msg(name: &str) -> LogMsg {
+        LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: Tuid::new(),
+            info: StoreInfo {
+                application_id: ApplicationId(name.to_owned()),
+                store_id: StoreId::random(StoreKind::Recording),
+                cloned_from: None,
+                is_official_example: false,
+                started: Time::now(),
+                store_source: StoreSource::Unknown,
+                store_version: None,
+            },
+        })
+    }
+
+    /// Flushing waits for the spool to be sent, once the server shows up.
+    #[test]
+    fn flush_spool_after_reconnect() {
+        let dir = tempfile::tempdir().unwrap();
+
+        // A port nobody listens on, yet.
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let client = Client::with_options(
+            ([127, 0, 0, 1], port).into(),
+            None,
+            ClientOptions {
+                spool: Some(SpoolOptions {
+                    dir: dir.path().to_owned(),
+                    max_bytes: 1_000_000,
+                    overflow: Default::default(),
+                }),
+                ..Default::default()
+            },
+        );
+
+        for name in ["a", "b", "c"] {
+            client.send(msg(name));
+        }
+
+        // Wait for the spool to be used, then let the server show up.
+        let start = Instant::now();
+        while std::fs::read_dir(dir.path()).unwrap().next().is_none() {
+            assert!(
+                start.elapsed() < Duration::from_secs(10),
+                "nothing was spooled"
+            );
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        let rx = crate::serve("127.0.0.1", port, Default::default()).unwrap();
+        client.flush();
+
+        // Everything has been sent by now, so it's just a matter of the server catching up.
+        let mut received = Vec::new();
+        while received.len() < 3 {
+            let msg = rx.recv_timeout(Duration::from_secs(10)).unwrap();
+            if let Some(LogMsg::SetStoreInfo(msg)) = msg.data() {
+                received.push(msg.info.application_id.0.clone());
+            }
+        }
+        assert_eq!(["a", "b", "c"], received.as_slice());
+    }
+}
@@ -0,0 +1,151 @@
+//! The server telling its clients how well it keeps up, see [`CongestionReport`].
+
+/// How well a server keeps up with the messages it receives, as last reported to a client.
+///
+/// Servers send these to clients using [`crate::PROTOCOL_VERSION_3`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CongestionReport {
+    /// How long messages wait in the server before being ingested, e.g. by the viewer.
+    pub latency_sec: f32,
+
+    /// How many messages are waiting to be ingested.
+    pub queue_len: u32,
+
+    /// Above this latency, the server starts dropping messages.
+    ///
+    /// Infinite if the server never drops messages.
+    pub max_latency_sec: f32,
+
+    /// The fraction of messages the server currently keeps, in `(0, 1]`.
+    ///
+    /// Anything below 1 means messages are being dropped.
+    pub accept_rate: f32,
+}
+
+impl CongestionReport {
+    /// Size of a report on the wire.
+    pub(crate) const NUM_BYTES: usize = 16;
+
+    /// Is the server dropping messages, or getting close to it?
+    ///
+    /// Servers that never drop messages are considered congested above one second of latency.
+    pub fn is_congested(&self) -> bool {
+        let latency_threshold = if self.max_latency_sec.is_finite() {
+            0.5 * self.max_latency_sec
+        } else {
+            1.0
+        };
+        self.accept_rate < 1.0 || latency_threshold < self.latency_sec
+    }
+
+    #[cfg(feature = "server")]
+    pub(crate) fn to_bytes(self) -> [u8; Self::NUM_BYTES] {
+        let mut bytes = [0_u8; Self::NUM_BYTES];
+        bytes[0..4].copy_from_slice(&self.latency_sec.to_le_bytes());
+        bytes[4..8].copy_from_slice(&self.queue_len.to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.max_latency_sec.to_le_bytes());
+        bytes[12..16].copy_from_slice(&self.accept_rate.to_le_bytes());
+        bytes
+    }
+
+    #[cfg(feature = "client")]
+    pub(crate) fn from_bytes(bytes: [u8; Self::NUM_BYTES]) -> Self {
+        let field = |i: usize| [bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]];
+        Self {
+            latency_sec: f32::from_le_bytes(field(0)),
+            queue_len: u32::from_le_bytes(field(4)),
+            max_latency_sec: f32::from_le_bytes(field(8)),
+            accept_rate: f32::from_le_bytes(field(12)),
+        }
+    }
+}
+
+/// Gives access to the latest [`CongestionReport`] received by a [`crate::Client`].
+///
+/// Cheap to clone, and can be read from any thread.
+#[cfg(feature = "client")]
+#[derive(Clone, Default)]
+pub struct CongestionMonitor {
+    latest: std::sync::Arc<parking_lot::Mutex<Option<(CongestionReport, std::time::Instant)>>>,
+}
+
+#[cfg(feature = "client")]
+impl CongestionMonitor {
+    /// Reports older than this are considered outdated.
+    ///
+    /// Servers only report while receiving messages, so this also covers an idle connection.
+    pub const MAX_AGE: std::time::Duration = std::time::Duration::from_secs(2);
+
+    /// The latest report, unless it is outdated or the server never sent one.
+    pub fn report(&self) -> Option<CongestionReport> {
+        let latest = *self.latest.lock();
+        latest
+            .filter(|(_, time)| time.elapsed() < Self::MAX_AGE)
+            .map(|(report, _)| report)
+    }
+
+    pub(crate) fn set(&self, report: CongestionReport) {
+        *self.latest.lock() = Some((report, std::time::Instant::now()));
+    }
+}
+
+#[cfg(feature = "client")]
+impl std::fmt::Debug for CongestionMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CongestionMonitor")
+            .field("report", &self.report())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "client", feature = "server"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let reports = [
+            CongestionReport {
+                latency_sec: 0.25,
+                queue_len: 1234,
+                max_latency_sec: f32::INFINITY,
+                accept_rate: 1.0,
+            },
+            CongestionReport {
+                latency_sec: 0.0,
+                queue_len: u32::MAX,
+                max_latency_sec: 0.5,
+                accept_rate: 0.125,
+            },
+        ];
+        for report in reports {
+            assert_eq!(report, CongestionReport::from_bytes(report.to_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_is_congested() {
+        let report = CongestionReport {
+            latency_sec: 0.1,
+            queue_len: 0,
+            max_latency_sec: f32::INFINITY,
+            accept_rate: 1.0,
+        };
+        assert!(!report.is_congested());
+        assert!(CongestionReport {
+            latency_sec: 1.5,
+            ..report
+        }
+        .is_congested());
+        assert!(CongestionReport {
+            accept_rate: 0.9,
+            ..report
+        }
+        .is_congested());
+        assert!(CongestionReport {
+            max_latency_sec: 0.1,
+            ..report
+        }
+        .is_congested());
+    }
+}
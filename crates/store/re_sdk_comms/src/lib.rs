@@ -11,13 +11,34 @@ pub(crate) mod tcp_client;
 mod buffered_client;
 
 #[cfg(feature = "client")]
-pub use {buffered_client::Client, tcp_client::ClientError};
+mod spool;
+
+#[cfg(feature = "client")]
+pub use {
+    buffered_client::Client,
+    spool::{SpoolOptions, SpoolOverflow},
+    tcp_client::{ClientError, ClientOptions},
+};
 
 #[cfg(feature = "server")]
 mod server;
 
 #[cfg(feature = "server")]
-pub use server::{serve, ServerError, ServerOptions};
+pub use server::{serve, serve_with_viewer_clients, ServerError, ServerOptions};
+
+mod congestion;
+mod connection;
+mod tls;
+mod viewer_messages;
+
+#[cfg(feature = "client")]
+pub use congestion::CongestionMonitor;
+pub use congestion::CongestionReport;
+pub use connection::{constant_time_eq, Stream};
+pub use tls::{ClientTlsOptions, ServerTlsOptions, TlsError};
+pub use viewer_messages::ViewerClients;
+#[cfg(feature = "client")]
+pub use viewer_messages::ViewerMessages;
 
 /// Server connection error.
 ///
@@ -30,6 +51,12 @@ pub enum ConnectionError {
     #[error("An unknown client tried to connect")]
     UnknownClient,
 
+    #[error("Client did not provide the auth token required by this server")]
+    Unauthenticated,
+
+    #[error("Client provided the wrong auth token")]
+    InvalidAuthToken,
+
     #[error(transparent)]
     VersionError(#[from] VersionError),
 
@@ -65,9 +92,49 @@ pub const PROTOCOL_VERSION_0: u16 = 0;
 /// Added [`PROTOCOL_HEADER`]. Introduced for Rerun 0.16.
 pub const PROTOCOL_VERSION_1: u16 = 1;
 
+/// Added an auth token after the [`PROTOCOL_HEADER`], answered by the server with
+/// [`AUTH_ACCEPTED`] or [`AUTH_REJECTED`]. Introduced for Rerun 0.18.
+///
+/// Clients only use this version when they have a token, so they can still talk to older servers.
+pub const PROTOCOL_VERSION_2: u16 = 2;
+
+/// Same as [`PROTOCOL_VERSION_2`], except the auth token is always sent (possibly empty),
+/// and after accepting it the server regularly sends [`CongestionReport`]s back to the client.
+/// Introduced for Rerun 0.18.
+///
+/// Clients only use this version when they ask for the reports.
+pub const PROTOCOL_VERSION_3: u16 = 3;
+
+/// Same as [`PROTOCOL_VERSION_3`], except that everything the server sends after accepting the
+/// auth token is framed as a kind byte ([`SERVER_MSG_CONGESTION_REPORT`] or
+/// [`SERVER_MSG_VIEWER`]) followed by a `u32` length and the payload.
+/// The server sends these at any time, not only in response to a packet.
+/// Introduced for Rerun 0.18.
+///
+/// Clients only use this version when they ask for [`re_log_types::ViewerMessage`]s.
+pub const PROTOCOL_VERSION_4: u16 = 4;
+
+/// A [`PROTOCOL_VERSION_4`] message with a [`CongestionReport`].
+pub const SERVER_MSG_CONGESTION_REPORT: u8 = 0;
+
+/// A [`PROTOCOL_VERSION_4`] message with a MsgPack-encoded [`re_log_types::ViewerMessage`].
+pub const SERVER_MSG_VIEWER: u8 = 1;
+
 /// Comes after version.
 pub const PROTOCOL_HEADER: &str = "rerun";
 
+/// Sent by the server after reading the auth token of a [`PROTOCOL_VERSION_2`] or later client.
+pub const AUTH_ACCEPTED: u8 = 1;
+
+/// Sent by the server after reading the auth token of a [`PROTOCOL_VERSION_2`] or later client,
+/// right before closing the connection.
+///
+/// Also sent to older clients, which can't send a token, when the server requires one.
+pub const AUTH_REJECTED: u8 = 0;
+
+/// Longer auth tokens are rejected without reading them.
+pub const MAX_AUTH_TOKEN_LEN: usize = 1024;
+
 pub const DEFAULT_SERVER_PORT: u16 = 9876;
 
 /// The default address of a Rerun TCP server which an SDK connects to.
@@ -0,0 +1,521 @@
+//! Spilling unsent packets to disk while disconnected, see [`SpoolOptions`].
+
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::PathBuf,
+};
+
+use re_log_encoding::{
+    decoder::{DecodeError, Decoder, VersionPolicy},
+    encoder::{EncodeError, Encoder},
+    EncodingOptions,
+};
+
+#[derive(thiserror::Error, Debug)]
+enum SpoolError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Encode(#[from] EncodeError),
+
+    #[error(transparent)]
+    Decode(#[from] DecodeError),
+}
+
+/// Keep messages in `.rrd` files while disconnected from the server, and send them once we
+/// reconnect.
+///
+/// Messages are always sent in order: once something has been spooled, all newer messages go
+/// through the spool too, until it has been drained.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpoolOptions {
+    /// Where to keep the spool.
+    ///
+    /// Any spool left over by a previous run is sent first, so don't share a directory between
+    /// clients that are running at the same time.
+    pub dir: PathBuf,
+
+    /// Upper limit on the total size of the spool on disk, in bytes.
+    pub max_bytes: u64,
+
+    /// What to do once the spool is full.
+    pub overflow: SpoolOverflow,
+}
+
+/// Which messages to lose once the spool is full.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SpoolOverflow {
+    /// Delete the oldest part of the spool to make room, so that the latest data makes it.
+    #[default]
+    DropOldest,
+
+    /// Stop spooling, keeping the oldest data.
+    DropNewest,
+}
+
+/// The spool is split into this many files, so that the oldest part can be dropped at once.
+const NUM_SEGMENTS: u64 = 8;
+
+/// One `.rrd` file of the spool.
+struct Segment {
+    path: PathBuf,
+    num_bytes: u64,
+}
+
+/// The packets that couldn't be sent yet, oldest first.
+pub(crate) struct Spool {
+    options: SpoolOptions,
+
+    /// Oldest first. If [`Self::writer`] is set, it's writing to the last one.
+    segments: VecDeque<Segment>,
+
+    /// If set, it's reading from the first of [`Self::segments`].
+    reader: Option<Decoder<BufReader<File>>>,
+
+    writer: Option<Encoder<BufWriter<File>>>,
+
+    /// A packet from the spool that we failed to send, to be sent first next time.
+    unsent: Option<Vec<u8>>,
+
+    next_segment_id: u64,
+}
+
+impl Spool {
+    /// Picks up any spool left over in the directory.
+    pub fn new(options: SpoolOptions) -> std::io::Result<Self> {
+        std::fs::create_dir_all(&options.dir)?;
+
+        let mut segments = Vec::new();
+        for entry in std::fs::read_dir(&options.dir)? {
+            let path = entry?.path();
+            let id = path
+                .extension()
+                .filter(|extension| *extension == "rrd")
+                .and_then(|_| path.file_stem()?.to_str()?.parse::<u64>().ok());
+            if let Some(id) = id {
+                let num_bytes = std::fs::metadata(&path)?.len();
+                segments.push((id, Segment { path, num_bytes }));
+            }
+        }
+        segments.sort_by_key(|(id, _)| *id);
+
+        let next_segment_id = segments.last().map_or(0, |(id, _)| id + 1);
+        let spool = Self {
+            options,
+            segments: segments.into_iter().map(|(_, segment)| segment).collect(),
+            reader: None,
+            writer: None,
+            unsent: None,
+            next_segment_id,
+        };
+
+        if !spool.is_empty() {
+            re_log::info!(
+                "Found {} of unsent messages in {:?}, sending them first",
+                re_format::format_bytes(spool.num_bytes() as _),
+                spool.options.dir
+            );
+        }
+
+        Ok(spool)
+    }
+
+    pub fn dir(&self) -> &std::path::Path {
+        &self.options.dir
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty() && self.unsent.is_none()
+    }
+
+    /// Approximate size of the spool on disk.
+    pub fn num_bytes(&self) -> u64 {
+        self.segments.iter().map(|segment| segment.num_bytes).sum()
+    }
+
+    /// Appends an encoded packet, as sent to the server, unless the spool is full.
+    pub fn push(&mut self, packet: &[u8]) {
+        let packet_len = packet.len() as u64;
+        if self.options.max_bytes < packet_len {
+            // Don't throw away the whole spool for nothing.
+            re_log::warn_once!("Message is larger than the spool - dropping it.");
+            return;
+        }
+
+        let is_full = |spool: &Self| spool.options.max_bytes < spool.num_bytes() + packet_len;
+
+        if is_full(self) {
+            match self.options.overflow {
+                SpoolOverflow::DropOldest => {
+                    while is_full(self) && !self.segments.is_empty() {
+                        self.remove_oldest_segment();
+                    }
+                }
+                SpoolOverflow::DropNewest => {
+                    re_log::warn_once!(
+                        "Spool at {:?} is full - dropping new messages.",
+                        self.options.dir
+                    );
+                    return;
+                }
+            }
+        }
+
+        if let Err(err) = self.append(packet) {
+            re_log::error_once!("Failed to spool message to {:?}: {err}", self.options.dir);
+        }
+    }
+
+    fn append(&mut self, packet: &[u8]) -> Result<(), SpoolError> {
+        let segment_max_bytes = (self.options.max_bytes / NUM_SEGMENTS).max(1);
+        let is_segment_full = self
+            .segments
+            .back()
+            .map_or(true, |segment| segment_max_bytes <= segment.num_bytes);
+        if is_segment_full {
+            self.close_writer();
+        }
+
+        if self.writer.is_none() {
+            let path = self
+                .options
+                .dir
+                .join(format!("{:08}.rrd", self.next_segment_id));
+            self.next_segment_id += 1;
+
+            let file = BufWriter::new(File::create(&path)?);
+            self.writer = Some(Encoder::new(
+                re_build_info::CrateVersion::LOCAL,
+                EncodingOptions::UNCOMPRESSED,
+                file,
+            )?);
+            self.segments.push_back(Segment { path, num_bytes: 0 });
+        }
+
+        let (Some(writer), Some(segment)) = (&mut self.writer, self.segments.back_mut()) else {
+            unreachable!("we just made sure there is a writer");
+        };
+
+        for msg in re_log_encoding::decoder::decode_bytes(VersionPolicy::Warn, packet)? {
+            writer.append(&msg)?;
+        }
+        writer.flush_blocking()?;
+        segment.num_bytes += packet.len() as u64;
+
+        Ok(())
+    }
+
+    /// Sends the spooled packets in order, until `send` fails or the spool is empty.
+    ///
+    /// Returns `true` if the spool was drained.
+    pub fn replay(&mut self, mut send: impl FnMut(&[u8]) -> bool) -> bool {
+        loop {
+            let Some(packet) = self.unsent.take().or_else(|| self.next_packet()) else {
+                return true;
+            };
+            if !send(&packet) {
+                self.unsent = Some(packet);
+                return false;
+            }
+        }
+    }
+
+    fn next_packet(&mut self) -> Option<Vec<u8>> {
+        loop {
+            let path = self.segments.front()?.path.clone();
+
+            if self.reader.is_none() {
+                if self.writer.is_some() && self.segments.len() == 1 {
+                    // Don't read what we're still writing to: new packets go to the next segment.
+                    self.close_writer();
+                }
+
+                match File::open(&path)
+                    .map_err(DecodeError::Read)
+                    .and_then(|file| Decoder::new(VersionPolicy::Warn, BufReader::new(file)))
+                {
+                    Ok(reader) => self.reader = Some(reader),
+                    Err(err) => {
+                        re_log::warn!("Skipping unreadable spool file {path:?}: {err}");
+                        self.remove_oldest_segment();
+                        continue;
+                    }
+                }
+            }
+
+            let msg = match self.reader.as_mut()?.next() {
+                Some(Ok(msg)) => msg,
+                Some(Err(err)) => {
+                    // E.g. the end of a file that was cut short by a crash.
+                    re_log::warn!("Skipping the rest of spool file {path:?}: {err}");
+                    self.remove_oldest_segment();
+                    continue;
+                }
+                None => {
+                    self.remove_oldest_segment();
+                    continue;
+                }
+            };
+
+            match re_log_encoding::encoder::encode_to_bytes(
+                re_build_info::CrateVersion::LOCAL,
+                EncodingOptions::UNCOMPRESSED,
+                std::iter::once(&msg),
+            ) {
+                Ok(packet) => return Some(packet),
+                Err(err) => {
+                    re_log::error_once!("Failed to encode spooled message: {err}");
+                }
+            }
+        }
+    }
+
+    fn close_writer(&mut self) {
+        if let Some(mut writer) = self.writer.take() {
+            if let Err(err) = writer.flush_blocking() {
+                re_log::error_once!("Failed to write spool to {:?}: {err}", self.options.dir);
+            }
+        }
+    }
+
+    fn remove_oldest_segment(&mut self) {
+        let Some(segment) = self.segments.pop_front() else {
+            return;
+        };
+
+        // Both of these belong to the oldest segment.
+        self.reader = None;
+        self.unsent = None;
+
+        if self.segments.is_empty() {
+            self.writer = None;
+        }
+
+        if let Err(err) = std::fs::remove_file(&segment.path) {
+            re_log::warn_once!("Failed to remove spool file {:?}: {err}", segment.path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{
+        external::re_tuid::Tuid, ApplicationId, LogMsg, SetStoreInfo, StoreId, StoreInfo,
+        StoreKind, StoreSource, Time,
+    };
+
+    use super::*;
+
+    /// A message we can recognize by its application id.
+    fn msg(name: &str) -> LogMsg {
+        LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: Tuid::new(),
+            info: StoreInfo {
+                application_id: ApplicationId(name.to_owned()),
+                store_id: StoreId::random(StoreKind::Recording),
+                cloned_from: None,
+                is_official_example: false,
+                started: Time::now(),
+                store_source: StoreSource::Unknown,
+                store_version: None,
+            },
+        })
+    }
+
+    /// A packet as the client would send it.
+    fn packet(names: &[&str]) -> Vec<u8> {
+        let msgs = names.iter().map(|name| msg(name)).collect::<Vec<_>>();
+        re_log_encoding::encoder::encode_to_bytes(
+            re_build_info::CrateVersion::LOCAL,
+            EncodingOptions::UNCOMPRESSED,
+            msgs.iter(),
+        )
+        .unwrap()
+    }
+
+    fn names(packet: &[u8]) -> Vec<String> {
+        re_log_encoding::decoder::decode_bytes(VersionPolicy::Error, packet)
+            .unwrap()
+            .into_iter()
+            .map(|msg| match msg {
+                LogMsg::SetStoreInfo(msg) => msg.info.application_id.0,
+                msg => panic!("unexpected message: {msg:?}"),
+            })
+            .collect()
+    }
+
+    fn options(dir: &std::path::Path, max_bytes: u64, overflow: SpoolOverflow) -> SpoolOptions {
+        SpoolOptions {
+            dir: dir.join("spool"),
+            max_bytes,
+            overflow,
+        }
+    }
+
+    /// Replays everything, returning the names of the messages in the order they were sent.
+    fn replay_all(spool: &mut Spool) -> Vec<String> {
+        let mut sent = Vec::new();
+        assert!(spool.replay(|packet| {
+            sent.extend(names(packet));
+            true
+        }));
+        assert!(spool.is_empty());
+        sent
+    }
+
+    fn spool_files(spool: &Spool) -> Vec<String> {
+        let mut files = std::fs::read_dir(spool.dir())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut spool = Spool::new(options(dir.path(), 1_000_000, Default::default())).unwrap();
+        assert!(spool.is_empty());
+        assert_eq!(0, spool.num_bytes());
+
+        spool.push(&packet(&["a"]));
+        spool.push(&packet(&["b", "c"]));
+        spool.push(&packet(&["d"]));
+        assert!(!spool.is_empty());
+        assert!(0 < spool.num_bytes());
+
+        assert_eq!(["a", "b", "c", "d"], replay_all(&mut spool).as_slice());
+        assert_eq!(0, spool.num_bytes());
+        assert!(spool_files(&spool).is_empty());
+
+        // Still usable once drained.
+        spool.push(&packet(&["e"]));
+        assert_eq!(["e"], replay_all(&mut spool).as_slice());
+    }
+
+    #[test]
+    fn replay_after_reconnect() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut spool = Spool::new(options(dir.path(), 1_000_000, Default::default())).unwrap();
+        for name in ["a", "b", "c"] {
+            spool.push(&packet(&[name]));
+        }
+
+        // The connection drops while sending "b"…
+        let mut sent = Vec::new();
+        let mut num_attempts = 0;
+        assert!(!spool.replay(|packet| {
+            num_attempts += 1;
+            if num_attempts == 2 {
+                return false;
+            }
+            sent.extend(names(packet));
+            true
+        }));
+        assert_eq!(["a"], sent.as_slice());
+        assert!(!spool.is_empty());
+
+        // …and new messages keep coming in meanwhile.
+        spool.push(&packet(&["d"]));
+
+        // Once we reconnect, "b" is sent first.
+        assert_eq!(["b", "c", "d"], replay_all(&mut spool).as_slice());
+    }
+
+    #[test]
+    fn left_over_by_previous_run() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut spool = Spool::new(options(dir.path(), 1_000_000, Default::default())).unwrap();
+        spool.push(&packet(&["a"]));
+        spool.push(&packet(&["b"]));
+        drop(spool);
+
+        let mut spool = Spool::new(options(dir.path(), 1_000_000, Default::default())).unwrap();
+        assert!(!spool.is_empty());
+        assert!(0 < spool.num_bytes());
+
+        // New messages go after the old ones.
+        spool.push(&packet(&["c"]));
+        assert_eq!(["a", "b", "c"], replay_all(&mut spool).as_slice());
+    }
+
+    #[test]
+    fn drop_oldest() {
+        let dir = tempfile::tempdir().unwrap();
+        let packet_len = packet(&["0"]).len() as u64;
+        let mut spool = Spool::new(options(
+            dir.path(),
+            4 * packet_len,
+            SpoolOverflow::DropOldest,
+        ))
+        .unwrap();
+
+        for i in 0..10 {
+            spool.push(&packet(&[i.to_string().as_str()]));
+            assert!(spool.num_bytes() <= 4 * packet_len);
+        }
+
+        // Too large to ever fit: dropped, without making room for it.
+        spool.push(&packet(&["x"; 10]));
+
+        assert_eq!(["6", "7", "8", "9"], replay_all(&mut spool).as_slice());
+    }
+
+    #[test]
+    fn drop_newest() {
+        let dir = tempfile::tempdir().unwrap();
+        let packet_len = packet(&["0"]).len() as u64;
+        let mut spool = Spool::new(options(
+            dir.path(),
+            4 * packet_len,
+            SpoolOverflow::DropNewest,
+        ))
+        .unwrap();
+
+        for i in 0..10 {
+            spool.push(&packet(&[i.to_string().as_str()]));
+            assert!(spool.num_bytes() <= 4 * packet_len);
+        }
+
+        assert_eq!(["0", "1", "2", "3"], replay_all(&mut spool).as_slice());
+    }
+
+    #[test]
+    fn corrupted() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut spool = Spool::new(options(dir.path(), 1_000_000, Default::default())).unwrap();
+        spool.push(&packet(&["a"]));
+        spool.push(&packet(&["b"]));
+        let spool_dir = spool.dir().to_owned();
+        drop(spool);
+
+        // Cut the last message short, as if we crashed while writing it…
+        let path = spool_dir.join("00000000.rrd");
+        let len = std::fs::metadata(&path).unwrap().len();
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(len - 1)
+            .unwrap();
+
+        // …scribble over a later file…
+        std::fs::write(spool_dir.join("00000001.rrd"), b"not an rrd file").unwrap();
+
+        // …and leave something that isn't ours.
+        std::fs::write(spool_dir.join("notes.txt"), b"hands off").unwrap();
+
+        let mut spool = Spool::new(options(dir.path(), 1_000_000, Default::default())).unwrap();
+        spool.push(&packet(&["c"]));
+
+        // We keep going past the broken parts.
+        assert_eq!(["a", "c"], replay_all(&mut spool).as_slice());
+        assert_eq!(["notes.txt"], spool_files(&spool).as_slice());
+    }
+}
@@ -1,9 +1,14 @@
 use std::{
-    io::Write,
+    io::{Read, Write},
     net::{SocketAddr, TcpStream},
     time::{Duration, Instant},
 };
 
+use crate::{
+    ClientTlsOptions, CongestionMonitor, CongestionReport, SpoolOptions, Stream, TlsError,
+    ViewerMessages,
+};
+
 #[derive(thiserror::Error, Debug)]
 pub enum ClientError {
     #[error("Failed to connect to Rerun server at {addr:?}: {err}")]
@@ -17,6 +22,38 @@ pub enum ClientError {
         addr: SocketAddr,
         err: std::io::Error,
     },
+
+    #[error("Rerun server at {addr:?} rejected our auth token")]
+    Unauthorized { addr: SocketAddr },
+
+    #[error("Failed to set up TLS for Rerun server at {addr:?}: {err}")]
+    Tls { addr: SocketAddr, err: TlsError },
+}
+
+/// How to connect to a Rerun server.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClientOptions {
+    /// Presented to servers that require authentication.
+    pub auth_token: Option<String>,
+
+    /// If set, the connection is encrypted with TLS.
+    ///
+    /// Requires the `tls` feature.
+    pub tls: Option<ClientTlsOptions>,
+
+    /// If set, messages are kept on disk while the server can't be reached, and sent once we
+    /// reconnect, instead of blocking until the connection is back.
+    pub spool: Option<SpoolOptions>,
+
+    /// Ask the server to regularly report how well it keeps up, see [`crate::Client::congestion`].
+    ///
+    /// Servers older than Rerun 0.18 reject clients that ask for this.
+    pub congestion_reports: bool,
+
+    /// Accept messages from the viewer, e.g. screenshots, see [`crate::Client::viewer_messages`].
+    ///
+    /// Servers older than Rerun 0.18 reject clients that ask for this.
+    pub viewer_messages: bool,
 }
 
 /// State of the [`TcpStream`]
@@ -47,7 +84,12 @@ enum TcpStreamState {
     ///
     /// Transitions:
     ///  - Connected -> Pending on send error
-    Connected(TcpStream),
+    Connected {
+        stream: Box<dyn Stream>,
+
+        /// The socket under `stream`, if we read messages from the server on it.
+        socket: Option<TcpStream>,
+    },
 }
 
 impl TcpStreamState {
@@ -66,23 +108,45 @@ pub struct TcpClient {
     addr: SocketAddr,
     stream_state: TcpStreamState,
     flush_timeout: Option<Duration>,
+    options: ClientOptions,
+    congestion: CongestionMonitor,
+    viewer_messages: ViewerMessages,
+
+    /// Received bytes of a message from the server that hasn't fully arrived yet.
+    received: Vec<u8>,
+    last_poll: Instant,
 }
 
 impl TcpClient {
-    pub fn new(addr: SocketAddr, flush_timeout: Option<Duration>) -> Self {
+    pub fn new(
+        addr: SocketAddr,
+        flush_timeout: Option<Duration>,
+        options: ClientOptions,
+        congestion: CongestionMonitor,
+        viewer_messages: ViewerMessages,
+    ) -> Self {
         Self {
             addr,
             stream_state: TcpStreamState::reset(),
             flush_timeout,
+            options,
+            congestion,
+            viewer_messages,
+            received: Vec::new(),
+            last_poll: Instant::now(),
         }
     }
 
+    pub fn is_connected(&self) -> bool {
+        matches!(self.stream_state, TcpStreamState::Connected { .. })
+    }
+
     /// Returns `false` on failure. Does nothing if already connected.
     ///
     /// [`Self::send`] will call this.
     pub fn connect(&mut self) -> Result<(), ClientError> {
         match self.stream_state {
-            TcpStreamState::Connected(_) => Ok(()),
+            TcpStreamState::Connected { .. } => Ok(()),
             TcpStreamState::Pending {
                 start_time,
                 num_attempts,
@@ -90,24 +154,28 @@ impl TcpClient {
                 re_log::debug!("Connecting to {:?}…", self.addr);
                 let timeout = std::time::Duration::from_secs(5);
                 match TcpStream::connect_timeout(&self.addr, timeout) {
-                    Ok(mut stream) => {
+                    Ok(stream) => {
                         re_log::debug!("Connected to {:?}.", self.addr);
 
-                        if let Err(err) = stream
-                            .write(&crate::PROTOCOL_VERSION_1.to_le_bytes())
-                            .and_then(|_| stream.write(crate::PROTOCOL_HEADER.as_bytes()))
-                        {
-                            self.stream_state = TcpStreamState::Pending {
-                                start_time,
-                                num_attempts: num_attempts + 1,
-                            };
-                            Err(ClientError::Send {
-                                addr: self.addr,
-                                err,
-                            })
+                        let socket = if crate::PROTOCOL_VERSION_3 <= self.protocol_version() {
+                            stream.try_clone().ok()
                         } else {
-                            self.stream_state = TcpStreamState::Connected(stream);
-                            Ok(())
+                            None
+                        };
+
+                        match self.handshake(stream) {
+                            Ok(stream) => {
+                                self.stream_state = TcpStreamState::Connected { stream, socket };
+                                self.received.clear();
+                                Ok(())
+                            }
+                            Err(err) => {
+                                self.stream_state = TcpStreamState::Pending {
+                                    start_time,
+                                    num_attempts: num_attempts + 1,
+                                };
+                                Err(err)
+                            }
                         }
                     }
                     Err(err) => {
@@ -125,13 +193,97 @@ impl TcpClient {
         }
     }
 
+    /// Sets up encryption, if any, then introduces ourselves to the server.
+    fn handshake(&self, stream: TcpStream) -> Result<Box<dyn Stream>, ClientError> {
+        let mut stream = self.wrap_stream(stream)?;
+
+        let send_err = |err| ClientError::Send {
+            addr: self.addr,
+            err,
+        };
+
+        let protocol_version = self.protocol_version();
+        stream
+            .write_all(&protocol_version.to_le_bytes())
+            .and_then(|()| stream.write_all(crate::PROTOCOL_HEADER.as_bytes()))
+            .map_err(send_err)?;
+
+        if crate::PROTOCOL_VERSION_2 <= protocol_version {
+            let auth_token = self.options.auth_token.as_deref().unwrap_or_default();
+            stream
+                .write_all(&(auth_token.len() as u32).to_le_bytes())
+                .and_then(|()| stream.write_all(auth_token.as_bytes()))
+                .and_then(|()| stream.flush())
+                .map_err(send_err)?;
+
+            let mut response = [0_u8; 1];
+            stream.read_exact(&mut response).map_err(send_err)?;
+            match response[0] {
+                crate::AUTH_ACCEPTED => {}
+                crate::AUTH_REJECTED => return Err(ClientError::Unauthorized { addr: self.addr }),
+                _ => {
+                    // E.g. a TLS alert, because we're not using TLS but the server is.
+                    return Err(send_err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "unexpected response to our auth token",
+                    )));
+                }
+            }
+        }
+
+        Ok(stream)
+    }
+
+    /// Only use the newer protocols when needed, so that we can keep talking to older servers.
+    fn protocol_version(&self) -> u16 {
+        if self.options.viewer_messages {
+            crate::PROTOCOL_VERSION_4
+        } else if self.options.congestion_reports {
+            crate::PROTOCOL_VERSION_3
+        } else if self.options.auth_token.is_some() {
+            crate::PROTOCOL_VERSION_2
+        } else {
+            crate::PROTOCOL_VERSION_1
+        }
+    }
+
+    #[cfg(feature = "tls")]
+    fn wrap_stream(&self, stream: TcpStream) -> Result<Box<dyn Stream>, ClientError> {
+        let Some(tls) = &self.options.tls else {
+            return Ok(Box::new(stream));
+        };
+
+        let tls_err = |err| ClientError::Tls {
+            addr: self.addr,
+            err,
+        };
+        let config = tls.load().map_err(tls_err)?;
+        let server_name = tls.server_name(self.addr).map_err(tls_err)?;
+        let connection = rustls::ClientConnection::new(config, server_name)
+            .map_err(|err| tls_err(err.into()))?;
+
+        Ok(Box::new(rustls::StreamOwned::new(connection, stream)))
+    }
+
+    #[cfg(not(feature = "tls"))]
+    fn wrap_stream(&self, stream: TcpStream) -> Result<Box<dyn Stream>, ClientError> {
+        if self.options.tls.is_some() {
+            Err(ClientError::Tls {
+                addr: self.addr,
+                err: TlsError::NotSupported,
+            })
+        } else {
+            Ok(Box::new(stream))
+        }
+    }
+
     /// Blocks until it is sent.
     pub fn send(&mut self, packet: &[u8]) -> Result<(), ClientError> {
         use std::io::Write as _;
 
         self.connect()?;
 
-        if let TcpStreamState::Connected(stream) = &mut self.stream_state {
+        if let TcpStreamState::Connected { stream, .. } = &mut self.stream_state {
             re_log::trace!("Sending a packet of size {}…", packet.len());
             if let Err(err) = stream.write(&(packet.len() as u32).to_le_bytes()) {
                 self.stream_state = TcpStreamState::reset();
@@ -149,12 +301,118 @@ impl TcpClient {
                 });
             }
 
+            self.poll();
+
             Ok(())
         } else {
             unreachable!("self.connect should have ensured this");
         }
     }
 
+    /// Reads whatever messages the server has sent us, without blocking.
+    ///
+    /// [`Self::send`] calls this, but it also needs calling while there is nothing to send.
+    pub fn poll(&mut self) {
+        /// Reports arrive at most every 100 ms, so there is no point in polling more often.
+        const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+        let TcpStreamState::Connected {
+            stream,
+            socket: Some(socket),
+        } = &mut self.stream_state
+        else {
+            return;
+        };
+        if self.last_poll.elapsed() < POLL_INTERVAL {
+            return;
+        }
+        self.last_poll = Instant::now();
+
+        if let Err(err) = socket.set_nonblocking(true) {
+            re_log::debug!("Failed to poll for server messages: {err}");
+            return;
+        }
+
+        let mut buffer = [0_u8; 256];
+        loop {
+            match stream.read(&mut buffer) {
+                Ok(0) => break, // Closed by the server: our next send will notice.
+                Ok(num_bytes) => self.received.extend_from_slice(&buffer[..num_bytes]),
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => {
+                    if err.kind() != std::io::ErrorKind::WouldBlock {
+                        re_log::debug!("Failed to read server messages: {err}");
+                    }
+                    break;
+                }
+            }
+        }
+
+        if let Err(err) = socket.set_nonblocking(false) {
+            re_log::warn!(
+                "Failed to make the connection to {:?} blocking again: {err}",
+                self.addr
+            );
+            self.stream_state = TcpStreamState::reset();
+            return;
+        }
+
+        self.handle_received();
+    }
+
+    /// Handles the complete messages we've received, keeping the rest until it has fully arrived.
+    fn handle_received(&mut self) {
+        if crate::PROTOCOL_VERSION_4 <= self.protocol_version() {
+            self.handle_frames();
+        } else {
+            // Only the latest report matters.
+            let num_reports = self.received.len() / CongestionReport::NUM_BYTES;
+            if 0 < num_reports {
+                let start = (num_reports - 1) * CongestionReport::NUM_BYTES;
+                self.handle_report(&self.received[start..start + CongestionReport::NUM_BYTES]);
+                self.received
+                    .drain(..num_reports * CongestionReport::NUM_BYTES);
+            }
+        }
+    }
+
+    /// Handles the complete [`crate::PROTOCOL_VERSION_4`] messages we've received.
+    fn handle_frames(&mut self) {
+        const HEADER_SIZE: usize = 5;
+
+        let mut num_handled_bytes = 0;
+        loop {
+            let rest = &self.received[num_handled_bytes..];
+            if rest.len() < HEADER_SIZE {
+                break;
+            }
+            let kind = rest[0];
+            let len = u32::from_le_bytes([rest[1], rest[2], rest[3], rest[4]]) as usize;
+            let Some(payload) = rest.get(HEADER_SIZE..HEADER_SIZE + len) else {
+                break;
+            };
+
+            match kind {
+                crate::SERVER_MSG_CONGESTION_REPORT => self.handle_report(payload),
+                crate::SERVER_MSG_VIEWER => match rmp_serde::from_slice(payload) {
+                    Ok(msg) => self.viewer_messages.publish(&msg),
+                    Err(err) => re_log::warn_once!("Failed to decode viewer message: {err}"),
+                },
+                _ => re_log::debug_once!("Ignoring unknown message of kind {kind} from server"),
+            }
+            num_handled_bytes += HEADER_SIZE + len;
+        }
+        self.received.drain(..num_handled_bytes);
+    }
+
+    fn handle_report(&self, bytes: &[u8]) {
+        if let Ok(bytes) = bytes.try_into() {
+            self.congestion.set(CongestionReport::from_bytes(bytes));
+        } else {
+            re_log::debug_once!("Ignoring congestion report of {} bytes", bytes.len());
+        }
+    }
+
     /// Wait until all logged data have been sent.
     pub fn flush(&mut self) {
         re_log::trace!("Attempting to flush TCP stream…");
@@ -164,7 +422,7 @@ impl TcpClient {
                     "Tried to flush while TCP stream was still Pending. Data was possibly dropped."
                 );
             }
-            TcpStreamState::Connected(stream) => {
+            TcpStreamState::Connected { stream, .. } => {
                 if let Err(err) = stream.flush() {
                     re_log::warn!("Failed to flush TCP stream: {err}");
                     self.stream_state = TcpStreamState::reset();
@@ -175,6 +433,44 @@ impl TcpClient {
         }
     }
 
+    /// Tells the server we're done, and reads what it still sends us until it closes the
+    /// connection in turn, for at most a second.
+    ///
+    /// Closing the socket while messages from the server are waiting to be read would reset the
+    /// connection, and the server could lose the last of our messages. Dropping a [`TcpClient`]
+    /// just closes it, so call this first, from the thread that [`Self::poll`]s.
+    pub fn close(&mut self) {
+        const CLOSE_TIMEOUT: Duration = Duration::from_secs(1);
+
+        let TcpStreamState::Connected {
+            stream,
+            socket: Some(socket),
+        } = &mut self.stream_state
+        else {
+            return;
+        };
+
+        if stream.flush().is_ok() && socket.shutdown(std::net::Shutdown::Write).is_ok() {
+            let deadline = Instant::now() + CLOSE_TIMEOUT;
+            let mut buffer = [0_u8; 256];
+            loop {
+                let timeout = deadline.saturating_duration_since(Instant::now());
+                if timeout.is_zero() || socket.set_read_timeout(Some(timeout)).is_err() {
+                    break;
+                }
+                match stream.read(&mut buffer) {
+                    Ok(0) => break,
+                    Ok(num_bytes) => self.received.extend_from_slice(&buffer[..num_bytes]),
+                    Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                    Err(_) => break,
+                }
+            }
+            self.handle_received();
+        }
+
+        self.stream_state = TcpStreamState::reset();
+    }
+
     /// Check if the underlying [`TcpStream`] is in the [`TcpStreamState::Pending`] state
     /// and has reached the flush timeout threshold.
     ///
@@ -190,7 +486,210 @@ impl TcpClient {
                     Instant::now().duration_since(start_time) > timeout && num_attempts > 0
                 })
             }
-            TcpStreamState::Connected(_) => false,
+            TcpStreamState::Connected { .. } => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(options: ClientOptions) -> TcpClient {
+        TcpClient::new(
+            ([127, 0, 0, 1], crate::DEFAULT_SERVER_PORT).into(),
+            None,
+            options,
+            Default::default(),
+            Default::default(),
+        )
+    }
+
+    fn report(queue_len: u32) -> CongestionReport {
+        CongestionReport {
+            latency_sec: 0.5,
+            queue_len,
+            max_latency_sec: 1.0,
+            accept_rate: 1.0,
+        }
+    }
+
+    /// Reports trickle in over several reads.
+    #[cfg(feature = "server")]
+    #[test]
+    fn truncated_reports() {
+        let mut client = client(ClientOptions {
+            congestion_reports: true,
+            ..Default::default()
+        });
+
+        let bytes = [report(1).to_bytes(), report(2).to_bytes()].concat();
+
+        client.received.extend_from_slice(&bytes[..10]);
+        client.handle_received();
+        assert_eq!(None, client.congestion.report());
+        assert_eq!(10, client.received.len());
+
+        // The first report completes, and half of the next arrives.
+        client.received.extend_from_slice(&bytes[10..24]);
+        client.handle_received();
+        assert_eq!(Some(report(1)), client.congestion.report());
+        assert_eq!(8, client.received.len());
+
+        client.received.extend_from_slice(&bytes[24..]);
+        client.handle_received();
+        assert_eq!(Some(report(2)), client.congestion.report());
+        assert!(client.received.is_empty());
+    }
+
+    /// [`crate::PROTOCOL_VERSION_4`] messages trickle in over several reads.
+    #[cfg(feature = "server")]
+    #[test]
+    fn truncated_frames() {
+        let mut client = client(ClientOptions {
+            viewer_messages: true,
+            ..Default::default()
+        });
+
+        let frame = |kind: u8, payload: &[u8]| {
+            let len = u32::try_from(payload.len()).unwrap();
+            [&[kind], &len.to_le_bytes()[..], payload].concat()
+        };
+        let bytes = [
+            frame(crate::SERVER_MSG_CONGESTION_REPORT, &report(1).to_bytes()),
+            frame(42, b"from the future"),
+            frame(crate::SERVER_MSG_CONGESTION_REPORT, &report(2).to_bytes()),
+        ]
+        .concat();
+
+        // Not even the header.
+        client.received.extend_from_slice(&bytes[..3]);
+        client.handle_received();
+        assert_eq!(None, client.congestion.report());
+
+        // The header, but not the whole payload.
+        client.received.extend_from_slice(&bytes[3..12]);
+        client.handle_received();
+        assert_eq!(None, client.congestion.report());
+        assert_eq!(12, client.received.len());
+
+        // The rest of the first report, and the unknown kind of message we skip.
+        client.received.extend_from_slice(&bytes[12..42]);
+        client.handle_received();
+        assert_eq!(Some(report(1)), client.congestion.report());
+        assert_eq!(42 - 21 - 20, client.received.len());
+
+        client.received.extend_from_slice(&bytes[42..]);
+        client.handle_received();
+        assert_eq!(Some(report(2)), client.congestion.report());
+        assert!(client.received.is_empty());
+    }
+
+    /// Reports of the wrong size are ignored, rather than misread.
+    #[test]
+    fn wrong_report_size() {
+        let client = client(ClientOptions::default());
+        client.handle_report(&[0; CongestionReport::NUM_BYTES - 1]);
+        client.handle_report(&[0; CongestionReport::NUM_BYTES + 1]);
+        assert_eq!(None, client.congestion.report());
+    }
+
+    /// Dropping a client doesn't wait for the server, even if it never closes the connection.
+    #[test]
+    fn drop_does_not_block() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (done_tx, done_rx) = crossbeam::channel::bounded::<()>(0);
+
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            // Version, header and an empty token.
+            let mut handshake = [0_u8; 2 + crate::PROTOCOL_HEADER.len() + 4];
+            stream.read_exact(&mut handshake).unwrap();
+            stream.write_all(&[crate::AUTH_ACCEPTED]).unwrap();
+
+            // Keep the connection open until the test is done.
+            done_rx.recv().ok();
+        });
+
+        let mut client = TcpClient::new(
+            addr,
+            None,
+            ClientOptions {
+                congestion_reports: true,
+                ..Default::default()
+            },
+            Default::default(),
+            Default::default(),
+        );
+        client.connect().unwrap();
+
+        let start = Instant::now();
+        drop(client);
+        assert!(start.elapsed() < Duration::from_millis(500));
+
+        drop(done_tx);
+        server.join().unwrap();
+    }
+
+    fn protocol_version(options: ClientOptions) -> u16 {
+        client(options).protocol_version()
+    }
+
+    /// We use the oldest protocol that does what we need, so that older servers accept us.
+    #[test]
+    fn test_protocol_version() {
+        assert_eq!(
+            crate::PROTOCOL_VERSION_1,
+            protocol_version(ClientOptions::default())
+        );
+
+        let with_token = ClientOptions {
+            auth_token: Some("secret".to_owned()),
+            ..Default::default()
+        };
+        assert_eq!(
+            crate::PROTOCOL_VERSION_2,
+            protocol_version(with_token.clone())
+        );
+
+        // Encryption and spooling don't change what we send.
+        let tls_and_spool = ClientOptions {
+            tls: Some(ClientTlsOptions::default()),
+            spool: Some(SpoolOptions {
+                dir: "spool".into(),
+                max_bytes: 1024,
+                overflow: Default::default(),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(crate::PROTOCOL_VERSION_1, protocol_version(tls_and_spool));
+
+        let congestion_reports = ClientOptions {
+            congestion_reports: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            crate::PROTOCOL_VERSION_3,
+            protocol_version(congestion_reports.clone())
+        );
+        assert_eq!(
+            crate::PROTOCOL_VERSION_3,
+            protocol_version(ClientOptions {
+                congestion_reports: true,
+                ..with_token.clone()
+            })
+        );
+
+        for options in [ClientOptions::default(), with_token, congestion_reports] {
+            assert_eq!(
+                crate::PROTOCOL_VERSION_4,
+                protocol_version(ClientOptions {
+                    viewer_messages: true,
+                    ..options
+                })
+            );
         }
     }
 }
@@ -0,0 +1,142 @@
+//! Optional TLS encryption of the connection between the SDK and the server.
+//!
+//! The options are always available, but actually encrypting anything requires the `tls` feature.
+
+use std::path::PathBuf;
+
+#[derive(thiserror::Error, Debug)]
+pub enum TlsError {
+    #[error("Rerun was not compiled with the 'tls' feature")]
+    NotSupported,
+
+    #[error("Failed to read {path:?}: {err}")]
+    Io { path: PathBuf, err: std::io::Error },
+
+    #[error("No certificate found in {0:?}")]
+    NoCertificate(PathBuf),
+
+    #[error("No private key found in {0:?}")]
+    NoPrivateKey(PathBuf),
+
+    #[error("Invalid TLS server name: {0:?}")]
+    InvalidServerName(String),
+
+    #[cfg(feature = "tls")]
+    #[error(transparent)]
+    Rustls(#[from] rustls::Error),
+}
+
+/// The certificate chain and private key a server presents to its clients.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerTlsOptions {
+    /// PEM file with the certificate chain, leaf first.
+    pub cert_path: PathBuf,
+
+    /// PEM file with the private key of the leaf certificate (PKCS#8, PKCS#1 or SEC1).
+    pub key_path: PathBuf,
+}
+
+impl ServerTlsOptions {
+    /// Loads the certificate and key, ready to accept TLS connections.
+    ///
+    /// The result can also be handed to `re_ws_comms`, to serve WebSockets with the same certificate.
+    #[cfg(all(feature = "tls", feature = "server"))]
+    pub fn load(&self) -> Result<std::sync::Arc<rustls::ServerConfig>, TlsError> {
+        let cert_chain = read_pem(&self.cert_path)?
+            .into_iter()
+            .filter_map(|item| match item {
+                rustls_pemfile::Item::X509Certificate(der) => Some(rustls::Certificate(der)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if cert_chain.is_empty() {
+            return Err(TlsError::NoCertificate(self.cert_path.clone()));
+        }
+
+        let key = read_pem(&self.key_path)?
+            .into_iter()
+            .find_map(|item| match item {
+                rustls_pemfile::Item::PKCS8Key(der)
+                | rustls_pemfile::Item::RSAKey(der)
+                | rustls_pemfile::Item::ECKey(der) => Some(rustls::PrivateKey(der)),
+                _ => None,
+            })
+            .ok_or_else(|| TlsError::NoPrivateKey(self.key_path.clone()))?;
+
+        let config = rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(cert_chain, key)?;
+
+        Ok(std::sync::Arc::new(config))
+    }
+}
+
+/// How a client verifies the server it connects to over TLS.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClientTlsOptions {
+    /// PEM file with the certificate(s) to trust, e.g. the self-signed certificate of the server.
+    ///
+    /// If `None`, the usual public certificate authorities are trusted.
+    pub ca_cert_path: Option<PathBuf>,
+
+    /// The name the certificate of the server must be valid for.
+    ///
+    /// If `None`, the IP address we connect to is used.
+    pub server_name: Option<String>,
+}
+
+impl ClientTlsOptions {
+    #[cfg(all(feature = "tls", feature = "client"))]
+    pub(crate) fn load(&self) -> Result<std::sync::Arc<rustls::ClientConfig>, TlsError> {
+        let mut roots = rustls::RootCertStore::empty();
+
+        if let Some(ca_cert_path) = &self.ca_cert_path {
+            for item in read_pem(ca_cert_path)? {
+                if let rustls_pemfile::Item::X509Certificate(der) = item {
+                    roots.add(&rustls::Certificate(der))?;
+                }
+            }
+            if roots.is_empty() {
+                return Err(TlsError::NoCertificate(ca_cert_path.clone()));
+            }
+        } else {
+            roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|anchor| {
+                rustls::OwnedTrustAnchor::from_subject_spki_name_constraints(
+                    anchor.subject,
+                    anchor.spki,
+                    anchor.name_constraints,
+                )
+            }));
+        }
+
+        let config = rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+
+        Ok(std::sync::Arc::new(config))
+    }
+
+    #[cfg(all(feature = "tls", feature = "client"))]
+    pub(crate) fn server_name(
+        &self,
+        addr: std::net::SocketAddr,
+    ) -> Result<rustls::ServerName, TlsError> {
+        match &self.server_name {
+            Some(name) => rustls::ServerName::try_from(name.as_str())
+                .map_err(|_err| TlsError::InvalidServerName(name.clone())),
+            None => Ok(rustls::ServerName::IpAddress(addr.ip())),
+        }
+    }
+}
+
+#[cfg(all(feature = "tls", any(feature = "client", feature = "server")))]
+fn read_pem(path: &std::path::Path) -> Result<Vec<rustls_pemfile::Item>, TlsError> {
+    let io_err = |err| TlsError::Io {
+        path: path.to_owned(),
+        err,
+    };
+    let file = std::fs::File::open(path).map_err(io_err)?;
+    rustls_pemfile::read_all(&mut std::io::BufReader::new(file)).map_err(io_err)
+}
@@ -0,0 +1,202 @@
+//! Routing [`ViewerMessage`]s from the viewer back to the SDK clients, over the connection they
+//! send their data on.
+//!
+//! Requires [`crate::PROTOCOL_VERSION_4`].
+
+use std::{collections::BTreeMap, net::SocketAddr, sync::Arc};
+
+use crossbeam::channel::Sender;
+use parking_lot::Mutex;
+
+use re_log_types::ViewerMessage;
+
+/// The clients of a server that accept [`ViewerMessage`]s, by address.
+///
+/// Owned by the server, and shared with whoever answers its clients, e.g. the viewer, which only
+/// knows a client by the [`re_smart_channel::SmartMessageSource::TcpClient`] its messages came from.
+///
+/// Cheap to clone, and can be used from any thread.
+#[derive(Clone, Default)]
+pub struct ViewerClients {
+    clients: Arc<Mutex<BTreeMap<SocketAddr, Sender<ViewerMessage>>>>,
+}
+
+impl ViewerClients {
+    /// Sends a message to the client connected from `addr`.
+    ///
+    /// Returns `false` if there is no such client, or it doesn't accept [`ViewerMessage`]s.
+    pub fn send(&self, addr: SocketAddr, msg: ViewerMessage) -> bool {
+        self.clients
+            .lock()
+            .get(&addr)
+            .is_some_and(|tx| tx.send(msg).is_ok())
+    }
+
+    /// Makes [`Self::send`] deliver to the returned channel, until the registration is dropped.
+    #[cfg(feature = "server")]
+    pub(crate) fn register(
+        &self,
+        addr: SocketAddr,
+    ) -> (
+        ClientRegistration,
+        crossbeam::channel::Receiver<ViewerMessage>,
+    ) {
+        let (tx, rx) = crossbeam::channel::unbounded();
+        self.clients.lock().insert(addr, tx);
+        let registration = ClientRegistration {
+            clients: self.clone(),
+            addr,
+        };
+        (registration, rx)
+    }
+}
+
+impl std::fmt::Debug for ViewerClients {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ViewerClients")
+            .field("clients", &self.clients.lock().keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Removes the client from its [`ViewerClients`] when dropped.
+#[cfg(feature = "server")]
+pub(crate) struct ClientRegistration {
+    clients: ViewerClients,
+    addr: SocketAddr,
+}
+
+#[cfg(feature = "server")]
+impl Drop for ClientRegistration {
+    fn drop(&mut self) {
+        self.clients.clients.lock().remove(&self.addr);
+    }
+}
+
+/// Hands out the [`ViewerMessage`]s received by a [`crate::Client`] to everyone interested.
+///
+/// Cheap to clone, and can be used from any thread.
+#[cfg(feature = "client")]
+#[derive(Clone, Default)]
+pub struct ViewerMessages {
+    subscribers: std::sync::Arc<Mutex<Vec<Sender<ViewerMessage>>>>,
+}
+
+#[cfg(feature = "client")]
+impl ViewerMessages {
+    /// Receive all messages from now on, until the returned channel is dropped.
+    pub fn subscribe(&self) -> crossbeam::channel::Receiver<ViewerMessage> {
+        let (tx, rx) = crossbeam::channel::unbounded();
+        self.subscribers.lock().push(tx);
+        rx
+    }
+
+    pub(crate) fn publish(&self, msg: &ViewerMessage) {
+        self.subscribers
+            .lock()
+            .retain(|tx| tx.send(msg.clone()).is_ok());
+    }
+}
+
+#[cfg(feature = "client")]
+impl std::fmt::Debug for ViewerMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ViewerMessages")
+            .field("num_subscribers", &self.subscribers.lock().len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{EntityPath, Instance, StoreId, StoreKind, TimeInt, Timeline, ViewerEvent};
+
+    use super::*;
+
+    fn screenshot(request_id: u64) -> ViewerMessage {
+        ViewerMessage::Screenshot {
+            request_id,
+            png: vec![1, 2, 3],
+        }
+    }
+
+    /// Everything the viewer sends must survive the trip through the wire format.
+    #[test]
+    fn test_encode_decode() {
+        let store_id = StoreId::from_string(StoreKind::Recording, "rec".to_owned());
+        let instance = (EntityPath::from("points"), Instance::from(3));
+        let events = [
+            ViewerEvent::SelectionChanged(vec![]),
+            ViewerEvent::SelectionChanged(vec![
+                instance.clone(),
+                (EntityPath::root(), Instance::ALL),
+            ]),
+            ViewerEvent::HoverChanged(None),
+            ViewerEvent::HoverChanged(Some(instance.clone())),
+            ViewerEvent::TimeChanged {
+                timeline: Timeline::new_sequence("frame"),
+                time: Some(TimeInt::new_temporal(-42)),
+            },
+            ViewerEvent::TimeChanged {
+                timeline: Timeline::log_time(),
+                time: None,
+            },
+            ViewerEvent::Picked {
+                entity_path: instance.0.clone(),
+                instance: instance.1,
+                space: EntityPath::from("world/camera"),
+                position: [1.0, -2.5, f32::MAX],
+            },
+        ];
+
+        let messages = events
+            .into_iter()
+            .map(|event| ViewerMessage::Event {
+                store_id: store_id.clone(),
+                event,
+            })
+            .chain([
+                screenshot(7),
+                ViewerMessage::Screenshot {
+                    request_id: u64::MAX,
+                    png: vec![],
+                },
+            ]);
+
+        for msg in messages {
+            let payload = rmp_serde::to_vec_named(&msg).expect("encoding failed");
+            let decoded: ViewerMessage = rmp_serde::from_slice(&payload).expect("decoding failed");
+            assert_eq!(msg, decoded);
+        }
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn test_viewer_clients() {
+        let addr: SocketAddr = ([127, 0, 0, 1], 1234).into();
+        let clients = ViewerClients::default();
+        assert!(!clients.send(addr, screenshot(0)));
+
+        let (registration, rx) = clients.register(addr);
+        assert!(clients.send(addr, screenshot(1)));
+        assert!(!clients.send(([127, 0, 0, 1], 1235).into(), screenshot(2)));
+        assert_eq!(vec![screenshot(1)], rx.try_iter().collect::<Vec<_>>());
+
+        // Each server has its own clients.
+        assert!(!ViewerClients::default().send(addr, screenshot(3)));
+
+        drop(registration);
+        assert!(!clients.send(addr, screenshot(4)));
+    }
+
+    /// A client that has disconnected, but whose server hasn't noticed yet.
+    #[cfg(feature = "server")]
+    #[test]
+    fn receiver_gone() {
+        let addr: SocketAddr = ([127, 0, 0, 1], 1234).into();
+        let clients = ViewerClients::default();
+        let (_registration, rx) = clients.register(addr);
+        drop(rx);
+        assert!(!clients.send(addr, screenshot(0)));
+    }
+}
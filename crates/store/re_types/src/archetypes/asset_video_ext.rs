@@ -0,0 +1,56 @@
+use crate::components::MediaType;
+
+use super::AssetVideo;
+
+impl AssetVideo {
+    /// Creates a new [`AssetVideo`] from the file contents at `path`.
+    ///
+    /// The [`MediaType`] will first be guessed from the file extension, then from the file
+    /// contents if needed.
+    ///
+    /// If no [`MediaType`] can be guessed at the moment, the Rerun Viewer will try to guess one
+    /// from the data at render-time. If it can't, rendering will fail with an error.
+    ///
+    /// Returns an error if the file cannot be read.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn from_file_path(filepath: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let filepath = filepath.as_ref();
+        let contents = std::fs::read(filepath)?;
+        Ok(Self::from_file_contents(
+            contents,
+            MediaType::guess_from_path(filepath),
+        ))
+    }
+
+    /// Creates a new [`AssetVideo`] from the given `contents`.
+    ///
+    /// The [`MediaType`] will be guessed from magic bytes in the data.
+    ///
+    /// If no [`MediaType`] can be guessed at the moment, the Rerun Viewer will try to guess one
+    /// from the data at render-time. If it can't, rendering will fail with an error.
+    #[inline]
+    pub fn from_file_contents(contents: Vec<u8>, media_type: Option<impl Into<MediaType>>) -> Self {
+        let media_type = media_type.map(Into::into);
+        let media_type = MediaType::or_guess_from_data(media_type, &contents);
+        Self {
+            blob: contents.into(),
+            media_type,
+        }
+    }
+
+    /// When each frame of the video is shown, in nanoseconds, in presentation order.
+    ///
+    /// Log a [`super::VideoFrameReference`] with each of these timestamps to show every frame
+    /// of the video.
+    #[cfg(feature = "video")]
+    pub fn read_frame_timestamps_ns(&self) -> Result<Vec<i64>, re_video::VideoLoadError> {
+        re_tracing::profile_function!();
+
+        let media_type = MediaType::or_guess_from_data(self.media_type.clone(), &self.blob.0)
+            .unwrap_or_default();
+        let video = re_video::VideoData::load(&self.blob.0, media_type.as_str())?;
+
+        Ok(video.frame_timestamps_ns().collect())
+    }
+}
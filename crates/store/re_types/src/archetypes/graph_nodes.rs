@@ -0,0 +1,329 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/graph_nodes.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::external::arrow2;
+use ::re_types_core::ComponentName;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, MaybeOwnedComponentBatch};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A list of nodes in a graph with optional labels, colors, etc.
+///
+/// Nodes without a position are placed automatically by a force-directed layout.
+/// The nodes are connected by the [`archetypes::GraphEdges`][crate::archetypes::GraphEdges] logged to the same entity.
+///
+/// ## Example
+///
+/// ### Simple directed graph
+/// ```ignore
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let rec = rerun::RecordingStreamBuilder::new("rerun_example_graph_directed").spawn()?;
+///
+///     rec.log(
+///         "simple",
+///         &rerun::GraphNodes::new(["a", "b", "c"])
+///             .with_positions([(0.0, 100.0), (-100.0, 0.0), (100.0, 0.0)])
+///             .with_labels(["A", "B", "C"]),
+///     )?;
+///     rec.log(
+///         "simple",
+///         &rerun::GraphEdges::new([("a", "b"), ("b", "c"), ("c", "a")])
+///             .with_graph_type(rerun::components::GraphType::Directed),
+///     )?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphNodes {
+    /// A list of node IDs.
+    pub node_ids: Vec<crate::components::GraphNode>,
+
+    /// Optional center positions of the nodes.
+    ///
+    /// Nodes without a position are placed by the viewer.
+    pub positions: Option<Vec<crate::components::Position2D>>,
+
+    /// Optional colors for the nodes.
+    pub colors: Option<Vec<crate::components::Color>>,
+
+    /// Optional text labels for the nodes.
+    ///
+    /// Nodes without a label show their ID instead.
+    pub labels: Option<Vec<crate::components::Text>>,
+
+    /// Optional radii for the nodes.
+    pub radii: Option<Vec<crate::components::Radius>>,
+}
+
+impl ::re_types_core::SizeBytes for GraphNodes {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.node_ids.heap_size_bytes()
+            + self.positions.heap_size_bytes()
+            + self.colors.heap_size_bytes()
+            + self.labels.heap_size_bytes()
+            + self.radii.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <Vec<crate::components::GraphNode>>::is_pod()
+            && <Option<Vec<crate::components::Position2D>>>::is_pod()
+            && <Option<Vec<crate::components::Color>>>::is_pod()
+            && <Option<Vec<crate::components::Text>>>::is_pod()
+            && <Option<Vec<crate::components::Radius>>>::is_pod()
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 1usize]> =
+    once_cell::sync::Lazy::new(|| ["rerun.components.GraphNode".into()]);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 1usize]> =
+    once_cell::sync::Lazy::new(|| ["rerun.components.GraphNodesIndicator".into()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            "rerun.components.Position2D".into(),
+            "rerun.components.Color".into(),
+            "rerun.components.Text".into(),
+            "rerun.components.Radius".into(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 6usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            "rerun.components.GraphNode".into(),
+            "rerun.components.GraphNodesIndicator".into(),
+            "rerun.components.Position2D".into(),
+            "rerun.components.Color".into(),
+            "rerun.components.Text".into(),
+            "rerun.components.Radius".into(),
+        ]
+    });
+
+impl GraphNodes {
+    /// The total number of components in the archetype: 1 required, 1 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 6usize;
+}
+
+/// Indicator component for the [`GraphNodes`] [`::re_types_core::Archetype`]
+pub type GraphNodesIndicator = ::re_types_core::GenericIndicatorComponent<GraphNodes>;
+
+impl ::re_types_core::Archetype for GraphNodes {
+    type Indicator = GraphNodesIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.GraphNodes".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Graph nodes"
+    }
+
+    #[inline]
+    fn indicator() -> MaybeOwnedComponentBatch<'static> {
+        static INDICATOR: GraphNodesIndicator = GraphNodesIndicator::DEFAULT;
+        MaybeOwnedComponentBatch::Ref(&INDICATOR)
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentName, Box<dyn arrow2::array::Array>)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_name: ::std::collections::HashMap<_, _> = arrow_data
+            .into_iter()
+            .map(|(name, array)| (name.full_name(), array))
+            .collect();
+        let node_ids = {
+            let array = arrays_by_name
+                .get("rerun.components.GraphNode")
+                .ok_or_else(DeserializationError::missing_data)
+                .with_context("rerun.archetypes.GraphNodes#node_ids")?;
+            <crate::components::GraphNode>::from_arrow_opt(&**array)
+                .with_context("rerun.archetypes.GraphNodes#node_ids")?
+                .into_iter()
+                .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                .collect::<DeserializationResult<Vec<_>>>()
+                .with_context("rerun.archetypes.GraphNodes#node_ids")?
+        };
+        let positions = if let Some(array) = arrays_by_name.get("rerun.components.Position2D") {
+            Some({
+                <crate::components::Position2D>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.GraphNodes#positions")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.GraphNodes#positions")?
+            })
+        } else {
+            None
+        };
+        let colors = if let Some(array) = arrays_by_name.get("rerun.components.Color") {
+            Some({
+                <crate::components::Color>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.GraphNodes#colors")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.GraphNodes#colors")?
+            })
+        } else {
+            None
+        };
+        let labels = if let Some(array) = arrays_by_name.get("rerun.components.Text") {
+            Some({
+                <crate::components::Text>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.GraphNodes#labels")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.GraphNodes#labels")?
+            })
+        } else {
+            None
+        };
+        let radii = if let Some(array) = arrays_by_name.get("rerun.components.Radius") {
+            Some({
+                <crate::components::Radius>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.GraphNodes#radii")?
+                    .into_iter()
+                    .map(|v| v.ok_or_else(DeserializationError::missing_data))
+                    .collect::<DeserializationResult<Vec<_>>>()
+                    .with_context("rerun.archetypes.GraphNodes#radii")?
+            })
+        } else {
+            None
+        };
+        Ok(Self {
+            node_ids,
+            positions,
+            colors,
+            labels,
+            radii,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for GraphNodes {
+    fn as_component_batches(&self) -> Vec<MaybeOwnedComponentBatch<'_>> {
+        re_tracing::profile_function!();
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            Some((&self.node_ids as &dyn ComponentBatch).into()),
+            self.positions
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+            self.colors
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+            self.labels
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+            self.radii
+                .as_ref()
+                .map(|comp_batch| (comp_batch as &dyn ComponentBatch).into()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl GraphNodes {
+    /// Create a new `GraphNodes`.
+    #[inline]
+    pub fn new(
+        node_ids: impl IntoIterator<Item = impl Into<crate::components::GraphNode>>,
+    ) -> Self {
+        Self {
+            node_ids: node_ids.into_iter().map(Into::into).collect(),
+            positions: None,
+            colors: None,
+            labels: None,
+            radii: None,
+        }
+    }
+
+    /// Optional center positions of the nodes.
+    ///
+    /// Nodes without a position are placed by the viewer.
+    #[inline]
+    pub fn with_positions(
+        mut self,
+        positions: impl IntoIterator<Item = impl Into<crate::components::Position2D>>,
+    ) -> Self {
+        self.positions = Some(positions.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Optional colors for the nodes.
+    #[inline]
+    pub fn with_colors(
+        mut self,
+        colors: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.colors = Some(colors.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Optional text labels for the nodes.
+    ///
+    /// Nodes without a label show their ID instead.
+    #[inline]
+    pub fn with_labels(
+        mut self,
+        labels: impl IntoIterator<Item = impl Into<crate::components::Text>>,
+    ) -> Self {
+        self.labels = Some(labels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Optional radii for the nodes.
+    #[inline]
+    pub fn with_radii(
+        mut self,
+        radii: impl IntoIterator<Item = impl Into<crate::components::Radius>>,
+    ) -> Self {
+        self.radii = Some(radii.into_iter().map(Into::into).collect());
+        self
+    }
+}
@@ -32,6 +32,10 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 /// Leading and trailing unit-dimensions are ignored, so that
 /// `1x480x640x3x1` is treated as a `480x640x3` RGB image.
 ///
+/// Use a [`components::ColorModel`][crate::components::ColorModel] to treat a 3- or 4-channel image as `BGR` or `BGRA` instead,
+/// or a [`components::PixelFormat`][crate::components::PixelFormat] to log the raw `NV12`, `YUY2` or Bayer buffer of a camera as-is.
+/// The viewer converts these to RGB when displaying them.
+///
 /// Rerun also supports compressed images (JPEG, PNG, …), using [`archetypes::ImageEncoded`][crate::archetypes::ImageEncoded].
 /// Compressing images can save a lot of bandwidth and memory.
 ///
@@ -70,6 +74,17 @@ pub struct Image {
     /// The image data. Should always be a 2- or 3-dimensional tensor.
     pub data: crate::components::TensorData,
 
+    /// The color model of the pixels, e.g. `BGR`.
+    ///
+    /// If not set, it is inferred from the number of channels: `L`, `RGB` or `RGBA`.
+    /// Ignored if a [`components::PixelFormat`][crate::components::PixelFormat] is set.
+    pub color_model: Option<crate::components::ColorModel>,
+
+    /// The pixel format of the raw image data, e.g. `NV12` or `BayerRGGB`.
+    ///
+    /// If set, the image data is the raw buffer of that format, see [`components::PixelFormat`][crate::components::PixelFormat].
+    pub pixel_format: Option<crate::components::PixelFormat>,
+
     /// Opacity of the image, useful for layering several images.
     ///
     /// Defaults to 1.0 (fully opaque).
@@ -85,6 +100,8 @@ impl ::re_types_core::SizeBytes for Image {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
         self.data.heap_size_bytes()
+            + self.color_model.heap_size_bytes()
+            + self.pixel_format.heap_size_bytes()
             + self.opacity.heap_size_bytes()
             + self.draw_order.heap_size_bytes()
     }
@@ -92,6 +109,8 @@ impl ::re_types_core::SizeBytes for Image {
     #[inline]
     fn is_pod() -> bool {
         <crate::components::TensorData>::is_pod()
+            && <Option<crate::components::ColorModel>>::is_pod()
+            && <Option<crate::components::PixelFormat>>::is_pod()
             && <Option<crate::components::Opacity>>::is_pod()
             && <Option<crate::components::DrawOrder>>::is_pod()
     }
@@ -103,27 +122,31 @@ static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 1usize]> =
 static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 1usize]> =
     once_cell::sync::Lazy::new(|| ["rerun.components.ImageIndicator".into()]);
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 2usize]> =
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 4usize]> =
     once_cell::sync::Lazy::new(|| {
         [
+            "rerun.components.ColorModel".into(),
+            "rerun.components.PixelFormat".into(),
             "rerun.components.Opacity".into(),
             "rerun.components.DrawOrder".into(),
         ]
     });
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 4usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 6usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             "rerun.components.TensorData".into(),
             "rerun.components.ImageIndicator".into(),
+            "rerun.components.ColorModel".into(),
+            "rerun.components.PixelFormat".into(),
             "rerun.components.Opacity".into(),
             "rerun.components.DrawOrder".into(),
         ]
     });
 
 impl Image {
-    /// The total number of components in the archetype: 1 required, 1 recommended, 2 optional
-    pub const NUM_COMPONENTS: usize = 4usize;
+    /// The total number of components in the archetype: 1 required, 1 recommended, 4 optional
+    pub const NUM_COMPONENTS: usize = 6usize;
 }
 
 /// Indicator component for the [`Image`] [`::re_types_core::Archetype`]
@@ -191,6 +214,24 @@ impl ::re_types_core::Archetype for Image {
                 .ok_or_else(DeserializationError::missing_data)
                 .with_context("rerun.archetypes.Image#data")?
         };
+        let color_model = if let Some(array) = arrays_by_name.get("rerun.components.ColorModel") {
+            <crate::components::ColorModel>::from_arrow_opt(&**array)
+                .with_context("rerun.archetypes.Image#color_model")?
+                .into_iter()
+                .next()
+                .flatten()
+        } else {
+            None
+        };
+        let pixel_format = if let Some(array) = arrays_by_name.get("rerun.components.PixelFormat") {
+            <crate::components::PixelFormat>::from_arrow_opt(&**array)
+                .with_context("rerun.archetypes.Image#pixel_format")?
+                .into_iter()
+                .next()
+                .flatten()
+        } else {
+            None
+        };
         let opacity = if let Some(array) = arrays_by_name.get("rerun.components.Opacity") {
             <crate::components::Opacity>::from_arrow_opt(&**array)
                 .with_context("rerun.archetypes.Image#opacity")?
@@ -211,6 +252,8 @@ impl ::re_types_core::Archetype for Image {
         };
         Ok(Self {
             data,
+            color_model,
+            pixel_format,
             opacity,
             draw_order,
         })
@@ -224,6 +267,12 @@ impl ::re_types_core::AsComponents for Image {
         [
             Some(Self::indicator()),
             Some((&self.data as &dyn ComponentBatch).into()),
+            self.color_model
+                .as_ref()
+                .map(|comp| (comp as &dyn ComponentBatch).into()),
+            self.pixel_format
+                .as_ref()
+                .map(|comp| (comp as &dyn ComponentBatch).into()),
             self.opacity
                 .as_ref()
                 .map(|comp| (comp as &dyn ComponentBatch).into()),
@@ -243,11 +292,38 @@ impl Image {
     pub fn new(data: impl Into<crate::components::TensorData>) -> Self {
         Self {
             data: data.into(),
+            color_model: None,
+            pixel_format: None,
             opacity: None,
             draw_order: None,
         }
     }
 
+    /// The color model of the pixels, e.g. `BGR`.
+    ///
+    /// If not set, it is inferred from the number of channels: `L`, `RGB` or `RGBA`.
+    /// Ignored if a [`components::PixelFormat`][crate::components::PixelFormat] is set.
+    #[inline]
+    pub fn with_color_model(
+        mut self,
+        color_model: impl Into<crate::components::ColorModel>,
+    ) -> Self {
+        self.color_model = Some(color_model.into());
+        self
+    }
+
+    /// The pixel format of the raw image data, e.g. `NV12` or `BayerRGGB`.
+    ///
+    /// If set, the image data is the raw buffer of that format, see [`components::PixelFormat`][crate::components::PixelFormat].
+    #[inline]
+    pub fn with_pixel_format(
+        mut self,
+        pixel_format: impl Into<crate::components::PixelFormat>,
+    ) -> Self {
+        self.pixel_format = Some(pixel_format.into());
+        self
+    }
+
     /// Opacity of the image, useful for layering several images.
     ///
     /// Defaults to 1.0 (fully opaque).
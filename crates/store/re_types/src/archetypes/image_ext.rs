@@ -1,5 +1,6 @@
 use crate::{
-    datatypes::TensorData,
+    components::PixelFormat,
+    datatypes::{TensorBuffer, TensorData, TensorDimension},
     image::{find_non_empty_dim_indices, ImageConstructionError},
 };
 
@@ -42,11 +43,33 @@ impl Image {
 
         Ok(Self {
             data: data.into(),
+            color_model: None,
+            pixel_format: None,
             draw_order: None,
             opacity: None,
         })
     }
 
+    /// Creates an [`Image`] from the raw buffer of a camera, e.g. an `NV12` or Bayer image.
+    ///
+    /// `NV12` and `YUY2` images need a `U8` buffer, Bayer images a `U8` or `U16` buffer.
+    /// The shape of the underlying tensor is derived from the resolution and the [`PixelFormat`].
+    pub fn from_pixel_format(
+        resolution: [u32; 2],
+        pixel_format: PixelFormat,
+        buffer: TensorBuffer,
+    ) -> Self {
+        let [height, width] = pixel_format.buffer_shape(resolution);
+        let data = TensorData::new(
+            vec![
+                TensorDimension::height(height),
+                TensorDimension::width(width),
+            ],
+            buffer,
+        );
+        Self::new(data).with_pixel_format(pixel_format)
+    }
+
     /// Creates a new [`Image`] from a file.
     ///
     /// The image format will be inferred from the path (extension), or the contents if that fails.
@@ -7,6 +7,8 @@ mod arrows3d;
 mod arrows3d_ext;
 mod asset3d;
 mod asset3d_ext;
+mod asset_video;
+mod asset_video_ext;
 mod bar_chart;
 mod boxes2d;
 mod boxes2d_ext;
@@ -17,6 +19,10 @@ mod depth_image_ext;
 mod disconnected_space;
 mod ellipsoids;
 mod ellipsoids_ext;
+mod geo_line_strings;
+mod geo_points;
+mod graph_edges;
+mod graph_nodes;
 mod image;
 mod image_encoded;
 mod image_encoded_ext;
@@ -42,6 +48,7 @@ mod text_document_ext;
 mod text_log;
 mod transform3d;
 mod transform3d_ext;
+mod video_frame_reference;
 mod view_coordinates;
 mod view_coordinates_ext;
 
@@ -49,12 +56,17 @@ pub use self::annotation_context::AnnotationContext;
 pub use self::arrows2d::Arrows2D;
 pub use self::arrows3d::Arrows3D;
 pub use self::asset3d::Asset3D;
+pub use self::asset_video::AssetVideo;
 pub use self::bar_chart::BarChart;
 pub use self::boxes2d::Boxes2D;
 pub use self::boxes3d::Boxes3D;
 pub use self::depth_image::DepthImage;
 pub use self::disconnected_space::DisconnectedSpace;
 pub use self::ellipsoids::Ellipsoids;
+pub use self::geo_line_strings::GeoLineStrings;
+pub use self::geo_points::GeoPoints;
+pub use self::graph_edges::GraphEdges;
+pub use self::graph_nodes::GraphNodes;
 pub use self::image::Image;
 pub use self::image_encoded::ImageEncoded;
 pub use self::line_strips2d::LineStrips2D;
@@ -71,4 +83,5 @@ pub use self::tensor::Tensor;
 pub use self::text_document::TextDocument;
 pub use self::text_log::TextLog;
 pub use self::transform3d::Transform3D;
+pub use self::video_frame_reference::VideoFrameReference;
 pub use self::view_coordinates::ViewCoordinates;
@@ -129,6 +129,25 @@ pub struct Pinhole {
     ///
     /// This is only used for visualization purposes, and does not affect the projection itself.
     pub image_plane_distance: Option<crate::components::ImagePlaneDistance>,
+
+    /// The lens distortion model of the camera.
+    ///
+    /// Only has an effect if [`components::DistortionCoefficients`][crate::components::DistortionCoefficients] are set as well.
+    /// Defaults to `BrownConrady`.
+    pub distortion_model: Option<crate::components::DistortionModel>,
+
+    /// The coefficients of the lens distortion model, see [`components::DistortionCoefficients`][crate::components::DistortionCoefficients].
+    ///
+    /// If not set, the camera is assumed to be free of lens distortion.
+    pub distortion_coefficients: Option<crate::components::DistortionCoefficients>,
+
+    /// Whether images logged under this camera should be shown with the lens distortion removed.
+    ///
+    /// Undistorted images line up with 3D content that is projected into the camera.
+    /// Otherwise, 3D points and line strips are distorted to line up with the images instead,
+    /// but other 3D content is projected into 2D views of the camera without lens distortion.
+    /// Defaults to false.
+    pub undistort_images: Option<crate::components::UndistortImages>,
 }
 
 impl ::re_types_core::SizeBytes for Pinhole {
@@ -138,6 +157,9 @@ impl ::re_types_core::SizeBytes for Pinhole {
             + self.resolution.heap_size_bytes()
             + self.camera_xyz.heap_size_bytes()
             + self.image_plane_distance.heap_size_bytes()
+            + self.distortion_model.heap_size_bytes()
+            + self.distortion_coefficients.heap_size_bytes()
+            + self.undistort_images.heap_size_bytes()
     }
 
     #[inline]
@@ -146,6 +168,9 @@ impl ::re_types_core::SizeBytes for Pinhole {
             && <Option<crate::components::Resolution>>::is_pod()
             && <Option<crate::components::ViewCoordinates>>::is_pod()
             && <Option<crate::components::ImagePlaneDistance>>::is_pod()
+            && <Option<crate::components::DistortionModel>>::is_pod()
+            && <Option<crate::components::DistortionCoefficients>>::is_pod()
+            && <Option<crate::components::UndistortImages>>::is_pod()
     }
 }
 
@@ -160,15 +185,18 @@ static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 2usize]> =
         ]
     });
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 2usize]> =
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 5usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             "rerun.components.ViewCoordinates".into(),
             "rerun.components.ImagePlaneDistance".into(),
+            "rerun.components.DistortionModel".into(),
+            "rerun.components.DistortionCoefficients".into(),
+            "rerun.components.UndistortImages".into(),
         ]
     });
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 5usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 8usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             "rerun.components.PinholeProjection".into(),
@@ -176,12 +204,15 @@ static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 5usize]> =
             "rerun.components.PinholeIndicator".into(),
             "rerun.components.ViewCoordinates".into(),
             "rerun.components.ImagePlaneDistance".into(),
+            "rerun.components.DistortionModel".into(),
+            "rerun.components.DistortionCoefficients".into(),
+            "rerun.components.UndistortImages".into(),
         ]
     });
 
 impl Pinhole {
-    /// The total number of components in the archetype: 1 required, 2 recommended, 2 optional
-    pub const NUM_COMPONENTS: usize = 5usize;
+    /// The total number of components in the archetype: 1 required, 2 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 8usize;
 }
 
 /// Indicator component for the [`Pinhole`] [`::re_types_core::Archetype`]
@@ -278,11 +309,44 @@ impl ::re_types_core::Archetype for Pinhole {
             } else {
                 None
             };
+        let distortion_model =
+            if let Some(array) = arrays_by_name.get("rerun.components.DistortionModel") {
+                <crate::components::DistortionModel>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.Pinhole#distortion_model")?
+                    .into_iter()
+                    .next()
+                    .flatten()
+            } else {
+                None
+            };
+        let distortion_coefficients =
+            if let Some(array) = arrays_by_name.get("rerun.components.DistortionCoefficients") {
+                <crate::components::DistortionCoefficients>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.Pinhole#distortion_coefficients")?
+                    .into_iter()
+                    .next()
+                    .flatten()
+            } else {
+                None
+            };
+        let undistort_images =
+            if let Some(array) = arrays_by_name.get("rerun.components.UndistortImages") {
+                <crate::components::UndistortImages>::from_arrow_opt(&**array)
+                    .with_context("rerun.archetypes.Pinhole#undistort_images")?
+                    .into_iter()
+                    .next()
+                    .flatten()
+            } else {
+                None
+            };
         Ok(Self {
             image_from_camera,
             resolution,
             camera_xyz,
             image_plane_distance,
+            distortion_model,
+            distortion_coefficients,
+            undistort_images,
         })
     }
 }
@@ -303,6 +367,15 @@ impl ::re_types_core::AsComponents for Pinhole {
             self.image_plane_distance
                 .as_ref()
                 .map(|comp| (comp as &dyn ComponentBatch).into()),
+            self.distortion_model
+                .as_ref()
+                .map(|comp| (comp as &dyn ComponentBatch).into()),
+            self.distortion_coefficients
+                .as_ref()
+                .map(|comp| (comp as &dyn ComponentBatch).into()),
+            self.undistort_images
+                .as_ref()
+                .map(|comp| (comp as &dyn ComponentBatch).into()),
         ]
         .into_iter()
         .flatten()
@@ -319,6 +392,9 @@ impl Pinhole {
             resolution: None,
             camera_xyz: None,
             image_plane_distance: None,
+            distortion_model: None,
+            distortion_coefficients: None,
+            undistort_images: None,
         }
     }
 
@@ -383,4 +459,44 @@ impl Pinhole {
         self.image_plane_distance = Some(image_plane_distance.into());
         self
     }
+
+    /// The lens distortion model of the camera.
+    ///
+    /// Only has an effect if [`components::DistortionCoefficients`][crate::components::DistortionCoefficients] are set as well.
+    /// Defaults to `BrownConrady`.
+    #[inline]
+    pub fn with_distortion_model(
+        mut self,
+        distortion_model: impl Into<crate::components::DistortionModel>,
+    ) -> Self {
+        self.distortion_model = Some(distortion_model.into());
+        self
+    }
+
+    /// The coefficients of the lens distortion model, see [`components::DistortionCoefficients`][crate::components::DistortionCoefficients].
+    ///
+    /// If not set, the camera is assumed to be free of lens distortion.
+    #[inline]
+    pub fn with_distortion_coefficients(
+        mut self,
+        distortion_coefficients: impl Into<crate::components::DistortionCoefficients>,
+    ) -> Self {
+        self.distortion_coefficients = Some(distortion_coefficients.into());
+        self
+    }
+
+    /// Whether images logged under this camera should be shown with the lens distortion removed.
+    ///
+    /// Undistorted images line up with 3D content that is projected into the camera.
+    /// Otherwise, 3D points and line strips are distorted to line up with the images instead,
+    /// but other 3D content is projected into 2D views of the camera without lens distortion.
+    /// Defaults to false.
+    #[inline]
+    pub fn with_undistort_images(
+        mut self,
+        undistort_images: impl Into<crate::components::UndistortImages>,
+    ) -> Self {
+        self.undistort_images = Some(undistort_images.into());
+        self
+    }
 }
@@ -1,3 +1,4 @@
+use crate::components::{DistortionCoefficients, DistortionModel};
 use crate::datatypes::Vec2D;
 
 use super::Pinhole;
@@ -68,6 +69,55 @@ impl Pinhole {
         self.resolution.map(|r| r[0] / r[1])
     }
 
+    /// The lens distortion of the camera, if any.
+    ///
+    /// Returns `None` if no distortion coefficients are set, or they are all zero.
+    #[inline]
+    pub fn lens_distortion(&self) -> Option<(DistortionModel, DistortionCoefficients)> {
+        let coefficients = self.distortion_coefficients?;
+        if coefficients.0 .0.iter().all(|&c| c == 0.0) {
+            return None;
+        }
+        Some((self.distortion_model.unwrap_or_default(), coefficients))
+    }
+
+    /// Should images under this camera be shown with the lens distortion removed?
+    #[inline]
+    pub fn undistorts_images(&self) -> bool {
+        self.undistort_images.map_or(false, |u| u.0 .0) && self.lens_distortion().is_some()
+    }
+
+    /// Like [`Self::project`], but also applies the lens distortion of the camera.
+    ///
+    /// This is where a point in camera space ends up on the actual (distorted) image.
+    #[cfg(feature = "glam")]
+    pub fn project_distorted(&self, point: glam::Vec3) -> glam::Vec3 {
+        let Some((model, coefficients)) = self.lens_distortion() else {
+            return self.project(point);
+        };
+        if point.z <= 0.0 {
+            return self.project(point);
+        }
+        let [x, y] = model.distort(&coefficients, [point.x / point.z, point.y / point.z]);
+        self.project(glam::vec3(x * point.z, y * point.z, point.z))
+    }
+
+    /// Like [`Self::unproject`], but also removes the lens distortion of the camera.
+    ///
+    /// Takes pixel coordinates on the actual (distorted) image.
+    #[cfg(feature = "glam")]
+    pub fn unproject_distorted(&self, pixel: glam::Vec3) -> glam::Vec3 {
+        let Some((model, coefficients)) = self.lens_distortion() else {
+            return self.unproject(pixel);
+        };
+        if pixel.z <= 0.0 {
+            return self.unproject(pixel);
+        }
+        let normalized = self.unproject(glam::vec3(pixel.x, pixel.y, 1.0));
+        let [x, y] = model.undistort(&coefficients, [normalized.x, normalized.y]);
+        glam::vec3(x * pixel.z, y * pixel.z, pixel.z)
+    }
+
     // ------------------------------------------------------------------------
     // Forwarding calls to `PinholeProjection`:
 
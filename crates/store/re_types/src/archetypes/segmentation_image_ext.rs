@@ -1,7 +1,7 @@
 use crate::{
-    components::{ChannelDataType, Resolution2D},
-    datatypes::{Blob, TensorBuffer, TensorData},
-    image::{find_non_empty_dim_indices, ImageConstructionError},
+    components::Resolution2D,
+    datatypes::TensorData,
+    image::{blob_and_datatype_from_tensor, find_non_empty_dim_indices, ImageConstructionError},
 };
 
 use super::SegmentationImage;
@@ -27,21 +27,8 @@ impl SegmentationImage {
             return Err(ImageConstructionError::BadImageShape(tensor_data.shape));
         }
 
-        let (blob, data_type) = match tensor_data.buffer {
-            TensorBuffer::U8(buffer) => (Blob(buffer), ChannelDataType::U8),
-            TensorBuffer::U16(buffer) => (Blob(buffer.cast_to_u8()), ChannelDataType::U16),
-            TensorBuffer::U32(buffer) => (Blob(buffer.cast_to_u8()), ChannelDataType::U32),
-            TensorBuffer::U64(buffer) => (Blob(buffer.cast_to_u8()), ChannelDataType::U64),
-            TensorBuffer::I8(buffer) => (Blob(buffer.cast_to_u8()), ChannelDataType::I8),
-            TensorBuffer::I16(buffer) => (Blob(buffer.cast_to_u8()), ChannelDataType::I16),
-            TensorBuffer::I32(buffer) => (Blob(buffer.cast_to_u8()), ChannelDataType::I32),
-            TensorBuffer::I64(buffer) => (Blob(buffer.cast_to_u8()), ChannelDataType::I64),
-            TensorBuffer::F16(buffer) => (Blob(buffer.cast_to_u8()), ChannelDataType::F16),
-            TensorBuffer::F32(buffer) => (Blob(buffer.cast_to_u8()), ChannelDataType::F32),
-            TensorBuffer::F64(buffer) => (Blob(buffer.cast_to_u8()), ChannelDataType::F64),
-            TensorBuffer::Nv12(_) | TensorBuffer::Yuy2(_) => {
-                return Err(ImageConstructionError::ChromaDownsamplingNotSupported);
-            }
+        let Some((blob, data_type)) = blob_and_datatype_from_tensor(tensor_data.buffer) else {
+            return Err(ImageConstructionError::ChromaDownsamplingNotSupported);
         };
 
         let (height, width) = (
@@ -0,0 +1,193 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/video_frame_reference.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::external::arrow2;
+use ::re_types_core::ComponentName;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, MaybeOwnedComponentBatch};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: References a single frame of the [`archetypes::AssetVideo`][crate::archetypes::AssetVideo] logged on the same entity.
+///
+/// Log one of these on a timeline for each point in time that should show a frame of the video.
+/// When scrubbing or playing that timeline, the viewer shows the referenced frames, decoding
+/// them on demand.
+///
+/// ## Example
+///
+/// ### Video with a frame reference per frame
+/// ```ignore
+/// use rerun::external::anyhow;
+///
+/// fn main() -> anyhow::Result<()> {
+///     let args = std::env::args().collect::<Vec<_>>();
+///     let Some(path) = args.get(1) else {
+///         anyhow::bail!("Usage: {} <path_to_video.[mp4]>", args[0]);
+///     };
+///
+///     let rec = rerun::RecordingStreamBuilder::new("rerun_example_asset_video").spawn()?;
+///
+///     let video = rerun::AssetVideo::from_file_path(path)?;
+///     rec.log_static("video", &video)?;
+///
+///     // Show every frame of the video at the time it would be shown when playing it.
+///     for timestamp_ns in video.read_frame_timestamps_ns()? {
+///         rec.set_time_nanos("video_time", timestamp_ns);
+///         rec.log(
+///             "video",
+///             &rerun::VideoFrameReference::new(rerun::components::VideoTimestamp::from_nanoseconds(
+///                 timestamp_ns,
+///             )),
+///         )?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VideoFrameReference {
+    /// Which frame of the video to show.
+    ///
+    /// This is the last frame that is shown at or before this timestamp, i.e. the one that would
+    /// be on screen at that point when playing the video.
+    pub timestamp: crate::components::VideoTimestamp,
+}
+
+impl ::re_types_core::SizeBytes for VideoFrameReference {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.timestamp.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::components::VideoTimestamp>::is_pod()
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 1usize]> =
+    once_cell::sync::Lazy::new(|| ["rerun.components.VideoTimestamp".into()]);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 1usize]> =
+    once_cell::sync::Lazy::new(|| ["rerun.components.VideoFrameReferenceIndicator".into()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentName; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            "rerun.components.VideoTimestamp".into(),
+            "rerun.components.VideoFrameReferenceIndicator".into(),
+        ]
+    });
+
+impl VideoFrameReference {
+    /// The total number of components in the archetype: 1 required, 1 recommended, 0 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
+}
+
+/// Indicator component for the [`VideoFrameReference`] [`::re_types_core::Archetype`]
+pub type VideoFrameReferenceIndicator =
+    ::re_types_core::GenericIndicatorComponent<VideoFrameReference>;
+
+impl ::re_types_core::Archetype for VideoFrameReference {
+    type Indicator = VideoFrameReferenceIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.VideoFrameReference".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Video frame reference"
+    }
+
+    #[inline]
+    fn indicator() -> MaybeOwnedComponentBatch<'static> {
+        static INDICATOR: VideoFrameReferenceIndicator = VideoFrameReferenceIndicator::DEFAULT;
+        MaybeOwnedComponentBatch::Ref(&INDICATOR)
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentName]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentName, Box<dyn arrow2::array::Array>)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_name: ::std::collections::HashMap<_, _> = arrow_data
+            .into_iter()
+            .map(|(name, array)| (name.full_name(), array))
+            .collect();
+        let timestamp = {
+            let array = arrays_by_name
+                .get("rerun.components.VideoTimestamp")
+                .ok_or_else(DeserializationError::missing_data)
+                .with_context("rerun.archetypes.VideoFrameReference#timestamp")?;
+            <crate::components::VideoTimestamp>::from_arrow_opt(&**array)
+                .with_context("rerun.archetypes.VideoFrameReference#timestamp")?
+                .into_iter()
+                .next()
+                .flatten()
+                .ok_or_else(DeserializationError::missing_data)
+                .with_context("rerun.archetypes.VideoFrameReference#timestamp")?
+        };
+        Ok(Self { timestamp })
+    }
+}
+
+impl ::re_types_core::AsComponents for VideoFrameReference {
+    fn as_component_batches(&self) -> Vec<MaybeOwnedComponentBatch<'_>> {
+        re_tracing::profile_function!();
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            Some((&self.timestamp as &dyn ComponentBatch).into()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl VideoFrameReference {
+    /// Create a new `VideoFrameReference`.
+    #[inline]
+    pub fn new(timestamp: impl Into<crate::components::VideoTimestamp>) -> Self {
+        Self {
+            timestamp: timestamp.into(),
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 mod background;
 mod dataframe_view_mode;
+mod map_options;
 mod plot_legend;
 mod scalar_axis;
 mod space_view_blueprint;
@@ -16,6 +17,7 @@ mod visual_bounds2d;
 
 pub use self::background::Background;
 pub use self::dataframe_view_mode::DataframeViewMode;
+pub use self::map_options::MapOptions;
 pub use self::plot_legend::PlotLegend;
 pub use self::scalar_axis::ScalarAxis;
 pub use self::space_view_blueprint::SpaceViewBlueprint;
@@ -22,6 +22,7 @@ mod space_view_origin;
 mod space_view_origin_ext;
 mod tensor_dimension_index_slider;
 mod tensor_dimension_index_slider_ext;
+mod tile_directory;
 mod view_fit;
 mod viewer_recommendation_hash;
 mod viewer_recommendation_hash_ext;
@@ -47,6 +48,7 @@ pub use self::sort_order::SortOrder;
 pub use self::space_view_class::SpaceViewClass;
 pub use self::space_view_origin::SpaceViewOrigin;
 pub use self::tensor_dimension_index_slider::TensorDimensionIndexSlider;
+pub use self::tile_directory::TileDirectory;
 pub use self::view_fit::ViewFit;
 pub use self::viewer_recommendation_hash::ViewerRecommendationHash;
 pub use self::visible::Visible;
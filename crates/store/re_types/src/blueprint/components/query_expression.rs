@@ -27,7 +27,8 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 ///
 /// The `/**` suffix matches the whole subtree, i.e. self and any child, recursively
 /// (`/world/**` matches both `/world` and `/world/car/driver`).
-/// Other uses of `*` are not (yet) supported.
+/// `*` matches a single part, or any characters within a part (`/robot_*/camera` matches `/robot_1/camera`),
+/// and `**` in the middle of a path matches any number of parts (`/world/**/wheel` matches `/world/car/wheel`).
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct QueryExpression(pub crate::datatypes::Utf8);
@@ -0,0 +1,52 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/views/map.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::external::arrow2;
+use ::re_types_core::ComponentName;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, MaybeOwnedComponentBatch};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **View**: A 2D map view to display geospatial primitives.
+#[derive(Clone, Debug)]
+pub struct MapView {
+    /// Configures the map background.
+    pub map_options: crate::blueprint::archetypes::MapOptions,
+
+    /// Configures which range on each timeline is shown by this view (unless specified differently per entity).
+    ///
+    /// If not specified, the default is to show the latest state of each component.
+    /// If a timeline is specified more than once, the first entry will be used.
+    pub time_ranges: crate::blueprint::archetypes::VisibleTimeRanges,
+}
+
+impl ::re_types_core::SizeBytes for MapView {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.map_options.heap_size_bytes() + self.time_ranges.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::blueprint::archetypes::MapOptions>::is_pod()
+            && <crate::blueprint::archetypes::VisibleTimeRanges>::is_pod()
+    }
+}
+
+impl ::re_types_core::View for MapView {
+    #[inline]
+    fn identifier() -> ::re_types_core::SpaceViewClassIdentifier {
+        "Map".into()
+    }
+}
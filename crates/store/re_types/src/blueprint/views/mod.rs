@@ -1,6 +1,8 @@
 // DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
 
 mod bar_chart_view;
+mod graph_view;
+mod map_view;
 mod spatial2d_view;
 mod spatial3d_view;
 mod tensor_view;
@@ -9,6 +11,8 @@ mod text_log_view;
 mod time_series_view;
 
 pub use self::bar_chart_view::BarChartView;
+pub use self::graph_view::GraphView;
+pub use self::map_view::MapView;
 pub use self::spatial2d_view::Spatial2DView;
 pub use self::spatial3d_view::Spatial3DView;
 pub use self::tensor_view::TensorView;
@@ -21,6 +21,8 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 /// **Component**: Specified what color components are present in an [`archetypes::Image`][crate::archetypes::Image].
 ///
 /// This combined with [`components::ChannelDataType`][crate::components::ChannelDataType] determines the pixel format of an image.
+/// Formats that cannot be described this way (e.g. chroma-downsampled or Bayer-mosaiced images)
+/// use a [`components::PixelFormat`][crate::components::PixelFormat] instead.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
 pub enum ColorModel {
     /// Grayscale luminance intencity/brightness/value, sometimes called `Y`
@@ -32,12 +34,18 @@ pub enum ColorModel {
 
     /// Red, Green, Blue, Alpha
     Rgba = 3,
+
+    /// Blue, Green, Red
+    Bgr = 4,
+
+    /// Blue, Green, Red, Alpha
+    Bgra = 5,
 }
 
 impl ::re_types_core::reflection::Enum for ColorModel {
     #[inline]
     fn variants() -> &'static [Self] {
-        &[Self::L, Self::Rgb, Self::Rgba]
+        &[Self::L, Self::Rgb, Self::Rgba, Self::Bgr, Self::Bgra]
     }
 
     #[inline]
@@ -46,6 +54,8 @@ impl ::re_types_core::reflection::Enum for ColorModel {
             Self::L => "Grayscale luminance intencity/brightness/value, sometimes called `Y`",
             Self::Rgb => "Red, Green, Blue",
             Self::Rgba => "Red, Green, Blue, Alpha",
+            Self::Bgr => "Blue, Green, Red",
+            Self::Bgra => "Blue, Green, Red, Alpha",
         }
     }
 }
@@ -68,6 +78,8 @@ impl std::fmt::Display for ColorModel {
             Self::L => write!(f, "L"),
             Self::Rgb => write!(f, "Rgb"),
             Self::Rgba => write!(f, "Rgba"),
+            Self::Bgr => write!(f, "Bgr"),
+            Self::Bgra => write!(f, "Bgra"),
         }
     }
 }
@@ -92,8 +104,12 @@ impl ::re_types_core::Loggable for ColorModel {
                 Field::new("L", DataType::Null, true),
                 Field::new("RGB", DataType::Null, true),
                 Field::new("RGBA", DataType::Null, true),
+                Field::new("BGR", DataType::Null, true),
+                Field::new("BGRA", DataType::Null, true),
             ]),
-            Some(std::sync::Arc::new(vec![0i32, 1i32, 2i32, 3i32])),
+            Some(std::sync::Arc::new(vec![
+                0i32, 1i32, 2i32, 3i32, 4i32, 5i32,
+            ])),
             UnionMode::Sparse,
         )
     }
@@ -116,7 +132,7 @@ impl ::re_types_core::Loggable for ColorModel {
                     datum
                 })
                 .collect();
-            let num_variants = 3usize;
+            let num_variants = 5usize;
             let types = data
                 .iter()
                 .map(|a| match a.as_deref() {
@@ -159,6 +175,8 @@ impl ::re_types_core::Loggable for ColorModel {
                     1 => Ok(Some(Self::L)),
                     2 => Ok(Some(Self::Rgb)),
                     3 => Ok(Some(Self::Rgba)),
+                    4 => Ok(Some(Self::Bgr)),
+                    5 => Ok(Some(Self::Bgra)),
                     _ => Err(DeserializationError::missing_union_arm(
                         Self::arrow_datatype(),
                         "<invalid>",
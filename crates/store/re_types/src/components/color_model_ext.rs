@@ -8,8 +8,23 @@ impl ColorModel {
     pub fn num_channels(self) -> usize {
         match self {
             Self::L => 1,
-            Self::Rgb => 3,
-            Self::Rgba => 4,
+            Self::Rgb | Self::Bgr => 3,
+            Self::Rgba | Self::Bgra => 4,
         }
     }
+
+    /// Do we have an alpha channel?
+    #[inline]
+    pub fn has_alpha(self) -> bool {
+        match self {
+            Self::L | Self::Rgb | Self::Bgr => false,
+            Self::Rgba | Self::Bgra => true,
+        }
+    }
+
+    /// Are the red and blue channels swapped, i.e. is this `BGR` or `BGRA`?
+    #[inline]
+    pub fn is_bgr(self) -> bool {
+        matches!(self, Self::Bgr | Self::Bgra)
+    }
 }
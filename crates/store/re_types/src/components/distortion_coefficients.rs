@@ -0,0 +1,121 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/components/distortion_coefficients.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::external::arrow2;
+use ::re_types_core::ComponentName;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, MaybeOwnedComponentBatch};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: Coefficients of the lens distortion of a camera.
+///
+/// Stored in the same order as OpenCV uses them: `[k1, k2, p1, p2, k3, k4, k5, k6]`.
+/// How they are interpreted depends on the [`components::DistortionModel`][crate::components::DistortionModel]:
+/// - `BrownConrady`: `k1`, `k2`, `p1`, `p2`, `k3` (OpenCV's 5 coefficient model).
+/// - `KannalaBrandt`: the first four values are the fisheye coefficients `k1`, `k2`, `k3`, `k4`.
+/// - `Rational`: all eight coefficients (OpenCV's `CALIB_RATIONAL_MODEL`).
+///
+/// Unused coefficients should be set to zero.
+#[derive(Clone, Debug, Default, Copy, PartialEq)]
+pub struct DistortionCoefficients(pub crate::datatypes::DistortionCoefficients);
+
+impl ::re_types_core::SizeBytes for DistortionCoefficients {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.0.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::datatypes::DistortionCoefficients>::is_pod()
+    }
+}
+
+impl<T: Into<crate::datatypes::DistortionCoefficients>> From<T> for DistortionCoefficients {
+    fn from(v: T) -> Self {
+        Self(v.into())
+    }
+}
+
+impl std::borrow::Borrow<crate::datatypes::DistortionCoefficients> for DistortionCoefficients {
+    #[inline]
+    fn borrow(&self) -> &crate::datatypes::DistortionCoefficients {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for DistortionCoefficients {
+    type Target = crate::datatypes::DistortionCoefficients;
+
+    #[inline]
+    fn deref(&self) -> &crate::datatypes::DistortionCoefficients {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for DistortionCoefficients {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut crate::datatypes::DistortionCoefficients {
+        &mut self.0
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(DistortionCoefficients);
+
+impl ::re_types_core::Loggable for DistortionCoefficients {
+    type Name = ::re_types_core::ComponentName;
+
+    #[inline]
+    fn name() -> Self::Name {
+        "rerun.components.DistortionCoefficients".into()
+    }
+
+    #[inline]
+    fn arrow_datatype() -> arrow2::datatypes::DataType {
+        crate::datatypes::DistortionCoefficients::arrow_datatype()
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<Box<dyn arrow2::array::Array>>
+    where
+        Self: Clone + 'a,
+    {
+        crate::datatypes::DistortionCoefficients::to_arrow_opt(data.into_iter().map(|datum| {
+            datum.map(|datum| match datum.into() {
+                ::std::borrow::Cow::Borrowed(datum) => ::std::borrow::Cow::Borrowed(&datum.0),
+                ::std::borrow::Cow::Owned(datum) => ::std::borrow::Cow::Owned(datum.0),
+            })
+        }))
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow2::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        crate::datatypes::DistortionCoefficients::from_arrow_opt(arrow_data)
+            .map(|v| v.into_iter().map(|v| v.map(Self)).collect())
+    }
+
+    #[inline]
+    fn from_arrow(arrow_data: &dyn arrow2::array::Array) -> DeserializationResult<Vec<Self>>
+    where
+        Self: Sized,
+    {
+        crate::datatypes::DistortionCoefficients::from_arrow(arrow_data)
+            .map(|v| v.into_iter().map(Self).collect())
+    }
+}
@@ -0,0 +1,112 @@
+use super::{DistortionCoefficients, DistortionModel};
+
+/// Number of iterations used when inverting a distortion model.
+///
+/// All models converge well within this for any reasonable lens.
+const UNDISTORT_ITERATIONS: usize = 20;
+
+impl DistortionModel {
+    /// Applies the lens distortion to undistorted, normalized image coordinates `(x/z, y/z)`.
+    ///
+    /// Returns the distorted normalized image coordinates,
+    /// which the [`super::PinholeProjection`] then maps to pixels.
+    pub fn distort(self, coefficients: &DistortionCoefficients, point: [f32; 2]) -> [f32; 2] {
+        let c = &coefficients.0 .0;
+        let [x, y] = point;
+
+        match self {
+            Self::BrownConrady | Self::Rational => {
+                let r2 = x * x + y * y;
+                let radial = self.radial_factor(c, r2);
+                let [dx, dy] = tangential_offset(c, x, y);
+                [x * radial + dx, y * radial + dy]
+            }
+
+            Self::KannalaBrandt => {
+                let r = x.hypot(y);
+                if r < f32::EPSILON {
+                    return point;
+                }
+                let scale = fisheye_theta_d(c, r.atan()) / r;
+                [x * scale, y * scale]
+            }
+        }
+    }
+
+    /// Removes the lens distortion from distorted, normalized image coordinates.
+    ///
+    /// This is the inverse of [`Self::distort`], found iteratively the same way OpenCV does it.
+    pub fn undistort(self, coefficients: &DistortionCoefficients, point: [f32; 2]) -> [f32; 2] {
+        let c = &coefficients.0 .0;
+        let [xd, yd] = point;
+
+        match self {
+            Self::BrownConrady | Self::Rational => {
+                let [mut x, mut y] = point;
+                for _ in 0..UNDISTORT_ITERATIONS {
+                    let radial = self.radial_factor(c, x * x + y * y);
+                    if radial <= 0.0 {
+                        // Outside of the valid range of the model.
+                        return point;
+                    }
+                    let [dx, dy] = tangential_offset(c, x, y);
+                    x = (xd - dx) / radial;
+                    y = (yd - dy) / radial;
+                }
+                [x, y]
+            }
+
+            Self::KannalaBrandt => {
+                let theta_d = xd.hypot(yd).min(std::f32::consts::FRAC_PI_2);
+                if theta_d < f32::EPSILON {
+                    return point;
+                }
+
+                // Newton's method on `theta_d = fisheye_theta_d(theta)`.
+                let [k1, k2, k3, k4, ..] = *c;
+                let mut theta = theta_d;
+                for _ in 0..UNDISTORT_ITERATIONS {
+                    let theta2 = theta * theta;
+                    let derivative = 1.0
+                        + theta2
+                            * (3.0 * k1
+                                + theta2 * (5.0 * k2 + theta2 * (7.0 * k3 + theta2 * 9.0 * k4)));
+                    if derivative.abs() < f32::EPSILON {
+                        break;
+                    }
+                    theta -= (fisheye_theta_d(c, theta) - theta_d) / derivative;
+                }
+
+                let scale = theta.tan() / xd.hypot(yd);
+                [xd * scale, yd * scale]
+            }
+        }
+    }
+
+    /// The radial scaling of the Brown-Conrady and rational models at the squared radius `r2`.
+    fn radial_factor(self, c: &[f32; 8], r2: f32) -> f32 {
+        let [k1, k2, _p1, _p2, k3, k4, k5, k6] = *c;
+        let numerator = 1.0 + r2 * (k1 + r2 * (k2 + r2 * k3));
+        match self {
+            Self::Rational => numerator / (1.0 + r2 * (k4 + r2 * (k5 + r2 * k6))),
+            Self::BrownConrady | Self::KannalaBrandt => numerator,
+        }
+    }
+}
+
+/// The tangential offset of the Brown-Conrady and rational models.
+fn tangential_offset(c: &[f32; 8], x: f32, y: f32) -> [f32; 2] {
+    let [_k1, _k2, p1, p2, ..] = *c;
+    let r2 = x * x + y * y;
+    [
+        2.0 * p1 * x * y + p2 * (r2 + 2.0 * x * x),
+        p1 * (r2 + 2.0 * y * y) + 2.0 * p2 * x * y,
+    ]
+}
+
+/// The distorted angle of the Kannala-Brandt model, for a ray at angle `theta` to the optical axis.
+fn fisheye_theta_d(c: &[f32; 8], theta: f32) -> f32 {
+    let [k1, k2, k3, k4, ..] = *c;
+    let theta2 = theta * theta;
+    theta * (1.0 + theta2 * (k1 + theta2 * (k2 + theta2 * (k3 + theta2 * k4))))
+}
@@ -0,0 +1,13 @@
+use crate::datatypes::DVec2D;
+
+use super::GeoLineString;
+
+// ---
+
+impl GeoLineString {
+    /// Create a new line string from a list of latitude/longitude pairs, in degrees.
+    #[allow(clippy::should_implement_trait)] // vanilla `FromIter` is too limiting in what it can express
+    pub fn from_iter(lat_lon: impl IntoIterator<Item = impl Into<DVec2D>>) -> Self {
+        Self(lat_lon.into_iter().map(Into::into).collect())
+    }
+}
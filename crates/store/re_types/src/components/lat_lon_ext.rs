@@ -0,0 +1,25 @@
+use crate::datatypes::DVec2D;
+
+use super::LatLon;
+
+// ---
+
+impl LatLon {
+    /// Create a new position.
+    #[inline]
+    pub const fn new(lat: f64, lon: f64) -> Self {
+        Self(DVec2D::new(lat, lon))
+    }
+
+    /// The latitude in degrees, i.e. index 0.
+    #[inline]
+    pub fn latitude(&self) -> f64 {
+        self.0.x()
+    }
+
+    /// The longitude in degrees, i.e. index 1.
+    #[inline]
+    pub fn longitude(&self) -> f64 {
+        self.0.y()
+    }
+}
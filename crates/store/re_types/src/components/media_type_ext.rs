@@ -45,6 +45,14 @@ impl MediaType {
     /// Either binary or ASCII.
     /// <https://www.iana.org/assignments/media-types/model/stl>
     pub const STL: &'static str = "model/stl";
+
+    // -------------------------------------------------------
+    // Video:
+
+    /// [MP4 video](https://en.wikipedia.org/wiki/MP4_file_format): `video/mp4`.
+    ///
+    /// <https://www.iana.org/assignments/media-types/video/mp4>
+    pub const MP4: &'static str = "video/mp4";
 }
 
 impl MediaType {
@@ -101,6 +109,15 @@ impl MediaType {
     pub fn stl() -> Self {
         Self(Self::STL.into())
     }
+
+    // -------------------------------------------------------
+    // Video:
+
+    /// `video/mp4`
+    #[inline]
+    pub fn mp4() -> Self {
+        Self(Self::MP4.into())
+    }
 }
 
 impl MediaType {
@@ -20,12 +20,21 @@ mod depth_meter;
 mod depth_meter_ext;
 mod disconnected_space;
 mod disconnected_space_ext;
+mod distortion_coefficients;
+mod distortion_model;
+mod distortion_model_ext;
 mod draw_order;
 mod draw_order_ext;
 mod fill_ratio;
 mod fill_ratio_ext;
 mod gamma_correction;
 mod gamma_correction_ext;
+mod geo_line_string;
+mod geo_line_string_ext;
+mod graph_edge;
+mod graph_node;
+mod graph_node_ext;
+mod graph_type;
 mod half_size2d;
 mod half_size2d_ext;
 mod half_size3d;
@@ -34,6 +43,8 @@ mod image_plane_distance;
 mod image_plane_distance_ext;
 mod keypoint_id;
 mod keypoint_id_ext;
+mod lat_lon;
+mod lat_lon_ext;
 mod line_strip2d;
 mod line_strip2d_ext;
 mod line_strip3d;
@@ -52,6 +63,8 @@ mod opacity_ext;
 mod out_of_tree_transform3d;
 mod pinhole_projection;
 mod pinhole_projection_ext;
+mod pixel_format;
+mod pixel_format_ext;
 mod position2d;
 mod position2d_ext;
 mod position3d;
@@ -91,10 +104,13 @@ mod translation3d;
 mod translation3d_ext;
 mod triangle_indices;
 mod triangle_indices_ext;
+mod undistort_images;
 mod vector2d;
 mod vector2d_ext;
 mod vector3d;
 mod vector3d_ext;
+mod video_timestamp;
+mod video_timestamp_ext;
 mod view_coordinates;
 mod view_coordinates_ext;
 
@@ -110,13 +126,20 @@ pub use self::color_model::ColorModel;
 pub use self::colormap::Colormap;
 pub use self::depth_meter::DepthMeter;
 pub use self::disconnected_space::DisconnectedSpace;
+pub use self::distortion_coefficients::DistortionCoefficients;
+pub use self::distortion_model::DistortionModel;
 pub use self::draw_order::DrawOrder;
 pub use self::fill_ratio::FillRatio;
 pub use self::gamma_correction::GammaCorrection;
+pub use self::geo_line_string::GeoLineString;
+pub use self::graph_edge::GraphEdge;
+pub use self::graph_node::GraphNode;
+pub use self::graph_type::GraphType;
 pub use self::half_size2d::HalfSize2D;
 pub use self::half_size3d::HalfSize3D;
 pub use self::image_plane_distance::ImagePlaneDistance;
 pub use self::keypoint_id::KeypointId;
+pub use self::lat_lon::LatLon;
 pub use self::line_strip2d::LineStrip2D;
 pub use self::line_strip3d::LineStrip3D;
 pub use self::magnification_filter::MagnificationFilter;
@@ -127,6 +150,7 @@ pub use self::name::Name;
 pub use self::opacity::Opacity;
 pub use self::out_of_tree_transform3d::OutOfTreeTransform3D;
 pub use self::pinhole_projection::PinholeProjection;
+pub use self::pixel_format::PixelFormat;
 pub use self::position2d::Position2D;
 pub use self::position3d::Position3D;
 pub use self::radius::Radius;
@@ -148,6 +172,8 @@ pub use self::transform3d::Transform3D;
 pub use self::transform_mat3x3::TransformMat3x3;
 pub use self::translation3d::Translation3D;
 pub use self::triangle_indices::TriangleIndices;
+pub use self::undistort_images::UndistortImages;
 pub use self::vector2d::Vector2D;
 pub use self::vector3d::Vector3D;
+pub use self::video_timestamp::VideoTimestamp;
 pub use self::view_coordinates::ViewCoordinates;
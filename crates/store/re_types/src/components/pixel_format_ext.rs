@@ -0,0 +1,85 @@
+use super::{ChannelDataType, PixelFormat};
+
+impl PixelFormat {
+    /// Is this a YUV format with chroma downsampling (`NV12` or `YUY2`)?
+    #[inline]
+    pub fn is_chroma_downsampled(self) -> bool {
+        matches!(self, Self::Nv12 | Self::Yuy2)
+    }
+
+    /// Is this a raw Bayer mosaic?
+    #[inline]
+    pub fn is_bayer(self) -> bool {
+        self.bayer_red_offset().is_some()
+    }
+
+    /// The number of bits used by a single pixel, given the datatype of the raw buffer.
+    ///
+    /// Note that this is not necessarily divisible by 8!
+    #[inline]
+    pub fn bits_per_pixel(self, data_type: ChannelDataType) -> usize {
+        match self {
+            Self::Nv12 => 12,
+            Self::Yuy2 => 16,
+            Self::BayerRggb | Self::BayerBggr | Self::BayerGrbg | Self::BayerGbrg => {
+                data_type.bits()
+            }
+        }
+    }
+
+    /// The `[height, width]` shape of the raw buffer of an image with the given `[width, height]`.
+    #[inline]
+    pub fn buffer_shape(self, [width, height]: [u32; 2]) -> [u64; 2] {
+        let [width, height] = [width as u64, height as u64];
+        match self {
+            Self::Nv12 => [height * 3 / 2, width],
+            Self::Yuy2 => [height, width * 2],
+            Self::BayerRggb | Self::BayerBggr | Self::BayerGrbg | Self::BayerGbrg => {
+                [height, width]
+            }
+        }
+    }
+
+    /// The `[width, height]` of an image whose raw buffer has the shape `[height, width]`.
+    ///
+    /// This is the inverse of [`Self::buffer_shape`].
+    ///
+    /// Returns `None` if the shape of the buffer cannot hold an image of this format.
+    pub fn resolution_from_buffer_shape(
+        self,
+        [buffer_height, buffer_width]: [u64; 2],
+    ) -> Option<[u32; 2]> {
+        let [width, height] = match self {
+            Self::Nv12 => {
+                if buffer_height % 3 != 0 {
+                    return None;
+                }
+                [buffer_width, buffer_height / 3 * 2]
+            }
+            Self::Yuy2 => {
+                if buffer_width % 2 != 0 {
+                    return None;
+                }
+                [buffer_width / 2, buffer_height]
+            }
+            Self::BayerRggb | Self::BayerBggr | Self::BayerGrbg | Self::BayerGbrg => {
+                [buffer_width, buffer_height]
+            }
+        };
+        Some([width.try_into().ok()?, height.try_into().ok()?])
+    }
+
+    /// Position `[x, y]` of the red sample within the top-left 2x2 block of a Bayer mosaic.
+    ///
+    /// Returns `None` for formats that aren't Bayer mosaics.
+    #[inline]
+    pub fn bayer_red_offset(self) -> Option<[u32; 2]> {
+        match self {
+            Self::BayerRggb => Some([0, 0]),
+            Self::BayerGrbg => Some([1, 0]),
+            Self::BayerGbrg => Some([0, 1]),
+            Self::BayerBggr => Some([1, 1]),
+            Self::Nv12 | Self::Yuy2 => None,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use re_types_core::datatypes::TimeInt;
+
+use super::VideoTimestamp;
+
+impl VideoTimestamp {
+    /// Create a new [`VideoTimestamp`] from a timestamp in nanoseconds, since the first frame.
+    #[inline]
+    pub fn from_nanoseconds(timestamp_ns: i64) -> Self {
+        Self(TimeInt(timestamp_ns))
+    }
+
+    /// Create a new [`VideoTimestamp`] from a timestamp in seconds, since the first frame.
+    #[inline]
+    pub fn from_seconds(timestamp_s: f64) -> Self {
+        Self::from_nanoseconds((timestamp_s * 1e9).round() as i64)
+    }
+
+    /// The timestamp in nanoseconds, since the first frame.
+    #[inline]
+    pub fn as_nanoseconds(self) -> i64 {
+        self.0 .0
+    }
+}
+
+impl Default for VideoTimestamp {
+    #[inline]
+    fn default() -> Self {
+        Self::from_nanoseconds(0)
+    }
+}
@@ -0,0 +1,258 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/datatypes/distortion_coefficients.fbs".
+
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::external::arrow2;
+use ::re_types_core::ComponentName;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch, MaybeOwnedComponentBatch};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Datatype**: Coefficients of a lens distortion model.
+///
+/// Stored in the same order as OpenCV uses them: `[k1, k2, p1, p2, k3, k4, k5, k6]`,
+/// where `k*` are radial and `p*` are tangential coefficients.
+/// Models that use fewer coefficients ignore the remaining ones, which should be set to zero.
+#[derive(Clone, Debug, Default, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(transparent)]
+pub struct DistortionCoefficients(
+    /// `[k1, k2, p1, p2, k3, k4, k5, k6]`
+    pub [f32; 8usize],
+);
+
+impl ::re_types_core::SizeBytes for DistortionCoefficients {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.0.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <[f32; 8usize]>::is_pod()
+    }
+}
+
+impl From<[f32; 8usize]> for DistortionCoefficients {
+    #[inline]
+    fn from(coefficients: [f32; 8usize]) -> Self {
+        Self(coefficients)
+    }
+}
+
+impl From<DistortionCoefficients> for [f32; 8usize] {
+    #[inline]
+    fn from(value: DistortionCoefficients) -> Self {
+        value.0
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(DistortionCoefficients);
+
+impl ::re_types_core::Loggable for DistortionCoefficients {
+    type Name = ::re_types_core::DatatypeName;
+
+    #[inline]
+    fn name() -> Self::Name {
+        "rerun.datatypes.DistortionCoefficients".into()
+    }
+
+    #[inline]
+    fn arrow_datatype() -> arrow2::datatypes::DataType {
+        #![allow(clippy::wildcard_imports)]
+        use arrow2::datatypes::*;
+        DataType::FixedSizeList(
+            std::sync::Arc::new(Field::new("item", DataType::Float32, false)),
+            8usize,
+        )
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<Box<dyn arrow2::array::Array>>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        use arrow2::{array::*, datatypes::*};
+        Ok({
+            let (somes, data0): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    let datum = datum.map(|datum| datum.into_owned().0);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let data0_bitmap: Option<arrow2::bitmap::Bitmap> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            {
+                use arrow2::{buffer::Buffer, offset::OffsetsBuffer};
+                let data0_inner_data: Vec<_> = data0
+                    .into_iter()
+                    .flat_map(|v| match v {
+                        Some(v) => itertools::Either::Left(v.into_iter()),
+                        None => itertools::Either::Right(
+                            std::iter::repeat(Default::default()).take(8usize),
+                        ),
+                    })
+                    .collect();
+                let data0_inner_bitmap: Option<arrow2::bitmap::Bitmap> =
+                    data0_bitmap.as_ref().map(|bitmap| {
+                        bitmap
+                            .iter()
+                            .map(|b| std::iter::repeat(b).take(8usize))
+                            .flatten()
+                            .collect::<Vec<_>>()
+                            .into()
+                    });
+                FixedSizeListArray::new(
+                    Self::arrow_datatype(),
+                    PrimitiveArray::new(
+                        DataType::Float32,
+                        data0_inner_data.into_iter().collect(),
+                        data0_inner_bitmap,
+                    )
+                    .boxed(),
+                    data0_bitmap,
+                )
+                .boxed()
+            }
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow2::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        use arrow2::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let arrow_data = arrow_data
+                .as_any()
+                .downcast_ref::<arrow2::array::FixedSizeListArray>()
+                .ok_or_else(|| {
+                    let expected = Self::arrow_datatype();
+                    let actual = arrow_data.data_type().clone();
+                    DeserializationError::datatype_mismatch(expected, actual)
+                })
+                .with_context("rerun.datatypes.DistortionCoefficients#coefficients")?;
+            if arrow_data.is_empty() {
+                Vec::new()
+            } else {
+                let offsets = (0..)
+                    .step_by(8usize)
+                    .zip((8usize..).step_by(8usize).take(arrow_data.len()));
+                let arrow_data_inner = {
+                    let arrow_data_inner = &**arrow_data.values();
+                    arrow_data_inner
+                        .as_any()
+                        .downcast_ref::<Float32Array>()
+                        .ok_or_else(|| {
+                            let expected = DataType::Float32;
+                            let actual = arrow_data_inner.data_type().clone();
+                            DeserializationError::datatype_mismatch(expected, actual)
+                        })
+                        .with_context("rerun.datatypes.DistortionCoefficients#coefficients")?
+                        .into_iter()
+                        .map(|opt| opt.copied())
+                        .collect::<Vec<_>>()
+                };
+                arrow2::bitmap::utils::ZipValidity::new_with_validity(
+                    offsets,
+                    arrow_data.validity(),
+                )
+                .map(|elem| {
+                    elem.map(|(start, end): (usize, usize)| {
+                        debug_assert!(end - start == 8usize);
+                        if end > arrow_data_inner.len() {
+                            return Err(DeserializationError::offset_slice_oob(
+                                (start, end),
+                                arrow_data_inner.len(),
+                            ));
+                        }
+
+                        #[allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+                        let data = unsafe { arrow_data_inner.get_unchecked(start..end) };
+                        let data = data.iter().cloned().map(Option::unwrap_or_default);
+
+                        // NOTE: Unwrapping cannot fail: the length must be correct.
+                        #[allow(clippy::unwrap_used)]
+                        Ok(array_init::from_iter(data).unwrap())
+                    })
+                    .transpose()
+                })
+                .collect::<DeserializationResult<Vec<Option<_>>>>()?
+            }
+            .into_iter()
+        }
+        .map(|v| v.ok_or_else(DeserializationError::missing_data))
+        .map(|res| res.map(|v| Some(Self(v))))
+        .collect::<DeserializationResult<Vec<Option<_>>>>()
+        .with_context("rerun.datatypes.DistortionCoefficients#coefficients")
+        .with_context("rerun.datatypes.DistortionCoefficients")?)
+    }
+
+    #[inline]
+    fn from_arrow(arrow_data: &dyn arrow2::array::Array) -> DeserializationResult<Vec<Self>>
+    where
+        Self: Sized,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        use arrow2::{array::*, buffer::*, datatypes::*};
+        if let Some(validity) = arrow_data.validity() {
+            if validity.unset_bits() != 0 {
+                return Err(DeserializationError::missing_data());
+            }
+        }
+        Ok({
+            let slice = {
+                let arrow_data = arrow_data
+                    .as_any()
+                    .downcast_ref::<arrow2::array::FixedSizeListArray>()
+                    .ok_or_else(|| {
+                        let expected = DataType::FixedSizeList(
+                            std::sync::Arc::new(Field::new("item", DataType::Float32, false)),
+                            8usize,
+                        );
+                        let actual = arrow_data.data_type().clone();
+                        DeserializationError::datatype_mismatch(expected, actual)
+                    })
+                    .with_context("rerun.datatypes.DistortionCoefficients#coefficients")?;
+                let arrow_data_inner = &**arrow_data.values();
+                bytemuck::cast_slice::<_, [_; 8usize]>(
+                    arrow_data_inner
+                        .as_any()
+                        .downcast_ref::<Float32Array>()
+                        .ok_or_else(|| {
+                            let expected = DataType::Float32;
+                            let actual = arrow_data_inner.data_type().clone();
+                            DeserializationError::datatype_mismatch(expected, actual)
+                        })
+                        .with_context("rerun.datatypes.DistortionCoefficients#coefficients")?
+                        .values()
+                        .as_slice(),
+                )
+            };
+            {
+                slice.iter().copied().map(Self).collect::<Vec<_>>()
+            }
+        })
+    }
+}
@@ -12,6 +12,9 @@ mod class_description_map_elem;
 mod class_description_map_elem_ext;
 mod class_id;
 mod class_id_ext;
+mod distortion_coefficients;
+mod dvec2d;
+mod dvec2d_ext;
 mod keypoint_id;
 mod keypoint_id_ext;
 mod keypoint_pair;
@@ -47,6 +50,8 @@ mod transform3d;
 mod transform3d_ext;
 mod translation_rotation_scale3d;
 mod translation_rotation_scale3d_ext;
+mod utf8_pair;
+mod utf8_pair_ext;
 mod uuid;
 mod uuid_ext;
 mod uvec2d;
@@ -70,6 +75,8 @@ pub use self::blob::Blob;
 pub use self::class_description::ClassDescription;
 pub use self::class_description_map_elem::ClassDescriptionMapElem;
 pub use self::class_id::ClassId;
+pub use self::distortion_coefficients::DistortionCoefficients;
+pub use self::dvec2d::DVec2D;
 pub use self::keypoint_id::KeypointId;
 pub use self::keypoint_pair::KeypointPair;
 pub use self::mat3x3::Mat3x3;
@@ -88,6 +95,7 @@ pub use self::tensor_dimension_index_selection::TensorDimensionIndexSelection;
 pub use self::tensor_dimension_selection::TensorDimensionSelection;
 pub use self::transform3d::Transform3D;
 pub use self::translation_rotation_scale3d::TranslationRotationScale3D;
+pub use self::utf8_pair::Utf8Pair;
 pub use self::uuid::Uuid;
 pub use self::uvec2d::UVec2D;
 pub use self::uvec3d::UVec3D;
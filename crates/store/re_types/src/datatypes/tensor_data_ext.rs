@@ -271,7 +271,7 @@ impl TensorData {
                 let u = buf[(uv_offset + (y / 2) * w + x) as usize];
                 let v = buf[(uv_offset + (y / 2) * w + x) as usize + 1];
 
-                Some(Self::set_color_standard(luma, u, v))
+                Some(crate::image::rgb_from_yuv(luma, u, v))
             }
             _ => None,
         }
@@ -297,42 +297,12 @@ impl TensorData {
                     (buf[index], buf[index - 1], buf[index + 1])
                 };
 
-                Some(Self::set_color_standard(luma, u, v))
+                Some(crate::image::rgb_from_yuv(luma, u, v))
             }
             _ => None,
         }
     }
 
-    /// Sets the color standard for the given YUV color.
-    ///
-    /// This conversion mirrors the function of the same name in `crates/viewer/re_renderer/shader/decodings.wgsl`
-    ///
-    /// Specifying the color standard should be exposed in the future [#3541](https://github.com/rerun-io/rerun/pull/3541)
-    fn set_color_standard(y: u8, u: u8, v: u8) -> [u8; 3] {
-        let (y, u, v) = (y as f32, u as f32, v as f32);
-
-        // rescale YUV values
-        let y = (y - 16.0) / 219.0;
-        let u = (u - 128.0) / 224.0;
-        let v = (v - 128.0) / 224.0;
-
-        // BT.601 (aka. SDTV, aka. Rec.601). wiki: https://en.wikipedia.org/wiki/YCbCr#ITU-R_BT.601_conversion
-        let r = y + 1.402 * v;
-        let g = y - 0.344 * u - 0.714 * v;
-        let b = y + 1.772 * u;
-
-        // BT.709 (aka. HDTV, aka. Rec.709). wiki: https://en.wikipedia.org/wiki/YCbCr#ITU-R_BT.709_conversion
-        // let r = y + 1.575 * v;
-        // let g = y - 0.187 * u - 0.468 * v;
-        // let b = y + 1.856 * u;
-
-        [
-            (255.0 * r).clamp(0.0, 255.0) as u8,
-            (255.0 * g).clamp(0.0, 255.0) as u8,
-            (255.0 * b).clamp(0.0, 255.0) as u8,
-        ]
-    }
-
     /// The datatype of the tensor.
     #[inline]
     pub fn dtype(&self) -> TensorDataType {
@@ -0,0 +1,12 @@
+use super::Utf8Pair;
+use crate::datatypes::Utf8;
+
+impl<T: Into<Utf8>> From<(T, T)> for Utf8Pair {
+    #[inline]
+    fn from(value: (T, T)) -> Self {
+        Self {
+            first: value.0.into(),
+            second: value.1.into(),
+        }
+    }
+}
@@ -2,7 +2,10 @@
 
 use smallvec::{smallvec, SmallVec};
 
-use crate::datatypes::{TensorData, TensorDimension};
+use crate::{
+    components::ChannelDataType,
+    datatypes::{Blob, TensorBuffer, TensorData, TensorDimension},
+};
 
 /// Error returned when trying to interpret a tensor as an image.
 #[derive(thiserror::Error, Clone, Debug)]
@@ -76,6 +79,58 @@ pub fn find_non_empty_dim_indices(shape: &[TensorDimension]) -> SmallVec<[usize;
     (min..=max).collect()
 }
 
+/// Converts a tensor buffer into the raw bytes of an image, and the type of each of its channels.
+///
+/// Returns `None` for `NV12` and `YUY2` buffers, which are not made up of channels.
+pub fn blob_and_datatype_from_tensor(
+    tensor_buffer: TensorBuffer,
+) -> Option<(Blob, ChannelDataType)> {
+    match tensor_buffer {
+        TensorBuffer::U8(buffer) => Some((Blob(buffer), ChannelDataType::U8)),
+        TensorBuffer::U16(buffer) => Some((Blob(buffer.cast_to_u8()), ChannelDataType::U16)),
+        TensorBuffer::U32(buffer) => Some((Blob(buffer.cast_to_u8()), ChannelDataType::U32)),
+        TensorBuffer::U64(buffer) => Some((Blob(buffer.cast_to_u8()), ChannelDataType::U64)),
+        TensorBuffer::I8(buffer) => Some((Blob(buffer.cast_to_u8()), ChannelDataType::I8)),
+        TensorBuffer::I16(buffer) => Some((Blob(buffer.cast_to_u8()), ChannelDataType::I16)),
+        TensorBuffer::I32(buffer) => Some((Blob(buffer.cast_to_u8()), ChannelDataType::I32)),
+        TensorBuffer::I64(buffer) => Some((Blob(buffer.cast_to_u8()), ChannelDataType::I64)),
+        TensorBuffer::F16(buffer) => Some((Blob(buffer.cast_to_u8()), ChannelDataType::F16)),
+        TensorBuffer::F32(buffer) => Some((Blob(buffer.cast_to_u8()), ChannelDataType::F32)),
+        TensorBuffer::F64(buffer) => Some((Blob(buffer.cast_to_u8()), ChannelDataType::F64)),
+        TensorBuffer::Nv12(_) | TensorBuffer::Yuy2(_) => None,
+    }
+}
+
+/// Converts a YUV pixel to RGB.
+///
+/// This conversion mirrors the function `set_color_standard` in `crates/viewer/re_renderer/shader/decodings.wgsl`
+///
+/// Specifying the color standard should be exposed in the future [#3541](https://github.com/rerun-io/rerun/pull/3541)
+pub fn rgb_from_yuv(y: u8, u: u8, v: u8) -> [u8; 3] {
+    let (y, u, v) = (y as f32, u as f32, v as f32);
+
+    // rescale YUV values
+    let y = (y - 16.0) / 219.0;
+    let u = (u - 128.0) / 224.0;
+    let v = (v - 128.0) / 224.0;
+
+    // BT.601 (aka. SDTV, aka. Rec.601). wiki: https://en.wikipedia.org/wiki/YCbCr#ITU-R_BT.601_conversion
+    let r = y + 1.402 * v;
+    let g = y - 0.344 * u - 0.714 * v;
+    let b = y + 1.772 * u;
+
+    // BT.709 (aka. HDTV, aka. Rec.709). wiki: https://en.wikipedia.org/wiki/YCbCr#ITU-R_BT.709_conversion
+    // let r = y + 1.575 * v;
+    // let g = y - 0.187 * u - 0.468 * v;
+    // let b = y + 1.856 * u;
+
+    [
+        (255.0 * r).clamp(0.0, 255.0) as u8,
+        (255.0 * g).clamp(0.0, 255.0) as u8,
+        (255.0 * b).clamp(0.0, 255.0) as u8,
+    ]
+}
+
 #[test]
 fn test_find_non_empty_dim_indices() {
     fn expect(shape: &[u64], expected: &[usize]) {
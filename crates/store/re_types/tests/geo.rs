@@ -0,0 +1,72 @@
+use re_types::{
+    archetypes::{GeoLineStrings, GeoPoints},
+    components::{Color, GeoLineString, LatLon, Radius},
+    Archetype as _, AsComponents as _,
+};
+
+#[test]
+fn geo_points_roundtrip() {
+    let expected = GeoPoints {
+        positions: vec![
+            LatLon::new(59.319221, 18.075631), //
+            LatLon::new(-33.856784, 151.215297),
+        ],
+        radii: Some(vec![
+            Radius::from(42.0), //
+            Radius::new_ui_points(43.0),
+        ]),
+        colors: Some(vec![
+            Color::from_unmultiplied_rgba(0xAA, 0x00, 0x00, 0xCC), //
+            Color::from_unmultiplied_rgba(0x00, 0xBB, 0x00, 0xDD),
+        ]),
+    };
+
+    let arch = GeoPoints::new([(59.319221, 18.075631), (-33.856784, 151.215297)])
+        .with_radii([Radius::from(42.0), Radius::new_ui_points(43.0)])
+        .with_colors([0xAA0000CC, 0x00BB00DD]);
+    similar_asserts::assert_eq!(expected, arch);
+
+    eprintln!("arch = {arch:#?}");
+    let serialized = arch.to_arrow().unwrap();
+    for (field, array) in &serialized {
+        eprintln!("{} = {array:#?}", field.name);
+    }
+
+    let deserialized = GeoPoints::from_arrow(serialized).unwrap();
+    similar_asserts::assert_eq!(expected, deserialized);
+}
+
+#[test]
+fn geo_line_strings_roundtrip() {
+    let expected = GeoLineStrings {
+        line_strings: vec![
+            GeoLineString::from_iter([[41.0, -109.0], [41.0, -102.0], [37.0, -102.0]]), //
+            GeoLineString::from_iter([[59.3, 18.1], [59.4, 18.0]]),
+        ],
+        radii: Some(vec![
+            Radius::from(42.0), //
+            Radius::from(43.0),
+        ]),
+        colors: Some(vec![
+            Color::from_unmultiplied_rgba(0xAA, 0x00, 0x00, 0xCC), //
+            Color::from_unmultiplied_rgba(0x00, 0xBB, 0x00, 0xDD),
+        ]),
+    };
+
+    let arch = GeoLineStrings::new([
+        [(41.0, -109.0), (41.0, -102.0), (37.0, -102.0)].to_vec(),
+        [(59.3, 18.1), (59.4, 18.0)].to_vec(),
+    ])
+    .with_radii([42.0, 43.0])
+    .with_colors([0xAA0000CC, 0x00BB00DD]);
+    similar_asserts::assert_eq!(expected, arch);
+
+    eprintln!("arch = {arch:#?}");
+    let serialized = arch.to_arrow().unwrap();
+    for (field, array) in &serialized {
+        eprintln!("{} = {array:#?}", field.name);
+    }
+
+    let deserialized = GeoLineStrings::from_arrow(serialized).unwrap();
+    similar_asserts::assert_eq!(expected, deserialized);
+}
@@ -0,0 +1,77 @@
+use re_types::{
+    archetypes::{GraphEdges, GraphNodes},
+    components::{Color, GraphEdge, GraphNode, GraphType, Position2D, Radius},
+    datatypes::Utf8Pair,
+    Archetype as _, AsComponents as _,
+};
+
+#[test]
+fn nodes_roundtrip() {
+    let expected = GraphNodes {
+        node_ids: vec![
+            GraphNode("a".into()), //
+            GraphNode("b".into()),
+        ],
+        positions: Some(vec![
+            Position2D::new(1.0, 2.0), //
+            Position2D::new(3.0, 4.0),
+        ]),
+        colors: Some(vec![
+            Color::from_unmultiplied_rgba(0xAA, 0x00, 0x00, 0xCC), //
+            Color::from_unmultiplied_rgba(0x00, 0xBB, 0x00, 0xDD),
+        ]),
+        labels: Some(vec![
+            "hello".into(),  //
+            "friend".into(), //
+        ]),
+        radii: Some(vec![
+            Radius::from(42.0), //
+            Radius::from(43.0),
+        ]),
+    };
+
+    let arch = GraphNodes::new(["a", "b"])
+        .with_positions([(1.0, 2.0), (3.0, 4.0)])
+        .with_colors([0xAA0000CC, 0x00BB00DD])
+        .with_labels(["hello", "friend"])
+        .with_radii([42.0, 43.0]);
+    similar_asserts::assert_eq!(expected, arch);
+
+    eprintln!("arch = {arch:#?}");
+    let serialized = arch.to_arrow().unwrap();
+    for (field, array) in &serialized {
+        eprintln!("{} = {array:#?}", field.name);
+    }
+
+    let deserialized = GraphNodes::from_arrow(serialized).unwrap();
+    similar_asserts::assert_eq!(expected, deserialized);
+}
+
+#[test]
+fn edges_roundtrip() {
+    let expected = GraphEdges {
+        edges: vec![
+            GraphEdge(Utf8Pair {
+                first: "a".into(),
+                second: "b".into(),
+            }), //
+            GraphEdge(Utf8Pair {
+                first: "b".into(),
+                second: "c".into(),
+            }),
+        ],
+        graph_type: Some(GraphType::Directed),
+    };
+
+    let arch = GraphEdges::new([("a", "b"), ("b", "c")]).with_graph_type(GraphType::Directed);
+    similar_asserts::assert_eq!(expected, arch);
+
+    eprintln!("arch = {arch:#?}");
+    let serialized = arch.to_arrow().unwrap();
+    for (field, array) in &serialized {
+        eprintln!("{} = {array:#?}", field.name);
+    }
+
+    let deserialized = GraphEdges::from_arrow(serialized).unwrap();
+    similar_asserts::assert_eq!(expected, deserialized);
+}
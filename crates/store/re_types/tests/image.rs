@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use re_types::{
     archetypes::Image,
+    components::{ColorModel, PixelFormat},
     datatypes::{TensorBuffer, TensorData, TensorDimension},
     Archetype as _, AsComponents as _,
 };
@@ -25,6 +26,8 @@ fn image_roundtrip() {
             buffer: TensorBuffer::U8(vec![1, 2, 3, 4, 5, 6].into()),
         }
         .into(),
+        color_model: None,
+        pixel_format: None,
         draw_order: None,
         opacity: None,
     }];
@@ -88,6 +91,8 @@ fn dynamic_image_roundtrip() {
             ),
         }
         .into(),
+        color_model: None,
+        pixel_format: None,
         draw_order: None,
         opacity: None,
     }];
@@ -126,6 +131,92 @@ fn dynamic_image_roundtrip() {
     }
 }
 
+#[test]
+fn image_pixel_format_roundtrip() {
+    let all_expected = [
+        Image {
+            data: TensorData {
+                shape: vec![TensorDimension::height(3), TensorDimension::width(2)],
+                buffer: TensorBuffer::U8(vec![1, 2, 3, 4, 5, 6].into()),
+            }
+            .into(),
+            color_model: None,
+            pixel_format: Some(PixelFormat::Nv12),
+            draw_order: None,
+            opacity: None,
+        },
+        Image {
+            data: TensorData {
+                shape: vec![
+                    TensorDimension::height(2),
+                    TensorDimension::width(2),
+                    TensorDimension::depth(3),
+                ],
+                buffer: TensorBuffer::U8((1..=12).collect::<Vec<u8>>().into()),
+            }
+            .into(),
+            color_model: Some(ColorModel::Bgr),
+            pixel_format: None,
+            draw_order: None,
+            opacity: None,
+        },
+    ];
+
+    let all_arch_serialized = [
+        Image::from_pixel_format(
+            [2, 2],
+            PixelFormat::Nv12,
+            TensorBuffer::U8(vec![1, 2, 3, 4, 5, 6].into()),
+        )
+        .to_arrow()
+        .unwrap(),
+        Image::try_from(ndarray::array![
+            [[1u8, 2, 3], [4, 5, 6]],
+            [[7, 8, 9], [10, 11, 12]]
+        ])
+        .unwrap()
+        .with_color_model(ColorModel::Bgr)
+        .to_arrow()
+        .unwrap(),
+    ];
+
+    for (expected, serialized) in all_expected.into_iter().zip(all_arch_serialized) {
+        let deserialized = Image::from_arrow(serialized).unwrap();
+        similar_asserts::assert_eq!(expected, deserialized);
+    }
+}
+
+#[test]
+fn pixel_format_buffer_shape() {
+    for pixel_format in [
+        PixelFormat::Nv12,
+        PixelFormat::Yuy2,
+        PixelFormat::BayerRggb,
+        PixelFormat::BayerBggr,
+        PixelFormat::BayerGrbg,
+        PixelFormat::BayerGbrg,
+    ] {
+        let resolution = [640, 480];
+        let buffer_shape = pixel_format.buffer_shape(resolution);
+        assert_eq!(
+            pixel_format.resolution_from_buffer_shape(buffer_shape),
+            Some(resolution),
+            "{pixel_format}"
+        );
+    }
+
+    assert_eq!(PixelFormat::Nv12.buffer_shape([640, 480]), [720, 640]);
+    assert_eq!(PixelFormat::Yuy2.buffer_shape([640, 480]), [480, 1280]);
+    assert_eq!(
+        PixelFormat::Nv12.resolution_from_buffer_shape([721, 640]),
+        None
+    );
+    assert_eq!(
+        PixelFormat::Yuy2.resolution_from_buffer_shape([480, 1281]),
+        None
+    );
+}
+
 macro_rules! check_image_array {
     ($img:ty, $typ:ty, $arr:expr, $color_dim:expr) => {{
         let arr = $arr;
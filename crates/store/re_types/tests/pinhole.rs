@@ -13,11 +13,19 @@ fn roundtrip() {
         resolution: Some(components::Resolution([1.0, 2.0].into())),
         camera_xyz: Some(components::ViewCoordinates::RDF),
         image_plane_distance: None,
+        distortion_model: Some(components::DistortionModel::Rational),
+        distortion_coefficients: Some(components::DistortionCoefficients(
+            [0.1, -0.2, 0.01, 0.02, 0.3, 0.4, 0.5, 0.6].into(),
+        )),
+        undistort_images: Some(components::UndistortImages(true.into())),
     };
 
     let arch = Pinhole::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
         .with_resolution([1.0, 2.0])
-        .with_camera_xyz(components::ViewCoordinates::RDF);
+        .with_camera_xyz(components::ViewCoordinates::RDF)
+        .with_distortion_model(components::DistortionModel::Rational)
+        .with_distortion_coefficients([0.1, -0.2, 0.01, 0.02, 0.3, 0.4, 0.5, 0.6])
+        .with_undistort_images(true);
     similar_asserts::assert_eq!(expected, arch);
 
     let expected_extensions: HashMap<_, _> = [
@@ -56,3 +64,54 @@ fn from_focal_length_and_resolution() {
             .with_resolution([3.0, 4.0])
     );
 }
+
+#[test]
+fn distortion_roundtrip() {
+    let models_and_coefficients = [
+        (
+            components::DistortionModel::BrownConrady,
+            [-0.28, 0.07, 0.0002, 0.00002, -0.008, 0.0, 0.0, 0.0],
+        ),
+        (
+            components::DistortionModel::KannalaBrandt,
+            [0.03, -0.01, 0.002, -0.0005, 0.0, 0.0, 0.0, 0.0],
+        ),
+        (
+            components::DistortionModel::Rational,
+            [2.5, 1.1, 0.0001, -0.0002, 0.04, 2.8, 1.7, 0.2],
+        ),
+    ];
+
+    for (model, coefficients) in models_and_coefficients {
+        let coefficients = components::DistortionCoefficients(coefficients.into());
+
+        for point in [[0.0, 0.0], [0.1, -0.2], [-0.4, 0.3], [0.5, 0.5]] {
+            let distorted = model.distort(&coefficients, point);
+            let undistorted = model.undistort(&coefficients, distorted);
+            assert!(
+                (undistorted[0] - point[0]).abs() < 1e-4
+                    && (undistorted[1] - point[1]).abs() < 1e-4,
+                "{model}: {point:?} -> {distorted:?} -> {undistorted:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn lens_distortion() {
+    let pinhole = Pinhole::from_focal_length_and_resolution([500.0, 500.0], [640.0, 480.0]);
+    assert_eq!(pinhole.lens_distortion(), None);
+
+    // All zero coefficients are the same as no distortion at all.
+    let pinhole = pinhole.with_distortion_coefficients([0.0; 8]);
+    assert_eq!(pinhole.lens_distortion(), None);
+
+    let pinhole = pinhole
+        .with_distortion_coefficients([-0.1, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0])
+        .with_undistort_images(true);
+    assert_eq!(
+        pinhole.lens_distortion().map(|(model, _)| model),
+        Some(components::DistortionModel::BrownConrady)
+    );
+    assert!(pinhole.undistorts_images());
+}
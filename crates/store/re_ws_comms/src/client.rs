@@ -5,11 +5,47 @@ use ewebsock::{WsEvent, WsMessage};
 // TODO(jleibs): use thiserror
 pub type Result<T> = anyhow::Result<T>;
 
+/// How a viewer connects to a [`crate::RerunServer`].
+///
+/// TLS is used for `wss://` urls. The certificate of the server is checked against the usual
+/// public certificate authorities.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClientOptions {
+    /// Presented to servers that require authentication.
+    ///
+    /// Added to the url as the [`crate::AUTH_TOKEN_QUERY_KEY`] query parameter.
+    /// Use [`crate::split_auth_token`] to get it out of a url that already holds one.
+    pub auth_token: Option<String>,
+
+    /// Only receive some of the data of the server.
+    ///
+    /// Added to the url as query parameters.
+    pub subscription: crate::Subscription,
+}
+
 /// Connect viewer to server
 pub fn viewer_to_server(
     url: String,
+    options: &ClientOptions,
     on_binary_msg: impl Fn(Vec<u8>) -> ControlFlow<()> + Send + 'static,
 ) -> Result<()> {
+    let url = if options.subscription == crate::Subscription::default() {
+        url
+    } else {
+        let mut url_with_subscription = url::Url::parse(&url)?;
+        options
+            .subscription
+            .append_to_url(&mut url_with_subscription);
+        url_with_subscription.into()
+    };
+
+    // Keep the token out of the logs:
+    let url_with_token = if let Some(auth_token) = &options.auth_token {
+        crate::url_with_auth_token(&url, auth_token)?
+    } else {
+        url.clone()
+    };
+
     let gigs = 1024 * 1024 * 1024;
     let options = ewebsock::Options {
         // This limits the size of one chunk of rerun log data when running a local websocket client.
@@ -19,7 +55,7 @@ pub fn viewer_to_server(
     };
 
     ewebsock::ws_receive(
-        url.clone(),
+        url_with_token,
         options,
         Box::new(move |event: WsEvent| match event {
             WsEvent::Opened => {
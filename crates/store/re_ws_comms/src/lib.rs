@@ -12,12 +12,17 @@ mod client;
 use std::{fmt::Display, str::FromStr};
 
 #[cfg(feature = "client")]
-pub use client::viewer_to_server;
+pub use client::{viewer_to_server, ClientOptions};
 
 #[cfg(feature = "server")]
 mod server;
 #[cfg(feature = "server")]
-pub use server::RerunServer;
+pub use server::{RerunServer, RerunServerOptions};
+
+mod subscription;
+pub use subscription::{
+    Subscription, APP_ID_QUERY_KEY, ENTITY_FILTER_QUERY_KEY, STORE_ID_QUERY_KEY,
+};
 
 use re_log_types::LogMsg;
 
@@ -29,6 +34,58 @@ pub const PROTOCOL: &str = "wss";
 #[cfg(not(feature = "tls"))]
 pub const PROTOCOL: &str = "ws";
 
+/// The query parameter of the WebSocket url which holds the auth token, e.g. `ws://localhost:9877/?token=secret`.
+///
+/// A query parameter rather than a header, since browsers can't set headers on WebSocket requests.
+pub const AUTH_TOKEN_QUERY_KEY: &str = "token";
+
+/// Adds `auth_token` to a WebSocket url, as its [`AUTH_TOKEN_QUERY_KEY`] query parameter.
+///
+/// Any token already in the url is replaced.
+pub fn url_with_auth_token(url: &str, auth_token: &str) -> Result<String, url::ParseError> {
+    let (url, _) = split_auth_token(url);
+    let mut url = url::Url::parse(&url)?;
+    url.query_pairs_mut()
+        .append_pair(AUTH_TOKEN_QUERY_KEY, auth_token);
+    Ok(url.into())
+}
+
+/// Removes the [`AUTH_TOKEN_QUERY_KEY`] query parameter from a WebSocket url, returning the url
+/// without it and the token, if any.
+///
+/// Used to keep the token out of the logs, and to pass it on to `viewer_to_server` separately.
+pub fn split_auth_token(url: &str) -> (String, Option<String>) {
+    let Ok(mut parsed) = url::Url::parse(url) else {
+        return (url.to_owned(), None);
+    };
+
+    let mut auth_token = None;
+    let other_pairs = parsed
+        .query_pairs()
+        .filter(|(key, value)| {
+            if key == AUTH_TOKEN_QUERY_KEY {
+                auth_token = Some(value.to_string());
+                false
+            } else {
+                true
+            }
+        })
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect::<Vec<_>>();
+
+    if auth_token.is_none() {
+        return (url.to_owned(), None);
+    }
+
+    if other_pairs.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(other_pairs);
+    }
+
+    (parsed.into(), auth_token)
+}
+
 // ----------------------------------------------------------------------------
 
 /// Failure to host the Rerun WebSocket server.
@@ -81,11 +138,15 @@ impl FromStr for RerunServerPort {
 
 /// Add a protocol (`ws://` or `wss://`) to the given address.
 pub fn server_url(local_addr: &std::net::SocketAddr) -> String {
+    server_url_with_protocol(PROTOCOL, local_addr)
+}
+
+fn server_url_with_protocol(protocol: &str, local_addr: &std::net::SocketAddr) -> String {
     if local_addr.ip().is_unspecified() {
         // "0.0.0.0"
-        format!("{PROTOCOL}://localhost:{}", local_addr.port())
+        format!("{protocol}://localhost:{}", local_addr.port())
     } else {
-        format!("{PROTOCOL}://{local_addr}")
+        format!("{protocol}://{local_addr}")
     }
 }
 
@@ -110,3 +171,63 @@ pub fn decode_log_msg(data: &[u8]) -> Result<LogMsg, RerunServerError> {
     use bincode::Options as _;
     Ok(bincode::DefaultOptions::new().deserialize(payload)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_with_auth_token() {
+        assert_eq!(
+            "ws://127.0.0.1:9877/?token=secret",
+            url_with_auth_token("ws://127.0.0.1:9877", "secret").unwrap()
+        );
+        assert_eq!(
+            "wss://localhost:9877/?recording=a&token=s%26cret",
+            url_with_auth_token("wss://localhost:9877?recording=a", "s&cret").unwrap()
+        );
+
+        // Replaces the token already there.
+        assert_eq!(
+            "ws://localhost:9877/?token=new",
+            url_with_auth_token("ws://localhost:9877?token=old", "new").unwrap()
+        );
+
+        assert!(url_with_auth_token("localhost:9877", "secret").is_err());
+    }
+
+    #[test]
+    fn test_split_auth_token() {
+        // Left untouched without a token.
+        assert_eq!(
+            ("ws://localhost:9877".to_owned(), None),
+            split_auth_token("ws://localhost:9877")
+        );
+        assert_eq!(
+            ("not a url".to_owned(), None),
+            split_auth_token("not a url")
+        );
+
+        assert_eq!(
+            ("ws://localhost:9877/".to_owned(), Some("secret".to_owned())),
+            split_auth_token("ws://localhost:9877?token=secret")
+        );
+        assert_eq!(
+            (
+                "ws://localhost:9877/?recording=a&entity=%2Fworld".to_owned(),
+                Some("s&cret".to_owned())
+            ),
+            split_auth_token("ws://localhost:9877?recording=a&token=s%26cret&entity=%2Fworld")
+        );
+
+        // Round-trips.
+        let url = url_with_auth_token("ws://localhost:9877/?recording=a", "secret").unwrap();
+        assert_eq!(
+            (
+                "ws://localhost:9877/?recording=a".to_owned(),
+                Some("secret".to_owned())
+            ),
+            split_auth_token(&url)
+        );
+    }
+}
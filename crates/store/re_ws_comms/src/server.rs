@@ -3,11 +3,15 @@
 //! Each incoming log message is stored, and sent to any connected client.
 //! Each connecting client is first sent the history of stored log messages.
 //!
+//! Clients can subscribe to only some of the recordings, or some of their entities, see
+//! [`Subscription`]. The history is kept separately for each recording, so that a busy one
+//! can't push the others out.
+//!
 //! In the future thing will be changed to a protocol where the clients can query
 //! for specific data based on e.g. time.
 
 use std::{
-    collections::VecDeque,
+    collections::{HashMap, VecDeque},
     net::{TcpListener, TcpStream},
     sync::{
         atomic::{AtomicBool, AtomicU64, Ordering},
@@ -17,17 +21,101 @@ use std::{
 
 use parking_lot::Mutex;
 use polling::{Event, Poller};
-use tungstenite::WebSocket;
+use tungstenite::{
+    handshake::server::{ErrorResponse, Request, Response},
+    WebSocket,
+};
 
-use re_log_types::LogMsg;
+use re_log_types::{ApplicationId, EntityPath, LogMsg, StoreId};
 use re_memory::MemoryLimit;
+use re_sdk_comms::{constant_time_eq, Stream};
 use re_smart_channel::ReceiveSet;
 
-use crate::{server_url, RerunServerError, RerunServerPort};
+use crate::{server_url_with_protocol, RerunServerError, RerunServerPort, Subscription};
+
+/// Access control and encryption for a [`RerunServer`].
+#[derive(Clone, Default)]
+pub struct RerunServerOptions {
+    /// If set, clients must present this token, or they are turned away.
+    ///
+    /// See [`crate::AUTH_TOKEN_QUERY_KEY`].
+    pub auth_token: Option<String>,
+
+    /// If set, all connections are encrypted with TLS (`wss://`).
+    #[cfg(feature = "tls")]
+    pub tls_config: Option<Arc<rustls::ServerConfig>>,
+}
+
+impl RerunServerOptions {
+    #[cfg_attr(not(feature = "tls"), allow(clippy::unused_self))]
+    fn protocol(&self) -> &'static str {
+        #[cfg(feature = "tls")]
+        if self.tls_config.is_some() {
+            return "wss";
+        }
+
+        "ws"
+    }
+
+    /// Does the request of the WebSocket handshake carry the right token?
+    fn is_authorized(&self, request: &tungstenite::handshake::server::Request) -> bool {
+        let Some(expected_token) = &self.auth_token else {
+            return true;
+        };
+
+        let query = request.uri().query().unwrap_or_default();
+        url::form_urlencoded::parse(query.as_bytes()).any(|(key, token)| {
+            key == crate::AUTH_TOKEN_QUERY_KEY
+                && constant_time_eq(expected_token.as_bytes(), token.as_bytes())
+        })
+    }
+}
+
+/// An encoded [`LogMsg`], with what we need to know to filter it.
+struct EncodedMsg {
+    store_id: StoreId,
+
+    /// The entity the data is about, if it is data.
+    entity_path: Option<EntityPath>,
+
+    bytes: Vec<u8>,
+}
+
+impl EncodedMsg {
+    fn new(msg: &LogMsg) -> Self {
+        let entity_path = match msg {
+            LogMsg::ArrowMsg(_, arrow_msg) => arrow_msg
+                .schema
+                .metadata
+                .get(re_chunk::TransportChunk::CHUNK_METADATA_KEY_ENTITY_PATH)
+                .map(|entity_path| EntityPath::parse_forgiving(entity_path)),
+            LogMsg::SetStoreInfo(_)
+            | LogMsg::BlueprintActivationCommand(_)
+            | LogMsg::ViewerCommand(..) => None,
+        };
+
+        Self {
+            store_id: msg.store_id().clone(),
+            entity_path,
+            bytes: crate::encode_log_msg(msg),
+        }
+    }
+
+    /// Is the entity of this message included? Whether its store is, is up to the caller.
+    fn is_included_in(&self, subscription: &Subscription) -> bool {
+        self.entity_path.as_ref().map_or(true, |entity_path| {
+            subscription.includes_entity(&self.store_id, entity_path)
+        })
+    }
+}
 
+/// The messages of one store, oldest first, within the memory budget of the server.
 struct MessageQueue {
     server_memory_limit: MemoryLimit,
-    messages: VecDeque<Vec<u8>>,
+    messages: VecDeque<EncodedMsg>,
+
+    /// The total size of [`Self::messages`].
+    bytes_used: u64,
 }
 
 impl MessageQueue {
@@ -35,47 +123,90 @@ impl MessageQueue {
         Self {
             server_memory_limit,
             messages: Default::default(),
+            bytes_used: 0,
         }
     }
 
-    pub fn push(&mut self, msg: Vec<u8>) {
+    pub fn push(&mut self, msg: EncodedMsg) {
         self.gc_if_using_too_much_ram();
+        self.bytes_used += msg.bytes.len() as u64;
         self.messages.push_back(msg);
     }
 
     fn gc_if_using_too_much_ram(&mut self) {
         re_tracing::profile_function!();
 
-        if let Some(max_bytes) = self.server_memory_limit.max_bytes {
-            let max_bytes = max_bytes as u64;
-            let bytes_used = self.messages.iter().map(|m| m.len() as u64).sum::<u64>();
+        let Some(max_bytes) = self.server_memory_limit.max_bytes else {
+            return;
+        };
+        let max_bytes = max_bytes as u64;
+        if self.bytes_used <= max_bytes {
+            return;
+        }
 
-            if max_bytes < bytes_used {
-                re_tracing::profile_scope!("Drop messages");
-                re_log::info_once!(
-                    "Memory limit ({}) exceeded. Dropping old log messages from the server. Clients connecting after this will not see the full history.",
-                    re_format::format_bytes(max_bytes as _)
-                );
+        re_tracing::profile_scope!("Drop messages");
+        re_log::info_once!(
+            "Memory limit ({}) of a recording exceeded. Dropping its old log messages from the server. Clients connecting after this will not see its full history.",
+            re_format::format_bytes(max_bytes as _)
+        );
 
-                let bytes_to_free = bytes_used - max_bytes;
+        let mut bytes_dropped = 0;
+        let mut messages_dropped = 0;
+        while max_bytes < self.bytes_used {
+            let Some(msg) = self.messages.pop_front() else {
+                break;
+            };
+            self.bytes_used -= msg.bytes.len() as u64;
+            bytes_dropped += msg.bytes.len() as u64;
+            messages_dropped += 1;
+        }
 
-                let mut bytes_dropped = 0;
-                let mut messages_dropped = 0;
+        re_log::trace!(
+            "Dropped {} bytes in {messages_dropped} message(s)",
+            re_format::format_bytes(bytes_dropped as _)
+        );
+    }
+}
 
-                while bytes_dropped < bytes_to_free {
-                    if let Some(msg) = self.messages.pop_front() {
-                        bytes_dropped += msg.len() as u64;
-                        messages_dropped += 1;
-                    } else {
-                        break;
-                    }
-                }
+/// What we keep of one recording or blueprint, to send to clients that connect later.
+///
+/// Kept for as long as the server runs, even once all of its messages were dropped:
+/// its later messages still need the store info and the application to be filtered and shown.
+struct StoreHistory {
+    /// `None` until we've seen the [`LogMsg::SetStoreInfo`].
+    application_id: Option<ApplicationId>,
 
-                re_log::trace!(
-                    "Dropped {} bytes in {messages_dropped} message(s)",
-                    re_format::format_bytes(bytes_dropped as _)
-                );
-            }
+    /// The [`LogMsg::SetStoreInfo`]. Kept out of [`Self::messages`] so that it is never dropped:
+    /// the viewer needs it to make sense of everything else.
+    store_info: Option<Vec<u8>>,
+
+    messages: MessageQueue,
+}
+
+impl StoreHistory {
+    fn new(server_memory_limit: MemoryLimit) -> Self {
+        Self {
+            application_id: None,
+            store_info: None,
+            messages: MessageQueue::new(server_memory_limit),
+        }
+    }
+}
+
+/// A connected viewer.
+struct Client {
+    ws: WebSocket<Box<dyn Stream>>,
+    subscription: Subscription,
+}
+
+impl Client {
+    /// Returns `false` if the client is gone.
+    fn send(&mut self, bytes: &[u8]) -> bool {
+        if let Err(err) = self.ws.send(tungstenite::Message::Binary(bytes.to_vec())) {
+            re_log::warn!("Error sending message to web socket client: {err}");
+            false
+        } else {
+            true
         }
     }
 }
@@ -86,6 +217,12 @@ impl MessageQueue {
 pub struct RerunServer {
     local_addr: std::net::SocketAddr,
 
+    /// `ws` or `wss`.
+    protocol: &'static str,
+
+    /// See [`RerunServerOptions::auth_token`].
+    auth_token: Option<String>,
+
     listener_join_handle: Option<std::thread::JoinHandle<()>>,
     poller: Arc<Poller>,
     shutdown_flag: Arc<AtomicBool>,
@@ -101,12 +238,32 @@ impl RerunServer {
     /// A `bind_ip` of `"0.0.0.0"` is a good default.
     /// A port of 0 will let the OS choose a free port.
     ///
+    /// The history kept for clients that connect later is limited to `server_memory_limit`
+    /// for each recording.
+    ///
     /// Once created, the server will immediately start listening for connections.
     pub fn new(
         rerun_rx: ReceiveSet<LogMsg>,
         bind_ip: &str,
         port: RerunServerPort,
         server_memory_limit: MemoryLimit,
+    ) -> Result<Self, RerunServerError> {
+        Self::with_options(
+            rerun_rx,
+            bind_ip,
+            port,
+            server_memory_limit,
+            RerunServerOptions::default(),
+        )
+    }
+
+    /// Like [`Self::new`], but only letting in the clients with the right token, and/or over TLS.
+    pub fn with_options(
+        rerun_rx: ReceiveSet<LogMsg>,
+        bind_ip: &str,
+        port: RerunServerPort,
+        server_memory_limit: MemoryLimit,
+        options: RerunServerOptions,
     ) -> Result<Self, RerunServerError> {
         let bind_addr = format!("{bind_ip}:{port}");
 
@@ -132,6 +289,8 @@ impl RerunServer {
         let poller_copy = poller.clone();
         let shutdown_flag_copy = shutdown_flag.clone();
         let num_clients_copy = num_accepted_clients.clone();
+        let protocol = options.protocol();
+        let auth_token = options.auth_token.clone();
 
         let listener_join_handle = std::thread::Builder::new()
             .name("rerun_ws_server: listener".to_owned())
@@ -142,11 +301,14 @@ impl RerunServer {
                     &ReceiveSetBroadcaster::new(rerun_rx, server_memory_limit),
                     &shutdown_flag,
                     &num_accepted_clients,
+                    &options,
                 );
             })?;
 
         let slf = Self {
             local_addr,
+            protocol,
+            auth_token,
             poller: poller_copy,
             listener_join_handle: Some(listener_join_handle),
             shutdown_flag: shutdown_flag_copy,
@@ -162,8 +324,23 @@ impl RerunServer {
     }
 
     /// Contains the `ws://` or `wss://` prefix.
+    ///
+    /// Never contains the auth token, see [`Self::client_url`].
     pub fn server_url(&self) -> String {
-        server_url(&self.local_addr)
+        server_url_with_protocol(self.protocol, &self.local_addr)
+    }
+
+    /// The url viewers should connect to: the [`Self::server_url`], with the auth token if the
+    /// server requires one.
+    ///
+    /// Keep it out of the logs.
+    pub fn client_url(&self) -> String {
+        let server_url = self.server_url();
+        match &self.auth_token {
+            Some(auth_token) => crate::url_with_auth_token(&server_url, auth_token)
+                .expect("server urls are always valid"),
+            None => server_url,
+        }
     }
 
     /// Total count; never decreasing.
@@ -177,6 +354,7 @@ impl RerunServer {
         message_broadcaster: &ReceiveSetBroadcaster,
         shutdown_flag: &AtomicBool,
         num_accepted_clients: &AtomicU64,
+        options: &RerunServerOptions,
     ) {
         // Each socket in `poll::Poller` needs a "name".
         // Doesn't matter much what we're using here, as long as it's not used for something else
@@ -207,6 +385,7 @@ impl RerunServer {
                         poller,
                         listener_poll_key,
                         num_accepted_clients,
+                        options,
                     );
                 }
             }
@@ -219,6 +398,7 @@ impl RerunServer {
         poller: &Poller,
         listener_poll_key: usize,
         num_accepted_clients: &AtomicU64,
+        options: &RerunServerOptions,
     ) {
         match listener_socket.accept() {
             Ok((tcp_stream, _)) => {
@@ -229,11 +409,43 @@ impl RerunServer {
 
                 re_log::debug!("New WebSocket connection from {address:?}");
 
-                match tungstenite::accept(tcp_stream) {
-                    Ok(ws_stream) => {
-                        message_broadcaster.add_client(ws_stream);
+                let stream = match Self::wrap_stream(tcp_stream, options) {
+                    Ok(stream) => stream,
+                    Err(err) => {
+                        re_log::warn!("Error accepting WebSocket connection: {err}");
+                        return;
+                    }
+                };
+
+                let mut subscription = Subscription::default();
+                let check_auth_token = |request: &Request, response: Response| {
+                    if options.is_authorized(request) {
+                        subscription =
+                            Subscription::from_query(request.uri().query().unwrap_or_default());
+                        Ok(response)
+                    } else {
+                        let mut response =
+                            ErrorResponse::new(Some("Missing or invalid auth token".to_owned()));
+                        *response.status_mut() = tungstenite::http::StatusCode::UNAUTHORIZED;
+                        Err(response)
+                    }
+                };
+
+                match tungstenite::accept_hdr(stream, check_auth_token) {
+                    Ok(ws) => {
+                        if subscription != Subscription::default() {
+                            re_log::debug!("{address:?} subscribed to {subscription:?}");
+                        }
+                        message_broadcaster.add_client(Client { ws, subscription });
                         num_accepted_clients.fetch_add(1, Ordering::Relaxed);
                     }
+                    Err(tungstenite::HandshakeError::Failure(tungstenite::Error::Http(
+                        response,
+                    ))) if response.status() == tungstenite::http::StatusCode::UNAUTHORIZED => {
+                        re_log::warn!(
+                            "Rejected WebSocket connection from {address:?}: missing or invalid auth token"
+                        );
+                    }
                     Err(err) => {
                         re_log::warn!("Error accepting WebSocket connection: {err}");
                     }
@@ -251,6 +463,29 @@ impl RerunServer {
         }
     }
 
+    #[cfg(feature = "tls")]
+    fn wrap_stream(
+        tcp_stream: TcpStream,
+        options: &RerunServerOptions,
+    ) -> Result<Box<dyn Stream>, rustls::Error> {
+        if let Some(tls_config) = &options.tls_config {
+            // The TLS handshake happens as part of the WebSocket handshake.
+            let connection = rustls::ServerConnection::new(tls_config.clone())?;
+            Ok(Box::new(rustls::StreamOwned::new(connection, tcp_stream)))
+        } else {
+            Ok(Box::new(tcp_stream))
+        }
+    }
+
+    #[cfg(not(feature = "tls"))]
+    #[allow(clippy::unnecessary_wraps)]
+    fn wrap_stream(
+        tcp_stream: TcpStream,
+        _options: &RerunServerOptions,
+    ) -> Result<Box<dyn Stream>, std::convert::Infallible> {
+        Ok(Box::new(tcp_stream))
+    }
+
     fn stop_listener(&mut self) {
         let Some(join_handle) = self.listener_join_handle.take() else {
             return;
@@ -291,16 +526,85 @@ struct ReceiveSetBroadcaster {
 struct ReceiveSetBroadcasterInnerState {
     /// Don't allow adding to the history while adding/removing clients.
     /// This way, no messages history is lost!
-    history: MessageQueue,
-    clients: Vec<WebSocket<TcpStream>>,
+    stores: HashMap<StoreId, StoreHistory>,
+    server_memory_limit: MemoryLimit,
+    clients: Vec<Client>,
+}
+
+impl ReceiveSetBroadcasterInnerState {
+    fn new(server_memory_limit: MemoryLimit) -> Self {
+        Self {
+            stores: HashMap::default(),
+            server_memory_limit,
+            clients: Vec::new(),
+        }
+    }
+
+    fn store_history(&mut self, store_id: &StoreId) -> &mut StoreHistory {
+        let server_memory_limit = self.server_memory_limit;
+        self.stores
+            .entry(store_id.clone())
+            .or_insert_with(|| StoreHistory::new(server_memory_limit))
+    }
+
+    /// The history a client with this subscription is sent when it connects.
+    ///
+    /// The viewer needs the store infos to make sense of the rest, so they go first.
+    fn history<'a>(&'a self, subscription: &'a Subscription) -> impl Iterator<Item = &'a [u8]> {
+        let stores = self.stores.iter().filter(|(store_id, store)| {
+            subscription.includes_store(store_id, store.application_id.as_ref())
+        });
+        let store_infos = stores
+            .clone()
+            .filter_map(|(_, store)| store.store_info.as_deref());
+        let messages = stores.flat_map(|(_, store)| {
+            store
+                .messages
+                .messages
+                .iter()
+                .filter(|msg| msg.is_included_in(subscription))
+                .map(|msg| msg.bytes.as_slice())
+        });
+        store_infos.chain(messages)
+    }
+
+    fn broadcast(&mut self, log_msg: &LogMsg) {
+        let store_id = log_msg.store_id();
+        let msg = EncodedMsg::new(log_msg);
+
+        let store = self.store_history(store_id);
+        if let LogMsg::SetStoreInfo(store_info) = log_msg {
+            store.application_id = Some(store_info.info.application_id.clone());
+        }
+        let application_id = store.application_id.clone();
+
+        // TODO(andreas): Should this be a parallel-for?
+        self.clients.retain_mut(|client| {
+            if client
+                .subscription
+                .includes_store(store_id, application_id.as_ref())
+                && msg.is_included_in(&client.subscription)
+            {
+                client.send(&msg.bytes)
+            } else {
+                true
+            }
+        });
+
+        let store = self.store_history(store_id);
+        if matches!(log_msg, LogMsg::SetStoreInfo(_)) {
+            store.store_info = Some(msg.bytes);
+        } else {
+            store.messages.push(msg);
+        }
+    }
 }
 
 impl ReceiveSetBroadcaster {
     pub fn new(log_rx: ReceiveSet<LogMsg>, server_memory_limit: MemoryLimit) -> Self {
-        let inner = Arc::new(Mutex::new(ReceiveSetBroadcasterInnerState {
-            history: MessageQueue::new(server_memory_limit),
-            clients: Vec::new(),
-        }));
+        let inner = Arc::new(Mutex::new(ReceiveSetBroadcasterInnerState::new(
+            server_memory_limit,
+        )));
         let shutdown = Arc::new(AtomicBool::new(false));
 
         let inner_copy = inner.clone();
@@ -336,20 +640,7 @@ impl ReceiveSetBroadcaster {
 
             match msg.payload {
                 re_smart_channel::SmartMessagePayload::Msg(data) => {
-                    let msg = crate::encode_log_msg(&data);
-                    let mut inner = inner.lock();
-
-                    // TODO(andreas): Should this be a parallel-for?
-                    inner.clients.retain_mut(|client| {
-                        if let Err(err) = client.send(tungstenite::Message::Binary(msg.clone())) {
-                            re_log::warn!("Error sending message to web socket client: {err}");
-                            false
-                        } else {
-                            true
-                        }
-                    });
-
-                    inner.history.push(msg);
+                    inner.lock().broadcast(&data);
                 }
 
                 re_smart_channel::SmartMessagePayload::Flush { on_flush_done } => {
@@ -372,16 +663,16 @@ impl ReceiveSetBroadcaster {
         }
     }
 
-    /// Adds a websocket client to the broadcaster and replies all message history so far to it.
-    pub fn add_client(&self, mut client: WebSocket<TcpStream>) {
+    /// Adds a websocket client to the broadcaster and replies the message history it subscribed to so far to it.
+    pub fn add_client(&self, mut client: Client) {
         // TODO(andreas): While it's great that we don't loose any messages while adding clients,
         // the problem with this is that now we won't be able to keep the other clients fed, until this one is done!
         // Meaning that if a new one connects, we stall the old connections until we have sent all messages to this one.
         let mut inner = self.inner.lock();
 
-        for msg in &inner.history.messages {
-            if let Err(err) = client.send(tungstenite::Message::Binary(msg.clone())) {
-                re_log::warn!("Error sending message to web socket client: {err}");
+        let subscription = client.subscription.clone();
+        for bytes in inner.history(&subscription) {
+            if !client.send(bytes) {
                 return;
             }
         }
@@ -400,3 +691,126 @@ impl Drop for ReceiveSetBroadcaster {
         self.inner.lock().clients.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{StoreKind, ViewerCommand};
+
+    use super::*;
+
+    fn request(uri: &str) -> Request {
+        tungstenite::http::Request::builder()
+            .uri(uri)
+            .body(())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_is_authorized() {
+        let options = RerunServerOptions {
+            auth_token: Some("s&cret".to_owned()),
+            ..Default::default()
+        };
+        assert!(options.is_authorized(&request("/?token=s%26cret")));
+        assert!(options.is_authorized(&request("/?recording=a&token=s%26cret")));
+        assert!(!options.is_authorized(&request("/?token=s&cret")));
+        assert!(!options.is_authorized(&request("/?token=")));
+        assert!(!options.is_authorized(&request("/?recording=a")));
+        assert!(!options.is_authorized(&request("/")));
+
+        // Anyone is welcome if there is no token.
+        let options = RerunServerOptions::default();
+        assert!(options.is_authorized(&request("/")));
+        assert!(options.is_authorized(&request("/?token=anything")));
+    }
+
+    fn store_id(store: &str) -> StoreId {
+        StoreId::from_string(StoreKind::Recording, store.to_owned())
+    }
+
+    fn message(store: &str) -> LogMsg {
+        LogMsg::ViewerCommand(store_id(store), ViewerCommand::SetPlaying(true))
+    }
+
+    fn store_info(store: &str, app_id: &str) -> LogMsg {
+        LogMsg::SetStoreInfo(re_log_types::SetStoreInfo {
+            row_id: *re_chunk::RowId::new(),
+            info: re_log_types::StoreInfo {
+                application_id: app_id.into(),
+                store_id: store_id(store),
+                cloned_from: None,
+                is_official_example: false,
+                started: re_log_types::Time::now(),
+                store_source: re_log_types::StoreSource::Unknown,
+                store_version: None,
+            },
+        })
+    }
+
+    /// The messages a client connecting now would get, in order.
+    fn history(
+        inner: &ReceiveSetBroadcasterInnerState,
+        subscription: &Subscription,
+    ) -> Vec<LogMsg> {
+        inner
+            .history(subscription)
+            .map(|bytes| crate::decode_log_msg(bytes).unwrap())
+            .collect()
+    }
+
+    fn num_messages(inner: &ReceiveSetBroadcasterInnerState, store: &str) -> usize {
+        inner.stores[&store_id(store)].messages.messages.len()
+    }
+
+    #[test]
+    fn test_memory_limit_per_store() {
+        let msg_size = EncodedMsg::new(&message("quiet")).bytes.len() as u64;
+        let mut inner = ReceiveSetBroadcasterInnerState::new(MemoryLimit::from_bytes(3 * msg_size));
+
+        inner.broadcast(&store_info("quiet", "quiet_app"));
+        inner.broadcast(&message("quiet"));
+        inner.broadcast(&store_info("busy", "busy_app"));
+        for _ in 0..100 {
+            inner.broadcast(&message("busy"));
+        }
+
+        // The busy store only pushes out its own old messages.
+        assert_eq!(num_messages(&inner, "busy"), 4);
+        assert_eq!(num_messages(&inner, "quiet"), 1);
+
+        // Once the quiet store uses up its own budget too, it keeps its store info.
+        for _ in 0..10 {
+            inner.broadcast(&message("quiet"));
+        }
+        assert_eq!(num_messages(&inner, "quiet"), 4);
+
+        let subscription = Subscription::from_query("app_id=quiet_app");
+        let late_joiner = history(&inner, &subscription);
+        assert_eq!(late_joiner.len(), 5);
+        assert!(matches!(
+            &late_joiner[0],
+            LogMsg::SetStoreInfo(info) if info.info.store_id == store_id("quiet")
+        ));
+        assert!(late_joiner[1..]
+            .iter()
+            .all(|msg| matches!(msg, LogMsg::ViewerCommand(id, _) if *id == store_id("quiet"))));
+
+        // All store infos go first.
+        let everything = history(&inner, &Subscription::default());
+        assert_eq!(everything.len(), 2 + 4 + 4);
+        assert!(everything[..2]
+            .iter()
+            .all(|msg| matches!(msg, LogMsg::SetStoreInfo(_))));
+    }
+
+    #[test]
+    fn test_unlimited_memory() {
+        let mut inner = ReceiveSetBroadcasterInnerState::new(MemoryLimit::UNLIMITED);
+        for _ in 0..100 {
+            inner.broadcast(&message("a"));
+            inner.broadcast(&message("b"));
+        }
+        assert_eq!(num_messages(&inner, "a"), 100);
+        assert_eq!(num_messages(&inner, "b"), 100);
+    }
+}
@@ -0,0 +1,224 @@
+use re_log_types::{
+    ApplicationId, EntityPath, EntityPathFilter, EntityPathSubs, StoreId, StoreKind,
+};
+
+/// The query parameter of the WebSocket url which selects an application, e.g. `?app_id=robot`.
+///
+/// Can be repeated to select several applications.
+pub const APP_ID_QUERY_KEY: &str = "app_id";
+
+/// The query parameter of the WebSocket url which selects a recording or blueprint by its id,
+/// e.g. `?store_id=1234`.
+///
+/// Can be repeated to select several of them.
+pub const STORE_ID_QUERY_KEY: &str = "store_id";
+
+/// The query parameter of the WebSocket url which holds one rule of an entity filter,
+/// e.g. `?entity=%2B%20/robot/**&entity=-%20/robot/lidar`.
+///
+/// See [`EntityPathFilter`] for the syntax of the rules.
+pub const ENTITY_FILTER_QUERY_KEY: &str = "entity";
+
+/// Which data a viewer receives from a [`crate::RerunServer`].
+///
+/// Chosen when connecting, with the query parameters of the WebSocket url.
+/// By default, everything is received.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Subscription {
+    /// Only receive these applications. All of them if empty.
+    pub application_ids: Vec<ApplicationId>,
+
+    /// Only receive the recordings and blueprints with these ids. All of them if empty.
+    pub store_ids: Vec<String>,
+
+    /// Only receive the data of the entities that pass this filter.
+    ///
+    /// Only applies to recordings: blueprints are sent whole.
+    pub entity_filter: Option<EntityPathFilter>,
+}
+
+impl Subscription {
+    /// Reads the subscription from the query of a WebSocket url.
+    ///
+    /// Unknown parameters are ignored.
+    pub fn from_query(query: &str) -> Self {
+        let mut subscription = Self::default();
+        let mut entity_rules = Vec::new();
+
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                APP_ID_QUERY_KEY => subscription.application_ids.push(value.as_ref().into()),
+                STORE_ID_QUERY_KEY => subscription.store_ids.push(value.into_owned()),
+                ENTITY_FILTER_QUERY_KEY => entity_rules.push(value.into_owned()),
+                _ => {}
+            }
+        }
+
+        if !entity_rules.is_empty() {
+            subscription.entity_filter = Some(EntityPathFilter::from_query_expressions(
+                entity_rules.iter().map(String::as_str),
+                &EntityPathSubs::default(),
+            ));
+        }
+
+        subscription
+    }
+
+    /// Adds the query parameters that [`Self::from_query`] reads back.
+    pub fn append_to_url(&self, url: &mut url::Url) {
+        if *self == Self::default() {
+            return;
+        }
+
+        let mut query_pairs = url.query_pairs_mut();
+        for application_id in &self.application_ids {
+            query_pairs.append_pair(APP_ID_QUERY_KEY, &application_id.0);
+        }
+        for store_id in &self.store_ids {
+            query_pairs.append_pair(STORE_ID_QUERY_KEY, store_id);
+        }
+        if let Some(entity_filter) = &self.entity_filter {
+            for rule in entity_filter.iter_expressions() {
+                query_pairs.append_pair(ENTITY_FILTER_QUERY_KEY, &rule);
+            }
+        }
+    }
+
+    /// Does this include the given store?
+    ///
+    /// The application is `None` if we don't know it yet, in which case the store is only
+    /// included if no application was asked for.
+    pub fn includes_store(
+        &self,
+        store_id: &StoreId,
+        application_id: Option<&ApplicationId>,
+    ) -> bool {
+        let app_matches = self.application_ids.is_empty()
+            || application_id.is_some_and(|app_id| self.application_ids.contains(app_id));
+        let store_matches =
+            self.store_ids.is_empty() || self.store_ids.iter().any(|id| **store_id.id == *id);
+        app_matches && store_matches
+    }
+
+    /// Does this include the data of this entity in the given store?
+    pub fn includes_entity(&self, store_id: &StoreId, entity_path: &EntityPath) -> bool {
+        match &self.entity_filter {
+            Some(entity_filter) if store_id.kind == StoreKind::Recording => {
+                entity_filter.is_included(entity_path)
+            }
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording(id: &str) -> StoreId {
+        StoreId::from_string(StoreKind::Recording, id.to_owned())
+    }
+
+    fn blueprint(id: &str) -> StoreId {
+        StoreId::from_string(StoreKind::Blueprint, id.to_owned())
+    }
+
+    fn robot_subscription() -> Subscription {
+        Subscription {
+            application_ids: vec!["robot".into(), "car & bike".into()],
+            store_ids: vec!["1234".to_owned()],
+            entity_filter: Some(EntityPathFilter::parse_forgiving(
+                "+ /robot/**\n- /robot/lidar",
+                &EntityPathSubs::default(),
+            )),
+        }
+    }
+
+    #[test]
+    fn test_from_query() {
+        assert_eq!(Subscription::from_query(""), Subscription::default());
+        assert_eq!(
+            Subscription::from_query("token=secret&other=1"),
+            Subscription::default()
+        );
+
+        assert_eq!(
+            Subscription::from_query(
+                "app_id=robot&token=secret&app_id=car+%26+bike&store_id=1234\
+                 &entity=%2B%20%2Frobot%2F**&entity=-%20%2Frobot%2Flidar"
+            ),
+            robot_subscription()
+        );
+
+        // A rule without an effect is included.
+        assert_eq!(
+            Subscription::from_query("entity=/robot/**").entity_filter,
+            Some(EntityPathFilter::parse_forgiving(
+                "+ /robot/**",
+                &EntityPathSubs::default()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_append_to_url() {
+        let mut url = url::Url::parse("ws://localhost:9877").unwrap();
+        Subscription::default().append_to_url(&mut url);
+        assert_eq!(url.as_str(), "ws://localhost:9877/");
+
+        let subscription = robot_subscription();
+        let mut url = url::Url::parse("ws://localhost:9877/?token=secret").unwrap();
+        subscription.append_to_url(&mut url);
+        assert!(url
+            .as_str()
+            .starts_with("ws://localhost:9877/?token=secret&"));
+        assert_eq!(
+            Subscription::from_query(url.query().unwrap_or_default()),
+            subscription
+        );
+    }
+
+    #[test]
+    fn test_includes_store() {
+        let everything = Subscription::default();
+        assert!(everything.includes_store(&recording("1234"), None));
+        assert!(everything.includes_store(&blueprint("5678"), Some(&"car".into())));
+
+        let by_app = Subscription {
+            application_ids: vec!["robot".into()],
+            ..Default::default()
+        };
+        assert!(by_app.includes_store(&recording("1234"), Some(&"robot".into())));
+        assert!(by_app.includes_store(&blueprint("5678"), Some(&"robot".into())));
+        assert!(!by_app.includes_store(&recording("1234"), Some(&"car".into())));
+        assert!(!by_app.includes_store(&recording("1234"), None));
+
+        let by_store = Subscription {
+            store_ids: vec!["1234".to_owned()],
+            ..Default::default()
+        };
+        assert!(by_store.includes_store(&recording("1234"), None));
+        assert!(by_store.includes_store(&blueprint("1234"), Some(&"car".into())));
+        assert!(!by_store.includes_store(&recording("12345"), None));
+
+        let both = robot_subscription();
+        assert!(both.includes_store(&recording("1234"), Some(&"robot".into())));
+        assert!(!both.includes_store(&recording("1234"), Some(&"car".into())));
+        assert!(!both.includes_store(&recording("5678"), Some(&"robot".into())));
+    }
+
+    #[test]
+    fn test_includes_entity() {
+        let everything = Subscription::default();
+        assert!(everything.includes_entity(&recording("1234"), &"robot/lidar".into()));
+
+        let subscription = robot_subscription();
+        assert!(subscription.includes_entity(&recording("1234"), &"robot".into()));
+        assert!(subscription.includes_entity(&recording("1234"), &"robot/camera".into()));
+        assert!(!subscription.includes_entity(&recording("1234"), &"robot/lidar".into()));
+        assert!(!subscription.includes_entity(&recording("1234"), &"world".into()));
+
+        // Blueprints are sent whole.
+        assert!(subscription.includes_entity(&blueprint("1234"), &"world".into()));
+    }
+}
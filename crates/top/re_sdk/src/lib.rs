@@ -22,6 +22,7 @@ mod global;
 mod log_sink;
 mod recording_stream;
 mod spawn;
+mod viewer_events;
 
 // -------------
 // Public items:
@@ -33,10 +34,13 @@ pub use self::recording_stream::{
     RecordingStreamResult,
 };
 
+pub use self::viewer_events::ViewerEvents;
+
 pub use re_sdk_comms::{default_flush_timeout, default_server_addr};
 
 pub use re_log_types::{
     entity_path, ApplicationId, EntityPath, EntityPathPart, Instance, StoreId, StoreKind,
+    ViewerCommand, ViewerEvent, ViewerMessage,
 };
 
 pub use re_memory::MemoryLimit;
@@ -70,6 +74,11 @@ pub mod sink {
         BufferedSink, CallbackSink, LogSink, MemorySink, MemorySinkStorage, TcpSink,
     };
 
+    pub use re_sdk_comms::{
+        ClientOptions, ClientTlsOptions, CongestionMonitor, CongestionReport, SpoolOptions,
+        SpoolOverflow, ViewerMessages,
+    };
+
     #[cfg(not(target_arch = "wasm32"))]
     pub use re_log_encoding::{FileSink, FileSinkError};
 }
@@ -102,6 +111,9 @@ pub use re_data_loader::{DataLoader, DataLoaderError, DataLoaderSettings, Loaded
 #[cfg(feature = "web_viewer")]
 pub mod web_viewer;
 
+/// Re-streaming recorded data as if it were being logged live.
+pub mod replay;
+
 /// Re-exports of other crates.
 pub mod external {
     pub use re_log;
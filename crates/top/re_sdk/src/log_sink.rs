@@ -39,6 +39,22 @@ pub trait LogSink: Send + Sync + 'static {
     #[inline]
     fn drop_if_disconnected(&self) {}
 
+    /// Gives access to how well the receiving end keeps up, for sinks that know.
+    ///
+    /// See [`crate::RecordingStream::congestion`].
+    #[inline]
+    fn congestion_monitor(&self) -> Option<re_sdk_comms::CongestionMonitor> {
+        None
+    }
+
+    /// Gives access to the messages the viewer sends back, for sinks that receive them.
+    ///
+    /// See [`crate::RecordingStream::viewer_screenshot`].
+    #[inline]
+    fn viewer_messages(&self) -> Option<re_sdk_comms::ViewerMessages> {
+        None
+    }
+
     /// Send a blueprint directly to the log-sink.
     ///
     /// This mirrors the behavior of [`crate::RecordingStream::send_blueprint`].
@@ -347,6 +363,18 @@ impl TcpSink {
             client: re_sdk_comms::Client::new(addr, flush_timeout),
         }
     }
+
+    /// Like [`Self::new`], but e.g. with an auth token or over TLS.
+    #[inline]
+    pub fn with_options(
+        addr: std::net::SocketAddr,
+        flush_timeout: Option<std::time::Duration>,
+        options: re_sdk_comms::ClientOptions,
+    ) -> Self {
+        Self {
+            client: re_sdk_comms::Client::with_options(addr, flush_timeout, options),
+        }
+    }
 }
 
 impl LogSink for TcpSink {
@@ -364,4 +392,14 @@ impl LogSink for TcpSink {
     fn drop_if_disconnected(&self) {
         self.client.drop_if_disconnected();
     }
+
+    #[inline]
+    fn congestion_monitor(&self) -> Option<re_sdk_comms::CongestionMonitor> {
+        Some(self.client.congestion_monitor().clone())
+    }
+
+    #[inline]
+    fn viewer_messages(&self) -> Option<re_sdk_comms::ViewerMessages> {
+        self.client.viewer_messages().cloned()
+    }
 }
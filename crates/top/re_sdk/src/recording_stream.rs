@@ -3,6 +3,7 @@ use std::fmt;
 use std::io::IsTerminal;
 use std::sync::Weak;
 use std::sync::{atomic::AtomicI64, Arc};
+use std::time::{Duration, Instant};
 
 use ahash::HashMap;
 use arrow2::offset::Offsets;
@@ -17,7 +18,7 @@ use re_chunk::{ChunkError, ChunkId, ChunkTimeline, ComponentName};
 use re_log_types::{
     ApplicationId, ArrowChunkReleaseCallback, BlueprintActivationCommand, EntityPath, LogMsg,
     StoreId, StoreInfo, StoreKind, StoreSource, Time, TimeInt, TimePoint, TimeType, Timeline,
-    TimelineName,
+    TimelineName, ViewerCommand, ViewerMessage,
 };
 use re_types_core::{AsComponents, ComponentBatch, SerializationError};
 
@@ -89,6 +90,14 @@ pub enum RecordingStreamError {
     #[cfg(feature = "data_loaders")]
     #[error(transparent)]
     DataLoaderError(#[from] re_data_loader::DataLoaderError),
+
+    /// The current sink doesn't receive messages from the viewer.
+    #[error("The current sink doesn't receive messages from the viewer; connect with `ClientOptions::viewer_messages` set")]
+    ViewerMessagesUnsupported,
+
+    /// The viewer didn't answer in time.
+    #[error("The viewer didn't answer within {0:?}")]
+    ViewerTimeout(Duration),
 }
 
 /// Results that can occur when creating/manipulating a [`RecordingStream`].
@@ -303,7 +312,8 @@ impl RecordingStreamBuilder {
     /// Creates a new [`RecordingStream`] that is pre-configured to stream the data through to a
     /// remote Rerun instance.
     ///
-    /// See also [`Self::connect_opts`] if you wish to configure the TCP connection.
+    /// See also [`Self::connect_opts`] if you wish to configure the TCP connection,
+    /// and [`Self::connect_with_options`] for authentication and TLS.
     ///
     /// ## Example
     ///
@@ -333,13 +343,46 @@ impl RecordingStreamBuilder {
         self,
         addr: std::net::SocketAddr,
         flush_timeout: Option<std::time::Duration>,
+    ) -> RecordingStreamResult<RecordingStream> {
+        self.connect_with_options(addr, flush_timeout, Default::default())
+    }
+
+    /// Creates a new [`RecordingStream`] that is pre-configured to stream the data through to a
+    /// remote Rerun instance that requires an auth token, or TLS, or to spool messages to disk
+    /// while that instance can't be reached.
+    ///
+    /// See [`Self::connect_opts`] for `flush_timeout`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// let options = re_sdk::sink::ClientOptions {
+    ///     auth_token: Some("my-secret-token".to_owned()),
+    ///     ..Default::default()
+    /// };
+    /// let rec = re_sdk::RecordingStreamBuilder::new("rerun_example_app").connect_with_options(
+    ///     re_sdk::default_server_addr(),
+    ///     re_sdk::default_flush_timeout(),
+    ///     options,
+    /// )?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn connect_with_options(
+        self,
+        addr: std::net::SocketAddr,
+        flush_timeout: Option<std::time::Duration>,
+        options: re_sdk_comms::ClientOptions,
     ) -> RecordingStreamResult<RecordingStream> {
         let (enabled, store_info, batcher_config) = self.into_args();
         if enabled {
             RecordingStream::new(
                 store_info,
                 batcher_config,
-                Box::new(crate::log_sink::TcpSink::new(addr, flush_timeout)),
+                Box::new(crate::log_sink::TcpSink::with_options(
+                    addr,
+                    flush_timeout,
+                    options,
+                )),
             )
         } else {
             re_log::debug!("Rerun disabled - call to connect() ignored");
@@ -685,6 +728,16 @@ struct RecordingStreamInner {
     batcher: ChunkBatcher,
     batcher_to_sink_handle: Option<std::thread::JoinHandle<()>>,
 
+    /// The congestion monitor of the current sink, if any.
+    ///
+    /// Kept up to date by the forwarding thread, which owns the sink.
+    congestion: Arc<Mutex<Option<re_sdk_comms::CongestionMonitor>>>,
+
+    /// Where the current sink hands out the messages from the viewer, if anywhere.
+    ///
+    /// Kept up to date by the forwarding thread, just like `congestion`.
+    viewer_messages: Arc<Mutex<Option<re_sdk_comms::ViewerMessages>>>,
+
     /// Keeps track of the top-level threads that were spawned in order to execute the `DataLoader`
     /// machinery in the context of this `RecordingStream`.
     ///
@@ -749,6 +802,9 @@ impl RecordingStreamInner {
 
         let (cmds_tx, cmds_rx) = crossbeam::channel::unbounded();
 
+        let congestion = Arc::new(Mutex::new(sink.congestion_monitor()));
+        let viewer_messages = Arc::new(Mutex::new(sink.viewer_messages()));
+
         let batcher_to_sink_handle = {
             const NAME: &str = "RecordingStream::batcher_to_sink";
             std::thread::Builder::new()
@@ -756,7 +812,13 @@ impl RecordingStreamInner {
                 .spawn({
                     let info = info.clone();
                     let batcher = batcher.clone();
-                    move || forwarding_thread(info, sink, cmds_rx, batcher.chunks(), on_release)
+                    let sink_state = SinkState {
+                        congestion: congestion.clone(),
+                        viewer_messages: viewer_messages.clone(),
+                    };
+                    move || {
+                        forwarding_thread(info, sink, cmds_rx, &batcher, &sink_state, on_release);
+                    }
                 })
                 .map_err(|err| RecordingStreamError::SpawnThread {
                     name: NAME.into(),
@@ -770,6 +832,8 @@ impl RecordingStreamInner {
             cmds_tx,
             batcher,
             batcher_to_sink_handle: Some(batcher_to_sink_handle),
+            congestion,
+            viewer_messages,
             dataloader_handles: Mutex::new(Vec::new()),
             pid_at_creation: std::process::id(),
         })
@@ -1265,17 +1329,91 @@ impl RecordingStream {
     }
 }
 
+/// Coarsens the batches while the receiving end of the sink is congested, so that it has fewer
+/// (but larger) chunks to ingest.
+///
+/// See [`ChunkBatcher::set_coarsening`].
+struct CongestionControl {
+    coarsening: u32,
+    last_update: Instant,
+}
+
+impl CongestionControl {
+    const MAX_COARSENING: u32 = 16;
+
+    /// How often we react to the reports of the sink.
+    const UPDATE_INTERVAL: Duration = Duration::from_millis(250);
+
+    fn new() -> Self {
+        Self {
+            coarsening: 1,
+            last_update: Instant::now(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        monitor: Option<&re_sdk_comms::CongestionMonitor>,
+        batcher: &ChunkBatcher,
+    ) {
+        if self.last_update.elapsed() < Self::UPDATE_INTERVAL {
+            return;
+        }
+        self.last_update = Instant::now();
+
+        let is_congested = monitor
+            .and_then(|monitor| monitor.report())
+            .is_some_and(|report| report.is_congested());
+
+        // Back off quickly, and recover just as quickly once the congestion is gone.
+        let coarsening = if is_congested {
+            (self.coarsening * 2).min(Self::MAX_COARSENING)
+        } else {
+            (self.coarsening / 2).max(1)
+        };
+
+        if coarsening != self.coarsening {
+            if self.coarsening == 1 {
+                re_log::debug!("The receiving end is congested: batching more coarsely");
+            } else if coarsening == 1 {
+                re_log::debug!("The receiving end has caught up: batching as usual");
+            }
+            self.coarsening = coarsening;
+            batcher.set_coarsening(coarsening);
+        }
+    }
+}
+
+/// What the [`RecordingStream`] needs to know about the sink owned by the forwarding thread.
+struct SinkState {
+    congestion: Arc<Mutex<Option<re_sdk_comms::CongestionMonitor>>>,
+    viewer_messages: Arc<Mutex<Option<re_sdk_comms::ViewerMessages>>>,
+}
+
+impl SinkState {
+    fn update(&self, sink: &dyn LogSink) {
+        *self.congestion.lock() = sink.congestion_monitor();
+        *self.viewer_messages.lock() = sink.viewer_messages();
+    }
+}
+
 #[allow(clippy::needless_pass_by_value)]
 fn forwarding_thread(
     info: StoreInfo,
     mut sink: Box<dyn LogSink>,
     cmds_rx: Receiver<Command>,
-    chunks: Receiver<Chunk>,
+    batcher: &ChunkBatcher,
+    sink_state: &SinkState,
     on_release: Option<ArrowChunkReleaseCallback>,
 ) {
     /// Returns `true` to indicate that processing can continue; i.e. `false` means immediate
     /// shutdown.
-    fn handle_cmd(info: &StoreInfo, cmd: Command, sink: &mut Box<dyn LogSink>) -> bool {
+    fn handle_cmd(
+        info: &StoreInfo,
+        cmd: Command,
+        sink: &mut Box<dyn LogSink>,
+        sink_state: &SinkState,
+    ) -> bool {
         match cmd {
             Command::RecordMsg(msg) => {
                 sink.send(msg);
@@ -1310,6 +1448,7 @@ fn forwarding_thread(
                     new_sink.send_all(backlog);
                 }
 
+                sink_state.update(new_sink.as_ref());
                 *sink = new_sink;
             }
             Command::Flush(oneshot) => {
@@ -1329,6 +1468,9 @@ fn forwarding_thread(
         true
     }
 
+    let chunks = batcher.chunks();
+    let mut congestion_control = CongestionControl::new();
+
     use crossbeam::select;
     loop {
         // NOTE: Always pop chunks first, this is what makes `Command::PopPendingChunks` possible,
@@ -1372,12 +1514,14 @@ fn forwarding_thread(
                     re_log::trace!("Shutting down forwarding_thread: all command senders are gone");
                     break;
                 };
-                if !handle_cmd(&info, cmd, &mut sink) {
+                if !handle_cmd(&info, cmd, &mut sink, sink_state) {
                     break; // shutdown
                 }
             }
         }
 
+        congestion_control.update(sink_state.congestion.lock().as_ref(), batcher);
+
         // NOTE: The receiving end of the command stream is owned solely by this thread.
         // Past this point, all command writes will return `ErrDisconnected`.
     }
@@ -1392,6 +1536,27 @@ impl RecordingStream {
         self.with(|_| true).unwrap_or(false)
     }
 
+    /// How well the receiving end of the sink keeps up with the data, according to its latest
+    /// report.
+    ///
+    /// Only a [`crate::sink::TcpSink`] connected with
+    /// [`crate::sink::ClientOptions::congestion_reports`] reports this, and only while data is
+    /// flowing.
+    ///
+    /// While congested, the batching is automatically made coarser. Use this to additionally
+    /// decide what to skip logging.
+    #[inline]
+    pub fn congestion(&self) -> Option<re_sdk_comms::CongestionReport> {
+        self.with(|inner| {
+            inner
+                .congestion
+                .lock()
+                .as_ref()
+                .and_then(|monitor| monitor.report())
+        })
+        .flatten()
+    }
+
     /// The [`StoreInfo`] associated with this `RecordingStream`.
     #[inline]
     pub fn store_info(&self) -> Option<StoreInfo> {
@@ -1678,13 +1843,30 @@ impl RecordingStream {
         &self,
         addr: std::net::SocketAddr,
         flush_timeout: Option<std::time::Duration>,
+    ) {
+        self.connect_with_options(addr, flush_timeout, Default::default());
+    }
+
+    /// Swaps the underlying sink for a [`crate::log_sink::TcpSink`] sink pre-configured to use
+    /// the specified address, e.g. with an auth token or over TLS.
+    ///
+    /// See [`Self::connect_opts`] for `flush_timeout`.
+    ///
+    /// This is a convenience wrapper for [`Self::set_sink`] that upholds the same guarantees in
+    /// terms of data durability and ordering.
+    /// See [`Self::set_sink`] for more information.
+    pub fn connect_with_options(
+        &self,
+        addr: std::net::SocketAddr,
+        flush_timeout: Option<std::time::Duration>,
+        options: re_sdk_comms::ClientOptions,
     ) {
         if forced_sink_path().is_some() {
             re_log::debug!("Ignored setting new TcpSink since {ENV_FORCE_SAVE} is set");
             return;
         }
 
-        let sink = crate::log_sink::TcpSink::new(addr, flush_timeout);
+        let sink = crate::log_sink::TcpSink::with_options(addr, flush_timeout, options);
 
         self.set_sink(Box::new(sink));
     }
@@ -1792,13 +1974,28 @@ impl RecordingStream {
     pub fn save_opts(
         &self,
         path: impl Into<std::path::PathBuf>,
+    ) -> Result<(), crate::sink::FileSinkError> {
+        self.save_with_encoding_options(path, re_log_encoding::EncodingOptions::COMPRESSED)
+    }
+
+    /// Swaps the underlying sink for a [`crate::sink::FileSink`] at the specified `path`,
+    /// encoded with the given `encoding_options`.
+    ///
+    /// Use e.g. [`re_log_encoding::EncodingOptions::ZSTD_COMPRESSED`] for smaller files at the
+    /// cost of more CPU time spent encoding.
+    ///
+    /// See [`Self::save_opts`] for more information.
+    pub fn save_with_encoding_options(
+        &self,
+        path: impl Into<std::path::PathBuf>,
+        encoding_options: re_log_encoding::EncodingOptions,
     ) -> Result<(), crate::sink::FileSinkError> {
         if forced_sink_path().is_some() {
             re_log::debug!("Ignored setting new file since {ENV_FORCE_SAVE} is set");
             return Ok(());
         }
 
-        let sink = crate::sink::FileSink::new(path)?;
+        let sink = crate::sink::FileSink::with_options(path, encoding_options)?;
 
         self.set_sink(Box::new(sink));
 
@@ -1908,6 +2105,8 @@ impl fmt::Debug for RecordingStream {
                 cmds_tx: _,
                 batcher: _,
                 batcher_to_sink_handle: _,
+                congestion: _,
+                viewer_messages: _,
                 dataloader_handles,
                 pid_at_creation,
             } = inner;
@@ -2209,6 +2408,186 @@ impl RecordingStream {
     }
 }
 
+impl RecordingStream {
+    /// Tells the viewer what to show, e.g. to script a review of the recording.
+    ///
+    /// Everything logged before this call reaches the viewer first, so that the command can
+    /// refer to it. Commands don't add any data to the recording: only viewers act on them.
+    ///
+    /// See also:
+    /// - [`Self::viewer_set_time_sequence`]
+    /// - [`Self::viewer_set_time_seconds`]
+    /// - [`Self::viewer_play`]
+    /// - [`Self::viewer_pause`]
+    /// - [`Self::viewer_select`]
+    /// - [`Self::viewer_screenshot`]
+    /// - [`Self::viewer_events`]
+    pub fn send_viewer_command(&self, command: ViewerCommand) {
+        let f = move |inner: &RecordingStreamInner| {
+            // NOTE: Internal channels can never be closed outside of the `Drop` impl, all these sends
+            // are safe.
+            inner.batcher.flush_blocking();
+            inner.cmds_tx.send(Command::PopPendingChunks).ok();
+
+            let msg = LogMsg::ViewerCommand(inner.info.store_id.clone(), command);
+            inner.cmds_tx.send(Command::RecordMsg(msg)).ok();
+        };
+
+        if self.with(f).is_none() {
+            re_log::warn_once!("Recording disabled - call to send_viewer_command() ignored");
+        }
+    }
+
+    /// Moves the time cursor of the viewer, and makes this the active timeline.
+    ///
+    /// For example: `rec.viewer_set_time_sequence("frame_nr", 42)`.
+    pub fn viewer_set_time_sequence(
+        &self,
+        timeline: impl Into<TimelineName>,
+        sequence: impl Into<i64>,
+    ) {
+        self.send_viewer_command(ViewerCommand::SetTime {
+            timeline: Timeline::new_sequence(timeline),
+            time: Some(TimeInt::new_temporal(sequence.into())),
+        });
+    }
+
+    /// Moves the time cursor of the viewer, and makes this the active timeline.
+    ///
+    /// For example: `rec.viewer_set_time_seconds("sim_time", 1.5)`.
+    pub fn viewer_set_time_seconds(
+        &self,
+        timeline: impl Into<TimelineName>,
+        seconds: impl Into<f64>,
+    ) {
+        let time = Time::from_seconds_since_epoch(seconds.into());
+        self.send_viewer_command(ViewerCommand::SetTime {
+            timeline: Timeline::new_temporal(timeline),
+            time: Some(TimeInt::try_from(time).unwrap_or(TimeInt::MIN)),
+        });
+    }
+
+    /// Starts playback in the viewer.
+    pub fn viewer_play(&self) {
+        self.send_viewer_command(ViewerCommand::SetPlaying(true));
+    }
+
+    /// Stops playback in the viewer.
+    pub fn viewer_pause(&self) {
+        self.send_viewer_command(ViewerCommand::SetPlaying(false));
+    }
+
+    /// Selects an entity in the viewer, or one instance of it.
+    ///
+    /// Use [`Instance::ALL`] to select the whole entity.
+    ///
+    /// [`Instance::ALL`]: re_log_types::Instance::ALL
+    pub fn viewer_select(
+        &self,
+        entity_path: impl Into<EntityPath>,
+        instance: impl Into<re_log_types::Instance>,
+    ) {
+        self.send_viewer_command(ViewerCommand::Select(Some((
+            entity_path.into(),
+            instance.into(),
+        ))));
+    }
+
+    /// Clears the selection in the viewer.
+    pub fn viewer_clear_selection(&self) {
+        self.send_viewer_command(ViewerCommand::Select(None));
+    }
+
+    /// Takes a screenshot of the viewer, and returns it as a PNG.
+    ///
+    /// Requires a native viewer, connected to over TCP with
+    /// [`crate::sink::ClientOptions::viewer_messages`] set, see [`Self::viewer_events`].
+    /// Everything logged before this call is shown in the screenshot.
+    pub fn viewer_screenshot(&self, timeout: Duration) -> RecordingStreamResult<Vec<u8>> {
+        static NEXT_REQUEST_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+        // Subscribe before asking, so that we can't miss the answer.
+        let rx = self.viewer_messages()?.subscribe();
+        let request_id = NEXT_REQUEST_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.send_viewer_command(ViewerCommand::Screenshot { request_id });
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            match rx.recv_deadline(deadline) {
+                Ok(ViewerMessage::Screenshot {
+                    request_id: answered_id,
+                    png,
+                }) if answered_id == request_id => return Ok(png),
+                Ok(_) => {}
+                Err(_) => return Err(RecordingStreamError::ViewerTimeout(timeout)),
+            }
+        }
+    }
+
+    /// Asks the viewer to report what the user does with this recording.
+    ///
+    /// Requires a viewer connected to with [`crate::sink::ClientOptions::viewer_messages`] set.
+    /// Events are only sent while this recording is the one shown in the viewer, until
+    /// [`Self::disable_viewer_events`] is called or the connection is lost.
+    ///
+    /// Events travel back over the TCP connection of [`Self::connect_opts`] or
+    /// [`Self::spawn_opts`]. A web viewer, which gets its data from the WebSocket server of
+    /// `serve`, can't send any: the WebSocket protocol only goes from the SDK to the viewer.
+    ///
+    /// For example:
+    /// ```no_run
+    /// # fn example(rec: &re_sdk::RecordingStream) -> re_sdk::RecordingStreamResult<()> {
+    /// for event in rec.viewer_events()? {
+    ///     if let re_sdk::ViewerEvent::SelectionChanged(selection) = event {
+    ///         println!("Selected: {selection:?}");
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn viewer_events(&self) -> RecordingStreamResult<crate::ViewerEvents> {
+        let (store_id, viewer_messages) = self
+            .with(|inner| {
+                let viewer_messages = inner.viewer_messages.lock().clone()?;
+                Some((inner.info.store_id.clone(), viewer_messages))
+            })
+            .flatten()
+            .ok_or(RecordingStreamError::ViewerMessagesUnsupported)?;
+
+        let rx = viewer_messages.subscribe();
+        self.send_viewer_command(ViewerCommand::SetEventsEnabled(true));
+        Ok(crate::ViewerEvents::new(store_id, rx))
+    }
+
+    /// Like [`Self::viewer_events`], but calls `callback` with each event on a background thread.
+    pub fn on_viewer_event(
+        &self,
+        callback: impl FnMut(re_log_types::ViewerEvent) + Send + 'static,
+    ) -> RecordingStreamResult<()> {
+        const NAME: &str = "RecordingStream::on_viewer_event";
+
+        let events = self.viewer_events()?;
+        std::thread::Builder::new()
+            .name(NAME.into())
+            .spawn(move || events.for_each(callback))
+            .map_err(|err| RecordingStreamError::SpawnThread {
+                name: NAME.into(),
+                err,
+            })?;
+        Ok(())
+    }
+
+    /// Asks the viewer to stop sending the events requested with [`Self::viewer_events`].
+    pub fn disable_viewer_events(&self) {
+        self.send_viewer_command(ViewerCommand::SetEventsEnabled(false));
+    }
+
+    fn viewer_messages(&self) -> RecordingStreamResult<re_sdk_comms::ViewerMessages> {
+        self.with(|inner| inner.viewer_messages.lock().clone())
+            .flatten()
+            .ok_or(RecordingStreamError::ViewerMessagesUnsupported)
+    }
+}
+
 // ---
 
 #[cfg(test)]
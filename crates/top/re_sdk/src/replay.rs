@@ -0,0 +1,425 @@
+use std::time::{Duration, Instant};
+
+use re_chunk::{Chunk, ChunkError, ChunkId, RowId};
+use re_log_types::{LogMsg, TimeType, TimelineName};
+
+use crate::sink::LogSink;
+
+/// Errors that can occur during a [`replay`].
+#[derive(thiserror::Error, Debug)]
+pub enum ReplayError {
+    /// [`ReplayOptions::speed`] isn't positive.
+    #[error("The replay speed must be positive, got {0}")]
+    InvalidSpeed(f64),
+
+    /// [`ReplayOptions::timeline`] is a sequence timeline, which has no notion of duration.
+    #[error("Can't pace a replay by the sequence timeline {0:?}: pick a temporal one")]
+    SequenceTimeline(TimelineName),
+
+    /// The data couldn't be decoded, or re-encoded with new ids.
+    #[error(transparent)]
+    Chunk(#[from] ChunkError),
+}
+
+/// Results that can occur during a [`replay`].
+pub type ReplayResult<T> = Result<T, ReplayError>;
+
+/// How to pace a [`replay`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplayOptions {
+    /// How much faster than originally to send the data, e.g. `2.0` for twice as fast.
+    pub speed: f64,
+
+    /// Pace the data by its time on this temporal timeline, e.g. `log_time`.
+    ///
+    /// If `None`, the data is paced by the time its [`RowId`]s were created, i.e. when it was
+    /// logged.
+    pub timeline: Option<TimelineName>,
+
+    /// Give all the data new ids.
+    ///
+    /// Receivers ignore data they have seen before, so this is needed to send the same data
+    /// more than once, e.g. when looping.
+    pub new_ids: bool,
+}
+
+impl Default for ReplayOptions {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            timeline: None,
+            new_ids: false,
+        }
+    }
+}
+
+/// Sends `messages` to `sink`, waiting between them as long as originally passed between
+/// them, divided by [`ReplayOptions::speed`].
+///
+/// Messages that carry no time, e.g. static data or blueprints, are sent right away.
+/// The timing of out-of-order data is not respected: it is sent right away too.
+///
+/// Returns once everything has been handed to the sink, which may still be sending it.
+pub fn replay(
+    messages: impl IntoIterator<Item = LogMsg>,
+    sink: &dyn LogSink,
+    options: &ReplayOptions,
+) -> ReplayResult<()> {
+    replay_with_clock(messages, sink, options, WallClock)
+}
+
+fn replay_with_clock(
+    messages: impl IntoIterator<Item = LogMsg>,
+    sink: &dyn LogSink,
+    options: &ReplayOptions,
+    clock: impl Clock,
+) -> ReplayResult<()> {
+    if options.speed.is_nan() || options.speed <= 0.0 {
+        return Err(ReplayError::InvalidSpeed(options.speed));
+    }
+
+    let mut pacer = Pacer::new(options.speed, clock);
+
+    for msg in messages {
+        let LogMsg::ArrowMsg(store_id, arrow_msg) = &msg else {
+            sink.send(msg);
+            continue;
+        };
+
+        let mut chunk = Chunk::from_arrow_msg(arrow_msg)?;
+        if let Some(time_nanos) = chunk_time_nanos(&chunk, options.timeline.as_ref())? {
+            pacer.wait_for(time_nanos);
+        }
+
+        if options.new_ids {
+            chunk = chunk.clone_as(ChunkId::new(), RowId::new());
+            sink.send(LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg()?));
+        } else {
+            sink.send(msg);
+        }
+    }
+
+    Ok(())
+}
+
+/// When the data in the chunk starts, in nanoseconds, if it has a time at all.
+fn chunk_time_nanos(chunk: &Chunk, timeline: Option<&TimelineName>) -> ReplayResult<Option<i64>> {
+    let Some(timeline_name) = timeline else {
+        return Ok(chunk
+            .row_id_range()
+            .map(|(min_row_id, _)| min_row_id.nanoseconds_since_epoch() as i64));
+    };
+
+    let Some((timeline, time_column)) = chunk
+        .timelines()
+        .iter()
+        .find(|(timeline, _)| timeline.name() == timeline_name)
+    else {
+        return Ok(None);
+    };
+
+    if timeline.typ() == TimeType::Sequence {
+        return Err(ReplayError::SequenceTimeline(*timeline_name));
+    }
+
+    Ok(Some(time_column.time_range().min().as_i64()))
+}
+
+/// Where a [`Pacer`] gets the time from, so that tests don't have to wait.
+trait Clock {
+    fn now(&self) -> Instant;
+
+    fn sleep(&mut self, duration: Duration);
+}
+
+struct WallClock;
+
+impl Clock for WallClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+impl<C: Clock> Clock for &mut C {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        (**self).sleep(duration);
+    }
+}
+
+/// Maps times in the data to times on the wall clock.
+struct Pacer<C> {
+    speed: f64,
+    clock: C,
+
+    /// The time of the first data we've seen, and when we saw it.
+    start: Option<(i64, Instant)>,
+}
+
+impl<C: Clock> Pacer<C> {
+    fn new(speed: f64, clock: C) -> Self {
+        Self {
+            speed,
+            clock,
+            start: None,
+        }
+    }
+
+    /// Sleeps until the data at `time_nanos` is due.
+    fn wait_for(&mut self, time_nanos: i64) {
+        let (start_nanos, start_instant) = *self
+            .start
+            .get_or_insert_with(|| (time_nanos, self.clock.now()));
+
+        let offset_secs = time_nanos.saturating_sub(start_nanos) as f64 * 1e-9 / self.speed;
+        let due = Duration::try_from_secs_f64(offset_secs)
+            .ok()
+            .and_then(|offset| start_instant.checked_add(offset));
+        if let Some(due) = due {
+            let now = self.clock.now();
+            if now < due {
+                self.clock.sleep(due - now);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use parking_lot::Mutex;
+
+    use re_chunk::{TimePoint, Timeline};
+    use re_log_types::{example_components::MyPoint, StoreId, StoreKind};
+
+    use crate::sink::CallbackSink;
+
+    use super::*;
+
+    /// A clock that only moves when told to, or when slept on.
+    struct FakeClock {
+        now: Instant,
+        sleeps: Vec<Duration>,
+    }
+
+    impl FakeClock {
+        fn new() -> Self {
+            Self {
+                now: Instant::now(),
+                sleeps: Vec::new(),
+            }
+        }
+
+        fn advance(&mut self, duration: Duration) {
+            self.now += duration;
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            self.now
+        }
+
+        fn sleep(&mut self, duration: Duration) {
+            self.sleeps.push(duration);
+            self.advance(duration);
+        }
+    }
+
+    const START: i64 = 1_700_000_000_000_000_000;
+
+    /// The durations, rounded to milliseconds: the pacing is computed with floats.
+    fn millis(durations: &[Duration]) -> Vec<u128> {
+        durations
+            .iter()
+            .map(|duration| (*duration + Duration::from_micros(500)).as_millis())
+            .collect()
+    }
+
+    fn nanos(ms: i64) -> i64 {
+        START + ms * 1_000_000
+    }
+
+    #[test]
+    fn pacing() {
+        let mut pacer = Pacer::new(1.0, FakeClock::new());
+        pacer.wait_for(nanos(0));
+        pacer.wait_for(nanos(500));
+        pacer.wait_for(nanos(500));
+        pacer.wait_for(nanos(1500));
+        assert_eq!(millis(&pacer.clock.sleeps), vec![500, 1000]);
+
+        // Falling behind isn't made up for later: late data is sent right away.
+        pacer.clock.advance(Duration::from_secs(2));
+        pacer.wait_for(nanos(2000));
+        pacer.wait_for(nanos(4000));
+        assert_eq!(millis(&pacer.clock.sleeps), vec![500, 1000, 500]);
+
+        // Neither is data out of order.
+        pacer.wait_for(nanos(1000));
+        pacer.wait_for(i64::MIN);
+        assert_eq!(pacer.clock.sleeps.len(), 3);
+    }
+
+    #[test]
+    fn speed() {
+        let mut pacer = Pacer::new(4.0, FakeClock::new());
+        pacer.wait_for(nanos(1000));
+        pacer.wait_for(nanos(2000));
+        pacer.wait_for(nanos(4000));
+        assert_eq!(millis(&pacer.clock.sleeps), vec![250, 500]);
+
+        let mut pacer = Pacer::new(0.5, FakeClock::new());
+        pacer.wait_for(nanos(0));
+        pacer.wait_for(nanos(100));
+        assert_eq!(millis(&pacer.clock.sleeps), vec![200]);
+    }
+
+    /// Data that is so far in the future that it would never be due.
+    #[test]
+    fn far_future() {
+        let mut pacer = Pacer::new(f64::MIN_POSITIVE, FakeClock::new());
+        pacer.wait_for(i64::MIN);
+        pacer.wait_for(i64::MAX);
+        assert!(pacer.clock.sleeps.is_empty());
+    }
+
+    fn store_id() -> StoreId {
+        StoreId::from_string(StoreKind::Recording, "rec".to_owned())
+    }
+
+    /// A message with one row, logged at `logged_ms` and at `log_time_ms` on the `log_time`
+    /// timeline, if any.
+    fn message(logged_ms: i64, log_time_ms: Option<i64>) -> LogMsg {
+        let row_id = RowId::from_u128(u128::try_from(nanos(logged_ms)).unwrap() << 64);
+        let mut timepoint = TimePoint::default();
+        if let Some(log_time_ms) = log_time_ms {
+            timepoint.insert(Timeline::log_time(), nanos(log_time_ms));
+        }
+        let chunk = Chunk::builder("points".into())
+            .with_component_batches(row_id, timepoint, [&[MyPoint::new(1.0, 2.0)] as _])
+            .build()
+            .unwrap();
+        LogMsg::ArrowMsg(store_id(), chunk.to_arrow_msg().unwrap())
+    }
+
+    fn chunk_id(msg: &LogMsg) -> ChunkId {
+        let LogMsg::ArrowMsg(_, arrow_msg) = msg else {
+            panic!("Expected an arrow message, got {msg:?}");
+        };
+        Chunk::from_arrow_msg(arrow_msg).unwrap().id()
+    }
+
+    /// Replays `messages`, returning what was sent and how long it waited in between.
+    fn run(
+        messages: &[LogMsg],
+        options: &ReplayOptions,
+    ) -> ReplayResult<(Vec<LogMsg>, Vec<Duration>)> {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let sink = CallbackSink::new({
+            let sent = sent.clone();
+            move |msgs| sent.lock().extend_from_slice(msgs)
+        });
+        let mut clock = FakeClock::new();
+        replay_with_clock(messages.to_vec(), &sink, options, &mut clock)?;
+        let sent = sent.lock().clone();
+        Ok((sent, clock.sleeps))
+    }
+
+    #[test]
+    fn replay_by_row_id() {
+        let messages = [
+            message(0, Some(0)),
+            message(100, Some(5000)),
+            message(300, None),
+            message(200, Some(1000)),
+        ];
+        let (sent, sleeps) = run(&messages, &ReplayOptions::default()).unwrap();
+        assert_eq!(
+            sent.iter().map(chunk_id).collect::<Vec<_>>(),
+            messages.iter().map(chunk_id).collect::<Vec<_>>()
+        );
+        assert_eq!(millis(&sleeps), vec![100, 200]);
+    }
+
+    #[test]
+    fn replay_by_timeline() {
+        let messages = [
+            message(0, Some(0)),
+            message(100, Some(5000)),
+            message(300, None),
+            message(200, Some(6000)),
+        ];
+        let options = ReplayOptions {
+            speed: 2.0,
+            timeline: Some("log_time".into()),
+            new_ids: false,
+        };
+        let (sent, sleeps) = run(&messages, &options).unwrap();
+        assert_eq!(sent.len(), messages.len());
+        assert_eq!(millis(&sleeps), vec![2500, 500]);
+
+        let options = ReplayOptions {
+            timeline: Some("frame".into()),
+            ..Default::default()
+        };
+        let (_, sleeps) = run(&messages, &options).unwrap();
+        assert!(sleeps.is_empty());
+    }
+
+    #[test]
+    fn replay_new_ids() {
+        let messages = [message(0, None), message(100, None)];
+        let options = ReplayOptions {
+            new_ids: true,
+            ..Default::default()
+        };
+        let (sent, sleeps) = run(&messages, &options).unwrap();
+        assert_eq!(millis(&sleeps), vec![100]);
+        for (sent, original) in sent.iter().zip(&messages) {
+            assert_ne!(chunk_id(sent), chunk_id(original));
+        }
+    }
+
+    #[test]
+    fn replay_errors() {
+        let messages = [message(0, None)];
+        for speed in [0.0, -1.0, f64::NAN] {
+            let options = ReplayOptions {
+                speed,
+                ..Default::default()
+            };
+            assert!(matches!(
+                run(&messages, &options),
+                Err(ReplayError::InvalidSpeed(_))
+            ));
+        }
+
+        let row_id = RowId::new();
+        let chunk = Chunk::builder("points".into())
+            .with_component_batches(
+                row_id,
+                TimePoint::default().with(Timeline::new_sequence("frame"), 1),
+                [&[MyPoint::new(1.0, 2.0)] as _],
+            )
+            .build()
+            .unwrap();
+        let messages = [LogMsg::ArrowMsg(store_id(), chunk.to_arrow_msg().unwrap())];
+        let options = ReplayOptions {
+            timeline: Some("frame".into()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            run(&messages, &options),
+            Err(ReplayError::SequenceTimeline(_))
+        ));
+    }
+}
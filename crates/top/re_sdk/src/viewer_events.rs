@@ -0,0 +1,132 @@
+//! Receiving the [`ViewerEvent`]s of a recording, see [`crate::RecordingStream::viewer_events`].
+//!
+//! Only viewers connected to over TCP send events; web viewers, fed over WebSockets, can't.
+
+use std::time::{Duration, Instant};
+
+use crossbeam::channel::Receiver;
+use re_log_types::{StoreId, ViewerEvent, ViewerMessage};
+
+/// What the user does in the viewer with one recording.
+///
+/// Iterating blocks until the next event, and ends once the connection to the viewer is closed
+/// for good.
+#[derive(Debug)]
+pub struct ViewerEvents {
+    store_id: StoreId,
+    rx: Receiver<ViewerMessage>,
+}
+
+impl ViewerEvents {
+    pub(crate) fn new(store_id: StoreId, rx: Receiver<ViewerMessage>) -> Self {
+        Self { store_id, rx }
+    }
+
+    /// The next event, if it has already arrived.
+    pub fn try_recv(&self) -> Option<ViewerEvent> {
+        while let Ok(msg) = self.rx.try_recv() {
+            if let Some(event) = self.event_of(msg) {
+                return Some(event);
+            }
+        }
+        None
+    }
+
+    /// Waits at most `timeout` for the next event.
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<ViewerEvent> {
+        let deadline = Instant::now() + timeout;
+        while let Ok(msg) = self.rx.recv_deadline(deadline) {
+            if let Some(event) = self.event_of(msg) {
+                return Some(event);
+            }
+        }
+        None
+    }
+
+    fn event_of(&self, msg: ViewerMessage) -> Option<ViewerEvent> {
+        match msg {
+            ViewerMessage::Event { store_id, event } if store_id == self.store_id => Some(event),
+            _ => None,
+        }
+    }
+}
+
+impl Iterator for ViewerEvents {
+    type Item = ViewerEvent;
+
+    fn next(&mut self) -> Option<ViewerEvent> {
+        while let Ok(msg) = self.rx.recv() {
+            if let Some(event) = self.event_of(msg) {
+                return Some(event);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{EntityPath, Instance, StoreKind};
+
+    use super::*;
+
+    fn store_id(name: &str) -> StoreId {
+        StoreId::from_string(StoreKind::Recording, name.to_owned())
+    }
+
+    fn hover(name: &str) -> ViewerEvent {
+        ViewerEvent::HoverChanged(Some((EntityPath::from(name), Instance::ALL)))
+    }
+
+    fn event(store_id: &StoreId, event: ViewerEvent) -> ViewerMessage {
+        ViewerMessage::Event {
+            store_id: store_id.clone(),
+            event,
+        }
+    }
+
+    #[test]
+    fn only_events_of_this_recording() {
+        let (tx, rx) = crossbeam::channel::unbounded();
+        let mut events = ViewerEvents::new(store_id("rec"), rx);
+
+        tx.send(event(&store_id("other"), hover("a"))).unwrap();
+        tx.send(ViewerMessage::Screenshot {
+            request_id: 0,
+            png: vec![],
+        })
+        .unwrap();
+        tx.send(event(&store_id("rec"), hover("b"))).unwrap();
+        tx.send(event(&store_id("other"), hover("c"))).unwrap();
+        tx.send(event(&store_id("rec"), hover("d"))).unwrap();
+        assert_eq!(events.try_recv(), Some(hover("b")));
+        assert_eq!(events.next(), Some(hover("d")));
+        assert_eq!(events.try_recv(), None);
+
+        tx.send(event(&store_id("other"), hover("e"))).unwrap();
+        assert_eq!(events.recv_timeout(Duration::from_millis(10)), None);
+        tx.send(event(&store_id("rec"), hover("f"))).unwrap();
+        assert_eq!(
+            events.recv_timeout(Duration::from_secs(10)),
+            Some(hover("f"))
+        );
+    }
+
+    /// Iterating ends once the connection is gone, after the events that made it through.
+    #[test]
+    fn disconnected() {
+        let (tx, rx) = crossbeam::channel::unbounded();
+        let events = ViewerEvents::new(store_id("rec"), rx);
+
+        tx.send(event(&store_id("rec"), hover("a"))).unwrap();
+        tx.send(event(&store_id("other"), hover("b"))).unwrap();
+        tx.send(event(&store_id("rec"), hover("c"))).unwrap();
+        drop(tx);
+
+        assert_eq!(
+            events.recv_timeout(Duration::from_secs(10)),
+            Some(hover("a"))
+        );
+        assert_eq!(events.collect::<Vec<_>>(), vec![hover("c")]);
+    }
+}
@@ -124,6 +124,9 @@ pub fn host_web_viewer(
     let web_server = WebViewerServer::new(bind_ip, web_port)?;
     let http_web_viewer_url = web_server.server_url();
 
+    // The source url may have a query of its own, e.g. an auth token.
+    let source_url =
+        url::form_urlencoded::byte_serialize(source_url.as_bytes()).collect::<String>();
     let mut viewer_url = format!("{http_web_viewer_url}?url={source_url}");
     if let Some(force_graphics) = force_wgpu_backend {
         viewer_url = format!("{viewer_url}&renderer={force_graphics}");
@@ -25,6 +25,9 @@ const SHORT_ABOUT: &str = "The Rerun Viewer and Server";
 // Place the important help _last_, to make it most visible in the terminal.
 const EXAMPLES: &str = r#"
 Environment variables:
+    RERUN_AUTH_TOKEN          Same as `--auth-token`, but without exposing the token to other users of the machine.
+    RERUN_CHUNK_DISK_CACHE_DIR
+                              When the memory limit is reached, move old data of recordings to this directory instead of dropping it.
     RERUN_SHADER_PATH         The search path for shader/shader-imports. Only available in developer builds.
     RERUN_TRACK_ALLOCATIONS   Track memory allocations to diagnose memory leaks in the viewer. WARNING: slows down the viewer by a lot!
     RUST_LOG                  Change the log level of the viewer, e.g. `RUST_LOG=debug`.
@@ -70,6 +73,15 @@ struct Args {
     #[command(subcommand)]
     command: Option<Command>,
 
+    #[clap(
+        long,
+        long_help = r"Only accept SDKs and viewers which present this token.
+SDKs pass it via their connection options, viewers as a `token` query parameter of the WebSocket url, e.g. `ws://localhost:9877/?token=…`.
+Also presented to the WebSocket servers we connect to, e.g. with `rerun ws://…`.
+Prefer setting `RERUN_AUTH_TOKEN` instead, since command line arguments are visible to other users of the machine."
+    )]
+    auth_token: Option<String>,
+
     /// What bind address IP to use.
     #[clap(long, default_value = "0.0.0.0")]
     bind: String,
@@ -167,6 +179,18 @@ When persisted, the state will be stored at the following locations:
     )]
     threads: i32,
 
+    /// A PEM file with the certificate chain to encrypt our TCP and WebSocket servers with.
+    ///
+    /// Requires `--tls-key`, and Rerun to have been compiled with the 'tls' feature.
+    #[cfg(feature = "server")]
+    #[clap(long, requires = "tls_key")]
+    tls_cert: Option<std::path::PathBuf>,
+
+    /// A PEM file with the private key of `--tls-cert`.
+    #[cfg(feature = "server")]
+    #[clap(long, requires = "tls_cert")]
+    tls_key: Option<std::path::PathBuf>,
+
     #[clap(long_help = r"Any combination of:
 - A WebSocket url to a Rerun server
 - A path to a Rerun .rrd recording
@@ -315,12 +339,25 @@ enum RrdCommands {
     /// `RERUN_CHUNK_MAX_BYTES`.
     ///
     /// Example: `RERUN_CHUNK_MAX_ROWS=4096 RERUN_CHUNK_MAX_BYTES=1048576 rerun compact -i input.rrd -o output.rrd`
+    ///
+    /// Use `--compression zstd` to produce smaller files, e.g. for archiving:
+    /// `rerun compact --compression zstd --compression-level 19 -i input.rrd -o output.rrd`
     Compact {
         #[arg(short = 'i', long = "input", value_name = "src.(rrd|rbl)")]
         path_to_input_rrd: String,
 
         #[arg(short = 'o', long = "output", value_name = "dst.(rrd|rbl)")]
         path_to_output_rrd: String,
+
+        /// Compression used for the output file: `off`, `lz4` or `zstd`.
+        #[clap(long, default_value = "lz4")]
+        compression: String,
+
+        /// Compression level, only used with `--compression zstd`.
+        ///
+        /// Ranges from 1 (fastest) to 22 (smallest).
+        #[clap(long)]
+        compression_level: Option<i32>,
     },
 
     /// Merges the contents of multiple .rrd and/or .rbl files, and writes the result to a new file.
@@ -338,6 +375,144 @@ enum RrdCommands {
         #[arg(short = 'o', long = "output", value_name = "dst.(rrd|rbl)")]
         path_to_output_rrd: String,
     },
+
+    /// Filters the contents of an .rrd file and writes the matching subset to a new file.
+    ///
+    /// Chunks that only partially overlap the requested time range are trimmed accordingly.
+    /// Static data is always kept, as long as it matches the entity and component filters.
+    /// Blueprints are passed through untouched.
+    ///
+    /// Example: `rerun rrd filter -i input.rrd -o output.rrd --entity "+ /world/**" --entity "- /world/debug/**" --timeline frame --min 10 --max 100`
+    Filter(FilterCommand),
+
+    /// Prints statistics about the contents of an .rrd or .rbl file, broken down per entity,
+    /// component and timeline.
+    ///
+    /// Useful to find out what is taking up space in a recording.
+    ///
+    /// Example: `rerun rrd stats recording.rrd --json > stats.json`
+    Stats(StatsCommand),
+
+    /// Re-streams the contents of an .rrd file as if it were being logged live, paced by when
+    /// the data was originally logged.
+    ///
+    /// Example: `rerun rrd replay recording.rrd --connect 127.0.0.1:9876 --speed 2 --timeline log_time --loop`
+    Replay(ReplayCommand),
+
+    /// Exports the contents of an .rrd file as Arrow IPC or Parquet files, one per entity.
+    ///
+    /// Each recording ends up in its own sub-directory, named after its recording ID, which
+    /// mirrors the entity hierarchy. Blueprints are skipped.
+    /// Every table has a `RowId` column, one column per timeline and one column per component.
+    /// Component columns are flattened unless `--no-flatten` is passed: single-instance
+    /// components are unwrapped from their list, and their struct fields are split into
+    /// `<component>.<field>` columns.
+    ///
+    /// The resulting `.arrow`/`.parquet` files can be read directly by e.g. pandas, polars or DuckDB.
+    ///
+    /// Example: `rerun rrd export --format parquet -i recording.rrd -o out/`
+    Export {
+        #[arg(short = 'i', long = "input", value_name = "src.rrd")]
+        path_to_input_rrd: String,
+
+        #[arg(short = 'o', long = "output", value_name = "DIR")]
+        path_to_output_dir: String,
+
+        /// Format of the output files: `arrow` (Arrow IPC) or `parquet`.
+        #[clap(long, default_value = "arrow")]
+        format: String,
+
+        /// Keep the component columns as lists of instances, exactly as they are stored.
+        #[clap(long, default_value_t = false)]
+        no_flatten: bool,
+    },
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+struct FilterCommand {
+    #[arg(short = 'i', long = "input", value_name = "src.rrd")]
+    path_to_input_rrd: String,
+
+    #[arg(short = 'o', long = "output", value_name = "dst.rrd")]
+    path_to_output_rrd: String,
+
+    /// An entity path filter rule, e.g. `+ /world/**` or `- /world/debug`.
+    ///
+    /// Can be specified multiple times. Defaults to keeping all entities.
+    #[clap(long = "entity", value_name = "RULE", allow_hyphen_values = true)]
+    entity_path_filter: Vec<String>,
+
+    /// Only keep data logged on this timeline (static data is always kept).
+    #[clap(long)]
+    timeline: Option<String>,
+
+    /// Start of the time range to keep (inclusive), in the native unit of `--timeline`:
+    /// sequence number or nanoseconds.
+    #[clap(long, requires = "timeline")]
+    min: Option<i64>,
+
+    /// End of the time range to keep (inclusive), in the native unit of `--timeline`:
+    /// sequence number or nanoseconds.
+    #[clap(long, requires = "timeline")]
+    max: Option<i64>,
+
+    /// Only keep these components, e.g. `rerun.components.Position3D` or `Position3D`.
+    ///
+    /// Can be specified multiple times. Defaults to keeping all components.
+    #[clap(long = "include-component", value_name = "COMPONENT")]
+    include_components: Vec<String>,
+
+    /// Drop these components, e.g. `rerun.components.Color` or `Color`.
+    ///
+    /// Can be specified multiple times. Applied after `--include-component`.
+    #[clap(long = "exclude-component", value_name = "COMPONENT")]
+    exclude_components: Vec<String>,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+struct ReplayCommand {
+    rrd_path: String,
+
+    /// The address of the Rerun viewer or server to send the data to.
+    #[clap(long, value_name = "ADDR", default_value_t = re_sdk::default_server_addr())]
+    connect: std::net::SocketAddr,
+
+    /// Presented to a server that requires it, see the top-level `--auth-token`.
+    ///
+    /// Defaults to `RERUN_AUTH_TOKEN`.
+    #[clap(long)]
+    auth_token: Option<String>,
+
+    /// Instead of connecting, host a WebSocket server on this port, e.g. for web viewers.
+    ///
+    /// The server binds to the top-level `--bind`, and keeps up to `--server-memory-limit` of
+    /// data for viewers that connect late, e.g.
+    /// `rerun --bind 127.0.0.1 rrd replay --ws-server-port 9877 recording.rrd`.
+    #[cfg(feature = "server")]
+    #[clap(long, conflicts_with = "connect")]
+    ws_server_port: Option<RerunServerPort>,
+
+    /// How much faster than originally to send the data, e.g. `2` for twice as fast.
+    #[clap(long, default_value_t = 1.0)]
+    speed: f64,
+
+    /// Pace the data by this temporal timeline, e.g. `log_time`, rather than by when it was
+    /// logged.
+    #[clap(long)]
+    timeline: Option<String>,
+
+    /// Start over once the end of the file is reached, until interrupted.
+    #[clap(long = "loop", default_value_t = false)]
+    looping: bool,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+struct StatsCommand {
+    rrd_path: String,
+
+    /// Output the statistics as JSON rather than as a human-readable table.
+    #[clap(long, default_value_t = false)]
+    json: bool,
 }
 
 /// Where are we calling [`run`] from?
@@ -412,7 +587,7 @@ where
             #[cfg(feature = "analytics")]
             Command::Analytics(analytics) => run_analytics_commands(analytics).map_err(Into::into),
 
-            Command::Rrd(rrd) => run_rrd_commands(rrd),
+            Command::Rrd(rrd) => run_rrd_commands(rrd, &args),
 
             #[cfg(feature = "native_viewer")]
             Command::Reset => re_viewer::reset_viewer_persistence(),
@@ -470,7 +645,7 @@ fn initialize_thread_pool(threads_args: i32) {
     }
 }
 
-fn run_rrd_commands(cmd: &RrdCommands) -> anyhow::Result<()> {
+fn run_rrd_commands(cmd: &RrdCommands, args: &Args) -> anyhow::Result<()> {
     match cmd {
         RrdCommands::Compare {
             path_to_rrd1,
@@ -489,10 +664,13 @@ fn run_rrd_commands(cmd: &RrdCommands) -> anyhow::Result<()> {
         RrdCommands::Compact {
             path_to_input_rrd,
             path_to_output_rrd,
+            compression,
+            compression_level,
         } => {
             let path_to_input_rrd = PathBuf::from(path_to_input_rrd);
             let path_to_output_rrd = PathBuf::from(path_to_output_rrd);
-            run_compact(&path_to_input_rrd, &path_to_output_rrd)
+            let compression = parse_compression(compression, *compression_level)?;
+            run_compact(&path_to_input_rrd, &path_to_output_rrd, compression)
         }
 
         RrdCommands::Merge {
@@ -503,6 +681,27 @@ fn run_rrd_commands(cmd: &RrdCommands) -> anyhow::Result<()> {
             let path_to_output_rrd = PathBuf::from(path_to_output_rrd);
             run_merge(&path_to_input_rrds, &path_to_output_rrd)
         }
+
+        RrdCommands::Filter(filter_command) => filter_command.run(),
+
+        RrdCommands::Stats(stats_command) => stats_command.run(),
+
+        RrdCommands::Replay(replay_command) => replay_command.run(args),
+
+        RrdCommands::Export {
+            path_to_input_rrd,
+            path_to_output_dir,
+            format,
+            no_flatten,
+        } => {
+            let path_to_input_rrd = PathBuf::from(path_to_input_rrd);
+            let path_to_output_dir = PathBuf::from(path_to_output_dir);
+            let options = re_chunk_store::ExportOptions {
+                format: parse_export_format(format)?,
+                flatten: !*no_flatten,
+            };
+            run_export(&path_to_input_rrd, &path_to_output_dir, &options)
+        }
     }
 }
 
@@ -603,7 +802,11 @@ fn run_compare(path_to_rrd1: &Path, path_to_rrd2: &Path, full_dump: bool) -> any
     Ok(())
 }
 
-fn run_compact(path_to_input_rrd: &Path, path_to_output_rrd: &Path) -> anyhow::Result<()> {
+fn run_compact(
+    path_to_input_rrd: &Path,
+    path_to_output_rrd: &Path,
+    compression: re_log_encoding::Compression,
+) -> anyhow::Result<()> {
     use re_entity_db::EntityDb;
     use re_log_types::StoreId;
 
@@ -668,8 +871,11 @@ fn run_compact(path_to_input_rrd: &Path, path_to_output_rrd: &Path) -> anyhow::R
     let messages = messages?;
     let messages = messages.iter().flatten();
 
-    let encoding_options = re_log_encoding::EncodingOptions::COMPRESSED;
-    re_log_encoding::encoder::encode(version, encoding_options, messages, &mut rrd_out)
+    let encoding_options = re_log_encoding::EncodingOptions {
+        compression,
+        ..re_log_encoding::EncodingOptions::COMPRESSED
+    };
+    re_log_encoding::encoder::encode_with_index(version, encoding_options, messages, &mut rrd_out)
         .context("Message encode")?;
 
     let rrd_out_size = rrd_out.metadata().ok().map(|md| md.len());
@@ -697,6 +903,76 @@ fn run_compact(path_to_input_rrd: &Path, path_to_output_rrd: &Path) -> anyhow::R
     Ok(())
 }
 
+fn run_export(
+    path_to_input_rrd: &Path,
+    path_to_output_dir: &Path,
+    options: &re_chunk_store::ExportOptions,
+) -> anyhow::Result<()> {
+    use re_entity_db::EntityDb;
+    use re_log_types::{StoreId, StoreKind};
+
+    let rrd_in =
+        std::fs::File::open(path_to_input_rrd).with_context(|| format!("{path_to_input_rrd:?}"))?;
+
+    use re_chunk_store::ChunkStoreConfig;
+    let mut store_config = ChunkStoreConfig::from_env().unwrap_or_default();
+    // NOTE: We're doing headless processing, there's no point in running subscribers, it will just
+    // (massively) slow us down.
+    store_config.enable_changelog = false;
+
+    re_log::info!(
+        src = ?path_to_input_rrd,
+        dst = ?path_to_output_dir,
+        "export started"
+    );
+
+    let now = std::time::Instant::now();
+
+    let mut entity_dbs: std::collections::BTreeMap<StoreId, EntityDb> = Default::default();
+    let version_policy = re_log_encoding::decoder::VersionPolicy::Warn;
+    let decoder = re_log_encoding::decoder::Decoder::new(version_policy, rrd_in)?;
+    for msg in decoder {
+        let msg = msg.context("decode rrd message")?;
+        if msg.store_id().kind != StoreKind::Recording {
+            continue;
+        }
+
+        entity_dbs
+            .entry(msg.store_id().clone())
+            .or_insert_with(|| {
+                re_entity_db::EntityDb::with_store_config(
+                    msg.store_id().clone(),
+                    store_config.clone(),
+                )
+            })
+            .add(&msg)
+            .context("decode rrd file contents")?;
+    }
+
+    anyhow::ensure!(!entity_dbs.is_empty(), "no recordings found in rrd file");
+
+    let mut num_files = 0;
+    for (store_id, entity_db) in &entity_dbs {
+        let dir = path_to_output_dir.join(store_id.to_string());
+        let paths = entity_db
+            .store()
+            .export(&dir, options)
+            .with_context(|| format!("{dir:?}"))?;
+        num_files += paths.len();
+    }
+
+    re_log::info!(
+        src = ?path_to_input_rrd,
+        dst = ?path_to_output_dir,
+        num_recordings = entity_dbs.len(),
+        num_files,
+        time = ?now.elapsed(),
+        "export finished"
+    );
+
+    Ok(())
+}
+
 fn run_merge(path_to_input_rrds: &[PathBuf], path_to_output_rrd: &Path) -> anyhow::Result<()> {
     use re_entity_db::EntityDb;
     use re_log_types::StoreId;
@@ -775,7 +1051,7 @@ fn run_merge(path_to_input_rrds: &[PathBuf], path_to_output_rrd: &Path) -> anyho
 
     let encoding_options = re_log_encoding::EncodingOptions::COMPRESSED;
     let version = version.unwrap_or(re_build_info::CrateVersion::LOCAL);
-    re_log_encoding::encoder::encode(version, encoding_options, messages, &mut rrd_out)
+    re_log_encoding::encoder::encode_with_index(version, encoding_options, messages, &mut rrd_out)
         .context("Message encode")?;
 
     let rrd_out_size = rrd_out.metadata().ok().map(|md| md.len());
@@ -792,6 +1068,142 @@ fn run_merge(path_to_input_rrds: &[PathBuf], path_to_output_rrd: &Path) -> anyho
     Ok(())
 }
 
+impl FilterCommand {
+    fn run(&self) -> anyhow::Result<()> {
+        use re_log_types::{EntityPathFilter, ResolvedTimeRange, TimeInt};
+
+        let Self {
+            path_to_input_rrd,
+            path_to_output_rrd,
+            entity_path_filter,
+            timeline,
+            min,
+            max,
+            include_components,
+            exclude_components,
+        } = self;
+
+        let path_to_input_rrd = PathBuf::from(path_to_input_rrd);
+        let path_to_output_rrd = PathBuf::from(path_to_output_rrd);
+
+        let entity_path_filter = if entity_path_filter.is_empty() {
+            EntityPathFilter::parse_forgiving("/**", &Default::default())
+        } else {
+            EntityPathFilter::from_query_expressions(
+                entity_path_filter.iter().map(String::as_str),
+                &Default::default(),
+            )
+        };
+
+        let time_range = ResolvedTimeRange::new(
+            min.map_or(TimeInt::MIN, TimeInt::new_temporal),
+            max.map_or(TimeInt::MAX, TimeInt::new_temporal),
+        );
+
+        let is_component_included = |component_name: &re_types::ComponentName| {
+            let matches = |name: &String| {
+                name == component_name.full_name() || name == component_name.short_name()
+            };
+            (include_components.is_empty() || include_components.iter().any(matches))
+                && !exclude_components.iter().any(matches)
+        };
+
+        let rrd_in = std::fs::File::open(&path_to_input_rrd)
+            .with_context(|| format!("{path_to_input_rrd:?}"))?;
+        let rrd_out = std::fs::File::create(&path_to_output_rrd)
+            .with_context(|| format!("{path_to_output_rrd:?}"))?;
+
+        re_log::info!(
+            src = ?path_to_input_rrd,
+            dst = ?path_to_output_rrd,
+            entity_path_filter = ?entity_path_filter,
+            timeline = ?timeline,
+            time_range = ?time_range,
+            "filter started"
+        );
+
+        let now = std::time::Instant::now();
+
+        let version_policy = re_log_encoding::decoder::VersionPolicy::Warn;
+        let decoder = re_log_encoding::decoder::Decoder::new(version_policy, rrd_in)?;
+        let mut encoder = re_log_encoding::encoder::Encoder::new(
+            decoder.version(),
+            re_log_encoding::EncodingOptions::COMPRESSED,
+            std::io::BufWriter::new(rrd_out),
+        )?
+        .with_index();
+
+        let mut num_chunks_in = 0;
+        let mut num_chunks_out = 0;
+
+        for msg in decoder {
+            let msg = msg.context("decode rrd message")?;
+
+            let LogMsg::ArrowMsg(store_id, arrow_msg) = &msg else {
+                encoder.append(&msg).context("Message encode")?;
+                continue;
+            };
+
+            if store_id.kind != re_log_types::StoreKind::Recording {
+                encoder.append(&msg).context("Message encode")?;
+                continue;
+            }
+
+            num_chunks_in += 1;
+
+            let chunk = Chunk::from_arrow_msg(arrow_msg).context("decode chunk")?;
+
+            if !entity_path_filter.is_included(chunk.entity_path()) {
+                continue;
+            }
+
+            let chunk = match timeline {
+                Some(timeline_name) if !chunk.is_static() => {
+                    let Some(timeline) = chunk
+                        .timelines()
+                        .keys()
+                        .find(|timeline| timeline.name().as_str() == timeline_name.as_str())
+                        .copied()
+                    else {
+                        continue;
+                    };
+                    chunk.time_range_sliced(&timeline, time_range)
+                }
+                _ => chunk,
+            };
+
+            let component_names: nohash_hasher::IntSet<re_types::ComponentName> = chunk
+                .component_names()
+                .filter(is_component_included)
+                .collect();
+            let chunk = chunk.components_sliced(&component_names);
+
+            if chunk.is_empty() || chunk.num_components() == 0 {
+                continue;
+            }
+
+            num_chunks_out += 1;
+
+            let msg = LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg()?);
+            encoder.append(&msg).context("Message encode")?;
+        }
+
+        encoder.finish().context("Message encode")?;
+        encoder.flush_blocking()?;
+
+        re_log::info!(
+            src = ?path_to_input_rrd,
+            dst = ?path_to_output_rrd,
+            num_chunks_in,
+            num_chunks_out,
+            time = ?now.elapsed(),
+            "filter finished"
+        );
+
+        Ok(())
+    }
+}
+
 impl PrintCommand {
     fn run(&self) -> anyhow::Result<()> {
         let rrd_path = PathBuf::from(&self.rrd_path);
@@ -850,12 +1262,279 @@ impl PrintCommand {
                 }) => {
                     println!("BlueprintActivationCommand({blueprint_id}, make_active: {make_active}, make_default: {make_default})");
                 }
+
+                LogMsg::ViewerCommand(store_id, command) => {
+                    println!("ViewerCommand({store_id}, {command:?})");
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ReplayCommand {
+    #[allow(clippy::infinite_loop)] // When hosting a server, we serve until interrupted.
+    #[cfg_attr(not(feature = "server"), allow(unused_variables))]
+    fn run(&self, args: &Args) -> anyhow::Result<()> {
+        let rrd_path = PathBuf::from(&self.rrd_path);
+
+        #[cfg(feature = "server")]
+        if let Some(ws_server_port) = self.ws_server_port {
+            let server_memory_limit = re_memory::MemoryLimit::parse(&args.server_memory_limit)
+                .map_err(|err| anyhow::format_err!("Bad --server-memory-limit: {err}"))?;
+
+            let (tx, rx) = re_smart_channel::smart_channel(
+                re_smart_channel::SmartMessageSource::File(rrd_path.clone()),
+                re_smart_channel::SmartChannelSource::File(rrd_path.clone()),
+            );
+            let ws_server = re_ws_comms::RerunServer::new(
+                ReceiveSet::new(vec![rx]),
+                &args.bind,
+                ws_server_port,
+                server_memory_limit,
+            )?;
+            re_log::info!("Replaying {rrd_path:?} on {}", ws_server.server_url());
+
+            let sink = re_sdk::sink::CallbackSink::new(move |msgs| {
+                for msg in msgs {
+                    tx.send(msg.clone()).ok();
+                }
+            });
+            self.replay(&rrd_path, &sink)?;
+
+            // Web viewers that connect later still get everything that was sent.
+            re_log::info!("Replay done. Still serving, press Ctrl-C to stop.");
+            loop {
+                std::thread::park();
+            }
+        }
+
+        let options = re_sdk::sink::ClientOptions {
+            auth_token: self
+                .auth_token
+                .clone()
+                .or_else(|| std::env::var("RERUN_AUTH_TOKEN").ok()),
+            ..Default::default()
+        };
+        let sink = re_sdk::sink::TcpSink::with_options(
+            self.connect,
+            re_sdk::default_flush_timeout(),
+            options,
+        );
+        re_log::info!("Replaying {rrd_path:?} to {}", self.connect);
+        let result = self.replay(&rrd_path, &sink);
+
+        // Like a `RecordingStream` that goes out of scope: give up on an unreachable server
+        // after the flush timeout, rather than retrying forever.
+        use re_sdk::sink::LogSink as _;
+        sink.drop_if_disconnected();
+        sink.flush_blocking();
+        result
+    }
+
+    fn replay(&self, rrd_path: &Path, sink: &dyn re_sdk::sink::LogSink) -> anyhow::Result<()> {
+        for pass in 0.. {
+            let rrd_file = std::fs::File::open(rrd_path)
+                .with_context(|| format!("couldn't open {rrd_path:?}"))?;
+            let version_policy = re_log_encoding::decoder::VersionPolicy::Warn;
+            let decoder = re_log_encoding::decoder::Decoder::new(version_policy, rrd_file)?;
+
+            let messages = decoder.map_while(|msg| {
+                msg.map_err(|err| re_log::error!("Failed to decode {rrd_path:?}: {err}"))
+                    .ok()
+            });
+            let options = re_sdk::replay::ReplayOptions {
+                speed: self.speed,
+                timeline: self.timeline.as_deref().map(Into::into),
+                new_ids: 0 < pass,
+            };
+            re_sdk::replay::replay(messages, sink, &options)?;
+
+            if !self.looping {
+                break;
+            }
+            re_log::info!("Reached the end of {rrd_path:?}, starting over");
+        }
+        Ok(())
+    }
+}
+
+impl StatsCommand {
+    fn run(&self) -> anyhow::Result<()> {
+        let rrd_path = PathBuf::from(&self.rrd_path);
+        self.print_stats(&rrd_path)
+            .with_context(|| format!("path: {rrd_path:?}"))
+    }
+
+    fn print_stats(&self, rrd_path: &Path) -> anyhow::Result<()> {
+        use re_chunk_store::ChunkStoreConfig;
+        use re_entity_db::EntityDb;
+        use re_log_types::StoreId;
+
+        let Self { rrd_path: _, json } = self;
+
+        // NOTE: We want to report on the chunks as they are laid out in the file, so no
+        // compaction. We're doing headless processing, so no changelog either.
+        let store_config = ChunkStoreConfig {
+            enable_changelog: false,
+            ..ChunkStoreConfig::COMPACTION_DISABLED
+        };
+
+        let rrd_file = std::fs::File::open(rrd_path)?;
+        let version_policy = re_log_encoding::decoder::VersionPolicy::Warn;
+        let decoder = re_log_encoding::decoder::Decoder::new(version_policy, rrd_file)?;
+
+        let mut entity_dbs: std::collections::BTreeMap<StoreId, EntityDb> = Default::default();
+        for msg in decoder {
+            let msg = msg.context("decode rrd message")?;
+            entity_dbs
+                .entry(msg.store_id().clone())
+                .or_insert_with(|| {
+                    EntityDb::with_store_config(msg.store_id().clone(), store_config.clone())
+                })
+                .add(&msg)
+                .context("decode rrd file contents")?;
+        }
+
+        if *json {
+            let stores = entity_dbs
+                .values()
+                .map(|entity_db| stats_to_json(entity_db.store()))
+                .collect_vec();
+            println!("{}", serde_json::to_string_pretty(&stores)?);
+        } else {
+            for entity_db in entity_dbs.values() {
+                print_stats_table(entity_db.store());
             }
         }
+
         Ok(())
     }
 }
 
+fn stats_to_json(store: &re_chunk_store::ChunkStore) -> serde_json::Value {
+    use re_chunk_store::{ChunkStoreChunkStats, ChunkStoreStats};
+    use serde_json::json;
+
+    fn chunk_stats_to_json(stats: ChunkStoreChunkStats) -> serde_json::Value {
+        let ChunkStoreChunkStats {
+            num_chunks,
+            total_size_bytes,
+            total_num_rows,
+        } = stats;
+        json!({
+            "num_chunks": num_chunks,
+            "size_bytes": total_size_bytes,
+            "num_rows": total_num_rows,
+        })
+    }
+
+    fn store_stats_to_json(stats: ChunkStoreStats) -> serde_json::Value {
+        json!({
+            "total": chunk_stats_to_json(stats.total()),
+            "static": chunk_stats_to_json(stats.static_chunks),
+            "temporal": chunk_stats_to_json(stats.temporal_chunks),
+        })
+    }
+
+    let entities: serde_json::Map<String, serde_json::Value> = store
+        .stats_per_entity()
+        .into_iter()
+        .map(|(entity_path, stats)| {
+            let components: serde_json::Map<String, serde_json::Value> = stats
+                .per_component
+                .into_iter()
+                .map(|(component_name, stats)| {
+                    (component_name.to_string(), store_stats_to_json(stats))
+                })
+                .collect();
+
+            let timelines: serde_json::Map<String, serde_json::Value> = stats
+                .per_timeline
+                .into_iter()
+                .map(|(timeline, stats)| {
+                    let mut value = chunk_stats_to_json(stats.chunks);
+                    value["time_min"] = stats.time_range.min().as_i64().into();
+                    value["time_max"] = stats.time_range.max().as_i64().into();
+                    (timeline.name().to_string(), value)
+                })
+                .collect();
+
+            let mut value = store_stats_to_json(stats.chunks);
+            value["components"] = components.into();
+            value["timelines"] = timelines.into();
+            (entity_path.to_string(), value)
+        })
+        .collect();
+
+    json!({
+        "store_id": store.id().to_string(),
+        "store_kind": store.id().kind.to_string(),
+        "stats": store_stats_to_json(store.stats()),
+        "entities": entities,
+    })
+}
+
+fn print_stats_table(store: &re_chunk_store::ChunkStore) {
+    use re_chunk_store::ChunkStoreStats;
+
+    fn format_row(name: &str, stats: ChunkStoreStats) -> String {
+        let total = stats.total();
+        format!(
+            "{name:<60} {:>10} {:>12} {:>12} {:>12} {:>12}",
+            re_format::format_uint(total.num_chunks),
+            re_format::format_uint(total.total_num_rows),
+            re_format::format_bytes(total.total_size_bytes as _),
+            re_format::format_bytes(stats.static_chunks.total_size_bytes as _),
+            re_format::format_bytes(stats.temporal_chunks.total_size_bytes as _),
+        )
+    }
+
+    println!("{} {}", store.id().kind, store.id());
+    println!(
+        "{:<60} {:>10} {:>12} {:>12} {:>12} {:>12}",
+        "", "chunks", "rows", "size", "static", "temporal"
+    );
+    println!("{}", format_row("<total>", store.stats()));
+
+    // Largest entities first: that's what people are looking for.
+    let stats_per_entity = store
+        .stats_per_entity()
+        .into_iter()
+        .sorted_by_key(|(_, stats)| std::cmp::Reverse(stats.chunks.total().total_size_bytes));
+
+    for (entity_path, stats) in stats_per_entity {
+        println!();
+        println!("{}", format_row(&entity_path.to_string(), stats.chunks));
+
+        for (component_name, component_stats) in stats
+            .per_component
+            .iter()
+            .sorted_by_key(|(_, stats)| std::cmp::Reverse(stats.total().total_size_bytes))
+        {
+            println!(
+                "{}",
+                format_row(
+                    &format!("  {}", component_name.short_name()),
+                    *component_stats
+                )
+            );
+        }
+
+        for (timeline, timeline_stats) in &stats.per_timeline {
+            println!(
+                "  {} {}: {} chunks, {} rows",
+                timeline.name(),
+                timeline.format_time_range_utc(&timeline_stats.time_range),
+                re_format::format_uint(timeline_stats.chunks.num_chunks),
+                re_format::format_uint(timeline_stats.chunks.total_num_rows),
+            );
+        }
+    }
+
+    println!();
+}
+
 #[cfg(feature = "analytics")]
 fn run_analytics_commands(cmd: &AnalyticsCommands) -> Result<(), re_analytics::cli::CliError> {
     match cmd {
@@ -917,15 +1596,41 @@ fn run_impl(
         }
     };
 
+    let auth_token = args
+        .auth_token
+        .clone()
+        .or_else(|| std::env::var("RERUN_AUTH_TOKEN").ok());
+
+    #[cfg(feature = "server")]
+    let tls = args
+        .tls_cert
+        .clone()
+        .zip(args.tls_key.clone())
+        .map(|(cert_path, key_path)| re_sdk_comms::ServerTlsOptions {
+            cert_path,
+            key_path,
+        });
+
+    // Lets the viewer answer the SDK clients of our TCP server, e.g. with screenshots.
+    #[cfg(feature = "server")]
+    let mut _viewer_clients = None;
+
     // Where do we get the data from?
+    #[cfg(feature = "native_viewer")]
+    let mut lazy_rrd_paths: Vec<std::path::PathBuf> = Vec::new();
+
     let rx: Vec<Receiver<LogMsg>> = if args.url_or_paths.is_empty() {
         #[cfg(feature = "server")]
         {
             let server_options = re_sdk_comms::ServerOptions {
                 max_latency_sec: parse_max_latency(args.drop_at_latency.as_ref()),
                 quiet: false,
+                auth_token: auth_token.clone(),
+                tls: tls.clone(),
             };
-            let rx = re_sdk_comms::serve(&args.bind, args.port, server_options)?;
+            let (rx, viewer_clients) =
+                re_sdk_comms::serve_with_viewer_clients(&args.bind, args.port, server_options)?;
+            _viewer_clients = Some(viewer_clients);
             vec![rx]
         }
 
@@ -937,7 +1642,15 @@ fn run_impl(
             .iter()
             .cloned()
             .map(|uri| DataSource::from_uri(re_log_types::FileSource::Cli, uri))
-            .collect_vec();
+            .map(|data_source| match (data_source, &auth_token) {
+                (DataSource::WebSocketAddr(url), Some(auth_token)) => {
+                    re_ws_comms::url_with_auth_token(&url, auth_token)
+                        .map(DataSource::WebSocketAddr)
+                        .with_context(|| format!("Invalid WebSocket url {url:?}"))
+                }
+                (data_source, _) => Ok(data_source),
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
 
         #[cfg(feature = "web_viewer")]
         if data_sources.len() == 1 && args.web_viewer {
@@ -957,6 +1670,27 @@ fn run_impl(
             }
         }
 
+        // Large `.rrd` files are loaded lazily by the native viewer, which then reads their
+        // chunks back on demand rather than keeping everything in memory.
+        #[cfg(feature = "native_viewer")]
+        let data_sources = if !args.test_receive && args.save.is_none() && !args.serve {
+            let (lazy, eager): (Vec<_>, Vec<_>) =
+                data_sources.into_iter().partition(|data_source| {
+                    matches!(data_source, DataSource::FilePath(_, path)
+                        if re_entity_db::rrd_chunk_source::should_load_lazily(path))
+                });
+            lazy_rrd_paths = lazy
+                .into_iter()
+                .filter_map(|data_source| match data_source {
+                    DataSource::FilePath(_, path) => Some(path),
+                    _ => None,
+                })
+                .collect();
+            eager
+        } else {
+            data_sources
+        };
+
         data_sources
             .into_iter()
             .map(|data_source| data_source.stream(None))
@@ -1002,12 +1736,27 @@ fn run_impl(
             let server_memory_limit = re_memory::MemoryLimit::parse(&args.server_memory_limit)
                 .map_err(|err| anyhow::format_err!("Bad --server-memory-limit: {err}"))?;
 
+            #[cfg(not(feature = "tls"))]
+            if tls.is_some() {
+                return Err(re_sdk_comms::TlsError::NotSupported.into());
+            }
+
+            let ws_server_options = re_ws_comms::RerunServerOptions {
+                auth_token,
+                #[cfg(feature = "tls")]
+                tls_config: tls
+                    .as_ref()
+                    .map(re_sdk_comms::ServerTlsOptions::load)
+                    .transpose()?,
+            };
+
             // This is the server which the web viewer will talk to:
-            let _ws_server = re_ws_comms::RerunServer::new(
+            let _ws_server = re_ws_comms::RerunServer::with_options(
                 ReceiveSet::new(rx),
                 &args.bind,
                 args.ws_server_port,
                 server_memory_limit,
+                ws_server_options,
             )?;
 
             #[cfg(feature = "web_viewer")]
@@ -1023,7 +1772,7 @@ fn run_impl(
                     args.web_viewer_port,
                     args.renderer,
                     open_browser,
-                    &_ws_server.server_url(),
+                    &_ws_server.client_url(),
                 )?
                 .block(); // dropping should stop the server
             }
@@ -1044,6 +1793,13 @@ fn run_impl(
                 for rx in rx {
                     app.add_receiver(rx);
                 }
+                for path in &lazy_rrd_paths {
+                    app.load_rrd_lazily(path);
+                }
+                #[cfg(feature = "server")]
+                if let Some(viewer_clients) = _viewer_clients {
+                    app.set_viewer_clients(viewer_clients);
+                }
                 app.set_profiler(profiler);
                 if let Ok(url) = std::env::var("EXAMPLES_MANIFEST_URL") {
                     app.set_examples_manifest_url(url);
@@ -1077,6 +1833,35 @@ fn parse_size(size: &str) -> anyhow::Result<[f32; 2]> {
         .ok_or_else(|| anyhow::anyhow!("Invalid size {:?}, expected e.g. 800x600", size))
 }
 
+fn parse_compression(
+    compression: &str,
+    level: Option<i32>,
+) -> anyhow::Result<re_log_encoding::Compression> {
+    use re_log_encoding::Compression;
+
+    match (compression.to_lowercase().as_str(), level) {
+        ("off" | "none", None) => Ok(Compression::Off),
+        ("lz4", None) => Ok(Compression::LZ4),
+        ("zstd", level) => Ok(Compression::Zstd {
+            level: level.unwrap_or(Compression::ZSTD_DEFAULT_LEVEL),
+        }),
+        ("off" | "none" | "lz4", Some(_)) => {
+            anyhow::bail!("--compression-level is only supported with --compression zstd")
+        }
+        _ => anyhow::bail!("Unknown compression {compression:?}, expected one of: off, lz4, zstd"),
+    }
+}
+
+fn parse_export_format(format: &str) -> anyhow::Result<re_chunk_store::ExportFormat> {
+    use re_chunk_store::ExportFormat;
+
+    match format.to_lowercase().as_str() {
+        "arrow" | "ipc" => Ok(ExportFormat::ArrowIpc),
+        "parquet" => Ok(ExportFormat::Parquet),
+        _ => anyhow::bail!("Unknown export format {format:?}, expected one of: arrow, parquet"),
+    }
+}
+
 // NOTE: This is only used as part of end-to-end tests.
 fn assert_receive_into_entity_db(
     rx: &ReceiveSet<LogMsg>,
@@ -1161,7 +1946,8 @@ fn stream_to_rrd_on_disk(
         re_build_info::CrateVersion::LOCAL,
         encoding_options,
         file,
-    )?;
+    )?
+    .with_index();
 
     loop {
         match rx.recv() {
@@ -1176,6 +1962,7 @@ fn stream_to_rrd_on_disk(
             }
         }
     }
+    encoder.finish()?;
 
     re_log::info!("File saved to {path:?}");
 
@@ -0,0 +1,131 @@
+//! Decoding video frames by running `ffmpeg`, see [`FfmpegDecoder`].
+
+use std::io::Write as _;
+use std::process::Command;
+
+use crate::VideoData;
+
+/// Errors that can occur when decoding frames, see [`FfmpegDecoder`].
+#[derive(thiserror::Error, Debug)]
+pub enum DecodeError {
+    #[error("Failed to write the video to a temporary file: {0}")]
+    TempFile(std::io::Error),
+
+    #[error("Failed to run ffmpeg, make sure it is installed and on your PATH: {0}")]
+    FfmpegNotFound(std::io::Error),
+
+    #[error("ffmpeg failed: {0}")]
+    Ffmpeg(String),
+
+    #[error("Frame #{frame_index} is out of bounds, the video has {num_frames} frames")]
+    FrameOutOfBounds {
+        frame_index: usize,
+        num_frames: usize,
+    },
+}
+
+/// A decoded frame of a video.
+#[derive(Clone)]
+pub struct Frame {
+    /// Which frame of the video this is, in presentation order.
+    pub frame_index: usize,
+
+    /// When this frame is shown, in nanoseconds.
+    pub timestamp_ns: i64,
+
+    pub width: u32,
+    pub height: u32,
+
+    /// RGB, 8 bits per channel, row by row.
+    pub data: Vec<u8>,
+}
+
+/// Decodes the frames of a video by running the `ffmpeg` executable, which must be on the `PATH`.
+///
+/// Supports whatever codecs the installed `ffmpeg` supports, e.g. H.264, H.265 and AV1.
+pub struct FfmpegDecoder {
+    /// The video, on disk so that `ffmpeg` can seek in it.
+    file: tempfile::NamedTempFile,
+}
+
+impl FfmpegDecoder {
+    /// `data` is the whole video file that `video` was loaded from.
+    pub fn new(data: &[u8]) -> Result<Self, DecodeError> {
+        re_tracing::profile_function!();
+
+        let mut file = tempfile::Builder::new()
+            .prefix("rerun-video-")
+            .tempfile()
+            .map_err(DecodeError::TempFile)?;
+        file.write_all(data).map_err(DecodeError::TempFile)?;
+        file.flush().map_err(DecodeError::TempFile)?;
+
+        Ok(Self { file })
+    }
+
+    /// Decodes up to `max_frames` consecutive frames, starting at `first_frame` (in presentation
+    /// order, see [`VideoData::frame_index_at`]).
+    ///
+    /// This is frame accurate: `ffmpeg` seeks to the last keyframe before `first_frame`, and
+    /// decodes (but doesn't output) every frame in between.
+    pub fn decode(
+        &self,
+        video: &VideoData,
+        first_frame: usize,
+        max_frames: usize,
+    ) -> Result<Vec<Frame>, DecodeError> {
+        re_tracing::profile_function!();
+
+        let Some(timestamp_ns) = video.frame_timestamp_ns(first_frame) else {
+            return Err(DecodeError::FrameOutOfBounds {
+                frame_index: first_frame,
+                num_frames: video.num_frames(),
+            });
+        };
+        let num_frames = max_frames.min(video.num_frames() - first_frame);
+        let (width, height) = (video.config.width, video.config.height);
+
+        // NOTE: Round down: `ffmpeg` drops every frame before the seek position, including the
+        // one we want if we overshoot it by a rounding error.
+        let output = Command::new("ffmpeg")
+            .args(["-hide_banner", "-loglevel", "error", "-nostdin"])
+            .args(["-ss", &format!("{}us", timestamp_ns.div_euclid(1_000))])
+            .arg("-i")
+            .arg(self.file.path())
+            .args(["-frames:v", &num_frames.to_string()])
+            .args(["-vsync", "passthrough"])
+            .args(["-s", &format!("{width}x{height}")])
+            .args(["-f", "rawvideo", "-pix_fmt", "rgb24", "pipe:1"])
+            .output()
+            .map_err(DecodeError::FfmpegNotFound)?;
+
+        if !output.status.success() {
+            return Err(DecodeError::Ffmpeg(
+                String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+            ));
+        }
+
+        let frame_size = width as usize * height as usize * 3;
+        if frame_size == 0 {
+            return Err(DecodeError::Ffmpeg(format!(
+                "invalid video resolution {width}x{height}"
+            )));
+        }
+
+        Ok(output
+            .stdout
+            .chunks_exact(frame_size)
+            .enumerate()
+            .filter_map(|(i, data)| {
+                let frame_index = first_frame + i;
+                Some(Frame {
+                    frame_index,
+                    timestamp_ns: video.frame_timestamp_ns(frame_index)?,
+                    width,
+                    height,
+                    data: data.to_vec(),
+                })
+            })
+            .collect())
+    }
+}
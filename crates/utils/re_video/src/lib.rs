@@ -0,0 +1,242 @@
+//! Demuxing and decoding of video files, e.g. MP4.
+//!
+//! [`VideoData`] is the frame table of a video: when each frame is shown, and which of them are
+//! keyframes. It is cheap to load, and all that's needed to map a timestamp to a frame.
+//!
+//! Decoding the frames themselves is done by [`decode::FfmpegDecoder`], which runs the `ffmpeg`
+//! executable: it must be installed and on the `PATH`. There is no decoder on the web yet.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod decode;
+
+mod mp4;
+
+/// A point in time or a duration, in units of a video's [`Timescale`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time(pub i64);
+
+impl Time {
+    #[inline]
+    pub fn into_nanos(self, timescale: Timescale) -> i64 {
+        (self.0 as i128 * 1_000_000_000 / timescale.0.max(1) as i128) as i64
+    }
+}
+
+/// How many [`Time`] units there are in one second.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Timescale(pub u64);
+
+/// What is needed to decode the samples of a video.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The codec, as the four character code of the sample entry, e.g. `avc1` or `av01`.
+    pub codec: String,
+
+    /// Width of the decoded frames, in pixels.
+    pub width: u32,
+
+    /// Height of the decoded frames, in pixels.
+    pub height: u32,
+}
+
+/// A single encoded frame of a video.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sample {
+    /// Can this sample be decoded on its own, i.e. is it a keyframe?
+    pub is_sync: bool,
+
+    /// When this sample needs to be decoded.
+    pub decode_timestamp: Time,
+
+    /// When this sample is shown, relative to the first frame of the video.
+    pub presentation_timestamp: Time,
+
+    /// How long this sample is shown.
+    pub duration: Time,
+
+    /// Where the encoded sample is in the video file.
+    pub byte_offset: u64,
+
+    /// How large the encoded sample is.
+    pub byte_length: u32,
+}
+
+/// The frame table of a video, see [`VideoData::load`].
+#[derive(Clone, Debug)]
+pub struct VideoData {
+    pub config: Config,
+
+    pub timescale: Timescale,
+
+    /// Total duration of the video.
+    pub duration: Time,
+
+    /// All samples of the video, in decode order.
+    pub samples: Vec<Sample>,
+
+    /// Indices into [`Self::samples`], in presentation order.
+    presentation_order: Vec<usize>,
+}
+
+/// Errors that can occur when loading a video, see [`VideoData::load`].
+#[derive(thiserror::Error, Debug)]
+pub enum VideoLoadError {
+    #[error("Unsupported media type {0:?}, only video/mp4 is supported")]
+    UnsupportedMediaType(String),
+
+    #[error("Invalid MP4 file: {0}")]
+    InvalidMp4(String),
+
+    #[error("Fragmented MP4 files are not supported")]
+    FragmentedMp4,
+
+    #[error("The file doesn't contain any video track")]
+    NoVideoTrack,
+}
+
+impl VideoData {
+    /// Loads the frame table of a video file, e.g. an MP4.
+    ///
+    /// Only MP4 files (`video/mp4`) are supported for now.
+    pub fn load(data: &[u8], media_type: &str) -> Result<Self, VideoLoadError> {
+        re_tracing::profile_function!();
+
+        match media_type {
+            "video/mp4" => Self::load_mp4(data),
+            _ => Err(VideoLoadError::UnsupportedMediaType(media_type.to_owned())),
+        }
+    }
+
+    /// Loads the frame table of the first video track of an MP4 file.
+    pub fn load_mp4(data: &[u8]) -> Result<Self, VideoLoadError> {
+        let mp4::Track {
+            config,
+            timescale,
+            duration,
+            samples,
+        } = mp4::read_video_track(data)?;
+
+        Ok(Self::new(config, timescale, duration, samples))
+    }
+
+    /// A frame table made of the given `samples`, in decode order.
+    pub fn new(config: Config, timescale: Timescale, duration: Time, samples: Vec<Sample>) -> Self {
+        let mut presentation_order = (0..samples.len()).collect::<Vec<_>>();
+        presentation_order.sort_by_key(|&index| samples[index].presentation_timestamp);
+
+        Self {
+            config,
+            timescale,
+            duration,
+            samples,
+            presentation_order,
+        }
+    }
+
+    /// Total duration of the video, in nanoseconds.
+    #[inline]
+    pub fn duration_ns(&self) -> i64 {
+        self.duration.into_nanos(self.timescale)
+    }
+
+    /// Number of frames in the video.
+    #[inline]
+    pub fn num_frames(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// The sample that is shown as the `frame_index`-th frame of the video.
+    #[inline]
+    pub fn frame(&self, frame_index: usize) -> Option<&Sample> {
+        self.presentation_order
+            .get(frame_index)
+            .map(|&index| &self.samples[index])
+    }
+
+    /// When the `frame_index`-th frame of the video is shown, in nanoseconds.
+    #[inline]
+    pub fn frame_timestamp_ns(&self, frame_index: usize) -> Option<i64> {
+        self.frame(frame_index)
+            .map(|sample| sample.presentation_timestamp.into_nanos(self.timescale))
+    }
+
+    /// When each frame of the video is shown, in nanoseconds, in presentation order.
+    ///
+    /// Log these as `VideoFrameReference`s to show every frame of the video.
+    pub fn frame_timestamps_ns(&self) -> impl Iterator<Item = i64> + '_ {
+        self.presentation_order.iter().map(|&index| {
+            self.samples[index]
+                .presentation_timestamp
+                .into_nanos(self.timescale)
+        })
+    }
+
+    /// The frame that is on screen at `timestamp_ns`, i.e. the last frame shown at or before it.
+    ///
+    /// Returns `None` if `timestamp_ns` is before the first frame.
+    pub fn frame_index_at(&self, timestamp_ns: i64) -> Option<usize> {
+        // NOTE: Convert the frame timestamps rather than the query, so that a timestamp that was
+        // read from `Self::frame_timestamps_ns` maps back to the exact same frame.
+        self.presentation_order
+            .partition_point(|&index| {
+                self.samples[index]
+                    .presentation_timestamp
+                    .into_nanos(self.timescale)
+                    <= timestamp_ns
+            })
+            .checked_sub(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn video(presentation_timestamps: &[i64]) -> VideoData {
+        let samples = presentation_timestamps
+            .iter()
+            .enumerate()
+            .map(|(index, &pts)| Sample {
+                is_sync: index == 0,
+                decode_timestamp: Time(index as i64 * 10),
+                presentation_timestamp: Time(pts),
+                duration: Time(10),
+                byte_offset: 0,
+                byte_length: 0,
+            })
+            .collect::<Vec<_>>();
+
+        VideoData::new(
+            Config {
+                codec: "avc1".to_owned(),
+                width: 1,
+                height: 1,
+            },
+            Timescale(30),
+            Time(presentation_timestamps.len() as i64 * 10),
+            samples,
+        )
+    }
+
+    #[test]
+    fn frame_lookup() {
+        // I P B B, in decode order.
+        let video = video(&[0, 30, 10, 20]);
+
+        let timestamps = video.frame_timestamps_ns().collect::<Vec<_>>();
+        assert_eq!(vec![0, 333_333_333, 666_666_666, 1_000_000_000], timestamps);
+
+        assert_eq!(None, video.frame_index_at(-1));
+        for (frame_index, &timestamp_ns) in timestamps.iter().enumerate() {
+            assert_eq!(Some(frame_index), video.frame_index_at(timestamp_ns));
+            assert_eq!(Some(frame_index), video.frame_index_at(timestamp_ns + 1));
+        }
+        assert_eq!(Some(3), video.frame_index_at(i64::MAX));
+
+        assert_eq!(
+            Some(Time(10)),
+            video.frame(1).map(|s| s.presentation_timestamp)
+        );
+        assert_eq!(1_333_333_333, video.duration_ns());
+    }
+}
@@ -0,0 +1,531 @@
+//! Just enough of ISO/IEC 14496-12 (the ISO base media file format, i.e. MP4) to read the frame
+//! table of a video track.
+//!
+//! Edit lists are not applied: instead, presentation timestamps are shifted so that the first
+//! frame is shown at zero, which is what edit lists are used for in practice.
+
+use crate::{Config, Sample, Time, Timescale, VideoLoadError};
+
+type FourCC = [u8; 4];
+
+/// The first video track of an MP4 file.
+pub(crate) struct Track {
+    pub config: Config,
+    pub timescale: Timescale,
+    pub duration: Time,
+    pub samples: Vec<Sample>,
+}
+
+pub(crate) fn read_video_track(data: &[u8]) -> Result<Track, VideoLoadError> {
+    let top_level = boxes(data)?;
+
+    let Some(moov) = find_box(&top_level, b"moov") else {
+        return Err(VideoLoadError::InvalidMp4("missing `moov` box".to_owned()));
+    };
+
+    for (fourcc, trak) in boxes(moov)? {
+        if &fourcc != b"trak" {
+            continue;
+        }
+        if let Some(track) = read_trak(trak, data.len())? {
+            if track.samples.is_empty() && find_box(&top_level, b"moof").is_some() {
+                return Err(VideoLoadError::FragmentedMp4);
+            }
+            return Ok(track);
+        }
+    }
+
+    Err(VideoLoadError::NoVideoTrack)
+}
+
+/// Returns `None` if this isn't a video track.
+///
+/// `file_len` is the size of the whole MP4 file, which all samples need to fit in.
+fn read_trak(trak: &[u8], file_len: usize) -> Result<Option<Track>, VideoLoadError> {
+    let trak = boxes(trak)?;
+    let mdia = boxes(required_box(&trak, b"mdia")?)?;
+
+    let mut hdlr = Reader::new(required_box(&mdia, b"hdlr")?);
+    hdlr.skip(4 + 4)?; // version & flags, pre_defined
+    if &hdlr.fourcc()? != b"vide" {
+        return Ok(None);
+    }
+
+    let mut mdhd = Reader::new(required_box(&mdia, b"mdhd")?);
+    let version = mdhd.u8()?;
+    mdhd.skip(3)?; // flags
+    let (timescale, duration) = if version == 1 {
+        mdhd.skip(8 + 8)?; // creation & modification time
+        (mdhd.u32()?, mdhd.u64()?)
+    } else {
+        mdhd.skip(4 + 4)?; // creation & modification time
+        (mdhd.u32()?, mdhd.u32()? as u64)
+    };
+
+    let minf = boxes(required_box(&mdia, b"minf")?)?;
+    let stbl = boxes(required_box(&minf, b"stbl")?)?;
+
+    // --- Sample description ---
+
+    let mut stsd = Reader::new(required_box(&stbl, b"stsd")?);
+    stsd.skip(4 + 4)?; // version & flags, entry_count
+    let sample_entries = boxes(stsd.rest())?;
+    let Some((codec, sample_entry)) = sample_entries.first() else {
+        return Err(VideoLoadError::InvalidMp4("empty `stsd` box".to_owned()));
+    };
+    let mut sample_entry = Reader::new(sample_entry);
+    // reserved, data_reference_index, pre_defined, reserved, pre_defined
+    sample_entry.skip(6 + 2 + 2 + 2 + 12)?;
+    let width = sample_entry.u16()? as u32;
+    let height = sample_entry.u16()? as u32;
+
+    let config = Config {
+        codec: String::from_utf8_lossy(codec).into_owned(),
+        width,
+        height,
+    };
+
+    // --- Sample table ---
+
+    let sizes = read_stsz(required_box(&stbl, b"stsz")?, file_len)?;
+    let num_samples = sizes.len();
+
+    let decode_timestamps = read_stts(required_box(&stbl, b"stts")?, num_samples)?;
+    let composition_offsets = find_box(&stbl, b"ctts")
+        .map(|ctts| read_ctts(ctts, num_samples))
+        .transpose()?;
+    let sync_samples = find_box(&stbl, b"stss").map(read_stss).transpose()?;
+    let offsets = read_sample_offsets(&stbl, &sizes)?;
+
+    let mut samples = (0..num_samples)
+        .map(|index| {
+            let (decode_timestamp, duration) = decode_timestamps[index];
+            let composition_offset = composition_offsets
+                .as_ref()
+                .map_or(0, |offsets| offsets[index]);
+
+            Sample {
+                // No `stss` box means that every sample is a sync sample.
+                is_sync: sync_samples
+                    .as_ref()
+                    .map_or(true, |sync| sync.binary_search(&(index as u32 + 1)).is_ok()),
+                decode_timestamp: Time(decode_timestamp),
+                presentation_timestamp: Time(decode_timestamp + composition_offset),
+                duration: Time(duration),
+                byte_offset: offsets[index],
+                byte_length: sizes[index],
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(first_presentation_timestamp) = samples
+        .iter()
+        .map(|sample| sample.presentation_timestamp)
+        .min()
+    {
+        for sample in &mut samples {
+            sample.presentation_timestamp.0 -= first_presentation_timestamp.0;
+        }
+    }
+
+    Ok(Some(Track {
+        config,
+        timescale: Timescale(timescale as u64),
+        duration: Time(duration as i64),
+        samples,
+    }))
+}
+
+/// The size of each sample.
+fn read_stsz(stsz: &[u8], file_len: usize) -> Result<Vec<u32>, VideoLoadError> {
+    let mut stsz = Reader::new(stsz);
+    stsz.skip(4)?; // version & flags
+    let sample_size = stsz.u32()?;
+    let sample_count = stsz.u32()? as usize;
+
+    // Don't trust the count to be sane before allocating for it.
+    let max_sample_count = if sample_size != 0 {
+        file_len / sample_size as usize
+    } else {
+        stsz.rest().len() / 4
+    };
+    if sample_count > max_sample_count {
+        return Err(VideoLoadError::InvalidMp4(format!(
+            "`stsz` box has {sample_count} samples, but there is only room for {max_sample_count}"
+        )));
+    }
+
+    if sample_size != 0 {
+        Ok(vec![sample_size; sample_count])
+    } else {
+        (0..sample_count).map(|_| stsz.u32()).collect()
+    }
+}
+
+/// The decode timestamp and duration of each sample.
+fn read_stts(stts: &[u8], num_samples: usize) -> Result<Vec<(i64, i64)>, VideoLoadError> {
+    let mut stts = Reader::new(stts);
+    stts.skip(4)?; // version & flags
+    let entry_count = stts.u32()?;
+
+    let mut timestamps = Vec::with_capacity(num_samples);
+    let mut timestamp = 0;
+    for _ in 0..entry_count {
+        let sample_count = stts.u32()?;
+        let sample_delta = stts.u32()? as i64;
+        // Don't trust the counts to be sane.
+        for _ in 0..(sample_count as usize).min(num_samples - timestamps.len()) {
+            timestamps.push((timestamp, sample_delta));
+            timestamp += sample_delta;
+        }
+    }
+
+    if timestamps.len() < num_samples {
+        return Err(VideoLoadError::InvalidMp4(format!(
+            "`stts` box only covers {} of {num_samples} samples",
+            timestamps.len()
+        )));
+    }
+
+    Ok(timestamps)
+}
+
+/// The offset between the decode and presentation timestamp of each sample.
+fn read_ctts(ctts: &[u8], num_samples: usize) -> Result<Vec<i64>, VideoLoadError> {
+    let mut ctts = Reader::new(ctts);
+    ctts.skip(4)?; // version & flags
+    let entry_count = ctts.u32()?;
+
+    let mut offsets = Vec::with_capacity(num_samples);
+    for _ in 0..entry_count {
+        let sample_count = ctts.u32()?;
+        // Unsigned in version 0, but encoders write negative offsets there too.
+        let sample_offset = ctts.u32()? as i32 as i64;
+        let sample_count = (sample_count as usize).min(num_samples - offsets.len());
+        offsets.extend(std::iter::repeat(sample_offset).take(sample_count));
+    }
+
+    if offsets.len() < num_samples {
+        return Err(VideoLoadError::InvalidMp4(format!(
+            "`ctts` box only covers {} of {num_samples} samples",
+            offsets.len()
+        )));
+    }
+
+    Ok(offsets)
+}
+
+/// The (1-based, sorted) numbers of the sync samples.
+fn read_stss(stss: &[u8]) -> Result<Vec<u32>, VideoLoadError> {
+    let mut stss = Reader::new(stss);
+    stss.skip(4)?; // version & flags
+    let entry_count = stss.u32()?;
+
+    (0..entry_count).map(|_| stss.u32()).collect()
+}
+
+/// The byte offset of each sample, from the chunk offsets and the sample-to-chunk table.
+fn read_sample_offsets(
+    stbl: &[(FourCC, &[u8])],
+    sizes: &[u32],
+) -> Result<Vec<u64>, VideoLoadError> {
+    let chunk_offsets = if let Some(stco) = find_box(stbl, b"stco") {
+        let mut stco = Reader::new(stco);
+        stco.skip(4)?; // version & flags
+        let entry_count = stco.u32()?;
+        (0..entry_count)
+            .map(|_| stco.u32().map(u64::from))
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        let mut co64 = Reader::new(required_box(stbl, b"co64")?);
+        co64.skip(4)?; // version & flags
+        let entry_count = co64.u32()?;
+        (0..entry_count)
+            .map(|_| co64.u64())
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    // (first_chunk, samples_per_chunk), with 1-based chunk numbers.
+    let mut stsc = Reader::new(required_box(stbl, b"stsc")?);
+    stsc.skip(4)?; // version & flags
+    let entry_count = stsc.u32()?;
+    let sample_to_chunk = (0..entry_count)
+        .map(|_| {
+            let first_chunk = stsc.u32()?;
+            let samples_per_chunk = stsc.u32()?;
+            stsc.skip(4)?; // sample_description_index
+            Ok((first_chunk, samples_per_chunk))
+        })
+        .collect::<Result<Vec<_>, VideoLoadError>>()?;
+
+    let mut offsets = Vec::with_capacity(sizes.len());
+    let mut entries = sample_to_chunk.iter().peekable();
+    let mut samples_per_chunk = 0;
+
+    for (chunk_index, &chunk_offset) in chunk_offsets.iter().enumerate() {
+        let chunk_number = chunk_index as u32 + 1;
+        while let Some(&&(first_chunk, num)) = entries.peek() {
+            if chunk_number < first_chunk {
+                break;
+            }
+            samples_per_chunk = num;
+            entries.next();
+        }
+
+        let mut offset = chunk_offset;
+        for _ in 0..samples_per_chunk {
+            let Some(&size) = sizes.get(offsets.len()) else {
+                break;
+            };
+            offsets.push(offset);
+            offset += size as u64;
+        }
+    }
+
+    if offsets.len() < sizes.len() {
+        return Err(VideoLoadError::InvalidMp4(format!(
+            "chunks only cover {} of {} samples",
+            offsets.len(),
+            sizes.len()
+        )));
+    }
+
+    Ok(offsets)
+}
+
+// ---
+
+/// Splits `data` into its boxes, returning their type and payload.
+fn boxes(data: &[u8]) -> Result<Vec<(FourCC, &[u8])>, VideoLoadError> {
+    let mut reader = Reader::new(data);
+    let mut boxes = Vec::new();
+
+    while !reader.rest().is_empty() {
+        let start = reader.pos;
+        let size = reader.u32()? as u64;
+        let fourcc = reader.fourcc()?;
+        let size = match size {
+            0 => (data.len() - start) as u64, // Extends to the end of the file.
+            1 => reader.u64()?,               // 64-bit size.
+            size => size,
+        };
+
+        let header_size = (reader.pos - start) as u64;
+        let Some(payload_size) = size.checked_sub(header_size) else {
+            return Err(VideoLoadError::InvalidMp4(format!(
+                "box {:?} is too small",
+                String::from_utf8_lossy(&fourcc)
+            )));
+        };
+        boxes.push((fourcc, reader.slice(payload_size as usize)?));
+    }
+
+    Ok(boxes)
+}
+
+fn find_box<'a>(boxes: &[(FourCC, &'a [u8])], fourcc: &FourCC) -> Option<&'a [u8]> {
+    boxes
+        .iter()
+        .find(|(box_fourcc, _)| box_fourcc == fourcc)
+        .map(|(_, payload)| *payload)
+}
+
+fn required_box<'a>(
+    boxes: &[(FourCC, &'a [u8])],
+    fourcc: &FourCC,
+) -> Result<&'a [u8], VideoLoadError> {
+    find_box(boxes, fourcc).ok_or_else(|| {
+        VideoLoadError::InvalidMp4(format!("missing `{}` box", String::from_utf8_lossy(fourcc)))
+    })
+}
+
+/// Reads big-endian values.
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.data[self.pos..]
+    }
+
+    fn slice(&mut self, len: usize) -> Result<&'a [u8], VideoLoadError> {
+        let slice = self
+            .data
+            .get(self.pos..self.pos.saturating_add(len))
+            .ok_or_else(|| VideoLoadError::InvalidMp4("unexpected end of data".to_owned()))?;
+        self.pos += len;
+        Ok(slice)
+    }
+
+    fn skip(&mut self, len: usize) -> Result<(), VideoLoadError> {
+        self.slice(len).map(|_| ())
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], VideoLoadError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.slice(N)?);
+        Ok(array)
+    }
+
+    fn fourcc(&mut self) -> Result<FourCC, VideoLoadError> {
+        self.array()
+    }
+
+    fn u8(&mut self) -> Result<u8, VideoLoadError> {
+        self.array().map(u8::from_be_bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16, VideoLoadError> {
+        self.array().map(u16::from_be_bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, VideoLoadError> {
+        self.array().map(u32::from_be_bytes)
+    }
+
+    fn u64(&mut self) -> Result<u64, VideoLoadError> {
+        self.array().map(u64::from_be_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mp4_box(fourcc: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut bytes = (8 + payload.len() as u32).to_be_bytes().to_vec();
+        bytes.extend_from_slice(fourcc);
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    /// A full box: version & flags, then big-endian `u32`s.
+    fn full_box(fourcc: &[u8; 4], values: &[u32]) -> Vec<u8> {
+        let payload = std::iter::once(0)
+            .chain(values.iter().copied())
+            .flat_map(u32::to_be_bytes)
+            .collect::<Vec<_>>();
+        mp4_box(fourcc, &payload)
+    }
+
+    /// I P B B P B B, 25 fps with a timescale of 100, as an encoder with B-frames would write it.
+    fn test_mp4() -> Vec<u8> {
+        let mut avc1 = vec![0; 6 + 2 + 2 + 2 + 12];
+        avc1.extend_from_slice(&640u16.to_be_bytes());
+        avc1.extend_from_slice(&480u16.to_be_bytes());
+        let mut stsd = vec![0, 0, 0, 0, 0, 0, 0, 1];
+        stsd.extend(mp4_box(b"avc1", &avc1));
+
+        let stbl = [
+            mp4_box(b"stsd", &stsd),
+            full_box(b"stts", &[1, 7, 4]),
+            // Presentation order: 0 3 1 2 6 4 5, shifted by one frame.
+            full_box(b"ctts", &[5, 1, 4, 1, 12, 2, 0, 1, 12, 2, 0]),
+            full_box(b"stss", &[1, 1]),
+            full_box(b"stsz", &[0, 7, 100, 10, 10, 10, 20, 10, 10]),
+            // Two chunks: 4 samples, then 3.
+            full_box(b"stsc", &[2, 1, 4, 1, 2, 3, 1]),
+            full_box(b"stco", &[2, 1000, 5000]),
+        ]
+        .concat();
+
+        let mdia = [
+            full_box(b"mdhd", &[0, 0, 100, 28]),
+            full_box(b"hdlr", &[0, u32::from_be_bytes(*b"vide"), 0, 0, 0]),
+            mp4_box(b"minf", &mp4_box(b"stbl", &stbl)),
+        ]
+        .concat();
+
+        let sound_trak = mp4_box(
+            b"trak",
+            &mp4_box(
+                b"mdia",
+                &full_box(b"hdlr", &[0, u32::from_be_bytes(*b"soun"), 0, 0, 0]),
+            ),
+        );
+        let video_trak = mp4_box(b"trak", &mp4_box(b"mdia", &mdia));
+
+        [
+            mp4_box(b"ftyp", b"isom\0\0\0\0"),
+            mp4_box(b"moov", &[sound_trak, video_trak].concat()),
+            mp4_box(b"mdat", &[]),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn read_track() {
+        let track = read_video_track(&test_mp4()).unwrap();
+
+        assert_eq!(
+            Config {
+                codec: "avc1".to_owned(),
+                width: 640,
+                height: 480,
+            },
+            track.config
+        );
+        assert_eq!(Timescale(100), track.timescale);
+        assert_eq!(Time(28), track.duration);
+
+        let samples = track.samples;
+        assert_eq!(7, samples.len());
+
+        assert_eq!(
+            vec![true, false, false, false, false, false, false],
+            samples.iter().map(|s| s.is_sync).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![0, 4, 8, 12, 16, 20, 24],
+            samples
+                .iter()
+                .map(|s| s.decode_timestamp.0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![0, 12, 4, 8, 24, 16, 20],
+            samples
+                .iter()
+                .map(|s| s.presentation_timestamp.0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![1000, 1100, 1110, 1120, 5000, 5020, 5030],
+            samples.iter().map(|s| s.byte_offset).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            read_video_track(&mp4_box(b"ftyp", b"isom\0\0\0\0")),
+            Err(VideoLoadError::InvalidMp4(_))
+        ));
+        assert!(matches!(
+            read_video_track(&mp4_box(b"moov", &[])),
+            Err(VideoLoadError::NoVideoTrack)
+        ));
+
+        let mut truncated = test_mp4();
+        truncated.truncate(truncated.len() / 2);
+        assert!(read_video_track(&truncated).is_err());
+
+        // Sample counts that can't possibly be right.
+        for stsz in [[0, u32::MAX], [1, u32::MAX]] {
+            assert!(matches!(
+                read_stsz(&full_box(b"stsz", &stsz)[8..], 1_000),
+                Err(VideoLoadError::InvalidMp4(_))
+            ));
+        }
+        assert_eq!(
+            vec![10; 100],
+            read_stsz(&full_box(b"stsz", &[10, 100])[8..], 1_000).unwrap()
+        );
+    }
+}
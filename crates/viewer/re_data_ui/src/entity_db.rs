@@ -100,6 +100,22 @@ impl crate::DataUi for EntityDb {
                          size of individual entities.",
                     );
                 ui.end_row();
+
+                let evicted_chunks_stats = self.store().evicted_chunks_stats();
+                if 0 < evicted_chunks_stats.num_chunks {
+                    ui.grid_left_hand_label("On disk");
+                    ui.label(re_format::format_bytes(
+                        evicted_chunks_stats.total_size_bytes as _,
+                    ))
+                    .on_hover_text(format!(
+                        "Data that isn't kept in memory, and is read back from disk as needed: \
+                         either because it is loaded lazily from a large .rrd file, or because \
+                         it didn't fit in the memory limit anymore.\n\
+                         Set {} to change where the latter goes.",
+                        ChunkStoreConfig::ENV_CHUNK_DISK_CACHE_DIR,
+                    ));
+                    ui.end_row();
+                }
             }
 
             {
@@ -108,6 +124,7 @@ impl crate::DataUi for EntityDb {
                     chunk_max_bytes,
                     chunk_max_rows,
                     chunk_max_rows_if_unsorted,
+                    disk_cache_dir: _,
                 } = self.store().config();
 
                 ui.grid_left_hand_label("Compaction");
@@ -697,17 +697,32 @@ fn image_pixel_value_ui(
         }
     });
 
-    let text = match image.color_model {
+    // Images with a pixel format are decoded to RGB by `ImageInfo::get_xyc`.
+    let color_model = if image.pixel_format.is_some() {
+        Some(ColorModel::Rgb)
+    } else {
+        image.color_model
+    };
+
+    // The channels holding red, green, blue and alpha.
+    let [r_channel, g_channel, b_channel, a_channel] =
+        if color_model.map_or(false, ColorModel::is_bgr) {
+            [2, 1, 0, 3]
+        } else {
+            [0, 1, 2, 3]
+        };
+
+    let text = match color_model {
         None => image.get_xyc(x, y, 0).map(|v| format!("Val: {v}")),
 
         Some(ColorModel::L) => image.get_xyc(x, y, 0).map(|v| format!("L: {v}")),
 
-        Some(ColorModel::Rgb) => {
+        Some(ColorModel::Rgb | ColorModel::Bgr) => {
             if let Some([r, g, b]) = {
                 if let [Some(r), Some(g), Some(b)] = [
-                    image.get_xyc(x, y, 0),
-                    image.get_xyc(x, y, 1),
-                    image.get_xyc(x, y, 2),
+                    image.get_xyc(x, y, r_channel),
+                    image.get_xyc(x, y, g_channel),
+                    image.get_xyc(x, y, b_channel),
                 ] {
                     Some([r, g, b])
                 } else {
@@ -725,12 +740,12 @@ fn image_pixel_value_ui(
             }
         }
 
-        Some(ColorModel::Rgba) => {
+        Some(ColorModel::Rgba | ColorModel::Bgra) => {
             if let (Some(r), Some(g), Some(b), Some(a)) = (
-                image.get_xyc(x, y, 0),
-                image.get_xyc(x, y, 1),
-                image.get_xyc(x, y, 2),
-                image.get_xyc(x, y, 3),
+                image.get_xyc(x, y, r_channel),
+                image.get_xyc(x, y, g_channel),
+                image.get_xyc(x, y, b_channel),
+                image.get_xyc(x, y, a_channel),
             ) {
                 match (r, g, b, a) {
                     (
@@ -1022,6 +1037,9 @@ fn tensor_pixel_value_ui(
                 None
             }
         }
+
+        // Tensors are never BGR; images with an explicit color model use `ImageInfo`.
+        Some(ColorModel::Bgr | ColorModel::Bgra) => None,
     };
 
     if let Some(text) = text {
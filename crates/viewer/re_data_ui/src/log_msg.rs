@@ -25,6 +25,9 @@ impl DataUi for LogMsg {
                     "BlueprintActivationCommand({blueprint_id}, make_active: {make_active}, make_default: {make_default})"
                 ));
             }
+            Self::ViewerCommand(store_id, command) => {
+                ui.label(format!("ViewerCommand({store_id}, {command:?})"));
+            }
         }
     }
 }
@@ -20,12 +20,13 @@ use datatype_editors::{
 use re_types::{
     blueprint::components::{
         BackgroundKind, Corner2D, DataframeViewMode, LockRangeDuringZoom, SortKey, SortOrder,
-        ViewFit, Visible,
+        TileDirectory, ViewFit, Visible,
     },
     components::{
         AggregationPolicy, AlbedoFactor, AxisLength, ChannelDataType, Color, ColorModel, Colormap,
-        DepthMeter, DrawOrder, FillRatio, GammaCorrection, ImagePlaneDistance, MagnificationFilter,
-        MarkerSize, Name, Opacity, Scale3D, StrokeWidth, Text, Translation3D,
+        DepthMeter, DistortionModel, DrawOrder, FillRatio, GammaCorrection, GraphType,
+        ImagePlaneDistance, MagnificationFilter, MarkerSize, Name, Opacity, PixelFormat, Scale3D,
+        StrokeWidth, Text, Translation3D, UndistortImages,
     },
     Loggable as _,
 };
@@ -59,6 +60,7 @@ pub fn register_editors(registry: &mut re_viewer_context::ComponentUiRegistry) {
 
     registry.add_singleline_edit_or_view::<Visible>(edit_bool);
     registry.add_singleline_edit_or_view::<LockRangeDuringZoom>(edit_bool);
+    registry.add_singleline_edit_or_view::<UndistortImages>(edit_bool);
 
     registry.add_display_ui(Text::name(), Box::new(display_text_ui));
     registry.add_singleline_edit_or_view::<Text>(edit_singleline_string);
@@ -66,6 +68,7 @@ pub fn register_editors(registry: &mut re_viewer_context::ComponentUiRegistry) {
     registry.add_display_ui(Name::name(), Box::new(display_name_ui));
     registry.add_singleline_edit_or_view::<Name>(edit_singleline_string);
     registry.add_multiline_edit_or_view::<Name>(edit_multiline_string);
+    registry.add_singleline_edit_or_view::<TileDirectory>(edit_singleline_string);
 
     registry
         .add_singleline_edit_or_view(|_ctx, ui, value| edit_view_enum::<BackgroundKind>(ui, value));
@@ -75,6 +78,8 @@ pub fn register_editors(registry: &mut re_viewer_context::ComponentUiRegistry) {
     registry.add_singleline_edit_or_view(|_ctx, ui, value| edit_view_enum::<Colormap>(ui, value));
     registry.add_singleline_edit_or_view(|_ctx, ui, value| edit_view_enum::<ColorModel>(ui, value));
     registry.add_singleline_edit_or_view(|_ctx, ui, value| edit_view_enum::<Corner2D>(ui, value));
+    registry
+        .add_singleline_edit_or_view(|_ctx, ui, value| edit_view_enum::<PixelFormat>(ui, value));
     registry.add_singleline_edit_or_view(|_ctx, ui, value| {
         edit_view_enum::<ChannelDataType>(ui, value)
     });
@@ -84,6 +89,10 @@ pub fn register_editors(registry: &mut re_viewer_context::ComponentUiRegistry) {
     registry.add_singleline_edit_or_view(|_ctx, ui, value| {
         edit_view_enum::<AggregationPolicy>(ui, value)
     });
+    registry.add_singleline_edit_or_view(|_ctx, ui, value| {
+        edit_view_enum::<DistortionModel>(ui, value)
+    });
+    registry.add_singleline_edit_or_view(|_ctx, ui, value| edit_view_enum::<GraphType>(ui, value));
     registry.add_singleline_edit_or_view(|_ctx, ui, value| edit_view_enum::<ViewFit>(ui, value));
     registry.add_singleline_edit_or_view(|_ctx, ui, value| {
         edit_view_enum::<DataframeViewMode>(ui, value)
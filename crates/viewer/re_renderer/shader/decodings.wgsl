@@ -1,8 +1,12 @@
 #import <./types.wgsl>
 
 
-/// Loads an RGBA texel from a texture holding an NV12 or YUY2 encoded image at the given screen space coordinates.
-fn decode_nv12_or_yuy2(sample_type: u32, texture: texture_2d<u32>, coords: vec2i) -> vec4f {
+/// Loads an RGBA texel from a texture holding an NV12, YUY2 or Bayer encoded image at the given screen space coordinates.
+fn decode_encoded_texel(sample_type: u32, bayer_offset: vec2u, texture: texture_2d<u32>, coords: vec2i) -> vec4f {
+    if sample_type == SAMPLE_TYPE_BAYER {
+        return decode_bayer(bayer_offset, texture, coords);
+    }
+
     let texture_dim = vec2f(textureDimensions(texture).xy);
     var y: f32;
     var u: f32;
@@ -41,9 +45,47 @@ fn decode_nv12_or_yuy2(sample_type: u32, texture: texture_2d<u32>, coords: vec2i
 }
 
 
+/// Demosaics a raw Bayer image using bilinear interpolation of the neighboring texels.
+///
+/// The returned values are in the range of the raw texels (e.g. 0-255 for `U8`).
+fn decode_bayer(bayer_offset: vec2u, texture: texture_2d<u32>, coords: vec2i) -> vec4f {
+    let center = bayer_texel(texture, coords, vec2i(0, 0));
+    let horizontal = 0.5 * (bayer_texel(texture, coords, vec2i(-1, 0)) + bayer_texel(texture, coords, vec2i(1, 0)));
+    let vertical = 0.5 * (bayer_texel(texture, coords, vec2i(0, -1)) + bayer_texel(texture, coords, vec2i(0, 1)));
+    let cross = 0.5 * (horizontal + vertical);
+    let diagonal = 0.25 * (
+        bayer_texel(texture, coords, vec2i(-1, -1)) + bayer_texel(texture, coords, vec2i(1, -1)) +
+        bayer_texel(texture, coords, vec2i(-1, 1)) + bayer_texel(texture, coords, vec2i(1, 1))
+    );
+
+    // (0, 0) on red texels, (1, 1) on blue texels, and (1, 0) or (0, 1) on green texels
+    // in the same row or column as red texels respectively.
+    let parity = (vec2u(coords) + bayer_offset) % vec2u(2u);
+
+    var rgb: vec3f;
+    if parity.x == parity.y {
+        rgb = vec3f(center, cross, diagonal);
+    } else {
+        rgb = vec3f(horizontal, center, vertical);
+    }
+    if parity.y == 1u {
+        rgb = rgb.bgr;
+    }
+
+    return vec4f(rgb, 1.0);
+}
+
+/// Loads a single raw Bayer texel, mirroring at the edges so that the color of the texel is preserved.
+fn bayer_texel(texture: texture_2d<u32>, coords: vec2i, offset: vec2i) -> f32 {
+    let max_coords = vec2i(textureDimensions(texture).xy) - vec2i(1);
+    var c = abs(coords + offset);
+    c = select(c, 2 * max_coords - c, max_coords < c);
+    return f32(textureLoad(texture, c, 0).r);
+}
+
 /// Sets the color standard for the given YUV color.
 ///
-/// This conversion mirrors the function in `crates/store/re_types/src/datatypes/tensor_data_ext.rs`
+/// This conversion mirrors the function `rgb_from_yuv` in `crates/store/re_types/src/image.rs`
 ///
 /// Specifying the color standard should be exposed in the future [#3541](https://github.com/rerun-io/rerun/pull/3541)
 fn set_color_standard(yuv: vec3f) -> vec3f {
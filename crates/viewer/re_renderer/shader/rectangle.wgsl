@@ -8,6 +8,7 @@ const SAMPLE_TYPE_SINT  = 2u;
 const SAMPLE_TYPE_UINT  = 3u;
 const SAMPLE_TYPE_NV12  = 4u;
 const SAMPLE_TYPE_YUY2  = 5u;
+const SAMPLE_TYPE_BAYER = 6u;
 
 // How do we do colormapping?
 const COLOR_MAPPER_OFF_GRAYSCALE = 1u;
@@ -59,6 +60,12 @@ struct UniformBuffer {
 
     /// Boolean: multiply RGB with alpha before filtering
     multiply_rgb_with_alpha: u32,
+
+    /// Position of the red texel within each 2x2 block, for Bayer images.
+    bayer_offset: vec2u,
+
+    /// Boolean: swap the red and blue channels of BGR(A) textures.
+    decode_bgr: u32,
 };
 
 @group(1) @binding(0)
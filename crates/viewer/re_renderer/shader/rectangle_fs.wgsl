@@ -26,7 +26,8 @@ fn normalize_range(sampled_value: vec4f) -> vec4f {
 
 fn decode_color(sampled_value: vec4f) -> vec4f {
     // Normalize the value first, otherwise premultiplying alpha and linear space conversion won't make sense.
-    var rgba = normalize_range(sampled_value);
+    // BGR(A) textures get their red and blue channels swapped on the way.
+    var rgba = normalize_range(select(sampled_value, sampled_value.bgra, rect_info.decode_bgr != 0u));
 
     // Convert to linear space
     if rect_info.decode_srgb != 0u {
@@ -81,9 +82,8 @@ fn fs_main(in: VertexOut) -> @location(0) vec4f {
         texture_dimensions = vec2f(textureDimensions(texture_sint).xy);
     } else if rect_info.sample_type == SAMPLE_TYPE_UINT {
         texture_dimensions = vec2f(textureDimensions(texture_uint).xy);
-    } else if rect_info.sample_type == SAMPLE_TYPE_NV12 {
-        texture_dimensions = vec2f(textureDimensions(texture_uint).xy);
-    } else if rect_info.sample_type == SAMPLE_TYPE_YUY2 {
+    } else {
+        // NV12, YUY2 and Bayer images are all stored in the uint texture.
         texture_dimensions = vec2f(textureDimensions(texture_uint).xy);
     }
 
@@ -136,14 +136,14 @@ fn fs_main(in: VertexOut) -> @location(0) vec4f {
             vec4f(textureLoad(texture_uint, v01_coord, 0)),
             vec4f(textureLoad(texture_uint, v10_coord, 0)),
             vec4f(textureLoad(texture_uint, v11_coord, 0)));
-    } else if rect_info.sample_type == SAMPLE_TYPE_NV12 || rect_info.sample_type == SAMPLE_TYPE_YUY2{
+    } else if rect_info.sample_type == SAMPLE_TYPE_NV12 || rect_info.sample_type == SAMPLE_TYPE_YUY2 || rect_info.sample_type == SAMPLE_TYPE_BAYER {
         normalized_value = decode_color_and_filter_nearest_or_bilinear(
             filter_nearest,
             coord,
-            decode_nv12_or_yuy2(rect_info.sample_type, texture_uint, v00_coord),
-            decode_nv12_or_yuy2(rect_info.sample_type, texture_uint, v01_coord),
-            decode_nv12_or_yuy2(rect_info.sample_type, texture_uint, v10_coord),
-            decode_nv12_or_yuy2(rect_info.sample_type, texture_uint, v11_coord));
+            decode_encoded_texel(rect_info.sample_type, rect_info.bayer_offset, texture_uint, v00_coord),
+            decode_encoded_texel(rect_info.sample_type, rect_info.bayer_offset, texture_uint, v01_coord),
+            decode_encoded_texel(rect_info.sample_type, rect_info.bayer_offset, texture_uint, v10_coord),
+            decode_encoded_texel(rect_info.sample_type, rect_info.bayer_offset, texture_uint, v11_coord));
     } else {
         return ERROR_RGBA; // unknown sample type
     }
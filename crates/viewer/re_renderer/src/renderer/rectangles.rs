@@ -51,6 +51,15 @@ pub enum TextureFilterMin {
 pub enum ShaderDecoding {
     Nv12,
     Yuy2,
+
+    /// Raw Bayer mosaic, demosaiced with bilinear interpolation.
+    Bayer {
+        /// Position of the red texel within each 2x2 block of the mosaic.
+        red_offset: [u32; 2],
+    },
+
+    /// The red and blue channels are swapped, i.e. the texture holds BGR(A) data.
+    Bgr,
 }
 
 /// Describes a texture and how to map it to a color.
@@ -239,6 +248,7 @@ mod gpu_data {
     const SAMPLE_TYPE_UINT: u32 = 3;
     const SAMPLE_TYPE_NV12: u32 = 4;
     const SAMPLE_TYPE_YUY2: u32 = 5;
+    const SAMPLE_TYPE_BAYER: u32 = 6;
 
     // How do we do colormapping?
     const COLOR_MAPPER_OFF_GRAYSCALE: u32 = 1;
@@ -275,9 +285,12 @@ mod gpu_data {
 
         decode_srgb: u32,
         multiply_rgb_with_alpha: u32,
-        _row_padding: [u32; 2],
+        bayer_offset: wgpu_buffer_types::UVec2,
 
-        _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 7],
+        decode_bgr: u32,
+        _row_padding: [u32; 3],
+
+        _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 8],
     }
 
     impl UniformBuffer {
@@ -314,18 +327,20 @@ mod gpu_data {
                 outline_mask,
             } = options;
 
+            let bayer_offset = match shader_decoding {
+                Some(super::ShaderDecoding::Bayer { red_offset }) => glam::UVec2::from(*red_offset),
+                _ => glam::UVec2::ZERO,
+            };
+
             let sample_type = match texture_format.sample_type(None, None) {
                 Some(wgpu::TextureSampleType::Float { .. }) => SAMPLE_TYPE_FLOAT,
                 Some(wgpu::TextureSampleType::Sint) => SAMPLE_TYPE_SINT,
-                Some(wgpu::TextureSampleType::Uint) => {
-                    if shader_decoding == &Some(super::ShaderDecoding::Nv12) {
-                        SAMPLE_TYPE_NV12
-                    } else if shader_decoding == &Some(super::ShaderDecoding::Yuy2) {
-                        SAMPLE_TYPE_YUY2
-                    } else {
-                        SAMPLE_TYPE_UINT
-                    }
-                }
+                Some(wgpu::TextureSampleType::Uint) => match shader_decoding {
+                    Some(super::ShaderDecoding::Nv12) => SAMPLE_TYPE_NV12,
+                    Some(super::ShaderDecoding::Yuy2) => SAMPLE_TYPE_YUY2,
+                    Some(super::ShaderDecoding::Bayer { .. }) => SAMPLE_TYPE_BAYER,
+                    Some(super::ShaderDecoding::Bgr) | None => SAMPLE_TYPE_UINT,
+                },
                 _ => {
                     return Err(RectangleError::TextureFormatNotSupported(texture_format));
                 }
@@ -379,6 +394,8 @@ mod gpu_data {
                 magnification_filter,
                 decode_srgb: *decode_srgb as _,
                 multiply_rgb_with_alpha: *multiply_rgb_with_alpha as _,
+                bayer_offset: bayer_offset.into(),
+                decode_bgr: (*shader_decoding == Some(super::ShaderDecoding::Bgr)) as _,
                 _row_padding: Default::default(),
                 _end_padding: Default::default(),
             })
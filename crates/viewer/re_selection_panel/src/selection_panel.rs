@@ -353,7 +353,8 @@ If no rules match, the path is excluded.
 
 The `/**` suffix matches the whole subtree, i.e. self and any child, recursively
 (`/world/**` matches both `/world` and `/world/car/driver`).
-Other uses of `*` are not (yet) supported.
+`*` matches a single part, or any characters within a part (`/robot_*/camera` matches `/robot_1/camera`),
+and `**` in the middle of a path matches any number of parts (`/world/**/wheel` matches `/world/car/wheel`).
 
 `EntityPathFilter` sorts the rule by entity path, with recursive coming before non-recursive.
 This means the last matching rule is also the most specific one.
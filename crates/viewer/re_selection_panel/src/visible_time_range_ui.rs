@@ -6,6 +6,7 @@ use egui::{NumExt as _, Response, Ui};
 use re_entity_db::TimeHistogram;
 use re_log_types::{EntityPath, ResolvedTimeRange, TimeType, TimeZone, TimelineName};
 use re_space_view_dataframe::DataframeSpaceView;
+use re_space_view_map::MapSpaceView;
 use re_space_view_spatial::{SpatialSpaceView2D, SpatialSpaceView3D};
 use re_space_view_time_series::TimeSeriesSpaceView;
 use re_types::{
@@ -26,6 +27,7 @@ static VISIBLE_HISTORY_SUPPORTED_SPACE_VIEWS: once_cell::sync::Lazy<
         SpatialSpaceView2D::identifier(),
         TimeSeriesSpaceView::identifier(),
         DataframeSpaceView::identifier(),
+        MapSpaceView::identifier(),
     ]
     .map(Into::into)
     .into()
@@ -0,0 +1,128 @@
+use ahash::HashMap;
+use re_log_types::{EntityPath, EntityPathHash, Instance};
+use re_types::components::{self, GraphNode, GraphType};
+
+use crate::visualizers::{EdgesVisualizer, NodesVisualizer};
+
+/// Identifies a node within a view.
+///
+/// Node ids are only unique within the entity they were logged to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NodeKey {
+    pub entity_path_hash: EntityPathHash,
+    pub node_id: GraphNode,
+}
+
+pub struct Node {
+    pub key: NodeKey,
+    pub entity_path: EntityPath,
+
+    /// The instance within the logged [`components::GraphNode`] batch.
+    ///
+    /// `None` for nodes that are only referenced by edges.
+    pub instance: Option<Instance>,
+
+    /// Explicit position in scene units, if any.
+    pub position: Option<egui::Pos2>,
+    pub color: Option<egui::Color32>,
+    pub label: Option<components::Text>,
+    pub radius: Option<components::Radius>,
+}
+
+pub struct Edge {
+    /// Index of the source node in [`Graph::nodes`].
+    pub from: usize,
+
+    /// Index of the target node in [`Graph::nodes`].
+    pub to: usize,
+
+    pub directed: bool,
+}
+
+/// All nodes and edges shown in a graph view.
+#[derive(Default)]
+pub struct Graph {
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+    node_indices: HashMap<NodeKey, usize>,
+}
+
+impl Graph {
+    pub fn new(nodes: &NodesVisualizer, edges: &EdgesVisualizer) -> Self {
+        re_tracing::profile_function!();
+
+        let mut graph = Self::default();
+
+        for data in &nodes.data {
+            let entity_path_hash = data.entity_path.hash();
+
+            for node in &data.nodes {
+                let key = NodeKey {
+                    entity_path_hash,
+                    node_id: node.node_id.clone(),
+                };
+
+                // If the same id shows up several times, the first occurrence wins.
+                if graph.node_indices.contains_key(&key) {
+                    continue;
+                }
+
+                graph.node_indices.insert(key.clone(), graph.nodes.len());
+                graph.nodes.push(Node {
+                    key,
+                    entity_path: data.entity_path.clone(),
+                    instance: Some(node.instance),
+                    position: node.position,
+                    color: Some(node.color),
+                    label: node.label.clone(),
+                    radius: node.radius,
+                });
+            }
+        }
+
+        for data in &edges.data {
+            let directed = data.graph_type == GraphType::Directed;
+
+            for edge in &data.edges {
+                let from = graph.node_index_or_insert(&data.entity_path, &edge.0.first);
+                let to = graph.node_index_or_insert(&data.entity_path, &edge.0.second);
+                graph.edges.push(Edge { from, to, directed });
+            }
+        }
+
+        graph
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Looks up a node referenced by an edge, adding it as an implicit node if it wasn't logged.
+    fn node_index_or_insert(
+        &mut self,
+        entity_path: &EntityPath,
+        node_id: &re_types::datatypes::Utf8,
+    ) -> usize {
+        let key = NodeKey {
+            entity_path_hash: entity_path.hash(),
+            node_id: GraphNode(node_id.clone()),
+        };
+
+        if let Some(index) = self.node_indices.get(&key) {
+            return *index;
+        }
+
+        let index = self.nodes.len();
+        self.node_indices.insert(key.clone(), index);
+        self.nodes.push(Node {
+            key,
+            entity_path: entity_path.clone(),
+            instance: None,
+            position: None,
+            color: None,
+            label: None,
+            radius: None,
+        });
+        index
+    }
+}
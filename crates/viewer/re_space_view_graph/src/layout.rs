@@ -0,0 +1,367 @@
+use ahash::HashMap;
+use egui::{Pos2, Vec2};
+use re_log_types::hash::Hash64;
+
+use crate::graph::{Graph, NodeKey};
+
+/// Preferred distance between two connected nodes, in scene units.
+const IDEAL_EDGE_LENGTH: f32 = 60.0;
+
+/// How strongly all free nodes are pulled towards the center of the graph.
+///
+/// Keeps disconnected parts of the graph from drifting apart.
+const GRAVITY: f32 = 0.05;
+
+/// Number of simulation steps run per frame while the layout hasn't settled yet.
+const STEPS_PER_FRAME: usize = 5;
+
+/// Factor by which the temperature decreases with each step.
+const COOLING: f32 = 0.98;
+
+/// Once the temperature drops below this, the layout is considered settled.
+const MIN_TEMPERATURE: f32 = 0.01;
+
+/// Temperature the simulation is reheated to when the graph changes.
+const REHEAT_TEMPERATURE: f32 = 0.5;
+
+/// Force-directed layout (Fruchterman-Reingold) of the nodes of a [`Graph`].
+///
+/// Nodes with an explicit position are pinned in place but still push and pull on all other nodes.
+///
+/// The simulation state is kept across frames: the simulation runs for a few steps each frame
+/// until it settles, and when the graph changes (e.g. when moving the time cursor) it continues
+/// from the previous positions instead of starting over.
+/// Repulsion is computed between all pairs of nodes, so this is meant for graphs with up to a
+/// few thousand nodes.
+#[derive(Default)]
+pub struct ForceLayout {
+    positions: HashMap<NodeKey, Pos2>,
+
+    /// Maximum displacement per step, as a fraction of [`IDEAL_EDGE_LENGTH`].
+    temperature: f32,
+
+    /// Hash of the nodes, edges and pinned positions the layout was computed for.
+    graph_hash: Option<Hash64>,
+}
+
+impl ForceLayout {
+    /// Position of the node in scene units, if it has been laid out.
+    pub fn position(&self, key: &NodeKey) -> Option<Pos2> {
+        self.positions.get(key).copied()
+    }
+
+    /// Advances the simulation by a few steps.
+    ///
+    /// Returns `true` if the layout has not settled yet, i.e. the view should be repainted.
+    pub fn update(&mut self, graph: &Graph) -> bool {
+        re_tracing::profile_function!();
+
+        let graph_hash = structure_hash(graph);
+        if self.graph_hash != Some(graph_hash) {
+            self.temperature = if self.graph_hash.is_none() {
+                1.0
+            } else {
+                self.temperature.max(REHEAT_TEMPERATURE)
+            };
+            self.graph_hash = Some(graph_hash);
+            self.place_new_nodes(graph);
+        }
+
+        if self.temperature < MIN_TEMPERATURE {
+            return false;
+        }
+
+        for _ in 0..STEPS_PER_FRAME {
+            self.step(graph);
+            self.temperature *= COOLING;
+        }
+
+        true
+    }
+
+    /// Forgets nodes that are gone, pins nodes with an explicit position and gives all other
+    /// new nodes a starting position.
+    fn place_new_nodes(&mut self, graph: &Graph) {
+        let mut previous = std::mem::take(&mut self.positions);
+
+        // Pinned nodes go first, so that free nodes can be placed next to them.
+        for node in &graph.nodes {
+            if let Some(position) = node.position {
+                self.positions.insert(node.key.clone(), position);
+            }
+        }
+
+        for (index, node) in graph.nodes.iter().enumerate() {
+            if node.position.is_some() {
+                continue;
+            }
+
+            let position = previous
+                .remove(&node.key)
+                .or_else(|| self.near_neighbors(graph, index))
+                .unwrap_or_else(|| spiral_position(index));
+            self.positions.insert(node.key.clone(), position);
+        }
+    }
+
+    /// Starting position next to the already placed neighbors of a node, if there are any.
+    fn near_neighbors(&self, graph: &Graph, index: usize) -> Option<Pos2> {
+        let mut sum = Vec2::ZERO;
+        let mut count = 0;
+
+        for edge in &graph.edges {
+            let neighbor = if edge.from == index {
+                edge.to
+            } else if edge.to == index {
+                edge.from
+            } else {
+                continue;
+            };
+
+            if let Some(position) = self.positions.get(&graph.nodes[neighbor].key) {
+                sum += position.to_vec2();
+                count += 1;
+            }
+        }
+
+        // Offset the node a bit so that it doesn't end up exactly on top of its neighbors.
+        (count > 0).then(|| (sum / count as f32).to_pos2() + spiral_position(index).to_vec2() * 0.1)
+    }
+
+    fn step(&mut self, graph: &Graph) {
+        let k = IDEAL_EDGE_LENGTH;
+
+        let positions: Vec<Pos2> = graph
+            .nodes
+            .iter()
+            .map(|node| self.positions.get(&node.key).copied().unwrap_or(Pos2::ZERO))
+            .collect();
+        let mut displacements = vec![Vec2::ZERO; positions.len()];
+
+        // Every pair of nodes repels each other.
+        for i in 0..positions.len() {
+            for j in (i + 1)..positions.len() {
+                let mut delta = positions[i] - positions[j];
+                if delta == Vec2::ZERO {
+                    // Nudge nodes that sit on top of each other apart in a deterministic way.
+                    delta = spiral_position(i + j).to_vec2() * 0.01;
+                }
+                let force = delta * (k * k / delta.length_sq().max(1e-3));
+                displacements[i] += force;
+                displacements[j] -= force;
+            }
+        }
+
+        // Connected nodes attract each other.
+        for edge in &graph.edges {
+            if edge.from == edge.to {
+                continue;
+            }
+            let delta = positions[edge.to] - positions[edge.from];
+            let force = delta * (delta.length() / k);
+            displacements[edge.from] += force;
+            displacements[edge.to] -= force;
+        }
+
+        let center = positions
+            .iter()
+            .fold(Vec2::ZERO, |sum, p| sum + p.to_vec2())
+            / positions.len().max(1) as f32;
+
+        let max_displacement = self.temperature * k;
+        for ((node, position), displacement) in
+            graph.nodes.iter().zip(&positions).zip(&displacements)
+        {
+            if node.position.is_some() {
+                continue;
+            }
+
+            let displacement = *displacement - (position.to_vec2() - center) * GRAVITY;
+            let length = displacement.length();
+            if length > 0.0 {
+                let new_position =
+                    *position + displacement * (length.min(max_displacement) / length);
+                self.positions.insert(node.key.clone(), new_position);
+            }
+        }
+    }
+}
+
+/// Deterministic starting position on a sunflower spiral, so that nodes don't overlap initially.
+fn spiral_position(index: usize) -> Pos2 {
+    let golden_angle = std::f32::consts::PI * (3.0 - 5.0_f32.sqrt());
+    let radius = IDEAL_EDGE_LENGTH * (0.5 + index as f32).sqrt();
+    let angle = index as f32 * golden_angle;
+    Pos2::new(radius * angle.cos(), radius * angle.sin())
+}
+
+fn structure_hash(graph: &Graph) -> Hash64 {
+    let nodes: Vec<_> = graph
+        .nodes
+        .iter()
+        .map(|node| {
+            (
+                &node.key,
+                node.position.map(|p| [p.x.to_bits(), p.y.to_bits()]),
+            )
+        })
+        .collect();
+    let edges: Vec<_> = graph
+        .edges
+        .iter()
+        .map(|edge| (edge.from, edge.to))
+        .collect();
+    Hash64::hash((&nodes, &edges))
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::EntityPath;
+
+    use super::*;
+    use crate::graph::{Edge, Node};
+
+    fn node(id: &str, position: Option<Pos2>) -> Node {
+        let entity_path = EntityPath::from("graph");
+        Node {
+            key: NodeKey {
+                entity_path_hash: entity_path.hash(),
+                node_id: id.into(),
+            },
+            entity_path,
+            instance: None,
+            position,
+            color: None,
+            label: None,
+            radius: None,
+        }
+    }
+
+    fn graph(nodes: Vec<Node>, edges: &[(usize, usize)]) -> Graph {
+        let mut graph = Graph::default();
+        graph.nodes = nodes;
+        graph.edges = edges
+            .iter()
+            .map(|&(from, to)| Edge {
+                from,
+                to,
+                directed: false,
+            })
+            .collect();
+        graph
+    }
+
+    /// Runs the simulation until it settles, returning the number of frames that took.
+    fn settle(layout: &mut ForceLayout, graph: &Graph) -> usize {
+        let mut frames = 1;
+        while layout.update(graph) {
+            frames += 1;
+            assert!(frames < 1_000, "the layout never settles");
+        }
+        frames
+    }
+
+    fn star() -> Graph {
+        graph(
+            vec![
+                node("center", None),
+                node("a", None),
+                node("b", None),
+                node("c", None),
+                node("d", None),
+            ],
+            &[(0, 1), (0, 2), (0, 3), (0, 4)],
+        )
+    }
+
+    #[test]
+    fn settles() {
+        let graph = star();
+        let mut layout = ForceLayout::default();
+
+        // Starting from a temperature of 1, it takes this many steps to cool down.
+        // One more frame to report that it is done, and one for rounding errors.
+        let steps = (MIN_TEMPERATURE.ln() / COOLING.ln()).ceil() as usize;
+        let frames = settle(&mut layout, &graph);
+        assert!(frames <= steps.div_ceil(STEPS_PER_FRAME) + 2);
+        assert!(layout.temperature < MIN_TEMPERATURE);
+
+        // Once settled, nothing moves anymore.
+        let positions = layout.positions.clone();
+        assert!(!layout.update(&graph));
+        assert_eq!(layout.positions, positions);
+
+        // All nodes are laid out, without any of them on top of each other.
+        for (i, a) in graph.nodes.iter().enumerate() {
+            let a = layout.position(&a.key).unwrap();
+            assert!(a.x.is_finite() && a.y.is_finite());
+            for b in &graph.nodes[i + 1..] {
+                let b = layout.position(&b.key).unwrap();
+                assert!(a.distance(b) > IDEAL_EDGE_LENGTH * 0.1);
+            }
+        }
+    }
+
+    #[test]
+    fn pinned_nodes_never_move() {
+        let pinned = Pos2::new(100.0, -50.0);
+        let graph = graph(
+            vec![
+                node("pinned", Some(pinned)),
+                node("a", None),
+                node("b", None),
+                node("c", None),
+            ],
+            &[(0, 1), (1, 2), (2, 3), (3, 0)],
+        );
+        let pinned_key = &graph.nodes[0].key;
+
+        let mut layout = ForceLayout::default();
+        while layout.update(&graph) {
+            assert_eq!(layout.position(pinned_key), Some(pinned));
+        }
+        assert_eq!(layout.position(pinned_key), Some(pinned));
+
+        // The free nodes still end up around the pinned node they are connected to.
+        for node in &graph.nodes[1..] {
+            let position = layout.position(&node.key).unwrap();
+            assert!(position.distance(pinned) < IDEAL_EDGE_LENGTH * 5.0);
+        }
+    }
+
+    #[test]
+    fn positions_survive_graph_change() {
+        let mut layout = ForceLayout::default();
+        settle(&mut layout, &star());
+        let settled = layout.positions.clone();
+
+        // Drop "d" and attach a new node "e" to "a".
+        let changed = graph(
+            vec![
+                node("center", None),
+                node("a", None),
+                node("b", None),
+                node("c", None),
+                node("e", None),
+            ],
+            &[(0, 1), (0, 2), (0, 3), (1, 4)],
+        );
+        assert!(layout.update(&changed));
+        assert!(layout.temperature <= REHEAT_TEMPERATURE);
+
+        // The nodes that are still there continue from where they were, instead of starting over.
+        let max_move = STEPS_PER_FRAME as f32 * REHEAT_TEMPERATURE * IDEAL_EDGE_LENGTH;
+        for node in &changed.nodes[..4] {
+            let before = settled[&node.key];
+            let after = layout.position(&node.key).unwrap();
+            assert!(before.distance(after) <= max_move + 1e-3);
+        }
+
+        // The removed node is forgotten, and the new one ends up next to its neighbor.
+        assert_eq!(layout.positions.len(), changed.nodes.len());
+        settle(&mut layout, &changed);
+        let a = layout.position(&changed.nodes[1].key).unwrap();
+        let e = layout.position(&changed.nodes[4].key).unwrap();
+        assert!(a.distance(e) < IDEAL_EDGE_LENGTH * 3.0);
+    }
+}
@@ -0,0 +1,11 @@
+//! Rerun graph Space View.
+//!
+//! A Space View that shows a graph (node-link diagram).
+//! Nodes without an explicit position are placed by a force-directed layout.
+
+mod graph;
+mod layout;
+mod space_view_class;
+mod visualizers;
+
+pub use space_view_class::GraphSpaceView;
@@ -0,0 +1,302 @@
+use egui::emath::TSTransform;
+use re_entity_db::InstancePath;
+use re_log_types::Instance;
+use re_space_view::{controls::ZOOM_SCROLL_MODIFIER, suggest_space_view_for_each_entity};
+use re_types::{SpaceViewClassIdentifier, View as _};
+use re_ui::{ContextExt as _, ModifiersMarkdown};
+use re_viewer_context::{
+    HoverHighlight, Item, SelectionHighlight, SpaceViewClass, SpaceViewClassRegistryError,
+    SpaceViewState, SpaceViewStateExt as _, SpaceViewSystemExecutionError, ViewQuery,
+    ViewerContext,
+};
+
+use crate::{
+    graph::{Graph, Node},
+    layout::ForceLayout,
+    visualizers::{EdgesVisualizer, NodesVisualizer},
+};
+
+/// Radius of nodes that don't specify one, in ui points.
+const DEFAULT_NODE_RADIUS: f32 = 4.0;
+
+/// Margin around the graph when fitting it into the view, in ui points.
+const FIT_MARGIN: f32 = 32.0;
+
+#[derive(Default)]
+pub struct GraphSpaceViewState {
+    layout: ForceLayout,
+
+    /// Transform from scene units to ui points.
+    ///
+    /// `None` until the user pans or zooms; until then the whole graph is kept in view.
+    ui_from_scene: Option<TSTransform>,
+}
+
+impl SpaceViewState for GraphSpaceViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct GraphSpaceView;
+
+type ViewType = re_types::blueprint::views::GraphView;
+
+impl SpaceViewClass for GraphSpaceView {
+    fn identifier() -> SpaceViewClassIdentifier {
+        ViewType::identifier()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Graph"
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &re_ui::icons::SPACE_VIEW_GENERIC
+    }
+
+    fn new_state(&self) -> Box<dyn SpaceViewState> {
+        Box::<GraphSpaceViewState>::default()
+    }
+
+    fn help_markdown(&self, egui_ctx: &egui::Context) -> String {
+        format!(
+            "# Graph view
+
+Display a graph of nodes and edges.
+Nodes without a position are laid out automatically by a force-directed simulation.
+
+## Navigation controls
+
+- Pan by dragging, or scroll.
+- Zoom with pinch gesture or scroll + {zoom_scroll_modifier}.
+- Double-click on empty space to reset the view.
+- Click on a node to select it.",
+            zoom_scroll_modifier = ModifiersMarkdown(ZOOM_SCROLL_MODIFIER, egui_ctx),
+        )
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::SpaceViewSystemRegistrator<'_>,
+    ) -> Result<(), SpaceViewClassRegistryError> {
+        system_registry.register_visualizer::<NodesVisualizer>()?;
+        system_registry.register_visualizer::<EdgesVisualizer>()
+    }
+
+    fn preferred_tile_aspect_ratio(&self, _state: &dyn SpaceViewState) -> Option<f32> {
+        None
+    }
+
+    fn spawn_heuristics(
+        &self,
+        ctx: &ViewerContext<'_>,
+    ) -> re_viewer_context::SpaceViewSpawnHeuristics {
+        re_tracing::profile_function!();
+        suggest_space_view_for_each_entity::<NodesVisualizer>(ctx, self)
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::SpaceViewClassLayoutPriority {
+        re_viewer_context::SpaceViewClassLayoutPriority::Medium
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn SpaceViewState,
+
+        query: &ViewQuery<'_>,
+        system_output: re_viewer_context::SystemExecutionOutput,
+    ) -> Result<(), SpaceViewSystemExecutionError> {
+        let state = state.downcast_mut::<GraphSpaceViewState>()?;
+
+        let graph = Graph::new(
+            system_output.view_systems.get::<NodesVisualizer>()?,
+            system_output.view_systems.get::<EdgesVisualizer>()?,
+        );
+
+        if state.layout.update(&graph) {
+            ui.ctx().request_repaint();
+        }
+
+        let (rect, response) =
+            ui.allocate_exact_size(ui.available_size(), egui::Sense::click_and_drag());
+
+        let mut ui_from_scene = state
+            .ui_from_scene
+            .unwrap_or_else(|| fit_to_rect(&graph, &state.layout, rect));
+
+        if response.dragged() {
+            ui_from_scene = TSTransform::from_translation(response.drag_delta()) * ui_from_scene;
+            state.ui_from_scene = Some(ui_from_scene);
+        }
+
+        if let Some(pointer) = response.hover_pos() {
+            let (zoom, scroll) = ui.input(|i| (i.zoom_delta(), i.smooth_scroll_delta));
+            if zoom != 1.0 || scroll != egui::Vec2::ZERO {
+                ui_from_scene = TSTransform::from_translation(pointer.to_vec2() + scroll)
+                    * TSTransform::from_scaling(zoom)
+                    * TSTransform::from_translation(-pointer.to_vec2())
+                    * ui_from_scene;
+                state.ui_from_scene = Some(ui_from_scene);
+            }
+        }
+
+        let node_shapes: Vec<(egui::Pos2, f32)> = graph
+            .nodes
+            .iter()
+            .map(|node| {
+                let position = state.layout.position(&node.key).unwrap_or_default();
+                (ui_from_scene * position, node_radius(node, ui_from_scene))
+            })
+            .collect();
+
+        // The node closest to the pointer, if the pointer is on top of one.
+        let hovered_node = response.hover_pos().and_then(|pointer| {
+            node_shapes
+                .iter()
+                .enumerate()
+                .map(|(index, (center, radius))| (index, center.distance(pointer) - radius))
+                .filter(|(_, distance)| *distance <= 2.0)
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(index, _)| index)
+        });
+
+        if response.double_clicked() && hovered_node.is_none() {
+            state.ui_from_scene = None;
+        }
+
+        let painter = ui.painter_at(rect);
+
+        let edge_stroke = egui::Stroke::new(1.0, ui.visuals().widgets.inactive.fg_stroke.color);
+        for edge in &graph.edges {
+            let (from, from_radius) = node_shapes[edge.from];
+            let (to, to_radius) = node_shapes[edge.to];
+
+            if edge.from == edge.to {
+                let loop_radius = from_radius.max(DEFAULT_NODE_RADIUS) * 1.5;
+                painter.circle_stroke(
+                    from - egui::vec2(0.0, loop_radius),
+                    loop_radius,
+                    edge_stroke,
+                );
+                continue;
+            }
+
+            let direction = (to - from).normalized();
+            let start = from + direction * from_radius;
+            let end = to - direction * to_radius;
+            painter.line_segment([start, end], edge_stroke);
+
+            if edge.directed {
+                let arrow_size = 8.0;
+                let normal = direction.rot90() * arrow_size * 0.5;
+                let base = end - direction * arrow_size;
+                painter.add(egui::Shape::convex_polygon(
+                    vec![end, base + normal, base - normal],
+                    edge_stroke.color,
+                    egui::Stroke::NONE,
+                ));
+            }
+        }
+
+        for (index, (node, (center, radius))) in graph.nodes.iter().zip(&node_shapes).enumerate() {
+            let highlight = query
+                .highlights
+                .entity_highlight(node.entity_path.hash())
+                .index_highlight(node.instance.unwrap_or(Instance::ALL));
+
+            let stroke =
+                if highlight.hover == HoverHighlight::Hovered || hovered_node == Some(index) {
+                    ui.ctx().hover_stroke()
+                } else if highlight.selection != SelectionHighlight::None {
+                    ui.ctx().selection_stroke()
+                } else {
+                    egui::Stroke::NONE
+                };
+
+            let fill = node
+                .color
+                .unwrap_or(ui.visuals().widgets.inactive.fg_stroke.color);
+            painter.circle(*center, *radius, fill, stroke);
+
+            // Nodes without a label show their id instead.
+            // Nodes that are only referenced by edges show it only when hovered.
+            let text = match &node.label {
+                Some(label) => Some(label.as_str()),
+                None if node.instance.is_some() || hovered_node == Some(index) => {
+                    Some(node.key.node_id.as_str())
+                }
+                None => None,
+            };
+            if let Some(text) = text {
+                painter.text(
+                    *center + egui::vec2(0.0, radius + 2.0),
+                    egui::Align2::CENTER_TOP,
+                    text,
+                    egui::TextStyle::Small.resolve(ui.style()),
+                    ui.visuals().text_color(),
+                );
+            }
+        }
+
+        if let Some(index) = hovered_node {
+            let node = &graph.nodes[index];
+            let instance_path = match node.instance {
+                Some(instance) => InstancePath::instance(node.entity_path.clone(), instance),
+                None => InstancePath::entity_all(node.entity_path.clone()),
+            };
+            ctx.select_hovered_on_click(
+                &response,
+                Item::DataResult(query.space_view_id, instance_path),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Radius of a node, in ui points.
+fn node_radius(node: &Node, ui_from_scene: TSTransform) -> f32 {
+    let Some(radius) = node.radius else {
+        return DEFAULT_NODE_RADIUS;
+    };
+
+    if let Some(scene_units) = radius.scene_units() {
+        scene_units * ui_from_scene.scaling
+    } else {
+        radius.ui_points().unwrap_or(DEFAULT_NODE_RADIUS)
+    }
+}
+
+/// Transform that fits all nodes of the graph into the given rectangle.
+fn fit_to_rect(graph: &Graph, layout: &ForceLayout, rect: egui::Rect) -> TSTransform {
+    let bounds = egui::Rect::from_points(
+        &graph
+            .nodes
+            .iter()
+            .filter_map(|node| layout.position(&node.key))
+            .collect::<Vec<_>>(),
+    );
+    if graph.is_empty() || !bounds.is_finite() {
+        return TSTransform::from_translation(rect.center().to_vec2());
+    }
+
+    let available = rect.shrink(FIT_MARGIN);
+    let scaling = (available.width() / bounds.width()).min(available.height() / bounds.height());
+    let scaling = if scaling.is_finite() && scaling > 0.0 {
+        scaling
+    } else {
+        1.0
+    };
+
+    TSTransform::from_translation(rect.center().to_vec2())
+        * TSTransform::from_scaling(scaling)
+        * TSTransform::from_translation(-bounds.center().to_vec2())
+}
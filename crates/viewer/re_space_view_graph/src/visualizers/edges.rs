@@ -0,0 +1,79 @@
+use re_chunk_store::LatestAtQuery;
+use re_log_types::EntityPath;
+use re_space_view::DataResultQuery as _;
+use re_types::{archetypes::GraphEdges, components};
+use re_viewer_context::{
+    IdentifiedViewSystem, QueryContext, SpaceViewSystemExecutionError,
+    TypedComponentFallbackProvider, ViewContext, ViewContextCollection, ViewQuery,
+    VisualizerQueryInfo, VisualizerSystem,
+};
+
+use super::component_slice;
+
+/// All edges logged to a single entity.
+pub struct EdgeData {
+    pub entity_path: EntityPath,
+    pub graph_type: components::GraphType,
+    pub edges: Vec<components::GraphEdge>,
+}
+
+/// Collects the edges of all graphs in the view.
+#[derive(Default)]
+pub struct EdgesVisualizer {
+    pub data: Vec<EdgeData>,
+}
+
+impl IdentifiedViewSystem for EdgesVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "GraphEdges".into()
+    }
+}
+
+impl VisualizerSystem for EdgesVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<GraphEdges>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, SpaceViewSystemExecutionError> {
+        let timeline_query = LatestAtQuery::new(view_query.timeline, view_query.latest_at);
+
+        for data_result in view_query.iter_visible_data_results(ctx, Self::identifier()) {
+            let results = data_result
+                .latest_at_with_blueprint_resolved_data::<GraphEdges>(ctx, &timeline_query);
+
+            let edges = component_slice::<components::GraphEdge>(&results);
+            if edges.is_empty() {
+                continue;
+            }
+
+            self.data.push(EdgeData {
+                entity_path: data_result.entity_path.clone(),
+                graph_type: results.get_mono_with_fallback(),
+                edges: edges.to_vec(),
+            });
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl TypedComponentFallbackProvider<components::GraphType> for EdgesVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> components::GraphType {
+        components::GraphType::Undirected
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(EdgesVisualizer => [components::GraphType]);
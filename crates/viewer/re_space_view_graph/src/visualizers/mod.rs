@@ -0,0 +1,27 @@
+mod edges;
+mod nodes;
+
+pub use edges::EdgesVisualizer;
+pub use nodes::NodesVisualizer;
+
+use re_space_view::HybridLatestAtResults;
+use re_types::Component;
+
+/// Returns all instances of the given component, or an empty slice if there are none.
+fn component_slice<'a, C: Component>(results: &'a HybridLatestAtResults<'_>) -> &'a [C] {
+    results
+        .get(C::name())
+        .and_then(|component_results| {
+            component_results
+                .to_dense::<C>(&results.resolver)
+                .flatten()
+                .ok()
+        })
+        .unwrap_or_default()
+}
+
+/// Picks the component instance for the given index, repeating the last one if there are fewer
+/// instances than indices.
+fn clamped<C>(values: &[C], index: usize) -> Option<&C> {
+    values.get(index).or_else(|| values.last())
+}
@@ -0,0 +1,110 @@
+use re_chunk_store::LatestAtQuery;
+use re_log_types::{EntityPath, Instance};
+use re_space_view::DataResultQuery as _;
+use re_types::{archetypes::GraphNodes, components};
+use re_viewer_context::{
+    auto_color_for_entity_path, IdentifiedViewSystem, QueryContext, SpaceViewSystemExecutionError,
+    TypedComponentFallbackProvider, ViewContext, ViewContextCollection, ViewQuery,
+    VisualizerQueryInfo, VisualizerSystem,
+};
+
+use super::{clamped, component_slice};
+
+/// A single node of a graph, as logged via [`GraphNodes`].
+pub struct NodeInstance {
+    pub node_id: components::GraphNode,
+    pub instance: Instance,
+
+    /// Explicit position in scene units, if any.
+    pub position: Option<egui::Pos2>,
+    pub color: egui::Color32,
+    pub label: Option<components::Text>,
+    pub radius: Option<components::Radius>,
+}
+
+/// All nodes logged to a single entity.
+pub struct NodeData {
+    pub entity_path: EntityPath,
+    pub nodes: Vec<NodeInstance>,
+}
+
+/// Collects the nodes of all graphs in the view.
+#[derive(Default)]
+pub struct NodesVisualizer {
+    pub data: Vec<NodeData>,
+}
+
+impl IdentifiedViewSystem for NodesVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "GraphNodes".into()
+    }
+}
+
+impl VisualizerSystem for NodesVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<GraphNodes>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, SpaceViewSystemExecutionError> {
+        let timeline_query = LatestAtQuery::new(view_query.timeline, view_query.latest_at);
+
+        for data_result in view_query.iter_visible_data_results(ctx, Self::identifier()) {
+            let results = data_result
+                .latest_at_with_blueprint_resolved_data::<GraphNodes>(ctx, &timeline_query);
+
+            let node_ids = component_slice::<components::GraphNode>(&results);
+            if node_ids.is_empty() {
+                continue;
+            }
+
+            let positions = component_slice::<components::Position2D>(&results);
+            let colors = component_slice::<components::Color>(&results);
+            let labels = component_slice::<components::Text>(&results);
+            let radii = component_slice::<components::Radius>(&results);
+
+            let fallback_color: components::Color = results.get_mono_with_fallback();
+
+            let nodes = node_ids
+                .iter()
+                .enumerate()
+                .map(|(index, node_id)| NodeInstance {
+                    node_id: node_id.clone(),
+                    instance: Instance::from(index as u64),
+                    // Positions are not clamped: every node without one gets laid out automatically.
+                    position: positions.get(index).map(|p| egui::pos2(p.x(), p.y())),
+                    color: clamped(colors, index).unwrap_or(&fallback_color).0.into(),
+                    label: labels.get(index).cloned(),
+                    radius: clamped(radii, index).copied(),
+                })
+                .collect();
+
+            self.data.push(NodeData {
+                entity_path: data_result.entity_path.clone(),
+                nodes,
+            });
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl TypedComponentFallbackProvider<components::Color> for NodesVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> components::Color {
+        auto_color_for_entity_path(ctx.target_entity_path)
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(NodesVisualizer => [components::Color]);
@@ -0,0 +1,11 @@
+//! Rerun map Space View.
+//!
+//! A Space View that shows geospatial data on a map.
+//! The map background is made of tiles read from a local directory, or a plain graticule if there are none.
+
+mod projection;
+mod space_view_class;
+mod tiles;
+mod visualizers;
+
+pub use space_view_class::MapSpaceView;
@@ -0,0 +1,62 @@
+//! Web Mercator projection, as used by "slippy map" tiles.
+//!
+//! Positions are projected to normalized world coordinates, where the whole world covers the
+//! unit square with `(0, 0)` in the north-west corner and `(1, 1)` in the south-east corner.
+
+use glam::DVec2;
+use re_types::components::LatLon;
+
+/// Latitudes beyond this are cut off by the Web Mercator projection, in degrees.
+pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Circumference of the earth at the equator, in meters.
+const EARTH_CIRCUMFERENCE: f64 = 40_075_016.686;
+
+/// Projects a position to normalized world coordinates.
+pub fn world_from_lat_lon(lat_lon: &LatLon) -> DVec2 {
+    let latitude = lat_lon.latitude().clamp(-MAX_LATITUDE, MAX_LATITUDE);
+    let x = (lat_lon.longitude() + 180.0) / 360.0;
+    let y = (1.0 - latitude.to_radians().tan().asinh() / std::f64::consts::PI) / 2.0;
+    DVec2::new(x, y)
+}
+
+/// Inverse of [`world_from_lat_lon`].
+pub fn lat_lon_from_world(world: DVec2) -> LatLon {
+    let longitude = world.x * 360.0 - 180.0;
+    let latitude = (std::f64::consts::PI * (1.0 - 2.0 * world.y))
+        .sinh()
+        .atan()
+        .to_degrees();
+    LatLon::new(latitude, longitude)
+}
+
+/// How many meters one normalized world unit covers at the given latitude.
+pub fn meters_per_world_unit(latitude: f64) -> f64 {
+    EARTH_CIRCUMFERENCE * latitude.to_radians().cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for (lat, lon) in [(0.0, 0.0), (59.319221, 18.075631), (-33.856784, 151.215297)] {
+            let lat_lon = lat_lon_from_world(world_from_lat_lon(&LatLon::new(lat, lon)));
+            assert!((lat_lon.latitude() - lat).abs() < 1e-9);
+            assert!((lat_lon.longitude() - lon).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn corners() {
+        let north_west = world_from_lat_lon(&LatLon::new(MAX_LATITUDE, -180.0));
+        assert!(north_west.abs_diff_eq(DVec2::ZERO, 1e-9));
+
+        let south_east = world_from_lat_lon(&LatLon::new(-90.0, 180.0));
+        assert!(south_east.abs_diff_eq(DVec2::ONE, 1e-9));
+
+        let center = world_from_lat_lon(&LatLon::new(0.0, 0.0));
+        assert!(center.abs_diff_eq(DVec2::splat(0.5), 1e-9));
+    }
+}
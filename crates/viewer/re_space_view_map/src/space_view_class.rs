@@ -0,0 +1,527 @@
+use std::path::Path;
+
+use glam::DVec2;
+use re_entity_db::InstancePath;
+use re_log_types::{EntityPath, Instance};
+use re_space_view::{controls::ZOOM_SCROLL_MODIFIER, view_property_ui};
+use re_types::{
+    blueprint::{archetypes::MapOptions, components::TileDirectory},
+    components::{LatLon, Radius},
+    SpaceViewClassIdentifier, View as _,
+};
+use re_ui::{list_item, ContextExt as _, ModifiersMarkdown};
+use re_viewer_context::{
+    HoverHighlight, IdentifiedViewSystem as _, Item, SelectionHighlight, SpaceViewClass,
+    SpaceViewClassRegistryError, SpaceViewId, SpaceViewSpawnHeuristics, SpaceViewState,
+    SpaceViewStateExt as _, SpaceViewSystemExecutionError, ViewQuery, ViewerContext,
+};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::{
+    projection::{lat_lon_from_world, meters_per_world_unit, world_from_lat_lon, MAX_LATITUDE},
+    tiles::{TileCache, TileId, MAX_TILE_ZOOM},
+    visualizers::{GeoLineStringsVisualizer, GeoPointsVisualizer},
+};
+
+/// Size of a map tile, in ui points.
+const TILE_SIZE: f64 = 256.0;
+
+/// Highest zoom level the user can zoom in to.
+const MAX_ZOOM: f64 = 22.0;
+
+/// Zoom level used when fitting a single point into the view.
+const SINGLE_POSITION_ZOOM: f64 = 16.0;
+
+/// Margin around the data when fitting it into the view, in ui points.
+const FIT_MARGIN: f32 = 32.0;
+
+/// Radius of points that don't specify one, in ui points.
+const DEFAULT_POINT_RADIUS: f32 = 5.0;
+
+/// Where the map is looking at.
+#[derive(Clone, Copy, Debug)]
+struct MapCamera {
+    /// Center of the view, in normalized world coordinates.
+    center: DVec2,
+
+    /// Zoom level, in the same sense as tile zoom levels: the whole world is `256 * 2^zoom` ui points wide.
+    zoom: f64,
+}
+
+impl MapCamera {
+    /// Size of one normalized world unit, in ui points.
+    fn ui_per_world(&self) -> f64 {
+        TILE_SIZE * self.zoom.exp2()
+    }
+
+    fn ui_from_world(&self, rect: egui::Rect, world: DVec2) -> egui::Pos2 {
+        let offset = (world - self.center) * self.ui_per_world();
+        rect.center() + egui::vec2(offset.x as f32, offset.y as f32)
+    }
+
+    fn world_from_ui(&self, rect: egui::Rect, pos: egui::Pos2) -> DVec2 {
+        let offset = pos - rect.center();
+        self.center + DVec2::new(offset.x as f64, offset.y as f64) / self.ui_per_world()
+    }
+
+    /// Zooms by the given factor, keeping the world position under `anchor` in place.
+    fn zoom_around(&mut self, rect: egui::Rect, anchor: egui::Pos2, factor: f32) {
+        let world_at_anchor = self.world_from_ui(rect, anchor);
+        self.zoom = (self.zoom + (factor as f64).log2()).clamp(0.0, MAX_ZOOM);
+        let offset = anchor - rect.center();
+        self.center =
+            world_at_anchor - DVec2::new(offset.x as f64, offset.y as f64) / self.ui_per_world();
+    }
+}
+
+#[derive(Default)]
+pub struct MapSpaceViewState {
+    tiles: TileCache,
+
+    /// `None` until the user pans or zooms; until then all data is kept in view.
+    camera: Option<MapCamera>,
+}
+
+impl SpaceViewState for MapSpaceViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct MapSpaceView;
+
+type ViewType = re_types::blueprint::views::MapView;
+
+impl SpaceViewClass for MapSpaceView {
+    fn identifier() -> SpaceViewClassIdentifier {
+        ViewType::identifier()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Map"
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &re_ui::icons::SPACE_VIEW_GENERIC
+    }
+
+    fn new_state(&self) -> Box<dyn SpaceViewState> {
+        Box::<MapSpaceViewState>::default()
+    }
+
+    fn help_markdown(&self, egui_ctx: &egui::Context) -> String {
+        format!(
+            "# Map view
+
+Display geospatial points and line strings on a map.
+
+The map is made of tiles read from the local tile directory configured in the view properties.
+Without a tile directory, a graticule of latitude and longitude lines is shown instead.
+
+Points logged at the current time are highlighted, and older points in the visible time range are dimmed.
+
+## Navigation controls
+
+- Pan by dragging, or scroll.
+- Zoom with pinch gesture or scroll + {zoom_scroll_modifier}.
+- Double-click to reset the view.",
+            zoom_scroll_modifier = ModifiersMarkdown(ZOOM_SCROLL_MODIFIER, egui_ctx),
+        )
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::SpaceViewSystemRegistrator<'_>,
+    ) -> Result<(), SpaceViewClassRegistryError> {
+        system_registry.register_visualizer::<GeoPointsVisualizer>()?;
+        system_registry.register_visualizer::<GeoLineStringsVisualizer>()
+    }
+
+    fn preferred_tile_aspect_ratio(&self, _state: &dyn SpaceViewState) -> Option<f32> {
+        None
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::SpaceViewClassLayoutPriority {
+        re_viewer_context::SpaceViewClassLayoutPriority::Medium
+    }
+
+    fn spawn_heuristics(&self, ctx: &ViewerContext<'_>) -> SpaceViewSpawnHeuristics {
+        re_tracing::profile_function!();
+
+        // Spawn a single map view at the root if there's any geospatial data around anywhere.
+        let has_geo_data = [
+            GeoPointsVisualizer::identifier(),
+            GeoLineStringsVisualizer::identifier(),
+        ]
+        .iter()
+        .any(|visualizer| {
+            ctx.indicated_entities_per_visualizer
+                .get(visualizer)
+                .map_or(false, |entities| !entities.is_empty())
+        });
+
+        if has_geo_data {
+            SpaceViewSpawnHeuristics::root()
+        } else {
+            SpaceViewSpawnHeuristics::default()
+        }
+    }
+
+    fn selection_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn SpaceViewState,
+        _space_origin: &EntityPath,
+        space_view_id: SpaceViewId,
+    ) -> Result<(), SpaceViewSystemExecutionError> {
+        list_item::list_item_scope(ui, "map_selection_ui", |ui| {
+            view_property_ui::<MapOptions>(ctx, ui, space_view_id, self, state);
+        });
+
+        Ok(())
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn SpaceViewState,
+
+        query: &ViewQuery<'_>,
+        system_output: re_viewer_context::SystemExecutionOutput,
+    ) -> Result<(), SpaceViewSystemExecutionError> {
+        let state = state.downcast_mut::<MapSpaceViewState>()?;
+
+        let map_options = ViewProperty::from_archetype::<MapOptions>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            query.space_view_id,
+        );
+        let tile_directory =
+            map_options.component_or_fallback::<TileDirectory>(ctx, self, state)?;
+        state
+            .tiles
+            .begin_frame(ui.ctx(), Path::new(tile_directory.as_str().trim()));
+
+        let points = system_output.view_systems.get::<GeoPointsVisualizer>()?;
+        let line_strings = system_output
+            .view_systems
+            .get::<GeoLineStringsVisualizer>()?;
+
+        let (rect, response) =
+            ui.allocate_exact_size(ui.available_size(), egui::Sense::click_and_drag());
+
+        let mut camera = state.camera.unwrap_or_else(|| {
+            let positions = points
+                .batches
+                .iter()
+                .flat_map(|batch| batch.points.iter().map(|point| point.position))
+                .chain(line_strings.batches.iter().flat_map(|batch| {
+                    batch
+                        .line_strings
+                        .iter()
+                        .flat_map(|instance| instance.line_string.0.iter())
+                        .map(|lat_lon| LatLon(*lat_lon))
+                }))
+                .map(|lat_lon| world_from_lat_lon(&lat_lon));
+            fit_to_rect(positions, rect)
+        });
+
+        if response.dragged() {
+            let delta = response.drag_delta();
+            camera.center -= DVec2::new(delta.x as f64, delta.y as f64) / camera.ui_per_world();
+            state.camera = Some(camera);
+        }
+
+        if let Some(pointer) = response.hover_pos() {
+            let (zoom, scroll) = ui.input(|i| (i.zoom_delta(), i.smooth_scroll_delta));
+            if zoom != 1.0 || scroll != egui::Vec2::ZERO {
+                camera.center -=
+                    DVec2::new(scroll.x as f64, scroll.y as f64) / camera.ui_per_world();
+                camera.zoom_around(rect, pointer, zoom);
+                state.camera = Some(camera);
+            }
+        }
+
+        if response.double_clicked() {
+            state.camera = None;
+        }
+
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 0.0, ui.visuals().extreme_bg_color);
+
+        draw_tiles(ui, &painter, &mut state.tiles, &camera, rect);
+        draw_graticule(ui, &painter, &camera, rect);
+
+        let highlight_stroke = |entity_path: &EntityPath, instance: Instance| {
+            let highlight = query
+                .highlights
+                .entity_highlight(entity_path.hash())
+                .index_highlight(instance);
+            if highlight.hover == HoverHighlight::Hovered {
+                Some(ui.ctx().hover_stroke())
+            } else if highlight.selection != SelectionHighlight::None {
+                Some(ui.ctx().selection_stroke())
+            } else {
+                None
+            }
+        };
+
+        for batch in &line_strings.batches {
+            for instance in &batch.line_strings {
+                let Some(first) = instance.line_string.0.first() else {
+                    continue;
+                };
+                let latitude = LatLon(*first).latitude();
+                let width = 2.0
+                    * radius_in_ui_points(instance.radius, latitude, camera.ui_per_world(), 1.0);
+                let ui_points: Vec<egui::Pos2> = instance
+                    .line_string
+                    .0
+                    .iter()
+                    .map(|lat_lon| {
+                        camera.ui_from_world(rect, world_from_lat_lon(&LatLon(*lat_lon)))
+                    })
+                    .collect();
+
+                if let Some(stroke) = highlight_stroke(&batch.entity_path, instance.instance) {
+                    painter.add(egui::Shape::line(
+                        ui_points.clone(),
+                        egui::Stroke::new(width + 2.0 * stroke.width, stroke.color),
+                    ));
+                }
+
+                let color = if instance.is_current {
+                    instance.color
+                } else {
+                    instance.color.gamma_multiply(0.5)
+                };
+                painter.add(egui::Shape::line(
+                    ui_points,
+                    egui::Stroke::new(width, color),
+                ));
+            }
+        }
+
+        let mut hovered_point = None;
+        let mut closest_distance = f32::INFINITY;
+
+        for batch in &points.batches {
+            for point in &batch.points {
+                let center = camera.ui_from_world(rect, world_from_lat_lon(&point.position));
+                let radius = radius_in_ui_points(
+                    point.radius,
+                    point.position.latitude(),
+                    camera.ui_per_world(),
+                    DEFAULT_POINT_RADIUS,
+                );
+
+                let stroke =
+                    if let Some(stroke) = highlight_stroke(&batch.entity_path, point.instance) {
+                        stroke
+                    } else if point.is_current {
+                        // Make the position at the time cursor stand out from its history.
+                        egui::Stroke::new(2.0, ui.visuals().strong_text_color())
+                    } else {
+                        egui::Stroke::NONE
+                    };
+
+                let fill = if point.is_current {
+                    point.color
+                } else {
+                    point.color.gamma_multiply(0.5)
+                };
+                painter.circle(center, radius, fill, stroke);
+
+                if let Some(pointer) = response.hover_pos() {
+                    let distance = center.distance(pointer) - radius;
+                    if distance <= 2.0 && distance < closest_distance {
+                        closest_distance = distance;
+                        hovered_point = Some((&batch.entity_path, point.instance));
+                    }
+                }
+            }
+        }
+
+        if let Some((entity_path, instance)) = hovered_point {
+            ctx.select_hovered_on_click(
+                &response,
+                Item::DataResult(
+                    query.space_view_id,
+                    InstancePath::instance(entity_path.clone(), instance),
+                ),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(MapSpaceView => []);
+
+/// Radius in ui points, interpreting scene units as meters at the given latitude.
+fn radius_in_ui_points(radius: Radius, latitude: f64, ui_per_world: f64, default: f32) -> f32 {
+    if let Some(meters) = radius.scene_units() {
+        (meters as f64 / meters_per_world_unit(latitude) * ui_per_world) as f32
+    } else {
+        radius.ui_points().unwrap_or(default)
+    }
+}
+
+/// Camera that fits all given world positions into the given rectangle.
+fn fit_to_rect(positions: impl Iterator<Item = DVec2>, rect: egui::Rect) -> MapCamera {
+    let mut min = DVec2::INFINITY;
+    let mut max = DVec2::NEG_INFINITY;
+    for position in positions {
+        min = min.min(position);
+        max = max.max(position);
+    }
+
+    let available = rect.shrink(FIT_MARGIN);
+    if !min.is_finite() || !max.is_finite() {
+        // No data: show the whole world.
+        let zoom = (available.width().min(available.height()) as f64 / TILE_SIZE).log2();
+        return MapCamera {
+            center: DVec2::splat(0.5),
+            zoom: zoom.clamp(0.0, MAX_ZOOM),
+        };
+    }
+
+    let size = max - min;
+    let zoom = if size.max_element() <= 0.0 {
+        SINGLE_POSITION_ZOOM
+    } else {
+        let ui_per_world =
+            (available.width() as f64 / size.x).min(available.height() as f64 / size.y);
+        (ui_per_world / TILE_SIZE).log2()
+    };
+
+    MapCamera {
+        center: (min + max) / 2.0,
+        zoom: if zoom.is_finite() {
+            zoom.clamp(0.0, MAX_ZOOM)
+        } else {
+            SINGLE_POSITION_ZOOM
+        },
+    }
+}
+
+/// Draws all tiles that are in view, at the tile zoom level closest to the camera zoom.
+fn draw_tiles(
+    ui: &egui::Ui,
+    painter: &egui::Painter,
+    tiles: &mut TileCache,
+    camera: &MapCamera,
+    rect: egui::Rect,
+) {
+    if !tiles.has_directory() {
+        return;
+    }
+
+    let tile_zoom = camera.zoom.round().clamp(0.0, MAX_TILE_ZOOM as f64) as u8;
+    let tiles_per_axis = TileId::tiles_per_axis(tile_zoom);
+    let tile_range = |min: f64, max: f64| {
+        let scale = tiles_per_axis as f64;
+        let first = (min * scale).floor().max(0.0) as u32;
+        let last = ((max * scale).floor() as u32).min(tiles_per_axis - 1);
+        first..=last
+    };
+
+    let world_min = camera.world_from_ui(rect, rect.min);
+    let world_max = camera.world_from_ui(rect, rect.max);
+    if world_max.x < 0.0 || world_max.y < 0.0 || 1.0 < world_min.x || 1.0 < world_min.y {
+        return;
+    }
+
+    let uv = egui::Rect::from_min_max(egui::Pos2::ZERO, egui::pos2(1.0, 1.0));
+    for y in tile_range(world_min.y, world_max.y) {
+        for x in tile_range(world_min.x, world_max.x) {
+            let tile = TileId {
+                zoom: tile_zoom,
+                x,
+                y,
+            };
+            let Some(texture) = tiles.get(ui.ctx(), tile) else {
+                continue;
+            };
+
+            let tile_min = DVec2::new(x as f64, y as f64) / tiles_per_axis as f64;
+            let tile_max = DVec2::new((x + 1) as f64, (y + 1) as f64) / tiles_per_axis as f64;
+            let tile_rect = egui::Rect::from_min_max(
+                camera.ui_from_world(rect, tile_min),
+                camera.ui_from_world(rect, tile_max),
+            );
+            painter.image(texture.id(), tile_rect, uv, egui::Color32::WHITE);
+        }
+    }
+}
+
+/// Draws lines of constant latitude and longitude, with labels along the view edges.
+fn draw_graticule(ui: &egui::Ui, painter: &egui::Painter, camera: &MapCamera, rect: egui::Rect) {
+    let top_left = lat_lon_from_world(camera.world_from_ui(rect, rect.min));
+    let bottom_right = lat_lon_from_world(camera.world_from_ui(rect, rect.max));
+
+    let lon_min = top_left.longitude().max(-180.0);
+    let lon_max = bottom_right.longitude().min(180.0);
+    let lat_min = bottom_right.latitude().max(-MAX_LATITUDE);
+    let lat_max = top_left.latitude().min(MAX_LATITUDE);
+    if lon_max <= lon_min || lat_max <= lat_min {
+        return;
+    }
+
+    let step = graticule_step(lon_max - lon_min);
+    let decimals = (-step.log10()).ceil().max(0.0) as usize;
+
+    let stroke = egui::Stroke::new(1.0, ui.visuals().weak_text_color().gamma_multiply(0.5));
+    let font = egui::TextStyle::Small.resolve(ui.style());
+    let text_color = ui.visuals().weak_text_color();
+
+    let mut longitude = (lon_min / step).ceil() * step;
+    while longitude <= lon_max {
+        let x = camera
+            .ui_from_world(rect, world_from_lat_lon(&LatLon::new(0.0, longitude)))
+            .x;
+        painter.vline(x, rect.y_range(), stroke);
+        painter.text(
+            egui::pos2(x + 2.0, rect.top() + 2.0),
+            egui::Align2::LEFT_TOP,
+            format!("{longitude:.decimals$}°"),
+            font.clone(),
+            text_color,
+        );
+        longitude += step;
+    }
+
+    let mut latitude = (lat_min / step).ceil() * step;
+    while latitude <= lat_max {
+        let y = camera
+            .ui_from_world(rect, world_from_lat_lon(&LatLon::new(latitude, 0.0)))
+            .y;
+        painter.hline(rect.x_range(), y, stroke);
+        painter.text(
+            egui::pos2(rect.left() + 2.0, y - 2.0),
+            egui::Align2::LEFT_BOTTOM,
+            format!("{latitude:.decimals$}°"),
+            font.clone(),
+            text_color,
+        );
+        latitude += step;
+    }
+}
+
+/// A "nice" spacing between graticule lines, so that a handful of them span the given range of degrees.
+fn graticule_step(span_in_degrees: f64) -> f64 {
+    let raw_step = (span_in_degrees / 6.0).min(30.0);
+    let magnitude = 10f64.powf(raw_step.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= raw_step)
+        .unwrap_or(10.0 * magnitude)
+}
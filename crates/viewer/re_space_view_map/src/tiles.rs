@@ -0,0 +1,360 @@
+use std::path::{Path, PathBuf};
+
+use crossbeam::channel::{Receiver, Sender};
+
+/// Highest zoom level for which tiles are looked up.
+pub const MAX_TILE_ZOOM: u8 = 19;
+
+/// How many tiles are kept in memory at most.
+///
+/// A typical 256x256 tile takes 256 kB as a texture.
+const MAX_CACHED_TILES: usize = 512;
+
+/// How many tiles are waiting to be loaded at most.
+///
+/// Keeps tiles that scrolled out of view while panning or zooming from piling up in the queue.
+const MAX_PENDING_LOADS: usize = 16;
+
+/// Identifies a tile in the `{zoom}/{x}/{y}.png` layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TileId {
+    pub zoom: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl TileId {
+    /// Number of tiles along each axis at the given zoom level.
+    pub fn tiles_per_axis(zoom: u8) -> u32 {
+        1 << zoom
+    }
+
+    fn path(&self, directory: &Path) -> PathBuf {
+        directory
+            .join(self.zoom.to_string())
+            .join(self.x.to_string())
+            .join(format!("{}.png", self.y))
+    }
+}
+
+enum TileState {
+    /// Being read and decoded by the [`TileLoader`].
+    Loading,
+
+    /// `None` for tiles that don't exist or failed to load, so that we don't try them again.
+    Loaded(Option<egui::TextureHandle>),
+}
+
+struct CachedTile {
+    state: TileState,
+
+    /// At which [`TileCache::frame`] was this tile last requested?
+    last_used_frame: u64,
+}
+
+/// Keeps the tiles of a local tile directory in memory, loading them lazily as they come into view.
+///
+/// Tiles are read and decoded on a background thread, and the least recently used ones are
+/// dropped once there are more than [`MAX_CACHED_TILES`].
+#[derive(Default)]
+pub struct TileCache {
+    directory: PathBuf,
+    tiles: ahash::HashMap<TileId, CachedTile>,
+
+    /// Started on first use.
+    ///
+    /// If no thread can be started (e.g. on the web), tiles are loaded on the UI thread instead.
+    loader: Option<std::io::Result<TileLoader>>,
+
+    /// Number of tiles that are [`TileState::Loading`].
+    num_pending: usize,
+
+    frame: u64,
+}
+
+impl TileCache {
+    /// Switches to another tile directory, dropping all tiles if it changed,
+    /// and picks up the tiles that were loaded since the last frame.
+    ///
+    /// Must be called once per frame, before any tiles are requested.
+    pub fn begin_frame(&mut self, egui_ctx: &egui::Context, directory: &Path) {
+        if self.directory != directory {
+            self.directory = directory.to_owned();
+            self.tiles.clear();
+            self.num_pending = 0;
+        }
+        self.frame += 1;
+
+        if let Some(Ok(loader)) = &self.loader {
+            for LoadedTile {
+                directory: requested_directory,
+                tile,
+                image,
+            } in loader.loaded.try_iter()
+            {
+                if requested_directory != self.directory {
+                    continue; // Requested before switching directories.
+                }
+                if let Some(cached) = self.tiles.get_mut(&tile) {
+                    if matches!(cached.state, TileState::Loading) {
+                        cached.state = TileState::Loaded(to_texture(egui_ctx, tile, image));
+                        self.num_pending -= 1;
+                    }
+                }
+            }
+        }
+
+        self.evict_least_recently_used(MAX_CACHED_TILES);
+    }
+
+    /// Whether there is a tile directory at all.
+    pub fn has_directory(&self) -> bool {
+        !self.directory.as_os_str().is_empty()
+    }
+
+    /// Returns the texture of the given tile, starting to load it if needed.
+    ///
+    /// Returns `None` if the tile doesn't exist or hasn't been loaded yet.
+    pub fn get(&mut self, egui_ctx: &egui::Context, tile: TileId) -> Option<&egui::TextureHandle> {
+        if !self.has_directory() {
+            return None;
+        }
+
+        if !self.tiles.contains_key(&tile) {
+            let loader = self.loader.get_or_insert_with(|| {
+                let egui_ctx = egui_ctx.clone();
+                let loader = TileLoader::new(move || egui_ctx.request_repaint());
+                if let Err(err) = &loader {
+                    re_log::warn_once!(
+                        "Failed to start loading map tiles in the background: {err}"
+                    );
+                }
+                loader
+            });
+
+            let state = match loader {
+                Ok(loader) => {
+                    if self.num_pending >= MAX_PENDING_LOADS
+                        || !loader.request(self.directory.clone(), tile)
+                    {
+                        return None;
+                    }
+                    self.num_pending += 1;
+                    TileState::Loading
+                }
+                Err(_) => TileState::Loaded(to_texture(
+                    egui_ctx,
+                    tile,
+                    load_tile(&tile.path(&self.directory)),
+                )),
+            };
+            self.tiles.insert(
+                tile,
+                CachedTile {
+                    state,
+                    last_used_frame: self.frame,
+                },
+            );
+        }
+
+        let cached = self.tiles.get_mut(&tile)?;
+        cached.last_used_frame = self.frame;
+        match &cached.state {
+            TileState::Loading => None,
+            TileState::Loaded(texture) => texture.as_ref(),
+        }
+    }
+
+    /// Drops the tiles that were used the longest time ago, until at most `max_tiles` are left.
+    ///
+    /// Tiles that are still loading are kept.
+    fn evict_least_recently_used(&mut self, max_tiles: usize) {
+        if self.tiles.len() <= max_tiles {
+            return;
+        }
+        re_tracing::profile_function!();
+
+        let mut loaded: Vec<(u64, TileId)> = self
+            .tiles
+            .iter()
+            .filter(|(_, cached)| matches!(cached.state, TileState::Loaded(_)))
+            .map(|(tile, cached)| (cached.last_used_frame, *tile))
+            .collect();
+        loaded.sort_unstable_by_key(|(last_used_frame, _)| *last_used_frame);
+
+        let num_evicted = self.tiles.len() - max_tiles;
+        for (_, tile) in loaded.into_iter().take(num_evicted) {
+            self.tiles.remove(&tile);
+        }
+    }
+}
+
+struct LoadedTile {
+    /// The directory the tile was requested from, see [`TileLoader::request`].
+    directory: PathBuf,
+    tile: TileId,
+    image: Option<egui::ColorImage>,
+}
+
+/// Reads and decodes tiles on a background thread.
+///
+/// The thread exits once the loader is dropped.
+struct TileLoader {
+    requests: Sender<(PathBuf, TileId)>,
+    loaded: Receiver<LoadedTile>,
+}
+
+impl TileLoader {
+    /// Starts the thread, which calls `on_loaded` whenever a tile is ready to be received.
+    fn new(on_loaded: impl Fn() + Send + 'static) -> std::io::Result<Self> {
+        let (requests, requests_rx) = crossbeam::channel::unbounded::<(PathBuf, TileId)>();
+        let (loaded_tx, loaded) = crossbeam::channel::unbounded();
+
+        std::thread::Builder::new()
+            .name("map_tile_loader".to_owned())
+            .spawn(move || {
+                while let Ok((directory, tile)) = requests_rx.recv() {
+                    let image = load_tile(&tile.path(&directory));
+                    if loaded_tx
+                        .send(LoadedTile {
+                            directory,
+                            tile,
+                            image,
+                        })
+                        .is_err()
+                    {
+                        break;
+                    }
+                    on_loaded();
+                }
+            })?;
+
+        Ok(Self { requests, loaded })
+    }
+
+    /// Queues the tile for loading. Returns `false` if the thread is gone.
+    fn request(&self, directory: PathBuf, tile: TileId) -> bool {
+        self.requests.send((directory, tile)).is_ok()
+    }
+}
+
+fn to_texture(
+    egui_ctx: &egui::Context,
+    tile: TileId,
+    image: Option<egui::ColorImage>,
+) -> Option<egui::TextureHandle> {
+    image.map(|image| {
+        egui_ctx.load_texture(
+            format!("map_tile_{}_{}_{}", tile.zoom, tile.x, tile.y),
+            image,
+            egui::TextureOptions::LINEAR,
+        )
+    })
+}
+
+fn load_tile(path: &Path) -> Option<egui::ColorImage> {
+    re_tracing::profile_function!();
+
+    if !path.exists() {
+        return None;
+    }
+
+    match image::open(path) {
+        Ok(image) => {
+            let image = image.to_rgba8();
+            let size = [image.width() as usize, image.height() as usize];
+            Some(egui::ColorImage::from_rgba_unmultiplied(
+                size,
+                image.as_raw(),
+            ))
+        }
+        Err(err) => {
+            re_log::warn_once!("Failed to load map tile {path:?}: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    fn tile(x: u32) -> TileId {
+        TileId { zoom: 3, x, y: 0 }
+    }
+
+    fn write_tile(directory: &Path, tile: TileId) {
+        let path = tile.path(directory);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        image::RgbaImage::from_pixel(2, 2, image::Rgba([255, 0, 0, 255]))
+            .save(path)
+            .unwrap();
+    }
+
+    /// Calls [`TileCache::get`] once per frame until the tile is no longer loading.
+    fn get_loaded(
+        cache: &mut TileCache,
+        egui_ctx: &egui::Context,
+        directory: &Path,
+        tile: TileId,
+    ) -> Option<egui::TextureHandle> {
+        for _ in 0..1_000 {
+            cache.begin_frame(egui_ctx, directory);
+            let texture = cache.get(egui_ctx, tile).cloned();
+            if texture.is_some() || cache.num_pending == 0 {
+                return texture;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        panic!("tile {tile:?} never finished loading");
+    }
+
+    #[test]
+    fn loads_in_background() {
+        let directory = tempfile::tempdir().unwrap();
+        write_tile(directory.path(), tile(1));
+
+        let egui_ctx = egui::Context::default();
+        let mut cache = TileCache::default();
+        cache.begin_frame(&egui_ctx, directory.path());
+
+        // The first request only starts loading the tile.
+        assert!(cache.get(&egui_ctx, tile(1)).is_none());
+        assert_eq!(cache.num_pending, 1);
+
+        let texture = get_loaded(&mut cache, &egui_ctx, directory.path(), tile(1)).unwrap();
+        assert_eq!(texture.size(), [2, 2]);
+
+        // Missing tiles are remembered as such.
+        assert!(get_loaded(&mut cache, &egui_ctx, directory.path(), tile(2)).is_none());
+        assert_eq!(cache.num_pending, 0);
+        assert_eq!(cache.tiles.len(), 2);
+
+        // Switching directories drops everything.
+        let other_directory = tempfile::tempdir().unwrap();
+        cache.begin_frame(&egui_ctx, other_directory.path());
+        assert!(cache.tiles.is_empty());
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let directory = tempfile::tempdir().unwrap();
+        let egui_ctx = egui::Context::default();
+        let mut cache = TileCache::default();
+        for x in 0..4 {
+            get_loaded(&mut cache, &egui_ctx, directory.path(), tile(x));
+        }
+
+        // Use the first tile again, making the second one the least recently used.
+        cache.begin_frame(&egui_ctx, directory.path());
+        cache.get(&egui_ctx, tile(0));
+
+        cache.evict_least_recently_used(3);
+        assert_eq!(cache.tiles.len(), 3);
+        assert!(!cache.tiles.contains_key(&tile(1)));
+
+        cache.evict_least_recently_used(1);
+        assert_eq!(cache.tiles.len(), 1);
+        assert!(cache.tiles.contains_key(&tile(0)));
+    }
+}
@@ -0,0 +1,132 @@
+use re_log_types::{EntityPath, Instance};
+use re_query::range_zip_1x2;
+use re_space_view::RangeResultsExt as _;
+use re_types::{
+    archetypes::GeoLineStrings,
+    components::{Color, GeoLineString, Radius},
+};
+use re_viewer_context::{
+    auto_color_for_entity_path, IdentifiedViewSystem, QueryContext, SpaceViewSystemExecutionError,
+    TypedComponentFallbackProvider, ViewContext, ViewContextCollection, ViewQuery,
+    VisualizerQueryInfo, VisualizerSystem,
+};
+
+use super::{clamped, latest_data_time, query_archetype_with_history};
+
+/// A single line string, as logged via [`GeoLineStrings`].
+pub struct GeoLineStringInstance {
+    pub line_string: GeoLineString,
+    pub instance: Instance,
+    pub color: egui::Color32,
+    pub radius: Radius,
+
+    /// Whether the line string was logged at the time cursor, as opposed to being part of the visible history.
+    pub is_current: bool,
+}
+
+/// All line strings logged to a single entity.
+pub struct GeoLineStringBatch {
+    pub entity_path: EntityPath,
+    pub line_strings: Vec<GeoLineStringInstance>,
+}
+
+/// Collects the geospatial line strings of all entities in the view.
+#[derive(Default)]
+pub struct GeoLineStringsVisualizer {
+    pub batches: Vec<GeoLineStringBatch>,
+}
+
+impl IdentifiedViewSystem for GeoLineStringsVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "GeoLineStrings".into()
+    }
+}
+
+impl VisualizerSystem for GeoLineStringsVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<GeoLineStrings>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, SpaceViewSystemExecutionError> {
+        let latest_at = view_query.latest_at_query();
+        let resolver = ctx.recording().resolver();
+
+        for data_result in view_query.iter_visible_data_results(ctx, Self::identifier()) {
+            let results =
+                query_archetype_with_history::<GeoLineStrings>(ctx, view_query, data_result);
+
+            let line_strings = match results.get_required_component_dense::<GeoLineString>(resolver)
+            {
+                Some(line_strings) => line_strings?,
+                None => continue,
+            };
+            let colors = results.get_or_empty_dense::<Color>(resolver)?;
+            let radii = results.get_or_empty_dense::<Radius>(resolver)?;
+
+            let query_ctx = ctx.query_context(data_result, &latest_at);
+            let fallback_color: Color = self.fallback_for(&query_ctx);
+            let fallback_radius: Radius = self.fallback_for(&query_ctx);
+
+            let current_time = latest_data_time(line_strings.range_indexed());
+
+            let mut instances = Vec::new();
+            for (index, line_strings, colors, radii) in range_zip_1x2(
+                line_strings.range_indexed(),
+                colors.range_indexed(),
+                radii.range_indexed(),
+            ) {
+                let is_current = Some(index.0) == current_time;
+                let colors = colors.unwrap_or_default();
+                let radii = radii.unwrap_or_default();
+
+                instances.extend(line_strings.iter().enumerate().map(|(i, line_string)| {
+                    GeoLineStringInstance {
+                        line_string: line_string.clone(),
+                        instance: Instance::from(i as u64),
+                        color: clamped(colors, i).unwrap_or(&fallback_color).0.into(),
+                        radius: *clamped(radii, i).unwrap_or(&fallback_radius),
+                        is_current,
+                    }
+                }));
+            }
+
+            if instances.is_empty() {
+                continue;
+            }
+
+            self.batches.push(GeoLineStringBatch {
+                entity_path: data_result.entity_path.clone(),
+                line_strings: instances,
+            });
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl TypedComponentFallbackProvider<Color> for GeoLineStringsVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Color {
+        auto_color_for_entity_path(ctx.target_entity_path)
+    }
+}
+
+impl TypedComponentFallbackProvider<Radius> for GeoLineStringsVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Radius {
+        Radius::new_ui_points(1.5)
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(GeoLineStringsVisualizer => [Color, Radius]);
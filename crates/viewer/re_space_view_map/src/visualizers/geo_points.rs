@@ -0,0 +1,133 @@
+use re_log_types::{EntityPath, Instance};
+use re_query::range_zip_1x2;
+use re_space_view::RangeResultsExt as _;
+use re_types::{
+    archetypes::GeoPoints,
+    components::{Color, LatLon, Radius},
+};
+use re_viewer_context::{
+    auto_color_for_entity_path, IdentifiedViewSystem, QueryContext, SpaceViewSystemExecutionError,
+    TypedComponentFallbackProvider, ViewContext, ViewContextCollection, ViewQuery,
+    VisualizerQueryInfo, VisualizerSystem,
+};
+
+use super::{clamped, latest_data_time, query_archetype_with_history};
+
+/// A single point, as logged via [`GeoPoints`].
+pub struct GeoPointInstance {
+    pub position: LatLon,
+    pub instance: Instance,
+    pub color: egui::Color32,
+    pub radius: Radius,
+
+    /// Whether the point was logged at the time cursor, as opposed to being part of the visible history.
+    pub is_current: bool,
+}
+
+/// All points logged to a single entity.
+pub struct GeoPointBatch {
+    pub entity_path: EntityPath,
+    pub points: Vec<GeoPointInstance>,
+}
+
+/// Collects the geospatial points of all entities in the view.
+#[derive(Default)]
+pub struct GeoPointsVisualizer {
+    pub batches: Vec<GeoPointBatch>,
+}
+
+impl IdentifiedViewSystem for GeoPointsVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "GeoPoints".into()
+    }
+}
+
+impl VisualizerSystem for GeoPointsVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<GeoPoints>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, SpaceViewSystemExecutionError> {
+        let latest_at = view_query.latest_at_query();
+        let resolver = ctx.recording().resolver();
+
+        for data_result in view_query.iter_visible_data_results(ctx, Self::identifier()) {
+            let results = query_archetype_with_history::<GeoPoints>(ctx, view_query, data_result);
+
+            let positions = match results.get_required_component_dense::<LatLon>(resolver) {
+                Some(positions) => positions?,
+                None => continue,
+            };
+            let colors = results.get_or_empty_dense::<Color>(resolver)?;
+            let radii = results.get_or_empty_dense::<Radius>(resolver)?;
+
+            let query_ctx = ctx.query_context(data_result, &latest_at);
+            let fallback_color: Color = self.fallback_for(&query_ctx);
+            let fallback_radius: Radius = self.fallback_for(&query_ctx);
+
+            let current_time = latest_data_time(positions.range_indexed());
+
+            let mut points = Vec::new();
+            for (index, positions, colors, radii) in range_zip_1x2(
+                positions.range_indexed(),
+                colors.range_indexed(),
+                radii.range_indexed(),
+            ) {
+                let is_current = Some(index.0) == current_time;
+                let colors = colors.unwrap_or_default();
+                let radii = radii.unwrap_or_default();
+
+                points.extend(
+                    positions
+                        .iter()
+                        .enumerate()
+                        .map(|(i, position)| GeoPointInstance {
+                            position: *position,
+                            instance: Instance::from(i as u64),
+                            color: clamped(colors, i).unwrap_or(&fallback_color).0.into(),
+                            radius: *clamped(radii, i).unwrap_or(&fallback_radius),
+                            is_current,
+                        }),
+                );
+            }
+
+            if points.is_empty() {
+                continue;
+            }
+
+            self.batches.push(GeoPointBatch {
+                entity_path: data_result.entity_path.clone(),
+                points,
+            });
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl TypedComponentFallbackProvider<Color> for GeoPointsVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Color {
+        auto_color_for_entity_path(ctx.target_entity_path)
+    }
+}
+
+impl TypedComponentFallbackProvider<Radius> for GeoPointsVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Radius {
+        Radius::new_ui_points(5.0)
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(GeoPointsVisualizer => [Color, Radius]);
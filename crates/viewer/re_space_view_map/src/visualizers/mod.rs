@@ -0,0 +1,70 @@
+mod geo_line_strings;
+mod geo_points;
+
+pub use geo_line_strings::GeoLineStringsVisualizer;
+pub use geo_points::GeoPointsVisualizer;
+
+use re_chunk_store::{LatestAtQuery, RangeQuery};
+use re_log_types::TimeInt;
+use re_space_view::{
+    latest_at_with_blueprint_resolved_data, range_with_blueprint_resolved_data, HybridResults,
+};
+use re_types::Archetype;
+use re_viewer_context::{DataResult, QueryRange, ViewContext, ViewQuery};
+
+/// Queries an archetype over the visible time range of the data result.
+///
+/// Without a visible time range this is a plain latest-at query at the time cursor.
+fn query_archetype_with_history<'a, A: Archetype>(
+    ctx: &'a ViewContext<'a>,
+    view_query: &ViewQuery<'_>,
+    data_result: &'a DataResult,
+) -> HybridResults<'a> {
+    match data_result.query_range() {
+        QueryRange::TimeRange(time_range) => {
+            let range_query = RangeQuery::new(
+                view_query.timeline,
+                re_log_types::ResolvedTimeRange::from_relative_time_range(
+                    time_range,
+                    view_query.latest_at,
+                ),
+            );
+            let results = range_with_blueprint_resolved_data(
+                ctx,
+                None,
+                &range_query,
+                data_result,
+                A::all_components().iter().copied(),
+            );
+            (range_query, results).into()
+        }
+        QueryRange::LatestAt => {
+            let latest_query = LatestAtQuery::new(view_query.timeline, view_query.latest_at);
+            let query_shadowed_defaults = false;
+            let results = latest_at_with_blueprint_resolved_data(
+                ctx,
+                None,
+                &latest_query,
+                data_result,
+                A::all_components().iter().copied(),
+                query_shadowed_defaults,
+            );
+            (latest_query, results).into()
+        }
+    }
+}
+
+/// The most recent data time among the given indices.
+///
+/// Data logged at this time is what the time cursor points at; everything else is history.
+fn latest_data_time<'a, T: 'a>(
+    indexed: impl Iterator<Item = (&'a (TimeInt, re_chunk_store::RowId), T)>,
+) -> Option<TimeInt> {
+    indexed.map(|((data_time, _row_id), _)| *data_time).max()
+}
+
+/// Picks the component instance for the given index, repeating the last one if there are fewer
+/// instances than indices.
+fn clamped<C>(values: &[C], index: usize) -> Option<&C> {
+    values.get(index).or_else(|| values.last())
+}
@@ -5,7 +5,7 @@ mod transform_context;
 pub use annotation_context::AnnotationSceneContext;
 pub use depth_offsets::EntityDepthOffsets;
 use re_types::SpaceViewClassIdentifier;
-pub use transform_context::TransformContext;
+pub use transform_context::{ProjectedLensDistortion, TransformContext};
 
 // -----------------------------------------------------------------------------
 
@@ -18,6 +18,9 @@ pub struct SpatialSceneEntityContext<'a> {
     pub depth_offset: DepthOffset,
     pub annotations: std::sync::Arc<Annotations>,
 
+    /// Lens distortion to apply to 3D content that is projected into this 2D view, if any.
+    pub lens_distortion: Option<&'a ProjectedLensDistortion>,
+
     pub highlight: &'a re_viewer_context::SpaceViewOutlineMasks, // Not part of the context, but convenient to have here.
     pub space_view_class_identifier: SpaceViewClassIdentifier,
 }
@@ -15,6 +15,12 @@ use re_viewer_context::{IdentifiedViewSystem, ViewContext, ViewContextSystem};
 
 use crate::visualizers::image_view_coordinates;
 
+/// Image plane distance of a pinhole camera above the reference path.
+///
+/// TODO(#1025): See comment in transform_at. This is a workaround for precision issues
+/// and the fact that there is no meaningful image plane distance for 3D->2D views.
+const IMAGE_PLANE_DISTANCE_ABOVE_REFERENCE: f32 = 500.0;
+
 #[derive(Clone)]
 struct TransformInfo {
     /// The transform from the entity to the reference space.
@@ -27,6 +33,91 @@ struct TransformInfo {
     pub parent_pinhole: Option<EntityPath>,
 }
 
+/// The lens distortion of the pinhole camera that 3D content is projected through into a 2D reference space.
+///
+/// The renderer only knows about ideal pinhole projections,
+/// so points are moved to where the ideal projection lands on their distorted position on the image.
+#[derive(Clone)]
+pub struct ProjectedLensDistortion {
+    /// The camera, with lens distortion.
+    pinhole: Pinhole,
+
+    reference_from_rdf_camera: glam::Affine3A,
+    rdf_camera_from_reference: glam::Affine3A,
+}
+
+impl ProjectedLensDistortion {
+    /// `reference_from_image2d` is the transform from the 2D space of the camera to the reference space.
+    fn new(pinhole: Pinhole, reference_from_image2d: glam::Affine3A) -> Self {
+        let reference_from_rdf_camera = reference_from_image2d
+            * image_plane3d_from_2d_content(
+                &pinhole.image_from_camera,
+                IMAGE_PLANE_DISTANCE_ABOVE_REFERENCE,
+            )
+            .inverse();
+
+        Self {
+            pinhole,
+            reference_from_rdf_camera,
+            rdf_camera_from_reference: reference_from_rdf_camera.inverse(),
+        }
+    }
+
+    /// Moves a point in reference space to where the lens distortion of the camera puts it on the image.
+    ///
+    /// Points behind the camera are left as they are.
+    pub fn distort(&self, point: glam::Vec3) -> glam::Vec3 {
+        let camera = self.rdf_camera_from_reference.transform_point3(point);
+        if camera.z <= 0.0 {
+            return point;
+        }
+        let distorted = self
+            .pinhole
+            .unproject(self.pinhole.project_distorted(camera));
+        self.reference_from_rdf_camera.transform_point3(distorted)
+    }
+
+    /// Like [`Self::distort`], but for points in the space of an entity.
+    pub fn distort_entity_points(
+        &self,
+        world_from_entity: glam::Affine3A,
+        points: impl Iterator<Item = glam::Vec3>,
+    ) -> Vec<glam::Vec3> {
+        let entity_from_world = world_from_entity.inverse();
+        points
+            .map(|point| {
+                entity_from_world
+                    .transform_point3(self.distort(world_from_entity.transform_point3(point)))
+            })
+            .collect()
+    }
+
+    /// Like [`Self::distort_entity_points`], but for the vertices of a line strip.
+    ///
+    /// Straight segments are bent by the distortion, so they are subdivided first.
+    pub fn distort_entity_strip(
+        &self,
+        world_from_entity: glam::Affine3A,
+        vertices: &[glam::Vec3],
+    ) -> Vec<glam::Vec3> {
+        let subdivided = vertices
+            .windows(2)
+            .flat_map(|segment| {
+                (0..STRIP_SEGMENT_STEPS_WITH_DISTORTION).map(|step| {
+                    segment[0].lerp(
+                        segment[1],
+                        step as f32 / STRIP_SEGMENT_STEPS_WITH_DISTORTION as f32,
+                    )
+                })
+            })
+            .chain(vertices.last().copied());
+        self.distort_entity_points(world_from_entity, subdivided)
+    }
+}
+
+/// Number of pieces each segment of a line strip is split into when applying lens distortion.
+const STRIP_SEGMENT_STEPS_WITH_DISTORTION: usize = 16;
+
 #[derive(Clone, Copy)]
 enum UnreachableTransformReason {
     /// More than one pinhole camera between this and the reference space.
@@ -56,6 +147,11 @@ pub struct TransformContext {
 
     /// The first parent of reference_path that is no longer reachable.
     first_unreachable_parent: Option<(EntityPath, UnreachableTransformReason)>,
+
+    /// Lens distortion of the pinhole camera above the reference path, if its images are shown distorted.
+    ///
+    /// Applies to all entities that are projected through that camera.
+    projected_lens_distortion: Option<(EntityPath, ProjectedLensDistortion)>,
 }
 
 impl IdentifiedViewSystem for TransformContext {
@@ -71,6 +167,7 @@ impl Default for TransformContext {
             transform_per_entity: Default::default(),
             unreachable_descendants: Default::default(),
             first_unreachable_parent: None,
+            projected_lens_distortion: None,
         }
     }
 }
@@ -101,6 +198,7 @@ impl ViewContextSystem for TransformContext {
         let entity_tree = ctx.recording().tree();
 
         self.space_origin = query.space_origin.clone();
+        self.projected_lens_distortion = None;
 
         // Find the entity path tree for the root.
         let Some(mut current_tree) = &entity_tree.subtree(query.space_origin) else {
@@ -143,9 +241,7 @@ impl ViewContextSystem for TransformContext {
                 current_tree,
                 ctx.recording(),
                 &time_query,
-                // TODO(#1025): See comment in transform_at. This is a workaround for precision issues
-                // and the fact that there is no meaningful image plane distance for 3D->2D views.
-                |_| 500.0,
+                |_| IMAGE_PLANE_DISTANCE_ABOVE_REFERENCE,
                 &mut encountered_pinhole,
             ) {
                 Err(unreachable_reason) => {
@@ -155,6 +251,14 @@ impl ViewContextSystem for TransformContext {
                 }
                 Ok(None) => {}
                 Ok(Some(parent_from_child)) => {
+                    if encountered_pinhole.as_ref() == Some(&current_tree.path) {
+                        self.projected_lens_distortion = projected_lens_distortion(
+                            &current_tree.path,
+                            ctx.recording(),
+                            &time_query,
+                            reference_from_ancestor,
+                        );
+                    }
                     reference_from_ancestor *= parent_from_child.inverse();
                 }
             }
@@ -300,6 +404,18 @@ impl TransformContext {
             .get(ent_path)
             .and_then(|i| i.parent_pinhole.as_ref())
     }
+
+    /// The lens distortion that 3D content of this entity is projected through into the reference space, if any.
+    ///
+    /// None if the entity isn't projected through a camera with lens distortion,
+    /// or if the images of that camera are shown undistorted.
+    pub fn projected_lens_distortion(
+        &self,
+        ent_path: &EntityPath,
+    ) -> Option<&ProjectedLensDistortion> {
+        let (pinhole_path, distortion) = self.projected_lens_distortion.as_ref()?;
+        (self.parent_pinhole(ent_path) == Some(pinhole_path)).then_some(distortion)
+    }
 }
 
 #[cfg(debug_assertions)]
@@ -411,6 +527,41 @@ fn get_cached_pinhole(
         })
 }
 
+/// Centers the image plane of a pinhole camera and moves it along z, scaling the further the image plane is.
+fn image_plane3d_from_2d_content(
+    image_from_camera: &PinholeProjection,
+    distance: f32,
+) -> glam::Affine3A {
+    let focal_length = image_from_camera.focal_length_in_pixels();
+    let focal_length = glam::vec2(focal_length.x(), focal_length.y());
+    let scale = distance / focal_length;
+    let translation = (-image_from_camera.principal_point() * scale).extend(distance);
+
+    glam::Affine3A::from_translation(translation)
+        // We want to preserve any depth that might be on the pinhole image.
+        // Use harmonic mean of x/y scale for those.
+        * glam::Affine3A::from_scale(scale.extend(2.0 / (1.0 / scale.x + 1.0 / scale.y)))
+}
+
+/// Lens distortion of the pinhole camera at `pinhole_path`, for 3D content projected through it
+/// into a reference space under the camera.
+fn projected_lens_distortion(
+    pinhole_path: &EntityPath,
+    entity_db: &EntityDb,
+    query: &LatestAtQuery,
+    reference_from_image2d: glam::Affine3A,
+) -> Option<(EntityPath, ProjectedLensDistortion)> {
+    let pinhole = crate::query_pinhole_legacy(entity_db, query, pinhole_path)?;
+    if pinhole.lens_distortion().is_none() || pinhole.undistorts_images() {
+        return None;
+    }
+
+    Some((
+        pinhole_path.clone(),
+        ProjectedLensDistortion::new(pinhole, reference_from_image2d),
+    ))
+}
+
 fn transform_at(
     subtree: &EntityTree,
     entity_db: &EntityDb,
@@ -438,18 +589,10 @@ fn transform_at(
         // Our visualization interprets this as looking at a 2D image plane from a single point (the pinhole).
 
         // Center the image plane and move it along z, scaling the further the image plane is.
-        let distance = pinhole_image_plane_distance(entity_path);
-        let focal_length = image_from_camera.focal_length_in_pixels();
-        let focal_length = glam::vec2(focal_length.x(), focal_length.y());
-        let scale = distance / focal_length;
-        let translation = (-image_from_camera.principal_point() * scale).extend(distance);
-
-        let image_plane3d_from_2d_content = glam::Affine3A::from_translation(translation)
-            // We want to preserve any depth that might be on the pinhole image.
-            // Use harmonic mean of x/y scale for those.
-            * glam::Affine3A::from_scale(
-                scale.extend(2.0 / (1.0 / scale.x + 1.0 / scale.y)),
-            );
+        let image_plane3d_from_2d_content = image_plane3d_from_2d_content(
+            &image_from_camera,
+            pinhole_image_plane_distance(entity_path),
+        );
 
         // Our interpretation of the pinhole camera implies that the axis semantics, i.e. ViewCoordinates,
         // determine how the image plane is oriented.
@@ -488,3 +631,61 @@ fn transform_at(
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use re_types::components::DistortionModel;
+
+    use super::*;
+
+    #[test]
+    fn distorted_points_project_onto_the_distorted_image() {
+        let pinhole = Pinhole::from_focal_length_and_resolution([400.0, 400.0], [640.0, 480.0])
+            .with_distortion_model(DistortionModel::BrownConrady)
+            .with_distortion_coefficients([-0.2, 0.05, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+
+        // A reference space that is moved around under the camera.
+        let reference_from_image2d = glam::Affine3A::from_translation(glam::vec3(3.0, -2.0, 1.0));
+        let distortion = ProjectedLensDistortion::new(pinhole.clone(), reference_from_image2d);
+        let rdf_camera_from_reference = distortion.rdf_camera_from_reference;
+
+        for camera_point in [
+            glam::vec3(0.0, 0.0, 2.0),
+            glam::vec3(1.0, 0.5, 2.0),
+            glam::vec3(-0.8, 0.6, 1.5),
+        ] {
+            let point = distortion
+                .reference_from_rdf_camera
+                .transform_point3(camera_point);
+            let distorted = rdf_camera_from_reference.transform_point3(distortion.distort(point));
+
+            // The renderer projects ideally, which must land on the distorted pixel at the same depth.
+            let expected = pinhole.project_distorted(camera_point);
+            let actual = pinhole.project(distorted);
+            assert!(
+                actual.abs_diff_eq(expected, 1e-2),
+                "{camera_point}: {actual} != {expected}"
+            );
+        }
+
+        // Points behind the camera are left alone.
+        let behind = distortion
+            .reference_from_rdf_camera
+            .transform_point3(glam::vec3(1.0, 1.0, -1.0));
+        assert_eq!(distortion.distort(behind), behind);
+
+        // Strips are subdivided, and keep their end points.
+        let strip = distortion.distort_entity_strip(
+            glam::Affine3A::IDENTITY,
+            &[
+                distortion
+                    .reference_from_rdf_camera
+                    .transform_point3(glam::vec3(-1.0, 0.0, 2.0)),
+                distortion
+                    .reference_from_rdf_camera
+                    .transform_point3(glam::vec3(1.0, 0.0, 2.0)),
+            ],
+        );
+        assert_eq!(strip.len(), STRIP_SEGMENT_STEPS_WITH_DISTORTION + 1);
+    }
+}
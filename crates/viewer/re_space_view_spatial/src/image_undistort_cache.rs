@@ -0,0 +1,271 @@
+use egui::util::hash;
+
+use re_chunk_store::RowId;
+use re_types::archetypes::Pinhole;
+use re_viewer_context::{Cache, ImageInfo, QueryContext};
+
+use crate::query_pinhole_legacy;
+
+/// The camera the queried entity is logged under, if it asks for its images to be undistorted.
+pub fn undistorting_pinhole(ctx: &QueryContext<'_>) -> Option<Pinhole> {
+    // The pinhole may be at the entity itself, or any of its ancestors.
+    let mut entity_path = Some(ctx.target_entity_path.clone());
+    while let Some(path) = entity_path {
+        if let Some(pinhole) = query_pinhole_legacy(ctx.recording(), ctx.query, &path) {
+            return pinhole.undistorts_images().then_some(pinhole);
+        }
+        entity_path = path.parent();
+    }
+    None
+}
+
+/// Removes the lens distortion of the given camera from the image, if possible.
+pub fn undistort_image(ctx: &QueryContext<'_>, pinhole: &Pinhole, image: ImageInfo) -> ImageInfo {
+    ctx.viewer_ctx
+        .cache
+        .entry(|c: &mut ImageUndistortCache| c.entry(&image, pinhole))
+        .unwrap_or(image)
+}
+
+struct UndistortedImage {
+    image: ImageInfo,
+
+    /// At which [`ImageUndistortCache::generation`] was this image last used?
+    last_use_generation: u64,
+}
+
+/// Where each pixel of an undistorted image comes from in the distorted one.
+///
+/// Only depends on the camera and the resolution of its images, so it is computed once and
+/// then used for all the images of the camera, which makes undistorting them a plain copy.
+struct UndistortMap {
+    /// For each pixel of the undistorted image, row by row, the index of the pixel of the
+    /// distorted image it shows, or [`Self::OUTSIDE`] if that is outside of the image.
+    source_pixels: Vec<u32>,
+
+    /// At which [`ImageUndistortCache::generation`] was this map last used?
+    last_use_generation: u64,
+}
+
+impl UndistortMap {
+    const OUTSIDE: u32 = u32::MAX;
+
+    fn new(pinhole: &Pinhole, [width, height]: [u32; 2]) -> Self {
+        re_tracing::profile_function!();
+
+        let source_pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                // Where does the ray through the center of this (undistorted) pixel hit the actual sensor?
+                let ray = pinhole.unproject(glam::vec3(x as f32 + 0.5, y as f32 + 0.5, 1.0));
+                let source = pinhole.project_distorted(ray);
+                if (0.0..width as f32).contains(&source.x)
+                    && (0.0..height as f32).contains(&source.y)
+                {
+                    source.y as u32 * width + source.x as u32
+                } else {
+                    Self::OUTSIDE
+                }
+            })
+            .collect();
+
+        Self {
+            source_pixels,
+            last_use_generation: 0,
+        }
+    }
+
+    fn memory_used(&self) -> u64 {
+        (self.source_pixels.len() * std::mem::size_of::<u32>()) as u64
+    }
+
+    /// Resamples the image so that it looks as if taken by the same camera without lens distortion.
+    ///
+    /// Uses nearest neighbor sampling, which works on the raw texels regardless of their type.
+    /// Pixels that map outside of the original image are left at zero.
+    fn resample(&self, image: &ImageInfo) -> ImageInfo {
+        re_tracing::profile_function!();
+
+        let bytes_per_texel = image.bits_per_texel() / 8;
+        let mut undistorted = vec![0_u8; self.source_pixels.len() * bytes_per_texel];
+
+        for (texel, &source_pixel) in undistorted
+            .chunks_exact_mut(bytes_per_texel)
+            .zip(&self.source_pixels)
+        {
+            if source_pixel == Self::OUTSIDE {
+                continue;
+            }
+            let source_offset = source_pixel as usize * bytes_per_texel;
+            if let Some(source_texel) = image
+                .blob
+                .get(source_offset..source_offset + bytes_per_texel)
+            {
+                texel.copy_from_slice(source_texel);
+            }
+        }
+
+        ImageInfo {
+            blob_row_id: RowId::new(),
+            blob: undistorted.into(),
+            ..image.clone()
+        }
+    }
+}
+
+/// Caches images with the lens distortion of their camera removed.
+#[derive(Default)]
+pub struct ImageUndistortCache {
+    cache: ahash::HashMap<u64, UndistortedImage>,
+
+    /// By camera and image resolution.
+    maps: ahash::HashMap<u64, UndistortMap>,
+
+    memory_used: u64,
+    generation: u64,
+}
+
+impl ImageUndistortCache {
+    /// Returns the image with the lens distortion of the given pinhole camera removed.
+    ///
+    /// Returns `None` if the camera has no lens distortion,
+    /// or the image can't be resampled (e.g. because it is chroma downsampled).
+    ///
+    /// The returned image has a [`RowId`] of its own,
+    /// so that it doesn't share a texture with the distorted original.
+    pub fn entry(&mut self, image: &ImageInfo, pinhole: &Pinhole) -> Option<ImageInfo> {
+        re_tracing::profile_function!();
+
+        let (model, coefficients) = pinhole.lens_distortion()?;
+        if image.pixel_format.is_some() {
+            return None;
+        }
+
+        let map_key = hash((
+            image.resolution,
+            model,
+            coefficients.0 .0.map(f32::to_bits),
+            pinhole.image_from_camera.0 .0.map(f32::to_bits),
+        ));
+        let key = hash((
+            image.blob_row_id,
+            image.data_type,
+            image.color_model,
+            map_key,
+        ));
+
+        let entry = self.cache.entry(key).or_insert_with(|| {
+            let map = self.maps.entry(map_key).or_insert_with(|| {
+                let map = UndistortMap::new(pinhole, image.resolution);
+                self.memory_used += map.memory_used();
+                map
+            });
+            map.last_use_generation = self.generation;
+
+            let image = map.resample(image);
+            self.memory_used += image.blob.len() as u64;
+            UndistortedImage {
+                image,
+                last_use_generation: 0,
+            }
+        });
+        entry.last_use_generation = self.generation;
+        Some(entry.image.clone())
+    }
+}
+
+impl Cache for ImageUndistortCache {
+    fn begin_frame(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        let max_cache_use = 1_000_000_000;
+
+        #[cfg(target_arch = "wasm32")]
+        let max_cache_use = 250_000_000;
+
+        if self.memory_used > max_cache_use {
+            self.purge_memory();
+        }
+
+        self.generation += 1;
+    }
+
+    fn purge_memory(&mut self) {
+        re_tracing::profile_function!();
+
+        // Flush everything not used in this frame.
+        self.cache.retain(|_, entry| {
+            let retain = entry.last_use_generation == self.generation;
+            if !retain {
+                self.memory_used -= entry.image.blob.len() as u64;
+            }
+            retain
+        });
+        self.maps.retain(|_, map| {
+            let retain = map.last_use_generation == self.generation;
+            if !retain {
+                self.memory_used -= map.memory_used();
+            }
+            retain
+        });
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::components::{ChannelDataType, ColorModel, DistortionModel};
+
+    use super::*;
+
+    fn gray_image(blob_row_id: RowId, resolution: [u32; 2]) -> ImageInfo {
+        let [width, height] = resolution;
+        ImageInfo {
+            blob_row_id,
+            blob: (0..width * height)
+                .map(|i| i as u8)
+                .collect::<Vec<_>>()
+                .into(),
+            resolution,
+            data_type: ChannelDataType::U8,
+            color_model: Some(ColorModel::L),
+            pixel_format: None,
+            colormap: None,
+        }
+    }
+
+    #[test]
+    fn one_map_per_camera() {
+        let resolution = [9, 7];
+        let pinhole = Pinhole::from_focal_length_and_resolution([10.0, 10.0], [9.0, 7.0])
+            .with_distortion_model(DistortionModel::BrownConrady)
+            .with_distortion_coefficients([0.1, 0.01, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+
+        let mut cache = ImageUndistortCache::default();
+        let first = gray_image(RowId::new(), resolution);
+        let second = gray_image(RowId::new(), resolution);
+        let first_undistorted = cache.entry(&first, &pinhole).unwrap();
+        let second_undistorted = cache.entry(&second, &pinhole).unwrap();
+        assert_eq!(cache.cache.len(), 2);
+        assert_eq!(cache.maps.len(), 1);
+
+        // Lens distortion leaves the principal point where it is.
+        let center = (3 * 9 + 4) as usize;
+        assert_eq!(first_undistorted.blob[center], first.blob[center]);
+        assert_eq!(second_undistorted.blob, first_undistorted.blob);
+
+        // Without distortion, there is nothing to undistort.
+        let undistorted_pinhole =
+            Pinhole::from_focal_length_and_resolution([10.0, 10.0], [9.0, 7.0]);
+        assert!(cache.entry(&first, &undistorted_pinhole).is_none());
+
+        // Unused images and maps are purged.
+        cache.begin_frame();
+        cache.purge_memory();
+        assert!(cache.cache.is_empty());
+        assert!(cache.maps.is_empty());
+        assert_eq!(cache.memory_used, 0);
+    }
+}
@@ -8,6 +8,7 @@
 mod contexts;
 mod eye;
 mod heuristics;
+mod image_undistort_cache;
 mod instance_hash_conversions;
 mod max_image_dimension_subscriber;
 mod mesh_cache;
@@ -84,11 +85,18 @@ fn query_pinhole(
 
     let image_plane_distance = Some(results.get_mono_with_fallback());
 
+    let distortion_model = results.get_mono();
+    let distortion_coefficients = results.get_mono();
+    let undistort_images = results.get_mono();
+
     Some(re_types::archetypes::Pinhole {
         image_from_camera,
         resolution,
         camera_xyz,
         image_plane_distance,
+        distortion_model,
+        distortion_coefficients,
+        undistort_images,
     })
 }
 
@@ -115,6 +123,15 @@ fn query_pinhole_legacy(
                 .latest_at_component(entity_path, query)
                 .map(|c| c.value),
             image_plane_distance: None,
+            distortion_model: entity_db
+                .latest_at_component(entity_path, query)
+                .map(|c| c.value),
+            distortion_coefficients: entity_db
+                .latest_at_component(entity_path, query)
+                .map(|c| c.value),
+            undistort_images: entity_db
+                .latest_at_component(entity_path, query)
+                .map(|c| c.value),
         })
 }
 
@@ -73,7 +73,13 @@ impl SpaceCamera3D {
         let point_in_image_unprojected =
             image_view_coordinates().from_other(&self.pinhole_view_coordinates) * point_in_cam;
 
-        let point_in_image = pinhole.project(point_in_image_unprojected);
+        // Unless the images of the camera are shown undistorted,
+        // the point should end up where the lens actually put it.
+        let point_in_image = if pinhole.undistorts_images() {
+            pinhole.project(point_in_image_unprojected)
+        } else {
+            pinhole.project_distorted(point_in_image_unprojected)
+        };
         Some(point_in_image)
     }
 }
@@ -18,7 +18,8 @@ use re_types::{
     archetypes::Pinhole,
     blueprint::components::VisualBounds2D,
     components::{
-        Blob, ChannelDataType, Colormap, DepthMeter, Resolution2D, TensorData, ViewCoordinates,
+        Blob, ChannelDataType, ColorModel, Colormap, DepthMeter, PixelFormat, Resolution2D,
+        TensorData, ViewCoordinates,
     },
     tensor_data::TensorDataMeaning,
     Loggable as _,
@@ -42,7 +43,7 @@ use crate::{
     view_kind::SpatialSpaceViewKind,
     visualizers::{
         CamerasVisualizer, DepthImageVisualizer, ImageEncodedVisualizer, ImageVisualizer,
-        SegmentationImageVisualizer, UiLabel, UiLabelTarget,
+        SegmentationImageVisualizer, UiLabel, UiLabelTarget, VideoFrameReferenceVisualizer,
     },
 };
 
@@ -113,6 +114,10 @@ impl SpatialSpaceViewState {
             view_systems.get::<ImageVisualizer>()?.images.len();
         self.num_non_segmentation_images_last_frame +=
             view_systems.get::<DepthImageVisualizer>()?.images.len();
+        self.num_non_segmentation_images_last_frame += view_systems
+            .get::<VideoFrameReferenceVisualizer>()?
+            .images
+            .len();
 
         Ok(())
     }
@@ -394,11 +399,13 @@ pub fn picking(
     let images = visualizers.get::<ImageVisualizer>()?;
     let images_encoded = visualizers.get::<ImageEncodedVisualizer>()?;
     let segmentation_images = visualizers.get::<SegmentationImageVisualizer>()?;
+    let video_frames = visualizers.get::<VideoFrameReferenceVisualizer>()?;
     let image_picking_rects = itertools::chain!(
         &depth_images.images,
         &images.images,
         &images_encoded.images,
         &segmentation_images.images,
+        &video_frames.images,
     );
 
     let picking_result = picking_context.pick(
@@ -641,7 +648,13 @@ fn picked_image_from_tensor_query(
         None,
         &view_ctx.viewer_ctx.current_query(),
         data_result,
-        [TensorData::name(), Colormap::name(), DepthMeter::name()],
+        [
+            TensorData::name(),
+            ColorModel::name(),
+            PixelFormat::name(),
+            Colormap::name(),
+            DepthMeter::name(),
+        ],
         query_shadowed_defaults,
     );
 
@@ -649,9 +662,30 @@ fn picked_image_from_tensor_query(
     // However, we're in the rare case where we really want a RowId to be able to identify the tensor for caching purposes.
     let tensor_untyped = results.get(TensorData::name())?;
     let tensor = tensor_untyped.mono::<TensorData>(&results.resolver)?;
+    let (_, row_id) = *tensor_untyped.index();
+
+    let color_model = results.get_mono::<ColorModel>();
+    let pixel_format = results.get_mono::<PixelFormat>();
+    if color_model.is_some() || pixel_format.is_some() {
+        // Show the decoded values, like the visualizer does.
+        let image = ImageInfo::from_tensor(row_id, &tensor.0, color_model, pixel_format)?;
+        let coordinates = hit
+            .instance_path_hash
+            .instance
+            .to_2d_image_coordinate(image.width() as _);
+
+        return Some(PickedImageInfo {
+            row_id,
+            tensor: None,
+            image: Some(image),
+            meaning,
+            coordinates,
+            colormap: results.get_mono_with_fallback::<Colormap>(),
+            depth_meter: results.get_mono::<DepthMeter>(),
+        });
+    }
 
     tensor.image_height_width_channels().map(|[_, w, _]| {
-        let (_, row_id) = *tensor_untyped.index();
         let coordinates = hit.instance_path_hash.instance.to_2d_image_coordinate(w);
 
         PickedImageInfo {
@@ -710,6 +744,7 @@ fn picked_image_from_image_query(
         resolution: resolution.0.into(),
         data_type,
         color_model: None,
+        pixel_format: None,
         colormap: Some(colormap),
     };
 
@@ -330,6 +330,9 @@ fn setup_target_config(
     if let Some(scene_pinhole) = scene_pinhole {
         // The user has a pinhole, and we may want to project 3D stuff into this 2D space,
         // and we want to use that pinhole projection to do so.
+        // The renderer only knows about ideal projections, so 3D points and line strips are
+        // distorted up front to match distorted images (see `ProjectedLensDistortion`).
+        // Other 3D content only lines up with images that are shown undistorted.
         pinhole = scene_pinhole.clone();
 
         resolution = pinhole.resolution().unwrap_or_else(|| {
@@ -355,6 +358,9 @@ fn setup_target_config(
             resolution: Some([resolution.x, resolution.y].into()),
             camera_xyz: Some(ViewCoordinates::RDF),
             image_plane_distance: None,
+            distortion_model: None,
+            distortion_coefficients: None,
+            undistort_images: None,
         };
     }
     let pinhole_rect = Rect::from_min_size(Pos2::ZERO, egui::vec2(resolution.x, resolution.y));
@@ -813,7 +813,13 @@ fn show_projections_from_2d_space(
                     } else {
                         cam.picture_plane_distance
                     };
-                    let stop_in_image_plane = pinhole.unproject(glam::vec3(pos.x, pos.y, depth));
+                    // Unless the image is shown undistorted, the hovered pixel is on the distorted image.
+                    let pos_in_image = glam::vec3(pos.x, pos.y, depth);
+                    let stop_in_image_plane = if pinhole.undistorts_images() {
+                        pinhole.unproject(pos_in_image)
+                    } else {
+                        pinhole.unproject_distorted(pos_in_image)
+                    };
 
                     let world_from_image = glam::Affine3A::from(cam.world_from_camera)
                         * glam::Affine3A::from_mat3(
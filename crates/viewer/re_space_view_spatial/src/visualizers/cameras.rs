@@ -22,6 +22,9 @@ use crate::{
 
 const CAMERA_COLOR: re_renderer::Color32 = re_renderer::Color32::from_rgb(150, 150, 150);
 
+/// Number of segments used for each edge of the image outline of a camera with lens distortion.
+const FRUSTUM_EDGE_STEPS_WITH_DISTORTION: usize = 16;
+
 pub struct CamerasVisualizer {
     pub data: SpatialViewVisualizerData,
     pub space_cameras: Vec<SpaceCamera3D>,
@@ -123,36 +126,53 @@ impl CamerasVisualizer {
         let h = resolution.y();
         let z = frustum_length;
 
+        // With lens distortion, the edges of the image are curves in camera space,
+        // so we follow them with a few segments each.
+        let unproject = |x: f32, y: f32| pinhole.unproject_distorted(vec3(x, y, z));
+        let steps_per_edge = if pinhole.lens_distortion().is_some() {
+            FRUSTUM_EDGE_STEPS_WITH_DISTORTION
+        } else {
+            1
+        };
+
         let corners = [
-            pinhole.unproject(vec3(0.0, 0.0, z)),
-            pinhole.unproject(vec3(0.0, h, z)),
-            pinhole.unproject(vec3(w, h, z)),
-            pinhole.unproject(vec3(w, 0.0, z)),
+            unproject(0.0, 0.0),
+            unproject(0.0, h),
+            unproject(w, h),
+            unproject(w, 0.0),
         ];
 
         let up_triangle = [
-            pinhole.unproject(vec3(0.4 * w, 0.0, z)),
-            pinhole.unproject(vec3(0.6 * w, 0.0, z)),
-            pinhole.unproject(vec3(0.5 * w, -0.1 * w, z)),
+            unproject(0.4 * w, 0.0),
+            unproject(0.6 * w, 0.0),
+            unproject(0.5 * w, -0.1 * w),
         ];
 
-        let segments = [
+        let mut segments = vec![
             // Frustum corners:
             (glam::Vec3::ZERO, corners[0]),
             (glam::Vec3::ZERO, corners[1]),
             (glam::Vec3::ZERO, corners[2]),
             (glam::Vec3::ZERO, corners[3]),
-            // Rectangle around "far plane":
-            (corners[0], corners[1]),
-            (corners[1], corners[2]),
-            (corners[2], corners[3]),
-            (corners[3], corners[0]),
             // Triangle indicating up direction:
             (up_triangle[0], up_triangle[1]),
             (up_triangle[1], up_triangle[2]),
             (up_triangle[2], up_triangle[0]),
         ];
 
+        // Outline of the image on the "far plane":
+        let image_corners = [[0.0, 0.0], [0.0, h], [w, h], [w, 0.0]];
+        for (i, [x0, y0]) in image_corners.iter().copied().enumerate() {
+            let [x1, y1] = image_corners[(i + 1) % image_corners.len()];
+            let mut previous = corners[i];
+            for step in 1..=steps_per_edge {
+                let t = step as f32 / steps_per_edge as f32;
+                let next = unproject(x0 + (x1 - x0) * t, y0 + (y1 - y0) * t);
+                segments.push((previous, next));
+                previous = next;
+            }
+        }
+
         let radius = re_renderer::Size::new_ui_points(1.0);
         let instance_path_for_picking =
             re_entity_db::InstancePathHash::instance(ent_path, instance);
@@ -18,6 +18,7 @@ use re_viewer_context::{
 
 use crate::{
     contexts::{SpatialSceneEntityContext, TransformContext},
+    image_undistort_cache::{undistort_image, undistorting_pinhole},
     query_pinhole_legacy,
     view_kind::SpatialSpaceViewKind,
     visualizers::{filter_visualizable_2d_entities, SIZE_BOOST_IN_POINTS_FOR_POINT_OUTLINES},
@@ -62,6 +63,12 @@ impl DepthImageVisualizer {
 
         let entity_path = ctx.target_entity_path;
         let meaning = TensorDataMeaning::Depth;
+        let undistorting_pinhole = if is_3d_view {
+            // Depth clouds are projected with the pinhole, so there is no need to undistort the image.
+            None
+        } else {
+            undistorting_pinhole(ctx)
+        };
 
         for data in images {
             let DepthImageComponentData {
@@ -109,6 +116,10 @@ impl DepthImageVisualizer {
                 };
             }
 
+            if let Some(pinhole) = &undistorting_pinhole {
+                image = undistort_image(ctx, pinhole, image);
+            }
+
             if let Some(textured_rect) = textured_rect_from_image(
                 ctx.viewer_ctx,
                 entity_path,
@@ -305,6 +316,7 @@ impl VisualizerSystem for DepthImageVisualizer {
                                 blob: blob.0.clone(),
                                 resolution: first_copied(resolution)?.0 .0,
                                 color_model: None,
+                                pixel_format: None,
                                 data_type: first_copied(data_type)?,
                                 colormap: first_copied(colormap),
                             },
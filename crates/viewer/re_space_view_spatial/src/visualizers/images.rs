@@ -2,28 +2,31 @@ use itertools::Itertools as _;
 
 use re_chunk_store::{ChunkStoreEvent, RowId};
 use re_log_types::TimeInt;
-use re_query::range_zip_1x1;
+use re_query::range_zip_1x3;
 use re_space_view::{diff_component_filter, HybridResults};
 use re_types::{
     archetypes::Image,
-    components::{DrawOrder, Opacity, TensorData},
+    components::{ColorModel, DrawOrder, Opacity, PixelFormat, TensorData},
     tensor_data::TensorDataMeaning,
 };
 use re_viewer_context::{
-    ApplicableEntities, IdentifiedViewSystem, QueryContext, SpaceViewClass,
+    ApplicableEntities, IdentifiedViewSystem, ImageInfo, QueryContext, SpaceViewClass,
     SpaceViewSystemExecutionError, TypedComponentFallbackProvider, ViewContext,
     ViewContextCollection, ViewQuery, VisualizableEntities, VisualizableFilterContext,
     VisualizerAdditionalApplicabilityFilter, VisualizerQueryInfo, VisualizerSystem,
 };
 
 use crate::{
-    contexts::SpatialSceneEntityContext, view_kind::SpatialSpaceViewKind,
-    visualizers::filter_visualizable_2d_entities, PickableImageRect, SpatialSpaceView2D,
+    contexts::SpatialSceneEntityContext,
+    image_undistort_cache::{undistort_image, undistorting_pinhole},
+    view_kind::SpatialSpaceViewKind,
+    visualizers::filter_visualizable_2d_entities,
+    PickableImageRect, SpatialSpaceView2D,
 };
 
 use super::{
-    bounding_box_for_textured_rect, entity_iterator::process_archetype, textured_rect_from_tensor,
-    SpatialViewVisualizerData,
+    bounding_box_for_textured_rect, entity_iterator::process_archetype, textured_rect_from_image,
+    textured_rect_from_tensor, SpatialViewVisualizerData,
 };
 
 pub struct ImageVisualizer {
@@ -44,6 +47,8 @@ struct ImageComponentData<'a> {
     index: (TimeInt, RowId),
 
     tensor: &'a TensorData,
+    color_model: Option<ColorModel>,
+    pixel_format: Option<PixelFormat>,
     opacity: Option<&'a Opacity>,
 }
 
@@ -162,29 +167,39 @@ impl ImageVisualizer {
             _ => return Ok(()),
         };
 
+        let color_models = results.get_or_empty_dense(resolver)?;
+        let pixel_formats = results.get_or_empty_dense(resolver)?;
         let opacity = results.get_or_empty_dense(resolver)?;
 
-        let data = range_zip_1x1(tensors.range_indexed(), opacity.range_indexed()).filter_map(
-            |(&index, tensors, opacity)| {
-                tensors.first().map(|tensor| ImageComponentData {
-                    index,
-                    tensor,
-                    opacity: opacity.and_then(|opacity| opacity.first()),
-                })
-            },
-        );
+        let data = range_zip_1x3(
+            tensors.range_indexed(),
+            color_models.range_indexed(),
+            pixel_formats.range_indexed(),
+            opacity.range_indexed(),
+        )
+        .filter_map(|(&index, tensors, color_models, pixel_formats, opacity)| {
+            tensors.first().map(|tensor| ImageComponentData {
+                index,
+                tensor,
+                color_model: color_models.and_then(|color_models| color_models.first().copied()),
+                pixel_format: pixel_formats
+                    .and_then(|pixel_formats| pixel_formats.first().copied()),
+                opacity: opacity.and_then(|opacity| opacity.first()),
+            })
+        });
 
         // Unknown is currently interpreted as "Some Color" in most cases.
         // TODO(jleibs): Make this more explicit
         let meaning = TensorDataMeaning::Unknown;
 
+        let undistorting_pinhole = undistorting_pinhole(ctx);
+
         for data in data {
             if !data.tensor.is_shaped_like_an_image() {
                 continue;
             }
 
             let tensor_data_row_id = data.index.1;
-            let tensor = data.tensor.0.clone();
 
             // TODO(andreas): We only support colormap for depth image at this point.
             let colormap = None;
@@ -196,16 +211,59 @@ impl ImageVisualizer {
             let multiplicative_tint =
                 re_renderer::Rgba::from_white_alpha(opacity.0.clamp(0.0, 1.0));
 
-            if let Some(textured_rect) = textured_rect_from_tensor(
-                ctx.viewer_ctx,
-                entity_path,
-                spatial_ctx,
-                tensor_data_row_id,
-                &tensor,
-                meaning,
-                multiplicative_tint,
-                colormap,
-            ) {
+            // Images with an explicit color model or pixel format go through the same path
+            // as the blob-based images, which knows how to convert them to RGB.
+            // The same goes for images that need to be undistorted.
+            let (textured_rect, tensor, image) = if data.color_model.is_none()
+                && data.pixel_format.is_none()
+                && undistorting_pinhole.is_none()
+            {
+                let tensor = data.tensor.0.clone();
+                let textured_rect = textured_rect_from_tensor(
+                    ctx.viewer_ctx,
+                    entity_path,
+                    spatial_ctx,
+                    tensor_data_row_id,
+                    &tensor,
+                    meaning,
+                    multiplicative_tint,
+                    colormap,
+                );
+                (textured_rect, Some(tensor), None)
+            } else {
+                let color_model = data.color_model.or_else(|| {
+                    if data.pixel_format.is_some() {
+                        None
+                    } else {
+                        color_model_from_tensor(data.tensor)
+                    }
+                });
+                let Some(mut image) = ImageInfo::from_tensor(
+                    tensor_data_row_id,
+                    &data.tensor.0,
+                    color_model,
+                    data.pixel_format,
+                ) else {
+                    re_log::warn_once!(
+                            "The shape of the image at {entity_path} does not match its color model or pixel format"
+                        );
+                    continue;
+                };
+                if let Some(pinhole) = &undistorting_pinhole {
+                    image = undistort_image(ctx, pinhole, image);
+                }
+                let textured_rect = textured_rect_from_image(
+                    ctx.viewer_ctx,
+                    entity_path,
+                    spatial_ctx,
+                    &image,
+                    meaning,
+                    multiplicative_tint,
+                );
+                (textured_rect, None, Some(image))
+            };
+
+            if let Some(textured_rect) = textured_rect {
                 // Only update the bounding box if this is a 2D space view.
                 // This is avoids a cyclic relationship where the image plane grows
                 // the bounds which in turn influence the size of the image plane.
@@ -224,8 +282,8 @@ impl ImageVisualizer {
                     textured_rect,
                     meaning: TensorDataMeaning::Unknown,
                     depth_meter: None,
-                    tensor: Some(tensor),
-                    image: None,
+                    tensor,
+                    image,
                 });
             }
         }
@@ -234,6 +292,17 @@ impl ImageVisualizer {
     }
 }
 
+/// Guesses the color model of an image tensor from its number of channels.
+fn color_model_from_tensor(tensor: &TensorData) -> Option<ColorModel> {
+    let [_height, _width, channels] = tensor.image_height_width_channels()?;
+    match channels {
+        1 => Some(ColorModel::L),
+        3 => Some(ColorModel::Rgb),
+        4 => Some(ColorModel::Rgba),
+        _ => None,
+    }
+}
+
 impl TypedComponentFallbackProvider<Opacity> for ImageVisualizer {
     fn fallback_for(&self, _ctx: &re_viewer_context::QueryContext<'_>) -> Opacity {
         1.0.into()
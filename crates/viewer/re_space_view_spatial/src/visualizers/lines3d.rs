@@ -1,3 +1,6 @@
+use std::borrow::Cow;
+
+use itertools::Itertools as _;
 use re_log_types::Instance;
 use re_query::range_zip_1x5;
 use re_renderer::PickingLayerInstanceId;
@@ -78,14 +81,30 @@ impl Lines3DVisualizer {
                 .outline_mask_ids(ent_context.highlight.overall)
                 .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()));
 
+            // Strips projected into a camera with lens distortion are bent to match its images.
+            let strips = data
+                .strips
+                .iter()
+                .map(|strip| {
+                    let vertices: &[glam::Vec3] = bytemuck::cast_slice(strip.0.as_slice());
+                    match ent_context.lens_distortion {
+                        Some(lens_distortion) => Cow::Owned(
+                            lens_distortion
+                                .distort_entity_strip(ent_context.world_from_entity, vertices),
+                        ),
+                        None => Cow::Borrowed(vertices),
+                    }
+                })
+                .collect_vec();
+
             let mut obj_space_bounding_box = re_math::BoundingBox::NOTHING;
 
             let mut num_rendered_strips = 0usize;
             for (i, (strip, radius, &color)) in
-                itertools::izip!(data.strips, radii, &colors).enumerate()
+                itertools::izip!(&strips, radii, &colors).enumerate()
             {
                 let lines = line_batch
-                    .add_strip(strip.0.iter().copied().map(Into::into))
+                    .add_strip(strip.iter().copied())
                     .color(color)
                     .radius(radius)
                     .picking_instance_id(PickingLayerInstanceId(i as _));
@@ -98,8 +117,8 @@ impl Lines3DVisualizer {
                     lines.outline_mask_ids(*outline_mask_ids);
                 }
 
-                for p in &strip.0 {
-                    obj_space_bounding_box.extend((*p).into());
+                for p in strip.iter() {
+                    obj_space_bounding_box.extend(*p);
                 }
 
                 num_rendered_strips += 1;
@@ -119,14 +138,8 @@ impl Lines3DVisualizer {
                     itertools::Either::Left(std::iter::once(obj_space_bounding_box.center()))
                 } else {
                     // Take middle point of every strip.
-                    itertools::Either::Right(data.strips.iter().map(|strip| {
-                        strip
-                            .0
-                            .iter()
-                            .copied()
-                            .map(glam::Vec3::from)
-                            .sum::<glam::Vec3>()
-                            / (strip.0.len() as f32)
+                    itertools::Either::Right(strips.iter().map(|strip| {
+                        strip.iter().copied().sum::<glam::Vec3>() / (strip.len() as f32)
                     }))
                 };
 
@@ -18,6 +18,7 @@ mod points3d;
 mod segmentation_images;
 mod transform3d_arrows;
 mod utilities;
+mod videos;
 
 pub use cameras::CamerasVisualizer;
 pub use depth_images::DepthImageVisualizer;
@@ -30,6 +31,7 @@ pub use utilities::{
     textured_rect_from_image, textured_rect_from_tensor, SpatialViewVisualizerData, UiLabel,
     UiLabelTarget, MAX_NUM_LABELS_PER_ENTITY,
 };
+pub use videos::VideoFrameReferenceVisualizer;
 
 // ---
 
@@ -86,6 +88,7 @@ pub fn register_2d_spatial_visualizers(
     system_registry.register_visualizer::<segmentation_images::SegmentationImageVisualizer>()?;
     system_registry.register_visualizer::<transform3d_arrows::AxisLengthDetector>()?;
     system_registry.register_visualizer::<transform3d_arrows::Transform3DArrowsVisualizer>()?;
+    system_registry.register_visualizer::<videos::VideoFrameReferenceVisualizer>()?;
     Ok(())
 }
 
@@ -110,6 +113,7 @@ pub fn register_3d_spatial_visualizers(
     system_registry.register_visualizer::<ellipsoids::EllipsoidsVisualizer>()?;
     system_registry.register_visualizer::<transform3d_arrows::AxisLengthDetector>()?;
     system_registry.register_visualizer::<transform3d_arrows::Transform3DArrowsVisualizer>()?;
+    system_registry.register_visualizer::<videos::VideoFrameReferenceVisualizer>()?;
     Ok(())
 }
 
@@ -124,6 +128,7 @@ pub fn visualizers_processing_draw_order() -> impl Iterator<Item = ViewSystemIde
         lines2d::Lines2DVisualizer::identifier(),
         points2d::Points2DVisualizer::identifier(),
         segmentation_images::SegmentationImageVisualizer::identifier(),
+        videos::VideoFrameReferenceVisualizer::identifier(),
     ]
     .into_iter()
 }
@@ -77,17 +77,26 @@ impl Points3DVisualizer {
                 .map(|i| PickingLayerInstanceId(i as _))
                 .collect_vec();
 
+            // Points projected into a camera with lens distortion are moved to where they end up on its images.
+            let distorted_positions = ent_context.lens_distortion.map(|lens_distortion| {
+                lens_distortion.distort_entity_points(
+                    ent_context.world_from_entity,
+                    data.positions.iter().map(|p| p.0.into()),
+                )
+            });
+            let positions: &[glam::Vec3] = distorted_positions
+                .as_deref()
+                .unwrap_or_else(|| bytemuck::cast_slice(data.positions));
+
             let (annotation_infos, keypoints) = process_annotation_and_keypoint_slices(
                 query.latest_at,
                 num_instances,
-                data.positions.iter().map(|p| p.0.into()),
+                positions.iter().copied(),
                 data.keypoint_ids,
                 data.class_ids,
                 &ent_context.annotations,
             );
 
-            let positions = bytemuck::cast_slice(data.positions);
-
             // Has not custom fallback for radius, so we use the default.
             // TODO(andreas): It would be nice to have this handle this fallback as part of the query.
             let radii =
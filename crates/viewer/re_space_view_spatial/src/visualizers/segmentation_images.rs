@@ -13,6 +13,7 @@ use re_viewer_context::{
 };
 
 use crate::{
+    image_undistort_cache::{undistort_image, undistorting_pinhole},
     ui::SpatialSpaceViewState,
     view_kind::SpatialSpaceViewKind,
     visualizers::{filter_visualizable_2d_entities, textured_rect_from_image},
@@ -114,6 +115,7 @@ impl VisualizerSystem for SegmentationImageVisualizer {
                             blob: blob.0.clone(),
                             resolution: first_copied(resolution)?.0 .0,
                             color_model: None,
+                            pixel_format: None,
                             data_type: first_copied(data_type)?,
                             colormap: None,
                         },
@@ -122,9 +124,14 @@ impl VisualizerSystem for SegmentationImageVisualizer {
                 });
 
                 let meaning = TensorDataMeaning::ClassId;
+                let undistorting_pinhole = undistorting_pinhole(ctx);
 
                 for data in data {
-                    let SegmentationImageComponentData { image, opacity } = data;
+                    let SegmentationImageComponentData { mut image, opacity } = data;
+
+                    if let Some(pinhole) = &undistorting_pinhole {
+                        image = undistort_image(ctx, pinhole, image);
+                    }
 
                     let opacity = opacity.unwrap_or_else(|| self.fallback_for(ctx));
                     let multiplicative_tint =
@@ -161,14 +161,20 @@ where
 
     for data_result in query.iter_visible_data_results(ctx, system_identifier) {
         // The transform that considers pinholes only makes sense if this is a 3D space-view
-        let world_from_entity =
+        let (world_from_entity, lens_distortion) =
             if view_ctx.space_view_class_identifier() == SpatialSpaceView3D::identifier() {
-                transforms.reference_from_entity(&data_result.entity_path)
+                (
+                    transforms.reference_from_entity(&data_result.entity_path),
+                    None,
+                )
             } else {
-                transforms.reference_from_entity_ignoring_pinhole(
-                    &data_result.entity_path,
-                    ctx.recording(),
-                    &latest_at,
+                (
+                    transforms.reference_from_entity_ignoring_pinhole(
+                        &data_result.entity_path,
+                        ctx.recording(),
+                        &latest_at,
+                    ),
+                    transforms.projected_lens_distortion(&data_result.entity_path),
                 )
             };
 
@@ -184,6 +190,7 @@ where
                 .copied()
                 .unwrap_or_default(),
             annotations: annotations.0.find(&data_result.entity_path),
+            lens_distortion,
             highlight: query
                 .highlights
                 .entity_outline_mask(data_result.entity_path.hash()),
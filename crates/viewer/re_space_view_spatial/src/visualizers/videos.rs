@@ -0,0 +1,214 @@
+use itertools::Itertools as _;
+
+use re_space_view::HybridResults;
+use re_types::{
+    archetypes::VideoFrameReference,
+    components::{Blob, DrawOrder, MediaType, VideoTimestamp},
+    tensor_data::TensorDataMeaning,
+};
+use re_viewer_context::{
+    ApplicableEntities, IdentifiedViewSystem, QueryContext, SpaceViewClass,
+    SpaceViewSystemExecutionError, TypedComponentFallbackProvider, VideoCache, ViewContext,
+    ViewContextCollection, ViewQuery, VisualizableEntities, VisualizableFilterContext,
+    VisualizerQueryInfo, VisualizerSystem,
+};
+
+use crate::{
+    contexts::SpatialSceneEntityContext, view_kind::SpatialSpaceViewKind,
+    visualizers::filter_visualizable_2d_entities, PickableImageRect, SpatialSpaceView2D,
+};
+
+use super::{
+    bounding_box_for_textured_rect, entity_iterator::process_archetype, textured_rect_from_image,
+    SpatialViewVisualizerData,
+};
+
+/// Shows the frames of `AssetVideo`s referenced by `VideoFrameReference`s.
+pub struct VideoFrameReferenceVisualizer {
+    pub data: SpatialViewVisualizerData,
+    pub images: Vec<PickableImageRect>,
+}
+
+impl Default for VideoFrameReferenceVisualizer {
+    fn default() -> Self {
+        Self {
+            data: SpatialViewVisualizerData::new(Some(SpatialSpaceViewKind::TwoD)),
+            images: Vec::new(),
+        }
+    }
+}
+
+impl IdentifiedViewSystem for VideoFrameReferenceVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "VideoFrameReference".into()
+    }
+}
+
+impl VisualizerSystem for VideoFrameReferenceVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<VideoFrameReference>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: ApplicableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_2d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, SpaceViewSystemExecutionError> {
+        let Some(render_ctx) = ctx.viewer_ctx.render_ctx else {
+            return Err(SpaceViewSystemExecutionError::NoRenderContextError);
+        };
+
+        process_archetype::<Self, VideoFrameReference, _>(
+            ctx,
+            view_query,
+            context_systems,
+            |ctx, spatial_ctx, results| self.process_video_frame(ctx, results, spatial_ctx),
+        )?;
+
+        // TODO(#702): draw order is translated to depth offset, which works fine for opaque images,
+        // but for everything with transparency, actual drawing order is still important.
+        self.images
+            .sort_by_key(|image| image.textured_rect.options.depth_offset);
+
+        let mut draw_data_list = Vec::new();
+
+        let rectangles = self
+            .images
+            .iter()
+            .map(|image| image.textured_rect.clone())
+            .collect_vec();
+        match re_renderer::renderer::RectangleDrawData::new(render_ctx, &rectangles) {
+            Ok(draw_data) => {
+                draw_data_list.push(draw_data.into());
+            }
+            Err(err) => {
+                re_log::error_once!(
+                    "Failed to create rectangle draw data from video frames: {err}"
+                );
+            }
+        }
+
+        Ok(draw_data_list)
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.data.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl VideoFrameReferenceVisualizer {
+    fn process_video_frame(
+        &mut self,
+        ctx: &QueryContext<'_>,
+        results: &HybridResults<'_>,
+        spatial_ctx: &SpatialSceneEntityContext<'_>,
+    ) -> Result<(), SpaceViewSystemExecutionError> {
+        use re_space_view::RangeResultsExt as _;
+
+        let resolver = ctx.recording().resolver();
+        let entity_path = ctx.target_entity_path;
+
+        let timestamps = match results.get_required_component_dense::<VideoTimestamp>(resolver) {
+            Some(timestamps) => timestamps?,
+            _ => return Ok(()),
+        };
+
+        // The video itself is usually logged once, as static data, on the same entity.
+        let Some(blob) = ctx
+            .recording()
+            .latest_at_component::<Blob>(entity_path, ctx.query)
+        else {
+            re_log::warn_once!(
+                "No AssetVideo found at path {entity_path} for its VideoFrameReference"
+            );
+            return Ok(());
+        };
+        let media_type = ctx
+            .recording()
+            .latest_at_component_quiet::<MediaType>(entity_path, ctx.query);
+
+        for (_index, timestamps) in timestamps.range_indexed() {
+            let Some(timestamp) = timestamps.first() else {
+                continue;
+            };
+
+            let image = ctx.viewer_ctx.cache.entry(|c: &mut VideoCache| {
+                c.frame_at(
+                    ctx.viewer_ctx.egui_ctx,
+                    blob.row_id(),
+                    &blob.value,
+                    media_type.as_ref().map(|media_type| &media_type.value),
+                    timestamp.as_nanoseconds(),
+                )
+            });
+
+            let image = match image {
+                Ok(Some(image)) => image,
+                Ok(None) => continue, // The first frame is still being decoded.
+                Err(err) => {
+                    re_log::warn_once!("Failed to show video frame at path {entity_path}: {err}");
+                    continue;
+                }
+            };
+
+            if let Some(textured_rect) = textured_rect_from_image(
+                ctx.viewer_ctx,
+                entity_path,
+                spatial_ctx,
+                &image,
+                TensorDataMeaning::Unknown,
+                re_renderer::Rgba::WHITE,
+            ) {
+                // Only update the bounding box if this is a 2D space view.
+                // This is avoids a cyclic relationship where the image plane grows
+                // the bounds which in turn influence the size of the image plane.
+                // See: https://github.com/rerun-io/rerun/issues/3728
+                if spatial_ctx.space_view_class_identifier == SpatialSpaceView2D::identifier() {
+                    self.data.add_bounding_box(
+                        entity_path.hash(),
+                        bounding_box_for_textured_rect(&textured_rect),
+                        spatial_ctx.world_from_entity,
+                    );
+                }
+
+                self.images.push(PickableImageRect {
+                    ent_path: entity_path.clone(),
+                    row_id: image.blob_row_id,
+                    textured_rect,
+                    meaning: TensorDataMeaning::Unknown,
+                    depth_meter: None,
+                    tensor: None,
+                    image: Some(image),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TypedComponentFallbackProvider<DrawOrder> for VideoFrameReferenceVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> DrawOrder {
+        DrawOrder::DEFAULT_IMAGE
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(VideoFrameReferenceVisualizer => [DrawOrder]);
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use re_build_info::CrateVersion;
 use re_data_source::{DataSource, FileContents};
 use re_entity_db::entity_db::EntityDb;
-use re_log_types::{ApplicationId, FileSource, LogMsg, StoreKind};
+use re_log_types::{ApplicationId, FileSource, LogMsg, StoreKind, ViewerCommand};
 use re_renderer::WgpuResourcePoolStatistics;
 use re_smart_channel::{ReceiveSet, SmartChannelSource};
 use re_ui::{toasts, DesignTokens, UICommand, UICommandSender};
@@ -148,6 +148,12 @@ pub struct App {
     pub(crate) egui_ctx: egui::Context,
     screenshotter: crate::screenshotter::Screenshotter,
 
+    /// Sends what the user does to the SDKs that asked for it.
+    viewer_events: crate::viewer_events::ViewerEventSender,
+
+    /// The SDK clients of our TCP server that we can answer, e.g. with screenshots.
+    viewer_clients: re_sdk_comms::ViewerClients,
+
     #[cfg(target_arch = "wasm32")]
     pub(crate) popstate_listener: Option<crate::history::PopstateListener>,
 
@@ -161,6 +167,14 @@ pub struct App {
 
     rx: ReceiveSet<LogMsg>,
 
+    /// Large `.rrd` files that are being loaded lazily, see [`re_entity_db::rrd_chunk_source`].
+    ///
+    /// The recordings loaded from these files read their chunks back from them on demand.
+    /// Kept until the file has been streamed, since the streaming thread may be done with it
+    /// before we get to the first message of a recording.
+    #[cfg(not(target_arch = "wasm32"))]
+    lazy_rrd_sources: ahash::HashMap<std::path::PathBuf, Arc<re_entity_db::RrdChunkSource>>,
+
     #[cfg(target_arch = "wasm32")]
     open_files_promise: Option<poll_promise::Promise<Vec<re_data_source::FileContents>>>,
 
@@ -295,6 +309,8 @@ impl App {
             ram_limit_warner: re_memory::RamLimitWarner::warn_at_fraction_of_max(0.75),
             egui_ctx,
             screenshotter,
+            viewer_events: Default::default(),
+            viewer_clients: Default::default(),
 
             #[cfg(target_arch = "wasm32")]
             popstate_listener: None,
@@ -305,6 +321,8 @@ impl App {
             text_log_rx,
             component_ui_registry,
             rx: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            lazy_rrd_sources: Default::default(),
             #[cfg(target_arch = "wasm32")]
             open_files_promise: Default::default(),
             state,
@@ -372,6 +390,43 @@ impl App {
         self.rx.add(rx);
     }
 
+    /// Lets us answer the SDK clients of a TCP server, see
+    /// [`re_sdk_comms::serve_with_viewer_clients`].
+    pub fn set_viewer_clients(&mut self, viewer_clients: re_sdk_comms::ViewerClients) {
+        self.viewer_clients = viewer_clients;
+    }
+
+    /// Streams a large `.rrd` file, keeping only the metadata of its chunks in memory.
+    ///
+    /// See [`re_entity_db::rrd_chunk_source::stream_rrd_lazily`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_rrd_lazily(&mut self, path: &std::path::Path) {
+        match re_entity_db::rrd_chunk_source::stream_rrd_lazily(path) {
+            Ok((chunk_source, rx)) => {
+                re_log::info!("Loading {path:?} lazily");
+                self.lazy_rrd_sources.insert(path.to_owned(), chunk_source);
+                self.add_receiver(rx);
+                self.forget_disconnected_lazy_rrd_sources();
+            }
+            Err(err) => {
+                re_log::error!("Failed to open {path:?}: {}", re_error::format(err));
+            }
+        }
+    }
+
+    /// Drops the chunk sources of the files we no longer receive from.
+    ///
+    /// The recordings loaded from them keep their own reference.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn forget_disconnected_lazy_rrd_sources(&mut self) {
+        let sources = self.rx.sources();
+        self.lazy_rrd_sources.retain(|path, _| {
+            sources
+                .iter()
+                .any(|source| matches!(&**source, SmartChannelSource::File(p) if p == path))
+        });
+    }
+
     pub fn msg_receive_set(&self) -> &ReceiveSet<LogMsg> {
         &self.rx
     }
@@ -448,11 +503,15 @@ impl App {
                     | SmartChannelSource::TcpServer { .. }
                     | SmartChannelSource::Stdin => true,
                 });
+                #[cfg(not(target_arch = "wasm32"))]
+                self.forget_disconnected_lazy_rrd_sources();
             }
 
             SystemCommand::ClearSourceAndItsStores(source) => {
                 self.rx.retain(|r| r.source() != &source);
                 store_hub.retain(|db| db.data_source.as_ref() != Some(&source));
+                #[cfg(not(target_arch = "wasm32"))]
+                self.forget_disconnected_lazy_rrd_sources();
             }
 
             SystemCommand::AddReceiver(rx) => {
@@ -461,6 +520,14 @@ impl App {
             }
 
             SystemCommand::LoadDataSource(data_source) => {
+                #[cfg(not(target_arch = "wasm32"))]
+                if let DataSource::FilePath(_, path) = &data_source {
+                    if re_entity_db::rrd_chunk_source::should_load_lazily(path) {
+                        self.load_rrd_lazily(path);
+                        return;
+                    }
+                }
+
                 let egui_ctx = egui_ctx.clone();
                 // On native, `add_receiver` spawns a thread that wakes up the ui thread
                 // on any new message. On web we cannot spawn threads, so instead we need
@@ -1021,6 +1088,7 @@ impl App {
         let start = web_time::Instant::now();
 
         while let Some((channel_source, msg)) = self.rx.try_recv() {
+            let msg_source = msg.source.clone();
             let msg = match msg.payload {
                 re_smart_channel::SmartMessagePayload::Msg(msg) => msg,
 
@@ -1030,6 +1098,12 @@ impl App {
                 }
 
                 re_smart_channel::SmartMessagePayload::Quit(err) => {
+                    // All the messages of the file have been handled.
+                    #[cfg(not(target_arch = "wasm32"))]
+                    if let SmartChannelSource::File(path) = &*channel_source {
+                        self.lazy_rrd_sources.remove(path);
+                    }
+
                     if let Some(err) = err {
                         let log_msg =
                             format!("Data source {} has left unexpectedly: {err}", msg.source);
@@ -1067,6 +1141,15 @@ impl App {
 
             if entity_db.data_source.is_none() {
                 entity_db.data_source = Some((*channel_source).clone());
+
+                #[cfg(not(target_arch = "wasm32"))]
+                if let SmartChannelSource::File(path) = &*channel_source {
+                    if let Some(chunk_source) = self.lazy_rrd_sources.get(path) {
+                        if store_id.kind == StoreKind::Recording {
+                            entity_db.set_chunk_source(chunk_source.clone());
+                        }
+                    }
+                }
             }
 
             if let Err(err) = entity_db.add(&msg) {
@@ -1108,6 +1191,10 @@ impl App {
                     // Handled by `EntityDb::add`
                 }
 
+                LogMsg::ViewerCommand(_, cmd) => {
+                    self.handle_viewer_command(entity_db, cmd, &msg_source, egui_ctx);
+                }
+
                 LogMsg::BlueprintActivationCommand(cmd) => match store_id.kind {
                     StoreKind::Recording => {
                         re_log::debug!(
@@ -1167,6 +1254,88 @@ impl App {
         }
     }
 
+    /// Applies a [`ViewerCommand`] that came with the data of `entity_db`.
+    fn handle_viewer_command(
+        &mut self,
+        entity_db: &EntityDb,
+        cmd: &ViewerCommand,
+        msg_source: &re_smart_channel::SmartMessageSource,
+        egui_ctx: &egui::Context,
+    ) {
+        re_log::debug!(
+            "Received viewer command for {}: {cmd:?}",
+            entity_db.store_id()
+        );
+
+        match cmd {
+            ViewerCommand::SetTime { timeline, time } => {
+                let rec_cfg = self.state.recording_config_or_insert(entity_db);
+                let mut time_ctrl = rec_cfg.time_ctrl.write();
+                if let Some(time) = time {
+                    time_ctrl.set_timeline_and_time(*timeline, *time);
+                } else {
+                    time_ctrl.set_timeline(*timeline);
+                }
+            }
+
+            ViewerCommand::SetPlaying(playing) => {
+                let rec_cfg = self.state.recording_config_or_insert(entity_db);
+                let mut time_ctrl = rec_cfg.time_ctrl.write();
+                if *playing {
+                    time_ctrl.set_play_state(entity_db.times_per_timeline(), PlayState::Playing);
+                } else {
+                    time_ctrl.pause();
+                }
+            }
+
+            ViewerCommand::Select(Some((entity_path, instance))) => {
+                let instance_path =
+                    re_entity_db::InstancePath::instance(entity_path.clone(), *instance);
+                self.state
+                    .selection_state
+                    .set_selection(re_viewer_context::Item::InstancePath(instance_path));
+            }
+
+            ViewerCommand::Select(None) => {
+                self.state.selection_state.clear_selection();
+            }
+
+            ViewerCommand::FocusSpaceView(id) => {
+                let item = re_viewer_context::Item::SpaceView((*id).into());
+                self.state.selection_state.set_selection(item.clone());
+                self.state.focused_item = Some(item);
+            }
+
+            ViewerCommand::SetEventsEnabled(enabled) => {
+                if let re_smart_channel::SmartMessageSource::TcpClient { addr: Some(addr) } =
+                    msg_source
+                {
+                    self.viewer_events
+                        .set_enabled(entity_db.store_id(), *addr, *enabled);
+                } else {
+                    re_log::warn_once!("Viewer events can only be sent to SDKs connected over TCP");
+                }
+            }
+
+            ViewerCommand::Screenshot { request_id } => {
+                #[cfg(not(target_arch = "wasm32"))]
+                if let re_smart_channel::SmartMessageSource::TcpClient { addr: Some(addr) } =
+                    msg_source
+                {
+                    self.screenshotter
+                        .request_screenshot_for_client(egui_ctx, *addr, *request_id);
+                    return;
+                }
+
+                re_log::warn_once!(
+                    "Screenshots can only be sent to SDKs connected over TCP to a native viewer"
+                );
+            }
+        }
+
+        egui_ctx.request_repaint();
+    }
+
     fn purge_memory_if_needed(&mut self, store_hub: &mut StoreHub) {
         re_tracing::profile_function!();
 
@@ -1612,6 +1781,12 @@ impl eframe::App for App {
             store_stats.as_ref(),
         );
 
+        self.state.send_viewer_events(
+            &mut self.viewer_events,
+            &self.viewer_clients,
+            store_context.as_ref(),
+        );
+
         if re_ui::CUSTOM_WINDOW_DECORATIONS {
             // Paint the main window frame on top of everything else
             paint_native_window_frame(egui_ctx);
@@ -1639,7 +1814,7 @@ impl eframe::App for App {
         egui_ctx.input(|i| {
             for event in &i.raw.events {
                 if let egui::Event::Screenshot { image, .. } = event {
-                    self.screenshotter.save(image);
+                    self.screenshotter.save(image, &self.viewer_clients);
                 }
             }
         });
@@ -1665,6 +1840,8 @@ fn populate_space_view_class_registry_with_builtin(
 ) -> Result<(), SpaceViewClassRegistryError> {
     re_tracing::profile_function!();
     space_view_class_registry.add_class::<re_space_view_bar_chart::BarChartSpaceView>()?;
+    space_view_class_registry.add_class::<re_space_view_graph::GraphSpaceView>()?;
+    space_view_class_registry.add_class::<re_space_view_map::MapSpaceView>()?;
     space_view_class_registry.add_class::<re_space_view_spatial::SpatialSpaceView2D>()?;
     space_view_class_registry.add_class::<re_space_view_spatial::SpatialSpaceView3D>()?;
     space_view_class_registry.add_class::<re_space_view_tensor::TensorSpaceView>()?;
@@ -118,6 +118,27 @@ impl AppState {
             .map(|q| (*time_ctrl.timeline(), q))
     }
 
+    /// Tells the SDKs that asked for it what the user does with the recording that is shown.
+    pub fn send_viewer_events(
+        &self,
+        sender: &mut crate::viewer_events::ViewerEventSender,
+        clients: &re_sdk_comms::ViewerClients,
+        store_context: Option<&StoreContext<'_>>,
+    ) {
+        let Some(store_context) = store_context else {
+            return;
+        };
+        let rec_id = store_context.recording.store_id();
+        if let Some(rec_cfg) = self.recording_configs.get(rec_id) {
+            sender.update(
+                rec_id,
+                &self.selection_state,
+                &rec_cfg.time_ctrl.read(),
+                |addr, msg| clients.send(addr, msg),
+            );
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn show(
         &mut self,
@@ -484,6 +505,15 @@ impl AppState {
         self.recording_configs.get_mut(rec_id)
     }
 
+    /// Like [`Self::recording_config_mut`], but creates the config if the recording was never shown.
+    pub fn recording_config_or_insert(&mut self, entity_db: &EntityDb) -> &mut RecordingConfig {
+        recording_config_entry(
+            &mut self.recording_configs,
+            entity_db.store_id().clone(),
+            entity_db,
+        )
+    }
+
     pub fn cleanup(&mut self, store_hub: &StoreHub) {
         re_tracing::profile_function!();
 
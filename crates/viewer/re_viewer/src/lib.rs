@@ -18,6 +18,7 @@ mod saving;
 mod screenshotter;
 mod ui;
 mod viewer_analytics;
+mod viewer_events;
 
 /// Auto-generated blueprint-related types.
 ///
@@ -135,7 +135,7 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
         (
             <QueryExpression as Loggable>::name(),
             ComponentReflection {
-                docstring_md: "An individual query expression used to filter a set of [`datatypes.EntityPath`](https://rerun.io/docs/reference/types/datatypes/entity_path)s.\n\nEach expression is either an inclusion or an exclusion expression.\nInclusions start with an optional `+` and exclusions must start with a `-`.\n\nMultiple expressions are combined together as part of `SpaceViewContents`.\n\nThe `/**` suffix matches the whole subtree, i.e. self and any child, recursively\n(`/world/**` matches both `/world` and `/world/car/driver`).\nOther uses of `*` are not (yet) supported.",
+                docstring_md: "An individual query expression used to filter a set of [`datatypes.EntityPath`](https://rerun.io/docs/reference/types/datatypes/entity_path)s.\n\nEach expression is either an inclusion or an exclusion expression.\nInclusions start with an optional `+` and exclusions must start with a `-`.\n\nMultiple expressions are combined together as part of `SpaceViewContents`.\n\nThe `/**` suffix matches the whole subtree, i.e. self and any child, recursively\n(`/world/**` matches both `/world` and `/world/car/driver`).\n`*` matches a single part, or any characters within a part (`/robot_*/camera` matches `/robot_1/camera`),\nand `**` in the middle of a path matches any number of parts (`/world/**/wheel` matches `/world/car/wheel`).",
                 placeholder: Some(QueryExpression::default().to_arrow()?),
             },
         ),
@@ -195,6 +195,13 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 placeholder: Some(TensorDimensionIndexSlider::default().to_arrow()?),
             },
         ),
+        (
+            <TileDirectory as Loggable>::name(),
+            ComponentReflection {
+                docstring_md: "A local directory containing map tiles.\n\nTiles are expected in the `{zoom}/{x}/{y}.png` layout of the common \"slippy map\" tile scheme,\nusing the Web Mercator projection.\nAn empty path means that no tiles are shown.",
+                placeholder: Some(TileDirectory::default().to_arrow()?),
+            },
+        ),
         (
             <ViewFit as Loggable>::name(),
             ComponentReflection {
@@ -303,7 +310,7 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
         (
             <ColorModel as Loggable>::name(),
             ComponentReflection {
-                docstring_md: "Specified what color components are present in an [`archetypes.Image`](https://rerun.io/docs/reference/types/archetypes/image).\n\nThis combined with [`components.ChannelDataType`](https://rerun.io/docs/reference/types/components/channel_data_type) determines the pixel format of an image.",
+                docstring_md: "Specified what color components are present in an [`archetypes.Image`](https://rerun.io/docs/reference/types/archetypes/image).\n\nThis combined with [`components.ChannelDataType`](https://rerun.io/docs/reference/types/components/channel_data_type) determines the pixel format of an image.\nFormats that cannot be described this way (e.g. chroma-downsampled or Bayer-mosaiced images)\nuse a [`components.PixelFormat`](https://rerun.io/docs/reference/types/components/pixel_format) instead.",
                 placeholder: Some(ColorModel::default().to_arrow()?),
             },
         ),
@@ -328,6 +335,20 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 placeholder: Some(DisconnectedSpace::default().to_arrow()?),
             },
         ),
+        (
+            <DistortionCoefficients as Loggable>::name(),
+            ComponentReflection {
+                docstring_md: "Coefficients of the lens distortion of a camera.\n\nStored in the same order as OpenCV uses them: `[k1, k2, p1, p2, k3, k4, k5, k6]`.\nHow they are interpreted depends on the [`components.DistortionModel`](https://rerun.io/docs/reference/types/components/distortion_model?speculative-link):\n- `BrownConrady`: `k1`, `k2`, `p1`, `p2`, `k3` (OpenCV's 5 coefficient model).\n- `KannalaBrandt`: the first four values are the fisheye coefficients `k1`, `k2`, `k3`, `k4`.\n- `Rational`: all eight coefficients (OpenCV's `CALIB_RATIONAL_MODEL`).\n\nUnused coefficients should be set to zero.",
+                placeholder: Some(DistortionCoefficients::default().to_arrow()?),
+            },
+        ),
+        (
+            <DistortionModel as Loggable>::name(),
+            ComponentReflection {
+                docstring_md: "The lens distortion model of a camera.\n\nThe model maps the undistorted, normalized image coordinates `(x/z, y/z)` of a point in camera space\nto where it actually ends up on the sensor, before the [`components.PinholeProjection`](https://rerun.io/docs/reference/types/components/pinhole_projection) is applied.\nIts parameters are given by [`components.DistortionCoefficients`](https://rerun.io/docs/reference/types/components/distortion_coefficients?speculative-link).",
+                placeholder: Some(DistortionModel::default().to_arrow()?),
+            },
+        ),
         (
             <DrawOrder as Loggable>::name(),
             ComponentReflection {
@@ -349,6 +370,34 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 placeholder: Some(GammaCorrection::default().to_arrow()?),
             },
         ),
+        (
+            <GeoLineString as Loggable>::name(),
+            ComponentReflection {
+                docstring_md: "A geospatial line string expressed in [EPSG:4326](https://epsg.io/4326) latitude and longitude (North/East-positive degrees).\n\nThe points are connected in order by straight lines in latitude/longitude space.",
+                placeholder: Some(GeoLineString::default().to_arrow()?),
+            },
+        ),
+        (
+            <GraphEdge as Loggable>::name(),
+            ComponentReflection {
+                docstring_md: "An edge in a graph connecting two nodes, given by their [`components.GraphNode`](https://rerun.io/docs/reference/types/components/graph_node?speculative-link) IDs.\n\nWhether the edge points from `first` to `second` depends on the [`components.GraphType`](https://rerun.io/docs/reference/types/components/graph_type?speculative-link).",
+                placeholder: Some(GraphEdge::default().to_arrow()?),
+            },
+        ),
+        (
+            <GraphNode as Loggable>::name(),
+            ComponentReflection {
+                docstring_md: "A string-based ID representing a node in a graph.\n\nNode IDs are unique within an entity, and are referenced by the [`components.GraphEdge`](https://rerun.io/docs/reference/types/components/graph_edge?speculative-link)s logged to the same entity.",
+                placeholder: Some(GraphNode::default().to_arrow()?),
+            },
+        ),
+        (
+            <GraphType as Loggable>::name(),
+            ComponentReflection {
+                docstring_md: "Specifies if a graph has directed or undirected edges.",
+                placeholder: Some(GraphType::default().to_arrow()?),
+            },
+        ),
         (
             <HalfSize2D as Loggable>::name(),
             ComponentReflection {
@@ -377,6 +426,13 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 placeholder: Some(KeypointId::default().to_arrow()?),
             },
         ),
+        (
+            <LatLon as Loggable>::name(),
+            ComponentReflection {
+                docstring_md: "A geospatial position expressed in [EPSG:4326](https://epsg.io/4326) latitude and longitude (North/East-positive degrees).\n\nThis is the coordinate system used by GPS receivers (WGS84).",
+                placeholder: Some(LatLon::default().to_arrow()?),
+            },
+        ),
         (
             <LineStrip2D as Loggable>::name(),
             ComponentReflection {
@@ -447,6 +503,13 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 placeholder: Some(PinholeProjection::default().to_arrow()?),
             },
         ),
+        (
+            <PixelFormat as Loggable>::name(),
+            ComponentReflection {
+                docstring_md: "Specifies the memory layout of an [`archetypes.Image`](https://rerun.io/docs/reference/types/archetypes/image) that cannot be described by a [`components.ColorModel`](https://rerun.io/docs/reference/types/components/color_model).\n\nMost images can be described by a [`components.ColorModel`](https://rerun.io/docs/reference/types/components/color_model) and a [`components.ChannelDataType`](https://rerun.io/docs/reference/types/components/channel_data_type),\ne.g. `RGB` and `U8` respectively.\nHowever, some image formats use chroma downsampling or a color filter mosaic,\nand that is what this [`components.PixelFormat`](https://rerun.io/docs/reference/types/components/pixel_format) is for.\n\nThe image data is then the raw buffer as it comes out of the camera:\n- `NV12`: a `(H*3/2)xW` tensor of `U8`.\n- `YUY2`: a `Hx(W*2)` tensor of `U8`.\n- Bayer formats: a `HxW` tensor of `U8` or `U16`.",
+                placeholder: Some(PixelFormat::default().to_arrow()?),
+            },
+        ),
         (
             <Position2D as Loggable>::name(),
             ComponentReflection {
@@ -594,6 +657,13 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 placeholder: Some(TriangleIndices::default().to_arrow()?),
             },
         ),
+        (
+            <UndistortImages as Loggable>::name(),
+            ComponentReflection {
+                docstring_md: "Whether to undistort the images of a camera when displaying them.\n\nUndistorted images line up with 3D content projected into the camera,\nbut are resampled and may lose a bit of sharpness.\n\nWhen images are shown distorted, 3D points and line strips projected into 2D views of the camera\nare distorted to match them. Other 3D content is projected without lens distortion.",
+                placeholder: Some(UndistortImages::default().to_arrow()?),
+            },
+        ),
         (
             <Vector2D as Loggable>::name(),
             ComponentReflection {
@@ -608,6 +678,13 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 placeholder: Some(Vector3D::default().to_arrow()?),
             },
         ),
+        (
+            <VideoTimestamp as Loggable>::name(),
+            ComponentReflection {
+                docstring_md: "A timestamp within an [`archetypes.AssetVideo`](https://rerun.io/docs/reference/types/archetypes/asset_video), in nanoseconds since its first frame.",
+                placeholder: Some(VideoTimestamp::default().to_arrow()?),
+            },
+        ),
         (
             <ViewCoordinates as Loggable>::name(),
             ComponentReflection {
@@ -677,6 +754,20 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.MapOptions"),
+            ArchetypeReflection {
+                display_name: "Map options",
+                docstring_md: "Configuration for the background of a map view.",
+                fields: vec![
+                    ArchetypeFieldReflection { component_name :
+                    "rerun.blueprint.components.TileDirectory".into(), display_name :
+                    "Tile directory", docstring_md :
+                    "Local directory the map tiles are loaded from.\n\nWithout tiles, only a graticule of latitude and longitude lines is shown.",
+                    },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.PlotLegend"),
             ArchetypeReflection {
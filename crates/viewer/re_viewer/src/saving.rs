@@ -72,6 +72,6 @@ pub fn encode_to_file<'a>(
         .with_context(|| format!("Failed to create file at {path:?}"))?;
 
     let encoding_options = re_log_encoding::EncodingOptions::COMPRESSED;
-    re_log_encoding::encoder::encode(version, encoding_options, messages, &mut file)
+    re_log_encoding::encoder::encode_with_index(version, encoding_options, messages, &mut file)
         .context("Message encode")
 }
@@ -2,6 +2,10 @@
 //! haven't implemented "copy image to clipboard" there.
 
 /// Helper for screenshotting the entire app
+///
+/// Screenshots for the user (`--screenshot-to`, or copying to the clipboard) are taken with the
+/// UI temporarily re-styled and enlarged, for consistent results across platforms. Screenshots
+/// requested by SDK clients show the viewer as it is.
 #[cfg(not(target_arch = "wasm32"))]
 #[derive(Default)]
 pub struct Screenshotter {
@@ -9,6 +13,15 @@ pub struct Screenshotter {
     target_path: Option<std::path::PathBuf>,
     quit: bool,
     pre_screenshot_zoom_factor: Option<f32>,
+
+    /// Copy the next re-styled screenshot to the clipboard.
+    copy_to_clipboard: bool,
+
+    /// Whether the UI was re-styled when we asked for the screenshot that is on its way.
+    in_flight_restyled: Option<bool>,
+
+    /// SDK clients waiting for the next screenshot, with the id of their request.
+    client_requests: Vec<(std::net::SocketAddr, u64)>,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -29,13 +42,43 @@ impl Screenshotter {
         path: std::path::PathBuf,
     ) {
         assert!(self.countdown.is_none(), "screenshotter misused");
-        self.request_screenshot(egui_ctx);
+        self.request_restyled_screenshot(egui_ctx);
         self.target_path = Some(path);
     }
 
+    /// Sends the next screenshot to an SDK client, see [`re_log_types::ViewerCommand::Screenshot`].
+    ///
+    /// Unlike the screenshots for the user, this doesn't touch the window or its style.
+    pub fn request_screenshot_for_client(
+        &mut self,
+        egui_ctx: &egui::Context,
+        addr: std::net::SocketAddr,
+        request_id: u64,
+    ) {
+        self.client_requests.push((addr, request_id));
+        if self.countdown.is_none() {
+            // Nothing to wait for.
+            self.countdown = Some(0);
+            egui_ctx.request_repaint();
+        }
+    }
+
+    /// Copies the next screenshot to the clipboard.
     pub fn request_screenshot(&mut self, egui_ctx: &egui::Context) {
-        // Give app time to change the style, and then wait for animations to finish:
-        self.countdown = Some(10);
+        self.request_restyled_screenshot(egui_ctx);
+        self.copy_to_clipboard = true;
+    }
+
+    fn request_restyled_screenshot(&mut self, egui_ctx: &egui::Context) {
+        if self.pre_screenshot_zoom_factor.is_some() {
+            return; // Already on it.
+        }
+
+        // Give app time to change the style, and then wait for animations to finish.
+        // If a screenshot for a client is already on its way, we take another one after it.
+        if self.countdown.map_or(true, |countdown| 0 <= countdown) {
+            self.countdown = Some(10);
+        }
 
         self.pre_screenshot_zoom_factor = Some(egui_ctx.zoom_factor());
 
@@ -58,6 +101,7 @@ impl Screenshotter {
                 // Obviously we want to send the command this command only once, so we keep counting down
                 // to negatives until we get a call to `save` which then disables the counter.
                 egui_ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot);
+                self.in_flight_restyled = Some(self.pre_screenshot_zoom_factor.is_some());
             }
             *countdown -= 1;
 
@@ -79,11 +123,28 @@ impl Screenshotter {
     /// We do the re-styling to create consistent screenshots across platforms.
     /// In particular, we style the UI to look like the web viewer.
     pub fn is_screenshotting(&self) -> bool {
-        self.countdown.is_some()
+        self.countdown.is_some() && self.pre_screenshot_zoom_factor.is_some()
     }
 
-    pub fn save(&mut self, image: &egui::ColorImage) {
+    /// Hands the screenshot to everyone waiting for it.
+    pub fn save(&mut self, image: &egui::ColorImage, clients: &re_sdk_comms::ViewerClients) {
+        if !self.client_requests.is_empty() {
+            self.send_to_clients(image, clients);
+        }
+
+        let is_restyled = self
+            .in_flight_restyled
+            .take()
+            .unwrap_or(self.pre_screenshot_zoom_factor.is_some());
+        let is_user_waiting = self.target_path.is_some() || self.copy_to_clipboard;
+
+        if is_user_waiting && !is_restyled {
+            // This one was for a client. The UI has been re-styled since, so take another.
+            self.countdown = Some(10);
+            return;
+        }
         self.countdown = None;
+
         if let Some(path) = self.target_path.take() {
             let w = image.width() as _;
             let h = image.height() as _;
@@ -99,12 +160,40 @@ impl Screenshotter {
                     panic!("Failed saving screenshot to {path:?}: {err}");
                 }
             }
-        } else {
+        }
+
+        if std::mem::take(&mut self.copy_to_clipboard) {
             re_viewer_context::Clipboard::with(|cb| {
                 cb.set_image(image.size, bytemuck::cast_slice(&image.pixels));
             });
         }
     }
+
+    fn send_to_clients(&mut self, image: &egui::ColorImage, clients: &re_sdk_comms::ViewerClients) {
+        let w = image.width() as _;
+        let h = image.height() as _;
+        let image = image::RgbaImage::from_raw(w, h, bytemuck::pod_collect_to_vec(&image.pixels))
+            .expect("Failed to create image");
+
+        let mut png = Vec::new();
+        if let Err(err) =
+            image.write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        {
+            re_log::error!("Failed to encode screenshot: {err}");
+            self.client_requests.clear();
+            return;
+        }
+
+        for (addr, request_id) in self.client_requests.drain(..) {
+            let msg = re_log_types::ViewerMessage::Screenshot {
+                request_id,
+                png: png.clone(),
+            };
+            if !clients.send(addr, msg) {
+                re_log::debug!("Client {addr} left before receiving its screenshot");
+            }
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -0,0 +1,401 @@
+//! Telling SDKs what the user does in the viewer, see [`ViewerCommand::SetEventsEnabled`].
+//!
+//! [`ViewerCommand::SetEventsEnabled`]: re_log_types::ViewerCommand::SetEventsEnabled
+
+use std::net::SocketAddr;
+
+use ahash::HashMap;
+
+use re_log_types::{EntityPath, Instance, StoreId, TimeInt, Timeline, ViewerEvent, ViewerMessage};
+use re_viewer_context::{ApplicationSelectionState, Item, ItemSpaceContext, TimeControl};
+
+/// The SDK clients that want events about one recording.
+#[derive(Default)]
+struct Subscription {
+    clients: Vec<SocketAddr>,
+
+    /// What we last told the clients. `None` until we've told them anything.
+    last_selection: Option<Vec<(EntityPath, Instance)>>,
+    last_hover: Option<Option<(EntityPath, Instance)>>,
+    last_time: Option<(Timeline, Option<TimeInt>)>,
+}
+
+/// Sends [`ViewerEvent`]s to the SDK clients that asked for them.
+#[derive(Default)]
+pub struct ViewerEventSender {
+    subscriptions: HashMap<StoreId, Subscription>,
+}
+
+impl ViewerEventSender {
+    pub fn set_enabled(&mut self, store_id: &StoreId, client: SocketAddr, enabled: bool) {
+        if enabled {
+            let subscription = self.subscriptions.entry(store_id.clone()).or_default();
+            if !subscription.clients.contains(&client) {
+                subscription.clients.push(client);
+            }
+        } else if let Some(subscription) = self.subscriptions.get_mut(store_id) {
+            subscription.clients.retain(|addr| *addr != client);
+            if subscription.clients.is_empty() {
+                self.subscriptions.remove(store_id);
+            }
+        }
+    }
+
+    /// Call once per frame with the state of the recording that is shown.
+    ///
+    /// `send_to_client` returns `false` once a client is gone, like
+    /// [`re_sdk_comms::ViewerClients::send`].
+    pub fn update(
+        &mut self,
+        store_id: &StoreId,
+        selection_state: &ApplicationSelectionState,
+        time_ctrl: &TimeControl,
+        mut send_to_client: impl FnMut(SocketAddr, ViewerMessage) -> bool,
+    ) {
+        re_tracing::profile_function!();
+
+        let Some(subscription) = self.subscriptions.get_mut(store_id) else {
+            return;
+        };
+
+        let mut events = Vec::new();
+
+        let selection: Vec<_> = selection_state
+            .selected_items()
+            .iter_items()
+            .filter_map(instance_of_item)
+            .collect();
+        if subscription.last_selection.as_ref() != Some(&selection) {
+            // Clicking in a spatial view selects with the position that was clicked on.
+            for (item, space_context) in selection_state.selected_items().iter() {
+                if let (Some((entity_path, instance)), Some(space_context)) =
+                    (instance_of_item(item), space_context)
+                {
+                    if let Some((space, position)) = picked_position(space_context) {
+                        events.push(ViewerEvent::Picked {
+                            entity_path,
+                            instance,
+                            space,
+                            position,
+                        });
+                    }
+                }
+            }
+            events.push(ViewerEvent::SelectionChanged(selection.clone()));
+            subscription.last_selection = Some(selection);
+        }
+
+        let hover = selection_state
+            .hovered_items()
+            .iter_items()
+            .find_map(instance_of_item);
+        if subscription.last_hover.as_ref() != Some(&hover) {
+            events.push(ViewerEvent::HoverChanged(hover.clone()));
+            subscription.last_hover = Some(hover);
+        }
+
+        let time = (*time_ctrl.timeline(), time_ctrl.time_int());
+        if subscription.last_time != Some(time) {
+            events.push(ViewerEvent::TimeChanged {
+                timeline: time.0,
+                time: time.1,
+            });
+            subscription.last_time = Some(time);
+        }
+
+        for event in events {
+            subscription.clients.retain(|addr| {
+                let msg = ViewerMessage::Event {
+                    store_id: store_id.clone(),
+                    event: event.clone(),
+                };
+                let is_connected = send_to_client(*addr, msg);
+                if !is_connected {
+                    re_log::debug!("No longer sending viewer events to {addr}");
+                }
+                is_connected
+            });
+        }
+
+        if subscription.clients.is_empty() {
+            self.subscriptions.remove(store_id);
+        }
+    }
+}
+
+fn instance_of_item(item: &Item) -> Option<(EntityPath, Instance)> {
+    match item {
+        Item::InstancePath(instance_path) | Item::DataResult(_, instance_path) => {
+            Some((instance_path.entity_path.clone(), instance_path.instance))
+        }
+        Item::AppId(_)
+        | Item::DataSource(_)
+        | Item::StoreId(_)
+        | Item::ComponentPath(_)
+        | Item::SpaceView(_)
+        | Item::Container(_) => None,
+    }
+}
+
+fn picked_position(space_context: &ItemSpaceContext) -> Option<(EntityPath, [f32; 3])> {
+    match space_context {
+        ItemSpaceContext::TwoD { space_2d, pos } => Some((space_2d.clone(), pos.to_array())),
+        ItemSpaceContext::ThreeD { space_3d, pos, .. } => {
+            pos.map(|pos| (space_3d.clone(), pos.to_array()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_entity_db::InstancePath;
+    use re_log_types::StoreKind;
+    use re_viewer_context::ItemCollection;
+
+    use super::*;
+
+    struct Viewer {
+        store_id: StoreId,
+        sender: ViewerEventSender,
+        selection_state: ApplicationSelectionState,
+        time_ctrl: TimeControl,
+    }
+
+    impl Viewer {
+        fn new() -> Self {
+            Self {
+                store_id: StoreId::from_string(StoreKind::Recording, "rec".to_owned()),
+                sender: ViewerEventSender::default(),
+                selection_state: ApplicationSelectionState::default(),
+                time_ctrl: TimeControl::default(),
+            }
+        }
+
+        /// Runs a frame, returning what was sent to whom.
+        ///
+        /// Clients in `gone` have disconnected.
+        fn frame_with_gone(&mut self, gone: &[SocketAddr]) -> Vec<(SocketAddr, ViewerEvent)> {
+            // Selection and hover changes only show up in the next frame.
+            self.selection_state.on_frame_start(|_| true, None);
+
+            let mut sent = Vec::new();
+            self.sender.update(
+                &self.store_id,
+                &self.selection_state,
+                &self.time_ctrl,
+                |addr, msg| {
+                    let ViewerMessage::Event { store_id, event } = msg else {
+                        panic!("Expected an event, got {msg:?}");
+                    };
+                    assert_eq!(store_id, self.store_id);
+                    if gone.contains(&addr) {
+                        return false;
+                    }
+                    sent.push((addr, event));
+                    true
+                },
+            );
+            sent
+        }
+
+        fn frame(&mut self) -> Vec<(SocketAddr, ViewerEvent)> {
+            self.frame_with_gone(&[])
+        }
+    }
+
+    fn client(port: u16) -> SocketAddr {
+        ([127, 0, 0, 1], port).into()
+    }
+
+    fn instance_item(entity_path: &str, instance: u64) -> Item {
+        Item::InstancePath(InstancePath::instance(
+            entity_path.into(),
+            Instance::from(instance),
+        ))
+    }
+
+    #[test]
+    fn not_subscribed() {
+        let mut viewer = Viewer::new();
+        assert!(viewer.frame().is_empty());
+
+        viewer.sender.set_enabled(&viewer.store_id, client(1), true);
+        viewer
+            .sender
+            .set_enabled(&viewer.store_id, client(1), false);
+        assert!(viewer.frame().is_empty());
+
+        // Other recordings don't matter.
+        let other = StoreId::from_string(StoreKind::Recording, "other".to_owned());
+        viewer.sender.set_enabled(&other, client(1), true);
+        assert!(viewer.frame().is_empty());
+    }
+
+    #[test]
+    fn only_changes_are_sent() {
+        let mut viewer = Viewer::new();
+        viewer.sender.set_enabled(&viewer.store_id, client(1), true);
+
+        // At first, the client learns about everything.
+        let timeline = *viewer.time_ctrl.timeline();
+        assert_eq!(
+            viewer.frame(),
+            vec![
+                (client(1), ViewerEvent::SelectionChanged(vec![])),
+                (client(1), ViewerEvent::HoverChanged(None)),
+                (
+                    client(1),
+                    ViewerEvent::TimeChanged {
+                        timeline,
+                        time: None,
+                    }
+                ),
+            ]
+        );
+        assert!(viewer.frame().is_empty());
+
+        viewer
+            .selection_state
+            .set_selection(instance_item("points", 3));
+        assert_eq!(
+            viewer.frame(),
+            vec![(
+                client(1),
+                ViewerEvent::SelectionChanged(vec![("points".into(), Instance::from(3))])
+            )]
+        );
+        assert!(viewer.frame().is_empty());
+
+        // Hovering lasts for one frame only.
+        viewer
+            .selection_state
+            .set_hovered(instance_item("points", 4));
+        assert_eq!(
+            viewer.frame(),
+            vec![(
+                client(1),
+                ViewerEvent::HoverChanged(Some(("points".into(), Instance::from(4))))
+            )]
+        );
+        assert_eq!(
+            viewer.frame(),
+            vec![(client(1), ViewerEvent::HoverChanged(None))]
+        );
+
+        let frame = Timeline::new_sequence("frame");
+        viewer
+            .time_ctrl
+            .set_timeline_and_time(frame, TimeInt::new_temporal(10));
+        assert_eq!(
+            viewer.frame(),
+            vec![(
+                client(1),
+                ViewerEvent::TimeChanged {
+                    timeline: frame,
+                    time: Some(TimeInt::new_temporal(10)),
+                }
+            )]
+        );
+        viewer.time_ctrl.set_time(TimeInt::new_temporal(10));
+        assert!(viewer.frame().is_empty());
+
+        // Non-instance items don't count.
+        viewer
+            .selection_state
+            .set_selection(Item::StoreId(viewer.store_id.clone()));
+        assert_eq!(
+            viewer.frame(),
+            vec![(client(1), ViewerEvent::SelectionChanged(vec![]))]
+        );
+        viewer
+            .selection_state
+            .set_selection(Item::AppId("app".into()));
+        assert!(viewer.frame().is_empty());
+    }
+
+    #[test]
+    fn picked() {
+        let mut viewer = Viewer::new();
+        viewer.sender.set_enabled(&viewer.store_id, client(1), true);
+        viewer.frame();
+
+        let space_context = ItemSpaceContext::TwoD {
+            space_2d: "image".into(),
+            pos: glam::vec3(1.0, 2.0, 3.0),
+        };
+        viewer.selection_state.set_selection(ItemCollection::from(
+            [(instance_item("image/points", 0), Some(space_context))].into_iter(),
+        ));
+        assert_eq!(
+            viewer.frame(),
+            vec![
+                (
+                    client(1),
+                    ViewerEvent::Picked {
+                        entity_path: "image/points".into(),
+                        instance: Instance::from(0),
+                        space: "image".into(),
+                        position: [1.0, 2.0, 3.0],
+                    }
+                ),
+                (
+                    client(1),
+                    ViewerEvent::SelectionChanged(vec![("image/points".into(), Instance::from(0))])
+                ),
+            ]
+        );
+
+        // A 3D click that missed everything has no position.
+        let space_context = ItemSpaceContext::ThreeD {
+            space_3d: "world".into(),
+            pos: None,
+            tracked_entity: None,
+            point_in_space_cameras: vec![],
+        };
+        viewer.selection_state.set_selection(ItemCollection::from(
+            [(instance_item("world/points", 1), Some(space_context))].into_iter(),
+        ));
+        assert_eq!(
+            viewer.frame(),
+            vec![(
+                client(1),
+                ViewerEvent::SelectionChanged(vec![("world/points".into(), Instance::from(1))])
+            )]
+        );
+    }
+
+    #[test]
+    fn clients() {
+        let mut viewer = Viewer::new();
+        viewer.sender.set_enabled(&viewer.store_id, client(1), true);
+        assert_eq!(viewer.frame().len(), 3);
+
+        // A new client only learns about what changes from now on.
+        viewer.sender.set_enabled(&viewer.store_id, client(2), true);
+        viewer.sender.set_enabled(&viewer.store_id, client(2), true);
+        assert!(viewer.frame().is_empty());
+        viewer
+            .selection_state
+            .set_selection(instance_item("points", 3));
+        let event = ViewerEvent::SelectionChanged(vec![("points".into(), Instance::from(3))]);
+        assert_eq!(
+            viewer.frame(),
+            vec![(client(1), event.clone()), (client(2), event)]
+        );
+
+        // Clients that are gone are dropped for good.
+        viewer.selection_state.clear_selection();
+        assert_eq!(
+            viewer.frame_with_gone(&[client(1)]),
+            vec![(client(2), ViewerEvent::SelectionChanged(vec![]))]
+        );
+        viewer
+            .selection_state
+            .set_selection(instance_item("points", 3));
+        assert_eq!(viewer.frame().len(), 1);
+
+        viewer.selection_state.clear_selection();
+        assert!(viewer.frame_with_gone(&[client(2)]).is_empty());
+        assert!(viewer.sender.subscriptions.is_empty());
+    }
+}
@@ -8,11 +8,11 @@ use wgpu::TextureFormat;
 
 use re_renderer::{
     pad_rgb_to_rgba,
-    renderer::{ColorMapper, ColormappedTexture},
+    renderer::{ColorMapper, ColormappedTexture, ShaderDecoding},
     resource_managers::Texture2DCreationDesc,
     RenderContext,
 };
-use re_types::components::{ClassId, ColorModel, Colormap};
+use re_types::components::{ClassId, ColorModel, Colormap, PixelFormat};
 use re_types::{components::ChannelDataType, tensor_data::TensorDataMeaning};
 
 use crate::{gpu_bridge::colormap::colormap_to_re_renderer, Annotations, ImageInfo, TensorStats};
@@ -33,11 +33,19 @@ fn generate_texture_key(image: &ImageInfo, meaning: TensorDataMeaning) -> u64 {
         resolution,
         data_type,
         color_model,
+        pixel_format,
 
         colormap: _, // No need to upload new texture when this changes
     } = image;
 
-    hash((blob_row_id, resolution, data_type, color_model, meaning))
+    hash((
+        blob_row_id,
+        resolution,
+        data_type,
+        color_model,
+        pixel_format,
+        meaning,
+    ))
 }
 
 pub fn image_to_gpu(
@@ -82,6 +90,10 @@ fn color_image_to_gpu(
 ) -> anyhow::Result<ColormappedTexture> {
     re_tracing::profile_function!();
 
+    if let Some(pixel_format) = image.pixel_format {
+        return pixel_format_image_to_gpu(render_ctx, debug_name, texture_key, image, pixel_format);
+    }
+
     let data_type = image.data_type;
     let color_model = image.color_model.unwrap_or_default();
 
@@ -89,32 +101,7 @@ fn color_image_to_gpu(
         // Profile creation of the texture, but not cache hits (those take close to no time, which would just add profiler overhead).
         re_tracing::profile_function!();
 
-        let (data, format) = match (color_model, data_type) {
-            // Normalize sRGB(A) textures to 0-1 range, and let the GPU premultiply alpha.
-            // Why? Because premul must happen _before_ sRGB decode, so we can't
-            // use a "Srgb-aware" texture like `Rgba8UnormSrgb` for RGBA.
-            (ColorModel::Rgb, ChannelDataType::U8) => (
-                pad_rgb_to_rgba(&image.blob, u8::MAX).into(),
-                TextureFormat::Rgba8Unorm,
-            ),
-
-            (ColorModel::Rgba, ChannelDataType::U8) => {
-                (cast_slice_to_cow(&image.blob), TextureFormat::Rgba8Unorm)
-            }
-
-            _ => {
-                // Fallback to general case:
-                return general_texture_creation_desc_from_image(debug_name, image);
-            }
-        };
-
-        Texture2DCreationDesc {
-            label: debug_name.into(),
-            data,
-            format,
-            width: image.width(),
-            height: image.height(),
-        }
+        color_texture_creation_desc(debug_name, image)
     })
     .map_err(|err| anyhow::anyhow!("{err}"))?;
 
@@ -155,9 +142,9 @@ fn color_image_to_gpu(
 
     // TODO(wumpf): There should be a way to specify whether a texture uses pre-multiplied alpha or not.
     let multiply_rgb_with_alpha = match color_model {
-        ColorModel::L | ColorModel::Rgb => false, // No alpha
+        ColorModel::L | ColorModel::Rgb | ColorModel::Bgr => false, // No alpha
 
-        ColorModel::Rgba => true, // Assume that the texture is not pre-multiplied
+        ColorModel::Rgba | ColorModel::Bgra => true, // Assume that the texture is not pre-multiplied
     };
     let gamma = 1.0;
 
@@ -172,7 +159,103 @@ fn color_image_to_gpu(
         multiply_rgb_with_alpha,
         gamma,
         color_mapper,
-        shader_decoding: None,
+        // BGR(A) images are uploaded as-is, and have their red and blue channels swapped in the shader.
+        shader_decoding: color_model.is_bgr().then_some(ShaderDecoding::Bgr),
+    })
+}
+
+/// Texture for an `L`, `RGB(A)` or `BGR(A)` image, using normalized formats for sRGB(A) images.
+///
+/// The channels of `BGR(A)` images are left in their order, see [`ShaderDecoding::Bgr`].
+fn color_texture_creation_desc<'a>(
+    debug_name: &str,
+    image: &'a ImageInfo,
+) -> Texture2DCreationDesc<'a> {
+    let (data, format) = match (image.color_model.unwrap_or_default(), image.data_type) {
+        // Normalize sRGB(A) textures to 0-1 range, and let the GPU premultiply alpha.
+        // Why? Because premul must happen _before_ sRGB decode, so we can't
+        // use a "Srgb-aware" texture like `Rgba8UnormSrgb` for RGBA.
+        (ColorModel::Rgb | ColorModel::Bgr, ChannelDataType::U8) => (
+            pad_rgb_to_rgba(&image.blob, u8::MAX).into(),
+            TextureFormat::Rgba8Unorm,
+        ),
+
+        (ColorModel::Rgba | ColorModel::Bgra, ChannelDataType::U8) => {
+            (cast_slice_to_cow(&image.blob), TextureFormat::Rgba8Unorm)
+        }
+
+        _ => {
+            // Fallback to general case:
+            return general_texture_creation_desc_from_image(debug_name, image);
+        }
+    };
+
+    Texture2DCreationDesc {
+        label: debug_name.into(),
+        data,
+        format,
+        width: image.width(),
+        height: image.height(),
+    }
+}
+
+/// Uploads the raw buffer of an image with a [`PixelFormat`] as-is,
+/// and lets the shader convert it to RGB.
+fn pixel_format_image_to_gpu(
+    render_ctx: &RenderContext,
+    debug_name: &str,
+    texture_key: u64,
+    image: &ImageInfo,
+    pixel_format: PixelFormat,
+) -> anyhow::Result<ColormappedTexture> {
+    re_tracing::profile_function!();
+
+    let data_type = image.data_type;
+
+    let (format, range) = match (pixel_format.is_bayer(), data_type) {
+        // The shader decodes YUV to the 0-1 range.
+        (false, ChannelDataType::U8) => (TextureFormat::R8Uint, [0.0, 1.0]),
+
+        // The shader demosaics Bayer images to the range of the raw values.
+        (true, ChannelDataType::U8) => (TextureFormat::R8Uint, [0.0, u8::MAX as f32]),
+        (true, ChannelDataType::U16) => (TextureFormat::R16Uint, [0.0, u16::MAX as f32]),
+
+        _ => {
+            anyhow::bail!("{pixel_format:?} images with {data_type} data are not supported");
+        }
+    };
+
+    let shader_decoding = match pixel_format {
+        PixelFormat::Nv12 => ShaderDecoding::Nv12,
+        PixelFormat::Yuy2 => ShaderDecoding::Yuy2,
+        PixelFormat::BayerRggb
+        | PixelFormat::BayerBggr
+        | PixelFormat::BayerGrbg
+        | PixelFormat::BayerGbrg => ShaderDecoding::Bayer {
+            red_offset: pixel_format.bayer_red_offset().unwrap_or_default(),
+        },
+    };
+
+    let texture = get_or_create_texture(render_ctx, texture_key, || {
+        let [buffer_height, buffer_width] = pixel_format.buffer_shape(image.resolution);
+        Texture2DCreationDesc {
+            label: debug_name.into(),
+            data: cast_slice_to_cow(&image.blob),
+            format,
+            width: buffer_width as u32,
+            height: buffer_height as u32,
+        }
+    })
+    .map_err(|err| anyhow::anyhow!("{err}"))?;
+
+    Ok(ColormappedTexture {
+        texture,
+        range,
+        decode_srgb: true,
+        multiply_rgb_with_alpha: false,
+        gamma: 1.0,
+        color_mapper: ColorMapper::OffRGB,
+        shader_decoding: Some(shader_decoding),
     })
 }
 
@@ -342,7 +425,8 @@ fn general_texture_creation_desc_from_image<'a>(
             }
         }
 
-        ColorModel::Rgb => {
+        // BGR(A) images have their channels swapped before getting here.
+        ColorModel::Rgb | ColorModel::Bgr => {
             // There are no 3-channel textures in wgpu, so we need to pad to 4 channels.
             // What should we pad with? It depends on whether or not the shader interprets these as alpha.
             // To be safe, we pad with the MAX value of integers, and with 1.0 for floats.
@@ -401,7 +485,7 @@ fn general_texture_creation_desc_from_image<'a>(
             }
         }
 
-        ColorModel::Rgba => {
+        ColorModel::Rgba | ColorModel::Bgra => {
             // TODO(emilk): premultiply alpha, or tell the shader to assume unmultiplied alpha
 
             match image.data_type {
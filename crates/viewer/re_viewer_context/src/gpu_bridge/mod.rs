@@ -119,7 +119,7 @@ pub fn image_decode_srgb_gamma_heuristic(
     color_model: ColorModel,
 ) -> Result<bool, RangeError> {
     match color_model {
-        ColorModel::L | ColorModel::Rgb | ColorModel::Rgba => {
+        ColorModel::L | ColorModel::Rgb | ColorModel::Rgba | ColorModel::Bgr | ColorModel::Bgra => {
             let (min, max) = tensor_stats.finite_range.ok_or(RangeError::MissingRange)?;
             #[allow(clippy::if_same_then_else)]
             if 0.0 <= min && max <= 255.0 {
@@ -144,7 +144,7 @@ fn color_tensor_to_gpu(
     };
     // TODO(emilk): let the user specify the color space.
     let decode_srgb = match shader_decoding {
-        Some(ShaderDecoding::Nv12 | ShaderDecoding::Yuy2) => true,
+        Some(_) => true,
         None => {
             texture_format == TextureFormat::Rgba8Unorm
                 || super::tensor_decode_srgb_gamma_heuristic(tensor_stats, tensor.dtype(), depth)?
@@ -159,9 +159,7 @@ fn color_tensor_to_gpu(
         [0.0, 1.0]
     } else if texture_format == TextureFormat::R8Snorm {
         [-1.0, 1.0]
-    } else if shader_decoding == Some(ShaderDecoding::Nv12)
-        || shader_decoding == Some(ShaderDecoding::Yuy2)
-    {
+    } else if shader_decoding.is_some() {
         [0.0, 1.0]
     } else {
         // TODO(#2341): The range should be determined by a `DataRange` component. In absence this, heuristics apply.
@@ -187,7 +185,7 @@ fn color_tensor_to_gpu(
             }
         }
 
-        Some(ShaderDecoding::Nv12 | ShaderDecoding::Yuy2) => ColorMapper::OffRGB,
+        Some(_) => ColorMapper::OffRGB,
     };
 
     // TODO(wumpf): There should be a way to specify whether a texture uses pre-multiplied alpha or not.
@@ -2,8 +2,8 @@ use std::borrow::Cow;
 
 use re_chunk::RowId;
 use re_types::{
-    components::{ChannelDataType, ColorModel, Colormap},
-    datatypes::Blob,
+    components::{ChannelDataType, ColorModel, Colormap, PixelFormat},
+    datatypes::{Blob, TensorData},
     tensor_data::TensorElement,
 };
 
@@ -30,12 +30,53 @@ pub struct ImageInfo {
     /// `Some` for color images.
     pub color_model: Option<ColorModel>,
 
+    /// Set for images whose blob is the raw output of a camera, e.g. `NV12` or Bayer.
+    ///
+    /// Takes precedence over [`Self::color_model`].
+    pub pixel_format: Option<PixelFormat>,
+
     /// Primarily for depth images atm
     pub colormap: Option<Colormap>,
-    // TODO(#6386): `PixelFormat` and `ColorModel`
 }
 
 impl ImageInfo {
+    /// Interprets an `Image` tensor with the given [`ColorModel`] or [`PixelFormat`].
+    ///
+    /// Returns `None` if the shape of the tensor doesn't match them.
+    pub fn from_tensor(
+        blob_row_id: RowId,
+        tensor: &TensorData,
+        color_model: Option<ColorModel>,
+        pixel_format: Option<PixelFormat>,
+    ) -> Option<Self> {
+        let [height, width, channels] = tensor.image_height_width_channels()?;
+        let (blob, data_type) =
+            re_types::image::blob_and_datatype_from_tensor(tensor.buffer.clone())?;
+
+        let resolution = if let Some(pixel_format) = pixel_format {
+            if channels != 1 {
+                return None;
+            }
+            pixel_format.resolution_from_buffer_shape([height, width])?
+        } else {
+            let num_channels = color_model.map_or(1, ColorModel::num_channels);
+            if channels != num_channels as u64 {
+                return None;
+            }
+            [width as u32, height as u32]
+        };
+
+        Some(Self {
+            blob_row_id,
+            blob,
+            resolution,
+            data_type,
+            color_model,
+            pixel_format,
+            colormap: None,
+        })
+    }
+
     #[inline]
     pub fn width(&self) -> u32 {
         self.resolution[0]
@@ -47,21 +88,34 @@ impl ImageInfo {
     }
 
     /// 1 for grayscale and depth images, 3 for RGB, etc.
+    ///
+    /// Images with a [`PixelFormat`] are always decoded to RGB.
     #[doc(alias = "components")]
     #[doc(alias = "depth")]
     #[inline]
     pub fn num_channels(&self) -> usize {
-        self.color_model.map_or(1, ColorModel::num_channels)
+        if self.pixel_format.is_some() {
+            3
+        } else {
+            self.color_model.map_or(1, ColorModel::num_channels)
+        }
     }
 
+    /// Average number of bits used by each pixel in the blob.
     #[inline]
     pub fn bits_per_texel(&self) -> usize {
-        // TODO(#6386): use `PixelFormat`
-        self.data_type.bits() * self.num_channels()
+        if let Some(pixel_format) = self.pixel_format {
+            pixel_format.bits_per_pixel(self.data_type)
+        } else {
+            self.data_type.bits() * self.num_channels()
+        }
     }
 
     /// Get the value of the element at the given index.
     ///
+    /// For images with a [`PixelFormat`], this is the decoded RGB value,
+    /// which is what the viewer shows.
+    ///
     /// Return `None` if out-of-bounds.
     #[inline]
     pub fn get_xyc(&self, x: u32, y: u32, channel: u32) -> Option<TensorElement> {
@@ -77,9 +131,97 @@ impl ImageInfo {
             return None;
         }
 
+        if let Some(pixel_format) = self.pixel_format {
+            return self.get_decoded_xyc(pixel_format, x, y, channel);
+        }
+
         let stride = width; // TODO(#6008): support stride
         let offset = (y as usize * stride as usize + x as usize) * num_channels + channel as usize;
 
+        self.get_element(offset)
+    }
+
+    /// Decodes a single channel of an image with a [`PixelFormat`],
+    /// mirroring what the shader does in `decodings.wgsl`.
+    fn get_decoded_xyc(
+        &self,
+        pixel_format: PixelFormat,
+        x: u32,
+        y: u32,
+        channel: u32,
+    ) -> Option<TensorElement> {
+        let [width, height] = self.resolution.map(|v| v as usize);
+        let (x, y) = (x as usize, y as usize);
+
+        let yuv = match pixel_format {
+            PixelFormat::Nv12 => {
+                let uv_offset = width * height + (y / 2) * width + (x / 2) * 2;
+                Some([
+                    *self.blob.get(y * width + x)?,
+                    *self.blob.get(uv_offset)?,
+                    *self.blob.get(uv_offset + 1)?,
+                ])
+            }
+            PixelFormat::Yuy2 => {
+                let row_offset = y * width * 2;
+                let block_offset = row_offset + (x / 2) * 4;
+                Some([
+                    *self.blob.get(row_offset + x * 2)?,
+                    *self.blob.get(block_offset + 1)?,
+                    *self.blob.get(block_offset + 3)?,
+                ])
+            }
+            PixelFormat::BayerRggb
+            | PixelFormat::BayerBggr
+            | PixelFormat::BayerGrbg
+            | PixelFormat::BayerGbrg => None,
+        };
+
+        if let Some([y, u, v]) = yuv {
+            let rgb = re_types::image::rgb_from_yuv(y, u, v);
+            return rgb.get(channel as usize).copied().map(TensorElement::U8);
+        }
+
+        // Bilinear demosaicing, like in the shader.
+        let [red_x, red_y] = pixel_format.bayer_red_offset()?.map(|v| v as usize);
+        let texel = |dx: isize, dy: isize| -> Option<f64> {
+            let mirror = |v: usize, delta: isize, size: usize| {
+                let v = (v as isize + delta).unsigned_abs();
+                if size <= v {
+                    (2 * size).saturating_sub(v + 2)
+                } else {
+                    v
+                }
+            };
+            let offset = mirror(y, dy, height) * width + mirror(x, dx, width);
+            self.get_element(offset).map(|element| element.as_f64())
+        };
+
+        let center = texel(0, 0)?;
+        let horizontal = 0.5 * (texel(-1, 0)? + texel(1, 0)?);
+        let vertical = 0.5 * (texel(0, -1)? + texel(0, 1)?);
+        let cross = 0.5 * (horizontal + vertical);
+        let diagonal = 0.25 * (texel(-1, -1)? + texel(1, -1)? + texel(-1, 1)? + texel(1, 1)?);
+
+        let parity = [(x + red_x) % 2, (y + red_y) % 2];
+        let mut rgb = if parity[0] == parity[1] {
+            [center, cross, diagonal]
+        } else {
+            [horizontal, center, vertical]
+        };
+        if parity[1] == 1 {
+            rgb.reverse();
+        }
+
+        let value = rgb[channel as usize];
+        Some(match self.data_type {
+            ChannelDataType::U16 => TensorElement::U16(value.round() as u16),
+            _ => TensorElement::U8(value.round() as u8),
+        })
+    }
+
+    /// The raw element at the given offset into the blob.
+    fn get_element(&self, offset: usize) -> Option<TensorElement> {
         match self.data_type {
             ChannelDataType::U8 => self.blob.get(offset).copied().map(TensorElement::U8),
             ChannelDataType::U16 => get(&self.blob, offset).map(TensorElement::U16),
@@ -145,7 +287,7 @@ fn get<T: bytemuck::Pod>(blob: &[u8], element_offset: usize) -> Option<T> {
 
     let size = std::mem::size_of::<T>();
     let byte_offset = element_offset * size;
-    if blob.len() <= byte_offset + size {
+    if blob.len() < byte_offset + size {
         return None;
     }
 
@@ -155,3 +297,196 @@ fn get<T: bytemuck::Pod>(blob: &[u8], element_offset: usize) -> Option<T> {
     bytemuck::bytes_of_mut(&mut dest).copy_from_slice(slice);
     Some(dest)
 }
+
+#[cfg(test)]
+mod tests {
+    use re_types::image::rgb_from_yuv;
+
+    use super::*;
+
+    fn image(
+        pixel_format: PixelFormat,
+        data_type: ChannelDataType,
+        resolution: [u32; 2],
+        blob: Vec<u8>,
+    ) -> ImageInfo {
+        ImageInfo {
+            blob_row_id: RowId::ZERO,
+            blob: blob.into(),
+            resolution,
+            data_type,
+            color_model: None,
+            pixel_format: Some(pixel_format),
+            colormap: None,
+        }
+    }
+
+    fn rgb_at(image: &ImageInfo, x: u32, y: u32) -> [f64; 3] {
+        [0, 1, 2].map(|channel| image.get_xyc(x, y, channel).unwrap().as_f64())
+    }
+
+    const BAYER_FORMATS: [PixelFormat; 4] = [
+        PixelFormat::BayerRggb,
+        PixelFormat::BayerBggr,
+        PixelFormat::BayerGrbg,
+        PixelFormat::BayerGbrg,
+    ];
+
+    /// The top-left 2x2 block of each Bayer layout, as indices into an RGB triplet.
+    fn bayer_block(pixel_format: PixelFormat) -> [[usize; 2]; 2] {
+        let (r, g, b) = (0, 1, 2);
+        match pixel_format {
+            PixelFormat::BayerRggb => [[r, g], [g, b]],
+            PixelFormat::BayerBggr => [[b, g], [g, r]],
+            PixelFormat::BayerGrbg => [[g, r], [b, g]],
+            PixelFormat::BayerGbrg => [[g, b], [r, g]],
+            PixelFormat::Nv12 | PixelFormat::Yuy2 => unreachable!(),
+        }
+    }
+
+    /// A uniformly colored image demosaics to that very color everywhere, which only works out
+    /// at the edges if the neighbors are mirrored so that they keep their color.
+    #[test]
+    fn bayer_uniform_color() {
+        // Odd sizes, so that the right and bottom edges start with either color of a row.
+        let [width, height] = [5, 3];
+
+        for pixel_format in BAYER_FORMATS {
+            let block = bayer_block(pixel_format);
+            let mosaic = |rgb: [u16; 3]| {
+                (0..height).flat_map(move |y| {
+                    (0..width).map(move |x| rgb[block[y as usize % 2][x as usize % 2]])
+                })
+            };
+
+            let rgb = [200, 100, 50];
+            let blob = mosaic(rgb).map(|v| v as u8).collect();
+            let image_u8 = image(pixel_format, ChannelDataType::U8, [width, height], blob);
+
+            let rgb_u16 = [40_000, 20_000, 1_000];
+            let blob = mosaic(rgb_u16).flat_map(u16::to_le_bytes).collect();
+            let image_u16 = image(pixel_format, ChannelDataType::U16, [width, height], blob);
+
+            for y in 0..height {
+                for x in 0..width {
+                    assert_eq!(
+                        rgb.map(f64::from),
+                        rgb_at(&image_u8, x, y),
+                        "{pixel_format:?} at {x}x{y}"
+                    );
+                    assert_eq!(
+                        rgb_u16.map(f64::from),
+                        rgb_at(&image_u16, x, y),
+                        "{pixel_format:?} at {x}x{y}"
+                    );
+                }
+            }
+
+            assert_eq!(None, image_u8.get_xyc(width, 0, 0));
+            assert_eq!(None, image_u8.get_xyc(0, height, 0));
+        }
+    }
+
+    #[test]
+    fn bayer_interpolation() {
+        // Each texel is `10 * y + x`.
+        let blob = (0..4)
+            .flat_map(|y| (0..4).map(move |x| 10 * y + x))
+            .collect();
+        let rggb = image(PixelFormat::BayerRggb, ChannelDataType::U8, [4, 4], blob);
+
+        // Red in the top-left corner: green from its right and bottom neighbors, mirrored.
+        assert_eq!([0.0, 6.0, 11.0], rgb_at(&rggb, 0, 0)); // 0.5 * (1 + 10) rounds up.
+
+        // Green on the top edge: red from the left and right, blue from below, mirrored.
+        assert_eq!([1.0, 1.0, 11.0], rgb_at(&rggb, 1, 0));
+
+        // Green on the left edge: red from above and below, blue from the right, mirrored.
+        assert_eq!([10.0, 10.0, 11.0], rgb_at(&rggb, 0, 1));
+
+        // Blue in the middle, from all around.
+        assert_eq!([11.0, 11.0, 11.0], rgb_at(&rggb, 1, 1));
+
+        // Blue in the bottom-right corner: all of its neighbors are mirrored.
+        assert_eq!([22.0, 28.0, 33.0], rgb_at(&rggb, 3, 3)); // 0.5 * (32 + 23) rounds up.
+
+        // The same texels, read as another layout.
+        let bggr = ImageInfo {
+            pixel_format: Some(PixelFormat::BayerBggr),
+            ..rggb
+        };
+        assert_eq!([11.0, 6.0, 0.0], rgb_at(&bggr, 0, 0));
+        assert_eq!([33.0, 28.0, 22.0], rgb_at(&bggr, 3, 3));
+    }
+
+    #[test]
+    fn nv12() {
+        let [width, height] = [4, 4];
+        let luma = [
+            16, 50, 100, 150, //
+            200, 235, 80, 120, //
+            30, 60, 90, 120, //
+            150, 180, 210, 240,
+        ];
+        // One (U, V) pair per 2x2 block of pixels.
+        let chroma = [[(90, 200), (160, 60)], [(30, 120), (220, 140)]];
+
+        let blob = luma
+            .iter()
+            .copied()
+            .chain(chroma.iter().flatten().flat_map(|&(u, v)| [u, v]))
+            .collect();
+        let image = image(
+            PixelFormat::Nv12,
+            ChannelDataType::U8,
+            [width, height],
+            blob,
+        );
+
+        for y in 0..height {
+            for x in 0..width {
+                let (u, v) = chroma[y as usize / 2][x as usize / 2];
+                let expected = rgb_from_yuv(luma[(y * width + x) as usize], u, v);
+                assert_eq!(expected.map(f64::from), rgb_at(&image, x, y), "at {x}x{y}");
+            }
+        }
+
+        assert_eq!(None, image.get_xyc(width, 0, 0));
+        assert_eq!(None, image.get_xyc(0, height, 0));
+    }
+
+    #[test]
+    fn yuy2() {
+        let [width, height] = [4, 2];
+        // Y0 U Y1 V, for each pair of pixels.
+        let blob = vec![
+            16, 90, 50, 200, 100, 160, 150, 60, //
+            200, 30, 235, 120, 80, 220, 120, 140,
+        ];
+        let pixels = [
+            [(16, 90, 200), (50, 90, 200), (100, 160, 60), (150, 160, 60)],
+            [
+                (200, 30, 120),
+                (235, 30, 120),
+                (80, 220, 140),
+                (120, 220, 140),
+            ],
+        ];
+        let image = image(
+            PixelFormat::Yuy2,
+            ChannelDataType::U8,
+            [width, height],
+            blob,
+        );
+
+        for y in 0..height {
+            for x in 0..width {
+                let (luma, u, v) = pixels[y as usize][x as usize];
+                let expected = rgb_from_yuv(luma, u, v);
+                assert_eq!(expected.map(f64::from), rgb_at(&image, x, y), "at {x}x{y}");
+            }
+        }
+
+        assert_eq!(None, image.get_xyc(width, 0, 0));
+    }
+}
@@ -28,6 +28,7 @@ pub mod test_context;
 mod time_control;
 mod typed_entity_collections;
 mod utils;
+mod video_cache;
 mod viewer_context;
 
 // TODO(andreas): Move to its own crate?
@@ -81,6 +82,7 @@ pub use typed_entity_collections::{
     ApplicableEntities, IndicatedEntities, PerVisualizer, VisualizableEntities,
 };
 pub use utils::{auto_color_egui, auto_color_for_entity_path, level_to_rich_text};
+pub use video_cache::{VideoCache, VideoError};
 pub use viewer_context::{RecordingConfig, ViewerContext};
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -0,0 +1,634 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use egui::util::hash;
+
+use re_chunk::RowId;
+use re_types::components::{ChannelDataType, ColorModel, MediaType};
+use re_video::VideoData;
+
+use crate::{Cache, ImageInfo};
+
+/// Errors that can occur when showing a frame of a video, see [`VideoCache::frame_at`].
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum VideoError {
+    #[error("Failed to load video: {0}")]
+    Load(Arc<re_video::VideoLoadError>),
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error("Failed to start the video decoder: {0}")]
+    DecoderThread(Arc<std::io::Error>),
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error("Failed to decode video: {0}")]
+    Decode(Arc<re_video::decode::DecodeError>),
+
+    #[cfg(target_arch = "wasm32")]
+    #[error("Decoding videos is not supported on the web yet")]
+    UnsupportedPlatform,
+
+    #[error("The video has no frame at {timestamp_ns}ns")]
+    NoFrame { timestamp_ns: i64 },
+}
+
+/// How many frames are decoded at once, so that playing a video doesn't require
+/// running the decoder for every single frame.
+#[cfg(not(target_arch = "wasm32"))]
+const NUM_FRAMES_PER_DECODE: usize = 30;
+
+struct DecodedFrame {
+    image: ImageInfo,
+
+    /// At which [`VideoCache::generation`] was this frame last used?
+    last_use_generation: u64,
+}
+
+struct CachedVideo {
+    video: Result<Arc<VideoData>, VideoError>,
+
+    /// Started the first time a frame is needed.
+    ///
+    /// Replaced by the error if decoding ever fails, so that we don't keep retrying.
+    #[cfg(not(target_arch = "wasm32"))]
+    decoder: Option<Result<worker::DecodeWorker, VideoError>>,
+
+    /// The frames that were decoded so far, by frame index.
+    frames: BTreeMap<usize, DecodedFrame>,
+
+    /// The last frame that was shown, which keeps being shown while the next one is decoded.
+    last_shown_frame: Option<usize>,
+
+    /// Total memory used by [`Self::frames`].
+    memory_used: u64,
+
+    /// At which [`VideoCache::generation`] was this video last used?
+    last_use_generation: u64,
+}
+
+impl CachedVideo {
+    fn new(video: Result<VideoData, VideoError>) -> Self {
+        Self {
+            video: video.map(Arc::new),
+            #[cfg(not(target_arch = "wasm32"))]
+            decoder: None,
+            frames: Default::default(),
+            last_shown_frame: None,
+            memory_used: 0,
+            last_use_generation: 0,
+        }
+    }
+
+    /// The decoded frame, if it is in the cache.
+    fn frame(&mut self, frame_index: usize, generation: u64) -> Option<ImageInfo> {
+        let frame = self.frames.get_mut(&frame_index)?;
+        frame.last_use_generation = generation;
+        Some(frame.image.clone())
+    }
+
+    /// Moves the frames that the decoder is done with into the cache.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn receive_decoded_frames(&mut self, generation: u64, cache_memory_used: &mut u64) {
+        let Some(Ok(decoder)) = &mut self.decoder else {
+            return;
+        };
+
+        let mut error = None;
+        for frames in decoder.receive() {
+            let frames = match frames {
+                Ok(frames) => frames,
+                Err(err) => {
+                    error = Some(err);
+                    continue;
+                }
+            };
+
+            for frame in frames {
+                let memory_used = frame.data.len() as u64;
+                let image = ImageInfo {
+                    // Each frame is a new texture.
+                    blob_row_id: RowId::new(),
+                    blob: frame.data.into(),
+                    resolution: [frame.width, frame.height],
+                    data_type: ChannelDataType::U8,
+                    color_model: Some(ColorModel::Rgb),
+                    pixel_format: None,
+                    colormap: None,
+                };
+
+                let previous = self.frames.insert(
+                    frame.frame_index,
+                    DecodedFrame {
+                        image,
+                        last_use_generation: generation,
+                    },
+                );
+                self.memory_used += memory_used;
+                *cache_memory_used += memory_used;
+                if let Some(previous) = previous {
+                    let previous_memory_used = previous.image.blob.len() as u64;
+                    self.memory_used -= previous_memory_used;
+                    *cache_memory_used -= previous_memory_used;
+                }
+            }
+        }
+
+        if let Some(err) = error {
+            self.decoder = Some(Err(VideoError::Decode(err)));
+        }
+    }
+}
+
+/// Caches the frame tables of `AssetVideo`s, and their decoded frames.
+///
+/// Frames are decoded a few at a time, on a background thread per video: seeking to a frame far
+/// from the ones that were decoded last decodes everything since the previous keyframe, which is
+/// frame accurate but can take a while. The last frame that was shown stays on screen meanwhile.
+pub struct VideoCache {
+    videos: ahash::HashMap<u64, CachedVideo>,
+    memory_used: u64,
+    generation: u64,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    new_decoder: worker::NewDecoder,
+}
+
+impl Default for VideoCache {
+    fn default() -> Self {
+        Self {
+            videos: Default::default(),
+            memory_used: 0,
+            generation: 0,
+            #[cfg(not(target_arch = "wasm32"))]
+            new_decoder: Arc::new(worker::ffmpeg_decoder),
+        }
+    }
+}
+
+impl VideoCache {
+    /// The frame of the video that is on screen at `timestamp_ns`.
+    ///
+    /// If that frame hasn't been decoded yet, this starts decoding it in the background and
+    /// returns the frame that was shown last instead, if any. `egui_ctx` is asked to repaint
+    /// once the frame is ready.
+    ///
+    /// The `blob_row_id` should be the `RowId` of the blob containing the video.
+    #[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+    pub fn frame_at(
+        &mut self,
+        egui_ctx: &egui::Context,
+        blob_row_id: RowId,
+        video_bytes: &[u8],
+        media_type: Option<&MediaType>,
+        timestamp_ns: i64,
+    ) -> Result<Option<ImageInfo>, VideoError> {
+        re_tracing::profile_function!();
+
+        let key = hash((blob_row_id, media_type.map(|mt| mt.as_str())));
+
+        let video = self.videos.entry(key).or_insert_with(|| {
+            let media_type =
+                MediaType::or_guess_from_data(media_type.cloned(), video_bytes).unwrap_or_default();
+            CachedVideo::new(
+                VideoData::load(video_bytes, media_type.as_str())
+                    .map_err(|err| VideoError::Load(Arc::new(err))),
+            )
+        });
+        video.last_use_generation = self.generation;
+
+        let data = Arc::clone(video.video.as_ref().map_err(Clone::clone)?);
+        let frame_index = data
+            .frame_index_at(timestamp_ns)
+            .ok_or(VideoError::NoFrame { timestamp_ns })?;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        video.receive_decoded_frames(self.generation, &mut self.memory_used);
+
+        if let Some(image) = video.frame(frame_index, self.generation) {
+            video.last_shown_frame = Some(frame_index);
+            return Ok(Some(image));
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            Err(VideoError::UnsupportedPlatform)
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let decoder = video.decoder.get_or_insert_with(|| {
+                let egui_ctx = egui_ctx.clone();
+                worker::DecodeWorker::new(
+                    data,
+                    video_bytes.to_vec(),
+                    Arc::clone(&self.new_decoder),
+                    move || egui_ctx.request_repaint(),
+                )
+                .map_err(|err| VideoError::DecoderThread(Arc::new(err)))
+            });
+            decoder
+                .as_mut()
+                .map_err(|err| err.clone())?
+                .request(frame_index);
+
+            Ok(video
+                .last_shown_frame
+                .and_then(|last_shown_frame| video.frame(last_shown_frame, self.generation)))
+        }
+    }
+}
+
+impl Cache for VideoCache {
+    fn begin_frame(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        let max_decode_cache_use = 2_000_000_000;
+
+        #[cfg(target_arch = "wasm32")]
+        let max_decode_cache_use = 500_000_000;
+
+        if self.memory_used > max_decode_cache_use {
+            self.purge_memory();
+        }
+
+        self.generation += 1;
+    }
+
+    fn purge_memory(&mut self) {
+        re_tracing::profile_function!();
+
+        // Flush all frames that weren't shown this frame, and the videos that weren't either.
+
+        let before = self.memory_used;
+
+        self.videos.retain(|_, video| {
+            if video.last_use_generation != self.generation {
+                self.memory_used -= video.memory_used;
+                return false;
+            }
+
+            video.frames.retain(|_, frame| {
+                let retain = frame.last_use_generation == self.generation;
+                if !retain {
+                    let memory_used = frame.image.blob.len() as u64;
+                    video.memory_used -= memory_used;
+                    self.memory_used -= memory_used;
+                }
+                retain
+            });
+
+            true
+        });
+
+        re_log::trace!(
+            "Flushed video cache. Before: {:.2} GB. After: {:.2} GB",
+            before as f64 / 1e9,
+            self.memory_used as f64 / 1e9,
+        );
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+// ---
+
+/// Decoding videos off the UI thread, see [`worker::DecodeWorker`].
+#[cfg(not(target_arch = "wasm32"))]
+mod worker {
+    use std::sync::Arc;
+
+    use crossbeam::channel::{Receiver, Sender};
+
+    use re_video::decode::{DecodeError, FfmpegDecoder, Frame};
+    use re_video::VideoData;
+
+    use super::NUM_FRAMES_PER_DECODE;
+
+    /// Something that decodes the frames of a video, e.g. [`FfmpegDecoder`].
+    pub trait FrameDecoder: Send {
+        /// See [`FfmpegDecoder::decode`].
+        fn decode(
+            &self,
+            video: &VideoData,
+            first_frame: usize,
+            max_frames: usize,
+        ) -> Result<Vec<Frame>, DecodeError>;
+    }
+
+    impl FrameDecoder for FfmpegDecoder {
+        fn decode(
+            &self,
+            video: &VideoData,
+            first_frame: usize,
+            max_frames: usize,
+        ) -> Result<Vec<Frame>, DecodeError> {
+            Self::decode(self, video, first_frame, max_frames)
+        }
+    }
+
+    /// Creates the decoder of a video, given the whole video file.
+    pub type NewDecoder =
+        Arc<dyn Fn(&[u8]) -> Result<Box<dyn FrameDecoder>, DecodeError> + Send + Sync>;
+
+    /// The default [`NewDecoder`].
+    pub fn ffmpeg_decoder(video_bytes: &[u8]) -> Result<Box<dyn FrameDecoder>, DecodeError> {
+        Ok(Box::new(FfmpegDecoder::new(video_bytes)?))
+    }
+
+    struct Decoded {
+        /// The frame that was asked for, see [`DecodeWorker::request`].
+        first_frame: usize,
+
+        frames: Result<Vec<Frame>, Arc<DecodeError>>,
+    }
+
+    /// Decodes the frames of a video on a background thread.
+    ///
+    /// Only the latest request is served: the ones that pile up while decoding, e.g. when
+    /// scrubbing through the video, are skipped.
+    /// The thread exits once the worker is dropped.
+    pub struct DecodeWorker {
+        requests: Sender<usize>,
+        decoded: Receiver<Decoded>,
+
+        /// The first frame of the latest request, until it is decoded.
+        pending: Option<usize>,
+    }
+
+    impl DecodeWorker {
+        /// Starts decoding `video`, whose file is `video_bytes`, calling `on_decoded` whenever
+        /// some frames are ready to be [`Self::receive`]d.
+        pub fn new(
+            video: Arc<VideoData>,
+            video_bytes: Vec<u8>,
+            new_decoder: NewDecoder,
+            on_decoded: impl Fn() + Send + 'static,
+        ) -> std::io::Result<Self> {
+            let (requests, requests_rx) = crossbeam::channel::unbounded::<usize>();
+            let (decoded_tx, decoded) = crossbeam::channel::unbounded();
+
+            std::thread::Builder::new()
+                .name("video_decoder".to_owned())
+                .spawn(move || {
+                    // Writing the video to disk can take a while too.
+                    let decoder = new_decoder(&video_bytes).map_err(Arc::new);
+                    drop(video_bytes);
+
+                    while let Ok(mut first_frame) = requests_rx.recv() {
+                        while let Ok(newer_first_frame) = requests_rx.try_recv() {
+                            first_frame = newer_first_frame;
+                        }
+
+                        let frames = match &decoder {
+                            Ok(decoder) => decoder
+                                .decode(&video, first_frame, NUM_FRAMES_PER_DECODE)
+                                .and_then(|frames| {
+                                    // Don't ask for the same frame over and over again.
+                                    if frames.iter().any(|frame| frame.frame_index == first_frame) {
+                                        Ok(frames)
+                                    } else {
+                                        Err(DecodeError::Ffmpeg(format!(
+                                            "frame #{first_frame} wasn't decoded"
+                                        )))
+                                    }
+                                })
+                                .map_err(Arc::new),
+                            Err(err) => Err(Arc::clone(err)),
+                        };
+
+                        if decoded_tx
+                            .send(Decoded {
+                                first_frame,
+                                frames,
+                            })
+                            .is_err()
+                        {
+                            break;
+                        }
+                        on_decoded();
+                    }
+                })?;
+
+            Ok(Self {
+                requests,
+                decoded,
+                pending: None,
+            })
+        }
+
+        /// Asks for the given frame, unless it is already being decoded.
+        pub fn request(&mut self, frame_index: usize) {
+            let is_pending = self.pending.is_some_and(|first_frame| {
+                (first_frame..first_frame + NUM_FRAMES_PER_DECODE).contains(&frame_index)
+            });
+            if !is_pending && self.requests.send(frame_index).is_ok() {
+                self.pending = Some(frame_index);
+            }
+        }
+
+        /// All the frames that were decoded since the last call.
+        pub fn receive(&mut self) -> Vec<Result<Vec<Frame>, Arc<DecodeError>>> {
+            self.decoded
+                .try_iter()
+                .map(
+                    |Decoded {
+                         first_frame,
+                         frames,
+                     }| {
+                        if self.pending == Some(first_frame) {
+                            self.pending = None;
+                        }
+                        frames
+                    },
+                )
+                .collect()
+        }
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    use re_video::decode::{DecodeError, Frame};
+    use re_video::{Config, Sample, Time, Timescale};
+
+    /// A video with one frame per second, and a keyframe every 10 frames.
+    fn video(num_frames: usize) -> VideoData {
+        let samples = (0..num_frames)
+            .map(|index| Sample {
+                is_sync: index % 10 == 0,
+                decode_timestamp: Time(index as i64),
+                presentation_timestamp: Time(index as i64),
+                duration: Time(1),
+                byte_offset: 0,
+                byte_length: 0,
+            })
+            .collect();
+
+        VideoData::new(
+            Config {
+                codec: "avc1".to_owned(),
+                width: 1,
+                height: 1,
+            },
+            Timescale(1),
+            Time(num_frames as i64),
+            samples,
+        )
+    }
+
+    /// Decodes each frame to a single pixel holding its frame index, once the test allows it.
+    struct FakeDecoder {
+        allow_decode: crossbeam::channel::Receiver<()>,
+    }
+
+    impl worker::FrameDecoder for FakeDecoder {
+        fn decode(
+            &self,
+            video: &VideoData,
+            first_frame: usize,
+            max_frames: usize,
+        ) -> Result<Vec<Frame>, DecodeError> {
+            self.allow_decode.recv().ok();
+
+            Ok(
+                (first_frame..video.num_frames().min(first_frame + max_frames))
+                    .map(|frame_index| Frame {
+                        frame_index,
+                        timestamp_ns: video.frame_timestamp_ns(frame_index).unwrap_or_default(),
+                        width: 1,
+                        height: 1,
+                        data: vec![frame_index as u8; 3],
+                    })
+                    .collect(),
+            )
+        }
+    }
+
+    type NewDecoderResult = Result<Box<dyn worker::FrameDecoder>, DecodeError>;
+
+    const SECOND: i64 = 1_000_000_000;
+
+    struct TestCache {
+        cache: VideoCache,
+        egui_ctx: egui::Context,
+        allow_decode: crossbeam::channel::Sender<()>,
+    }
+
+    impl TestCache {
+        fn new(videos: impl IntoIterator<Item = (RowId, VideoData)>) -> Self {
+            let (allow_decode, allow_decode_rx) = crossbeam::channel::unbounded();
+
+            let mut cache = VideoCache {
+                new_decoder: Arc::new(move |_video_bytes: &[u8]| -> NewDecoderResult {
+                    Ok(Box::new(FakeDecoder {
+                        allow_decode: allow_decode_rx.clone(),
+                    }))
+                }),
+                ..Default::default()
+            };
+            for (blob_row_id, video) in videos {
+                let key = hash((blob_row_id, None::<&str>));
+                cache.videos.insert(key, CachedVideo::new(Ok(video)));
+            }
+
+            Self {
+                cache,
+                egui_ctx: egui::Context::default(),
+                allow_decode,
+            }
+        }
+
+        /// The frame index shown at `timestamp_ns`, if any, without waiting for the decoder.
+        fn shown_frame(&mut self, blob_row_id: RowId, timestamp_ns: i64) -> Option<u8> {
+            self.cache
+                .frame_at(&self.egui_ctx, blob_row_id, &[], None, timestamp_ns)
+                .unwrap()
+                .map(|image| image.blob[0])
+        }
+
+        /// The frame index shown at `timestamp_ns`, once the decoder is done with it.
+        fn decoded_frame(&mut self, blob_row_id: RowId, timestamp_ns: i64) -> u8 {
+            self.allow_decode.send(()).unwrap();
+
+            let expected = (timestamp_ns / SECOND) as u8;
+            let start = std::time::Instant::now();
+            loop {
+                let shown = self.shown_frame(blob_row_id, timestamp_ns);
+                if shown == Some(expected) || start.elapsed().as_secs() > 10 {
+                    return shown.unwrap();
+                }
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+        }
+    }
+
+    #[test]
+    fn frame_selection() {
+        let blob_row_id = RowId::new();
+        let mut cache = TestCache::new([(blob_row_id, video(100))]);
+
+        // Nothing to show until the first frame is decoded.
+        assert_eq!(None, cache.shown_frame(blob_row_id, 0));
+        assert_eq!(0, cache.decoded_frame(blob_row_id, 0));
+
+        // The frame on screen is the last one shown at or before the given time…
+        assert_eq!(Some(1), cache.shown_frame(blob_row_id, SECOND));
+        assert_eq!(Some(1), cache.shown_frame(blob_row_id, SECOND + SECOND / 2));
+        assert_eq!(Some(29), cache.shown_frame(blob_row_id, 29 * SECOND));
+
+        // …and frames are decoded as a batch: seeking past it shows the last frame until the new
+        // one is decoded.
+        assert_eq!(Some(29), cache.shown_frame(blob_row_id, 50 * SECOND));
+        assert_eq!(Some(29), cache.shown_frame(blob_row_id, 51 * SECOND));
+        assert_eq!(50, cache.decoded_frame(blob_row_id, 50 * SECOND));
+        assert_eq!(Some(51), cache.shown_frame(blob_row_id, 51 * SECOND));
+
+        // Frames that were decoded before are still there.
+        assert_eq!(Some(3), cache.shown_frame(blob_row_id, 3 * SECOND));
+
+        // The last frame stays on screen after the end of the video.
+        assert_eq!(99, cache.decoded_frame(blob_row_id, 99 * SECOND));
+        assert_eq!(Some(99), cache.shown_frame(blob_row_id, 1_000 * SECOND));
+
+        assert!(matches!(
+            cache
+                .cache
+                .frame_at(&cache.egui_ctx, blob_row_id, &[], None, -1),
+            Err(VideoError::NoFrame { timestamp_ns: -1 })
+        ));
+    }
+
+    #[test]
+    fn purging() {
+        let (blob_row_id_a, blob_row_id_b) = (RowId::new(), RowId::new());
+        let mut cache = TestCache::new([(blob_row_id_a, video(100)), (blob_row_id_b, video(10))]);
+
+        cache.decoded_frame(blob_row_id_a, 0);
+        cache.decoded_frame(blob_row_id_b, 0);
+        assert_eq!(3 * (30 + 10), cache.cache.memory_used);
+
+        // Only the frames that are shown are kept…
+        cache.cache.begin_frame();
+        assert_eq!(Some(5), cache.shown_frame(blob_row_id_a, 5 * SECOND));
+        assert_eq!(Some(7), cache.shown_frame(blob_row_id_b, 7 * SECOND));
+        cache.cache.purge_memory();
+        assert_eq!(3 * 2, cache.cache.memory_used);
+        assert_eq!(Some(5), cache.shown_frame(blob_row_id_a, 5 * SECOND));
+
+        // …which includes the one shown while another one is being decoded…
+        cache.cache.begin_frame();
+        assert_eq!(Some(7), cache.shown_frame(blob_row_id_b, 8 * SECOND));
+        assert_eq!(Some(5), cache.shown_frame(blob_row_id_a, 50 * SECOND));
+        cache.cache.purge_memory();
+        assert_eq!(3 * 2, cache.cache.memory_used);
+
+        // …and videos that aren't shown at all are dropped.
+        cache.cache.begin_frame();
+        assert_eq!(Some(5), cache.shown_frame(blob_row_id_a, 6 * SECOND));
+        cache.cache.purge_memory();
+        assert_eq!(3, cache.cache.memory_used);
+        assert_eq!(1, cache.cache.videos.len());
+    }
+}
@@ -0,0 +1,23 @@
+// Log a geospatial line string.
+
+#include <rerun.hpp>
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_geo_line_strings");
+    rec.spawn().exit_on_failure();
+
+    auto colorado = rerun::components::GeoLineString::from_lat_lon({
+        {41.0000, -109.0452},
+        {41.0000, -102.0415},
+        {36.9931, -102.0415},
+        {36.9931, -109.0452},
+        {41.0000, -109.0452},
+    });
+
+    rec.log(
+        "colorado",
+        rerun::GeoLineStrings(colorado)
+            .with_radii(rerun::Radius::scene_units(2000.0f))
+            .with_colors(rerun::Color(0, 0, 255))
+    );
+}
@@ -0,0 +1,22 @@
+"""Log a geospatial line string."""
+
+import rerun as rr
+
+rr.init("rerun_example_geo_line_strings", spawn=True)
+
+rr.log(
+    "colorado",
+    rr.GeoLineStrings(
+        line_strings=[
+            [
+                [41.0000, -109.0452],
+                [41.0000, -102.0415],
+                [36.9931, -102.0415],
+                [36.9931, -109.0452],
+                [41.0000, -109.0452],
+            ]
+        ],
+        radii=2000.0,
+        colors=[0, 0, 255],
+    ),
+)
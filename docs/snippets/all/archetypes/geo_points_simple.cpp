@@ -0,0 +1,15 @@
+// Log a geospatial point.
+
+#include <rerun.hpp>
+
+int main() {
+    const auto rec = rerun::RecordingStream("rerun_example_geo_points");
+    rec.spawn().exit_on_failure();
+
+    rec.log(
+        "rerun_hq",
+        rerun::GeoPoints({{59.319221, 18.075631}})
+            .with_radii(rerun::Radius::ui_points(10.0f))
+            .with_colors(rerun::Color(255, 0, 0))
+    );
+}
@@ -0,0 +1,19 @@
+"""Log a video asset, and a reference to each of its frames."""
+
+import sys
+
+import rerun as rr
+
+if len(sys.argv) < 2:
+    print(f"Usage: {sys.argv[0]} <path_to_video.[mp4]>")
+    sys.exit(1)
+
+rr.init("rerun_example_asset_video", spawn=True)
+
+video = rr.AssetVideo(path=sys.argv[1])
+rr.log("video", video, static=True)
+
+# Show every frame of the video at the time it would be shown when playing it.
+for timestamp_ns in video.read_frame_timestamps_ns():
+    rr.set_time_nanos("video_time", timestamp_ns)
+    rr.log("video", rr.VideoFrameReference(timestamp_ns))
@@ -0,0 +1,28 @@
+//! Log a video asset, and a reference to each of its frames.
+
+use rerun::external::anyhow;
+
+fn main() -> anyhow::Result<()> {
+    let args = std::env::args().collect::<Vec<_>>();
+    let Some(path) = args.get(1) else {
+        anyhow::bail!("Usage: {} <path_to_video.[mp4]>", args[0]);
+    };
+
+    let rec = rerun::RecordingStreamBuilder::new("rerun_example_asset_video").spawn()?;
+
+    let video = rerun::AssetVideo::from_file_path(path)?;
+    rec.log_static("video", &video)?;
+
+    // Show every frame of the video at the time it would be shown when playing it.
+    for timestamp_ns in video.read_frame_timestamps_ns()? {
+        rec.set_time_nanos("video_time", timestamp_ns);
+        rec.log(
+            "video",
+            &rerun::VideoFrameReference::new(rerun::components::VideoTimestamp::from_nanoseconds(
+                timestamp_ns,
+            )),
+        )?;
+    }
+
+    Ok(())
+}
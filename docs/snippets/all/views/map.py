@@ -0,0 +1,21 @@
+"""Use a blueprint to create a map view."""
+
+import rerun as rr
+import rerun.blueprint as rrb
+
+rr.init("rerun_example_map_view", spawn=True)
+
+rr.log("points", rr.GeoPoints(positions=[[47.6344, 19.1397], [47.6334, 19.1399]], radii=rr.Radius.ui_points(20.0)))
+
+# Create a map view to display the points.
+blueprint = rrb.Blueprint(
+    rrb.MapView(
+        origin="points",
+        name="MapView",
+        # Load map tiles from a local directory, in the `{zoom}/{x}/{y}.png` layout.
+        map_options=rrb.archetypes.MapOptions(tile_directory="/path/to/tiles"),
+    ),
+    collapse_panels=True,
+)
+
+rr.send_blueprint(blueprint)
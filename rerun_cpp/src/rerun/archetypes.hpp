@@ -6,6 +6,7 @@
 #include "archetypes/arrows2d.hpp"
 #include "archetypes/arrows3d.hpp"
 #include "archetypes/asset3d.hpp"
+#include "archetypes/asset_video.hpp"
 #include "archetypes/bar_chart.hpp"
 #include "archetypes/boxes2d.hpp"
 #include "archetypes/boxes3d.hpp"
@@ -13,6 +14,10 @@
 #include "archetypes/depth_image.hpp"
 #include "archetypes/disconnected_space.hpp"
 #include "archetypes/ellipsoids.hpp"
+#include "archetypes/geo_line_strings.hpp"
+#include "archetypes/geo_points.hpp"
+#include "archetypes/graph_edges.hpp"
+#include "archetypes/graph_nodes.hpp"
 #include "archetypes/image.hpp"
 #include "archetypes/image_encoded.hpp"
 #include "archetypes/line_strips2d.hpp"
@@ -29,4 +34,5 @@
 #include "archetypes/text_document.hpp"
 #include "archetypes/text_log.hpp"
 #include "archetypes/transform3d.hpp"
+#include "archetypes/video_frame_reference.hpp"
 #include "archetypes/view_coordinates.hpp"
@@ -0,0 +1,83 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/asset_video.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../compiler_utils.hpp"
+#include "../components/blob.hpp"
+#include "../components/media_type.hpp"
+#include "../data_cell.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A video file, e.g. an MP4 containing H.264 or AV1.
+    ///
+    /// The video is stored as-is and decoded by the viewer, which is a lot more compact than
+    /// logging every frame as an image.
+    ///
+    /// To show it, log `archetypes::VideoFrameReference`s on the same entity, on the timeline(s) of
+    /// your choice: the viewer then shows the referenced frame of the video at each point in time.
+    ///
+    /// Decoding requires the `ffmpeg` executable to be installed and on the `PATH` of the
+    /// machine running the viewer. Videos can't be played in the web viewer yet.
+    struct AssetVideo {
+        /// The asset's bytes.
+        rerun::components::Blob blob;
+
+        /// The Media Type of the asset.
+        ///
+        /// Supported values:
+        /// * `video/mp4`
+        ///
+        /// If omitted, the viewer will try to guess from the data blob.
+        /// If it cannot guess, it won't be able to render the asset.
+        std::optional<rerun::components::MediaType> media_type;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.AssetVideoIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+
+      public:
+        AssetVideo() = default;
+        AssetVideo(AssetVideo&& other) = default;
+
+        explicit AssetVideo(rerun::components::Blob _blob) : blob(std::move(_blob)) {}
+
+        /// The Media Type of the asset.
+        ///
+        /// Supported values:
+        /// * `video/mp4`
+        ///
+        /// If omitted, the viewer will try to guess from the data blob.
+        /// If it cannot guess, it won't be able to render the asset.
+        AssetVideo with_media_type(rerun::components::MediaType _media_type) && {
+            media_type = std::move(_media_type);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::AssetVideo> {
+        /// Serialize all set component batches.
+        static Result<std::vector<DataCell>> serialize(const archetypes::AssetVideo& archetype);
+    };
+} // namespace rerun
@@ -0,0 +1,43 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/geo_points.fbs".
+
+#include "geo_points.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {}
+
+namespace rerun {
+
+    Result<std::vector<DataCell>> AsComponents<archetypes::GeoPoints>::serialize(
+        const archetypes::GeoPoints& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<DataCell> cells;
+        cells.reserve(4);
+
+        {
+            auto result = DataCell::from_loggable(archetype.positions);
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.radii.has_value()) {
+            auto result = DataCell::from_loggable(archetype.radii.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.colors.has_value()) {
+            auto result = DataCell::from_loggable(archetype.colors.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        {
+            auto indicator = GeoPoints::IndicatorComponent();
+            auto result = DataCell::from_loggable(indicator);
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
@@ -0,0 +1,38 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/graph_edges.fbs".
+
+#include "graph_edges.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {}
+
+namespace rerun {
+
+    Result<std::vector<DataCell>> AsComponents<archetypes::GraphEdges>::serialize(
+        const archetypes::GraphEdges& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<DataCell> cells;
+        cells.reserve(3);
+
+        {
+            auto result = DataCell::from_loggable(archetype.edges);
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.graph_type.has_value()) {
+            auto result = DataCell::from_loggable(archetype.graph_type.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        {
+            auto indicator = GraphEdges::IndicatorComponent();
+            auto result = DataCell::from_loggable(indicator);
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
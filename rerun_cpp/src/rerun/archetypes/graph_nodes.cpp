@@ -0,0 +1,53 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/graph_nodes.fbs".
+
+#include "graph_nodes.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {}
+
+namespace rerun {
+
+    Result<std::vector<DataCell>> AsComponents<archetypes::GraphNodes>::serialize(
+        const archetypes::GraphNodes& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<DataCell> cells;
+        cells.reserve(6);
+
+        {
+            auto result = DataCell::from_loggable(archetype.node_ids);
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.positions.has_value()) {
+            auto result = DataCell::from_loggable(archetype.positions.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.colors.has_value()) {
+            auto result = DataCell::from_loggable(archetype.colors.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.labels.has_value()) {
+            auto result = DataCell::from_loggable(archetype.labels.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.radii.has_value()) {
+            auto result = DataCell::from_loggable(archetype.radii.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        {
+            auto indicator = GraphNodes::IndicatorComponent();
+            auto result = DataCell::from_loggable(indicator);
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
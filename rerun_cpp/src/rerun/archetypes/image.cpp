@@ -14,13 +14,23 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<DataCell> cells;
-        cells.reserve(4);
+        cells.reserve(6);
 
         {
             auto result = DataCell::from_loggable(archetype.data);
             RR_RETURN_NOT_OK(result.error);
             cells.push_back(std::move(result.value));
         }
+        if (archetype.color_model.has_value()) {
+            auto result = DataCell::from_loggable(archetype.color_model.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.pixel_format.has_value()) {
+            auto result = DataCell::from_loggable(archetype.pixel_format.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
         if (archetype.opacity.has_value()) {
             auto result = DataCell::from_loggable(archetype.opacity.value());
             RR_RETURN_NOT_OK(result.error);
@@ -5,8 +5,10 @@
 
 #include "../collection.hpp"
 #include "../compiler_utils.hpp"
+#include "../components/color_model.hpp"
 #include "../components/draw_order.hpp"
 #include "../components/opacity.hpp"
+#include "../components/pixel_format.hpp"
 #include "../components/tensor_data.hpp"
 #include "../data_cell.hpp"
 #include "../indicator_component.hpp"
@@ -32,6 +34,10 @@ namespace rerun::archetypes {
     /// Leading and trailing unit-dimensions are ignored, so that
     /// `1x480x640x3x1` is treated as a `480x640x3` RGB image.
     ///
+    /// Use a `components::ColorModel` to treat a 3- or 4-channel image as `BGR` or `BGRA` instead,
+    /// or a `components::PixelFormat` to log the raw `NV12`, `YUY2` or Bayer buffer of a camera as-is.
+    /// The viewer converts these to RGB when displaying them.
+    ///
     /// Rerun also supports compressed images (JPEG, PNG, …), using `archetypes::ImageEncoded`.
     /// Compressing images can save a lot of bandwidth and memory.
     ///
@@ -77,6 +83,17 @@ namespace rerun::archetypes {
         /// The image data. Should always be a 2- or 3-dimensional tensor.
         rerun::components::TensorData data;
 
+        /// The color model of the pixels, e.g. `BGR`.
+        ///
+        /// If not set, it is inferred from the number of channels: `L`, `RGB` or `RGBA`.
+        /// Ignored if a `components::PixelFormat` is set.
+        std::optional<rerun::components::ColorModel> color_model;
+
+        /// The pixel format of the raw image data, e.g. `NV12` or `BayerRGGB`.
+        ///
+        /// If set, the image data is the raw buffer of that format, see `components::PixelFormat`.
+        std::optional<rerun::components::PixelFormat> pixel_format;
+
         /// Opacity of the image, useful for layering several images.
         ///
         /// Defaults to 1.0 (fully opaque).
@@ -131,6 +148,25 @@ namespace rerun::archetypes {
         Image() = default;
         Image(Image&& other) = default;
 
+        /// The color model of the pixels, e.g. `BGR`.
+        ///
+        /// If not set, it is inferred from the number of channels: `L`, `RGB` or `RGBA`.
+        /// Ignored if a `components::PixelFormat` is set.
+        Image with_color_model(rerun::components::ColorModel _color_model) && {
+            color_model = std::move(_color_model);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// The pixel format of the raw image data, e.g. `NV12` or `BayerRGGB`.
+        ///
+        /// If set, the image data is the raw buffer of that format, see `components::PixelFormat`.
+        Image with_pixel_format(rerun::components::PixelFormat _pixel_format) && {
+            pixel_format = std::move(_pixel_format);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
         /// Opacity of the image, useful for layering several images.
         ///
         /// Defaults to 1.0 (fully opaque).
@@ -14,7 +14,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<DataCell> cells;
-        cells.reserve(5);
+        cells.reserve(8);
 
         {
             auto result = DataCell::from_loggable(archetype.image_from_camera);
@@ -36,6 +36,21 @@ namespace rerun {
             RR_RETURN_NOT_OK(result.error);
             cells.push_back(std::move(result.value));
         }
+        if (archetype.distortion_model.has_value()) {
+            auto result = DataCell::from_loggable(archetype.distortion_model.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.distortion_coefficients.has_value()) {
+            auto result = DataCell::from_loggable(archetype.distortion_coefficients.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        if (archetype.undistort_images.has_value()) {
+            auto result = DataCell::from_loggable(archetype.undistort_images.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
         {
             auto indicator = Pinhole::IndicatorComponent();
             auto result = DataCell::from_loggable(indicator);
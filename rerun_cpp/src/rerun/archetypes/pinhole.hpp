@@ -5,9 +5,12 @@
 
 #include "../collection.hpp"
 #include "../compiler_utils.hpp"
+#include "../components/distortion_coefficients.hpp"
+#include "../components/distortion_model.hpp"
 #include "../components/image_plane_distance.hpp"
 #include "../components/pinhole_projection.hpp"
 #include "../components/resolution.hpp"
+#include "../components/undistort_images.hpp"
 #include "../components/view_coordinates.hpp"
 #include "../data_cell.hpp"
 #include "../indicator_component.hpp"
@@ -122,6 +125,25 @@ namespace rerun::archetypes {
         /// This is only used for visualization purposes, and does not affect the projection itself.
         std::optional<rerun::components::ImagePlaneDistance> image_plane_distance;
 
+        /// The lens distortion model of the camera.
+        ///
+        /// Only has an effect if `components::DistortionCoefficients` are set as well.
+        /// Defaults to `BrownConrady`.
+        std::optional<rerun::components::DistortionModel> distortion_model;
+
+        /// The coefficients of the lens distortion model, see `components::DistortionCoefficients`.
+        ///
+        /// If not set, the camera is assumed to be free of lens distortion.
+        std::optional<rerun::components::DistortionCoefficients> distortion_coefficients;
+
+        /// Whether images logged under this camera should be shown with the lens distortion removed.
+        ///
+        /// Undistorted images line up with 3D content that is projected into the camera.
+        /// Otherwise, 3D points and line strips are distorted to line up with the images instead,
+        /// but other 3D content is projected into 2D views of the camera without lens distortion.
+        /// Defaults to false.
+        std::optional<rerun::components::UndistortImages> undistort_images;
+
       public:
         static constexpr const char IndicatorComponentName[] = "rerun.components.PinholeIndicator";
 
@@ -247,6 +269,39 @@ namespace rerun::archetypes {
             // See: https://github.com/rerun-io/rerun/issues/4027
             RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
         }
+
+        /// The lens distortion model of the camera.
+        ///
+        /// Only has an effect if `components::DistortionCoefficients` are set as well.
+        /// Defaults to `BrownConrady`.
+        Pinhole with_distortion_model(rerun::components::DistortionModel _distortion_model) && {
+            distortion_model = std::move(_distortion_model);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// The coefficients of the lens distortion model, see `components::DistortionCoefficients`.
+        ///
+        /// If not set, the camera is assumed to be free of lens distortion.
+        Pinhole with_distortion_coefficients(
+            rerun::components::DistortionCoefficients _distortion_coefficients
+        ) && {
+            distortion_coefficients = std::move(_distortion_coefficients);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+
+        /// Whether images logged under this camera should be shown with the lens distortion removed.
+        ///
+        /// Undistorted images line up with 3D content that is projected into the camera.
+        /// Otherwise, 3D points and line strips are distorted to line up with the images instead,
+        /// but other 3D content is projected into 2D views of the camera without lens distortion.
+        /// Defaults to false.
+        Pinhole with_undistort_images(rerun::components::UndistortImages _undistort_images) && {
+            undistort_images = std::move(_undistort_images);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
     };
 
 } // namespace rerun::archetypes
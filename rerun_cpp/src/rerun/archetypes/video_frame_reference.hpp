@@ -0,0 +1,59 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/video_frame_reference.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../components/video_timestamp.hpp"
+#include "../data_cell.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: References a single frame of the `archetypes::AssetVideo` logged on the same entity.
+    ///
+    /// Log one of these on a timeline for each point in time that should show a frame of the video.
+    /// When scrubbing or playing that timeline, the viewer shows the referenced frames, decoding
+    /// them on demand.
+    struct VideoFrameReference {
+        /// Which frame of the video to show.
+        ///
+        /// This is the last frame that is shown at or before this timestamp, i.e. the one that would
+        /// be on screen at that point when playing the video.
+        rerun::components::VideoTimestamp timestamp;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.VideoFrameReferenceIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+
+      public:
+        VideoFrameReference() = default;
+        VideoFrameReference(VideoFrameReference&& other) = default;
+
+        explicit VideoFrameReference(rerun::components::VideoTimestamp _timestamp)
+            : timestamp(std::move(_timestamp)) {}
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::VideoFrameReference> {
+        /// Serialize all set component batches.
+        static Result<std::vector<DataCell>> serialize(
+            const archetypes::VideoFrameReference& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,33 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/map_options.fbs".
+
+#include "map_options.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {}
+
+namespace rerun {
+
+    Result<std::vector<DataCell>> AsComponents<blueprint::archetypes::MapOptions>::serialize(
+        const blueprint::archetypes::MapOptions& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<DataCell> cells;
+        cells.reserve(2);
+
+        if (archetype.tile_directory.has_value()) {
+            auto result = DataCell::from_loggable(archetype.tile_directory.value());
+            RR_RETURN_NOT_OK(result.error);
+            cells.push_back(std::move(result.value));
+        }
+        {
+            auto indicator = MapOptions::IndicatorComponent();
+            auto result = DataCell::from_loggable(indicator);
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return cells;
+    }
+} // namespace rerun
@@ -0,0 +1,63 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/map_options.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/tile_directory.hpp"
+#include "../../collection.hpp"
+#include "../../compiler_utils.hpp"
+#include "../../data_cell.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for the background of a map view.
+    struct MapOptions {
+        /// Local directory the map tiles are loaded from.
+        ///
+        /// Without tiles, only a graticule of latitude and longitude lines is shown.
+        std::optional<rerun::blueprint::components::TileDirectory> tile_directory;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.MapOptionsIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+
+      public:
+        MapOptions() = default;
+        MapOptions(MapOptions&& other) = default;
+
+        /// Local directory the map tiles are loaded from.
+        ///
+        /// Without tiles, only a graticule of latitude and longitude lines is shown.
+        MapOptions with_tile_directory(rerun::blueprint::components::TileDirectory _tile_directory
+        ) && {
+            tile_directory = std::move(_tile_directory);
+            // See: https://github.com/rerun-io/rerun/issues/4027
+            RR_WITH_MAYBE_UNINITIALIZED_DISABLED(return std::move(*this);)
+        }
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::MapOptions> {
+        /// Serialize all set component batches.
+        static Result<std::vector<DataCell>> serialize(
+            const blueprint::archetypes::MapOptions& archetype
+        );
+    };
+} // namespace rerun
@@ -21,7 +21,8 @@ namespace rerun::blueprint::components {
     ///
     /// The `/**` suffix matches the whole subtree, i.e. self and any child, recursively
     /// (`/world/**` matches both `/world` and `/world/car/driver`).
-    /// Other uses of `*` are not (yet) supported.
+    /// `*` matches a single part, or any characters within a part (`/robot_*/camera` matches `/robot_1/camera`),
+    /// and `**` in the middle of a path matches any number of parts (`/world/**/wheel` matches `/world/car/wheel`).
     struct QueryExpression {
         rerun::datatypes::Utf8 filter;
 
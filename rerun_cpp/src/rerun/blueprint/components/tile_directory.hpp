@@ -0,0 +1,67 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/tile_directory.fbs".
+
+#pragma once
+
+#include "../../datatypes/utf8.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+namespace rerun::blueprint::components {
+    /// **Component**: A local directory containing map tiles.
+    ///
+    /// Tiles are expected in the `{zoom}/{x}/{y}.png` layout of the common "slippy map" tile scheme,
+    /// using the Web Mercator projection.
+    /// An empty path means that no tiles are shown.
+    struct TileDirectory {
+        rerun::datatypes::Utf8 path;
+
+      public:
+        TileDirectory() = default;
+
+        TileDirectory(rerun::datatypes::Utf8 path_) : path(std::move(path_)) {}
+
+        TileDirectory& operator=(rerun::datatypes::Utf8 path_) {
+            path = std::move(path_);
+            return *this;
+        }
+
+        TileDirectory(std::string path_) : path(std::move(path_)) {}
+
+        TileDirectory& operator=(std::string path_) {
+            path = std::move(path_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8 datatype
+        operator rerun::datatypes::Utf8() const {
+            return path;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8) == sizeof(blueprint::components::TileDirectory));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::TileDirectory> {
+        static constexpr const char Name[] = "rerun.blueprint.components.TileDirectory";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::TileDirectory` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::TileDirectory* instances, size_t num_instances
+        ) {
+            return Loggable<rerun::datatypes::Utf8>::to_arrow(&instances->path, num_instances);
+        }
+    };
+} // namespace rerun
@@ -15,13 +15,20 @@
 #include "components/colormap.hpp"
 #include "components/depth_meter.hpp"
 #include "components/disconnected_space.hpp"
+#include "components/distortion_coefficients.hpp"
+#include "components/distortion_model.hpp"
 #include "components/draw_order.hpp"
 #include "components/fill_ratio.hpp"
 #include "components/gamma_correction.hpp"
+#include "components/geo_line_string.hpp"
+#include "components/graph_edge.hpp"
+#include "components/graph_node.hpp"
+#include "components/graph_type.hpp"
 #include "components/half_size2d.hpp"
 #include "components/half_size3d.hpp"
 #include "components/image_plane_distance.hpp"
 #include "components/keypoint_id.hpp"
+#include "components/lat_lon.hpp"
 #include "components/line_strip2d.hpp"
 #include "components/line_strip3d.hpp"
 #include "components/magnification_filter.hpp"
@@ -32,6 +39,7 @@
 #include "components/opacity.hpp"
 #include "components/out_of_tree_transform3d.hpp"
 #include "components/pinhole_projection.hpp"
+#include "components/pixel_format.hpp"
 #include "components/position2d.hpp"
 #include "components/position3d.hpp"
 #include "components/radius.hpp"
@@ -53,6 +61,8 @@
 #include "components/transform_mat3x3.hpp"
 #include "components/translation3d.hpp"
 #include "components/triangle_indices.hpp"
+#include "components/undistort_images.hpp"
 #include "components/vector2d.hpp"
 #include "components/vector3d.hpp"
+#include "components/video_timestamp.hpp"
 #include "components/view_coordinates.hpp"
@@ -13,6 +13,8 @@ namespace rerun {
             arrow::field("L", arrow::null(), true),
             arrow::field("RGB", arrow::null(), true),
             arrow::field("RGBA", arrow::null(), true),
+            arrow::field("BGR", arrow::null(), true),
+            arrow::field("BGRA", arrow::null(), true),
         });
         return datatype;
     }
@@ -18,6 +18,8 @@ namespace rerun::components {
     /// **Component**: Specified what color components are present in an `archetypes::Image`.
     ///
     /// This combined with `components::ChannelDataType` determines the pixel format of an image.
+    /// Formats that cannot be described this way (e.g. chroma-downsampled or Bayer-mosaiced images)
+    /// use a `components::PixelFormat` instead.
     enum class ColorModel : uint8_t {
 
         /// Grayscale luminance intencity/brightness/value, sometimes called `Y`
@@ -28,6 +30,12 @@ namespace rerun::components {
 
         /// Red, Green, Blue, Alpha
         RGBA = 3,
+
+        /// Blue, Green, Red
+        BGR = 4,
+
+        /// Blue, Green, Red, Alpha
+        BGRA = 5,
     };
 } // namespace rerun::components
 
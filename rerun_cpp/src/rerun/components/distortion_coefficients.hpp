@@ -0,0 +1,77 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/distortion_coefficients.fbs".
+
+#pragma once
+
+#include "../datatypes/distortion_coefficients.hpp"
+#include "../result.hpp"
+
+#include <array>
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: Coefficients of the lens distortion of a camera.
+    ///
+    /// Stored in the same order as OpenCV uses them: `[k1, k2, p1, p2, k3, k4, k5, k6]`.
+    /// How they are interpreted depends on the `components::DistortionModel`:
+    /// - `BrownConrady`: `k1`, `k2`, `p1`, `p2`, `k3` (OpenCV's 5 coefficient model).
+    /// - `KannalaBrandt`: the first four values are the fisheye coefficients `k1`, `k2`, `k3`, `k4`.
+    /// - `Rational`: all eight coefficients (OpenCV's `CALIB_RATIONAL_MODEL`).
+    ///
+    /// Unused coefficients should be set to zero.
+    struct DistortionCoefficients {
+        rerun::datatypes::DistortionCoefficients coefficients;
+
+      public:
+        DistortionCoefficients() = default;
+
+        DistortionCoefficients(rerun::datatypes::DistortionCoefficients coefficients_)
+            : coefficients(coefficients_) {}
+
+        DistortionCoefficients& operator=(rerun::datatypes::DistortionCoefficients coefficients_) {
+            coefficients = coefficients_;
+            return *this;
+        }
+
+        DistortionCoefficients(std::array<float, 8> coefficients_) : coefficients(coefficients_) {}
+
+        DistortionCoefficients& operator=(std::array<float, 8> coefficients_) {
+            coefficients = coefficients_;
+            return *this;
+        }
+
+        /// Cast to the underlying DistortionCoefficients datatype
+        operator rerun::datatypes::DistortionCoefficients() const {
+            return coefficients;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(
+        sizeof(rerun::datatypes::DistortionCoefficients) ==
+        sizeof(components::DistortionCoefficients)
+    );
+
+    /// \private
+    template <>
+    struct Loggable<components::DistortionCoefficients> {
+        static constexpr const char Name[] = "rerun.components.DistortionCoefficients";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::DistortionCoefficients>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::DistortionCoefficients` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::DistortionCoefficients* instances, size_t num_instances
+        ) {
+            return Loggable<rerun::datatypes::DistortionCoefficients>::to_arrow(
+                &instances->coefficients,
+                num_instances
+            );
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,63 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/distortion_model.fbs".
+
+#pragma once
+
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    class Array;
+    class DataType;
+    class SparseUnionBuilder;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: The lens distortion model of a camera.
+    ///
+    /// The model maps the undistorted, normalized image coordinates `(x/z, y/z)` of a point in camera space
+    /// to where it actually ends up on the sensor, before the `components::PinholeProjection` is applied.
+    /// Its parameters are given by `components::DistortionCoefficients`.
+    enum class DistortionModel : uint8_t {
+
+        /// Brown-Conrady model with three radial (`k1`, `k2`, `k3`) and two tangential (`p1`, `p2`) coefficients.
+        ///
+        /// This is OpenCV's default camera model.
+        BrownConrady = 1,
+
+        /// Kannala-Brandt fisheye model with four coefficients (`k1`, `k2`, `k3`, `k4`).
+        ///
+        /// This is the model used by OpenCV's `fisheye` module.
+        KannalaBrandt = 2,
+
+        /// OpenCV's rational model with six radial (`k1`-`k6`) and two tangential (`p1`, `p2`) coefficients.
+        Rational = 3,
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::DistortionModel> {
+        static constexpr const char Name[] = "rerun.components.DistortionModel";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::components::DistortionModel` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::DistortionModel* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::SparseUnionBuilder* builder, const components::DistortionModel* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,28 @@
+#include <utility>
+#include "geo_line_string.hpp"
+
+// Uncomment for better auto-complete while editing the extension.
+// #define EDIT_EXTENSION
+
+namespace rerun {
+    namespace components {
+
+#ifdef EDIT_EXTENSION
+        struct GeoLineStringExt {
+            rerun::Collection<rerun::datatypes::DVec2D> lat_lon;
+#define GeoLineString GeoLineStringExt
+
+            // <CODEGEN_COPY_TO_HEADER>
+
+            /// Creates a new GeoLineString object based on [EPSG:4326](https://epsg.io/4326) latitude and longitude (North/East-positive degrees).
+            static GeoLineString from_lat_lon(Collection<datatypes::DVec2D> lat_lon_) {
+                GeoLineString line_string;
+                line_string.lat_lon = std::move(lat_lon_);
+                return line_string;
+            }
+
+            // </CODEGEN_COPY_TO_HEADER>
+        };
+#endif
+    } // namespace components
+} // namespace rerun
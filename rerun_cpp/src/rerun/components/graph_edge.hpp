@@ -0,0 +1,65 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/graph_edge.fbs".
+
+#pragma once
+
+#include "../datatypes/utf8_pair.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+namespace rerun::components {
+    /// **Component**: An edge in a graph connecting two nodes, given by their `components::GraphNode` IDs.
+    ///
+    /// Whether the edge points from `first` to `second` depends on the `components::GraphType`.
+    struct GraphEdge {
+        rerun::datatypes::Utf8Pair edge;
+
+      public:
+        // Extensions to generated type defined in 'graph_edge_ext.cpp'
+
+        /// Construct `GraphEdge` from the IDs of the two nodes it connects.
+        GraphEdge(std::string first, std::string second)
+            : edge(std::move(first), std::move(second)) {}
+
+      public:
+        GraphEdge() = default;
+
+        GraphEdge(rerun::datatypes::Utf8Pair edge_) : edge(std::move(edge_)) {}
+
+        GraphEdge& operator=(rerun::datatypes::Utf8Pair edge_) {
+            edge = std::move(edge_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8Pair datatype
+        operator rerun::datatypes::Utf8Pair() const {
+            return edge;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8Pair) == sizeof(components::GraphEdge));
+
+    /// \private
+    template <>
+    struct Loggable<components::GraphEdge> {
+        static constexpr const char Name[] = "rerun.components.GraphEdge";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8Pair>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::GraphEdge` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::GraphEdge* instances, size_t num_instances
+        ) {
+            return Loggable<rerun::datatypes::Utf8Pair>::to_arrow(&instances->edge, num_instances);
+        }
+    };
+} // namespace rerun
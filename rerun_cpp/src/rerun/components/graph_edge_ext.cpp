@@ -0,0 +1,26 @@
+#include <string>
+#include <utility>
+#include "graph_edge.hpp"
+
+// Uncomment for better auto-complete while editing the extension.
+// #define EDIT_EXTENSION
+
+namespace rerun {
+    namespace components {
+
+#ifdef EDIT_EXTENSION
+        struct GraphEdgeExt {
+            rerun::datatypes::Utf8Pair edge;
+#define GraphEdge GraphEdgeExt
+
+            // <CODEGEN_COPY_TO_HEADER>
+
+            /// Construct `GraphEdge` from the IDs of the two nodes it connects.
+            GraphEdge(std::string first, std::string second)
+                : edge(std::move(first), std::move(second)) {}
+
+            // </CODEGEN_COPY_TO_HEADER>
+        };
+#endif
+    } // namespace components
+} // namespace rerun
@@ -0,0 +1,75 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/graph_node.fbs".
+
+#pragma once
+
+#include "../datatypes/utf8.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+namespace rerun::components {
+    /// **Component**: A string-based ID representing a node in a graph.
+    ///
+    /// Node IDs are unique within an entity, and are referenced by the `components::GraphEdge`s logged to the same entity.
+    struct GraphNode {
+        rerun::datatypes::Utf8 id;
+
+      public:
+        // Extensions to generated type defined in 'graph_node_ext.cpp'
+
+        /// Construct `GraphNode` from a null-terminated UTF8 string.
+        GraphNode(const char* str) : id(str) {}
+
+        const char* c_str() const {
+            return id.c_str();
+        }
+
+      public:
+        GraphNode() = default;
+
+        GraphNode(rerun::datatypes::Utf8 id_) : id(std::move(id_)) {}
+
+        GraphNode& operator=(rerun::datatypes::Utf8 id_) {
+            id = std::move(id_);
+            return *this;
+        }
+
+        GraphNode(std::string id_) : id(std::move(id_)) {}
+
+        GraphNode& operator=(std::string id_) {
+            id = std::move(id_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8 datatype
+        operator rerun::datatypes::Utf8() const {
+            return id;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8) == sizeof(components::GraphNode));
+
+    /// \private
+    template <>
+    struct Loggable<components::GraphNode> {
+        static constexpr const char Name[] = "rerun.components.GraphNode";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::GraphNode` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::GraphNode* instances, size_t num_instances
+        ) {
+            return Loggable<rerun::datatypes::Utf8>::to_arrow(&instances->id, num_instances);
+        }
+    };
+} // namespace rerun
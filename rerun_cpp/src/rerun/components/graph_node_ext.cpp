@@ -0,0 +1,27 @@
+#include "graph_node.hpp"
+
+// Uncomment for better auto-complete while editing the extension.
+// #define EDIT_EXTENSION
+
+namespace rerun {
+    namespace components {
+
+#ifdef EDIT_EXTENSION
+        struct GraphNodeExt {
+            rerun::datatypes::Utf8 id;
+#define GraphNode GraphNodeExt
+
+            // <CODEGEN_COPY_TO_HEADER>
+
+            /// Construct `GraphNode` from a null-terminated UTF8 string.
+            GraphNode(const char* str) : id(str) {}
+
+            const char* c_str() const {
+                return id.c_str();
+            }
+
+            // </CODEGEN_COPY_TO_HEADER>
+        };
+#endif
+    } // namespace components
+} // namespace rerun
@@ -0,0 +1,52 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/graph_type.fbs".
+
+#pragma once
+
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    class Array;
+    class DataType;
+    class SparseUnionBuilder;
+} // namespace arrow
+
+namespace rerun::components {
+    /// **Component**: Specifies if a graph has directed or undirected edges.
+    enum class GraphType : uint8_t {
+
+        /// The graph has undirected edges.
+        Undirected = 1,
+
+        /// The graph has directed edges, pointing from the first to the second node of each `components::GraphEdge`.
+        Directed = 2,
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<components::GraphType> {
+        static constexpr const char Name[] = "rerun.components.GraphType";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::components::GraphType` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::GraphType* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::SparseUnionBuilder* builder, const components::GraphType* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
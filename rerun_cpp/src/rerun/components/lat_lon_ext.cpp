@@ -0,0 +1,33 @@
+#include "lat_lon.hpp"
+
+// Uncomment for better auto-complete while editing the extension.
+// #define EDIT_EXTENSION
+
+namespace rerun {
+    namespace components {
+
+#ifdef EDIT_EXTENSION
+        struct LatLonExt {
+            double lat_lon[2];
+#define LatLon LatLonExt
+
+            // <CODEGEN_COPY_TO_HEADER>
+
+            /// Construct LatLon from latitude and longitude, in degrees.
+            LatLon(double lat, double lon) : lat_lon{lat, lon} {}
+
+            /// The latitude, in degrees.
+            double latitude() const {
+                return lat_lon.x();
+            }
+
+            /// The longitude, in degrees.
+            double longitude() const {
+                return lat_lon.y();
+            }
+
+            // </CODEGEN_COPY_TO_HEADER>
+        };
+#endif
+    } // namespace components
+} // namespace rerun
@@ -87,6 +87,16 @@ namespace rerun::components {
             return "model/stl";
         }
 
+        // ------------------------------------------------
+        // Video:
+
+        /// [MP4 video](https://en.wikipedia.org/wiki/MP4_file_format): `video/mp4`.
+        ///
+        /// <https://www.iana.org/assignments/media-types/video/mp4>
+        static MediaType mp4() {
+            return "video/mp4";
+        }
+
       public:
         MediaType() = default;
 
@@ -0,0 +1,71 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/undistort_images.fbs".
+
+#pragma once
+
+#include "../datatypes/bool.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: Whether to undistort the images of a camera when displaying them.
+    ///
+    /// Undistorted images line up with 3D content projected into the camera,
+    /// but are resampled and may lose a bit of sharpness.
+    ///
+    /// When images are shown distorted, 3D points and line strips projected into 2D views of the camera
+    /// are distorted to match them. Other 3D content is projected without lens distortion.
+    struct UndistortImages {
+        /// If true, images logged under the camera are shown with the lens distortion removed.
+        rerun::datatypes::Bool undistort;
+
+      public:
+        UndistortImages() = default;
+
+        UndistortImages(rerun::datatypes::Bool undistort_) : undistort(undistort_) {}
+
+        UndistortImages& operator=(rerun::datatypes::Bool undistort_) {
+            undistort = undistort_;
+            return *this;
+        }
+
+        UndistortImages(bool value_) : undistort(value_) {}
+
+        UndistortImages& operator=(bool value_) {
+            undistort = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying Bool datatype
+        operator rerun::datatypes::Bool() const {
+            return undistort;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Bool) == sizeof(components::UndistortImages));
+
+    /// \private
+    template <>
+    struct Loggable<components::UndistortImages> {
+        static constexpr const char Name[] = "rerun.components.UndistortImages";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Bool>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::UndistortImages` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::UndistortImages* instances, size_t num_instances
+        ) {
+            return Loggable<rerun::datatypes::Bool>::to_arrow(
+                &instances->undistort,
+                num_instances
+            );
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,64 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/components/video_timestamp.fbs".
+
+#pragma once
+
+#include "../datatypes/time_int.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::components {
+    /// **Component**: A timestamp within an `archetypes::AssetVideo`, in nanoseconds since its first frame.
+    struct VideoTimestamp {
+        rerun::datatypes::TimeInt timestamp_ns;
+
+      public:
+        VideoTimestamp() = default;
+
+        VideoTimestamp(rerun::datatypes::TimeInt timestamp_ns_) : timestamp_ns(timestamp_ns_) {}
+
+        VideoTimestamp& operator=(rerun::datatypes::TimeInt timestamp_ns_) {
+            timestamp_ns = timestamp_ns_;
+            return *this;
+        }
+
+        VideoTimestamp(int64_t timestamp_ns_) : timestamp_ns(timestamp_ns_) {}
+
+        VideoTimestamp& operator=(int64_t timestamp_ns_) {
+            timestamp_ns = timestamp_ns_;
+            return *this;
+        }
+
+        /// Cast to the underlying TimeInt datatype
+        operator rerun::datatypes::TimeInt() const {
+            return timestamp_ns;
+        }
+    };
+} // namespace rerun::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::TimeInt) == sizeof(components::VideoTimestamp));
+
+    /// \private
+    template <>
+    struct Loggable<components::VideoTimestamp> {
+        static constexpr const char Name[] = "rerun.components.VideoTimestamp";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::TimeInt>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::components::VideoTimestamp` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const components::VideoTimestamp* instances, size_t num_instances
+        ) {
+            return Loggable<rerun::datatypes::TimeInt>::to_arrow(
+                &instances->timestamp_ns,
+                num_instances
+            );
+        }
+    };
+} // namespace rerun
@@ -9,6 +9,8 @@
 #include "datatypes/class_description.hpp"
 #include "datatypes/class_description_map_elem.hpp"
 #include "datatypes/class_id.hpp"
+#include "datatypes/distortion_coefficients.hpp"
+#include "datatypes/dvec2d.hpp"
 #include "datatypes/entity_path.hpp"
 #include "datatypes/float32.hpp"
 #include "datatypes/float64.hpp"
@@ -36,6 +38,7 @@
 #include "datatypes/uint32.hpp"
 #include "datatypes/uint64.hpp"
 #include "datatypes/utf8.hpp"
+#include "datatypes/utf8_pair.hpp"
 #include "datatypes/uuid.hpp"
 #include "datatypes/uvec2d.hpp"
 #include "datatypes/uvec3d.hpp"
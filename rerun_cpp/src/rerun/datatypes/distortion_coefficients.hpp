@@ -0,0 +1,62 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/datatypes/distortion_coefficients.fbs".
+
+#pragma once
+
+#include "../result.hpp"
+
+#include <array>
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    class Array;
+    class DataType;
+    class FixedSizeListBuilder;
+} // namespace arrow
+
+namespace rerun::datatypes {
+    /// **Datatype**: Coefficients of a lens distortion model.
+    ///
+    /// Stored in the same order as OpenCV uses them: `[k1, k2, p1, p2, k3, k4, k5, k6]`,
+    /// where `k*` are radial and `p*` are tangential coefficients.
+    /// Models that use fewer coefficients ignore the remaining ones, which should be set to zero.
+    struct DistortionCoefficients {
+        std::array<float, 8> coefficients;
+
+      public:
+        DistortionCoefficients() = default;
+
+        DistortionCoefficients(std::array<float, 8> coefficients_) : coefficients(coefficients_) {}
+
+        DistortionCoefficients& operator=(std::array<float, 8> coefficients_) {
+            coefficients = coefficients_;
+            return *this;
+        }
+    };
+} // namespace rerun::datatypes
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<datatypes::DistortionCoefficients> {
+        static constexpr const char Name[] = "rerun.datatypes.DistortionCoefficients";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::datatypes::DistortionCoefficients` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const datatypes::DistortionCoefficients* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::FixedSizeListBuilder* builder, const datatypes::DistortionCoefficients* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,31 @@
+#include <string>
+#include <utility>
+#include "utf8_pair.hpp"
+
+// Uncomment for better auto-complete while editing the extension.
+// #define EDIT_EXTENSION
+
+namespace rerun {
+    namespace datatypes {
+
+#ifdef EDIT_EXTENSION
+        struct Utf8PairExt {
+            rerun::datatypes::Utf8 first;
+            rerun::datatypes::Utf8 second;
+
+#define Utf8Pair Utf8PairExt
+
+            // <CODEGEN_COPY_TO_HEADER>
+
+            Utf8Pair(std::string _first, std::string _second)
+                : first(std::move(_first)), second(std::move(_second)) {}
+
+            Utf8Pair(std::pair<std::string, std::string> pair)
+                : first(std::move(pair.first)), second(std::move(pair.second)) {}
+
+            // </CODEGEN_COPY_TO_HEADER>
+        };
+
+#endif
+    } // namespace datatypes
+} // namespace rerun
@@ -166,6 +166,16 @@ SECTION_TABLE: Final[list[Section]] = [
         class_list=["ImageChromaDownsampled"],
         show_tables=False,
     ),
+    Section(
+        title="Geospatial",
+        class_list=["archetypes.GeoPoints", "archetypes.GeoLineStrings"],
+        gen_page=False,
+    ),
+    Section(
+        title="Graphs",
+        class_list=["archetypes.GraphNodes", "archetypes.GraphEdges"],
+        gen_page=False,
+    ),
     Section(
         title="Plotting",
         class_list=[
@@ -219,6 +229,11 @@ SECTION_TABLE: Final[list[Section]] = [
         ],
         gen_page=False,
     ),
+    Section(
+        title="Video",
+        class_list=["archetypes.AssetVideo", "archetypes.VideoFrameReference"],
+        gen_page=False,
+    ),
     ################################################################################
     # Other referenced things
     Section(
@@ -254,6 +269,8 @@ SECTION_TABLE: Final[list[Section]] = [
             "Tabs",
             "SpaceView",
             "BarChartView",
+            "GraphView",
+            "MapView",
             "Spatial2DView",
             "Spatial3DView",
             "TensorView",
@@ -42,6 +42,7 @@ from .archetypes import (
     Arrows2D as Arrows2D,
     Arrows3D as Arrows3D,
     Asset3D as Asset3D,
+    AssetVideo as AssetVideo,
     BarChart as BarChart,
     Boxes2D as Boxes2D,
     Boxes3D as Boxes3D,
@@ -49,6 +50,10 @@ from .archetypes import (
     DepthImage as DepthImage,
     DisconnectedSpace as DisconnectedSpace,
     Ellipsoids as Ellipsoids,
+    GeoLineStrings as GeoLineStrings,
+    GeoPoints as GeoPoints,
+    GraphEdges as GraphEdges,
+    GraphNodes as GraphNodes,
     Image as Image,
     ImageEncoded as ImageEncoded,
     LineStrips2D as LineStrips2D,
@@ -65,6 +70,7 @@ from .archetypes import (
     TextDocument as TextDocument,
     TextLog as TextLog,
     Transform3D as Transform3D,
+    VideoFrameReference as VideoFrameReference,
     ViewCoordinates as ViewCoordinates,
 )
 from .archetypes.boxes2d_ext import (
@@ -6,6 +6,7 @@ from .annotation_context import AnnotationContext
 from .arrows2d import Arrows2D
 from .arrows3d import Arrows3D
 from .asset3d import Asset3D
+from .asset_video import AssetVideo
 from .bar_chart import BarChart
 from .boxes2d import Boxes2D
 from .boxes3d import Boxes3D
@@ -13,6 +14,10 @@ from .clear import Clear
 from .depth_image import DepthImage
 from .disconnected_space import DisconnectedSpace
 from .ellipsoids import Ellipsoids
+from .geo_line_strings import GeoLineStrings
+from .geo_points import GeoPoints
+from .graph_edges import GraphEdges
+from .graph_nodes import GraphNodes
 from .image import Image
 from .image_encoded import ImageEncoded
 from .line_strips2d import LineStrips2D
@@ -29,6 +34,7 @@ from .tensor import Tensor
 from .text_document import TextDocument
 from .text_log import TextLog
 from .transform3d import Transform3D
+from .video_frame_reference import VideoFrameReference
 from .view_coordinates import ViewCoordinates
 
 __all__ = [
@@ -36,6 +42,7 @@ __all__ = [
     "Arrows2D",
     "Arrows3D",
     "Asset3D",
+    "AssetVideo",
     "BarChart",
     "Boxes2D",
     "Boxes3D",
@@ -43,6 +50,10 @@ __all__ = [
     "DepthImage",
     "DisconnectedSpace",
     "Ellipsoids",
+    "GeoLineStrings",
+    "GeoPoints",
+    "GraphEdges",
+    "GraphNodes",
     "Image",
     "ImageEncoded",
     "LineStrips2D",
@@ -59,5 +70,6 @@ __all__ = [
     "TextDocument",
     "TextLog",
     "Transform3D",
+    "VideoFrameReference",
     "ViewCoordinates",
 ]
@@ -0,0 +1,98 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/asset_video.fbs".
+
+# You can extend this class by creating a "AssetVideoExt" class in "asset_video_ext.py".
+
+from __future__ import annotations
+
+from attrs import define, field
+
+from .. import components
+from .._baseclasses import (
+    Archetype,
+)
+from .asset_video_ext import AssetVideoExt
+
+__all__ = ["AssetVideo"]
+
+
+@define(str=False, repr=False, init=False)
+class AssetVideo(AssetVideoExt, Archetype):
+    """
+    **Archetype**: A video file, e.g. an MP4 containing H.264 or AV1.
+
+    The video is stored as-is and decoded by the viewer, which is a lot more compact than
+    logging every frame as an image.
+
+    To show it, log [`archetypes.VideoFrameReference`][rerun.archetypes.VideoFrameReference]s on the same entity, on the timeline(s) of
+    your choice: the viewer then shows the referenced frame of the video at each point in time.
+
+    Decoding requires the `ffmpeg` executable to be installed and on the `PATH` of the
+    machine running the viewer. Videos can't be played in the web viewer yet.
+
+    Example
+    -------
+    ### Video with a frame reference per frame:
+    ```python
+    import sys
+
+    import rerun as rr
+
+    if len(sys.argv) < 2:
+        print(f"Usage: {sys.argv[0]} <path_to_video.[mp4]>")
+        sys.exit(1)
+
+    rr.init("rerun_example_asset_video", spawn=True)
+
+    video = rr.AssetVideo(path=sys.argv[1])
+    rr.log("video", video, static=True)
+
+    # Show every frame of the video at the time it would be shown when playing it.
+    for timestamp_ns in video.read_frame_timestamps_ns():
+        rr.set_time_nanos("video_time", timestamp_ns)
+        rr.log("video", rr.VideoFrameReference(timestamp_ns))
+    ```
+
+    """
+
+    # __init__ can be found in asset_video_ext.py
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            blob=None,  # type: ignore[arg-type]
+            media_type=None,  # type: ignore[arg-type]
+        )
+
+    @classmethod
+    def _clear(cls) -> AssetVideo:
+        """Produce an empty AssetVideo, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    blob: components.BlobBatch = field(
+        metadata={"component": "required"},
+        converter=components.BlobBatch._required,  # type: ignore[misc]
+    )
+    # The asset's bytes.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    media_type: components.MediaTypeBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.MediaTypeBatch._optional,  # type: ignore[misc]
+    )
+    # The Media Type of the asset.
+    #
+    # Supported values:
+    # * `video/mp4`
+    #
+    # If omitted, the viewer will try to guess from the data blob.
+    # If it cannot guess, it won't be able to render the asset.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,129 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/geo_line_strings.fbs".
+
+# You can extend this class by creating a "GeoLineStringsExt" class in "geo_line_strings_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["GeoLineStrings"]
+
+
+@define(str=False, repr=False, init=False)
+class GeoLineStrings(Archetype):
+    """
+    **Archetype**: Geospatial line strings with positions expressed in [EPSG:4326](https://epsg.io/4326) latitude and longitude (North/East-positive degrees), and optional colors and radii.
+
+    Also known as "line strips" or "polylines".
+
+    Example
+    -------
+    ### Log a geospatial line string:
+    ```python
+    import rerun as rr
+
+    rr.init("rerun_example_geo_line_strings", spawn=True)
+
+    rr.log(
+        "colorado",
+        rr.GeoLineStrings(
+            line_strings=[
+                [
+                    [41.0000, -109.0452],
+                    [41.0000, -102.0415],
+                    [36.9931, -102.0415],
+                    [36.9931, -109.0452],
+                    [41.0000, -109.0452],
+                ]
+            ],
+            radii=2000.0,
+            colors=[0, 0, 255],
+        ),
+    )
+    ```
+
+    """
+
+    def __init__(
+        self: Any,
+        line_strings: components.GeoLineStringArrayLike,
+        *,
+        radii: datatypes.Float32ArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+    ):
+        """
+        Create a new instance of the GeoLineStrings archetype.
+
+        Parameters
+        ----------
+        line_strings:
+            The line strings, expressed in [EPSG:4326](https://epsg.io/4326) coordinates (North/East-positive degrees).
+        radii:
+            Optional radii for the line strings.
+
+            *Note*: scene unit radii are interpreted as meters.
+        colors:
+            Optional colors for the line strings.
+
+        """
+
+        # You can define your own __init__ function as a member of GeoLineStringsExt in geo_line_strings_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(line_strings=line_strings, radii=radii, colors=colors)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            line_strings=None,  # type: ignore[arg-type]
+            radii=None,  # type: ignore[arg-type]
+            colors=None,  # type: ignore[arg-type]
+        )
+
+    @classmethod
+    def _clear(cls) -> GeoLineStrings:
+        """Produce an empty GeoLineStrings, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    line_strings: components.GeoLineStringBatch = field(
+        metadata={"component": "required"},
+        converter=components.GeoLineStringBatch._required,  # type: ignore[misc]
+    )
+    # The line strings, expressed in [EPSG:4326](https://epsg.io/4326) coordinates (North/East-positive degrees).
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    radii: components.RadiusBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.RadiusBatch._optional,  # type: ignore[misc]
+    )
+    # Optional radii for the line strings.
+    #
+    # *Note*: scene unit radii are interpreted as meters.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colors: components.ColorBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.ColorBatch._optional,  # type: ignore[misc]
+    )
+    # Optional colors for the line strings.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -36,6 +36,10 @@ class Image(ImageExt, Archetype):
     Leading and trailing unit-dimensions are ignored, so that
     `1x480x640x3x1` is treated as a `480x640x3` RGB image.
 
+    Use a [`components.ColorModel`][rerun.components.ColorModel] to treat a 3- or 4-channel image as `BGR` or `BGRA` instead,
+    or a [`components.PixelFormat`][rerun.components.PixelFormat] to log the raw `NV12`, `YUY2` or Bayer buffer of a camera as-is.
+    The viewer converts these to RGB when displaying them.
+
     Rerun also supports compressed images (JPEG, PNG, …), using [`archetypes.ImageEncoded`][rerun.archetypes.ImageEncoded].
     Compressing images can save a lot of bandwidth and memory.
 
@@ -76,6 +80,8 @@ class Image(ImageExt, Archetype):
         self: Any,
         data: datatypes.TensorDataLike,
         *,
+        color_model: components.ColorModelLike | None = None,
+        pixel_format: components.PixelFormatLike | None = None,
         opacity: datatypes.Float32Like | None = None,
         draw_order: datatypes.Float32Like | None = None,
     ):
@@ -86,6 +92,15 @@ class Image(ImageExt, Archetype):
         ----------
         data:
             The image data. Should always be a 2- or 3-dimensional tensor.
+        color_model:
+            The color model of the pixels, e.g. `BGR`.
+
+            If not set, it is inferred from the number of channels: `L`, `RGB` or `RGBA`.
+            Ignored if a [`components.PixelFormat`][rerun.components.PixelFormat] is set.
+        pixel_format:
+            The pixel format of the raw image data, e.g. `NV12` or `BayerRGGB`.
+
+            If set, the image data is the raw buffer of that format, see [`components.PixelFormat`][rerun.components.PixelFormat].
         opacity:
             Opacity of the image, useful for layering several images.
 
@@ -99,7 +114,9 @@ class Image(ImageExt, Archetype):
 
         # You can define your own __init__ function as a member of ImageExt in image_ext.py
         with catch_and_log_exceptions(context=self.__class__.__name__):
-            self.__attrs_init__(data=data, opacity=opacity, draw_order=draw_order)
+            self.__attrs_init__(
+                data=data, color_model=color_model, pixel_format=pixel_format, opacity=opacity, draw_order=draw_order
+            )
             return
         self.__attrs_clear__()
 
@@ -107,6 +124,8 @@ class Image(ImageExt, Archetype):
         """Convenience method for calling `__attrs_init__` with all `None`s."""
         self.__attrs_init__(
             data=None,  # type: ignore[arg-type]
+            color_model=None,  # type: ignore[arg-type]
+            pixel_format=None,  # type: ignore[arg-type]
             opacity=None,  # type: ignore[arg-type]
             draw_order=None,  # type: ignore[arg-type]
         )
@@ -126,6 +145,29 @@ class Image(ImageExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    color_model: components.ColorModelBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.ColorModelBatch._optional,  # type: ignore[misc]
+    )
+    # The color model of the pixels, e.g. `BGR`.
+    #
+    # If not set, it is inferred from the number of channels: `L`, `RGB` or `RGBA`.
+    # Ignored if a [`components.PixelFormat`][rerun.components.PixelFormat] is set.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    pixel_format: components.PixelFormatBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.PixelFormatBatch._optional,  # type: ignore[misc]
+    )
+    # The pixel format of the raw image data, e.g. `NV12` or `BayerRGGB`.
+    #
+    # If set, the image data is the raw buffer of that format, see [`components.PixelFormat`][rerun.components.PixelFormat].
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     opacity: components.OpacityBatch | None = field(
         metadata={"component": "optional"},
         default=None,
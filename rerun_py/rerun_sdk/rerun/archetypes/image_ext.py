@@ -23,8 +23,8 @@ class ImageExt:
         """
         Converts an `Image` to an [`rerun.ImageEncoded`][] using JPEG compression.
 
-        JPEG compression works best for photographs. Only RGB or Mono images are
-        supported, not RGBA. Note that compressing to JPEG costs a bit of CPU time,
+        JPEG compression works best for photographs. Only RGB, BGR or Mono images are
+        supported, not RGBA or images with a pixel format. Note that compressing to JPEG costs a bit of CPU time,
         both when logging and later when viewing them.
 
         Parameters
@@ -43,6 +43,16 @@ class ImageExt:
         self = cast(Image, self)
 
         with catch_and_log_exceptions(context="Image compression"):
+            if self.pixel_format is not None:
+                raise ValueError("Images with a pixel format (NV12, YUY2, Bayer) cannot be JPEG compressed")
+
+            is_bgr = False
+            if self.color_model is not None:
+                from ..components import ColorModel
+
+                color_model = self.color_model.as_arrow_array().storage.type_codes[0].as_py()
+                is_bgr = color_model == ColorModel.Bgr.value
+
             tensor_data_arrow = self.data.as_arrow_array()
 
             shape_dims = tensor_data_arrow[0].value["shape"].values.field(0).to_numpy()
@@ -56,6 +66,8 @@ class ImageExt:
                 raise ValueError("Only RGB or Mono images are supported for JPEG compression")
 
             image_array = tensor_data_arrow[0].value["buffer"].value.values.to_numpy().reshape(filtered_shape)
+            if is_bgr and mode == "RGB":
+                image_array = image_array[..., ::-1]
 
             if image_array.dtype not in ["uint8", "sint32", "float32"]:
                 # Convert to a format supported by Image.fromarray
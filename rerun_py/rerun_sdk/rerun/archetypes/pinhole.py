@@ -76,6 +76,9 @@ class Pinhole(PinholeExt, Archetype):
             resolution=None,  # type: ignore[arg-type]
             camera_xyz=None,  # type: ignore[arg-type]
             image_plane_distance=None,  # type: ignore[arg-type]
+            distortion_model=None,  # type: ignore[arg-type]
+            distortion_coefficients=None,  # type: ignore[arg-type]
+            undistort_images=None,  # type: ignore[arg-type]
         )
 
     @classmethod
@@ -155,5 +158,42 @@ class Pinhole(PinholeExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    distortion_model: components.DistortionModelBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.DistortionModelBatch._optional,  # type: ignore[misc]
+    )
+    # The lens distortion model of the camera.
+    #
+    # Only has an effect if [`components.DistortionCoefficients`][rerun.components.DistortionCoefficients] are set as well.
+    # Defaults to `BrownConrady`.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    distortion_coefficients: components.DistortionCoefficientsBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.DistortionCoefficientsBatch._optional,  # type: ignore[misc]
+    )
+    # The coefficients of the lens distortion model, see [`components.DistortionCoefficients`][rerun.components.DistortionCoefficients].
+    #
+    # If not set, the camera is assumed to be free of lens distortion.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    undistort_images: components.UndistortImagesBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=components.UndistortImagesBatch._optional,  # type: ignore[misc]
+    )
+    # Whether images logged under this camera should be shown with the lens distortion removed.
+    #
+    # Undistorted images line up with 3D content that is projected into the camera.
+    # Otherwise, 3D points and line strips are distorted to line up with the images instead,
+    # but other 3D content is projected into 2D views of the camera without lens distortion.
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -3,8 +3,10 @@ from __future__ import annotations
 import math
 from typing import Any, cast
 
+import numpy as np
 import numpy.typing as npt
 
+from ..components import DistortionModelLike
 from ..datatypes import Mat3x3Like, Vec2D, Vec2DLike, ViewCoordinatesLike
 from ..error_utils import _send_warning_or_raise, catch_and_log_exceptions
 
@@ -25,6 +27,9 @@ class PinholeExt:
         fov_y: float | None = None,
         aspect_ratio: float | None = None,
         image_plane_distance: float | None = None,
+        distortion_model: DistortionModelLike | None = None,
+        distortion_coefficients: npt.ArrayLike | None = None,
+        undistort_images: bool | None = None,
     ) -> None:
         """
         Create a new instance of the Pinhole archetype.
@@ -87,6 +92,15 @@ class PinholeExt:
         image_plane_distance:
             The distance from the camera origin to the image plane when the projection is shown in a 3D viewer.
             This is only used for visualization purposes, and does not affect the projection itself.
+        distortion_model:
+            The lens distortion model of the camera, e.g. `"BrownConrady"`, `"KannalaBrandt"` or `"Rational"`.
+            Defaults to `BrownConrady` if `distortion_coefficients` are set.
+        distortion_coefficients:
+            Up to eight coefficients of the lens distortion model, in the order OpenCV uses:
+            `[k1, k2, p1, p2, k3, k4, k5, k6]`.
+            Shorter lists (e.g. the five coefficients returned by `cv2.calibrateCamera`) are padded with zeros.
+        undistort_images:
+            If true, images logged under this camera are shown with the lens distortion removed.
 
         """
 
@@ -150,11 +164,20 @@ class PinholeExt:
                 if fov_y is not None or aspect_ratio is not None:
                     _send_warning_or_raise("Both image_from_camera and fov_y or aspect_ratio set", 1)
 
+            if distortion_coefficients is not None:
+                coefficients = np.asarray(distortion_coefficients, dtype=np.float32).flatten()
+                if len(coefficients) > 8:
+                    raise ValueError(f"Expected at most 8 distortion coefficients, got {len(coefficients)}")
+                distortion_coefficients = np.pad(coefficients, (0, 8 - len(coefficients)))
+
             self.__attrs_init__(
                 image_from_camera=image_from_camera,
                 resolution=resolution,
                 camera_xyz=camera_xyz,
                 image_plane_distance=image_plane_distance,
+                distortion_model=distortion_model,
+                distortion_coefficients=distortion_coefficients,
+                undistort_images=undistort_images,
             )
             return
 
@@ -0,0 +1,100 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/video_frame_reference.fbs".
+
+# You can extend this class by creating a "VideoFrameReferenceExt" class in "video_frame_reference_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["VideoFrameReference"]
+
+
+@define(str=False, repr=False, init=False)
+class VideoFrameReference(Archetype):
+    """
+    **Archetype**: References a single frame of the [`archetypes.AssetVideo`][rerun.archetypes.AssetVideo] logged on the same entity.
+
+    Log one of these on a timeline for each point in time that should show a frame of the video.
+    When scrubbing or playing that timeline, the viewer shows the referenced frames, decoding
+    them on demand.
+
+    Example
+    -------
+    ### Video with a frame reference per frame:
+    ```python
+    import sys
+
+    import rerun as rr
+
+    if len(sys.argv) < 2:
+        print(f"Usage: {sys.argv[0]} <path_to_video.[mp4]>")
+        sys.exit(1)
+
+    rr.init("rerun_example_asset_video", spawn=True)
+
+    video = rr.AssetVideo(path=sys.argv[1])
+    rr.log("video", video, static=True)
+
+    # Show every frame of the video at the time it would be shown when playing it.
+    for timestamp_ns in video.read_frame_timestamps_ns():
+        rr.set_time_nanos("video_time", timestamp_ns)
+        rr.log("video", rr.VideoFrameReference(timestamp_ns))
+    ```
+
+    """
+
+    def __init__(self: Any, timestamp: datatypes.TimeIntLike):
+        """
+        Create a new instance of the VideoFrameReference archetype.
+
+        Parameters
+        ----------
+        timestamp:
+            Which frame of the video to show.
+
+            This is the last frame that is shown at or before this timestamp, i.e. the one that would
+            be on screen at that point when playing the video.
+
+        """
+
+        # You can define your own __init__ function as a member of VideoFrameReferenceExt in video_frame_reference_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(timestamp=timestamp)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            timestamp=None,  # type: ignore[arg-type]
+        )
+
+    @classmethod
+    def _clear(cls) -> VideoFrameReference:
+        """Produce an empty VideoFrameReference, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    timestamp: components.VideoTimestampBatch = field(
+        metadata={"component": "required"},
+        converter=components.VideoTimestampBatch._required,  # type: ignore[misc]
+    )
+    # Which frame of the video to show.
+    #
+    # This is the last frame that is shown at or before this timestamp, i.e. the one that would
+    # be on screen at that point when playing the video.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -51,6 +51,8 @@ from .containers import (
 )
 from .views import (
     BarChartView as BarChartView,
+    GraphView as GraphView,
+    MapView as MapView,
     Spatial2DView as Spatial2DView,
     Spatial3DView as Spatial3DView,
     TensorView as TensorView,
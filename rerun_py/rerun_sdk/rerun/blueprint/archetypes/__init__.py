@@ -5,6 +5,7 @@ from __future__ import annotations
 from .background import Background
 from .container_blueprint import ContainerBlueprint
 from .dataframe_view_mode import DataframeViewMode
+from .map_options import MapOptions
 from .panel_blueprint import PanelBlueprint
 from .plot_legend import PlotLegend
 from .scalar_axis import ScalarAxis
@@ -22,6 +23,7 @@ __all__ = [
     "Background",
     "ContainerBlueprint",
     "DataframeViewMode",
+    "MapOptions",
     "PanelBlueprint",
     "PlotLegend",
     "ScalarAxis",
@@ -0,0 +1,50 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/map_options.fbs".
+
+# You can extend this class by creating a "MapOptionsExt" class in "map_options_ext.py".
+
+from __future__ import annotations
+
+from attrs import define, field
+
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from .map_options_ext import MapOptionsExt
+
+__all__ = ["MapOptions"]
+
+
+@define(str=False, repr=False, init=False)
+class MapOptions(MapOptionsExt, Archetype):
+    """**Archetype**: Configuration for the background of a map view."""
+
+    # __init__ can be found in map_options_ext.py
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            tile_directory=None,  # type: ignore[arg-type]
+        )
+
+    @classmethod
+    def _clear(cls) -> MapOptions:
+        """Produce an empty MapOptions, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    tile_directory: blueprint_components.TileDirectoryBatch | None = field(
+        metadata={"component": "optional"},
+        default=None,
+        converter=blueprint_components.TileDirectoryBatch._optional,  # type: ignore[misc]
+    )
+    # Local directory the map tiles are loaded from.
+    #
+    # Without tiles, only a graticule of latitude and longitude lines is shown.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
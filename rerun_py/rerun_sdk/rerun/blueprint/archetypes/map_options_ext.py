@@ -0,0 +1,29 @@
+from __future__ import annotations
+
+from typing import Any
+
+from ... import datatypes
+from ...error_utils import catch_and_log_exceptions
+
+
+class MapOptionsExt:
+    """Extension for [MapOptions][rerun.blueprint.archetypes.MapOptions]."""
+
+    def __init__(self: Any, tile_directory: datatypes.Utf8Like | None = None):
+        """
+        Create a new instance of the MapOptions archetype.
+
+        Parameters
+        ----------
+        tile_directory:
+            Local directory the map tiles are loaded from.
+
+            Tiles are expected in the `{zoom}/{x}/{y}.png` layout.
+            Without tiles, only a graticule of latitude and longitude lines is shown.
+
+        """
+
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(tile_directory=tile_directory)
+            return
+        self.__attrs_clear__()
@@ -47,6 +47,7 @@ from .tensor_dimension_index_slider import (
     TensorDimensionIndexSliderBatch,
     TensorDimensionIndexSliderType,
 )
+from .tile_directory import TileDirectory, TileDirectoryBatch, TileDirectoryType
 from .view_fit import ViewFit, ViewFitArrayLike, ViewFitBatch, ViewFitLike, ViewFitType
 from .viewer_recommendation_hash import (
     ViewerRecommendationHash,
@@ -142,6 +143,9 @@ __all__ = [
     "TensorDimensionIndexSlider",
     "TensorDimensionIndexSliderBatch",
     "TensorDimensionIndexSliderType",
+    "TileDirectory",
+    "TileDirectoryBatch",
+    "TileDirectoryType",
     "ViewFit",
     "ViewFitArrayLike",
     "ViewFitBatch",
@@ -0,0 +1,42 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/tile_directory.fbs".
+
+# You can extend this class by creating a "TileDirectoryExt" class in "tile_directory_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["TileDirectory", "TileDirectoryBatch", "TileDirectoryType"]
+
+
+class TileDirectory(datatypes.Utf8, ComponentMixin):
+    """
+    **Component**: A local directory containing map tiles.
+
+    Tiles are expected in the `{zoom}/{x}/{y}.png` layout of the common "slippy map" tile scheme,
+    using the Web Mercator projection.
+    An empty path means that no tiles are shown.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TileDirectoryExt in tile_directory_ext.py
+
+    # Note: there are no fields here because TileDirectory delegates to datatypes.Utf8
+    pass
+
+
+class TileDirectoryType(datatypes.Utf8Type):
+    _TYPE_NAME: str = "rerun.blueprint.components.TileDirectory"
+
+
+class TileDirectoryBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _ARROW_TYPE = TileDirectoryType()
+
+
+# This is patched in late to avoid circular dependencies.
+TileDirectory._BATCH_TYPE = TileDirectoryBatch  # type: ignore[assignment]
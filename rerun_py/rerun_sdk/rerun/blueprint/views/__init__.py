@@ -3,6 +3,8 @@
 from __future__ import annotations
 
 from .bar_chart_view import BarChartView
+from .graph_view import GraphView
+from .map_view import MapView
 from .spatial2d_view import Spatial2DView
 from .spatial3d_view import Spatial3DView
 from .tensor_view import TensorView
@@ -12,6 +14,8 @@ from .time_series_view import TimeSeriesView
 
 __all__ = [
     "BarChartView",
+    "GraphView",
+    "MapView",
     "Spatial2DView",
     "Spatial3DView",
     "TensorView",
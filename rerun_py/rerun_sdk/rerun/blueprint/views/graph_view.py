@@ -0,0 +1,76 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/views/graph.fbs".
+
+from __future__ import annotations
+
+from typing import Union
+
+__all__ = ["GraphView"]
+
+
+from ... import datatypes
+from ..._baseclasses import AsComponents, ComponentBatchLike
+from ...datatypes import EntityPathLike, Utf8Like
+from ..api import SpaceView, SpaceViewContentsLike
+
+
+class GraphView(SpaceView):
+    """
+    **View**: A graph view to display a directed or undirected graph.
+
+    Nodes without a position are laid out by a force-directed simulation.
+    """
+
+    def __init__(
+        self,
+        *,
+        origin: EntityPathLike = "/",
+        contents: SpaceViewContentsLike = "$origin/**",
+        name: Utf8Like | None = None,
+        visible: datatypes.BoolLike | None = None,
+        defaults: list[Union[AsComponents, ComponentBatchLike]] = [],
+        overrides: dict[EntityPathLike, list[ComponentBatchLike]] = {},
+    ) -> None:
+        """
+        Construct a blueprint for a new GraphView view.
+
+        Parameters
+        ----------
+        origin:
+            The `EntityPath` to use as the origin of this view.
+            All other entities will be transformed to be displayed relative to this origin.
+        contents:
+            The contents of the view specified as a query expression.
+            This is either a single expression, or a list of multiple expressions.
+            See [rerun.blueprint.archetypes.SpaceViewContents][].
+        name:
+            The display name of the view.
+        visible:
+            Whether this view is visible.
+
+            Defaults to true if not specified.
+        defaults:
+            List of default components or component batches to add to the space view. When an archetype
+            in the view is missing a component included in this set, the value of default will be used
+            instead of the normal fallback for the visualizer.
+        overrides:
+            Dictionary of overrides to apply to the space view. The key is the path to the entity where the override
+            should be applied. The value is a list of component or component batches to apply to the entity.
+
+            Important note: the path must be a fully qualified entity path starting at the root. The override paths
+            do not yet support `$origin` relative paths or glob expressions.
+            This will be addressed in: [https://github.com/rerun-io/rerun/issues/6673][].
+
+        """
+
+        properties: dict[str, AsComponents] = {}
+        super().__init__(
+            class_identifier="Graph",
+            origin=origin,
+            contents=contents,
+            name=name,
+            visible=visible,
+            properties=properties,
+            defaults=defaults,
+            overrides=overrides,
+        )
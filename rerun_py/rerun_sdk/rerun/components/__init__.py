@@ -33,13 +33,32 @@ from .color_model import ColorModel, ColorModelArrayLike, ColorModelBatch, Color
 from .colormap import Colormap, ColormapArrayLike, ColormapBatch, ColormapLike, ColormapType
 from .depth_meter import DepthMeter, DepthMeterBatch, DepthMeterType
 from .disconnected_space import DisconnectedSpace, DisconnectedSpaceBatch, DisconnectedSpaceType
+from .distortion_coefficients import DistortionCoefficients, DistortionCoefficientsBatch, DistortionCoefficientsType
+from .distortion_model import (
+    DistortionModel,
+    DistortionModelArrayLike,
+    DistortionModelBatch,
+    DistortionModelLike,
+    DistortionModelType,
+)
 from .draw_order import DrawOrder, DrawOrderBatch, DrawOrderType
 from .fill_ratio import FillRatio, FillRatioBatch, FillRatioType
 from .gamma_correction import GammaCorrection, GammaCorrectionBatch, GammaCorrectionType
+from .geo_line_string import (
+    GeoLineString,
+    GeoLineStringArrayLike,
+    GeoLineStringBatch,
+    GeoLineStringLike,
+    GeoLineStringType,
+)
+from .graph_edge import GraphEdge, GraphEdgeBatch, GraphEdgeType
+from .graph_node import GraphNode, GraphNodeBatch, GraphNodeType
+from .graph_type import GraphType, GraphTypeArrayLike, GraphTypeBatch, GraphTypeLike, GraphTypeType
 from .half_size2d import HalfSize2D, HalfSize2DBatch, HalfSize2DType
 from .half_size3d import HalfSize3D, HalfSize3DBatch, HalfSize3DType
 from .image_plane_distance import ImagePlaneDistance, ImagePlaneDistanceBatch, ImagePlaneDistanceType
 from .keypoint_id import KeypointId, KeypointIdBatch, KeypointIdType
+from .lat_lon import LatLon, LatLonBatch, LatLonType
 from .line_strip2d import LineStrip2D, LineStrip2DArrayLike, LineStrip2DBatch, LineStrip2DLike, LineStrip2DType
 from .line_strip3d import LineStrip3D, LineStrip3DArrayLike, LineStrip3DBatch, LineStrip3DLike, LineStrip3DType
 from .magnification_filter import (
@@ -56,6 +75,7 @@ from .name import Name, NameBatch, NameType
 from .opacity import Opacity, OpacityBatch, OpacityType
 from .out_of_tree_transform3d import OutOfTreeTransform3D, OutOfTreeTransform3DBatch, OutOfTreeTransform3DType
 from .pinhole_projection import PinholeProjection, PinholeProjectionBatch, PinholeProjectionType
+from .pixel_format import PixelFormat, PixelFormatArrayLike, PixelFormatBatch, PixelFormatLike, PixelFormatType
 from .position2d import Position2D, Position2DBatch, Position2DType
 from .position3d import Position3D, Position3DBatch, Position3DType
 from .radius import Radius, RadiusBatch, RadiusType
@@ -81,8 +101,10 @@ from .transform3d import Transform3D, Transform3DBatch, Transform3DType
 from .transform_mat3x3 import TransformMat3x3, TransformMat3x3Batch, TransformMat3x3Type
 from .translation3d import Translation3D, Translation3DBatch, Translation3DType
 from .triangle_indices import TriangleIndices, TriangleIndicesBatch, TriangleIndicesType
+from .undistort_images import UndistortImages, UndistortImagesBatch, UndistortImagesType
 from .vector2d import Vector2D, Vector2DBatch, Vector2DType
 from .vector3d import Vector3D, Vector3DBatch, Vector3DType
+from .video_timestamp import VideoTimestamp, VideoTimestampBatch, VideoTimestampType
 from .view_coordinates import ViewCoordinates, ViewCoordinatesBatch, ViewCoordinatesType
 
 __all__ = [
@@ -135,6 +157,14 @@ __all__ = [
     "DisconnectedSpace",
     "DisconnectedSpaceBatch",
     "DisconnectedSpaceType",
+    "DistortionCoefficients",
+    "DistortionCoefficientsBatch",
+    "DistortionCoefficientsType",
+    "DistortionModel",
+    "DistortionModelArrayLike",
+    "DistortionModelBatch",
+    "DistortionModelLike",
+    "DistortionModelType",
     "DrawOrder",
     "DrawOrderBatch",
     "DrawOrderType",
@@ -144,6 +174,22 @@ __all__ = [
     "GammaCorrection",
     "GammaCorrectionBatch",
     "GammaCorrectionType",
+    "GeoLineString",
+    "GeoLineStringArrayLike",
+    "GeoLineStringBatch",
+    "GeoLineStringLike",
+    "GeoLineStringType",
+    "GraphEdge",
+    "GraphEdgeBatch",
+    "GraphEdgeType",
+    "GraphNode",
+    "GraphNodeBatch",
+    "GraphNodeType",
+    "GraphType",
+    "GraphTypeArrayLike",
+    "GraphTypeBatch",
+    "GraphTypeLike",
+    "GraphTypeType",
     "HalfSize2D",
     "HalfSize2DBatch",
     "HalfSize2DType",
@@ -156,6 +202,9 @@ __all__ = [
     "KeypointId",
     "KeypointIdBatch",
     "KeypointIdType",
+    "LatLon",
+    "LatLonBatch",
+    "LatLonType",
     "LineStrip2D",
     "LineStrip2DArrayLike",
     "LineStrip2DBatch",
@@ -194,6 +243,11 @@ __all__ = [
     "PinholeProjection",
     "PinholeProjectionBatch",
     "PinholeProjectionType",
+    "PixelFormat",
+    "PixelFormatArrayLike",
+    "PixelFormatBatch",
+    "PixelFormatLike",
+    "PixelFormatType",
     "Position2D",
     "Position2DBatch",
     "Position2DType",
@@ -257,12 +311,18 @@ __all__ = [
     "TriangleIndices",
     "TriangleIndicesBatch",
     "TriangleIndicesType",
+    "UndistortImages",
+    "UndistortImagesBatch",
+    "UndistortImagesType",
     "Vector2D",
     "Vector2DBatch",
     "Vector2DType",
     "Vector3D",
     "Vector3DBatch",
     "Vector3DType",
+    "VideoTimestamp",
+    "VideoTimestampBatch",
+    "VideoTimestampType",
     "ViewCoordinates",
     "ViewCoordinatesBatch",
     "ViewCoordinatesType",
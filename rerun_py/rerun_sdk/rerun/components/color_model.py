@@ -26,6 +26,8 @@ class ColorModel(Enum):
     **Component**: Specified what color components are present in an [`archetypes.Image`][rerun.archetypes.Image].
 
     This combined with [`components.ChannelDataType`][rerun.components.ChannelDataType] determines the pixel format of an image.
+    Formats that cannot be described this way (e.g. chroma-downsampled or Bayer-mosaiced images)
+    use a [`components.PixelFormat`][rerun.components.PixelFormat] instead.
     """
 
     L = 1
@@ -37,8 +39,14 @@ class ColorModel(Enum):
     Rgba = 3
     """Red, Green, Blue, Alpha"""
 
+    Bgr = 4
+    """Blue, Green, Red"""
 
-ColorModelLike = Union[ColorModel, Literal["l", "rgb", "rgba"]]
+    Bgra = 5
+    """Blue, Green, Red, Alpha"""
+
+
+ColorModelLike = Union[ColorModel, Literal["l", "rgb", "rgba", "bgr", "bgra"]]
 ColorModelArrayLike = Union[ColorModelLike, Sequence[ColorModelLike]]
 
 
@@ -53,6 +61,8 @@ class ColorModelType(BaseExtensionType):
                 pa.field("L", pa.null(), nullable=True, metadata={}),
                 pa.field("RGB", pa.null(), nullable=True, metadata={}),
                 pa.field("RGBA", pa.null(), nullable=True, metadata={}),
+                pa.field("BGR", pa.null(), nullable=True, metadata={}),
+                pa.field("BGRA", pa.null(), nullable=True, metadata={}),
             ]),
             self._TYPE_NAME,
         )
@@ -84,6 +94,10 @@ class ColorModelBatch(BaseBatch[ColorModelArrayLike], ComponentBatchMixin):
                     types.append(ColorModel.Rgb.value)
                 elif value.lower() == "rgba":
                     types.append(ColorModel.Rgba.value)
+                elif value.lower() == "bgr":
+                    types.append(ColorModel.Bgr.value)
+                elif value.lower() == "bgra":
+                    types.append(ColorModel.Bgra.value)
                 else:
                     raise ValueError(f"Unknown ColorModel kind: {value}")
             else:
@@ -93,7 +107,7 @@ class ColorModelBatch(BaseBatch[ColorModelArrayLike], ComponentBatchMixin):
             None,
             pa.array(types, type=pa.int8()).buffers()[1],
         ]
-        children = (1 + 3) * [pa.nulls(len(data))]
+        children = (1 + 5) * [pa.nulls(len(data))]
 
         return pa.UnionArray.from_buffers(
             type=data_type,
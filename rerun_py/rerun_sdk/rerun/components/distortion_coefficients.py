@@ -0,0 +1,46 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/distortion_coefficients.fbs".
+
+# You can extend this class by creating a "DistortionCoefficientsExt" class in "distortion_coefficients_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["DistortionCoefficients", "DistortionCoefficientsBatch", "DistortionCoefficientsType"]
+
+
+class DistortionCoefficients(datatypes.DistortionCoefficients, ComponentMixin):
+    """
+    **Component**: Coefficients of the lens distortion of a camera.
+
+    Stored in the same order as OpenCV uses them: `[k1, k2, p1, p2, k3, k4, k5, k6]`.
+    How they are interpreted depends on the [`components.DistortionModel`][rerun.components.DistortionModel]:
+    - `BrownConrady`: `k1`, `k2`, `p1`, `p2`, `k3` (OpenCV's 5 coefficient model).
+    - `KannalaBrandt`: the first four values are the fisheye coefficients `k1`, `k2`, `k3`, `k4`.
+    - `Rational`: all eight coefficients (OpenCV's `CALIB_RATIONAL_MODEL`).
+
+    Unused coefficients should be set to zero.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of DistortionCoefficientsExt in distortion_coefficients_ext.py
+
+    # Note: there are no fields here because DistortionCoefficients delegates to datatypes.DistortionCoefficients
+    pass
+
+
+class DistortionCoefficientsType(datatypes.DistortionCoefficientsType):
+    _TYPE_NAME: str = "rerun.components.DistortionCoefficients"
+
+
+class DistortionCoefficientsBatch(datatypes.DistortionCoefficientsBatch, ComponentBatchMixin):
+    _ARROW_TYPE = DistortionCoefficientsType()
+
+
+# This is patched in late to avoid circular dependencies.
+DistortionCoefficients._BATCH_TYPE = DistortionCoefficientsBatch  # type: ignore[assignment]
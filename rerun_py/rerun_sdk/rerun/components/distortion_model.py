@@ -0,0 +1,113 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/distortion_model.fbs".
+
+# You can extend this class by creating a "DistortionModelExt" class in "distortion_model_ext.py".
+
+from __future__ import annotations
+
+from typing import Literal, Sequence, Union
+
+import pyarrow as pa
+
+from .._baseclasses import (
+    BaseBatch,
+    BaseExtensionType,
+    ComponentBatchMixin,
+)
+
+__all__ = ["DistortionModel", "DistortionModelArrayLike", "DistortionModelBatch", "DistortionModelLike", "DistortionModelType"]
+
+
+from enum import Enum
+
+
+class DistortionModel(Enum):
+    """
+    **Component**: The lens distortion model of a camera.
+
+    The model maps the undistorted, normalized image coordinates `(x/z, y/z)` of a point in camera space
+    to where it actually ends up on the sensor, before the [`components.PinholeProjection`][rerun.components.PinholeProjection] is applied.
+    Its parameters are given by [`components.DistortionCoefficients`][rerun.components.DistortionCoefficients].
+    """
+
+    BrownConrady = 1
+    """
+    Brown-Conrady model with three radial (`k1`, `k2`, `k3`) and two tangential (`p1`, `p2`) coefficients.
+
+    This is OpenCV's default camera model.
+    """
+
+    KannalaBrandt = 2
+    """
+    Kannala-Brandt fisheye model with four coefficients (`k1`, `k2`, `k3`, `k4`).
+
+    This is the model used by OpenCV's `fisheye` module.
+    """
+
+    Rational = 3
+    """OpenCV's rational model with six radial (`k1`-`k6`) and two tangential (`p1`, `p2`) coefficients."""
+
+
+DistortionModelLike = Union[DistortionModel, Literal["brownconrady", "kannalabrandt", "rational"]]
+DistortionModelArrayLike = Union[DistortionModelLike, Sequence[DistortionModelLike]]
+
+
+class DistortionModelType(BaseExtensionType):
+    _TYPE_NAME: str = "rerun.components.DistortionModel"
+
+    def __init__(self) -> None:
+        pa.ExtensionType.__init__(
+            self,
+            pa.sparse_union([
+                pa.field("_null_markers", pa.null(), nullable=True, metadata={}),
+                pa.field("BrownConrady", pa.null(), nullable=True, metadata={}),
+                pa.field("KannalaBrandt", pa.null(), nullable=True, metadata={}),
+                pa.field("Rational", pa.null(), nullable=True, metadata={}),
+            ]),
+            self._TYPE_NAME,
+        )
+
+
+class DistortionModelBatch(BaseBatch[DistortionModelArrayLike], ComponentBatchMixin):
+    _ARROW_TYPE = DistortionModelType()
+
+    @staticmethod
+    def _native_to_pa_array(data: DistortionModelArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (DistortionModel, int, str)):
+            data = [data]
+
+        types: list[int] = []
+
+        for value in data:
+            if value is None:
+                types.append(0)
+            elif isinstance(value, DistortionModel):
+                types.append(value.value)  # Actual enum value
+            elif isinstance(value, int):
+                types.append(value)  # By number
+            elif isinstance(value, str):
+                if hasattr(DistortionModel, value):
+                    types.append(DistortionModel[value].value)  # fast path
+                elif value.lower() == "brownconrady":
+                    types.append(DistortionModel.BrownConrady.value)
+                elif value.lower() == "kannalabrandt":
+                    types.append(DistortionModel.KannalaBrandt.value)
+                elif value.lower() == "rational":
+                    types.append(DistortionModel.Rational.value)
+                else:
+                    raise ValueError(f"Unknown DistortionModel kind: {value}")
+            else:
+                raise ValueError(f"Unknown DistortionModel kind: {value}")
+
+        buffers = [
+            None,
+            pa.array(types, type=pa.int8()).buffers()[1],
+        ]
+        children = (1 + 3) * [pa.nulls(len(data))]
+
+        return pa.UnionArray.from_buffers(
+            type=data_type,
+            length=len(data),
+            buffers=buffers,
+            children=children,
+        )
@@ -0,0 +1,95 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/graph_type.fbs".
+
+# You can extend this class by creating a "GraphTypeExt" class in "graph_type_ext.py".
+
+from __future__ import annotations
+
+from typing import Literal, Sequence, Union
+
+import pyarrow as pa
+
+from .._baseclasses import (
+    BaseBatch,
+    BaseExtensionType,
+    ComponentBatchMixin,
+)
+
+__all__ = ["GraphType", "GraphTypeArrayLike", "GraphTypeBatch", "GraphTypeLike", "GraphTypeType"]
+
+
+from enum import Enum
+
+
+class GraphType(Enum):
+    """
+    **Component**: Specifies if a graph has directed or undirected edges.
+    """
+
+    Undirected = 1
+    """The graph has undirected edges."""
+
+    Directed = 2
+    """The graph has directed edges, pointing from the first to the second node of each [`components.GraphEdge`][rerun.components.GraphEdge]."""
+
+
+GraphTypeLike = Union[GraphType, Literal["undirected", "directed"]]
+GraphTypeArrayLike = Union[GraphTypeLike, Sequence[GraphTypeLike]]
+
+
+class GraphTypeType(BaseExtensionType):
+    _TYPE_NAME: str = "rerun.components.GraphType"
+
+    def __init__(self) -> None:
+        pa.ExtensionType.__init__(
+            self,
+            pa.sparse_union([
+                pa.field("_null_markers", pa.null(), nullable=True, metadata={}),
+                pa.field("Undirected", pa.null(), nullable=True, metadata={}),
+                pa.field("Directed", pa.null(), nullable=True, metadata={}),
+            ]),
+            self._TYPE_NAME,
+        )
+
+
+class GraphTypeBatch(BaseBatch[GraphTypeArrayLike], ComponentBatchMixin):
+    _ARROW_TYPE = GraphTypeType()
+
+    @staticmethod
+    def _native_to_pa_array(data: GraphTypeArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (GraphType, int, str)):
+            data = [data]
+
+        types: list[int] = []
+
+        for value in data:
+            if value is None:
+                types.append(0)
+            elif isinstance(value, GraphType):
+                types.append(value.value)  # Actual enum value
+            elif isinstance(value, int):
+                types.append(value)  # By number
+            elif isinstance(value, str):
+                if hasattr(GraphType, value):
+                    types.append(GraphType[value].value)  # fast path
+                elif value.lower() == "undirected":
+                    types.append(GraphType.Undirected.value)
+                elif value.lower() == "directed":
+                    types.append(GraphType.Directed.value)
+                else:
+                    raise ValueError(f"Unknown GraphType kind: {value}")
+            else:
+                raise ValueError(f"Unknown GraphType kind: {value}")
+
+        buffers = [
+            None,
+            pa.array(types, type=pa.int8()).buffers()[1],
+        ]
+        children = (1 + 2) * [pa.nulls(len(data))]
+
+        return pa.UnionArray.from_buffers(
+            type=data_type,
+            length=len(data),
+            buffers=buffers,
+            children=children,
+        )
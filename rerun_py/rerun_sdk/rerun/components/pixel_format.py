@@ -0,0 +1,138 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/pixel_format.fbs".
+
+# You can extend this class by creating a "PixelFormatExt" class in "pixel_format_ext.py".
+
+from __future__ import annotations
+
+from typing import Literal, Sequence, Union
+
+import pyarrow as pa
+
+from .._baseclasses import (
+    BaseBatch,
+    BaseExtensionType,
+    ComponentBatchMixin,
+)
+
+__all__ = ["PixelFormat", "PixelFormatArrayLike", "PixelFormatBatch", "PixelFormatLike", "PixelFormatType"]
+
+
+from enum import Enum
+
+
+class PixelFormat(Enum):
+    """
+    **Component**: Specifies the memory layout of an [`archetypes.Image`][rerun.archetypes.Image] that cannot be described by a [`components.ColorModel`][rerun.components.ColorModel].
+
+    Most images can be described by a [`components.ColorModel`][rerun.components.ColorModel] and a [`components.ChannelDataType`][rerun.components.ChannelDataType],
+    e.g. `RGB` and `U8` respectively.
+    However, some image formats use chroma downsampling or a color filter mosaic,
+    and that is what this [`components.PixelFormat`][rerun.components.PixelFormat] is for.
+
+    The image data is then the raw buffer as it comes out of the camera:
+    - `NV12`: a `(H*3/2)xW` tensor of `U8`.
+    - `YUY2`: a `Hx(W*2)` tensor of `U8`.
+    - Bayer formats: a `HxW` tensor of `U8` or `U16`.
+    """
+
+    Nv12 = 1
+    """
+    `NV12` (aka `Y_UV12`) is a YUV 4:2:0 chroma downsampled format with 12 bits per pixel and 8 bits per channel.
+
+    First comes entire image in Y in one plane,
+    followed by a plane with interleaved lines ordered as U0, V0, U1, V1, etc.
+    """
+
+    Yuy2 = 2
+    """
+    `YUY2` (aka `YUYV` or `YUYV16`), is a YUV 4:2:2 chroma downsampled format with 16 bits per pixel and 8 bits per channel.
+
+    The order of the channels is Y0, U0, Y1, V0, all in the same plane.
+    """
+
+    BayerRggb = 3
+    """Raw Bayer mosaic whose top-left 2x2 block is red, green, green, blue (row by row)."""
+
+    BayerBggr = 4
+    """Raw Bayer mosaic whose top-left 2x2 block is blue, green, green, red (row by row)."""
+
+    BayerGrbg = 5
+    """Raw Bayer mosaic whose top-left 2x2 block is green, red, blue, green (row by row)."""
+
+    BayerGbrg = 6
+    """Raw Bayer mosaic whose top-left 2x2 block is green, blue, red, green (row by row)."""
+
+
+PixelFormatLike = Union[PixelFormat, Literal["nv12", "yuy2", "bayerrggb", "bayerbggr", "bayergrbg", "bayergbrg"]]
+PixelFormatArrayLike = Union[PixelFormatLike, Sequence[PixelFormatLike]]
+
+
+class PixelFormatType(BaseExtensionType):
+    _TYPE_NAME: str = "rerun.components.PixelFormat"
+
+    def __init__(self) -> None:
+        pa.ExtensionType.__init__(
+            self,
+            pa.sparse_union([
+                pa.field("_null_markers", pa.null(), nullable=True, metadata={}),
+                pa.field("NV12", pa.null(), nullable=True, metadata={}),
+                pa.field("YUY2", pa.null(), nullable=True, metadata={}),
+                pa.field("BayerRGGB", pa.null(), nullable=True, metadata={}),
+                pa.field("BayerBGGR", pa.null(), nullable=True, metadata={}),
+                pa.field("BayerGRBG", pa.null(), nullable=True, metadata={}),
+                pa.field("BayerGBRG", pa.null(), nullable=True, metadata={}),
+            ]),
+            self._TYPE_NAME,
+        )
+
+
+class PixelFormatBatch(BaseBatch[PixelFormatArrayLike], ComponentBatchMixin):
+    _ARROW_TYPE = PixelFormatType()
+
+    @staticmethod
+    def _native_to_pa_array(data: PixelFormatArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (PixelFormat, int, str)):
+            data = [data]
+
+        types: list[int] = []
+
+        for value in data:
+            if value is None:
+                types.append(0)
+            elif isinstance(value, PixelFormat):
+                types.append(value.value)  # Actual enum value
+            elif isinstance(value, int):
+                types.append(value)  # By number
+            elif isinstance(value, str):
+                if hasattr(PixelFormat, value):
+                    types.append(PixelFormat[value].value)  # fast path
+                elif value.lower() == "nv12":
+                    types.append(PixelFormat.Nv12.value)
+                elif value.lower() == "yuy2":
+                    types.append(PixelFormat.Yuy2.value)
+                elif value.lower() == "bayerrggb":
+                    types.append(PixelFormat.BayerRggb.value)
+                elif value.lower() == "bayerbggr":
+                    types.append(PixelFormat.BayerBggr.value)
+                elif value.lower() == "bayergrbg":
+                    types.append(PixelFormat.BayerGrbg.value)
+                elif value.lower() == "bayergbrg":
+                    types.append(PixelFormat.BayerGbrg.value)
+                else:
+                    raise ValueError(f"Unknown PixelFormat kind: {value}")
+            else:
+                raise ValueError(f"Unknown PixelFormat kind: {value}")
+
+        buffers = [
+            None,
+            pa.array(types, type=pa.int8()).buffers()[1],
+        ]
+        children = (1 + 6) * [pa.nulls(len(data))]
+
+        return pa.UnionArray.from_buffers(
+            type=data_type,
+            length=len(data),
+            buffers=buffers,
+            children=children,
+        )
@@ -0,0 +1,44 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/undistort_images.fbs".
+
+# You can extend this class by creating a "UndistortImagesExt" class in "undistort_images_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["UndistortImages", "UndistortImagesBatch", "UndistortImagesType"]
+
+
+class UndistortImages(datatypes.Bool, ComponentMixin):
+    """
+    **Component**: Whether to undistort the images of a camera when displaying them.
+
+    Undistorted images line up with 3D content projected into the camera,
+    but are resampled and may lose a bit of sharpness.
+
+    When images are shown distorted, 3D points and line strips projected into 2D views of the camera
+    are distorted to match them. Other 3D content is projected without lens distortion.
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of UndistortImagesExt in undistort_images_ext.py
+
+    # Note: there are no fields here because UndistortImages delegates to datatypes.Bool
+    pass
+
+
+class UndistortImagesType(datatypes.BoolType):
+    _TYPE_NAME: str = "rerun.components.UndistortImages"
+
+
+class UndistortImagesBatch(datatypes.BoolBatch, ComponentBatchMixin):
+    _ARROW_TYPE = UndistortImagesType()
+
+
+# This is patched in late to avoid circular dependencies.
+UndistortImages._BATCH_TYPE = UndistortImagesBatch  # type: ignore[assignment]
@@ -0,0 +1,36 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/video_timestamp.fbs".
+
+# You can extend this class by creating a "VideoTimestampExt" class in "video_timestamp_ext.py".
+
+from __future__ import annotations
+
+from .. import datatypes
+from .._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["VideoTimestamp", "VideoTimestampBatch", "VideoTimestampType"]
+
+
+class VideoTimestamp(datatypes.TimeInt, ComponentMixin):
+    """**Component**: A timestamp within an [`archetypes.AssetVideo`][rerun.archetypes.AssetVideo], in nanoseconds since its first frame."""
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of VideoTimestampExt in video_timestamp_ext.py
+
+    # Note: there are no fields here because VideoTimestamp delegates to datatypes.TimeInt
+    pass
+
+
+class VideoTimestampType(datatypes.TimeIntType):
+    _TYPE_NAME: str = "rerun.components.VideoTimestamp"
+
+
+class VideoTimestampBatch(datatypes.TimeIntBatch, ComponentBatchMixin):
+    _ARROW_TYPE = VideoTimestampType()
+
+
+# This is patched in late to avoid circular dependencies.
+VideoTimestamp._BATCH_TYPE = VideoTimestampBatch  # type: ignore[assignment]
@@ -27,6 +27,14 @@ from .class_description_map_elem import (
     ClassDescriptionMapElemType,
 )
 from .class_id import ClassId, ClassIdArrayLike, ClassIdBatch, ClassIdLike, ClassIdType
+from .distortion_coefficients import (
+    DistortionCoefficients,
+    DistortionCoefficientsArrayLike,
+    DistortionCoefficientsBatch,
+    DistortionCoefficientsLike,
+    DistortionCoefficientsType,
+)
+from .dvec2d import DVec2D, DVec2DArrayLike, DVec2DBatch, DVec2DLike, DVec2DType
 from .entity_path import EntityPath, EntityPathArrayLike, EntityPathBatch, EntityPathLike, EntityPathType
 from .float32 import Float32, Float32ArrayLike, Float32Batch, Float32Like, Float32Type
 from .float64 import Float64, Float64ArrayLike, Float64Batch, Float64Like, Float64Type
@@ -90,6 +98,7 @@ from .translation_rotation_scale3d import (
 from .uint32 import UInt32, UInt32ArrayLike, UInt32Batch, UInt32Like, UInt32Type
 from .uint64 import UInt64, UInt64ArrayLike, UInt64Batch, UInt64Like, UInt64Type
 from .utf8 import Utf8, Utf8ArrayLike, Utf8Batch, Utf8Like, Utf8Type
+from .utf8_pair import Utf8Pair, Utf8PairArrayLike, Utf8PairBatch, Utf8PairLike, Utf8PairType
 from .uuid import Uuid, UuidArrayLike, UuidBatch, UuidLike, UuidType
 from .uvec2d import UVec2D, UVec2DArrayLike, UVec2DBatch, UVec2DLike, UVec2DType
 from .uvec3d import UVec3D, UVec3DArrayLike, UVec3DBatch, UVec3DLike, UVec3DType
@@ -148,6 +157,16 @@ __all__ = [
     "ClassIdBatch",
     "ClassIdLike",
     "ClassIdType",
+    "DVec2D",
+    "DVec2DArrayLike",
+    "DVec2DBatch",
+    "DVec2DLike",
+    "DVec2DType",
+    "DistortionCoefficients",
+    "DistortionCoefficientsArrayLike",
+    "DistortionCoefficientsBatch",
+    "DistortionCoefficientsLike",
+    "DistortionCoefficientsType",
     "EntityPath",
     "EntityPathArrayLike",
     "EntityPathBatch",
@@ -297,6 +316,11 @@ __all__ = [
     "Utf8ArrayLike",
     "Utf8Batch",
     "Utf8Like",
+    "Utf8Pair",
+    "Utf8PairArrayLike",
+    "Utf8PairBatch",
+    "Utf8PairLike",
+    "Utf8PairType",
     "Utf8Type",
     "Uuid",
     "UuidArrayLike",
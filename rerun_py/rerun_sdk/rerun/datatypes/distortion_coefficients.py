@@ -0,0 +1,85 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/datatypes/distortion_coefficients.fbs".
+
+# You can extend this class by creating a "DistortionCoefficientsExt" class in "distortion_coefficients_ext.py".
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any, Sequence, Union
+
+import numpy as np
+import numpy.typing as npt
+import pyarrow as pa
+from attrs import define, field
+
+from .._baseclasses import (
+    BaseBatch,
+    BaseExtensionType,
+)
+from .._converters import (
+    to_np_float32,
+)
+from .distortion_coefficients_ext import DistortionCoefficientsExt
+
+__all__ = [
+    "DistortionCoefficients",
+    "DistortionCoefficientsArrayLike",
+    "DistortionCoefficientsBatch",
+    "DistortionCoefficientsLike",
+    "DistortionCoefficientsType",
+]
+
+
+@define(init=False)
+class DistortionCoefficients(DistortionCoefficientsExt):
+    """
+    **Datatype**: Coefficients of a lens distortion model.
+
+    Stored in the same order as OpenCV uses them: `[k1, k2, p1, p2, k3, k4, k5, k6]`,
+    where `k*` are radial and `p*` are tangential coefficients.
+    Models that use fewer coefficients ignore the remaining ones, which should be set to zero.
+    """
+
+    def __init__(self: Any, coefficients: DistortionCoefficientsLike):
+        """Create a new instance of the DistortionCoefficients datatype."""
+
+        # You can define your own __init__ function as a member of DistortionCoefficientsExt in distortion_coefficients_ext.py
+        self.__attrs_init__(coefficients=coefficients)
+
+    coefficients: npt.NDArray[np.float32] = field(converter=to_np_float32)
+
+    def __array__(self, dtype: npt.DTypeLike = None) -> npt.NDArray[Any]:
+        # You can define your own __array__ function as a member of DistortionCoefficientsExt in distortion_coefficients_ext.py
+        return np.asarray(self.coefficients, dtype=dtype)
+
+
+if TYPE_CHECKING:
+    DistortionCoefficientsLike = Union[DistortionCoefficients, npt.NDArray[Any], npt.ArrayLike, Sequence[float]]
+else:
+    DistortionCoefficientsLike = Any
+
+DistortionCoefficientsArrayLike = Union[
+    DistortionCoefficients,
+    Sequence[DistortionCoefficientsLike],
+    npt.NDArray[Any],
+    npt.ArrayLike,
+    Sequence[Sequence[float]],
+    Sequence[float],
+]
+
+
+class DistortionCoefficientsType(BaseExtensionType):
+    _TYPE_NAME: str = "rerun.datatypes.DistortionCoefficients"
+
+    def __init__(self) -> None:
+        pa.ExtensionType.__init__(
+            self, pa.list_(pa.field("item", pa.float32(), nullable=False, metadata={}), 8), self._TYPE_NAME
+        )
+
+
+class DistortionCoefficientsBatch(BaseBatch[DistortionCoefficientsArrayLike]):
+    _ARROW_TYPE = DistortionCoefficientsType()
+
+    @staticmethod
+    def _native_to_pa_array(data: DistortionCoefficientsArrayLike, data_type: pa.DataType) -> pa.Array:
+        return DistortionCoefficientsExt.native_to_pa_array_override(data, data_type)
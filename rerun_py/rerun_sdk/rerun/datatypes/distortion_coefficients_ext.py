@@ -0,0 +1,32 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Sequence
+
+import numpy as np
+import pyarrow as pa
+
+from .._validators import flat_np_float32_array_from_array_like
+
+if TYPE_CHECKING:
+    from . import DistortionCoefficientsArrayLike
+
+
+NUMPY_VERSION = tuple(map(int, np.version.version.split(".")[:2]))
+
+
+class DistortionCoefficientsExt:
+    """Extension for [DistortionCoefficients][rerun.datatypes.DistortionCoefficients]."""
+
+    @staticmethod
+    def native_to_pa_array_override(data: DistortionCoefficientsArrayLike, data_type: pa.DataType) -> pa.Array:
+        # TODO(ab): get rid of this once we drop support for Python 3.8. Make sure to pin numpy>=1.25.
+        if NUMPY_VERSION < (1, 25):
+            # Older numpy doesn't seem to support `data` in the form of a list of `DistortionCoefficients`
+            # this happens for python 3.8 (1.25 supports 3.9+)
+            from . import DistortionCoefficients
+
+            if isinstance(data, Sequence):
+                data = [np.array(p.coefficients) if isinstance(p, DistortionCoefficients) else p for p in data]
+
+        coefficients = flat_np_float32_array_from_array_like(data, 8)
+        return pa.FixedSizeListArray.from_arrays(coefficients, type=data_type)
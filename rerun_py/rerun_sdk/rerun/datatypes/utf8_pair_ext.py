@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pyarrow as pa
+
+if TYPE_CHECKING:
+    from . import (
+        Utf8Pair,
+        Utf8PairArrayLike,
+        Utf8PairLike,
+    )
+
+
+def _utf8_pair_converter(
+    data: Utf8PairLike,
+) -> Utf8Pair:
+    from . import Utf8Pair
+
+    if isinstance(data, Utf8Pair):
+        return data
+    else:
+        return Utf8Pair(*data)
+
+
+class Utf8PairExt:
+    """Extension for [Utf8Pair][rerun.datatypes.Utf8Pair]."""
+
+    @staticmethod
+    def native_to_pa_array_override(data: Utf8PairArrayLike, data_type: pa.DataType) -> pa.Array:
+        from . import Utf8Batch, Utf8Pair
+
+        if isinstance(data, Utf8Pair):
+            data = [data]
+
+        pairs = [_utf8_pair_converter(item) for item in data]
+
+        first = [pair.first for pair in pairs]
+        second = [pair.second for pair in pairs]
+
+        first_array = Utf8Batch(first).as_arrow_array().storage
+        second_array = Utf8Batch(second).as_arrow_array().storage
+
+        return pa.StructArray.from_arrays(
+            arrays=[first_array, second_array],
+            fields=[data_type.field("first"), data_type.field("second")],
+        )
@@ -169,6 +169,9 @@ fn rerun_bindings(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(escape_entity_path_part, m)?)?;
     m.add_function(wrap_pyfunction!(new_entity_path, m)?)?;
 
+    // video
+    m.add_function(wrap_pyfunction!(asset_video_read_frame_timestamps_ns, m)?)?;
+
     Ok(())
 }
 
@@ -1309,6 +1312,21 @@ fn new_entity_path(parts: Vec<&str>) -> String {
     path.to_string()
 }
 
+// --- Video ---
+
+/// When each frame of a video is shown, in nanoseconds, in presentation order.
+///
+/// If `media_type` is `None`, it is guessed from the contents of the video.
+#[pyfunction]
+fn asset_video_read_frame_timestamps_ns(
+    video_bytes: &[u8],
+    media_type: Option<&str>,
+) -> PyResult<Vec<i64>> {
+    re_types::archetypes::AssetVideo::from_file_contents(video_bytes.to_vec(), media_type)
+        .read_frame_timestamps_ns()
+        .map_err(|err| PyRuntimeError::new_err(err.to_string()))
+}
+
 // --- Helpers ---
 
 fn python_version(py: Python<'_>) -> re_log_types::PythonVersion {
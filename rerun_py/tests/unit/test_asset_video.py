@@ -0,0 +1,34 @@
+from __future__ import annotations
+
+import pathlib
+import tempfile
+
+import rerun as rr
+
+
+def test_asset_video() -> None:
+    blob_bytes = b"not really a video"
+    blob_comp = rr.components.Blob(blob_bytes)
+
+    rr.set_strict_mode(True)
+
+    with tempfile.TemporaryDirectory() as tmpdir:
+        path = pathlib.Path(tmpdir) / "video.mp4"
+        path.write_bytes(blob_bytes)
+
+        assets = [
+            rr.AssetVideo(path=path),
+            rr.AssetVideo(path=str(path)),
+            rr.AssetVideo(contents=blob_bytes, media_type=rr.components.MediaType.MP4),
+            rr.AssetVideo(contents=blob_comp, media_type=rr.components.MediaType.MP4),
+        ]
+
+    for asset in assets:
+        assert asset.blob.as_arrow_array() == rr.components.BlobBatch(blob_comp).as_arrow_array()
+        assert asset.media_type == rr.components.MediaTypeBatch(rr.components.MediaType.MP4)
+
+
+def test_video_frame_reference() -> None:
+    frame = rr.VideoFrameReference(timestamp=1_000_000_000)
+
+    assert frame.timestamp == rr.components.VideoTimestampBatch(1_000_000_000)
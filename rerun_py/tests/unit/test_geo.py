@@ -0,0 +1,66 @@
+from __future__ import annotations
+
+import numpy as np
+import rerun as rr
+import rerun.blueprint as rrb
+from rerun.components import ColorBatch, GeoLineString, GeoLineStringBatch, LatLonBatch, RadiusBatch
+from rerun.datatypes import DVec2D, DVec2DArrayLike
+
+
+def test_geo_points() -> None:
+    positions_variants: list[DVec2DArrayLike] = [
+        [[59.3, 18.1], [41.0, -109.0]],
+        [DVec2D([59.3, 18.1]), DVec2D([41.0, -109.0])],
+        np.array([[59.3, 18.1], [41.0, -109.0]]),
+        [59.3, 18.1, 41.0, -109.0],
+    ]
+
+    for positions in positions_variants:
+        arch = rr.GeoPoints(positions, radii=[2.0], colors=[0xFF0000FF])
+        print(f"{arch}\n")
+
+        assert arch.positions == LatLonBatch([[59.3, 18.1], [41.0, -109.0]])
+        assert arch.radii == RadiusBatch._optional([2.0])
+        assert arch.colors == ColorBatch._optional([0xFF0000FF])
+
+
+def test_geo_points_keep_double_precision() -> None:
+    arch = rr.GeoPoints([[59.319221123, 18.075631456]])
+
+    assert arch.positions.as_arrow_array().storage.values.to_pylist() == [59.319221123, 18.075631456]
+
+
+def test_geo_line_strings() -> None:
+    line_strings_variants = [
+        [[[41.0, -109.0], [41.0, -102.0], [37.0, -102.0]], [[59.3, 18.1], [59.4, 18.0]]],
+        [
+            np.array([[41.0, -109.0], [41.0, -102.0], [37.0, -102.0]]),
+            np.array([[59.3, 18.1], [59.4, 18.0]]),
+        ],
+        [
+            GeoLineString([[41.0, -109.0], [41.0, -102.0], [37.0, -102.0]]),
+            GeoLineString([[59.3, 18.1], [59.4, 18.0]]),
+        ],
+    ]
+
+    for line_strings in line_strings_variants:
+        arch = rr.GeoLineStrings(line_strings, radii=[1.0, 2.0])
+        print(f"{arch}\n")
+
+        assert arch.line_strings == GeoLineStringBatch(
+            [[[41.0, -109.0], [41.0, -102.0], [37.0, -102.0]], [[59.3, 18.1], [59.4, 18.0]]]
+        )
+        assert arch.radii == RadiusBatch._optional([1.0, 2.0])
+        assert arch.colors == ColorBatch._optional(None)
+
+
+def test_geo_line_string_single() -> None:
+    arch = rr.GeoLineStrings([[41.0, -109.0], [41.0, -102.0]])
+
+    assert arch.line_strings == GeoLineStringBatch([GeoLineString([[41.0, -109.0], [41.0, -102.0]])])
+
+
+def test_map_view_properties() -> None:
+    view = rrb.MapView(map_options="/path/to/tiles")
+
+    assert view.properties["MapOptions"] == rrb.archetypes.MapOptions(tile_directory="/path/to/tiles")
@@ -0,0 +1,51 @@
+from __future__ import annotations
+
+import rerun as rr
+from rerun.components import (
+    ColorBatch,
+    GraphEdgeBatch,
+    GraphNodeBatch,
+    GraphType,
+    GraphTypeBatch,
+    GraphTypeLike,
+    Position2DBatch,
+    RadiusBatch,
+    TextBatch,
+)
+from rerun.datatypes import Utf8Pair, Utf8PairArrayLike
+
+
+def test_graph_nodes() -> None:
+    arch = rr.GraphNodes(
+        ["a", "b", "c"],
+        positions=[[0, 0], [1, 0], [0, 1]],
+        colors=[0xFF0000FF],
+        labels=["A", "B", "C"],
+        radii=[2.0],
+    )
+    print(f"{arch}\n")
+
+    assert arch.node_ids == GraphNodeBatch(["a", "b", "c"])
+    assert arch.positions == Position2DBatch._optional([[0, 0], [1, 0], [0, 1]])
+    assert arch.colors == ColorBatch._optional([0xFF0000FF])
+    assert arch.labels == TextBatch._optional(["A", "B", "C"])
+    assert arch.radii == RadiusBatch._optional([2.0])
+
+
+def test_graph_edges() -> None:
+    edges_variants: list[Utf8PairArrayLike] = [
+        [("a", "b"), ("b", "c")],
+        [Utf8Pair("a", "b"), Utf8Pair("b", "c")],
+    ]
+
+    for edges in edges_variants:
+        arch = rr.GraphEdges(edges)
+        print(f"{arch}\n")
+
+        assert arch.edges == GraphEdgeBatch([("a", "b"), ("b", "c")])
+        assert arch.graph_type == GraphTypeBatch._optional(None)
+
+    graph_types: list[GraphTypeLike] = [GraphType.Directed, "directed"]
+    for graph_type in graph_types:
+        arch = rr.GraphEdges([("a", "b")], graph_type=graph_type)
+        assert arch.graph_type == GraphTypeBatch._optional(GraphType.Directed)
@@ -119,3 +119,26 @@ def test_image_compress() -> None:
 
         # Should still be an Image
         assert type(compressed) == rr.Image
+
+
+def test_image_pixel_format() -> None:
+    rr.set_strict_mode(True)
+
+    # BGR is an RGB-shaped image with swapped channels.
+    image_data = np.asarray(rng.uniform(0, 255, (10, 20, 3)), dtype=np.uint8)
+    image = rr.Image(image_data, color_model="bgr")
+    assert image.color_model == rr.components.ColorModelBatch(rr.components.ColorModel.Bgr)
+    assert image.pixel_format is None
+
+    # NV12 is a single plane of shape (height * 3/2, width).
+    nv12_data = np.zeros((15, 20), dtype=np.uint8)
+    image = rr.Image(nv12_data, pixel_format=rr.components.PixelFormat.Nv12)
+    assert image.pixel_format == rr.components.PixelFormatBatch("nv12")
+
+    rr.set_strict_mode(False)
+    with pytest.warns(RerunWarning) as warnings:
+        compressed = image.compress(jpeg_quality=80)
+
+        assert len(warnings) == 1
+        assert "cannot be JPEG compressed" in str(warnings[0])
+        assert type(compressed) == rr.Image
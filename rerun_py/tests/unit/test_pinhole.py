@@ -4,7 +4,15 @@ import itertools
 
 import numpy as np
 import rerun as rr
-from rerun.components import PinholeProjectionBatch, ResolutionBatch, ViewCoordinatesBatch
+from rerun.components import (
+    DistortionCoefficientsBatch,
+    DistortionModel,
+    DistortionModelBatch,
+    PinholeProjectionBatch,
+    ResolutionBatch,
+    UndistortImagesBatch,
+    ViewCoordinatesBatch,
+)
 from rerun.datatypes import Mat3x3Like, Vec2DLike, ViewCoordinatesLike
 
 
@@ -44,5 +52,27 @@ def test_pinhole() -> None:
         )
 
 
+def test_pinhole_distortion() -> None:
+    arch = rr.Pinhole(
+        focal_length=500,
+        resolution=[640, 480],
+        distortion_model="KannalaBrandt",
+        distortion_coefficients=[0.1, -0.01, 0.002, 0.0003],
+        undistort_images=True,
+    )
+
+    assert arch.distortion_model == DistortionModelBatch._optional(DistortionModel.KannalaBrandt)
+    assert arch.distortion_coefficients == DistortionCoefficientsBatch._optional(
+        [0.1, -0.01, 0.002, 0.0003, 0.0, 0.0, 0.0, 0.0]
+    )
+    assert arch.undistort_images == UndistortImagesBatch._optional(True)
+
+    arch = rr.Pinhole(focal_length=500, resolution=[640, 480])
+    assert arch.distortion_model is None
+    assert arch.distortion_coefficients is None
+    assert arch.undistort_images is None
+
+
 if __name__ == "__main__":
     test_pinhole()
+    test_pinhole_distortion()